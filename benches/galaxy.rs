@@ -0,0 +1,143 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Benchmarks of filtering large galaxies, run with `cargo bench`.
+ *
+ * Each filter is run both by looking at every celestial body and through
+ * `Galaxy::matching`, which only looks at the celestial bodies the secondary
+ * indexes say can match. The size of the galaxy can be changed by passing it
+ * as an argument, e.g. `cargo bench -- 200000`.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use chrono::NaiveDate;
+
+use planit::{core::generate_demo, CelestialBodyKind, Filter, Galaxy, Reminders, Status};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of celestial bodies in the benchmarked galaxy by default
+const BODIES: usize = 50_000;
+/// How often each benchmark is run, the fastest run is reported
+const RUNS: usize = 20;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+fn main() {
+    let bodies = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(BODIES);
+
+    let start = Instant::now();
+    let mut galaxy = Galaxy::default();
+    let ids = generate_demo(&mut galaxy, bodies, 1);
+    for id in ids.iter().step_by(7) {
+        let due = format!("2025-{:02}-{:02}", id % 12 + 1, id % 28 + 1);
+        galaxy.get_mut(*id).unwrap().field("due".into(), due);
+    }
+    println!(
+        "Generated {bodies} celestial bodies in {:?}",
+        start.elapsed()
+    );
+
+    let star = galaxy
+        .iter()
+        .find(|body| body.kind() == CelestialBodyKind::Star && body.parent().is_some())
+        .map(|body| body.id())
+        .unwrap();
+    let filters = [
+        "status:block".to_string(),
+        "tag:bug".to_string(),
+        format!("parent:{star}"),
+        "status:start tag:backend".to_string(),
+        "priority:high".to_string(),
+    ];
+
+    let start = Instant::now();
+    galaxy.matching(&"status:done".parse().unwrap());
+    println!("Built the indexes in {:?}\n", start.elapsed());
+
+    println!(
+        "{:<28} {:>8} {:>12} {:>12}",
+        "Filter", "Matches", "Scan", "Indexed"
+    );
+    for filter in &filters {
+        let parsed: Filter = filter.parse().unwrap();
+        let matches = galaxy.matching(&parsed).len();
+        let scan = fastest(|| galaxy.iter().filter(|body| parsed.matches(body)).count());
+        let indexed = fastest(|| galaxy.matching(&parsed).len());
+        println!("{filter:<28} {matches:>8} {scan:>12?} {indexed:>12?}");
+    }
+
+    let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    let reminders = fastest(|| Reminders::of(&galaxy, today, 7).overdue.len());
+    println!("\n{:<28} {:>21} {reminders:>12?}", "Reminders", "");
+
+    // Changing a celestial body only indexes that celestial body again
+    let planet = ids[ids.len() / 2];
+    let mut done = Status::Done;
+    let edit = fastest(|| {
+        done = if done == Status::Done {
+            Status::Todo
+        } else {
+            Status::Done
+        };
+        galaxy.get_mut(planet).unwrap().status(done, String::new());
+        galaxy.matching(&"status:done".parse().unwrap()).len()
+    });
+    println!("{:<28} {:>21} {edit:>12?}", "Change, then filter", "");
+}
+
+/// Helper function that runs `f` `RUNS` times, returning the fastest run
+fn fastest<T>(mut f: impl FnMut() -> T) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
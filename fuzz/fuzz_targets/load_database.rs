@@ -0,0 +1,57 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Fuzz target for loading the database. Any input must either be rejected
+ * with an error or load into a `Galaxy` that can be used, saved, and loaded
+ * again.
+ *
+ * Run with `cargo fuzz run load_database`.
+ */
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use planit::core::Galaxy;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(galaxy) = Galaxy::load_from_reader(data) else {
+        return;
+    };
+
+    // Walk everything that follows IDs between celestial bodies
+    for body in galaxy.iter() {
+        let _ = galaxy.display_id(body.id());
+        let _ = galaxy.progress(body.id());
+    }
+    let mut output = Vec::new();
+    let _ = galaxy.pretty_print_to_writer(&mut output, 80, true, true);
+
+    let mut saved = Vec::new();
+    galaxy
+        .save_to_writer(&mut saved)
+        .expect("a loaded database can be saved");
+    Galaxy::load_from_reader(saved.as_slice()).expect("a saved database can be loaded");
+});
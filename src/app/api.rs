@@ -0,0 +1,369 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the structured API over the `Galaxy`. Requests are modeled after
+ * HTTP (a method, a path, and query parameters) and responses are JSON, but the
+ * handlers do not depend on any particular transport. This allows the same
+ * handlers to be used locally from the command line or by a server.
+ *
+ * | **Route**          | **Query Parameters**                                |
+ * |:-------------------|:----------------------------------------------------|
+ * | `GET /galaxy`      |                                                     |
+ * | `GET /bodies`      | `filter`, `limit`, `offset`, `include`              |
+ * | `GET /bodies/<id>` | `include`                                           |
+ *
+ * `include` is a comma separated list of extra data to include with each
+ * celestial body: `history`, `children` (the full children instead of their
+ * IDs), and `parents` (the chain of parents from the root).
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::core::{CelestialBodyKind, CelestialBodyRef, Filter, Galaxy};
+
+use super::cli::ValueEnum;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The method of an API request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Method {
+    Get,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A request to the API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    /// The path without the query string (e.g. `/bodies/3`)
+    pub path: String,
+    /// The decoded query parameters
+    pub query: HashMap<String, String>,
+}
+
+impl Request {
+    /// Creates a request from a method and a target that may contain a query
+    /// string (e.g. `/bodies?filter=tag%3Abackend&limit=10`)
+    pub fn new(method: Method, target: &str) -> Self {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let query = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode(key), decode(value))
+            })
+            .collect();
+
+        Self {
+            method,
+            path: path.trim_end_matches('/').to_string(),
+            query,
+        }
+    }
+}
+
+/// A response from the API
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    /// The status code, using the HTTP meanings
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl ToString) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.to_string() }),
+        }
+    }
+
+    /// Whether or not the request succeeded
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Handles a single request against `galaxy`
+pub fn handle(galaxy: &Galaxy, request: &Request) -> Response {
+    let segments: Vec<&str> = request.path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match (request.method, segments.as_slice()) {
+        (Method::Get, ["galaxy"]) => get_galaxy(galaxy),
+        (Method::Get, ["bodies"]) => get_bodies(galaxy, &request.query),
+        (Method::Get, ["bodies", id]) => match id.parse() {
+            Ok(id) => get_body(galaxy, id, &request.query),
+            Err(_) => Response::error(400, format!("Invalid id: {id}")),
+        },
+        _ => Response::error(404, format!("Unknown route: {}", request.path)),
+    }
+}
+
+/// Handler for `GET /galaxy`
+fn get_galaxy(galaxy: &Galaxy) -> Response {
+    Response::ok(json!({
+        "title": galaxy.get_title(),
+        "description": galaxy.get_description(),
+        "count": galaxy.iter().count(),
+    }))
+}
+
+/// Handler for `GET /bodies`
+fn get_bodies(galaxy: &Galaxy, query: &HashMap<String, String>) -> Response {
+    let filter: Filter = match query.get("filter").map(|f| f.parse()) {
+        Some(Ok(filter)) => filter,
+        Some(Err(e)) => return Response::error(400, e),
+        None => Filter::default(),
+    };
+    let offset = match query.get("offset").map(|o| o.parse::<usize>()) {
+        Some(Ok(offset)) => offset,
+        Some(Err(_)) => return Response::error(400, "Invalid offset"),
+        None => 0,
+    };
+    let limit = match query.get("limit").map(|l| l.parse::<usize>()) {
+        Some(Ok(limit)) => Some(limit),
+        Some(Err(_)) => return Response::error(400, "Invalid limit"),
+        None => None,
+    };
+    let include = includes(query);
+
+    let mut matching = galaxy.matching(&filter);
+    matching.sort_by_key(|body| (body.priority(), body.id()));
+    let total = matching.len();
+    let items: Vec<Value> = matching
+        .into_iter()
+        .skip(offset)
+        .take(limit.unwrap_or(usize::MAX))
+        .map(|body| body_to_json(galaxy, body, &include))
+        .collect();
+
+    Response::ok(json!({
+        "total": total,
+        "offset": offset,
+        "limit": limit,
+        "items": items,
+    }))
+}
+
+/// Handler for `GET /bodies/<id>`
+fn get_body(galaxy: &Galaxy, id: u64, query: &HashMap<String, String>) -> Response {
+    match galaxy.get(id) {
+        Some(body) => Response::ok(body_to_json(galaxy, body, &includes(query))),
+        None => Response::error(404, format!("No celestial body with id {id}")),
+    }
+}
+
+/// Helper function that parses the `include` query parameter
+fn includes(query: &HashMap<String, String>) -> Vec<&str> {
+    query
+        .get("include")
+        .map(|include| include.split(',').map(str::trim).collect())
+        .unwrap_or_default()
+}
+
+/// Helper function that converts `body` to JSON, adding the extra data listed
+/// in `include`
+fn body_to_json(galaxy: &Galaxy, body: CelestialBodyRef, include: &[&str]) -> Value {
+    let mut value = json!({
+        "id": body.id(),
+        "key": body.key(),
+        "display_id": galaxy.display_id(body.id()),
+        "kind": body.kind(),
+        "parent": body.parent(),
+        "title": body.title(),
+        "description": body.description(),
+        "status": body.status(),
+        "stage": body.stage(),
+        "priority": body.priority(),
+        "archived": body.archived(),
+        "pinned": body.pinned(),
+        "snoozed_until": body.snoozed_until(),
+        "rank": body.rank(),
+        "estimate": body.estimate(),
+        "tags": body.tags(),
+        "assignees": body.assignees(),
+        "links": body.links(),
+        "commits": body.commits(),
+        "checklist": body.checklist(),
+        "blocker": body.blocker(),
+        "fields": body.fields(),
+        "children": body.children(),
+    });
+
+    if body.kind() == CelestialBodyKind::Star {
+        let rollup = galaxy.rollup(body.id()).unwrap_or_default();
+        value["rollup"] = json!({
+            "done_points": rollup.done_points,
+            "total_points": rollup.total_points,
+            "done_minutes": rollup.done_minutes,
+            "total_minutes": rollup.total_minutes,
+        });
+        let progress = galaxy.progress(body.id()).unwrap_or_default();
+        value["progress"] = json!({
+            "finished": progress.finished,
+            "total": progress.total,
+            "percent": progress.percent(),
+        });
+    }
+    if include.contains(&"history") {
+        value["history"] = json!(body.history());
+    }
+    if include.contains(&"children") {
+        value["children"] = body
+            .children()
+            .iter()
+            .filter_map(|id| galaxy.get(*id))
+            .map(|child| body_to_json(galaxy, child, &[]))
+            .collect();
+    }
+    if include.contains(&"parents") {
+        let mut parents = Vec::new();
+        let mut parent = body.parent().and_then(|id| galaxy.get(id));
+        while let Some(p) = parent {
+            parents.push(json!({ "id": p.id(), "title": p.title() }));
+            parent = p.parent().and_then(|id| galaxy.get(id));
+        }
+        parents.reverse();
+        value["parents"] = json!(parents);
+    }
+
+    value
+}
+
+/// Helper function that decodes a percent-encoded query string component
+fn decode(s: &str) -> String {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut itr = s.bytes();
+    while let Some(b) = itr.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = itr.by_ref().take(2).collect();
+                match std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|h| u8::from_str_radix(h, 16).ok())
+                {
+                    Some(decoded) => bytes.push(decoded),
+                    None => {
+                        bytes.push(b'%');
+                        bytes.extend(hex);
+                    }
+                }
+            }
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::core::CelestialBody;
+
+    use super::*;
+
+    fn galaxy() -> Galaxy {
+        let mut galaxy = Galaxy::default();
+        galaxy.star().title("Star".into());
+        galaxy.planet().title("Planet 1".into());
+        galaxy.planet().title("Planet 2".into());
+        galaxy.comet().title("Comet".into());
+        galaxy
+    }
+
+    #[test]
+    fn requests_decode_query_strings() {
+        let request = Request::new(Method::Get, "/bodies/?filter=kind%3Aplanet+Planet&limit=1");
+        assert_eq!(request.path, "/bodies");
+        assert_eq!(request.query["filter"], "kind:planet Planet");
+        assert_eq!(request.query["limit"], "1");
+    }
+
+    #[test]
+    fn bodies_are_filtered_and_paginated() {
+        let galaxy = galaxy();
+        let request = Request::new(Method::Get, "/bodies?filter=kind:planet&offset=1&limit=5");
+        let response = handle(&galaxy, &request);
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["total"], 2);
+        assert_eq!(response.body["items"].as_array().unwrap().len(), 1);
+        assert_eq!(response.body["items"][0]["title"], "Planet 2");
+        assert_eq!(response.body["items"][0]["kind"], "Planet");
+    }
+
+    #[test]
+    fn errors_are_reported_with_status_codes() {
+        let galaxy = galaxy();
+
+        let response = handle(&galaxy, &Request::new(Method::Get, "/bodies/42"));
+        assert_eq!(response.status, 404);
+
+        let response = handle(
+            &galaxy,
+            &Request::new(Method::Get, "/bodies?filter=bad:key"),
+        );
+        assert_eq!(response.status, 400);
+
+        let response = handle(&galaxy, &Request::new(Method::Get, "/nothing"));
+        assert_eq!(response.status, 404);
+    }
+}
@@ -0,0 +1,212 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains `planit batch`, which runs many commands in a single process. The
+ * database is loaded once before the first command and saved once after the
+ * last one, and only if every command succeeded.
+ *
+ * Every line is a command with the same syntax as on the command line, with
+ * or without the leading `planit`. Words are split like in a shell: quotes
+ * keep whitespace, and a backslash escapes the next character. Empty lines
+ * and lines starting with `#` are skipped.
+ *
+ * ``` text
+ * # Plan the release
+ * new star "Release 1.2"
+ * new planet "Update the changelog" --priority high
+ * bulk tag --filter "kind:planet status:todo" release
+ * ```
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    fs,
+    io::{self, BufRead},
+};
+
+use clap::Parser;
+
+use super::{
+    cli::{BatchArgs, Cli, Commands},
+    AppError, Result,
+};
+use crate::{core::Galaxy, util::theme::Themed};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Runs every command in the file given by `args` (or stdin), saving the
+/// `Galaxy` once at the end
+///
+/// # Errors
+/// Errors will occur in the following situations:
+/// - The file cannot be read
+/// - The database cannot be loaded or saved
+/// - Any of the commands failed, in which case nothing is saved
+pub fn run(args: BatchArgs) -> Result<()> {
+    let lines: Vec<String> = match args.file.as_deref() {
+        Some(path) if path.as_os_str() != "-" => fs::read_to_string(path)?
+            .lines()
+            .map(String::from)
+            .collect(),
+        _ => io::stdin().lock().lines().collect::<io::Result<_>>()?,
+    };
+
+    Galaxy::begin_batch()?;
+    let mut failed = 0;
+    for (number, line) in lines.iter().enumerate() {
+        if let Err(e) = run_line(line) {
+            eprintln!("{}", format!("Line {}: {e}", number + 1).error());
+            failed += 1;
+        }
+    }
+
+    Galaxy::end_batch(failed == 0)?;
+    match failed {
+        0 => Ok(()),
+        failed => Err(AppError::BatchFailed(failed)),
+    }
+}
+
+/// Helper function that runs the command on a single line of the batch
+fn run_line(line: &str) -> Result<()> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(());
+    }
+
+    let mut words = split_words(line).map_err(AppError::InvalidArgument)?;
+    if words.first().is_some_and(|word| word == "planit") {
+        words.remove(0);
+    }
+    let cli =
+        Cli::try_parse_from(std::iter::once("planit".to_string()).chain(words)).map_err(|e| {
+            // Only the first line of clap's message, without the usage
+            let message = e.to_string();
+            let message = message.lines().next().unwrap_or_default();
+            AppError::InvalidArgument(message.trim_start_matches("error: ").to_string())
+        })?;
+
+    match cli.command {
+        Some(command) if batchable(&command) => super::dispatch(Some(command)),
+        Some(_) => Err(AppError::InvalidArgument(
+            "this command cannot be run in a batch".into(),
+        )),
+        None => Err(AppError::InvalidArgument("missing command".into())),
+    }
+}
+
+/// Helper function that checks whether `command` can be run in a batch. This
+/// excludes commands that take over the terminal or stdin, run forever, or
+/// work on the database file directly.
+fn batchable(command: &Commands) -> bool {
+    match command {
+        Commands::Init(_)
+        | Commands::Batch(_)
+        | Commands::Tui(_)
+        | Commands::Convert(_)
+        | Commands::Merge(_)
+        | Commands::Sync => false,
+        #[cfg(feature = "serve")]
+        Commands::Serve(_) => false,
+        Commands::List(args) => !args.watch,
+        Commands::Api(args) => !args.rpc,
+        _ => true,
+    }
+}
+
+/// Splits `line` into words like a shell. Single and double quotes keep
+/// whitespace (e.g. `"Fix the login"`), and a backslash outside of single
+/// quotes escapes the next character.
+///
+/// # Errors
+/// Errors will occur if a quote is not closed or the line ends with a
+/// backslash
+pub fn split_words(line: &str) -> std::result::Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some('\''), '\'') | (Some('"'), '"') => quote = None,
+            (Some('\''), c) => word.get_or_insert_default().push(c),
+            (_, '\\') => match chars.next() {
+                Some(c) => word.get_or_insert_default().push(c),
+                None => return Err("the line ends with a backslash".into()),
+            },
+            (Some(_), c) => word.get_or_insert_default().push(c),
+            (None, '\'' | '"') => {
+                word.get_or_insert_default();
+                quote = Some(c);
+            }
+            (None, c) if c.is_whitespace() => words.extend(word.take()),
+            (None, c) => word.get_or_insert_default().push(c),
+        }
+    }
+
+    match quote {
+        Some(quote) => Err(format!("unclosed {quote}")),
+        None => {
+            words.extend(word);
+            Ok(words)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lines_are_split_like_in_a_shell() {
+        assert_eq!(
+            split_words(r#"new planet "Fix the login"  --priority high"#).unwrap(),
+            ["new", "planet", "Fix the login", "--priority", "high"]
+        );
+        assert_eq!(
+            split_words(r#"bulk tag --filter 'tag:a "b"' it\'s """#).unwrap(),
+            ["bulk", "tag", "--filter", r#"tag:a "b""#, "it's", ""]
+        );
+        assert!(split_words(r#"new star "Unclosed"#).is_err());
+        assert!(split_words(r"new star \").is_err());
+    }
+}
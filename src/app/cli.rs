@@ -33,13 +33,59 @@
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{env, path::PathBuf};
+use std::{
+    env, fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
 
-use clap::{ArgAction, Args, Subcommand};
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, Utc};
+use clap::{ArgAction, ArgGroup, Args, Subcommand};
 pub use clap::{Parser, ValueEnum};
+use colored::{ColoredString, Colorize};
 
-use super::Result;
-use crate::core::{CelestialBody, CelestialBodyKind, Galaxy};
+use super::{
+    api, completions,
+    config::{Config, SavedView},
+    csv::{Csv, Mapping},
+    diff, edit,
+    export::{self, Exporter},
+    githooks,
+    import::{self, Importer},
+    org::Org,
+    rpc, split,
+    taskwarrior::Taskwarrior,
+    tui, AppError, Result,
+};
+use crate::{
+    core::{
+        self, Activity, Blocker, Burndown, CelestialBody, CelestialBodyKind, CelestialBodyRef,
+        ChecklistItem, Comparison, Completion, Estimate, Filter, Format, Galaxy, GalaxyError,
+        GroupBy, IdFormat, IdScheme, Layout, Link, MemoryUsage, Merge, Priority, Reminders, Sort,
+        Sprint, Stage, Stats, Status, Workflow, ID,
+    },
+    util::{
+        self, shell,
+        theme::{self, Color, ColorChoice, Themed},
+        tree::{Column, Descriptions},
+    },
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TYPES                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of blocked celestial bodies and Stars listed by `stats`
+const STATS_TOP: usize = 5;
+/// The number of characters in the longest bar of a burndown chart
+const BURNDOWN_WIDTH: u64 = 40;
+/// How often `list --watch` checks whether the database has changed
+const WATCH_POLL: Duration = Duration::from_millis(250);
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -58,6 +104,27 @@ pub struct Cli {
     #[arg(short, long, action = ArgAction::Count)]
     pub verbose: u8,
 
+    /// Do not print what was changed after modifying celestial bodies
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// When to color the output, `NO_COLOR` turns off `auto` coloring
+    #[arg(long, value_enum, global = true, default_value_t)]
+    pub color: ColorChoice,
+
+    /// Only use ASCII characters for trees and icons
+    #[arg(long, global = true)]
+    pub ascii: bool,
+
+    /// Show what the command would change without saving anything
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Skip the parts of the database that cannot be parsed instead of
+    /// failing. They are removed once the database is saved.
+    #[arg(long, global = true)]
+    pub lenient: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -70,14 +137,179 @@ pub enum Commands {
     List(ListArgs),
     /// Create a new celestial body
     New(NewArgs),
+    /// Show everything about a single celestial body
+    Show(ShowArgs),
+    /// Apply an operation to many celestial bodies at once
+    Bulk(BulkArgs),
+    /// Edit the description of a celestial body, or the status, priority,
+    /// title, and tags of many celestial bodies, in $EDITOR
+    Edit(EditArgs),
+    /// Run a configured action on a celestial body
+    Run(RunArgs),
+    /// Query the Galaxy through the structured API, printing JSON
+    Api(ApiArgs),
+    /// Serve the structured API over HTTP
+    #[cfg(feature = "serve")]
+    Serve(ServeArgs),
+    /// Export due dates, milestones, and tasks to other applications
+    Export(ExportArgs),
+    /// Import open issues and milestones from an external tracker, or the tasks
+    /// of another task manager
+    Import(ImportArgs),
+    /// Record or export the retrospective of a sprint
+    Retro(RetroArgs),
+    /// Show what the colors and icons used for celestial bodies mean
+    Legend,
+    /// Track time spent working on celestial bodies
+    Track(TrackArgs),
+    /// Split the Markdown description of a Star into child celestial bodies
+    Split(SplitArgs),
+    /// Launch the TUI (the default when no command is given)
+    Tui(TuiArgs),
+    /// Archive celestial bodies, hiding them (and their descendants) from views
+    Archive(ArchiveArgs),
+    /// Unarchive celestial bodies, showing them in views again
+    Unarchive(ArchiveArgs),
+    /// Pin celestial bodies to the focus view until they are finished or
+    /// unpinned
+    Pin(PinArgs),
+    /// Unpin celestial bodies from the focus view
+    Unpin(PinArgs),
+    /// Move a celestial body before or after another one, changing the order
+    /// of siblings
+    Move(MoveArgs),
+    /// Hide a celestial body from views until a date, e.g. `planit snooze 12
+    /// until friday`
+    Snooze(SnoozeArgs),
+    /// Show snoozed celestial bodies in views again before their date
+    Unsnooze(UnsnoozeArgs),
+    /// Move celestial bodies (and their descendants) to the trash
+    Delete(DeleteArgs),
+    /// List, restore, or permanently remove deleted celestial bodies
+    Trash(TrashArgs),
+    /// Manage tags across the whole Galaxy
+    Tag(TagArgs),
+    /// Manage custom fields across the whole Galaxy
+    Field(FieldArgs),
+    /// Save, list, or remove named filters and sort orders
+    View(ViewArgs),
+    /// Add, remove, list, or open links to URLs and files
+    Link(LinkArgs),
+    /// Add, check off, remove, or list the checklist items of a Planet or
+    /// Comet
+    Check(CheckArgs),
+    /// Show or set the prefix of the short keys given to celestial bodies
+    KeyPrefix(KeyPrefixArgs),
+    /// Show or set how the IDs of celestial bodies are displayed
+    IdFormat(IdFormatArgs),
+    /// Show or set how the IDs of new celestial bodies are generated
+    IdScheme(IdSchemeArgs),
+    /// Set or remove the prefix used for the IDs within a Star
+    IdPrefix(IdPrefixArgs),
+    /// Show or change the rules that update the status of Stars when the
+    /// status of their children changes
+    Rules(RulesArgs),
+    /// Show or change the statuses that celestial bodies can have
+    Workflow(WorkflowArgs),
+    /// Show or change the celestial bodies picked to work on today
+    Today(TodayArgs),
+    /// List the working set for today: pinned celestial bodies, ones due by
+    /// today, and started ones
+    Focus(FocusArgs),
+    /// List the celestial bodies that are overdue or due soon
+    Remind(RemindArgs),
+    /// Show, plan, or close sprints
+    Sprint(SprintArgs),
+    /// Run commands from a file (or stdin), one per line, saving once at the
+    /// end and only if all of them succeeded
+    Batch(BatchArgs),
+    /// Print the completion script for a shell
+    Completions(CompletionsArgs),
+    /// Show statistics about the Galaxy
+    Stats(StatsArgs),
+    /// Create reports from the status history of the Galaxy
+    Report(ReportArgs),
+    /// Convert the database to another file format
+    Convert(ConvertArgs),
+    /// Merge two versions of the database, e.g. as a git merge driver
+    Merge(MergeArgs),
+    /// Compare two versions of the database, e.g. a file and a git revision
+    Diff(DiffArgs),
+    /// Set up git to merge the database with `planit merge`
+    Sync,
+    /// Attach git commits to the celestial bodies they reference
+    Git(GitArgs),
+    /// Install git hooks that check the celestial bodies referenced by commit
+    /// messages
+    Hooks(HooksArgs),
+    /// Print the path of the database used from the current directory
+    Which,
+    /// Print the JSON Schema of the database
+    Schema(SchemaArgs),
+    /// Check the database files against the JSON Schema and load them,
+    /// listing everything that is wrong with them
+    Doctor,
+    /// Add generated sample celestial bodies, e.g. for benchmarking
+    Generate(GenerateArgs),
+    /// Print the celestial bodies for shell completion, used by the
+    /// completion scripts
+    #[command(name = "__complete-ids", hide = true)]
+    CompleteIds,
 }
 
 #[derive(Args)]
 pub struct InitArgs {
     /// Title for the new project
-    pub title: String,
+    #[arg(required_unless_present = "demo")]
+    pub title: Option<String>,
     /// Description for the new project
     pub description: Option<String>,
+    /// Fill the new Galaxy with sample celestial bodies to explore
+    #[arg(long)]
+    pub demo: bool,
+    /// Prefix of the short keys given to celestial bodies (e.g. "PLAN-")
+    #[arg(short, long)]
+    pub key_prefix: Option<String>,
+    /// File format of the database
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Whether the database is a single file or a `.planit` directory with a
+    /// file per celestial body, which avoids most git merge conflicts
+    #[arg(short, long, value_enum, default_value_t)]
+    pub layout: Layout,
+    /// How the IDs of celestial bodies are generated. Random IDs do not
+    /// collide when celestial bodies are created on different git branches
+    #[arg(long, value_enum, default_value_t)]
+    pub id_scheme: IdScheme,
+    /// Create the Galaxy even if it would shadow a Galaxy in a parent
+    /// directory
+    #[arg(long)]
+    pub nested: bool,
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Number of celestial bodies to add, nothing is saved if they make the
+    /// database larger than its size limit
+    #[arg(short, long, default_value_t = 1000)]
+    pub bodies: usize,
+    /// Seed for the generated celestial bodies, the same seed always generates
+    /// the same titles, tags, and statuses
+    #[arg(short, long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+#[derive(Args)]
+pub struct SchemaArgs {
+    #[command(subcommand)]
+    pub command: SchemaCommands,
+}
+
+#[derive(Subcommand)]
+pub enum SchemaCommands {
+    /// Print the JSON Schema of the current version of the database, for
+    /// tools and scripts that read or write it directly
+    Print,
 }
 
 #[derive(Args)]
@@ -88,9 +320,63 @@ pub struct ListArgs {
     /// Include description in listing
     #[arg(short, long)]
     pub description: bool,
+    /// Wrap descriptions onto more lines instead of truncating them
+    #[arg(short, long, requires = "description")]
+    pub wrap: bool,
+    /// Number of columns to fit lines into. Defaults to the width of the
+    /// terminal, lines are not cut off when the output is not a terminal.
+    #[arg(long)]
+    pub width: Option<usize>,
+    /// Print everything at once instead of using $PAGER when the list does
+    /// not fit in the terminal
+    #[arg(long)]
+    pub no_pager: bool,
+    /// Include archived and snoozed celestial bodies
+    #[arg(short, long)]
+    pub all: bool,
+    /// Only list the celestial bodies assigned to the current user (`user` in
+    /// the configuration, or $USER)
+    #[arg(short, long)]
+    pub mine: bool,
+    /// List the celestial bodies matching a saved view (see `planit view`),
+    /// in its sort order
+    #[arg(long)]
+    pub view: Option<String>,
+    /// List the celestial bodies in sections, each with a header and the
+    /// number of celestial bodies in it: status, tag, assignee, parent,
+    /// priority, or field:<key>
+    #[arg(short, long)]
+    pub group_by: Option<GroupBy>,
+    /// Only list the celestial bodies matching this filter (e.g. "status:block
+    /// reason:vendor")
+    #[arg(short, long)]
+    pub filter: Option<String>,
+    /// Show why blocked and held celestial bodies are waiting, below their
+    /// titles
+    #[arg(long)]
+    pub show_reason: bool,
+    /// Only list the unfinished celestial bodies without any activity since
+    /// this date (e.g. "14d"), noting how long they have been idle
+    #[arg(long, value_parser = util::date::parse_from_today)]
+    pub stale: Option<NaiveDate>,
+    /// The columns printed for each celestial body, in order (e.g.
+    /// "id,status,title,assignee"). Defaults to `columns` in the configuration
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub columns: Vec<Column>,
+    /// List every project in `projects` of the configuration, one after
+    /// another, with IDs namespaced by project (e.g. "web:#12")
+    #[arg(long, conflicts_with = "watch")]
+    pub all_projects: bool,
+    /// Keep the list on screen, redrawing it whenever the database changes
+    #[arg(long)]
+    pub watch: bool,
+    /// Also redraw the watched list every this many seconds (e.g. to keep
+    /// relative times up to date)
+    #[arg(long, requires = "watch")]
+    pub interval: Option<u64>,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct NewArgs {
     /// Type of celestial body to create
     #[arg(value_enum)]
@@ -99,68 +385,4090 @@ pub struct NewArgs {
     pub title: String,
     /// Description for the new celestial body
     pub description: Option<String>,
+    /// Priority for the new celestial body
+    #[arg(short, long, value_enum, default_value_t = Priority::None)]
+    pub priority: Priority,
+    /// Estimated effort for the new Planet / Comet, in points (e.g. "3pts") or
+    /// as a duration (e.g. "2h")
+    #[arg(short, long)]
+    pub estimate: Option<Estimate>,
+    /// Comma separated list of people to assign the new celestial body to
+    #[arg(short, long = "assignee", value_delimiter = ',')]
+    pub assignees: Vec<String>,
+    /// Day the new Planet is due (e.g. "fri", "in 2 weeks", or "2025-12-01"),
+    /// stored in its `due` field
+    #[arg(long, value_parser = util::date::parse_from_today)]
+    pub due: Option<NaiveDate>,
 }
 
-////////////////////////////////////////////////////////////////////////////////
-//                                                                            //
-//                                 FUNCTIONS                                  //
-//                                                                            //
-////////////////////////////////////////////////////////////////////////////////
+#[derive(Args)]
+pub struct ShowArgs {
+    /// ID or short key of the celestial body to show
+    pub id: String,
+}
 
-/// Initializes a new Galaxy in the current directory
-pub fn init(args: InitArgs) -> Result<()> {
-    let mut galaxy = Galaxy::default().title(args.title);
-    if let Some(description) = args.description {
-        galaxy = galaxy.description(description);
-    }
+#[derive(Args)]
+pub struct BulkArgs {
+    #[command(subcommand)]
+    pub command: BulkCommands,
+}
 
-    let dir = env::current_dir()?;
-    galaxy.init(dir)?;
+#[derive(Subcommand)]
+pub enum BulkCommands {
+    /// Change the status of the selected celestial bodies
+    Status(BulkStatusArgs),
+    /// Change the priority of the selected celestial bodies
+    Priority(BulkPriorityArgs),
+    /// Change the estimate of the selected Planets / Comets
+    Estimate(BulkEstimateArgs),
+    /// Edit the title and/or description of the selected celestial bodies
+    Edit(BulkEditArgs),
+    /// Add a tag to the selected celestial bodies
+    Tag(BulkTagArgs),
+    /// Remove a tag from the selected celestial bodies
+    Untag(BulkTagArgs),
+    /// Assign someone to the selected celestial bodies
+    Assign(BulkAssignArgs),
+    /// Remove someone from the assignees of the selected celestial bodies
+    Unassign(BulkAssignArgs),
+}
 
-    Ok(())
+#[derive(Args)]
+#[group(required = true, multiple = true)]
+pub struct SelectArgs {
+    /// Comma separated list of IDs or short keys to select
+    #[arg(long, value_delimiter = ',')]
+    pub ids: Vec<String>,
+    /// Select celestial bodies matching the filter (e.g. "tag:backend status:todo")
+    #[arg(long)]
+    pub filter: Option<String>,
 }
 
-/// Lists all celestial bodies in the Galaxy
-pub fn list(args: ListArgs) -> Result<()> {
-    let (width, _) = crossterm::terminal::size()?;
-    let galaxy = Galaxy::load()?;
+#[derive(Args)]
+pub struct BulkStatusArgs {
+    #[command(flatten)]
+    pub select: SelectArgs,
+    /// New status for the selected celestial bodies, either a built in
+    /// status or one added with `workflow add`
+    pub status: String,
+    /// Explanation of why the status changed
+    #[arg(short, long, default_value = "")]
+    pub comment: String,
+    /// Why the celestial bodies are blocked or on hold, shown in views until
+    /// the status changes again
+    #[arg(short, long)]
+    pub reason: Option<String>,
+    /// ID or key of the celestial body that has to be finished first
+    #[arg(long)]
+    pub blocked_by: Option<String>,
+    /// Something outside of the Galaxy that is being waited on (e.g. a ticket
+    /// of another team)
+    #[arg(long)]
+    pub waiting_on: Option<String>,
+}
 
-    galaxy.pretty_print_to_writer(
-        &mut std::io::stdout(),
-        width as usize,
-        args.description,
-        args.recursive,
-    )?;
+#[derive(Args)]
+pub struct BulkPriorityArgs {
+    #[command(flatten)]
+    pub select: SelectArgs,
+    /// New priority for the selected celestial bodies
+    #[arg(value_enum)]
+    pub priority: Priority,
+}
 
-    Ok(())
+#[derive(Args)]
+pub struct BulkEstimateArgs {
+    #[command(flatten)]
+    pub select: SelectArgs,
+    /// New estimate in points (e.g. "3pts") or as a duration (e.g. "2h").
+    /// Clears the estimate if not given
+    pub estimate: Option<Estimate>,
 }
 
-/// Creates a new celestial body
-pub fn new(args: NewArgs) -> Result<()> {
-    let mut galaxy = Galaxy::load()?;
+#[derive(Args)]
+pub struct BulkEditArgs {
+    #[command(flatten)]
+    pub select: SelectArgs,
+    /// New title for the selected celestial bodies
+    #[arg(short, long)]
+    pub title: Option<String>,
+    /// New description for the selected celestial bodies
+    #[arg(short, long)]
+    pub description: Option<String>,
+}
 
-    match args.kind {
-        CelestialBodyKind::Comet => {
-            let comet = galaxy.comet().title(args.title);
-            if let Some(description) = args.description {
-                comet.description(description);
-            }
-        }
-        CelestialBodyKind::Planet => {
-            let planet = galaxy.planet().title(args.title);
-            if let Some(description) = args.description {
-                planet.description(description);
-            }
-        }
-        CelestialBodyKind::Star => {
-            let star = galaxy.star().title(args.title);
-            if let Some(description) = args.description {
-                star.description(description);
-            }
-        }
-    }
+#[derive(Args)]
+#[command(group(ArgGroup::new("select").args(["ids", "filter"]).multiple(true)))]
+pub struct EditArgs {
+    /// ID or short key of the celestial body to edit with --editor
+    #[arg(requires = "editor")]
+    pub id: Option<String>,
+    /// Edit the title and description of the celestial body as Markdown in
+    /// $EDITOR
+    #[arg(short, long, requires = "id", conflicts_with = "interactive")]
+    pub editor: bool,
+    /// Edit the selected celestial bodies as lines of text in $EDITOR
+    #[arg(short, long, required_unless_present = "editor", requires = "select")]
+    pub interactive: bool,
+    /// Comma separated list of IDs or short keys to select
+    #[arg(long, value_delimiter = ',', requires = "interactive")]
+    pub ids: Vec<String>,
+    /// Select celestial bodies matching the filter (e.g. "tag:backend status:todo")
+    #[arg(long, requires = "interactive")]
+    pub filter: Option<String>,
+}
 
-    galaxy.save()?;
+#[derive(Args)]
+pub struct BulkAssignArgs {
+    #[command(flatten)]
+    pub select: SelectArgs,
+    /// The name of the person to assign / unassign
+    pub name: String,
+}
 
-    Ok(())
+#[derive(Args)]
+pub struct BulkTagArgs {
+    #[command(flatten)]
+    pub select: SelectArgs,
+    /// The tag to add / remove
+    pub tag: String,
+}
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// ID or short key of the celestial body to run the action on
+    pub id: String,
+    /// Name of the action to run. Lists the available actions if not given
+    pub action: Option<String>,
+}
+
+#[derive(Args)]
+pub struct BatchArgs {
+    /// File with one command per line, stdin is read if not given or "-"
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ApiArgs {
+    /// Method of the request
+    #[arg(value_enum, required_unless_present = "rpc")]
+    pub method: Option<api::Method>,
+    /// Path of the request, including any query string (e.g. "/bodies?limit=10")
+    #[arg(required_unless_present = "rpc")]
+    pub path: Option<String>,
+    /// Answer JSON-RPC requests read from stdin, one per line, until stdin is
+    /// closed
+    #[arg(long, conflicts_with_all = ["method", "path"])]
+    pub rpc: bool,
+}
+
+#[cfg(feature = "serve")]
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 7777)]
+    pub port: u16,
+    /// Address to listen on. Only this machine can connect by default.
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+    /// Allow creating and updating celestial bodies
+    #[arg(long)]
+    pub write: bool,
+}
+
+#[derive(Args)]
+pub struct RetroArgs {
+    #[command(subcommand)]
+    pub command: RetroCommands,
+}
+
+#[derive(Subcommand)]
+pub enum RetroCommands {
+    /// Add notes and action items to the retrospective of a sprint
+    Add(RetroAddArgs),
+    /// Print the retrospective of a sprint as Markdown
+    Export(RetroExportArgs),
+}
+
+#[derive(Args)]
+pub struct RetroAddArgs {
+    /// Name of the sprint the retrospective is for
+    pub sprint: String,
+    /// Something that went well (may be repeated)
+    #[arg(short, long)]
+    pub went_well: Vec<String>,
+    /// Something that needs improvement (may be repeated)
+    #[arg(short, long)]
+    pub needs_improvement: Vec<String>,
+    /// An action item, created as a Comet in the next sprint (may be repeated)
+    #[arg(short, long)]
+    pub action: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct RetroExportArgs {
+    /// Name of the sprint the retrospective is for
+    pub sprint: String,
+}
+
+#[derive(Args)]
+pub struct TrackArgs {
+    #[command(subcommand)]
+    pub command: TrackCommands,
+}
+
+#[derive(Subcommand)]
+pub enum TrackCommands {
+    /// Start tracking work on a celestial body, stopping any other tracking
+    Start(TrackStartArgs),
+    /// Stop tracking work
+    Stop,
+    /// Show what is currently being tracked
+    Status,
+    /// Summarize the time tracked per Star and per tag
+    Report(TrackReportArgs),
+}
+
+#[derive(Args)]
+pub struct TrackStartArgs {
+    /// ID or short key of the celestial body to track work on
+    pub id: String,
+}
+
+#[derive(Args)]
+pub struct TrackReportArgs {
+    /// Only include time tracked since the start of the current week (Monday)
+    #[arg(short, long)]
+    pub week: bool,
+}
+
+#[derive(Args)]
+pub struct ImportArgs {
+    /// Tracker to import from
+    #[arg(long, value_enum)]
+    pub from: import::Source,
+    /// Repository to import from GitHub, in the form owner/name
+    #[arg(long)]
+    pub repo: Option<String>,
+    /// File to import from org-mode, Taskwarrior (`task export`), or CSV, `-`
+    /// for stdin
+    #[arg(long)]
+    pub file: Option<PathBuf>,
+    /// JSON file saying which CSV columns hold the title, status, parent key,
+    /// labels, due date, etc.
+    #[arg(long)]
+    pub mapping: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ExportArgs {
+    #[command(subcommand)]
+    pub command: ExportCommands,
+}
+
+#[derive(Subcommand)]
+pub enum ExportCommands {
+    /// Export due dates, planned days, and milestone Stars as an iCalendar
+    /// (.ics) file
+    Ics(IcsArgs),
+    /// Export celestial bodies as Emacs org-mode headlines
+    Org(BridgeArgs),
+    /// Export Planets and Comets as Taskwarrior JSON, for `task import`
+    Taskwarrior(BridgeArgs),
+}
+
+#[derive(Args)]
+pub struct IcsArgs {
+    /// Only export celestial bodies matching this filter (e.g. "tag:release")
+    #[arg(short, long, default_value = "")]
+    pub filter: String,
+    /// Also export finished celestial bodies
+    #[arg(short, long)]
+    pub done: bool,
+    /// File to write to instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct BridgeArgs {
+    /// Only export celestial bodies matching this filter (e.g. "tag:release")
+    #[arg(short, long, default_value = "")]
+    pub filter: String,
+    /// File to write to instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct SplitArgs {
+    /// ID or short key of the Star whose description will be split
+    pub id: String,
+    /// Type of celestial body that headings become
+    #[arg(long, value_enum, default_value_t = CelestialBodyKind::Planet)]
+    pub sections: CelestialBodyKind,
+    /// Type of celestial body that checkbox items become
+    #[arg(long, value_enum, default_value_t = CelestialBodyKind::Planet)]
+    pub items: CelestialBodyKind,
+}
+
+#[derive(Args)]
+pub struct ArchiveArgs {
+    /// IDs or short keys of the celestial bodies
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct PinArgs {
+    /// IDs or short keys of the celestial bodies
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("position").args(["before", "after"]).required(true)))]
+pub struct MoveArgs {
+    /// ID or short key of the celestial body to move
+    pub id: String,
+    /// Place it right before this celestial body
+    #[arg(long)]
+    pub before: Option<String>,
+    /// Place it right after this celestial body
+    #[arg(long)]
+    pub after: Option<String>,
+}
+
+#[derive(Args)]
+pub struct SnoozeArgs {
+    /// ID or short key of the celestial body
+    pub id: String,
+    #[arg(value_name = "until", value_parser = ["until"], hide_possible_values = true)]
+    pub until: String,
+    /// The day it shows up again, e.g. `2025-03-01`, `friday` or `2w`
+    #[arg(value_parser = util::date::parse_from_today)]
+    pub date: NaiveDate,
+}
+
+#[derive(Args)]
+pub struct UnsnoozeArgs {
+    /// IDs or short keys of the celestial bodies
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct FocusArgs {
+    /// Include description in listing
+    #[arg(short, long)]
+    pub description: bool,
+    /// Number of columns to fit lines into. Defaults to the width of the
+    /// terminal, lines are not cut off when the output is not a terminal.
+    #[arg(long)]
+    pub width: Option<usize>,
+}
+
+#[derive(Args)]
+pub struct DeleteArgs {
+    /// IDs or short keys of the celestial bodies to delete
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct TrashArgs {
+    #[command(subcommand)]
+    pub command: TrashCommands,
+}
+
+#[derive(Subcommand)]
+pub enum TrashCommands {
+    /// List the deleted celestial bodies
+    List,
+    /// Restore a deleted celestial body and its descendants
+    Restore(TrashRestoreArgs),
+    /// Permanently remove everything in the trash
+    Empty,
+}
+
+#[derive(Args)]
+pub struct TrashRestoreArgs {
+    /// ID or short key of the deleted celestial body
+    pub id: String,
+}
+
+#[derive(Args)]
+pub struct TagArgs {
+    #[command(subcommand)]
+    pub command: TagCommands,
+}
+
+#[derive(Subcommand)]
+pub enum TagCommands {
+    /// Rename a tag on every celestial body and in the configured filters
+    Rename(RenameArgs),
+}
+
+#[derive(Args)]
+pub struct FieldArgs {
+    #[command(subcommand)]
+    pub command: FieldCommands,
+}
+
+#[derive(Subcommand)]
+pub enum FieldCommands {
+    /// Rename a field key on every celestial body and in the configured filters
+    Rename(RenameArgs),
+}
+
+#[derive(Args)]
+pub struct ViewArgs {
+    #[command(subcommand)]
+    pub command: ViewCommands,
+}
+
+#[derive(Subcommand)]
+pub enum ViewCommands {
+    /// Save a filter and sort order under a name, replacing the view with
+    /// that name if there is one
+    Save(ViewSaveArgs),
+    /// List the saved views
+    List,
+    /// Remove a saved view
+    Remove(ViewRemoveArgs),
+}
+
+#[derive(Args)]
+pub struct ViewSaveArgs {
+    /// Name used to open the view (e.g. "active-backend")
+    pub name: String,
+    /// Only show celestial bodies matching this filter (e.g. "status:start
+    /// tag:backend")
+    #[arg(short, long, default_value = "")]
+    pub filter: String,
+    /// Order the celestial bodies are listed in
+    #[arg(short, long, value_enum, default_value_t)]
+    pub sort: Sort,
+    /// View the TUI shows the celestial bodies in
+    #[arg(short, long, value_enum, default_value_t)]
+    pub layout: tui::View,
+    /// What `list --view` groups the celestial bodies by, and the columns of
+    /// the kanban view (e.g. priority or field:team)
+    #[arg(short, long)]
+    pub group_by: Option<GroupBy>,
+}
+
+#[derive(Args)]
+pub struct ViewRemoveArgs {
+    /// Name of the saved view
+    pub name: String,
+}
+
+#[derive(Args)]
+pub struct LinkArgs {
+    #[command(subcommand)]
+    pub command: LinkCommands,
+}
+
+#[derive(Subcommand)]
+pub enum LinkCommands {
+    /// Link a celestial body to a URL or file (e.g. a pull request or design)
+    Add(LinkAddArgs),
+    /// Remove a link from a celestial body
+    Remove(LinkRemoveArgs),
+    /// List the links of a celestial body
+    List(LinkListArgs),
+    /// Open a link of a celestial body with the system opener
+    Open(LinkOpenArgs),
+}
+
+#[derive(Args)]
+pub struct LinkAddArgs {
+    /// ID or key of the celestial body
+    pub id: String,
+    /// The URL or path of the file to link to
+    pub target: String,
+    /// Name shown instead of the URL or path
+    #[arg(short, long)]
+    pub label: Option<String>,
+}
+
+#[derive(Args)]
+pub struct LinkRemoveArgs {
+    /// ID or key of the celestial body
+    pub id: String,
+    /// Number (as shown by `link list`), URL, path, or label of the link
+    pub link: String,
+}
+
+#[derive(Args)]
+pub struct LinkListArgs {
+    /// ID or key of the celestial body
+    pub id: String,
+}
+
+#[derive(Args)]
+pub struct LinkOpenArgs {
+    /// ID or key of the celestial body
+    pub id: String,
+    /// Number (as shown by `link list`), URL, path, or label of the link
+    #[arg(default_value = "1")]
+    pub link: String,
+}
+
+#[derive(Args)]
+pub struct CheckArgs {
+    /// ID or key of the Planet or Comet
+    pub id: String,
+    /// Lists the checklist if none is given
+    #[command(subcommand)]
+    pub command: Option<CheckCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum CheckCommands {
+    /// Add an item to the end of the checklist
+    Add {
+        /// What has to be done
+        text: String,
+    },
+    /// Check off an item, or uncheck it if it already is
+    Toggle {
+        /// Number (as shown by `check <id>`) or text of the item
+        item: String,
+    },
+    /// Remove an item from the checklist
+    Remove {
+        /// Number (as shown by `check <id>`) or text of the item
+        item: String,
+    },
+}
+
+#[derive(Args)]
+pub struct RenameArgs {
+    /// The current name
+    pub old: String,
+    /// The new name
+    pub new: String,
+}
+
+#[derive(Args)]
+pub struct KeyPrefixArgs {
+    /// The new prefix (e.g. "PLAN-"). Celestial bodies without a short key are
+    /// given one. Shows the current prefix if not given
+    pub prefix: Option<String>,
+}
+
+#[derive(Args)]
+pub struct IdFormatArgs {
+    /// The new format. Shows the current format if not given
+    #[arg(value_enum)]
+    pub format: Option<IdFormat>,
+}
+
+#[derive(Args)]
+pub struct IdSchemeArgs {
+    /// The new scheme. Shows the current scheme if not given
+    #[arg(value_enum)]
+    pub scheme: Option<IdScheme>,
+}
+
+#[derive(Args)]
+pub struct RulesArgs {
+    /// Turn the rules on for this Galaxy
+    #[arg(long, conflicts_with = "disable")]
+    pub enable: bool,
+    /// Turn the rules off for this Galaxy
+    #[arg(long)]
+    pub disable: bool,
+    /// What is done with a Star once all of its children are Done or
+    /// Cancel
+    #[arg(long, value_enum)]
+    pub complete_stars: Option<Completion>,
+    /// Whether a Star in Todo is started once one of its children is
+    #[arg(long)]
+    pub start_parents: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct WorkflowArgs {
+    /// Lists the statuses if none is given
+    #[command(subcommand)]
+    pub command: Option<WorkflowCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum WorkflowCommands {
+    /// Add a status to the workflow
+    Add {
+        /// Name of the status (e.g. "Review")
+        name: String,
+        /// The built in status it counts as, e.g. for progress and filters
+        #[arg(short, long, value_enum)]
+        category: Status,
+        /// Color it is shown in (e.g. "magenta" or "#ff8800"), the color of
+        /// its category if not given
+        #[arg(long)]
+        display_color: Option<Color>,
+        /// Statuses that may follow it, any status may if not given
+        #[arg(long, value_delimiter = ',')]
+        next: Vec<String>,
+    },
+    /// Remove a status from the workflow, moving its celestial bodies back
+    /// to the built in status it counts as
+    Remove {
+        /// Name of the status
+        name: String,
+    },
+    /// Set which statuses may follow a status
+    Next {
+        /// Name of the status
+        name: String,
+        /// Statuses that may follow it, any status may if none are given
+        next: Vec<String>,
+    },
+}
+
+#[derive(Args)]
+pub struct IdPrefixArgs {
+    /// ID or key of the Star
+    pub star: String,
+    /// The new prefix (e.g. "WEB-") used instead of "#" for the Star and its
+    /// descendants. Removes the prefix if not given
+    pub prefix: Option<String>,
+}
+
+#[derive(Args)]
+pub struct SprintArgs {
+    #[command(subcommand)]
+    pub command: Option<SprintCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum SprintCommands {
+    /// Create a new sprint
+    Create(SprintCreateArgs),
+    /// Add celestial bodies to a sprint, moving them out of other open sprints
+    Add(SprintEditArgs),
+    /// Remove celestial bodies from a sprint
+    Remove(SprintEditArgs),
+    /// Close a sprint, carrying unfinished celestial bodies over to the next one
+    Close(SprintNameArgs),
+    /// List all sprints
+    List,
+    /// Show a sprint (the active one by default)
+    Show(SprintShowArgs),
+}
+
+#[derive(Args)]
+pub struct SprintCreateArgs {
+    pub name: String,
+    /// The first day of the sprint (e.g. "today", "mon", or "2025-03-01")
+    #[arg(short, long, default_value = "today", value_parser = util::date::parse_from_today)]
+    pub start: NaiveDate,
+    /// The last day of the sprint (e.g. "in 2 weeks" or "2025-03-14"), instead
+    /// of --days
+    #[arg(short, long, conflicts_with = "days", value_parser = util::date::parse_from_today)]
+    pub end: Option<NaiveDate>,
+    /// The number of days in the sprint
+    #[arg(short, long, default_value_t = 14)]
+    pub days: u64,
+}
+
+#[derive(Args)]
+pub struct SprintEditArgs {
+    pub name: String,
+    /// IDs or short keys of the celestial bodies
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct SprintNameArgs {
+    pub name: String,
+}
+
+#[derive(Args)]
+pub struct SprintShowArgs {
+    pub name: Option<String>,
+}
+
+#[derive(Args)]
+pub struct RemindArgs {
+    /// Also list celestial bodies due within this many days
+    #[arg(short, long, default_value_t = 0)]
+    pub days: u64,
+    /// Show a desktop notification if anything is overdue or due today
+    #[cfg(feature = "notify")]
+    #[arg(long)]
+    pub notify: bool,
+}
+
+#[derive(Args)]
+pub struct TodayArgs {
+    #[command(subcommand)]
+    pub command: Option<TodayCommands>,
+}
+
+#[derive(Subcommand)]
+pub enum TodayCommands {
+    /// Pick celestial bodies to work on today
+    Add(TodayEditArgs),
+    /// Remove celestial bodies from today's plan
+    Remove(TodayEditArgs),
+}
+
+#[derive(Args)]
+pub struct TodayEditArgs {
+    /// IDs or short keys of the celestial bodies
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct StatsArgs {
+    /// Show an estimate of the memory used by the Galaxy instead
+    #[arg(long)]
+    pub memory: bool,
+    /// Number of weeks to show the throughput for
+    #[arg(short, long, default_value_t = 4)]
+    pub weeks: usize,
+    /// Print the statistics as JSON
+    #[arg(long, conflicts_with = "memory")]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct ReportArgs {
+    #[command(subcommand)]
+    pub command: ReportCommands,
+}
+
+#[derive(Subcommand)]
+pub enum ReportCommands {
+    /// Show how much work in a Star was left at the end of each day
+    Burndown(BurndownArgs),
+    /// Summarize what changed recently as Markdown, e.g. for standup notes
+    Activity(ActivityArgs),
+}
+
+#[derive(Args)]
+pub struct ActivityArgs {
+    /// The first day to include: today, yesterday, a number of days or weeks
+    /// ago (e.g. 3d or 2w), a weekday (e.g. "last fri"), or a date (e.g.
+    /// 2025-03-01)
+    #[arg(short, long, default_value = "yesterday", value_parser = util::date::parse_from_today)]
+    pub since: NaiveDate,
+}
+
+#[derive(Args)]
+pub struct BurndownArgs {
+    /// ID or short key of the Star
+    #[arg(long)]
+    pub star: String,
+    /// The first day (e.g. 2025-03-01 or "2 weeks ago")
+    #[arg(long, value_parser = util::date::parse_from_today)]
+    pub from: NaiveDate,
+    /// The last day, defaults to today
+    #[arg(long, value_parser = util::date::parse_from_today)]
+    pub to: Option<NaiveDate>,
+    /// Count estimate points instead of celestial bodies
+    #[arg(short, long)]
+    pub points: bool,
+    /// Write the chart to an SVG file instead of printing it
+    #[cfg(feature = "svg")]
+    #[arg(long)]
+    pub svg: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ConvertArgs {
+    /// File format to convert the database to, the current one if not given
+    #[arg(long, value_enum, required_unless_present = "layout")]
+    pub to: Option<Format>,
+    /// Layout to convert the database to, the current one if not given
+    #[arg(long, value_enum)]
+    pub layout: Option<Layout>,
+}
+
+#[derive(Args)]
+pub struct MergeArgs {
+    /// The version both versions were changed from
+    pub base: PathBuf,
+    /// Our version, which is replaced by the merged database
+    pub ours: PathBuf,
+    /// Their version
+    pub theirs: PathBuf,
+    /// File format of the databases, taken from the file name of `ours` if
+    /// not given
+    #[arg(long, value_enum)]
+    pub format: Option<Format>,
+}
+
+#[derive(Args)]
+pub struct DiffArgs {
+    /// The old version, either a database file or a git revision of the
+    /// database (e.g. HEAD~1)
+    #[arg(default_value = "HEAD")]
+    pub old: String,
+    /// The new version, either a database file or a git revision of the
+    /// database, the database itself if not given
+    pub new: Option<String>,
+}
+
+#[derive(Args)]
+pub struct GitArgs {
+    #[command(subcommand)]
+    pub command: GitCommands,
+}
+
+#[derive(Subcommand)]
+pub enum GitCommands {
+    /// Attach the commits that reference celestial bodies (e.g. "#42" or a
+    /// "Planit: PLAN-42" trailer) to them
+    Scan(GitScanArgs),
+}
+
+#[derive(Args)]
+pub struct GitScanArgs {
+    /// The commits to scan (e.g. "main..HEAD"), the whole history of HEAD if
+    /// not given
+    pub range: Option<String>,
+    /// Mark celestial bodies referenced after a closing keyword (e.g. "Fixes
+    /// PLAN-42") as done
+    #[arg(short, long)]
+    pub close: bool,
+}
+
+#[derive(Args)]
+pub struct HooksArgs {
+    #[command(subcommand)]
+    pub command: HooksCommands,
+}
+
+#[derive(Subcommand)]
+pub enum HooksCommands {
+    /// Install a commit-msg hook that rejects references to unknown celestial
+    /// bodies and adds the one being tracked to the message
+    Install(HooksInstallArgs),
+    /// Remove the hook installed by `hooks install`
+    Uninstall,
+    /// Check and amend a commit message, run by the installed hook
+    #[command(hide = true)]
+    CommitMsg(CommitMsgArgs),
+}
+
+#[derive(Args)]
+pub struct HooksInstallArgs {
+    /// Replace a commit-msg hook that was not installed by planit
+    #[arg(short, long)]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct CommitMsgArgs {
+    /// The file containing the commit message
+    pub file: PathBuf,
+}
+
+#[derive(Args)]
+pub struct CompletionsArgs {
+    /// Shell to print the completion script for
+    #[arg(value_enum)]
+    pub shell: clap_complete::Shell,
+}
+
+#[derive(Args, Default)]
+pub struct TuiArgs {
+    /// View to start in
+    #[arg(long, value_enum, default_value_t = tui::View::Tree)]
+    pub view: tui::View,
+    /// Only show celestial bodies matching this filter (e.g. "tag:backend")
+    #[arg(long)]
+    pub filter: Option<String>,
+    /// Only show the descendants of this Star (ID or short key)
+    #[arg(long)]
+    pub focus: Option<String>,
+    /// Include archived and snoozed celestial bodies
+    #[arg(short, long)]
+    pub all: bool,
+    /// Run this command once the TUI has started, as if it was typed after
+    /// `:` (can be given more than once)
+    #[arg(short, long = "command", value_name = "COMMAND")]
+    pub commands: Vec<String>,
+}
+
+/// A single entry of the legend, see `legend_sections`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegendRow {
+    /// What is shown, e.g. an icon or the name of a status
+    pub symbol: String,
+    /// The color it is shown in
+    pub color: Color,
+    pub description: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Initializes a new Galaxy in the current directory
+pub fn init(args: InitArgs) -> Result<()> {
+    let title = args.title.unwrap_or_else(|| "Demo".to_string());
+    let mut galaxy = Galaxy::default()
+        .title(title)
+        .format(args.format)
+        .layout(args.layout);
+    if let Some(description) = args.description {
+        galaxy = galaxy.description(description);
+    }
+    if let Some(prefix) = args.key_prefix {
+        galaxy.set_key_prefix(prefix)?;
+    }
+    galaxy.set_id_scheme(args.id_scheme);
+
+    if args.demo {
+        /// Enough to fill a few screens without being overwhelming
+        const DEMO_BODIES: usize = 150;
+        core::generate_demo(&mut galaxy, DEMO_BODIES, 0);
+    }
+
+    let dir = env::current_dir()?;
+    galaxy.init(dir, args.nested)?;
+
+    Ok(())
+}
+
+/// Adds generated sample celestial bodies to the Galaxy
+pub fn generate(args: GenerateArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let added = core::generate_demo(&mut galaxy, args.bodies, args.seed).len();
+    // A single file holds every celestial body, so too many of them make a
+    // database the loader refuses
+    match galaxy.save() {
+        Err(core::DatabaseError::DatabaseTooLarge(max)) => Err(AppError::InvalidArgument(format!(
+            "{added} more celestial bodies would make the database larger than the \
+             limit of {} MiB, nothing was saved. Generate fewer of them, or use \
+             `convert --layout directory` first, which limits each file instead",
+            max / (1024 * 1024)
+        ))),
+        saved => saved.map_err(AppError::from),
+    }?;
+
+    println!("Generated {added} celestial bodies");
+    Ok(())
+}
+
+/// Prints the path of the database that would be used from the current
+/// directory
+pub fn which() -> Result<()> {
+    println!("{}", Galaxy::location()?.display());
+    Ok(())
+}
+
+/// Prints the JSON Schema of the database
+pub fn schema(args: SchemaArgs) -> Result<()> {
+    match args.command {
+        SchemaCommands::Print => {
+            let schema =
+                serde_json::to_string_pretty(&core::schema()).map_err(core::DatabaseError::from)?;
+            println!("{schema}");
+        }
+    }
+    Ok(())
+}
+
+/// Checks the database against its JSON Schema, and that it can be loaded
+///
+/// # Errors
+/// Errors will occur in the following situations:
+/// - The database cannot be found or read
+/// - The database does not match the schema or cannot be loaded
+pub fn doctor() -> Result<()> {
+    let path = Galaxy::location()?;
+    let mut problems = 0;
+    for issue in Galaxy::validate_files()? {
+        println!("{}", issue.to_string().error());
+        problems += 1;
+    }
+    // Some problems (e.g. children that do not exist) are only found when the
+    // database is loaded
+    if problems == 0
+        && let Err(e) = Galaxy::load()
+    {
+        println!("{}", e.to_string().error());
+        problems += 1;
+    }
+    match problems {
+        0 => {
+            let message = format!("{} is a valid database", path.display());
+            println!("{}", message.success());
+            Ok(())
+        }
+        problems => Err(AppError::DoctorFailed(problems)),
+    }
+}
+
+/// Lists all celestial bodies in the Galaxy
+pub fn list(args: ListArgs) -> Result<()> {
+    if args.watch {
+        return watch_list(&args);
+    }
+    let output = list_output(&args)?;
+    print_paged(&output, args.no_pager)
+}
+
+/// Helper function that keeps redrawing the list until interrupted. The list
+/// is redrawn when the database is modified, when the terminal is resized, and
+/// every `--interval` seconds.
+fn watch_list(args: &ListArgs) -> Result<()> {
+    let interval = args.interval.map(Duration::from_secs);
+    let modified = Galaxy::last_modified;
+    let size = || crossterm::terminal::size().ok();
+    let mut stdout = io::stdout();
+
+    loop {
+        let (last_modified, last_size) = (modified(), size());
+        // A database that cannot be loaded (e.g. while it is being written)
+        // is shown as an error until it changes again
+        let output = match list_output(args) {
+            Ok(output) => output,
+            Err(e) => format!("{}\n", e.to_string().error()).into_bytes(),
+        };
+        crossterm::execute!(
+            stdout,
+            crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+            crossterm::cursor::MoveTo(0, 0)
+        )?;
+        writeln!(
+            stdout,
+            "{}",
+            format!(
+                "Updated at {}, Ctrl+C to stop",
+                Local::now().format("%H:%M:%S")
+            )
+            .muted()
+        )?;
+        stdout.write_all(&output)?;
+        stdout.flush()?;
+
+        let drawn = Instant::now();
+        while modified() == last_modified
+            && size() == last_size
+            && interval.is_none_or(|interval| drawn.elapsed() < interval)
+        {
+            thread::sleep(WATCH_POLL);
+        }
+    }
+}
+
+/// Helper function that renders the output of `list`
+fn list_output(args: &ListArgs) -> Result<Vec<u8>> {
+    let config = Config::load()?;
+    let mut output = vec![];
+    if !args.all_projects {
+        list_galaxy(args, &config, Galaxy::load()?, &mut output)?;
+        return Ok(output);
+    }
+
+    if config.projects.is_empty() {
+        return Err(AppError::InvalidArgument(
+            "--all-projects needs `projects` to be set in the configuration".into(),
+        ));
+    }
+    for (index, (name, dir)) in config.projects.iter().enumerate() {
+        if index > 0 {
+            writeln!(output)?;
+        }
+        writeln!(output, "{}", format!("[{name}]").highlight().bold())?;
+        list_galaxy(args, &config, Galaxy::load_project(name, dir)?, &mut output)?;
+    }
+    Ok(output)
+}
+
+/// Helper function that writes the celestial bodies of `galaxy` to `output`
+/// as listed by `planit list`
+fn list_galaxy(
+    args: &ListArgs,
+    config: &Config,
+    galaxy: Galaxy,
+    output: &mut Vec<u8>,
+) -> Result<()> {
+    let width = output_width(args.width)?;
+    let galaxy = galaxy
+        .show_archived(args.all)
+        .show_reasons(args.show_reason)
+        .show_stale(args.stale.map(util::date::start_of));
+    let descriptions = match (args.description, args.wrap) {
+        (false, _) => Descriptions::Hidden,
+        (true, false) => Descriptions::Truncated,
+        (true, true) => Descriptions::Wrapped,
+    };
+    let columns = match args.columns.is_empty() {
+        true => config.columns(),
+        false => &args.columns,
+    };
+
+    let filtered = args.view.is_some() || args.filter.is_some() || args.stale.is_some();
+    if args.mine || args.group_by.is_some() || filtered {
+        let mut filter = Filter::default();
+        let mut sort = Sort::Priority;
+        let mut group_by = args.group_by.clone();
+        let mut title = galaxy.get_title().to_string();
+        if let Some(name) = &args.view {
+            let view = config.views.get(name).ok_or_else(|| {
+                AppError::InvalidArgument(format!("There is no view named \"{name}\""))
+            })?;
+            filter = view.filter.parse()?;
+            sort = view.sort;
+            group_by = group_by.or_else(|| view.group_by.clone());
+            title = format!("{title} ({name})");
+        }
+        if let Some(extra) = &args.filter {
+            filter = filter.and(extra.parse()?);
+        }
+        if let Some(since) = args.stale {
+            filter = filter.and(Filter::stale(util::date::start_of(since)));
+            title = format!("{title} idle since {since}");
+        }
+        if args.mine {
+            let user = config.user().ok_or_else(|| {
+                AppError::InvalidArgument(
+                    "--mine needs `user` to be set in the configuration or $USER".into(),
+                )
+            })?;
+            filter = filter.and(Filter::assignee(&user));
+            title = format!("{title} assigned to {user}");
+        }
+        let mut bodies: Vec<_> = galaxy
+            .matching(&filter)
+            .into_iter()
+            .filter(|b| !galaxy.is_hidden(b.id()))
+            .collect();
+        bodies.sort_by(|a, b| sort.compare(a, b));
+        let ids: Vec<_> = bodies.iter().map(|body| body.id()).collect();
+
+        // Every group is printed as a tree of its own, headed by its size
+        let sections = match group_by {
+            Some(group_by) => group_by
+                .group(&galaxy, &ids)
+                .into_iter()
+                .map(|group| {
+                    let title = format!("{} ({})", group.label, group.ids.len());
+                    (title, String::new(), group.ids)
+                })
+                .collect(),
+            None => vec![(title, galaxy.get_description().to_string(), ids)],
+        };
+        for (index, (title, description, ids)) in sections.into_iter().enumerate() {
+            if index > 0 {
+                writeln!(output)?;
+            }
+            let children = ids
+                .iter()
+                .filter_map(|id| galaxy.get(*id))
+                .map(|body| Box::new(body.as_tree_node()))
+                .collect();
+            util::tree::print_to_writer(
+                &galaxy,
+                output,
+                width,
+                descriptions,
+                args.recursive,
+                columns,
+                title.accent(),
+                description.muted(),
+                children,
+            )?;
+        }
+    } else {
+        galaxy.pretty_print_to_writer(output, width, descriptions, args.recursive, columns)?;
+    }
+
+    Ok(())
+}
+
+/// Helper function that finds the number of columns that printed trees have to
+/// fit into. Without an explicit `width`, this is the width of the terminal,
+/// or unlimited if stdout is not a terminal (e.g. piped into another command).
+fn output_width(width: Option<usize>) -> Result<usize> {
+    match width {
+        Some(width) => Ok(width),
+        None if io::stdout().is_terminal() => Ok(crossterm::terminal::size()?.0 as usize),
+        None => Ok(usize::MAX),
+    }
+}
+
+/// Helper function that prints `output`, through the pager if stdout is a
+/// terminal that `output` does not fit into. Without a pager, everything is
+/// printed at once.
+fn print_paged(output: &[u8], no_pager: bool) -> Result<()> {
+    let mut stdout = io::stdout();
+    let height = || crossterm::terminal::size().ok().map(|(_, height)| height);
+    if no_pager || !stdout.is_terminal() || fits(output, height()) {
+        stdout.write_all(output)?;
+        return Ok(());
+    }
+
+    let Ok(mut pager) = shell::pager().stdin(Stdio::piped()).spawn() else {
+        stdout.write_all(output)?;
+        return Ok(());
+    };
+    // Quitting the pager early closes the pipe, which is not an error
+    if let Some(mut stdin) = pager.stdin.take() {
+        let _ = stdin.write_all(output);
+    }
+    pager.wait()?;
+    Ok(())
+}
+
+/// Helper function that checks if `output` fits into a terminal `height` lines
+/// high, leaving a line for the prompt. It always fits if the height is not
+/// known.
+fn fits(output: &[u8], height: Option<u16>) -> bool {
+    height.is_none_or(|height| output.lines().count() < height as usize)
+}
+
+/// Creates a new celestial body
+pub fn new(args: NewArgs) -> Result<()> {
+    if args.kind == CelestialBodyKind::Star && args.estimate.is_some() {
+        return Err(AppError::InvalidArgument(
+            "Stars cannot have an estimate, it is rolled up from their children".into(),
+        ));
+    }
+    if args.kind != CelestialBodyKind::Planet && args.due.is_some() {
+        return Err(AppError::InvalidArgument(
+            "Only Planets can have a due date".into(),
+        ));
+    }
+
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+    let id = create(&mut galaxy, args);
+    snapshot.print(&galaxy)?;
+
+    // Offer to turn pasted notes into structured work
+    offer_split(&mut galaxy, id)?;
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Helper function that offers to split the description of the Star `id` if
+/// it contains headings or checkbox items (see `split`). Nothing is asked
+/// unless both stdin and stderr are terminals, and a hint to run `planit split`
+/// is printed if the description is not split.
+fn offer_split(galaxy: &mut Galaxy, id: ID) -> Result<()> {
+    let outline = match galaxy.get(id) {
+        Some(body) if body.kind() == CelestialBodyKind::Star => {
+            split::Outline::parse(body.description())
+        }
+        _ => return Ok(()),
+    };
+    if outline.is_empty() {
+        return Ok(());
+    }
+
+    let interactive =
+        !super::dry_running() && io::stdin().is_terminal() && io::stderr().is_terminal();
+    if !interactive || !ask_split(galaxy, id)? {
+        println!(
+            "The description contains {} headings and {} checkbox items, run `planit split {}` to turn them into celestial bodies",
+            outline.sections.len(),
+            outline.items.len() + outline.sections.iter().map(|s| s.items.len()).sum::<usize>(),
+            galaxy.display_id(id)
+        );
+    }
+    Ok(())
+}
+
+/// Helper function that asks what headings and checkbox items become, shows
+/// what splitting the description of the Star `id` that way would change, and
+/// splits it if the user agrees
+///
+/// # Returns
+/// Whether the description was split
+fn ask_split(galaxy: &mut Galaxy, id: ID) -> Result<bool> {
+    let mut stdin = io::stdin().lock();
+    let mapping = choose_mapping(&mut stdin, &mut io::stderr())?;
+
+    let mut preview = galaxy.clone();
+    let snapshot = diff::Snapshot::take(galaxy);
+    let summary = split::split(&mut preview, id, mapping)?;
+    // Shown even with `--quiet`, the question cannot be answered without it
+    println!("Splitting the description would make these changes:");
+    snapshot.write(&preview, &mut io::stdout())?;
+
+    eprint!(
+        "Split it into {} sections and {} items? [y/N] ",
+        summary.sections, summary.items
+    );
+    let mut answer = String::new();
+    stdin.read_line(&mut answer)?;
+    if !matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") {
+        return Ok(false);
+    }
+
+    *galaxy = preview;
+    println!(
+        "Split {} into {} sections and {} items",
+        galaxy.display_id(id).muted(),
+        summary.sections,
+        summary.items
+    );
+    Ok(true)
+}
+
+/// Helper function that asks which kind of celestial body headings and
+/// checkbox items become, reading the answers from `input` and writing the
+/// questions to `prompt`. Empty answers keep the default of `split`, Planets.
+fn choose_mapping<R: BufRead, W: Write>(
+    input: &mut R,
+    prompt: &mut W,
+) -> io::Result<split::Mapping> {
+    let mut ask = |what: &str| -> io::Result<CelestialBodyKind> {
+        loop {
+            write!(prompt, "Turn {what} into [planet/star/comet]: ")?;
+            prompt.flush()?;
+            let mut answer = String::new();
+            if input.read_line(&mut answer)? == 0 {
+                return Ok(CelestialBodyKind::Planet);
+            }
+            match answer.trim() {
+                "" => return Ok(CelestialBodyKind::Planet),
+                answer => match CelestialBodyKind::from_str(answer, true) {
+                    Ok(kind) => return Ok(kind),
+                    Err(_) => writeln!(prompt, "Unknown kind \"{answer}\"")?,
+                },
+            }
+        }
+    };
+    Ok(split::Mapping {
+        sections: ask("headings")?,
+        items: ask("checkbox items")?,
+    })
+}
+
+/// Creates the celestial body described by `args` in `galaxy`, at its root,
+/// and returns its ID. Used by `planit new` and the quick-add popup of the TUI.
+pub fn create(galaxy: &mut Galaxy, args: NewArgs) -> u64 {
+    let id = match args.kind {
+        CelestialBodyKind::Comet => {
+            let comet = galaxy.comet().title(args.title).priority(args.priority);
+            if let Some(description) = args.description {
+                comet.description(description);
+            }
+            comet.id()
+        }
+        CelestialBodyKind::Planet => {
+            let planet = galaxy.planet().title(args.title).priority(args.priority);
+            if let Some(description) = args.description {
+                planet.description(description);
+            }
+            planet.id()
+        }
+        CelestialBodyKind::Star => {
+            let star = galaxy.star().title(args.title).priority(args.priority);
+            if let Some(description) = args.description {
+                star.description(description);
+            }
+            star.id()
+        }
+    };
+    if let Some(mut body) = galaxy.get_mut(id) {
+        body.estimate(args.estimate);
+        if let Some(due) = args.due {
+            body.field("due".into(), due.format("%Y-%m-%d").to_string());
+        }
+        for assignee in args.assignees {
+            body.assign(assignee);
+        }
+    }
+    id
+}
+
+/// Shows everything about a single celestial body
+pub fn show(args: ShowArgs) -> Result<()> {
+    let galaxy = Galaxy::load()?;
+    let id = galaxy.resolve(&args.id)?;
+    let body = galaxy.get(id).ok_or(AppError::CelestialBodyNotFound(id))?;
+
+    show_to_writer(&galaxy, body, &mut io::stdout())?;
+
+    Ok(())
+}
+
+/// Helper function that writes the details of `body` to `w`
+fn show_to_writer<W: io::Write>(
+    galaxy: &Galaxy,
+    body: CelestialBodyRef,
+    w: &mut W,
+) -> io::Result<()> {
+    let now = Utc::now();
+    let node = body.as_tree_node();
+
+    writeln!(
+        w,
+        "{} {} {} {} {}",
+        node.icon(),
+        node.label(),
+        node.status(galaxy),
+        node.title(),
+        match body.key() {
+            Some(key) => format!("({} {key})", galaxy.display_id(body.id())),
+            None => format!("({})", galaxy.display_id(body.id())),
+        }
+        .muted()
+    )?;
+    if !body.description().is_empty() {
+        for line in body.description().lines() {
+            writeln!(w, "    {}", line.muted())?;
+        }
+    }
+
+    // Walk up the parents to produce a chain from the root to this body
+    let mut chain = Vec::new();
+    let mut parent = body.parent();
+    while let Some(id) = parent {
+        match galaxy.get(id) {
+            Some(p) => {
+                chain.push(format!("{} ({})", p.title(), galaxy.display_id(p.id())));
+                parent = p.parent();
+            }
+            None => {
+                chain.push(format!("({})", galaxy.display_id(id)));
+                parent = None;
+            }
+        }
+    }
+    chain.reverse();
+    writeln!(w)?;
+    if chain.is_empty() {
+        writeln!(w, "{} {}", "Parent:".bold(), "None".muted())?;
+    } else {
+        writeln!(w, "{} {}", "Parent:".bold(), chain.join(" > "))?;
+    }
+    if galaxy.is_archived(body.id()) {
+        writeln!(w, "{} {}", "Archived:".bold(), "Yes".muted())?;
+    }
+    if body.pinned() {
+        writeln!(w, "{} {}", "Pinned:".bold(), "Yes".highlight())?;
+    }
+    if let Some(until) = body.snoozed_until() {
+        writeln!(w, "{} {until}", "Snoozed until:".bold())?;
+    }
+    match body.priority() {
+        Priority::None => writeln!(w, "{} {}", "Priority:".bold(), "None".muted())?,
+        priority => writeln!(
+            w,
+            "{} {}",
+            "Priority:".bold(),
+            ColoredString::from(priority)
+        )?,
+    }
+    match body.kind() {
+        CelestialBodyKind::Star => {
+            let progress = galaxy.progress(body.id()).unwrap_or_default();
+            match progress.percent() {
+                Some(percent) => writeln!(
+                    w,
+                    "{} {} {percent}% ({}/{})",
+                    "Progress:".bold(),
+                    progress.bar(20).success(),
+                    progress.finished,
+                    progress.total
+                )?,
+                None => writeln!(w, "{} {}", "Progress:".bold(), "None".muted())?,
+            }
+
+            let rollup = galaxy.rollup(body.id()).unwrap_or_default();
+            if rollup.is_empty() {
+                writeln!(w, "{} {}", "Estimate:".bold(), "None".muted())?;
+            } else {
+                writeln!(
+                    w,
+                    "{} {}",
+                    "Estimate:".bold(),
+                    rollup.to_string().highlight()
+                )?;
+            }
+        }
+        _ => match body.estimate() {
+            Some(estimate) => writeln!(w, "{} {}", "Estimate:".bold(), estimate)?,
+            None => writeln!(w, "{} {}", "Estimate:".bold(), "None".muted())?,
+        },
+    }
+    let tracked = body
+        .work_log()
+        .total_between(DateTime::<Utc>::MIN_UTC, now, now);
+    match body.work_log().active() {
+        Some(session) => writeln!(
+            w,
+            "{} {} {}",
+            "Time tracked:".bold(),
+            util::time::hours_minutes(tracked.num_minutes() as u64),
+            format!(
+                "(tracking since {})",
+                util::time::timestamp(session.start, now)
+            )
+            .success()
+        )?,
+        None if body.work_log().is_empty() => {
+            writeln!(w, "{} {}", "Time tracked:".bold(), "None".muted())?
+        }
+        None => writeln!(
+            w,
+            "{} {}",
+            "Time tracked:".bold(),
+            util::time::hours_minutes(tracked.num_minutes() as u64)
+        )?,
+    }
+    if let Some(updated) = body.updated_at() {
+        writeln!(
+            w,
+            "{} {}",
+            "Updated:".bold(),
+            util::time::timestamp(updated, now)
+        )?;
+    }
+    if let Some(due) = body.due() {
+        let today = now.with_timezone(&Local).date_naive();
+        let relative = format!("({})", util::time::relative_due(due, today));
+        match body.overdue(today) {
+            true => writeln!(w, "{} {due} {}", "Due:".bold(), relative.error())?,
+            false => writeln!(w, "{} {due} {}", "Due:".bold(), relative.muted())?,
+        }
+    }
+    match body.assignees() {
+        [] => writeln!(w, "{} {}", "Assignees:".bold(), "None".muted())?,
+        assignees => writeln!(
+            w,
+            "{} {}",
+            "Assignees:".bold(),
+            assignees.join(", ").highlight()
+        )?,
+    }
+    if !body.links().is_empty() {
+        writeln!(w, "{}", "Links:".bold())?;
+        for (i, link) in body.links().iter().enumerate() {
+            writeln!(w, "    {} {link}", format!("{}.", i + 1).muted())?;
+        }
+    }
+    if let Some(blocker) = body.blocker() {
+        writeln!(
+            w,
+            "{} {}",
+            "Reason:".bold(),
+            blocker.describe(galaxy).warning()
+        )?;
+    }
+    if let Some((done, total)) = body.checklist_progress() {
+        writeln!(
+            w,
+            "{} {}",
+            "Checklist:".bold(),
+            format!("{done}/{total}").highlight()
+        )?;
+        for item in body.checklist() {
+            writeln!(w, "    {item}")?;
+        }
+    }
+    if !body.commits().is_empty() {
+        writeln!(w, "{}", "Commits:".bold())?;
+        for commit in body.commits() {
+            writeln!(
+                w,
+                "    {} {} {}",
+                commit.short_hash().highlight(),
+                commit.summary,
+                format!("({})", util::time::timestamp(commit.time, now)).muted()
+            )?;
+        }
+    }
+
+    if body.kind() == CelestialBodyKind::Planet {
+        let tags = body.tags();
+        if tags.is_empty() {
+            writeln!(w, "{} {}", "Tags:".bold(), "None".muted())?;
+        } else {
+            let tags: Vec<_> = tags
+                .iter()
+                .map(|tag| theme::tag_chip(tag).to_string())
+                .collect();
+            writeln!(w, "{} {}", "Tags:".bold(), tags.join(" "))?;
+        }
+
+        let mut fields: Vec<_> = body.fields().into_iter().flatten().collect();
+        fields.sort();
+        if fields.is_empty() {
+            writeln!(w, "{} {}", "Fields:".bold(), "None".muted())?;
+        } else {
+            writeln!(w, "{}", "Fields:".bold())?;
+            for (key, value) in fields {
+                writeln!(w, "    {}: {}", key.highlight(), value)?;
+            }
+        }
+    }
+
+    writeln!(w)?;
+    if body.history().is_empty() {
+        writeln!(w, "{} {}", "History:".bold(), "None".muted())?;
+    } else {
+        writeln!(w, "{}", "History:".bold())?;
+        for change in body.history() {
+            writeln!(
+                w,
+                "    {} {} {} {} {}",
+                util::time::absolute(change.time()).muted(),
+                format!("({})", util::time::relative(change.time(), now)).muted(),
+                colored::ColoredString::from(change.old_status()),
+                "->".muted(),
+                colored::ColoredString::from(change.new_status()),
+            )?;
+            if !change.comment().is_empty() {
+                writeln!(w, "        {}", change.comment())?;
+            }
+        }
+    }
+
+    writeln!(w)?;
+    if body.events().is_empty() {
+        writeln!(w, "{} {}", "Activity:".bold(), "None".muted())?;
+    } else {
+        writeln!(w, "{}", "Activity:".bold())?;
+        for event in body.events() {
+            writeln!(
+                w,
+                "    {} {} {}",
+                util::time::absolute(event.time).muted(),
+                format!("({})", util::time::relative(event.time, now)).muted(),
+                event.change,
+            )?;
+        }
+    }
+
+    if body.kind() == CelestialBodyKind::Star {
+        writeln!(w)?;
+        if body.children().is_empty() {
+            writeln!(w, "{} {}", "Children:".bold(), "None".muted())?;
+        } else {
+            writeln!(w, "{}", "Children:".bold())?;
+            for child in body.children().iter().filter_map(|id| galaxy.get(*id)) {
+                let node = child.as_tree_node();
+                writeln!(
+                    w,
+                    "    {} {} {} {} {}",
+                    node.icon(),
+                    node.label(),
+                    node.status(galaxy),
+                    node.title(),
+                    format!("({})", galaxy.display_id(child.id())).muted()
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies an operation to many celestial bodies at once. All changes are
+/// saved together, so either every selected celestial body is updated or none
+/// of them are.
+pub fn bulk(args: BulkArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    let select = match &args.command {
+        BulkCommands::Status(op) => &op.select,
+        BulkCommands::Priority(op) => &op.select,
+        BulkCommands::Estimate(op) => &op.select,
+        BulkCommands::Edit(op) => &op.select,
+        BulkCommands::Tag(op) | BulkCommands::Untag(op) => &op.select,
+        BulkCommands::Assign(op) | BulkCommands::Unassign(op) => &op.select,
+    };
+
+    let blocker = match &args.command {
+        BulkCommands::Status(op) => blocker(&galaxy, op)?,
+        _ => None,
+    };
+
+    for id in select_ids(&galaxy, select)? {
+        if let BulkCommands::Status(op) = &args.command
+            && galaxy.get(id).is_some()
+        {
+            galaxy.set_status(id, &op.status, op.comment.clone())?;
+        }
+        let Some(mut body) = galaxy.get_mut(id) else {
+            continue;
+        };
+        match &args.command {
+            BulkCommands::Status(_) => {
+                if blocker.is_some() {
+                    body.set_blocker(blocker.clone());
+                }
+            }
+            BulkCommands::Priority(op) => {
+                if body.as_body_ref().priority() != op.priority {
+                    body.priority(op.priority);
+                }
+            }
+            BulkCommands::Estimate(op) => {
+                body.estimate(op.estimate);
+            }
+            BulkCommands::Edit(op) => {
+                if let Some(title) = &op.title {
+                    body.title(title.clone());
+                }
+                if let Some(description) = &op.description {
+                    body.description(description.clone());
+                }
+            }
+            BulkCommands::Tag(op) => {
+                body.tag(op.tag.clone());
+            }
+            BulkCommands::Untag(op) => {
+                body.untag(&op.tag);
+            }
+            BulkCommands::Assign(op) => {
+                body.assign(op.name.clone());
+            }
+            BulkCommands::Unassign(op) => {
+                body.unassign(&op.name);
+            }
+        }
+    }
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Helper function that creates the reason for being blocked given to `bulk
+/// status`, `None` if there is none
+fn blocker(galaxy: &Galaxy, op: &BulkStatusArgs) -> Result<Option<Blocker>> {
+    if op.reason.is_none() && op.blocked_by.is_none() && op.waiting_on.is_none() {
+        return Ok(None);
+    }
+    let stage = galaxy
+        .workflow()
+        .find(&op.status)
+        .ok_or_else(|| GalaxyError::StatusNotFound(op.status.clone()))?;
+    if !matches!(stage.category, Status::Block | Status::Hold) {
+        return Err(AppError::InvalidArgument(
+            "--reason, --blocked-by, and --waiting-on need the status block or hold".into(),
+        ));
+    }
+    let by = match &op.blocked_by {
+        Some(reference) => Some(galaxy.resolve(reference)?),
+        None => None,
+    };
+    Ok(Some(Blocker {
+        reason: op.reason.clone().unwrap_or_default(),
+        by,
+        reference: op.waiting_on.clone(),
+    }))
+}
+
+/// Helper function that finds the IDs of all celestial bodies selected by
+/// `select`. If both IDs and a filter are given, only the listed IDs that also
+/// match the filter are selected.
+fn select_ids(galaxy: &Galaxy, select: &SelectArgs) -> Result<Vec<u64>> {
+    let filter: Filter = match &select.filter {
+        Some(filter) => filter.parse()?,
+        None => Filter::default(),
+    };
+
+    if select.ids.is_empty() {
+        return Ok(galaxy
+            .matching(&filter)
+            .iter()
+            .map(|body| body.id())
+            .collect());
+    }
+
+    let mut ids = Vec::new();
+    for reference in &select.ids {
+        let id = galaxy.resolve(reference)?;
+        let body = galaxy.get(id).ok_or(AppError::CelestialBodyNotFound(id))?;
+        if filter.matches(&body) && !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    Ok(ids)
+}
+
+/// Edits the selected celestial bodies as lines of text in the user's editor,
+/// applying every change at once after the editor is closed. With `--editor`,
+/// edits the title and description of a single celestial body as Markdown
+/// instead.
+pub fn edit(args: EditArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    if let Some(reference) = args.id.filter(|_| args.editor) {
+        let id = galaxy.resolve(&reference)?;
+        let body = galaxy.get(id).ok_or(AppError::CelestialBodyNotFound(id))?;
+        let markdown = edit::to_markdown(body.title(), body.description());
+        let edited = edit::in_editor(&markdown, "md")?;
+        let (title, description) = edit::parse_markdown(&edited)?;
+        let mut body = galaxy
+            .get_mut(id)
+            .ok_or(AppError::CelestialBodyNotFound(id))?;
+        body.title(title).description(description);
+        offer_split(&mut galaxy, id)?;
+    } else {
+        let select = SelectArgs {
+            ids: args.ids,
+            filter: args.filter,
+        };
+        let selected = select_ids(&galaxy, &select)?;
+        if selected.is_empty() {
+            println!("Nothing to edit");
+            return Ok(());
+        }
+        let edited = edit::in_editor(&edit::to_buffer(&galaxy, &selected), "txt")?;
+        let entries = edit::parse(&galaxy, &edited)?;
+        edit::apply(&mut galaxy, entries);
+    }
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Runs a configured action on a celestial body
+pub fn run(args: RunArgs) -> Result<()> {
+    let config = Config::load()?;
+    let galaxy = Galaxy::load()?;
+    let id = galaxy.resolve(&args.id)?;
+    let body = galaxy.get(id).ok_or(AppError::CelestialBodyNotFound(id))?;
+
+    let Some(name) = args.action else {
+        for action in config.actions_for(&body) {
+            println!("{} {}", action.name.bold(), action.command.muted());
+        }
+        return Ok(());
+    };
+
+    let action = config
+        .actions_for(&body)
+        .find(|action| action.name == name)
+        .ok_or(AppError::ActionNotFound(name))?;
+    let command = util::shell::expand(&action.command, |key| body.value(key))?;
+
+    let status = util::shell::shell(&command).status()?;
+    if !status.success() {
+        return Err(AppError::ActionFailed(action.name.clone(), status.code()));
+    }
+
+    Ok(())
+}
+
+/// Queries the Galaxy through the structured API, printing the JSON response
+pub fn api(args: ApiArgs) -> Result<()> {
+    let (Some(method), Some(path)) = (args.method, args.path) else {
+        return api_rpc();
+    };
+    let galaxy = Galaxy::load()?;
+    let request = api::Request::new(method, &path);
+    let response = api::handle(&galaxy, &request);
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&response.body).map_err(crate::core::DatabaseError::from)?
+    );
+
+    if !response.is_success() {
+        return Err(AppError::ApiError(response.status));
+    }
+
+    Ok(())
+}
+
+/// Answers JSON-RPC requests from stdin until it is closed. The Galaxy is
+/// loaded for every request, so that changes made in the meantime (e.g. by the
+/// TUI) are not overwritten.
+fn api_rpc() -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let reply = match Galaxy::load() {
+            Ok(mut galaxy) => {
+                let reply = rpc::handle(&mut galaxy, &line);
+                match reply.changed {
+                    true => match galaxy.save() {
+                        Ok(()) => reply,
+                        Err(e) => rpc::Reply {
+                            response: reply.response.map(|response| {
+                                rpc::response(response["id"].clone(), Err(rpc::RpcError::server(e)))
+                            }),
+                            changed: false,
+                        },
+                    },
+                    false => reply,
+                }
+            }
+            Err(e) => rpc::Reply {
+                // Unreadable requests are still answered, without an id
+                response: match serde_json::from_str::<serde_json::Value>(&line) {
+                    Ok(request) => request.get("id").cloned(),
+                    Err(_) => Some(serde_json::Value::Null),
+                }
+                .map(|id| rpc::response(id, Err(rpc::RpcError::server(e)))),
+                changed: false,
+            },
+        };
+
+        if let Some(response) = reply.response {
+            writeln!(stdout, "{response}")?;
+            stdout.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Imports open issues and milestones from an external tracker, or the tasks
+/// of another task manager, into a new subtree of the Galaxy
+pub fn import(args: ImportArgs) -> Result<()> {
+    if args.mapping.is_some() && args.from != import::Source::Csv {
+        return Err(AppError::InvalidArgument(
+            "--mapping can only be used with --from csv".into(),
+        ));
+    }
+    let importer: Box<dyn Importer> = match args.mapping {
+        Some(path) => Box::new(Csv {
+            mapping: Mapping::load(&path)?,
+        }),
+        None => args.from.importer(),
+    };
+
+    let (source, origin) = match (args.from.is_file(), args.repo, args.file) {
+        (false, Some(repo), None) => (repo.clone(), repo),
+        (true, None, Some(path)) if path == Path::new("-") => {
+            (io::read_to_string(io::stdin())?, "stdin".into())
+        }
+        (true, None, Some(path)) => (fs::read_to_string(&path)?, path.display().to_string()),
+        (false, ..) => {
+            return Err(AppError::InvalidArgument(
+                "GitHub imports need --repo and no --file".into(),
+            ))
+        }
+        (true, ..) => {
+            return Err(AppError::InvalidArgument(
+                "file imports need --file and no --repo".into(),
+            ))
+        }
+    };
+
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    let summary = importer.import(&mut galaxy, &source, util::progress::stderr().as_mut())?;
+
+    snapshot.print(&galaxy)?;
+    if super::dry_running() {
+        print_subtree(&galaxy, summary.root)?;
+    }
+    let root = galaxy.display_id(summary.root);
+    galaxy.save()?;
+
+    println!(
+        "Imported {} stars and {} planets from {} under {}",
+        summary.stars,
+        summary.planets,
+        origin,
+        root.muted()
+    );
+
+    Ok(())
+}
+
+/// Helper function that prints the celestial body `id` and its descendants as
+/// a tree, e.g. to preview an import
+fn print_subtree(galaxy: &Galaxy, id: u64) -> Result<()> {
+    let Some(body) = galaxy.get(id) else {
+        return Ok(());
+    };
+    let children = body
+        .children()
+        .iter()
+        .filter_map(|id| galaxy.get(*id))
+        .map(|body| Box::new(body.as_tree_node()))
+        .collect();
+    util::tree::print_to_writer(
+        galaxy,
+        &mut io::stdout(),
+        output_width(None)?,
+        Descriptions::Hidden,
+        true,
+        &Column::DEFAULT,
+        body.title().to_string().accent(),
+        String::new().muted(),
+        children,
+    )?;
+    Ok(())
+}
+
+/// Exports the celestial bodies for other applications
+pub fn export(args: ExportArgs) -> Result<()> {
+    let galaxy = Galaxy::load()?;
+    let (exporter, filter, output): (Box<dyn Exporter>, _, _) = match args.command {
+        ExportCommands::Ics(args) => (
+            Box::new(export::Ics {
+                finished: args.done,
+            }),
+            args.filter,
+            args.output,
+        ),
+        ExportCommands::Org(args) => (Box::new(Org), args.filter, args.output),
+        ExportCommands::Taskwarrior(args) => (Box::new(Taskwarrior), args.filter, args.output),
+    };
+
+    let filter: Filter = filter.parse()?;
+    let text = exporter.export(&galaxy, &filter);
+    match output {
+        Some(path) => fs::write(path, text)?,
+        None => io::stdout().write_all(text.as_bytes())?,
+    }
+    Ok(())
+}
+
+/// Splits the Markdown description of a Star into child celestial bodies
+pub fn split(args: SplitArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+    let id = galaxy.resolve(&args.id)?;
+
+    let mapping = split::Mapping {
+        sections: args.sections,
+        items: args.items,
+    };
+    let summary = split::split(&mut galaxy, id, mapping)?;
+    if summary.sections == 0 && summary.items == 0 {
+        return Err(AppError::InvalidArgument(format!(
+            "The description of Star {} has no headings or checkbox items to split",
+            galaxy.display_id(id)
+        )));
+    }
+
+    snapshot.print(&galaxy)?;
+    let star = galaxy.display_id(id);
+    galaxy.save()?;
+
+    println!(
+        "Split {} into {} sections and {} items",
+        star.muted(),
+        summary.sections,
+        summary.items
+    );
+
+    Ok(())
+}
+
+/// Archives (or unarchives if `archived` is `false`) celestial bodies. The
+/// celestial bodies are kept in the database, but hidden from views unless
+/// asked for.
+pub fn archive(args: ArchiveArgs, archived: bool) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    let mut changed = Vec::new();
+    for reference in args.ids {
+        let id = galaxy.resolve(&reference)?;
+        let mut body = galaxy
+            .get_mut(id)
+            .ok_or(AppError::CelestialBodyNotFound(id))?;
+        if body.archive(archived) {
+            changed.push(galaxy.display_id(id));
+        }
+    }
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    let verb = if archived { "Archived" } else { "Unarchived" };
+    match changed.is_empty() {
+        true => println!("Nothing was {}", verb.to_lowercase()),
+        false => println!("{verb} {}", changed.join(", ").muted()),
+    }
+
+    Ok(())
+}
+
+/// Pins (or unpins if `pinned` is `false`) celestial bodies to the focus view,
+/// see `Galaxy::focus`
+pub fn pin(args: PinArgs, pinned: bool) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    let mut changed = Vec::new();
+    for reference in args.ids {
+        let id = galaxy.resolve(&reference)?;
+        let mut body = galaxy
+            .get_mut(id)
+            .ok_or(AppError::CelestialBodyNotFound(id))?;
+        if body.pin(pinned) {
+            changed.push(galaxy.display_id(id));
+        }
+    }
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    let verb = if pinned { "Pinned" } else { "Unpinned" };
+    match changed.is_empty() {
+        true => println!("Nothing was {}", verb.to_lowercase()),
+        false => println!("{verb} {}", changed.join(", ").muted()),
+    }
+
+    Ok(())
+}
+
+/// Moves a celestial body before or after another one, see `Galaxy::place`
+pub fn reorder(args: MoveArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    let (reference, after) = match (args.before, args.after) {
+        (Some(before), _) => (before, false),
+        (None, Some(after)) => (after, true),
+        (None, None) => {
+            return Err(AppError::InvalidArgument(
+                "Either --before or --after is needed".into(),
+            ));
+        }
+    };
+    let id = galaxy.resolve(&args.id)?;
+    let target = galaxy.resolve(&reference)?;
+    galaxy.place(id, target, after)?;
+    let (id, target) = (galaxy.display_id(id), galaxy.display_id(target));
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    let position = if after { "after" } else { "before" };
+    println!("Moved {} {position} {}", id.muted(), target.muted());
+    Ok(())
+}
+
+/// Snoozes a celestial body, hiding it from views until the date of `args`
+pub fn snooze(args: SnoozeArgs) -> Result<()> {
+    if args.date <= Local::now().date_naive() {
+        return Err(AppError::InvalidArgument(format!(
+            "Cannot snooze until {}, the date has to be after today",
+            args.date
+        )));
+    }
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    let id = galaxy.resolve(&args.id)?;
+    let mut body = galaxy
+        .get_mut(id)
+        .ok_or(AppError::CelestialBodyNotFound(id))?;
+    body.snooze(Some(args.date));
+    let label = galaxy.display_id(id);
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    println!("Snoozed {} until {}", label.muted(), args.date);
+    Ok(())
+}
+
+/// Unsnoozes celestial bodies, showing them in views again
+pub fn unsnooze(args: UnsnoozeArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    let mut changed = Vec::new();
+    for reference in args.ids {
+        let id = galaxy.resolve(&reference)?;
+        let mut body = galaxy
+            .get_mut(id)
+            .ok_or(AppError::CelestialBodyNotFound(id))?;
+        if body.snooze(None) {
+            changed.push(galaxy.display_id(id));
+        }
+    }
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    match changed.is_empty() {
+        true => println!("Nothing was unsnoozed"),
+        false => println!("Unsnoozed {}", changed.join(", ").muted()),
+    }
+
+    Ok(())
+}
+
+/// Lists the working set for today (see `Galaxy::focus`) as a tree, in the
+/// columns of the configuration
+pub fn focus(args: FocusArgs) -> Result<()> {
+    let config = Config::load()?;
+    let galaxy = Galaxy::load()?;
+    let today = Local::now().date_naive();
+    let descriptions = match args.description {
+        true => Descriptions::Truncated,
+        false => Descriptions::Hidden,
+    };
+
+    let ids = galaxy.focus(today);
+    let children = ids
+        .iter()
+        .filter_map(|id| galaxy.get(*id))
+        .map(|body| Box::new(body.as_tree_node()))
+        .collect();
+    let description = match ids.is_empty() {
+        true => "Nothing to focus on, pin something with `planit pin <id>`",
+        false => "",
+    };
+    util::tree::print_to_writer(
+        &galaxy,
+        &mut io::stdout(),
+        output_width(args.width)?,
+        descriptions,
+        false,
+        config.columns(),
+        format!("Focus {today}").accent(),
+        description.muted(),
+        children,
+    )?;
+
+    Ok(())
+}
+
+/// Moves celestial bodies (and their descendants) to the trash, where they can
+/// be restored from until the trash is emptied
+pub fn delete(args: DeleteArgs) -> Result<()> {
+    let config = Config::load()?;
+    let mut galaxy = Galaxy::load()?;
+    let now = Utc::now();
+    purge_trash(&mut galaxy, &config, now);
+
+    let ids = args
+        .ids
+        .iter()
+        .map(|reference| galaxy.resolve(reference))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let mut deleted = Vec::new();
+    let mut count = 0;
+    for id in ids {
+        // Already deleted along with one of its ancestors
+        if galaxy.get(id).is_none() {
+            continue;
+        }
+        deleted.push(galaxy.display_id(id));
+        count += galaxy.delete(id, now)?;
+    }
+
+    galaxy.save()?;
+
+    println!(
+        "Moved {} to the trash {}",
+        deleted.join(", "),
+        format!("({count} celestial bodies)").muted()
+    );
+    println!(
+        "{}",
+        "Use `planit trash restore <id>` to restore them".muted()
+    );
+
+    Ok(())
+}
+
+/// Lists, restores, or permanently removes deleted celestial bodies
+pub fn trash(args: TrashArgs) -> Result<()> {
+    let config = Config::load()?;
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+    let now = Utc::now();
+    purge_trash(&mut galaxy, &config, now);
+
+    match args.command {
+        TrashCommands::List => {
+            if galaxy.trash().is_empty() {
+                println!("The trash is empty");
+            }
+            for entry in galaxy.trash() {
+                println!(
+                    "{} {} {} {} {}",
+                    format!("#{}", galaxy.get_id_format().format(entry.id())).muted(),
+                    entry.key().unwrap_or_default().muted(),
+                    entry.title(),
+                    format!("({} celestial bodies)", entry.len()).muted(),
+                    format!("deleted {}", util::time::timestamp(entry.deleted(), now)).muted(),
+                );
+            }
+        }
+        TrashCommands::Restore(args) => {
+            // The celestial body is not in the Galaxy, so it is looked up in
+            // the trash instead
+            let reference = args.id.trim_start_matches('#');
+            let format = galaxy.get_id_format();
+            let id = galaxy
+                .trash()
+                .iter()
+                .find(|entry| {
+                    format.parse(reference) == Some(entry.id())
+                        || entry
+                            .key()
+                            .is_some_and(|k| k.eq_ignore_ascii_case(reference))
+                })
+                .map(|entry| entry.id())
+                .ok_or_else(|| {
+                    AppError::InvalidArgument(format!("{} is not in the trash", args.id))
+                })?;
+            let count = galaxy.restore(id)?;
+            println!(
+                "Restored {} {}",
+                galaxy.display_id(id),
+                format!("({count} celestial bodies)").muted()
+            );
+        }
+        TrashCommands::Empty => {
+            let count = galaxy.empty_trash(None);
+            println!("Permanently removed {count} entries from the trash");
+        }
+    }
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Manages tags across the whole Galaxy
+pub fn tag(args: TagArgs) -> Result<()> {
+    match args.command {
+        TagCommands::Rename(args) => {
+            let mut config = Config::load()?;
+            let mut galaxy = Galaxy::load()?;
+            let snapshot = diff::Snapshot::take(&galaxy);
+
+            let renamed = galaxy.rename_tag(&args.old, &args.new);
+            let renamed: Vec<_> = renamed.iter().map(|id| galaxy.display_id(*id)).collect();
+            let config_changed = config.rename_tag(&args.old, &args.new);
+
+            snapshot.print(&galaxy)?;
+            galaxy.save()?;
+            if config_changed {
+                config.save()?;
+            }
+
+            print_renamed("tag", &args, &renamed, config_changed);
+        }
+    }
+
+    Ok(())
+}
+
+/// Manages custom fields across the whole Galaxy
+pub fn field(args: FieldArgs) -> Result<()> {
+    match args.command {
+        FieldCommands::Rename(args) => {
+            let mut config = Config::load()?;
+            let mut galaxy = Galaxy::load()?;
+            let snapshot = diff::Snapshot::take(&galaxy);
+
+            let renamed = galaxy.rename_field(&args.old, &args.new)?;
+            let renamed: Vec<_> = renamed.iter().map(|id| galaxy.display_id(*id)).collect();
+            let config_changed = config.rename_field(&args.old, &args.new);
+
+            snapshot.print(&galaxy)?;
+            galaxy.save()?;
+            if config_changed {
+                config.save()?;
+            }
+
+            print_renamed("field", &args, &renamed, config_changed);
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds, removes, lists, or opens the links of a celestial body
+pub fn link(args: LinkArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    match args.command {
+        LinkCommands::Add(args) => {
+            let id = galaxy.resolve(&args.id)?;
+            let link = Link::new(args.target, args.label);
+            if !link.is_url() && !Path::new(&link.target).exists() {
+                println!(
+                    "{}",
+                    format!("There is no file at {}", link.target).warning()
+                );
+            }
+            let target = link.target.clone();
+            let mut body = galaxy
+                .get_mut(id)
+                .ok_or(AppError::CelestialBodyNotFound(id))?;
+            if !body.link(link) {
+                println!("{} already links to {target}", galaxy.display_id(id));
+            }
+        }
+        LinkCommands::Remove(args) => {
+            let id = galaxy.resolve(&args.id)?;
+            let body = galaxy.get(id).ok_or(AppError::CelestialBodyNotFound(id))?;
+            let target = find_link(body.links(), &args.link)?.target.clone();
+            if let Some(mut body) = galaxy.get_mut(id) {
+                body.unlink(&target);
+            }
+        }
+        LinkCommands::List(args) => {
+            let id = galaxy.resolve(&args.id)?;
+            let body = galaxy.get(id).ok_or(AppError::CelestialBodyNotFound(id))?;
+            if body.links().is_empty() {
+                println!("{}", "No links".muted());
+            }
+            for (i, link) in body.links().iter().enumerate() {
+                println!("{} {link}", format!("{}.", i + 1).muted());
+            }
+            return Ok(());
+        }
+        LinkCommands::Open(args) => {
+            let id = galaxy.resolve(&args.id)?;
+            let body = galaxy.get(id).ok_or(AppError::CelestialBodyNotFound(id))?;
+            shell::open(&find_link(body.links(), &args.link)?.target)?;
+            return Ok(());
+        }
+    }
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Helper function that finds a link by its number (starting at 1), target, or
+/// label
+fn find_link<'a>(links: &'a [Link], reference: &str) -> Result<&'a Link> {
+    let found = match reference.parse::<usize>() {
+        Ok(n) => n.checked_sub(1).and_then(|i| links.get(i)),
+        Err(_) => links
+            .iter()
+            .find(|l| l.target == reference)
+            .or_else(|| links.iter().find(|l| l.label == reference)),
+    };
+    found.ok_or_else(|| AppError::InvalidArgument(format!("No link {reference}")))
+}
+
+/// Adds, checks off, removes, or lists the checklist items of a celestial body
+pub fn check(args: CheckArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+    let id = galaxy.resolve(&args.id)?;
+    let body = galaxy.get(id).ok_or(AppError::CelestialBodyNotFound(id))?;
+    if body.kind() == CelestialBodyKind::Star {
+        return Err(AppError::InvalidArgument(format!(
+            "{} is a Star, only Planets and Comets have checklists",
+            galaxy.display_id(id)
+        )));
+    }
+
+    let Some(command) = args.command else {
+        if body.checklist().is_empty() {
+            println!("{}", "No checklist items".muted());
+        }
+        for (i, item) in body.checklist().iter().enumerate() {
+            println!("{} {item}", format!("{}.", i + 1).muted());
+        }
+        return Ok(());
+    };
+
+    let mut body = galaxy
+        .get_mut(id)
+        .ok_or(AppError::CelestialBodyNotFound(id))?;
+    match command {
+        CheckCommands::Add { text } => {
+            body.check_add(text);
+        }
+        CheckCommands::Toggle { item } => {
+            let index = find_item(body.as_body_ref().checklist(), &item)?;
+            body.check_toggle(index);
+        }
+        CheckCommands::Remove { item } => {
+            let index = find_item(body.as_body_ref().checklist(), &item)?;
+            body.check_remove(index);
+        }
+    }
+
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Helper function that finds the index of a checklist item by its number
+/// (starting at 1) or text
+fn find_item(checklist: &[ChecklistItem], reference: &str) -> Result<usize> {
+    let found = match reference.parse::<usize>() {
+        Ok(n) => n.checked_sub(1).filter(|i| *i < checklist.len()),
+        Err(_) => checklist.iter().position(|item| item.text == reference),
+    };
+    found.ok_or_else(|| AppError::InvalidArgument(format!("No checklist item {reference}")))
+}
+
+/// Saves, lists, or removes named filters and sort orders
+pub fn view(args: ViewArgs) -> Result<()> {
+    let mut config = Config::load()?;
+    match args.command {
+        ViewCommands::Save(args) => {
+            if args.name.contains(char::is_whitespace)
+                || tui::VIEWS
+                    .iter()
+                    .any(|v| v.names.iter().any(|n| n.eq_ignore_ascii_case(&args.name)))
+            {
+                return Err(AppError::InvalidArgument(format!(
+                    "\"{}\" cannot be used as the name of a view",
+                    args.name
+                )));
+            }
+            args.filter.parse::<Filter>()?;
+            let view = SavedView {
+                filter: args.filter,
+                sort: args.sort,
+                layout: args.layout,
+                group_by: args.group_by,
+            };
+            config.views.insert(args.name.clone(), view);
+            config.save()?;
+            println!("Saved the view \"{}\"", args.name);
+        }
+        ViewCommands::List => {
+            if config.views.is_empty() {
+                println!("There are no saved views");
+            }
+            for (name, view) in &config.views {
+                let grouped = match &view.group_by {
+                    Some(group_by) => format!(", grouped by {group_by}"),
+                    None => String::new(),
+                };
+                println!(
+                    "{name} {} {}",
+                    view.filter,
+                    format!("(sorted by {}{grouped}, {} view)", view.sort, view.layout).muted()
+                );
+            }
+        }
+        ViewCommands::Remove(args) => {
+            if config.views.remove(&args.name).is_none() {
+                return Err(AppError::InvalidArgument(format!(
+                    "There is no view named \"{}\"",
+                    args.name
+                )));
+            }
+            config.save()?;
+            println!("Removed the view \"{}\"", args.name);
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper function that prints the result of renaming a tag or field
+fn print_renamed(what: &str, args: &RenameArgs, ids: &[String], config_changed: bool) {
+    match ids.is_empty() {
+        true => println!("No celestial body has the {what} \"{}\"", args.old),
+        false => println!(
+            "Renamed {what} \"{}\" to \"{}\" on {}",
+            args.old,
+            args.new,
+            ids.join(", ").muted()
+        ),
+    }
+    if config_changed {
+        println!(
+            "{}",
+            "Updated the filters and actions in the config".muted()
+        );
+    }
+}
+
+/// Shows or sets the prefix of the short keys given to celestial bodies
+pub fn key_prefix(args: KeyPrefixArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+
+    let Some(prefix) = args.prefix else {
+        match galaxy.get_key_prefix() {
+            Some(prefix) => println!("{prefix}"),
+            None => println!("No key prefix is set"),
+        }
+        return Ok(());
+    };
+
+    let count = galaxy.set_key_prefix(prefix.clone())?;
+    galaxy.save()?;
+
+    println!(
+        "Set the key prefix to {prefix} {}",
+        format!("({count} celestial bodies were given a key)").muted()
+    );
+
+    Ok(())
+}
+
+/// Shows or sets how the IDs of celestial bodies are displayed
+pub fn id_format(args: IdFormatArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+
+    let Some(format) = args.format else {
+        println!("{}", galaxy.get_id_format());
+        return Ok(());
+    };
+
+    galaxy.set_id_format(format);
+    galaxy.save()?;
+
+    println!("Set the ID format to {format}");
+
+    Ok(())
+}
+
+/// Shows or changes the status rules of the Galaxy, which are shown if
+/// nothing is changed
+pub fn rules(args: RulesArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let mut rules = galaxy.get_rules().clone();
+    let old = rules.clone();
+
+    if args.enable || args.disable {
+        rules.enabled = args.enable;
+    }
+    if let Some(complete_stars) = args.complete_stars {
+        rules.complete_stars = complete_stars;
+    }
+    if let Some(start_parents) = args.start_parents {
+        rules.start_parents = start_parents;
+    }
+
+    let changed = rules != old;
+    println!(
+        "Rules:          {}",
+        match rules.enabled {
+            true => "enabled".success(),
+            false => "disabled".muted(),
+        }
+    );
+    println!("Complete Stars: {}", rules.complete_stars);
+    println!("Start parents:  {}", rules.start_parents);
+    if changed {
+        galaxy.set_rules(rules);
+        galaxy.save()?;
+    }
+
+    Ok(())
+}
+
+/// Lists or changes the statuses of the workflow of the Galaxy
+pub fn workflow(args: WorkflowArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+
+    let Some(command) = args.command else {
+        let width = galaxy
+            .workflow()
+            .stages()
+            .iter()
+            .map(|stage| stage.name.chars().count())
+            .max()
+            .unwrap_or_default();
+        for stage in galaxy.workflow().stages() {
+            let mut line = format!("{:<width$}", stage.name)
+                .color(stage.color())
+                .to_string();
+            if !stage.is_built_in() {
+                line += &format!(" {}", format!("(counts as {})", stage.category).muted());
+            }
+            if !stage.next.is_empty() {
+                line += &format!(" {} {}", "->".muted(), stage.next.join(", "));
+            }
+            println!("{line}");
+        }
+        return Ok(());
+    };
+
+    match command {
+        WorkflowCommands::Add {
+            name,
+            category,
+            display_color,
+            next,
+        } => {
+            let mut stage = Stage::new(name.clone(), category);
+            stage.color = display_color;
+            stage.next = next;
+            galaxy.workflow_add(stage)?;
+            println!("Added the status {name} (counts as {category})");
+        }
+        WorkflowCommands::Remove { name } => {
+            let moved = galaxy.workflow_remove(&name)?;
+            println!(
+                "Removed the status {name} {}",
+                format!("({} celestial bodies were moved back)", moved.len()).muted()
+            );
+        }
+        WorkflowCommands::Next { name, next } => {
+            galaxy.workflow_next(&name, next.clone())?;
+            match next.is_empty() {
+                true => println!("Any status may now follow {name}"),
+                false => println!("{name} may now be followed by {}", next.join(", ")),
+            }
+        }
+    }
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Shows or sets how the IDs of new celestial bodies are generated
+pub fn id_scheme(args: IdSchemeArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+
+    let Some(scheme) = args.scheme else {
+        println!("{}", galaxy.get_id_scheme());
+        return Ok(());
+    };
+
+    galaxy.set_id_scheme(scheme);
+    galaxy.save()?;
+
+    println!("Set the ID scheme to {scheme}");
+
+    Ok(())
+}
+
+/// Sets or removes the prefix used for the IDs of a Star and its descendants
+pub fn id_prefix(args: IdPrefixArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let star = galaxy.resolve(&args.star)?;
+
+    galaxy.set_id_prefix(star, args.prefix.clone())?;
+    let display = galaxy.display_id(star);
+    galaxy.save()?;
+
+    match args.prefix {
+        Some(prefix) => println!(
+            "Set the ID prefix to {prefix} {}",
+            format!("(the Star is now {display})").muted()
+        ),
+        None => println!(
+            "Removed the ID prefix {}",
+            format!("(the Star is now {display})").muted()
+        ),
+    }
+
+    Ok(())
+}
+
+/// Shows or changes the celestial bodies picked to work on today. Unfinished
+/// celestial bodies from earlier days are carried over first.
+pub fn today(args: TodayArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+    let today = Local::now().date_naive();
+
+    let carried = galaxy.carry_over(today);
+    if !carried.is_empty() {
+        let ids: Vec<_> = carried.iter().map(|id| galaxy.display_id(*id)).collect();
+        println!(
+            "Carried over unfinished {} from earlier days",
+            ids.join(", ").muted()
+        );
+    }
+
+    match args.command {
+        Some(TodayCommands::Add(args)) => {
+            for reference in args.ids {
+                let id = galaxy.resolve(&reference)?;
+                if !galaxy.plan_add(today, id)? {
+                    println!("{} is already planned for today", galaxy.display_id(id));
+                }
+            }
+        }
+        Some(TodayCommands::Remove(args)) => {
+            for reference in args.ids {
+                let id = galaxy.resolve(&reference)?;
+                if !galaxy.plan_remove(today, id) {
+                    println!("{} is not planned for today", galaxy.display_id(id));
+                }
+            }
+        }
+        None => {}
+    }
+
+    today_to_writer(&galaxy, today, &mut io::stdout())?;
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Shows, plans, or closes sprints
+pub fn sprint(args: SprintArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let today = Local::now().date_naive();
+
+    match args.command {
+        Some(SprintCommands::Create(args)) => {
+            let start = args.start;
+            let end = match args.end {
+                Some(end) => end,
+                None => start
+                    .checked_add_days(Days::new(args.days.saturating_sub(1)))
+                    .ok_or(AppError::InvalidArgument(format!(
+                        "{} days is too long",
+                        args.days
+                    )))?,
+            };
+            galaxy.sprint_create(args.name.clone(), start, end)?;
+            println!("Created sprint {} from {start} to {end}", args.name.bold());
+        }
+        Some(SprintCommands::Add(args)) => {
+            for reference in args.ids {
+                let id = galaxy.resolve(&reference)?;
+                if !galaxy.sprint_add(&args.name, id)? {
+                    println!("{} is already in the sprint", galaxy.display_id(id));
+                }
+            }
+            if let Some(sprint) = galaxy.sprint(&args.name) {
+                sprint_to_writer(&galaxy, sprint, today, &mut io::stdout())?;
+            }
+        }
+        Some(SprintCommands::Remove(args)) => {
+            for reference in args.ids {
+                let id = galaxy.resolve(&reference)?;
+                if !galaxy.sprint_remove(&args.name, id)? {
+                    println!("{} is not in the sprint", galaxy.display_id(id));
+                }
+            }
+            if let Some(sprint) = galaxy.sprint(&args.name) {
+                sprint_to_writer(&galaxy, sprint, today, &mut io::stdout())?;
+            }
+        }
+        Some(SprintCommands::Close(args)) => {
+            let (next, unfinished) = galaxy.sprint_close(&args.name)?;
+            let ids: Vec<_> = unfinished.iter().map(|id| galaxy.display_id(*id)).collect();
+            match (next, ids.is_empty()) {
+                (_, true) => println!("Closed sprint {}", args.name.bold()),
+                (Some(next), false) => println!(
+                    "Closed sprint {}, carried over unfinished {} to {}",
+                    args.name.bold(),
+                    ids.join(", ").muted(),
+                    next.bold()
+                ),
+                (None, false) => println!(
+                    "Closed sprint {}, unfinished {} {}",
+                    args.name.bold(),
+                    ids.join(", ").muted(),
+                    "(create the next sprint to carry them over)".warning()
+                ),
+            }
+        }
+        Some(SprintCommands::List) => {
+            if galaxy.sprints().is_empty() {
+                println!(
+                    "{}",
+                    "No sprints, create one with `planit sprint create <name>`".muted()
+                );
+            }
+            for sprint in galaxy.sprints() {
+                let state = match (sprint.is_closed(), sprint.is_active(today)) {
+                    (true, _) => "closed".muted(),
+                    (false, true) => "active".success(),
+                    (false, false) => "open".normal(),
+                };
+                println!(
+                    "{} {} {} {}",
+                    sprint.name().bold(),
+                    format!("{} to {}", sprint.start(), sprint.end()).muted(),
+                    state,
+                    format!("({} items)", sprint.items().len()).muted()
+                );
+            }
+            return Ok(());
+        }
+        Some(SprintCommands::Show(SprintShowArgs { name: Some(name) })) => {
+            let sprint = galaxy
+                .sprint(&name)
+                .ok_or(GalaxyError::SprintNotFound(name))?;
+            return Ok(sprint_to_writer(&galaxy, sprint, today, &mut io::stdout())?);
+        }
+        Some(SprintCommands::Show(SprintShowArgs { name: None })) | None => {
+            match galaxy.active_sprint(today) {
+                Some(sprint) => sprint_to_writer(&galaxy, sprint, today, &mut io::stdout())?,
+                None => println!(
+                    "{}",
+                    "No active sprint, create one with `planit sprint create <name>`".muted()
+                ),
+            }
+            return Ok(());
+        }
+    }
+
+    galaxy.save()?;
+    Ok(())
+}
+
+/// Helper function that writes `sprint` and its celestial bodies to `w`
+fn sprint_to_writer<W: io::Write>(
+    galaxy: &Galaxy,
+    sprint: &Sprint,
+    today: NaiveDate,
+    w: &mut W,
+) -> io::Result<()> {
+    let bodies: Vec<_> = sprint
+        .items()
+        .iter()
+        .filter_map(|id| galaxy.get(*id))
+        .collect();
+    let finished = bodies.iter().filter(|b| b.status().is_final()).count();
+    let left = match (sprint.is_closed(), (sprint.end() - today).num_days()) {
+        (true, _) => "closed".to_string(),
+        (false, days) if days < 0 => "ended".to_string(),
+        (false, 0) => "last day".to_string(),
+        (false, days) => format!("{days} days left"),
+    };
+    writeln!(
+        w,
+        "{} {} {}",
+        sprint.name().bold(),
+        format!("{} to {}, {left},", sprint.start(), sprint.end()).muted(),
+        format!("{finished}/{} finished", bodies.len()).highlight()
+    )?;
+
+    if bodies.is_empty() {
+        writeln!(
+            w,
+            "    {}",
+            "Nothing planned, add something with `planit sprint add <name> <id>`".muted()
+        )?;
+    }
+    for body in bodies {
+        let node = body.as_tree_node();
+        let check = match body.status().is_final() {
+            true => "[x]",
+            false => "[ ]",
+        };
+        writeln!(
+            w,
+            "    {} {} {} {} {}",
+            check,
+            node.icon(),
+            node.status(galaxy),
+            node.title(),
+            format!("({})", galaxy.display_id(body.id())).muted()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Lists the celestial bodies that are overdue or due soon
+pub fn remind(args: RemindArgs) -> Result<()> {
+    let galaxy = Galaxy::load()?;
+    let today = Local::now().date_naive();
+    let reminders = Reminders::of(&galaxy, today, args.days);
+    reminders_to_writer(&galaxy, &reminders, today, &mut io::stdout())?;
+
+    #[cfg(feature = "notify")]
+    if let Some(summary) = reminders_summary(&reminders).filter(|_| args.notify) {
+        let titles: Vec<_> = (reminders.overdue.iter().chain(&reminders.today))
+            .filter_map(|(id, _)| galaxy.get(*id))
+            .map(|body| body.title().to_string())
+            .collect();
+        let shown = notify_rust::Notification::new()
+            .appname("planit")
+            .summary(&summary)
+            .body(&titles.join("\n"))
+            .show();
+        if let Err(e) = shown {
+            log::warn!("Could not show a desktop notification: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns how many celestial bodies are overdue or due today (e.g. "2
+/// overdue, 1 due today"), `None` if there are none
+pub fn reminders_summary(reminders: &Reminders) -> Option<String> {
+    let parts: Vec<_> = [
+        (reminders.overdue.len(), "overdue"),
+        (reminders.today.len(), "due today"),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, what)| format!("{count} {what}"))
+    .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Helper function that writes `reminders` on `today` to `w`
+fn reminders_to_writer<W: io::Write>(
+    galaxy: &Galaxy,
+    reminders: &Reminders,
+    today: NaiveDate,
+    w: &mut W,
+) -> io::Result<()> {
+    if reminders.is_empty() {
+        return writeln!(w, "{}", "Nothing is due".muted());
+    }
+
+    let sections = [
+        ("Overdue", &reminders.overdue),
+        ("Due today", &reminders.today),
+        ("Upcoming", &reminders.upcoming),
+    ];
+    let mut first = true;
+    for (heading, items) in sections.into_iter().filter(|(_, items)| !items.is_empty()) {
+        if !first {
+            writeln!(w)?;
+        }
+        first = false;
+        writeln!(w, "{}", heading.bold())?;
+
+        for (id, due) in items {
+            let Some(body) = galaxy.get(*id) else {
+                continue;
+            };
+            let days = (*due - today).num_days();
+            let when = match days {
+                0 => "today".to_string().warning(),
+                1 => "tomorrow".to_string().normal(),
+                -1 => "1 day ago".to_string().error(),
+                ..0 => format!("{} days ago", -days).error(),
+                _ => format!("in {days} days").normal(),
+            };
+            let node = body.as_tree_node();
+            writeln!(
+                w,
+                "    {} {} {} {} {} {}",
+                node.icon(),
+                node.status(galaxy),
+                node.title(),
+                format!("({})", galaxy.display_id(*id)).muted(),
+                format!("due {due},").muted(),
+                when
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper function that writes the plan for `date` to `w`
+fn today_to_writer<W: io::Write>(galaxy: &Galaxy, date: NaiveDate, w: &mut W) -> io::Result<()> {
+    writeln!(w, "{} {}", "Today".bold(), date.to_string().muted())?;
+
+    let items = galaxy
+        .plan(date)
+        .map(|plan| plan.items())
+        .unwrap_or_default();
+    let bodies: Vec<_> = items.iter().filter_map(|id| galaxy.get(*id)).collect();
+    if bodies.is_empty() {
+        writeln!(
+            w,
+            "    {}",
+            "Nothing planned, pick something with `planit today add <id>`".muted()
+        )?;
+    }
+    for body in bodies {
+        let node = body.as_tree_node();
+        let check = match body.status().is_final() {
+            true => "[x]",
+            false => "[ ]",
+        };
+        writeln!(
+            w,
+            "    {} {} {} {} {}",
+            check,
+            node.icon(),
+            node.status(galaxy),
+            node.title(),
+            format!("({})", galaxy.display_id(body.id())).muted()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Shows statistics about the Galaxy
+pub fn stats(args: StatsArgs) -> Result<()> {
+    let galaxy = Galaxy::load()?;
+    match args.memory {
+        true => {
+            let config = Config::load()?;
+            let size = Galaxy::database_size()?;
+            memory_to_writer(&galaxy, size, config.history_limit(), &mut io::stdout())?;
+        }
+        false => {
+            let now = Utc::now();
+            let stats = Stats::of(&galaxy, now, args.weeks, STATS_TOP);
+            match args.json {
+                true => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&stats)
+                        .map_err(crate::core::DatabaseError::from)?
+                ),
+                false => stats_to_writer(&galaxy, &stats, now, &mut io::stdout())?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Helper function that writes `stats` as aligned tables to `w`
+fn stats_to_writer<W: io::Write>(
+    galaxy: &Galaxy,
+    stats: &Stats,
+    now: DateTime<Utc>,
+    w: &mut W,
+) -> io::Result<()> {
+    writeln!(w, "{}", "Kinds".bold())?;
+    for (kind, count) in &stats.kinds {
+        writeln!(w, "  {:<8} {count}", kind.to_string())?;
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{}", "Statuses".bold())?;
+    for (status, count) in &stats.statuses {
+        writeln!(w, "  {:<8} {count}", ColoredString::from(*status))?;
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{}", "Done per week".bold())?;
+    let most = stats.throughput.iter().map(|t| t.done).max().unwrap_or(0);
+    for throughput in &stats.throughput {
+        let bar = util::glyphs::current().bar_filled.repeat(match most {
+            0 => 0,
+            most => throughput.done * 20 / most,
+        });
+        writeln!(
+            w,
+            "  {}  {:>3} {}",
+            throughput.week,
+            throughput.done,
+            bar.success()
+        )?;
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{}", "Cycle time".bold())?;
+    match stats.cycle_time {
+        Some(cycle) => writeln!(
+            w,
+            "  {} from Start to Done on average {}",
+            util::time::hours_minutes(cycle.average_minutes).highlight(),
+            format!("({} celestial bodies)", cycle.bodies).muted()
+        )?,
+        None => writeln!(
+            w,
+            "  {}",
+            "Nothing has been started and finished yet".muted()
+        )?,
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{}", "Longest blocked".bold())?;
+    if stats.blocked.is_empty() {
+        writeln!(w, "  {}", "None".muted())?;
+    }
+    let width = stats
+        .blocked
+        .iter()
+        .map(|blocked| galaxy.display_id(blocked.id).len())
+        .max()
+        .unwrap_or(0);
+    for blocked in &stats.blocked {
+        let since = match blocked.since {
+            Some(since) => format!("since {}", util::time::timestamp(since, now)),
+            None => "since an unknown time".into(),
+        };
+        writeln!(
+            w,
+            "  {:<width$}  {} {}",
+            galaxy.display_id(blocked.id).muted(),
+            blocked.title,
+            since.muted()
+        )?;
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{}", "Busiest Stars".bold())?;
+    if stats.busiest_stars.is_empty() {
+        writeln!(w, "  {}", "None".muted())?;
+    }
+    let width = stats
+        .busiest_stars
+        .iter()
+        .map(|star| galaxy.display_id(star.id).len())
+        .max()
+        .unwrap_or(0);
+    for star in &stats.busiest_stars {
+        writeln!(
+            w,
+            "  {:<width$}  {:>4} open of {:<4}{}",
+            galaxy.display_id(star.id).muted(),
+            star.open,
+            star.total,
+            star.title
+        )?;
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{}", "By assignee".bold())?;
+    if stats.assignees.is_empty() {
+        writeln!(w, "  {}", "Nobody is assigned".muted())?;
+    }
+    let width = stats
+        .assignees
+        .keys()
+        .map(|name| name.len())
+        .max()
+        .unwrap_or(0);
+    for (name, workload) in &stats.assignees {
+        writeln!(
+            w,
+            "  {name:<width$}  {:>4} open {}",
+            workload.open,
+            format!("{} done", workload.done).muted()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Helper function that writes an estimate of the memory used by `galaxy` to
+/// `w`. `size` is the size of the database file.
+fn memory_to_writer<W: io::Write>(
+    galaxy: &Galaxy,
+    size: u64,
+    history_limit: Option<usize>,
+    w: &mut W,
+) -> io::Result<()> {
+    let bytes = |n: u64| match n {
+        0..1024 => format!("{n} B"),
+        1024..1_048_576 => format!("{:.1} KiB", n as f64 / 1024.0),
+        _ => format!("{:.1} MiB", n as f64 / 1_048_576.0),
+    };
+    let usage = MemoryUsage::of(galaxy);
+
+    writeln!(w, "{:<18} {:>10}", "Database file", bytes(size))?;
+    writeln!(w)?;
+    let rows = [
+        ("Celestial bodies", usage.bodies, usage.body_bytes),
+        ("Status changes", usage.history_entries, usage.history_bytes),
+        ("Events", usage.events, usage.event_bytes),
+    ];
+    for (name, count, used) in rows {
+        writeln!(w, "{name:<18} {:>10} {count:>8}", bytes(used as u64))?;
+    }
+    writeln!(
+        w,
+        "{:<18} {:>10}",
+        "Total",
+        bytes(usage.total() as u64).bold()
+    )?;
+
+    writeln!(w)?;
+    match history_limit {
+        Some(limit) => writeln!(
+            w,
+            "{}",
+            format!("The TUI keeps at most {limit} status changes and events per celestial body")
+                .muted()
+        ),
+        None => writeln!(
+            w,
+            "{}",
+            "The TUI keeps all status changes and events".muted()
+        ),
+    }
+}
+
+/// Converts the database to another file format
+pub fn convert(args: ConvertArgs) -> Result<()> {
+    let galaxy = Galaxy::load()?;
+    let format = args.to.unwrap_or(galaxy.get_format());
+    let layout = args.layout.unwrap_or(galaxy.get_layout());
+    let path = galaxy.convert(format, layout)?;
+    println!(
+        "Converted the database to {format} in {layout} {}",
+        format!("({})", path.display()).muted()
+    );
+    Ok(())
+}
+
+/// Merges the changes made in two versions of the database into `ours`. Both
+/// sides are kept where possible, and conflicting changes are printed.
+pub fn merge(args: MergeArgs) -> Result<()> {
+    let format = args.format.unwrap_or_else(|| Format::of(&args.ours));
+    let load = |path: &PathBuf| -> Result<Galaxy> {
+        let reader = io::BufReader::new(fs::File::open(path)?);
+        Ok(Galaxy::load_from_reader_as(reader, format)?)
+    };
+    let merge = Merge::of(load(&args.base)?, load(&args.ours)?, load(&args.theirs)?)?;
+
+    for (old, new) in &merge.renumbered {
+        eprintln!(
+            "{} #{old} was created on both sides, theirs is now #{new}",
+            "Renumbered:".warning()
+        );
+    }
+    for conflict in &merge.conflicts {
+        eprintln!("{} {conflict}", "Conflict:".warning());
+    }
+    merge.galaxy.format(format).save_to(args.ours)?;
+    Ok(())
+}
+
+/// Compares two versions of the database and prints the added, removed, and
+/// modified celestial bodies
+pub fn diff(args: DiffArgs) -> Result<()> {
+    let old = load_version(&args.old)?;
+    let new = match &args.new {
+        Some(new) => load_version(new)?,
+        None => Galaxy::load()?,
+    };
+
+    let comparison = Comparison::of(old.clone(), new.clone())?;
+    if comparison.is_empty() {
+        println!("No differences");
+        return Ok(());
+    }
+    diff::write_comparison(&old, &new, &comparison, &mut io::stdout())?;
+    Ok(())
+}
+
+/// Helper function that loads a version of the database for `planit diff`,
+/// which is either a file or a git revision of the database
+fn load_version(version: &str) -> Result<Galaxy> {
+    let path = PathBuf::from(version);
+    if path.exists() {
+        return Ok(Galaxy::load_from(&path)?);
+    }
+
+    let path = Galaxy::location()?;
+    if path.is_dir() {
+        return Err(AppError::InvalidArgument(
+            "Git revisions can only be compared for databases in a single file".into(),
+        ));
+    }
+    let dir = path.parent().unwrap_or(&path);
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let output = process::Command::new("git")
+        .args(["show", &format!("{version}:./{name}")])
+        .current_dir(dir)
+        .stderr(process::Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(AppError::GitFailed(output.status.code()));
+    }
+    Ok(Galaxy::load_from_reader_as(
+        &output.stdout[..],
+        Format::of(&path),
+    )?)
+}
+
+/// Sets up the git repository containing the database to merge it with
+/// `planit merge` instead of line by line
+pub fn sync() -> Result<()> {
+    let path = Galaxy::location()?;
+    if path.is_dir() {
+        println!(
+            "Changes to {} are merged by git file by file already",
+            path.display()
+        );
+        return Ok(());
+    }
+    let dir = path.parent().unwrap_or(&path);
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let format = Format::of(&path)
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_default();
+
+    let attributes = dir.join(".gitattributes");
+    let line = format!("{name} merge=planit");
+    let existing = fs::read_to_string(&attributes).unwrap_or_default();
+    if !existing.lines().any(|l| l.trim() == line) {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&attributes)?;
+        if !existing.is_empty() && !existing.ends_with('\n') {
+            writeln!(file)?;
+        }
+        writeln!(file, "{line}")?;
+    }
+
+    let driver = format!("planit merge --format {format} %O %A %B");
+    for (key, value) in [
+        ("merge.planit.name", "planit database"),
+        ("merge.planit.driver", &driver),
+    ] {
+        let status = process::Command::new("git")
+            .args(["config", key, value])
+            .current_dir(dir)
+            .status()?;
+        if !status.success() {
+            return Err(AppError::GitFailed(status.code()));
+        }
+    }
+    println!(
+        "Changes to {name} are now merged by planit {}",
+        format!("({})", attributes.display()).muted()
+    );
+    Ok(())
+}
+
+/// Works with the git repository containing the database
+pub fn git(args: GitArgs) -> Result<()> {
+    match args.command {
+        GitCommands::Scan(args) => git_scan(args),
+    }
+}
+
+/// Helper function that attaches the commits in a range to the celestial
+/// bodies they reference, closing them if asked to. Commits that are already
+/// attached are skipped, so scanning the same commits again changes nothing.
+fn git_scan(args: GitScanArgs) -> Result<()> {
+    let mut galaxy = Galaxy::load()?;
+    let snapshot = diff::Snapshot::take(&galaxy);
+
+    let path = Galaxy::location()?;
+    let dir = path.parent().unwrap_or(&path);
+    let output = process::Command::new("git")
+        .args(["log", "--reverse", "--format=%H%x1f%cI%x1f%B%x1e"])
+        .args(args.range)
+        .current_dir(dir)
+        .stderr(process::Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(AppError::GitFailed(output.status.code()));
+    }
+
+    let log = String::from_utf8_lossy(&output.stdout);
+    let mut attached = 0;
+    for entry in log.split('\x1e') {
+        let mut parts = entry.trim_start().splitn(3, '\x1f');
+        let (Some(hash), Some(time), Some(message)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let commit = core::Commit {
+            hash: hash.to_string(),
+            summary: message.lines().next().unwrap_or_default().to_string(),
+            time: DateTime::parse_from_rfc3339(time)
+                .map(|time| time.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+        };
+        for mention in core::mentions(message) {
+            let Some(mut body) = galaxy
+                .resolve(&mention.reference)
+                .ok()
+                .and_then(|id| galaxy.get_mut(id))
+            else {
+                continue;
+            };
+            if !body.commit(commit.clone()) {
+                continue;
+            }
+            attached += 1;
+            if args.close && mention.closes && !body.as_body_ref().status().is_final() {
+                let comment = format!("Closed by commit {}", commit.short_hash());
+                body.status(Status::Done, comment);
+            }
+        }
+    }
+
+    if attached == 0 {
+        println!("No new commits reference celestial bodies");
+    }
+    snapshot.print(&galaxy)?;
+    galaxy.save()?;
+
+    Ok(())
+}
+
+/// Installs or removes the git hooks, or runs the commit-msg hook
+pub fn hooks(args: HooksArgs) -> Result<()> {
+    match args.command {
+        HooksCommands::Install(args) => {
+            let path = githooks::install(args.force)?;
+            println!("Installed {}", path.display());
+        }
+        HooksCommands::Uninstall => match githooks::uninstall()? {
+            Some(path) => println!("Removed {}", path.display()),
+            None => println!("No hook to remove"),
+        },
+        HooksCommands::CommitMsg(args) => {
+            // Commits are never blocked in repositories without a database
+            let Ok(galaxy) = Galaxy::load() else {
+                return Ok(());
+            };
+            let message = fs::read_to_string(&args.file)?;
+            let amended = githooks::commit_msg(&galaxy, &message)?;
+            if amended != message {
+                fs::write(&args.file, amended)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Prints the completion script for a shell
+pub fn completions(args: CompletionsArgs) -> Result<()> {
+    completions::generate(args.shell, &mut io::stdout())?;
+    Ok(())
+}
+
+/// Prints the celestial bodies for shell completion. Nothing is printed if
+/// there is no `Galaxy`, as completion should never fail loudly.
+pub fn complete_ids() -> Result<()> {
+    if let Ok(galaxy) = Galaxy::load() {
+        completions::complete_ids(&galaxy, &mut io::stdout())?;
+    }
+    Ok(())
+}
+
+/// Creates a report from the status history of the Galaxy
+pub fn report(args: ReportArgs) -> Result<()> {
+    match args.command {
+        ReportCommands::Burndown(args) => {
+            let galaxy = Galaxy::load()?;
+            let star = galaxy.resolve(&args.star)?;
+            let to = args.to.unwrap_or_else(|| Local::now().date_naive());
+            if args.from > to {
+                return Err(AppError::InvalidArgument(format!(
+                    "The burndown cannot start ({}) after it ends ({to})",
+                    args.from
+                )));
+            }
+            let burndown = Burndown::of(&galaxy, star, args.from, to, args.points, &Local)?;
+            let title = galaxy
+                .get(star)
+                .map(|s| s.title().to_string())
+                .unwrap_or_default();
+
+            #[cfg(feature = "svg")]
+            if let Some(path) = args.svg {
+                std::fs::write(&path, burndown.to_svg(&title))?;
+                println!("Wrote the burndown of {title} to {}", path.display());
+                return Ok(());
+            }
+            burndown_to_writer(&galaxy, &burndown, &title, &mut io::stdout())?;
+        }
+        ReportCommands::Activity(args) => {
+            let galaxy = Galaxy::load()?;
+            let day = args.since;
+            let activity = Activity::since(&galaxy, util::date::start_of(day));
+            activity_to_writer(&galaxy, &activity, day, &mut io::stdout())?;
+        }
+    }
+    Ok(())
+}
+
+/// Helper function that writes `activity` since `day` to `w` as Markdown, so
+/// that it can be pasted into notes
+fn activity_to_writer<W: io::Write>(
+    galaxy: &Galaxy,
+    activity: &Activity,
+    day: NaiveDate,
+    w: &mut W,
+) -> io::Result<()> {
+    writeln!(w, "# Activity since {day}")?;
+    if activity.is_empty() {
+        writeln!(w)?;
+        writeln!(w, "Nothing changed")?;
+    }
+
+    let name = |id: u64| match galaxy.get(id) {
+        Some(body) => format!("{} ({})", body.title(), galaxy.display_id(id)),
+        None => galaxy.display_id(id),
+    };
+    for (star, entries) in &activity.groups {
+        writeln!(w)?;
+        match star {
+            Some(star) => writeln!(w, "## {}", name(*star))?,
+            None => writeln!(w, "## No Star")?,
+        }
+        for entry in entries {
+            let what = match entry.status {
+                Some((_, Status::Done)) => "Finished".to_string(),
+                Some((_, Status::Cancel)) => "Cancelled".to_string(),
+                Some((old, new)) => format!("{old} → {new}"),
+                None => "New".to_string(),
+            };
+            let new = match entry.created && entry.status.is_some() {
+                true => " (new)",
+                false => "",
+            };
+            writeln!(w, "- {what}: {}{new}", name(entry.id))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper function that writes `burndown` as a bar chart to `w`, with one bar
+/// for each day
+fn burndown_to_writer<W: io::Write>(
+    galaxy: &Galaxy,
+    burndown: &Burndown,
+    title: &str,
+    w: &mut W,
+) -> io::Result<()> {
+    let unit = match burndown.points {
+        true => "points",
+        false => "celestial bodies",
+    };
+    writeln!(
+        w,
+        "{} {} {}",
+        "Burndown of".bold(),
+        title.bold(),
+        format!("({}, open {unit})", galaxy.display_id(burndown.star)).muted()
+    )?;
+
+    let most = burndown
+        .days
+        .iter()
+        .map(|day| day.remaining)
+        .max()
+        .unwrap_or(0);
+    let digits = most.to_string().len();
+    for day in &burndown.days {
+        let filled = match most {
+            0 => 0,
+            most => (day.remaining * BURNDOWN_WIDTH / most) as usize,
+        };
+        let bar = util::glyphs::current().bar_filled.repeat(filled);
+        let done = match day.done {
+            0 => String::new(),
+            done => format!(" -{done}"),
+        };
+        writeln!(
+            w,
+            "  {}  {:>digits$} {}{}",
+            day.date,
+            day.remaining,
+            bar.highlight(),
+            done.success()
+        )?;
+    }
+
+    let done: u64 = burndown.days.iter().map(|day| day.done).sum();
+    writeln!(
+        w,
+        "{} {:.1} {unit} per day {}",
+        "Velocity:".bold(),
+        burndown.velocity(),
+        format!("({done} finished in {} days)", burndown.days.len()).muted()
+    )?;
+
+    Ok(())
+}
+
+/// Helper function that permanently removes everything in the trash that is
+/// older than the configured retention period
+fn purge_trash(galaxy: &mut Galaxy, config: &Config, now: DateTime<Utc>) {
+    if let Some(retention) = config.trash_retention() {
+        galaxy.empty_trash(Some(now - retention));
+    }
+}
+
+/// Records or exports the retrospective of a sprint
+pub fn retro(args: RetroArgs) -> Result<()> {
+    match args.command {
+        RetroCommands::Add(args) => {
+            let mut galaxy = Galaxy::load()?;
+            let snapshot = diff::Snapshot::take(&galaxy);
+            let retro = galaxy.retro(&args.sprint)?;
+            for note in args.went_well {
+                retro.add_went_well(note);
+            }
+            for note in args.needs_improvement {
+                retro.add_needs_improvement(note);
+            }
+            for item in args.action {
+                let id = galaxy.retro_action_item(&args.sprint, item)?;
+                println!("Created action item {}", galaxy.display_id(id).muted());
+            }
+
+            snapshot.print(&galaxy)?;
+            galaxy.save()?;
+        }
+        RetroCommands::Export(args) => {
+            let galaxy = Galaxy::load()?;
+            match galaxy.get_retro(&args.sprint) {
+                Some(retro) => print!("{}", retro.to_markdown(&galaxy)),
+                None if galaxy.sprint(&args.sprint).is_none() => {
+                    return Err(GalaxyError::SprintNotFound(args.sprint).into());
+                }
+                None => return Err(AppError::RetroNotFound(args.sprint)),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the meaning of the colors and icons used when rendering celestial
+/// bodies. Everything is taken from the same code that renders celestial
+/// bodies, so the legend always matches what is shown. The statuses are those
+/// of the workflow of the current `Galaxy`, or the built in ones outside of
+/// one.
+pub fn legend() -> Result<()> {
+    let workflow = Galaxy::load()
+        .map(|galaxy| galaxy.workflow().clone())
+        .unwrap_or_default();
+    legend_to_writer(&mut io::stdout(), &workflow)?;
+    Ok(())
+}
+
+/// Helper function that writes the legend of `workflow` to `w`
+fn legend_to_writer<W: io::Write>(w: &mut W, workflow: &Workflow) -> io::Result<()> {
+    for (index, (title, rows)) in legend_sections(workflow).into_iter().enumerate() {
+        if index > 0 {
+            writeln!(w)?;
+        }
+        writeln!(w, "{}", title.bold())?;
+        let width = rows.iter().map(|row| row.symbol.chars().count()).max();
+        for row in rows {
+            let symbol = format!("{:<1$}", row.symbol, width.unwrap_or_default());
+            writeln!(w, "  {} {}", symbol.color(row.color), row.description)?;
+        }
+    }
+    Ok(())
+}
+
+/// The sections of the legend, each with a title and the meaning of every
+/// icon or color in it. Shared by `legend` and the legend of the TUI.
+pub fn legend_sections(workflow: &Workflow) -> Vec<(&'static str, Vec<LegendRow>)> {
+    let help = |value: Option<clap::builder::PossibleValue>| {
+        value
+            .and_then(|v| v.get_help().map(|h| h.to_string()))
+            .unwrap_or_default()
+    };
+
+    // Create one celestial body of each kind to render with
+    let mut sample = Galaxy::default();
+    let ids = [
+        sample.comet().id(),
+        sample.planet().id(),
+        sample.star().id(),
+    ];
+    let kinds = CelestialBodyKind::value_variants()
+        .iter()
+        .zip(ids)
+        .filter_map(|(kind, id)| {
+            let node = sample.get(id)?.as_tree_node();
+            Some(LegendRow {
+                symbol: format!("{} {}", &*node.icon(), node.label().trim_end()),
+                color: kind.color(),
+                description: help(kind.to_possible_value()),
+            })
+        })
+        .collect();
+
+    let statuses = workflow
+        .stages()
+        .iter()
+        .map(|stage| LegendRow {
+            symbol: stage.name.clone(),
+            color: stage.color(),
+            description: match stage.is_built_in() {
+                true => help(stage.category.to_possible_value()),
+                false => format!("Counts as {}", stage.category),
+            },
+        })
+        .collect();
+
+    let priorities = Priority::value_variants()
+        .iter()
+        .map(|priority| LegendRow {
+            symbol: priority.to_string(),
+            color: priority.color(),
+            description: help(priority.to_possible_value()),
+        })
+        .collect();
+
+    vec![
+        ("Kinds", kinds),
+        ("Statuses", statuses),
+        ("Priorities", priorities),
+    ]
+}
+
+/// Tracks time spent working on celestial bodies
+pub fn track(args: TrackArgs) -> Result<()> {
+    let now = Utc::now();
+
+    match args.command {
+        TrackCommands::Start(args) => {
+            let mut galaxy = Galaxy::load()?;
+            let tracked = galaxy.resolve(&args.id)?;
+            if let Some((id, elapsed)) = galaxy.start_tracking(tracked, now)? {
+                println!(
+                    "Stopped tracking {} after {}",
+                    galaxy.display_id(id),
+                    util::time::hours_minutes(elapsed.num_minutes() as u64)
+                );
+            }
+            println!("Tracking {}", galaxy.display_id(tracked));
+            galaxy.save()?;
+        }
+        TrackCommands::Stop => {
+            let mut galaxy = Galaxy::load()?;
+            match galaxy.stop_tracking(now) {
+                Some((id, elapsed)) => println!(
+                    "Stopped tracking {} after {}",
+                    galaxy.display_id(id),
+                    util::time::hours_minutes(elapsed.num_minutes() as u64)
+                ),
+                None => println!("Nothing is being tracked"),
+            }
+            galaxy.save()?;
+        }
+        TrackCommands::Status => {
+            let galaxy = Galaxy::load()?;
+            let tracking = galaxy.tracking().and_then(|id| galaxy.get(id));
+            match tracking.and_then(|body| Some((body, body.work_log().active()?))) {
+                Some((body, session)) => println!(
+                    "Tracking {} ({}) for {}",
+                    body.title(),
+                    galaxy.display_id(body.id()),
+                    util::time::hours_minutes((now - session.start).num_minutes() as u64)
+                ),
+                None => println!("Nothing is being tracked"),
+            }
+        }
+        TrackCommands::Report(args) => {
+            let galaxy = Galaxy::load()?;
+            let from = if args.week {
+                let today = Local::now().date_naive();
+                let monday = today - Days::new(today.weekday().num_days_from_monday().into());
+                monday
+                    .and_hms_opt(0, 0, 0)
+                    .and_then(|t| t.and_local_timezone(Local).earliest())
+                    .map(|t| t.with_timezone(&Utc))
+                    .unwrap_or(now)
+            } else {
+                DateTime::<Utc>::MIN_UTC
+            };
+            track_report_to_writer(&galaxy, from, now, &mut io::stdout())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper function that writes the time tracked between `from` and `now`,
+/// grouped by Star and by tag, to `w`. Time is grouped under the closest Star
+/// that contains the celestial body (or the body itself if it is a Star).
+fn track_report_to_writer<W: io::Write>(
+    galaxy: &Galaxy,
+    from: DateTime<Utc>,
+    now: DateTime<Utc>,
+    w: &mut W,
+) -> io::Result<()> {
+    let mut by_star: Vec<(Option<u64>, i64)> = Vec::new();
+    let mut by_tag: Vec<(&str, i64)> = Vec::new();
+    let mut total = 0;
+
+    for body in galaxy.iter() {
+        let minutes = body.work_log().total_between(from, now, now).num_minutes();
+        if minutes == 0 {
+            continue;
+        }
+        total += minutes;
+
+        let mut star = Some(body);
+        while let Some(s) = star.filter(|s| s.kind() != CelestialBodyKind::Star) {
+            star = s.parent().and_then(|id| galaxy.get(id));
+        }
+        let star = star.map(|s| s.id());
+        match by_star.iter_mut().find(|(id, _)| *id == star) {
+            Some((_, sum)) => *sum += minutes,
+            None => by_star.push((star, minutes)),
+        }
+
+        for tag in body.tags() {
+            match by_tag.iter_mut().find(|(t, _)| t == tag) {
+                Some((_, sum)) => *sum += minutes,
+                None => by_tag.push((tag, minutes)),
+            }
+        }
+    }
+
+    let format = |minutes: i64| util::time::hours_minutes(minutes as u64);
+
+    writeln!(w, "{}", "By Star".bold())?;
+    by_star.sort_by_key(|(_, minutes)| -minutes);
+    for (star, minutes) in &by_star {
+        let name = match star.and_then(|id| galaxy.get(id)) {
+            Some(star) => format!("{} ({})", star.title(), galaxy.display_id(star.id())),
+            None => "No Star".to_string(),
+        };
+        writeln!(w, "  {:<40} {}", name, format(*minutes))?;
+    }
+    if by_star.is_empty() {
+        writeln!(w, "  {}", "None".muted())?;
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{}", "By Tag".bold())?;
+    by_tag.sort_by_key(|(_, minutes)| -minutes);
+    for (tag, minutes) in &by_tag {
+        writeln!(w, "  {:<40} {}", tag.highlight(), format(*minutes))?;
+    }
+    if by_tag.is_empty() {
+        writeln!(w, "  {}", "None".muted())?;
+    }
+
+    writeln!(w)?;
+    writeln!(w, "{} {}", "Total:".bold(), format(total))?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn color_is_a_global_option() {
+        let color = |args: &[&str]| Cli::try_parse_from(args).unwrap().color;
+        assert_eq!(color(&["planit", "list"]), ColorChoice::Auto);
+        assert_eq!(
+            color(&["planit", "--color", "never", "list"]),
+            ColorChoice::Never
+        );
+        assert_eq!(
+            color(&["planit", "list", "--color", "always"]),
+            ColorChoice::Always
+        );
+        assert!(Cli::try_parse_from(["planit", "--color", "sometimes", "list"]).is_err());
+    }
+
+    #[test]
+    fn lists_fit_into_the_given_width() {
+        assert_eq!(output_width(Some(40)).unwrap(), 40);
+
+        colored::control::set_override(false);
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().title("Launch the new website".into()).id();
+        let title = "Rewrite the login page so that it works with single sign on";
+        let planet = galaxy.planet().title(title.into()).id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        let print = |width| {
+            let mut w = Vec::new();
+            let columns = Column::DEFAULT;
+            galaxy
+                .pretty_print_to_writer(&mut w, width, Descriptions::Hidden, true, &columns)
+                .unwrap();
+            String::from_utf8(w).unwrap()
+        };
+
+        let narrow = print(40);
+        assert!(narrow.lines().all(|line| line.chars().count() <= 40));
+        assert!(!narrow.contains(title));
+        // Output that is not a terminal is never cut off
+        assert!(print(usize::MAX).contains(title));
+    }
+
+    #[test]
+    fn only_output_taller_than_the_terminal_is_paged() {
+        let output = "one\ntwo\nthree\n".as_bytes();
+        assert!(fits(output, Some(4)));
+        assert!(!fits(output, Some(3)));
+        assert!(!fits(output, Some(1)));
+        assert!(fits(output, None));
+        assert!(fits(b"", Some(1)));
+    }
+
+    #[test]
+    fn watch_options_are_validated() {
+        let parse = |args: &[&str]| Cli::try_parse_from(args).map(|cli| cli.command);
+        let Ok(Some(Commands::List(args))) =
+            parse(&["planit", "list", "--watch", "--interval", "5"])
+        else {
+            panic!("list --watch was not parsed");
+        };
+        assert_eq!((args.watch, args.interval), (true, Some(5)));
+        assert!(parse(&["planit", "list", "--interval", "5"]).is_err());
+        assert!(parse(&["planit", "list", "--watch", "--all-projects"]).is_err());
+    }
+
+    #[test]
+    fn the_split_mapping_is_chosen() {
+        let mut prompt = Vec::new();
+        let mut input = io::Cursor::new("Star\nmoon\ncomet\n");
+        let mapping = choose_mapping(&mut input, &mut prompt).unwrap();
+        assert_eq!(
+            (mapping.sections, mapping.items),
+            (CelestialBodyKind::Star, CelestialBodyKind::Comet)
+        );
+        let prompt = String::from_utf8(prompt).unwrap();
+        assert!(prompt.contains("Unknown kind \"moon\""));
+        assert_eq!(prompt.matches("Turn checkbox items into").count(), 2);
+
+        // Nothing typed keeps the defaults
+        let mapping = choose_mapping(&mut io::Cursor::new("\n"), &mut Vec::new()).unwrap();
+        assert_eq!(
+            (mapping.sections, mapping.items),
+            (CelestialBodyKind::Planet, CelestialBodyKind::Planet)
+        );
+    }
+
+    #[test]
+    fn the_legend_explains_every_kind_status_and_priority() {
+        let mut galaxy = Galaxy::default();
+        let review = Stage::new("Review".into(), Status::Start);
+        galaxy.workflow_add(review).unwrap();
+
+        let sections = legend_sections(galaxy.workflow());
+        let titles: Vec<_> = sections.iter().map(|(title, _)| *title).collect();
+        assert_eq!(titles, ["Kinds", "Statuses", "Priorities"]);
+        let (_, kinds) = &sections[0];
+        assert_eq!(kinds.len(), CelestialBodyKind::value_variants().len());
+        assert!(kinds[0].symbol.contains("COMET"));
+        let (_, statuses) = &sections[1];
+        assert_eq!(statuses.len(), Status::value_variants().len() + 1);
+        let review = statuses.iter().find(|row| row.symbol == "Review").unwrap();
+        assert_eq!(review.description, format!("Counts as {}", Status::Start));
+        let (_, priorities) = &sections[2];
+        assert_eq!(priorities.len(), Priority::value_variants().len());
+        let rows = sections.iter().flat_map(|(_, rows)| rows);
+        assert!(rows.clone().all(|row| !row.description.is_empty()));
+
+        let mut w = Vec::new();
+        legend_to_writer(&mut w, galaxy.workflow()).unwrap();
+        let legend = String::from_utf8(w).unwrap();
+        for row in rows {
+            assert!(legend.contains(&row.description));
+        }
+    }
 }
@@ -0,0 +1,201 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Shell completion scripts.
+ *
+ * The static part of the scripts is generated by `clap_complete` from the
+ * command line definition. For the shells that support it, a small wrapper is
+ * appended that completes the arguments of commands taking an ID with the
+ * celestial bodies in the current `Galaxy`, by calling the hidden
+ * `planit __complete-ids` command.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::io;
+
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+use super::cli::Cli;
+use crate::core::Galaxy;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The (sub)commands whose arguments are IDs of celestial bodies
+const ID_COMMANDS: [&str; 14] = [
+    "show",
+    "id-prefix",
+    "split",
+    "archive",
+    "unarchive",
+    "delete",
+    "run",
+    "start",
+    "add",
+    "remove",
+    "export",
+    "open",
+    "check",
+    "toggle",
+];
+
+/// Writes the completion script for `shell` to `w`
+pub fn generate<W: io::Write>(shell: Shell, w: &mut W) -> io::Result<()> {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, &name, w);
+
+    let commands = ID_COMMANDS.join(" ");
+    match shell {
+        Shell::Bash => {
+            let cases = ID_COMMANDS.map(|c| format!("*\" {c} \"*")).join("|");
+            writeln!(
+                w,
+                r#"
+_{name}_ids() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    case " ${{COMP_WORDS[*]:1:COMP_CWORD-1}} " in
+        {cases})
+            if [[ "$cur" != -* ]]; then
+                COMPREPLY=( $(compgen -W "$({name} __complete-ids 2>/dev/null | cut -f1)" -- "$cur") )
+                return 0
+            fi
+            ;;
+    esac
+    _{name} "$@"
+}}
+complete -F _{name}_ids -o bashdefault -o default {name}"#
+            )?;
+        }
+        Shell::Zsh => {
+            let pattern = ID_COMMANDS.join("|");
+            writeln!(
+                w,
+                r#"
+_{name}_ids() {{
+    if [[ " ${{words[2,CURRENT-1]}} " == *" "({pattern})" "* && "${{words[CURRENT]}}" != -* ]]; then
+        local -a ids
+        ids=(${{(f)"$({name} __complete-ids 2>/dev/null | tr '\t' ':')"}})
+        _describe 'celestial body' ids
+    else
+        _{name} "$@"
+    fi
+}}
+compdef _{name}_ids {name}"#
+            )?;
+        }
+        Shell::Fish => {
+            writeln!(
+                w,
+                r#"complete -c {name} -n "__fish_seen_subcommand_from {commands}" -f -a "({name} __complete-ids 2>/dev/null)""#
+            )?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Writes every visible celestial body in `galaxy` to `w` as a completion,
+/// one per line. Each line is the short key (or the ID if there is no short
+/// key) and the title, separated by a tab.
+pub fn complete_ids<W: io::Write>(galaxy: &Galaxy, w: &mut W) -> io::Result<()> {
+    let mut bodies: Vec<_> = galaxy
+        .iter()
+        .filter(|body| !galaxy.is_hidden(body.id()))
+        .collect();
+    bodies.sort_by_key(|body| body.id());
+
+    for body in bodies {
+        match body.key() {
+            Some(key) => writeln!(w, "{key}\t{}", body.title())?,
+            None => writeln!(w, "{}\t{}", galaxy.display_id(body.id()), body.title())?,
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::core::CelestialBody;
+
+    use super::*;
+
+    #[test]
+    fn id_commands_exist() {
+        fn collect(command: &clap::Command, names: &mut Vec<String>) {
+            for sub in command.get_subcommands() {
+                names.push(sub.get_name().to_string());
+                collect(sub, names);
+            }
+        }
+        let mut all = Vec::new();
+        collect(&Cli::command(), &mut all);
+
+        for command in ID_COMMANDS {
+            assert!(all.iter().any(|c| c == command), "{command} does not exist");
+        }
+        assert!(all.iter().any(|c| c == "__complete-ids"));
+    }
+
+    #[test]
+    fn scripts_complete_ids() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let mut script = Vec::new();
+            generate(shell, &mut script).unwrap();
+            let script = String::from_utf8(script).unwrap();
+            assert!(script.contains("planit __complete-ids"), "{shell}");
+        }
+    }
+
+    #[test]
+    fn ids_are_completed_with_titles() {
+        let mut galaxy = Galaxy::default();
+        galaxy.star().title("Sprint".into());
+        galaxy.set_key_prefix("P-".into()).unwrap();
+        galaxy.planet().title("Login".into());
+
+        let mut ids = Vec::new();
+        complete_ids(&galaxy, &mut ids).unwrap();
+        assert_eq!(String::from_utf8(ids).unwrap(), "P-1\tSprint\nP-2\tLogin\n");
+    }
+}
@@ -0,0 +1,720 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the user configuration for the application. The configuration is
+ * stored as JSON in `config.json` within the config directory (see
+ * `util::dir::config`). Every setting is optional, so a missing file or
+ * missing keys fall back to the defaults.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use chrono::TimeDelta;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::tui::View,
+    core::{CelestialBodyKind, CelestialBodyRef, Filter, GroupBy, Notification, Sort, Trigger},
+    util::{
+        self,
+        glyphs::GlyphSet,
+        theme::{Color, Theme},
+        time::DateStyle,
+        tree::Column,
+    },
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether `Config::save` leaves the configuration file alone, set by
+/// `--dry-run`
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Possible errors when loading the configuration
+#[derive(Debug)]
+pub enum ConfigError {
+    /// An error occurred while reading the configuration file
+    FileSystemError(io::Error),
+    /// An error occurred while parsing the configuration file
+    ParsingError(serde_json::Error),
+    /// The theme is neither built in nor defined in `themes`
+    UnknownTheme(String),
+}
+
+impl std::error::Error for ConfigError {}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::FileSystemError(io_error) => {
+                write!(f, "Config file system error: {io_error}")
+            }
+            ConfigError::ParsingError(json_error) => {
+                write!(f, "Config parsing error: {json_error}")
+            }
+            ConfigError::UnknownTheme(name) => write!(
+                f,
+                "Unknown theme \"{name}\", expected one of {} or a theme in \"themes\"",
+                Theme::BUILT_IN.join(", ")
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(value: io::Error) -> Self {
+        Self::FileSystemError(value)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::ParsingError(value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The user configuration
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    /// Named commands that can be run on celestial bodies
+    pub actions: Vec<Action>,
+    /// Commands run and URLs notified when something happens to celestial
+    /// bodies
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<Hook>,
+    /// Named sequences of TUI commands, run by typing `:<name>` in the TUI
+    pub aliases: BTreeMap<String, Vec<String>>,
+    /// Keys (or sequences of keys) bound to a TUI command or alias, e.g.
+    /// `"t": "triage"` or `"g k": "view kanban"`
+    pub keys: BTreeMap<String, String>,
+    /// Filters and sort orders saved under a name, see `SavedView`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub views: BTreeMap<String, SavedView>,
+    /// Other projects by name, each the directory that contains its database.
+    /// `list --all-projects` and the project switcher of the TUI span them.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub projects: BTreeMap<String, PathBuf>,
+    /// Number of milliseconds the TUI waits for the next key of a sequence in
+    /// `keys`. Defaults to `DEFAULT_KEY_TIMEOUT_MS`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_timeout_ms: Option<u64>,
+    /// Number of days deleted celestial bodies are kept in the trash. Defaults
+    /// to `DEFAULT_TRASH_RETENTION_DAYS`, `0` keeps them forever.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trash_retention_days: Option<u64>,
+    /// Number of status changes and events per celestial body the TUI keeps
+    /// in memory. Defaults to `DEFAULT_HISTORY_LIMIT`, `0` keeps all of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_limit: Option<usize>,
+    /// Number of days without any activity after which the TUI dims an
+    /// unfinished celestial body. Defaults to `DEFAULT_STALE_DAYS`, `0` never
+    /// dims them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_days: Option<u64>,
+    /// Number of milliseconds without changes after which the TUI saves them
+    /// on its own. Changes are only saved with `:w` if it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autosave_ms: Option<u64>,
+    /// Name of the color theme, either a built in theme or one of `themes`.
+    /// Defaults to the first built in theme.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// User defined color themes
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub themes: BTreeMap<String, ThemeConfig>,
+    /// Characters used for trees and icons. Defaults to the Nerd Font icons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glyphs: Option<GlyphSet>,
+    /// The columns `list` prints for each celestial body, in order. Defaults
+    /// to `Column::DEFAULT`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<Column>,
+    /// Colors pinned to tags (e.g. `"urgent": "red"`). Other tags are colored
+    /// with the palette of the theme.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub tag_colors: BTreeMap<String, Color>,
+    /// Whether dates and times are shown relative to now (e.g. "3 days ago",
+    /// the default) or as dates (e.g. "2025-03-01 14:30")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dates: Option<DateStyle>,
+    /// Whether every command first prints how many celestial bodies are
+    /// overdue or due today
+    pub remind: bool,
+    /// The name of the current user, as used for assignees (e.g. by
+    /// `list --mine`). Defaults to `$USER`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl Config {
+    const FILENAME: &str = "config.json";
+    const DEFAULT_TRASH_RETENTION_DAYS: u64 = 30;
+    const DEFAULT_HISTORY_LIMIT: usize = 100;
+    const DEFAULT_STALE_DAYS: u64 = 14;
+    const DEFAULT_KEY_TIMEOUT_MS: u64 = 1000;
+
+    /// Finds the location of the configuration file
+    pub fn location() -> Option<PathBuf> {
+        let mut path = util::dir::config()?;
+        path.push(Config::FILENAME);
+        Some(path)
+    }
+
+    /// Loads the configuration. If there is no configuration file, the default
+    /// configuration is used.
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - There is an error while doing a filesystem operation
+    /// - There is an error while parsing the configuration
+    pub fn load() -> Result<Self, ConfigError> {
+        match Config::location() {
+            Some(path) if path.exists() => {
+                let file = fs::File::open(path)?;
+                Self::load_from_reader(io::BufReader::new(file))
+            }
+            _ => Ok(Config::default()),
+        }
+    }
+
+    /// Saves the configuration to the configuration file, creating it (and the
+    /// config directory) if it does not exist. Nothing is written after `set_read_only(true)`.
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - There is an error while doing a filesystem operation
+    /// - There is an error while serializing the configuration
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let Some(path) = Config::location().filter(|_| !READ_ONLY.load(Ordering::Relaxed)) else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = fs::File::create(path)?;
+        serde_json::to_writer_pretty(io::BufWriter::new(file), self)?;
+        Ok(())
+    }
+
+    /// A helper function that parses the configuration from `reader`
+    fn load_from_reader<R: io::Read>(reader: R) -> Result<Self, ConfigError> {
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Returns the command or alias bound to the sequence of `keys`, if any.
+    /// Spaces between the keys of a bound sequence are ignored, so `g k` is
+    /// the same as `gk`.
+    pub fn binding(&self, keys: &str) -> Option<&str> {
+        self.keys
+            .iter()
+            .find(|(k, _)| sequence(k) == keys)
+            .map(|(_, command)| command.as_str())
+    }
+
+    /// Returns the bound sequences of keys that are longer than `prefix` and
+    /// start with it, along with what they are bound to
+    pub fn continuations(&self, prefix: &str) -> Vec<(String, &str)> {
+        self.keys
+            .iter()
+            .map(|(keys, command)| (sequence(keys), command.as_str()))
+            .filter(|(keys, _)| keys.len() > prefix.len() && keys.starts_with(prefix))
+            .collect()
+    }
+
+    /// Returns how long the TUI waits for the next key of a sequence
+    pub fn key_timeout(&self) -> Duration {
+        Duration::from_millis(
+            self.key_timeout_ms
+                .unwrap_or(Config::DEFAULT_KEY_TIMEOUT_MS),
+        )
+    }
+
+    /// Returns how long deleted celestial bodies are kept in the trash, `None`
+    /// if they are kept forever
+    pub fn trash_retention(&self) -> Option<TimeDelta> {
+        match self
+            .trash_retention_days
+            .unwrap_or(Config::DEFAULT_TRASH_RETENTION_DAYS)
+        {
+            0 => None,
+            days => TimeDelta::try_days(days as i64),
+        }
+    }
+
+    /// Returns the columns `list` prints for each celestial body, in order
+    pub fn columns(&self) -> &[Column] {
+        match self.columns.is_empty() {
+            true => &Column::DEFAULT,
+            false => &self.columns,
+        }
+    }
+
+    /// Returns the name of the project (see `projects`) whose database is at
+    /// `location`, if any
+    pub fn project_at(&self, location: &Path) -> Option<&str> {
+        self.projects
+            .iter()
+            .find(|(_, dir)| location.parent() == Some(dir.as_path()))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns how many status changes and events per celestial body the TUI
+    /// keeps in memory, `None` if it keeps all of them
+    pub fn history_limit(&self) -> Option<usize> {
+        match self.history_limit.unwrap_or(Config::DEFAULT_HISTORY_LIMIT) {
+            0 => None,
+            limit => Some(limit),
+        }
+    }
+
+    /// Returns how long an unfinished celestial body can go without any
+    /// activity before the TUI dims it, `None` if it never does
+    pub fn stale_after(&self) -> Option<TimeDelta> {
+        match self.stale_days.unwrap_or(Config::DEFAULT_STALE_DAYS) {
+            0 => None,
+            days => TimeDelta::try_days(days as i64),
+        }
+    }
+
+    /// Returns how long the TUI waits after the last change before saving,
+    /// `None` if it does not save on its own
+    pub fn autosave(&self) -> Option<Duration> {
+        self.autosave_ms.map(Duration::from_millis)
+    }
+
+    /// Returns the name of the current user, `None` if neither `user` nor
+    /// `$USER` is set
+    pub fn user(&self) -> Option<String> {
+        self.user
+            .clone()
+            .or_else(|| std::env::var("USER").ok())
+            .filter(|user| !user.trim().is_empty())
+    }
+
+    /// Returns the color theme to use
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - The theme (or the theme a user defined theme is based on) does not
+    ///   exist
+    /// - A user defined theme sets a color that themes do not have
+    pub fn theme(&self) -> Result<Theme, ConfigError> {
+        let name = self.theme.as_deref().unwrap_or(Theme::BUILT_IN[0]);
+        match (self.themes.get(name), Theme::built_in(name)) {
+            (Some(theme), _) => theme.resolve(),
+            (None, Some(theme)) => Ok(theme),
+            (None, None) => Err(ConfigError::UnknownTheme(name.to_string())),
+        }
+    }
+
+    /// Renames the tag `old` to `new` in the filters used by aliases, keys, and
+    /// saved views, and in the tags that actions and hooks are restricted to
+    ///
+    /// # Returns
+    /// `true` if anything was changed
+    pub fn rename_tag(&mut self, old: &str, new: &str) -> bool {
+        let mut changed = self.rename_filters(|filter| Filter::rename_tag(filter, old, new));
+        for action in &mut self.actions {
+            for tag in action.tags.iter_mut().filter(|t| *t == old) {
+                *tag = new.to_string();
+                changed = true;
+            }
+        }
+        for hook in &mut self.hooks {
+            for tag in hook.tags.iter_mut().filter(|t| *t == old) {
+                *tag = new.to_string();
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Renames the field `old` to `new` in the filters used by aliases, keys,
+    /// and saved views, in what saved views are grouped by, and in the
+    /// `{field.<key>}` placeholders of actions and hooks
+    ///
+    /// # Returns
+    /// `true` if anything was changed
+    pub fn rename_field(&mut self, old: &str, new: &str) -> bool {
+        let mut changed = self.rename_filters(|filter| Filter::rename_field(filter, old, new));
+        for group_by in self.views.values_mut().filter_map(|v| v.group_by.as_mut()) {
+            if *group_by == GroupBy::Field(old.to_string()) {
+                *group_by = GroupBy::Field(new.to_string());
+                changed = true;
+            }
+        }
+        let (old, new) = (format!("{{field.{old}}}"), format!("{{field.{new}}}"));
+        for action in &mut self.actions {
+            if action.command.contains(&old) {
+                action.command = action.command.replace(&old, &new);
+                changed = true;
+            }
+        }
+        for command in self.hooks.iter_mut().filter_map(|h| h.command.as_mut()) {
+            if command.contains(&old) {
+                *command = command.replace(&old, &new);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Helper function that rewrites the filter of every `filter` command in
+    /// the aliases and keys, and of every saved view, using `rename`
+    fn rename_filters(&mut self, rename: impl Fn(&str) -> String) -> bool {
+        let mut changed = false;
+        for view in self.views.values_mut() {
+            let renamed = rename(&view.filter);
+            if renamed != view.filter {
+                view.filter = renamed;
+                changed = true;
+            }
+        }
+        let commands = self
+            .aliases
+            .values_mut()
+            .flatten()
+            .chain(self.keys.values_mut());
+        for command in commands {
+            let Some(filter) = command.strip_prefix("filter ") else {
+                continue;
+            };
+            let renamed = format!("filter {}", rename(filter));
+            if renamed != *command {
+                *command = renamed;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Returns all actions that can be run on `body`
+    pub fn actions_for<'a>(
+        &'a self,
+        body: &'a CelestialBodyRef,
+    ) -> impl Iterator<Item = &'a Action> {
+        self.actions.iter().filter(|action| action.applies_to(body))
+    }
+}
+
+/// A named command that can be run on a celestial body. The command may
+/// contain placeholders (e.g. `{id}` or `{field.url}`) that are replaced with
+/// the values of the celestial body before running it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Action {
+    /// The name used to refer to the action
+    pub name: String,
+    /// The templated shell command to run
+    pub command: String,
+    /// Kinds of celestial bodies the action applies to. Applies to all kinds
+    /// if empty.
+    #[serde(default)]
+    pub kinds: Vec<CelestialBodyKind>,
+    /// Tags the celestial body must have one of for the action to apply.
+    /// Applies regardless of tags if empty.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// A shell command and/or URL that is notified when something happens to a
+/// celestial body. Commands may contain the same placeholders as actions, as
+/// well as `{trigger}`, `{old}` (the previous status), and `{comment}`. URLs
+/// are sent a `POST` request with the notification as JSON.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Hook {
+    /// What the hook is run for
+    pub on: Vec<Trigger>,
+    /// The templated shell command to run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// The URL to post the notification to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Kinds of celestial bodies the hook is run for. Runs for all kinds if
+    /// empty.
+    #[serde(default)]
+    pub kinds: Vec<CelestialBodyKind>,
+    /// Tags the celestial body must have one of for the hook to run. Runs
+    /// regardless of tags if empty.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// A filter and sort order saved under a name, which is listed with `planit
+/// list --view <name>` and opened in the TUI with `:open <name>`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SavedView {
+    /// The unparsed filter, see `Filter`
+    pub filter: String,
+    pub sort: Sort,
+    /// The view the TUI shows the celestial bodies in
+    pub layout: View,
+    /// What `list --view` groups the celestial bodies by, they are not
+    /// grouped if `None`. Also the columns of the kanban view, which are the
+    /// statuses if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<GroupBy>,
+}
+
+/// A user defined color theme, which changes some of the colors of a built in
+/// theme
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// The built in theme the colors are taken from, unless they are changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    /// The changed colors, e.g. `"border": "cyan"`
+    pub colors: BTreeMap<String, Color>,
+}
+
+impl ThemeConfig {
+    /// Helper function that applies the changed colors to the base theme
+    fn resolve(&self) -> Result<Theme, ConfigError> {
+        let base = self.base.as_deref().unwrap_or(Theme::BUILT_IN[0]);
+        let theme = Theme::built_in(base).ok_or_else(|| ConfigError::UnknownTheme(base.into()))?;
+        let mut value = serde_json::to_value(theme)?;
+        for (slot, color) in &self.colors {
+            value[slot] = serde_json::to_value(color)?;
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+impl Action {
+    /// Whether or not the action can be run on `body`
+    pub fn applies_to(&self, body: &CelestialBodyRef) -> bool {
+        (self.kinds.is_empty() || self.kinds.contains(&body.kind()))
+            && (self.tags.is_empty() || self.tags.iter().any(|t| body.tags().contains(t)))
+    }
+}
+
+impl Hook {
+    /// Whether or not the hook is run for `notification`
+    pub fn applies_to(&self, notification: &Notification) -> bool {
+        self.on.contains(&notification.trigger)
+            && (self.kinds.is_empty() || self.kinds.contains(&notification.kind))
+            && (self.tags.is_empty() || self.tags.iter().any(|t| notification.tags.contains(t)))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Makes `Config::save` leave the configuration file alone (or write it
+/// again), e.g. so that `--dry-run` changes nothing on disk
+pub fn set_read_only(read_only: bool) {
+    READ_ONLY.store(read_only, Ordering::Relaxed);
+}
+
+/// Helper function that removes the spaces between the keys of a bound
+/// sequence. A space on its own is kept, so that the space key can be bound.
+fn sequence(keys: &str) -> String {
+    match keys.trim() {
+        "" => keys.to_string(),
+        keys => keys.chars().filter(|c| !c.is_whitespace()).collect(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::core::Galaxy;
+
+    use super::*;
+
+    const CONFIG_STRING: &str = r##"{
+  "actions": [
+    {
+      "name": "Open PR page",
+      "command": "xdg-open {field.pr}",
+      "kinds": ["Planet"],
+      "tags": ["review"]
+    },
+    {
+      "name": "Echo",
+      "command": "echo {title}"
+    }
+  ],
+  "hooks": [
+    { "on": ["completed", "blocked"], "command": "notify-send {title} {status}" },
+    { "on": ["overdue"], "url": "http://localhost:9000/planit", "tags": ["review"] }
+  ],
+  "aliases": {
+    "triage": ["view kanban", "filter tag:inbox"]
+  },
+  "keys": {
+    "t": "triage",
+    "g k": "view kanban"
+  },
+  "views": {
+    "inbox": { "filter": "tag:inbox status:todo", "sort": "due", "layout": "kanban", "group_by": "assignee" }
+  },
+  "projects": {
+    "api": "/home/me/api",
+    "web": "/home/me/web"
+  },
+  "trash_retention_days": 0,
+  "theme": "mine",
+  "themes": {
+    "mine": { "base": "light", "colors": { "border": "cyan", "todo": "#ff8800" } },
+    "broken": { "colors": { "borders": "cyan" } }
+  }
+}"##;
+
+    #[test]
+    fn missing_keys_use_defaults() {
+        let config = Config::load_from_reader(io::Cursor::new("{}")).unwrap();
+        assert!(config.actions.is_empty());
+        assert!(config.aliases.is_empty());
+        assert_eq!(config.trash_retention(), Some(TimeDelta::days(30)));
+        assert_eq!(config.history_limit(), Some(100));
+        assert_eq!(config.autosave(), None);
+        assert_eq!(config.stale_after(), Some(TimeDelta::days(14)));
+    }
+
+    #[test]
+    fn keys_are_bound_to_commands() {
+        let config = Config::load_from_reader(io::Cursor::new(CONFIG_STRING)).unwrap();
+        assert_eq!(config.binding("t"), Some("triage"));
+        assert_eq!(config.binding("x"), None);
+        assert_eq!(config.binding("gk"), Some("view kanban"));
+        assert_eq!(
+            config.continuations("g"),
+            vec![("gk".into(), "view kanban")]
+        );
+        assert!(config.continuations("gk").is_empty());
+        assert_eq!(config.aliases["triage"].len(), 2);
+        assert_eq!(config.trash_retention(), None);
+    }
+
+    #[test]
+    fn projects_are_found_by_database() {
+        let config = Config::load_from_reader(io::Cursor::new(CONFIG_STRING)).unwrap();
+        assert_eq!(
+            config.project_at(Path::new("/home/me/web/.planit.json")),
+            Some("web")
+        );
+        assert_eq!(
+            config.project_at(Path::new("/home/me/api/.planit")),
+            Some("api")
+        );
+        assert_eq!(config.project_at(Path::new("/home/me/.planit.json")), None);
+        assert_eq!(
+            config.project_at(Path::new("/home/me/web/docs/.planit.json")),
+            None
+        );
+    }
+
+    #[test]
+    fn themes_change_built_in_colors() {
+        let mut config = Config::load_from_reader(io::Cursor::new(CONFIG_STRING)).unwrap();
+        let theme = config.theme().unwrap();
+        assert_eq!(theme.border, Color::Cyan);
+        assert_eq!(theme.todo, Color::Rgb(0xff, 0x88, 0x00));
+        assert_eq!(theme.muted, Theme::LIGHT.muted);
+
+        config.theme = Some("broken".into());
+        assert!(matches!(config.theme(), Err(ConfigError::ParsingError(_))));
+        config.theme = Some("high-contrast".into());
+        assert_eq!(config.theme().unwrap(), Theme::HIGH_CONTRAST);
+        config.theme = Some("missing".into());
+        assert!(matches!(config.theme(), Err(ConfigError::UnknownTheme(_))));
+    }
+
+    #[test]
+    fn renaming_updates_filters_and_actions() {
+        let mut config = Config::load_from_reader(io::Cursor::new(CONFIG_STRING)).unwrap();
+
+        assert!(config.rename_tag("inbox", "triage"));
+        assert_eq!(config.aliases["triage"][1], "filter tag:triage");
+        assert_eq!(config.views["inbox"].filter, "tag:triage status:todo");
+        assert_eq!(config.views["inbox"].sort, Sort::Due);
+        assert_eq!(config.views["inbox"].layout, View::Kanban);
+        assert_eq!(config.views["inbox"].group_by, Some(GroupBy::Assignee));
+        assert!(config.rename_tag("review", "needs-review"));
+        assert_eq!(config.actions[0].tags, ["needs-review"]);
+        assert_eq!(config.hooks[1].tags, ["needs-review"]);
+        assert!(!config.rename_tag("missing", "other"));
+
+        config.views.get_mut("inbox").unwrap().group_by = Some(GroupBy::Field("pr".into()));
+        assert!(config.rename_field("pr", "pull"));
+        assert_eq!(config.actions[0].command, "xdg-open {field.pull}");
+        assert_eq!(
+            config.views["inbox"].group_by,
+            Some(GroupBy::Field("pull".into()))
+        );
+    }
+
+    #[test]
+    fn actions_are_filtered_by_kind_and_tag() {
+        let config = Config::load_from_reader(io::Cursor::new(CONFIG_STRING)).unwrap();
+        let mut galaxy = Galaxy::default();
+        galaxy.comet();
+        let body = galaxy.get(0).unwrap();
+
+        let names: Vec<_> = config.actions_for(&body).map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["Echo"]);
+    }
+}
@@ -0,0 +1,457 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the importer for CSV exports of other trackers (e.g. Jira, Linear,
+ * or Trello).
+ *
+ * Since every tracker names its columns differently, a `Mapping` says which
+ * columns hold the title, status, labels, and so on. Rows that are the parent
+ * of another row become `Star`s and all other rows become `Planet`s, placed
+ * under a new `Star` for the file.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashMap, fs, path::Path};
+
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Deserialize;
+
+use crate::{
+    core::{CelestialBody, Galaxy, Priority, Status, ID},
+    util::progress::Reporter,
+};
+
+use super::{
+    cli::ValueEnum,
+    import::{ImportError, Importer, Summary},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The formats due dates are tried in if the mapping has no `due_format`
+const DATE_FORMATS: [&str; 5] = ["%Y-%m-%d", "%m/%d/%Y", "%d/%b/%y", "%d %b %Y", "%b %d, %Y"];
+
+/// The statuses of common trackers that are recognized without a mapping
+const STATUSES: [(&str, Status); 13] = [
+    ("to do", Status::Todo),
+    ("backlog", Status::Todo),
+    ("open", Status::Todo),
+    ("selected for development", Status::Next),
+    ("in progress", Status::Start),
+    ("in review", Status::Start),
+    ("blocked", Status::Block),
+    ("on hold", Status::Hold),
+    ("closed", Status::Done),
+    ("resolved", Status::Done),
+    ("completed", Status::Done),
+    ("canceled", Status::Cancel),
+    ("won't do", Status::Cancel),
+];
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Which columns of a CSV file hold what. Columns are matched by their header,
+/// ignoring case, and only `title` is required to exist.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Mapping {
+    pub title: String,
+    pub description: Option<String>,
+    /// Identifies the row for `parent`, e.g. `Issue key` in Jira
+    pub key: Option<String>,
+    /// Holds the key of the row the row belongs to
+    pub parent: Option<String>,
+    pub status: Option<String>,
+    /// Maps the values of `status` to statuses, for values that are neither a
+    /// status nor used by common trackers (e.g. `"QA": "start"`)
+    pub statuses: HashMap<String, String>,
+    pub priority: Option<String>,
+    /// Maps the values of `priority` to priorities (e.g. `"Highest":
+    /// "critical"`)
+    pub priorities: HashMap<String, String>,
+    /// Holds the labels, which become tags. Every column with this header is
+    /// used, since some trackers repeat the column for every label.
+    pub labels: Option<String>,
+    /// Separates multiple labels within one cell
+    pub separator: String,
+    pub due: Option<String>,
+    /// The `strftime` format of `due`, tried before the common formats
+    pub due_format: Option<String>,
+    /// Maps field names to the columns holding their values
+    pub fields: HashMap<String, String>,
+}
+
+impl Default for Mapping {
+    fn default() -> Self {
+        Self {
+            title: "Title".into(),
+            description: Some("Description".into()),
+            key: Some("ID".into()),
+            parent: Some("Parent".into()),
+            status: Some("Status".into()),
+            statuses: HashMap::new(),
+            priority: Some("Priority".into()),
+            priorities: HashMap::new(),
+            labels: Some("Labels".into()),
+            separator: ",".into(),
+            due: Some("Due Date".into()),
+            due_format: None,
+            fields: HashMap::new(),
+        }
+    }
+}
+
+impl Mapping {
+    /// Reads a mapping from the JSON file at `path`
+    pub fn load(path: &Path) -> Result<Self, ImportError> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| ImportError::InvalidFile(format!("{}: {e}", path.display())))?;
+        serde_json::from_str(&text)
+            .map_err(|e| ImportError::InvalidFile(format!("{}: {e}", path.display())))
+    }
+}
+
+/// Imports the rows of a CSV file according to `mapping`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Csv {
+    pub mapping: Mapping,
+}
+
+impl Importer for Csv {
+    /// Imports the rows of the CSV file with the contents `source`, which
+    /// needs a header row
+    fn import(
+        &self,
+        galaxy: &mut Galaxy,
+        source: &str,
+        progress: &mut dyn Reporter,
+    ) -> Result<Summary, ImportError> {
+        let mut records = records(source)?.into_iter();
+        let header = records.next().unwrap_or_default();
+        let rows: Vec<Row> = records
+            .enumerate()
+            .filter(|(_, record)| record.iter().any(|cell| !cell.trim().is_empty()))
+            // The header is the first line, and lines are counted from 1
+            .map(|(index, record)| self.row(&header, &record, index + 2))
+            .collect::<Result<_, _>>()?;
+
+        let root = galaxy.star().title("Imported from CSV".into()).id();
+        let mut summary = Summary {
+            root,
+            stars: 1,
+            planets: 0,
+        };
+
+        progress.start("Adding rows", Some(rows.len() as u64));
+        let parents: Vec<&str> = rows
+            .iter()
+            .filter_map(|row| row.parent.as_deref())
+            .collect();
+        let mut ids: HashMap<&str, ID> = HashMap::new();
+        let mut created = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let is_parent = row.key.as_deref().is_some_and(|key| parents.contains(&key));
+            let id = if is_parent {
+                summary.stars += 1;
+                let star = galaxy.star().title(row.title.clone());
+                star.description(row.description.clone());
+                star.id()
+            } else {
+                summary.planets += 1;
+                let planet = galaxy.planet().title(row.title.clone());
+                planet.description(row.description.clone());
+                planet.id()
+            };
+
+            if let Some(mut body) = galaxy.get_mut(id) {
+                if let Some(status) = row.status {
+                    body.status(status, "Imported from CSV".into());
+                }
+                body.priority(row.priority);
+                for tag in &row.labels {
+                    body.tag(tag.clone());
+                }
+                for (key, value) in &row.fields {
+                    body.field(key.clone(), value.clone());
+                }
+            }
+            if let Some(key) = &row.key {
+                ids.insert(key, id);
+            }
+            created.push(id);
+            progress.advance(1);
+        }
+        progress.finish();
+
+        // Parents are placed once every row exists, since they can come after
+        // their children
+        for (row, id) in rows.iter().zip(created) {
+            let parent = row.parent.as_deref().and_then(|key| ids.get(key));
+            galaxy.set_parent(id, Some(parent.copied().unwrap_or(root)))?;
+        }
+
+        Ok(summary)
+    }
+}
+
+impl Csv {
+    /// Helper function that reads the mapped columns of `record`, which is on
+    /// line `line` of the file
+    fn row(&self, header: &[String], record: &[String], line: usize) -> Result<Row, ImportError> {
+        let mapping = &self.mapping;
+        let invalid = |message: String| ImportError::InvalidFile(format!("line {line}: {message}"));
+        let cells = |column: &str| cells(header, record, column).into_iter();
+        let cell = |column: &Option<String>| {
+            column
+                .as_deref()
+                .and_then(|column| cells(column).next())
+                .map(String::from)
+        };
+
+        let title = cells(&mapping.title)
+            .next()
+            .ok_or_else(|| invalid(format!("no value in column \"{}\"", mapping.title)))?;
+
+        let status = match cell(&mapping.status) {
+            Some(value) => Some(
+                mapped(&mapping.statuses, &value, &STATUSES)
+                    .ok_or_else(|| invalid(format!("unknown status \"{value}\"")))?,
+            ),
+            None => None,
+        };
+        let priority = match cell(&mapping.priority) {
+            Some(value) => mapped(&mapping.priorities, &value, &[])
+                .ok_or_else(|| invalid(format!("unknown priority \"{value}\"")))?,
+            None => Priority::None,
+        };
+
+        let mut labels: Vec<String> = Vec::new();
+        if let Some(column) = &mapping.labels {
+            for label in cells(column).flat_map(|cell| cell.split(mapping.separator.as_str())) {
+                let label = label.trim();
+                if !label.is_empty() && !labels.iter().any(|l| l == label) {
+                    labels.push(label.into());
+                }
+            }
+        }
+
+        let mut fields: Vec<(String, String)> = mapping
+            .fields
+            .iter()
+            .filter_map(|(field, column)| Some((field.clone(), cells(column).next()?.into())))
+            .collect();
+        fields.sort();
+        if let Some(value) = cell(&mapping.due) {
+            let due = date(&value, mapping.due_format.as_deref())
+                .ok_or_else(|| invalid(format!("invalid due date \"{value}\"")))?;
+            fields.push(("due".into(), due.format("%Y-%m-%d").to_string()));
+        }
+
+        Ok(Row {
+            title: title.into(),
+            description: cell(&mapping.description).unwrap_or_default(),
+            key: cell(&mapping.key),
+            parent: cell(&mapping.parent),
+            status,
+            priority,
+            labels,
+            fields,
+        })
+    }
+}
+
+/// The mapped values of a single row
+#[derive(Debug)]
+struct Row {
+    title: String,
+    description: String,
+    key: Option<String>,
+    parent: Option<String>,
+    status: Option<Status>,
+    priority: Priority,
+    labels: Vec<String>,
+    fields: Vec<(String, String)>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that splits `source` into records of cells, following RFC
+/// 4180: cells may be quoted, and quoted cells may contain commas, newlines,
+/// and doubled quotes
+fn records(source: &str) -> Result<Vec<Vec<String>>, ImportError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = source.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, c) => cell.push(c),
+            (false, '"') if cell.is_empty() => quoted = true,
+            (false, ',') => record.push(std::mem::take(&mut cell)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut cell));
+                records.push(std::mem::take(&mut record));
+            }
+            (false, c) => cell.push(c),
+        }
+    }
+    if quoted {
+        return Err(ImportError::InvalidFile("unterminated quoted cell".into()));
+    }
+    if !cell.is_empty() || !record.is_empty() {
+        record.push(cell);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Helper function that finds the non-empty cells of `record` in every column
+/// named `column`
+fn cells<'a>(header: &[String], record: &'a [String], column: &str) -> Vec<&'a str> {
+    header
+        .iter()
+        .zip(record)
+        .filter(|(name, _)| name.trim().eq_ignore_ascii_case(column.trim()))
+        .map(|(_, cell)| cell.trim())
+        .filter(|cell| !cell.is_empty())
+        .collect()
+}
+
+/// Helper function that maps `value` with `map`, falling back to `defaults`
+/// and to the names of the values themselves (e.g. `done`), ignoring case
+fn mapped<T: ValueEnum + Copy>(
+    map: &HashMap<String, String>,
+    value: &str,
+    defaults: &[(&str, T)],
+) -> Option<T> {
+    let value = map
+        .iter()
+        .find(|(from, _)| from.eq_ignore_ascii_case(value))
+        .map_or(value, |(_, to)| to.as_str());
+    defaults
+        .iter()
+        .find(|(from, _)| from.eq_ignore_ascii_case(value))
+        .map(|(_, to)| *to)
+        .or_else(|| T::from_str(value, true).ok())
+}
+
+/// Helper function that parses a due date, which may also have a time
+fn date(value: &str, format: Option<&str>) -> Option<NaiveDate> {
+    format
+        .into_iter()
+        .chain(DATE_FORMATS)
+        .find_map(|format| {
+            NaiveDate::parse_from_str(value, format)
+                .or_else(|_| NaiveDateTime::parse_from_str(value, format).map(|t| t.date()))
+                .ok()
+        })
+        // ISO 8601 timestamps, e.g. `2025-03-01T12:00:00.000Z`
+        .or_else(|| NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::progress::Silent;
+
+    const JIRA: &str = "Summary,Issue key,Parent,Status,Priority,Labels,Labels,Due date,Description
+Fix login,WEB-2,WEB-1,In Progress,Highest,auth,backend,01/Mar/25 5:00 PM,\"Fails with \"\"401\"\",
+sometimes\"
+Launch,WEB-1,,QA,Medium,,,,
+Update docs,WEB-3,,Done,Low,docs,,,
+";
+
+    #[test]
+    fn rows_are_mapped_to_celestial_bodies() {
+        let mapping = Mapping {
+            title: "Summary".into(),
+            key: Some("Issue key".into()),
+            statuses: HashMap::from([("QA".into(), "hold".into())]),
+            priorities: HashMap::from([("Highest".into(), "critical".into())]),
+            due: Some("Due date".into()),
+            due_format: Some("%d/%b/%y %I:%M %p".into()),
+            fields: HashMap::from([("jira".into(), "Issue key".into())]),
+            ..Default::default()
+        };
+        let csv = Csv { mapping };
+        let mut galaxy = Galaxy::default();
+        let summary = csv.import(&mut galaxy, JIRA, &mut Silent).unwrap();
+        assert_eq!((summary.stars, summary.planets), (2, 2));
+
+        let root = galaxy.get(summary.root).unwrap();
+        let launch = galaxy.get(root.children()[0]).unwrap();
+        assert_eq!(launch.title(), "Launch");
+        assert_eq!(launch.status(), Status::Hold);
+        let login = galaxy.get(launch.children()[0]).unwrap();
+        assert_eq!(login.title(), "Fix login");
+        assert_eq!(login.description(), "Fails with \"401\",\nsometimes");
+        assert_eq!(login.status(), Status::Start);
+        assert_eq!(login.priority(), Priority::Critical);
+        assert_eq!(login.tags(), &["auth".to_string(), "backend".to_string()]);
+        assert_eq!(login.due(), NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert_eq!(login.value("jira").as_deref(), Some("WEB-2"));
+        let docs = galaxy.get(root.children()[1]).unwrap();
+        assert_eq!(docs.status(), Status::Done);
+
+        let unknown = "Title,Status\nA,Triage\n";
+        assert!(matches!(
+            Csv::default().import(&mut galaxy, unknown, &mut Silent),
+            Err(ImportError::InvalidFile(_))
+        ));
+    }
+}
@@ -0,0 +1,319 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Before / after diffs of the changes made by a CLI command.
+ *
+ * A `Snapshot` is taken before a command runs, so that the `Summary` of
+ * everything recorded since (see `core::Snapshot`) can be printed as a concise
+ * colored diff afterwards. The same format is used for the `Comparison` of two
+ * database files by `planit diff`.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{
+    core::{self, Change, Comparison, FieldChange, Galaxy, Summary},
+    util::theme::Themed,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether diffs should be printed, set once from the `--quiet` argument
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The state of every celestial body at the time the snapshot was taken
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    snapshot: core::Snapshot,
+}
+
+impl Snapshot {
+    /// Takes a snapshot of every celestial body in `galaxy`
+    pub fn take(galaxy: &Galaxy) -> Self {
+        Self {
+            snapshot: core::Snapshot::take(galaxy),
+        }
+    }
+
+    /// Prints everything that changed in `galaxy` since the snapshot was
+    /// taken, unless diffs have been silenced with `--quiet`
+    pub fn print(&self, galaxy: &Galaxy) -> io::Result<()> {
+        if QUIET.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        self.write(galaxy, &mut io::stdout())
+    }
+
+    /// Writes everything that changed in `galaxy` since the snapshot was taken
+    /// to `w`. Celestial bodies created since are only listed.
+    pub fn write<W: io::Write>(&self, galaxy: &Galaxy, w: &mut W) -> io::Result<()> {
+        write_summary(galaxy, &self.snapshot.changes(galaxy), w)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Writes `summary` as a colored diff to `w`. Created and removed celestial
+/// bodies are only listed.
+pub fn write_summary<W: io::Write>(
+    galaxy: &Galaxy,
+    summary: &Summary,
+    w: &mut W,
+) -> io::Result<()> {
+    let header = |id: u64, title: &str| {
+        format!("{title} {}", format!("({})", galaxy.display_id(id)).muted())
+    };
+
+    let mut bodies: Vec<_> = galaxy.iter().collect();
+    bodies.sort_by_key(|body| body.id());
+    for body in bodies {
+        if summary.created.contains(&body.id()) {
+            writeln!(w, "{} {}", "+".success(), header(body.id(), body.title()))?;
+            continue;
+        }
+        let Some(changes) = summary.changed.iter().find(|c| c.id == body.id()) else {
+            continue;
+        };
+        writeln!(w, "{} {}", "~".warning(), header(body.id(), body.title()))?;
+        for change in &changes.statuses {
+            writeln!(
+                w,
+                "    status: {} → {}",
+                change.old_status().to_string().error(),
+                change.new_status().to_string().success()
+            )?;
+        }
+        for event in &changes.events {
+            writeln!(w, "    {}", describe(galaxy, &event.change))?;
+        }
+    }
+    for (id, title) in &summary.removed {
+        writeln!(w, "{} {}", "-".error(), header(*id, title))?;
+    }
+
+    Ok(())
+}
+
+/// Writes `comparison` as a colored diff to `w`, looking up the celestial
+/// bodies that were removed in `old` and all others in `new`
+pub fn write_comparison<W: io::Write>(
+    old: &Galaxy,
+    new: &Galaxy,
+    comparison: &Comparison,
+    w: &mut W,
+) -> io::Result<()> {
+    let header = |galaxy: &Galaxy, id: u64, title: &str| {
+        format!("{title} {}", format!("({})", galaxy.display_id(id)).muted())
+    };
+
+    if !comparison.galaxy.is_empty() {
+        writeln!(w, "{} Galaxy", "~".warning())?;
+        for change in &comparison.galaxy {
+            write_field(change, w)?;
+        }
+    }
+    for body in &comparison.added {
+        let header = header(new, body.id, &body.title);
+        writeln!(
+            w,
+            "{} {header} {}",
+            "+".success(),
+            body.kind.to_string().muted()
+        )?;
+    }
+    for body in &comparison.modified {
+        writeln!(w, "{} {}", "~".warning(), header(new, body.id, &body.title))?;
+        for change in &body.changes {
+            write_field(change, w)?;
+        }
+    }
+    for body in &comparison.removed {
+        let header = header(old, body.id, &body.title);
+        writeln!(
+            w,
+            "{} {header} {}",
+            "-".error(),
+            body.kind.to_string().muted()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Silences (or re-enables) the diffs printed by `Snapshot::print`
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Helper function that writes a changed value as colored diff lines. Lists
+/// (e.g. the tags) are written as the items that were added and removed.
+fn write_field<W: io::Write>(change: &FieldChange, w: &mut W) -> io::Result<()> {
+    let name = change.field.strip_suffix('s').unwrap_or(&change.field);
+    let items = |v: &Option<serde_json::Value>| -> Option<Vec<String>> {
+        match v {
+            None => Some(Vec::new()),
+            Some(serde_json::Value::Array(items)) => Some(items.iter().map(show).collect()),
+            Some(_) => None,
+        }
+    };
+    if let (Some(old), Some(new)) = (items(&change.old), items(&change.new)) {
+        for item in old.iter().filter(|i| !new.contains(i)) {
+            writeln!(w, "    {} {name} {item}", "-".error())?;
+        }
+        for item in new.iter().filter(|i| !old.contains(i)) {
+            writeln!(w, "    {} {name} {item}", "+".success())?;
+        }
+        return Ok(());
+    }
+
+    let or_unset = |v: &Option<serde_json::Value>| match v {
+        Some(serde_json::Value::String(s)) if change.field == "title" => format!("\"{s}\""),
+        Some(v) => show(v),
+        None => "unset".to_string(),
+    };
+    writeln!(
+        w,
+        "    {}: {} → {}",
+        change.field,
+        or_unset(&change.old).error(),
+        or_unset(&change.new).success()
+    )
+}
+
+/// Helper function that shows a value from the database, without quoting
+/// strings
+fn show(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Helper function that describes a single change as a colored diff line
+fn describe(galaxy: &Galaxy, change: &Change) -> String {
+    let diff = |name: &str, old: String, new: String| {
+        format!("{name}: {} → {}", old.error(), new.success())
+    };
+    let or = |value: Option<String>, default: &str| value.unwrap_or_else(|| default.to_string());
+
+    match change {
+        Change::Title { old, new } => diff("title", format!("\"{old}\""), format!("\"{new}\"")),
+        Change::Parent { old, new } => diff(
+            "parent",
+            or(old.map(|id| galaxy.display_id(id)), "root"),
+            or(new.map(|id| galaxy.display_id(id)), "root"),
+        ),
+        Change::Priority { old, new } => diff("priority", old.to_string(), new.to_string()),
+        Change::Estimate { old, new } => diff(
+            "estimate",
+            or(old.map(|e| e.to_string()), "none"),
+            or(new.map(|e| e.to_string()), "none"),
+        ),
+        Change::TagAdded(tag) => format!("{} tag {tag}", "+".success()),
+        Change::TagRemoved(tag) => format!("{} tag {tag}", "-".error()),
+        Change::Assigned(name) => format!("{} assignee {name}", "+".success()),
+        Change::Unassigned(name) => format!("{} assignee {name}", "-".error()),
+        Change::Linked(target) => format!("{} link {target}", "+".success()),
+        Change::Unlinked(target) => format!("{} link {target}", "-".error()),
+        Change::Committed(hash) => {
+            format!("{} commit {}", "+".success(), &hash[..hash.len().min(7)])
+        }
+        Change::Reason(Some(reason)) => format!("{} reason {reason}", "+".success()),
+        Change::Reason(None) => format!("{} reason", "-".error()),
+        Change::Stage(Some(stage)) => format!("{} status {stage}", "+".success()),
+        Change::Stage(None) => format!("{} custom status", "-".error()),
+        Change::ChecklistAdded(text) => format!("{} item {text}", "+".success()),
+        Change::ChecklistRemoved(text) => format!("{} item {text}", "-".error()),
+        Change::Checked(text) => diff(&format!("item {text}"), "[ ]".into(), "[x]".into()),
+        Change::Unchecked(text) => diff(&format!("item {text}"), "[x]".into(), "[ ]".into()),
+        Change::Field { key, old, new } => diff(
+            &format!("field {key}"),
+            or(old.clone(), "unset"),
+            or(new.clone(), "unset"),
+        ),
+        Change::TagRenamed { old, new } => diff("tag", old.clone(), new.clone()),
+        Change::FieldRenamed { old, new } => diff("field", old.clone(), new.clone()),
+        change => change.to_string().to_lowercase().warning().to_string(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::core::{CelestialBody, Priority, Status};
+
+    use super::*;
+
+    #[test]
+    fn only_changes_since_the_snapshot_are_written() {
+        colored::control::set_override(false);
+        let mut galaxy = Galaxy::default();
+        let changed = galaxy.planet().title("Changed".into()).id();
+        galaxy.planet().title("Untouched".into());
+        let snapshot = Snapshot::take(&galaxy);
+
+        let mut body = galaxy.get_mut(changed).unwrap();
+        body.status(Status::Done, String::new());
+        body.priority(Priority::High);
+        body.tag("backend".into());
+        galaxy.comet().title("New".into());
+
+        let mut diff = Vec::new();
+        snapshot.write(&galaxy, &mut diff).unwrap();
+        assert_eq!(
+            String::from_utf8(diff).unwrap(),
+            "~ Changed (#0)\n    status: Todo → Done\n    priority: None → High\n    + tag backend\n+ New (#2)\n"
+        );
+    }
+}
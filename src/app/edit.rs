@@ -0,0 +1,427 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the implementation of interactively editing many celestial bodies
+ * at once as lines of text, similar to an interactive rebase.
+ *
+ * Every celestial body is written as a single line containing its ID, status,
+ * priority, title, and tags:
+ *
+ * ``` text
+ * #12  todo   high  Fix the login page | backend, urgent
+ * ```
+ *
+ * Lines starting with `# ` are comments. Removing a line leaves the celestial
+ * body unchanged. Every line is checked before any change is made, so either
+ * all of the changes are applied or none of them are.
+ *
+ * The title and description of a single celestial body can also be edited as
+ * Markdown, where the first heading is the title and everything after it is
+ * the description.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{env, fmt, fs, process};
+
+use crate::{
+    core::{CelestialBodyKind, Galaxy, Priority, Status},
+    util,
+};
+
+use super::{cli::ValueEnum, AppError};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Possible errors when reading the edited text. Most contain the (1-based)
+/// number of the line the error is on.
+#[derive(Debug, PartialEq, Eq)]
+pub enum EditError {
+    /// The line is missing the ID, status, priority, title, or `|`
+    MalformedLine(usize),
+    InvalidStatus(usize, String),
+    InvalidPriority(usize, String),
+    /// The ID does not refer to any celestial body
+    UnknownId(usize, String),
+    /// The celestial body already appeared on an earlier line
+    DuplicateId(usize, String),
+    /// Tags were given to a celestial body that cannot have tags
+    CannotTag(usize, String),
+    /// The edited Markdown does not start with a `# <title>` heading
+    MissingTitle,
+}
+
+impl std::error::Error for EditError {}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MalformedLine(line) => write!(
+                f,
+                "Line {line} is not in the form \"<id> <status> <priority> <title> | <tags>\""
+            ),
+            Self::InvalidStatus(line, status) => {
+                write!(f, "Line {line} has an invalid status: {status}")
+            }
+            Self::InvalidPriority(line, priority) => {
+                write!(f, "Line {line} has an invalid priority: {priority}")
+            }
+            Self::UnknownId(line, id) => write!(f, "Line {line} has an unknown ID: {id}"),
+            Self::DuplicateId(line, id) => write!(f, "Line {line} repeats the ID {id}"),
+            Self::CannotTag(line, id) => {
+                write!(f, "Line {line} gives tags to {id}, which cannot have tags")
+            }
+            Self::MissingTitle => write!(f, "The first line must be a \"# <title>\" heading"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single edited line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub id: u64,
+    pub status: Status,
+    pub priority: Priority,
+    pub title: String,
+    pub tags: Vec<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Writes the celestial bodies `ids` as editable lines, after a comment
+/// explaining how to edit them
+pub fn to_buffer(galaxy: &Galaxy, ids: &[u64]) -> String {
+    let mut rows = Vec::new();
+    for body in ids.iter().filter_map(|id| galaxy.get(*id)) {
+        rows.push([
+            galaxy.display_id(body.id()),
+            name(body.status()),
+            name(body.priority()),
+            format!("{} | {}", body.title(), body.tags().join(", "))
+                .trim_end()
+                .to_string(),
+        ]);
+    }
+    let width = |column: usize| rows.iter().map(|r| r[column].len()).max().unwrap_or(0);
+    let widths = [width(0), width(1), width(2)];
+
+    let mut buffer = format!(
+        "# Edit the celestial bodies below, then save and quit to apply the changes.\n\
+         # Each line is: <id> <status> <priority> <title> | <tags>\n\
+         # Statuses: {}\n\
+         # Priorities: {}\n\
+         # Tags are separated by commas. Only Planets can have tags.\n\
+         # Removing a line leaves the celestial body unchanged.\n\n",
+        names::<Status>(),
+        names::<Priority>(),
+    );
+    for [id, status, priority, rest] in rows {
+        buffer.push_str(&format!(
+            "{id:<0$}  {status:<1$}  {priority:<2$}  {rest}\n",
+            widths[0], widths[1], widths[2]
+        ));
+    }
+    buffer
+}
+
+/// Reads the edited lines in `buffer`, skipping blank lines and comments
+///
+/// # Errors
+/// Errors will occur if any line is not valid, see `EditError`
+pub fn parse(galaxy: &Galaxy, buffer: &str) -> Result<Vec<Entry>, EditError> {
+    let mut entries: Vec<Entry> = Vec::new();
+    for (number, line) in buffer.lines().enumerate().map(|(n, l)| (n + 1, l.trim())) {
+        if line.is_empty() || line == "#" || line.starts_with("# ") {
+            continue;
+        }
+
+        let malformed = || EditError::MalformedLine(number);
+        let (rest, tags) = line.rsplit_once('|').ok_or_else(malformed)?;
+        let (reference, rest) = word(rest).ok_or_else(malformed)?;
+        let (status, rest) = word(rest).ok_or_else(malformed)?;
+        let (priority, title) = word(rest).ok_or_else(malformed)?;
+        if title.is_empty() {
+            return Err(malformed());
+        }
+
+        let id = galaxy
+            .resolve(reference)
+            .map_err(|_| EditError::UnknownId(number, reference.into()))?;
+        if entries.iter().any(|e| e.id == id) {
+            return Err(EditError::DuplicateId(number, reference.into()));
+        }
+        let status = Status::from_str(status, true)
+            .map_err(|_| EditError::InvalidStatus(number, status.into()))?;
+        let priority = Priority::from_str(priority, true)
+            .map_err(|_| EditError::InvalidPriority(number, priority.into()))?;
+        let tags: Vec<String> = tags
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(String::from)
+            .collect();
+        let kind = galaxy.get(id).map(|body| body.kind());
+        if !tags.is_empty() && kind != Some(CelestialBodyKind::Planet) {
+            return Err(EditError::CannotTag(number, reference.into()));
+        }
+
+        entries.push(Entry {
+            id,
+            status,
+            priority,
+            title: title.to_string(),
+            tags,
+        });
+    }
+    Ok(entries)
+}
+
+/// Applies the edited `entries` to `galaxy`
+///
+/// # Returns
+/// The number of celestial bodies that changed
+pub fn apply(galaxy: &mut Galaxy, entries: Vec<Entry>) -> usize {
+    let mut changed = 0;
+    for entry in entries {
+        let Some(mut body) = galaxy.get_mut(entry.id) else {
+            continue;
+        };
+        let old = body.as_body_ref();
+        let (status, priority) = (old.status(), old.priority());
+        let title = old.title().to_string();
+        let tags = old.tags().to_vec();
+
+        let mut did_change = false;
+        if status != entry.status {
+            body.status(entry.status, String::new());
+            did_change = true;
+        }
+        if priority != entry.priority {
+            body.priority(entry.priority);
+            did_change = true;
+        }
+        if title != entry.title {
+            body.title(entry.title);
+            did_change = true;
+        }
+        for tag in tags.iter().filter(|t| !entry.tags.contains(t)) {
+            did_change |= body.untag(tag);
+        }
+        for tag in entry.tags.into_iter().filter(|t| !tags.contains(t)) {
+            did_change |= body.tag(tag);
+        }
+
+        if did_change {
+            changed += 1;
+        }
+    }
+    changed
+}
+
+/// Writes a title and description as Markdown, with the title as a heading
+pub fn to_markdown(title: &str, description: &str) -> String {
+    match description.trim_end() {
+        "" => format!("# {title}\n\n"),
+        description => format!("# {title}\n\n{description}\n"),
+    }
+}
+
+/// Reads the title and description from edited Markdown
+///
+/// # Errors
+/// Errors will occur if the first line that is not blank is not a heading
+pub fn parse_markdown(markdown: &str) -> Result<(String, String), EditError> {
+    let markdown = markdown.trim_start();
+    let (heading, description) = markdown.split_once('\n').unwrap_or((markdown, ""));
+    let title = heading
+        .strip_prefix("# ")
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .ok_or(EditError::MissingTitle)?;
+    let description = description.trim_start_matches(['\r', '\n']).trim_end();
+    Ok((title.to_string(), description.to_string()))
+}
+
+/// Opens `contents` in the user's editor as a temporary file with the
+/// extension `extension`, returning the edited contents once it is closed
+///
+/// # Errors
+/// Errors will occur if the file could not be written or read, or the editor
+/// failed
+pub fn in_editor(contents: &str, extension: &str) -> Result<String, AppError> {
+    let path = env::temp_dir().join(format!("planit-edit-{}.{extension}", process::id()));
+    fs::write(&path, contents)?;
+    let status = util::shell::editor(&path).status();
+    let edited = fs::read_to_string(&path);
+    let _ = fs::remove_file(&path);
+
+    let status = status?;
+    if !status.success() {
+        return Err(AppError::EditorFailed(status.code()));
+    }
+    Ok(edited?)
+}
+
+/// Helper function that splits the first whitespace separated word off of `s`
+fn word(s: &str) -> Option<(&str, &str)> {
+    let (word, rest) = s.trim().split_once(char::is_whitespace)?;
+    Some((word, rest.trim()))
+}
+
+/// Helper function that gives the name `value` is parsed from
+fn name<T: ValueEnum>(value: T) -> String {
+    value
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_default()
+}
+
+/// Helper function that lists every name of `T`, separated by commas
+fn names<T: ValueEnum>() -> String {
+    T::value_variants()
+        .iter()
+        .map(|v| name(v.clone()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::CelestialBody;
+
+    #[test]
+    fn edited_lines_are_applied() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().title("Release".into()).id();
+        let planet = galaxy.planet().title("Fix login".into()).id();
+        galaxy.get_mut(planet).unwrap().tag("backend".into());
+
+        let buffer = to_buffer(&galaxy, &[star, planet]);
+        assert!(buffer.ends_with(
+            "#0  todo  none  Release |\n\
+             #1  todo  none  Fix login | backend\n"
+        ));
+        assert_eq!(parse(&galaxy, &buffer).unwrap().len(), 2);
+
+        let edited = buffer.replace(
+            "#1  todo  none  Fix login | backend",
+            "#1 start high Fix the login page | urgent, ui",
+        );
+        let entries = parse(&galaxy, &edited).unwrap();
+        assert_eq!(apply(&mut galaxy, entries), 1);
+
+        let body = galaxy.get(planet).unwrap();
+        assert_eq!(body.status(), Status::Start);
+        assert_eq!(body.priority(), Priority::High);
+        assert_eq!(body.title(), "Fix the login page");
+        assert_eq!(body.tags(), ["urgent", "ui"]);
+        assert_eq!(galaxy.get(star).unwrap().title(), "Release");
+    }
+
+    #[test]
+    fn markdown_has_the_title_as_heading() {
+        let markdown = to_markdown("Fix login", "Steps:\n\n- open the page\n");
+        assert_eq!(markdown, "# Fix login\n\nSteps:\n\n- open the page\n");
+        assert_eq!(
+            parse_markdown(&markdown),
+            Ok(("Fix login".into(), "Steps:\n\n- open the page".into()))
+        );
+        assert_eq!(
+            parse_markdown("\n# Fix  \n"),
+            Ok(("Fix".into(), String::new()))
+        );
+        assert_eq!(parse_markdown("Fix login"), Err(EditError::MissingTitle));
+        assert_eq!(parse_markdown("## Fix"), Err(EditError::MissingTitle));
+    }
+
+    #[test]
+    fn invalid_lines_are_rejected() {
+        let mut galaxy = Galaxy::default();
+        galaxy.star().title("Release".into());
+        galaxy.planet().title("Fix login".into());
+
+        let parse = |buffer: &str| parse(&galaxy, buffer);
+        assert_eq!(
+            parse("# comment\n\n#1 todo"),
+            Err(EditError::MalformedLine(3))
+        );
+        assert_eq!(
+            parse("#1 doing none Title |"),
+            Err(EditError::InvalidStatus(1, "doing".into()))
+        );
+        assert_eq!(
+            parse("#1 todo urgent Title |"),
+            Err(EditError::InvalidPriority(1, "urgent".into()))
+        );
+        assert_eq!(
+            parse("#7 todo none Title |"),
+            Err(EditError::UnknownId(1, "#7".into()))
+        );
+        assert_eq!(
+            parse("#1 todo none A |\n#1 done none B |"),
+            Err(EditError::DuplicateId(2, "#1".into()))
+        );
+        assert_eq!(
+            parse("#0 todo none Release | tag"),
+            Err(EditError::CannotTag(1, "#0".into()))
+        );
+        assert_eq!(
+            parse("#1 todo none A | b |"),
+            Ok(vec![Entry {
+                id: 1,
+                status: Status::Todo,
+                priority: Priority::None,
+                title: "A | b".into(),
+                tags: vec![],
+            }])
+        );
+    }
+}
@@ -0,0 +1,97 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the exporters that write celestial bodies in the formats of other
+ * applications, e.g. calendars (see `core::Calendar`) or other task managers.
+ * Like imports, exports are one-way: they are written from scratch each time
+ * and never read back by the `Galaxy` they came from.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+
+use crate::core::{Calendar, Filter, Galaxy, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TRAITS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Something celestial bodies can be exported to
+pub trait Exporter {
+    /// Writes the celestial bodies matching `filter` in the format of the
+    /// exporter
+    fn export(&self, galaxy: &Galaxy, filter: &Filter) -> String;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Exports due dates, planned days, and milestones as an iCalendar file
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Ics {
+    /// Whether finished celestial bodies are exported as well
+    pub finished: bool,
+}
+
+impl Exporter for Ics {
+    fn export(&self, galaxy: &Galaxy, filter: &Filter) -> String {
+        Calendar::new(galaxy, filter, self.finished).to_ics(galaxy, Utc::now())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Finds the celestial bodies matching `filter` together with all of their
+/// ancestors, so that exports which keep the hierarchy can place them
+pub fn with_ancestors(galaxy: &Galaxy, filter: &Filter) -> HashSet<ID> {
+    let mut selected = HashSet::new();
+    for body in galaxy.iter().filter(|body| filter.matches(body)) {
+        let mut id = Some(body.id());
+        while let Some(current) = id {
+            if !selected.insert(current) {
+                break;
+            }
+            id = galaxy.get(current).and_then(|body| body.parent());
+        }
+    }
+    selected
+}
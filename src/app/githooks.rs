@@ -0,0 +1,288 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the git hooks installed by `planit hooks install`.
+ *
+ * The `commit-msg` hook checks that every celestial body referenced in the
+ * commit message (see `core::mentions`) exists, and adds a `Planit:` trailer
+ * for the celestial body whose time is being tracked. The hook runs a single
+ * `planit hooks commit-msg`, which does all of this in process, so committing
+ * stays fast however many celestial bodies are referenced.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::core::{self, Galaxy};
+
+use super::{AppError, Result};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The line that marks a hook as installed by planit, so that it is never
+/// overwritten or removed by accident
+const MARKER: &str = "# Installed by planit hooks install";
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Possible errors when installing or running the git hooks
+#[derive(Debug, PartialEq, Eq)]
+pub enum GitHookError {
+    /// The commit message references celestial bodies that do not exist
+    UnknownReferences(Vec<String>),
+    /// A hook that was not installed by planit is in the way
+    HookExists(PathBuf),
+}
+
+impl std::error::Error for GitHookError {}
+
+impl fmt::Display for GitHookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownReferences(references) => write!(
+                f,
+                "The commit message references unknown celestial bodies: {} (commit with \
+                 --no-verify to skip this check)",
+                references.join(", ")
+            ),
+            Self::HookExists(path) => write!(
+                f,
+                "{} was not installed by planit (use --force to replace it)",
+                path.display()
+            ),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Writes the `commit-msg` hook into the hooks directory of the git repository
+/// in the current directory, replacing a hook that was not installed by planit
+/// only if `force` is set
+///
+/// # Returns
+/// The path of the hook
+///
+/// # Errors
+/// Errors will occur if git fails, or another hook is in the way
+pub fn install(force: bool) -> Result<PathBuf> {
+    let path = hooks_dir()?.join("commit-msg");
+    if !force && fs::read_to_string(&path).is_ok_and(|hook| !hook.contains(MARKER)) {
+        return Err(GitHookError::HookExists(path).into());
+    }
+
+    let planit = env::current_exe()
+        .map(|exe| exe.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "planit".into());
+    // Git runs hooks with `sh` on every platform, so the path is quoted for it
+    let planit = format!("'{}'", planit.replace('\'', r"'\''"));
+    let hook = format!("#!/bin/sh\n{MARKER}\nexec {planit} hooks commit-msg \"$1\"\n");
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, hook)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(path)
+}
+
+/// Removes the `commit-msg` hook if it was installed by planit
+///
+/// # Returns
+/// The path of the removed hook, `None` if there was nothing to remove
+///
+/// # Errors
+/// Errors will occur if git fails, or another hook is in the way
+pub fn uninstall() -> Result<Option<PathBuf>> {
+    let path = hooks_dir()?.join("commit-msg");
+    match fs::read_to_string(&path) {
+        Ok(hook) if hook.contains(MARKER) => {
+            fs::remove_file(&path)?;
+            Ok(Some(path))
+        }
+        Ok(_) => Err(GitHookError::HookExists(path).into()),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Checks the commit `message` written by git and adds a `Planit:` trailer
+/// for the celestial body being tracked, unless the message already
+/// references it. Lines starting with `#` are comments that git removes, so
+/// they are ignored, and an empty message (which aborts the commit) is left
+/// empty.
+///
+/// # Returns
+/// The message to commit
+///
+/// # Errors
+/// Errors will occur if the message references celestial bodies that do not
+/// exist
+pub fn commit_msg(galaxy: &Galaxy, message: &str) -> std::result::Result<String, GitHookError> {
+    let lines: Vec<&str> = message.lines().collect();
+    let is_content = |line: &&str| !line.starts_with('#') && !line.trim().is_empty();
+    let text: Vec<&str> = lines
+        .iter()
+        .copied()
+        .filter(|line| !line.starts_with('#'))
+        .collect();
+
+    let mut referenced = Vec::new();
+    let mut unknown = Vec::new();
+    for mention in core::mentions(&text.join("\n")) {
+        match galaxy.resolve(&mention.reference) {
+            Ok(id) => referenced.push(id),
+            Err(_) => unknown.push(mention.reference),
+        }
+    }
+    if !unknown.is_empty() {
+        return Err(GitHookError::UnknownReferences(unknown));
+    }
+
+    let Some(end) = lines.iter().rposition(is_content) else {
+        return Ok(message.to_string());
+    };
+    let tracked = galaxy
+        .tracking()
+        .filter(|id| !referenced.contains(id))
+        .and_then(|id| galaxy.get(id));
+    let Some(tracked) = tracked else {
+        return Ok(message.to_string());
+    };
+
+    // The trailer joins the last paragraph if that is made of trailers
+    // already (e.g. `Signed-off-by: ...`), but never the subject
+    let start = lines[..=end]
+        .iter()
+        .rposition(|line| !is_content(line))
+        .map_or(0, |i| i + 1);
+    let joins = start > 0 && lines[start..=end].iter().all(|line| is_trailer(line));
+    let reference = match tracked.key() {
+        Some(key) => key.to_string(),
+        None => galaxy.display_id(tracked.id()),
+    };
+
+    let mut result: Vec<String> = lines[..=end].iter().map(|l| l.to_string()).collect();
+    if !joins {
+        result.push(String::new());
+    }
+    result.push(format!("Planit: {reference}"));
+    result.extend(lines[end + 1..].iter().map(|l| l.to_string()));
+    Ok(result.join("\n") + "\n")
+}
+
+/// Helper function that checks whether a line is a git trailer, e.g.
+/// `Signed-off-by: Jacob Long`
+fn is_trailer(line: &str) -> bool {
+    line.split_once(": ").is_some_and(|(token, _)| {
+        !token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+/// Helper function that finds the directory git runs hooks from, which may be
+/// moved by `core.hooksPath`
+fn hooks_dir() -> Result<PathBuf> {
+    let output = process::Command::new("git")
+        .args(["rev-parse", "--git-path", "hooks"])
+        .stderr(process::Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(AppError::GitFailed(output.status.code()));
+    }
+    let dir = String::from_utf8_lossy(&output.stdout);
+    Ok(Path::new(dir.trim()).to_path_buf())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use crate::core::CelestialBody;
+
+    use super::*;
+
+    #[test]
+    fn commit_messages_are_checked_and_get_the_tracked_body() {
+        let mut galaxy = Galaxy::default();
+        galaxy.planet().title("Login".into());
+        let logout = galaxy.planet().title("Logout".into()).id();
+
+        let message = "Fix login\n\nFixes #0\n# Please enter the commit message\n";
+        assert_eq!(commit_msg(&galaxy, message), Ok(message.to_string()));
+        assert_eq!(
+            commit_msg(&galaxy, "Fix #0 and #7\n# See #8\n"),
+            Err(GitHookError::UnknownReferences(vec!["#7".into()]))
+        );
+
+        galaxy.start_tracking(logout, Utc::now()).unwrap();
+        assert_eq!(
+            commit_msg(&galaxy, message),
+            Ok("Fix login\n\nFixes #0\n\nPlanit: #1\n# Please enter the commit message\n".into())
+        );
+        assert_eq!(
+            commit_msg(&galaxy, "Fix logout\n\nSigned-off-by: Jacob\n"),
+            Ok("Fix logout\n\nSigned-off-by: Jacob\nPlanit: #1\n".into())
+        );
+        assert_eq!(
+            commit_msg(&galaxy, "Fix logout (#1)"),
+            Ok("Fix logout (#1)".into())
+        );
+        assert_eq!(
+            commit_msg(&galaxy, "\n# Aborted\n"),
+            Ok("\n# Aborted\n".into())
+        );
+    }
+}
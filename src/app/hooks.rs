@@ -0,0 +1,113 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the `Dispatcher` that runs the hooks in the configuration. Shell
+ * commands are started in the background and not waited for, while URLs are
+ * notified before the command that saved the `Galaxy` finishes. Failing hooks
+ * are logged, but never stop the change that triggered them.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{process::Stdio, time::Duration};
+
+use log::warn;
+
+use crate::{
+    core::{Dispatcher, Notification},
+    util::shell,
+};
+
+use super::config::Hook;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Runs the hooks that apply to each notification
+#[derive(Debug)]
+pub struct Hooks {
+    hooks: Vec<Hook>,
+    agent: ureq::Agent,
+}
+
+impl Hooks {
+    /// How long a URL may take to answer before it is given up on
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    pub fn new(hooks: Vec<Hook>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Self::TIMEOUT))
+            .build()
+            .into();
+        Self { hooks, agent }
+    }
+
+    /// Helper function that starts the command of a hook. The notification is
+    /// also passed as JSON in `$PLANIT_NOTIFICATION`.
+    fn run(command: &str, notification: &Notification) {
+        let command = match shell::expand(command, |key| notification.value(key)) {
+            Ok(command) => command,
+            Err(e) => return warn!("Could not run hook \"{command}\": {e}"),
+        };
+        let json = serde_json::to_string(notification).unwrap_or_default();
+        let spawned = shell::shell(&command)
+            .env("PLANIT_NOTIFICATION", json)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        if let Err(e) = spawned {
+            warn!("Could not run hook \"{command}\": {e}");
+        }
+    }
+
+    /// Helper function that posts the notification to the URL of a hook
+    fn post(&self, url: &str, notification: &Notification) {
+        if let Err(e) = self.agent.post(url).send_json(notification) {
+            warn!("Could not notify {url}: {e}");
+        }
+    }
+}
+
+impl Dispatcher for Hooks {
+    fn dispatch(&self, notification: &Notification) {
+        for hook in self.hooks.iter().filter(|h| h.applies_to(notification)) {
+            if let Some(command) = &hook.command {
+                Self::run(command, notification);
+            }
+            if let Some(url) = &hook.url {
+                self.post(url, notification);
+            }
+        }
+    }
+}
@@ -0,0 +1,430 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains importers that bootstrap a `Galaxy` from external project trackers.
+ * Imports are one-way and read-only: the external tracker is never modified and
+ * re-running an import creates a new subtree rather than updating an old one.
+ *
+ * Every source implements `Importer`. For GitHub, open milestones become
+ * `Star`s and open issues become `Planet`s (with labels as tags), all placed
+ * under a new `Star` representing the repository. Files from other task
+ * managers are handled by the `org`, `taskwarrior`, and `csv` modules.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashMap, env, fmt};
+
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{
+    core::{CelestialBody, Galaxy, GalaxyError},
+    util::progress::Reporter,
+};
+
+use super::{cli::ValueEnum, csv::Csv, org::Org, taskwarrior::Taskwarrior};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TRAITS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Something celestial bodies can be imported from
+pub trait Importer {
+    /// Adds the celestial bodies described by `source` to `galaxy`. What
+    /// `source` is depends on the importer, e.g. a repository or the contents
+    /// of a file.
+    fn import(
+        &self,
+        galaxy: &mut Galaxy,
+        source: &str,
+        progress: &mut dyn Reporter,
+    ) -> Result<Summary, ImportError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// External trackers that can be imported from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Source {
+    /// Open issues and milestones of a GitHub repository
+    Github,
+    /// Headlines of an Emacs org-mode file
+    Org,
+    /// Tasks exported with `task export`
+    Taskwarrior,
+    /// Rows of a CSV file, e.g. exported from Jira, Linear, or Trello
+    Csv,
+}
+
+impl Source {
+    /// The importer for the source
+    pub fn importer(&self) -> Box<dyn Importer> {
+        match self {
+            Self::Github => Box::new(Github),
+            Self::Org => Box::new(Org),
+            Self::Taskwarrior => Box::new(Taskwarrior),
+            Self::Csv => Box::new(Csv::default()),
+        }
+    }
+
+    /// Whether the source is read from a file rather than a repository
+    pub fn is_file(&self) -> bool {
+        !matches!(self, Self::Github)
+    }
+}
+
+/// Possible errors when importing
+#[derive(Debug)]
+pub enum ImportError {
+    /// The repository is not in the form `owner/name`
+    InvalidRepository(String),
+    /// The imported file could not be parsed
+    InvalidFile(String),
+    /// An error occurred while making a request or parsing its response
+    HttpError(Box<ureq::Error>),
+    /// An error occurred while adding the imported data to the `Galaxy`
+    GalaxyError(GalaxyError),
+}
+
+impl std::error::Error for ImportError {}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::InvalidRepository(repo) => {
+                write!(f, "Invalid repository \"{repo}\", expected owner/name")
+            }
+            ImportError::InvalidFile(message) => write!(f, "Invalid file: {message}"),
+            ImportError::HttpError(e) => write!(f, "HTTP error: {e}"),
+            ImportError::GalaxyError(e) => write!(f, "Galaxy error: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for ImportError {
+    fn from(value: ureq::Error) -> Self {
+        Self::HttpError(Box::new(value))
+    }
+}
+
+impl From<GalaxyError> for ImportError {
+    fn from(value: GalaxyError) -> Self {
+        Self::GalaxyError(value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What was added to the `Galaxy` by an import
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    /// ID of the `Star` that all imported celestial bodies are placed under
+    pub root: u64,
+    pub stars: usize,
+    pub planets: usize,
+}
+
+/// Imports from a GitHub repository, see `github`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Github;
+
+impl Importer for Github {
+    fn import(
+        &self,
+        galaxy: &mut Galaxy,
+        source: &str,
+        progress: &mut dyn Reporter,
+    ) -> Result<Summary, ImportError> {
+        github(galaxy, source, progress)
+    }
+}
+
+/// A GitHub milestone, as returned by the REST API
+#[derive(Debug, Deserialize)]
+struct Milestone {
+    number: u64,
+    title: String,
+    description: Option<String>,
+    html_url: String,
+}
+
+/// A GitHub issue, as returned by the REST API
+#[derive(Debug, Deserialize)]
+struct Issue {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    html_url: String,
+    #[serde(default)]
+    labels: Vec<Label>,
+    milestone: Option<MilestoneRef>,
+    /// Only present if the issue is actually a pull request
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Label {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MilestoneRef {
+    number: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Imports the open milestones and issues of the GitHub repository `repo` into
+/// `galaxy`. The `GITHUB_TOKEN` environment variable is used for authentication
+/// if it is set, which is required for private repositories.
+///
+/// # Arguments
+/// - `galaxy`: The `Galaxy` to add the imported celestial bodies to
+/// - `repo`: The repository in the form `owner/name`
+/// - `progress`: Receives the progress of fetching and adding the items
+///
+/// # Errors
+/// Errors will occur in the following situations:
+/// - `repo` is not in the form `owner/name`
+/// - A request to GitHub fails or returns an unexpected response
+pub fn github(
+    galaxy: &mut Galaxy,
+    repo: &str,
+    progress: &mut dyn Reporter,
+) -> Result<Summary, ImportError> {
+    match repo.split_once('/') {
+        Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {}
+        _ => return Err(ImportError::InvalidRepository(repo.into())),
+    }
+
+    let token = env::var("GITHUB_TOKEN").ok();
+    let milestones: Vec<Milestone> = fetch_all(repo, "milestones", token.as_deref(), progress)?;
+    let issues: Vec<Issue> = fetch_all(repo, "issues", token.as_deref(), progress)?;
+
+    populate(galaxy, repo, milestones, issues, progress)
+}
+
+/// Helper function that fetches every page of open items from the GitHub REST
+/// API endpoint `/repos/<repo>/<endpoint>`
+fn fetch_all<T: DeserializeOwned>(
+    repo: &str,
+    endpoint: &str,
+    token: Option<&str>,
+    progress: &mut dyn Reporter,
+) -> Result<Vec<T>, ImportError> {
+    const PER_PAGE: usize = 100;
+
+    progress.start(&format!("Fetching {endpoint}"), None);
+    let mut items = Vec::new();
+    for page in 1.. {
+        let url = format!(
+            "https://api.github.com/repos/{repo}/{endpoint}?state=open&per_page={PER_PAGE}&page={page}"
+        );
+        let mut request = ureq::get(&url)
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "planit");
+        if let Some(token) = token {
+            request = request.header("Authorization", &format!("Bearer {token}"));
+        }
+
+        let page: Vec<T> = request.call()?.body_mut().read_json()?;
+        let done = page.len() < PER_PAGE;
+        progress.advance(page.len() as u64);
+        items.extend(page);
+        if done {
+            break;
+        }
+    }
+    progress.finish();
+
+    Ok(items)
+}
+
+/// Helper function that adds the fetched milestones and issues to `galaxy`
+/// under a new `Star` for `repo`
+fn populate(
+    galaxy: &mut Galaxy,
+    repo: &str,
+    milestones: Vec<Milestone>,
+    issues: Vec<Issue>,
+    progress: &mut dyn Reporter,
+) -> Result<Summary, ImportError> {
+    let root = galaxy
+        .star()
+        .title(repo.into())
+        .description(format!("Imported from https://github.com/{repo}"))
+        .id();
+    let mut summary = Summary {
+        root,
+        stars: 1,
+        planets: 0,
+    };
+
+    progress.start("Adding milestones", Some(milestones.len() as u64));
+    let mut milestone_ids = HashMap::new();
+    for milestone in milestones {
+        let star = galaxy.star().title(milestone.title);
+        if let Some(description) = milestone.description {
+            star.description(description);
+        }
+        let id = star.id();
+        galaxy.set_parent(id, Some(root))?;
+        milestone_ids.insert(milestone.number, id);
+        summary.stars += 1;
+        log::info!("Imported milestone {} from {}", milestone.html_url, repo);
+        progress.advance(1);
+    }
+    progress.finish();
+
+    let issues: Vec<_> = issues
+        .into_iter()
+        .filter(|i| i.pull_request.is_none())
+        .collect();
+    progress.start("Adding issues", Some(issues.len() as u64));
+
+    for issue in issues {
+        let planet = galaxy.planet().title(issue.title);
+        if let Some(body) = issue.body {
+            planet.description(body);
+        }
+        let id = planet.id();
+
+        if let Some(mut body) = galaxy.get_mut(id) {
+            for label in issue.labels {
+                body.tag(label.name);
+            }
+            body.field("github".into(), format!("#{}", issue.number));
+            body.field("url".into(), issue.html_url);
+        }
+
+        let parent = issue
+            .milestone
+            .and_then(|m| milestone_ids.get(&m.number).copied())
+            .unwrap_or(root);
+        galaxy.set_parent(id, Some(parent))?;
+        summary.planets += 1;
+        progress.advance(1);
+    }
+    progress.finish();
+
+    Ok(summary)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::progress::Silent;
+
+    const MILESTONES: &str = r#"[
+  { "number": 1, "title": "v1.0", "description": "First release", "html_url": "https://github.com/o/r/milestone/1" }
+]"#;
+
+    const ISSUES: &str = r#"[
+  {
+    "number": 10,
+    "title": "Crash on start",
+    "body": "It crashes",
+    "html_url": "https://github.com/o/r/issues/10",
+    "labels": [{ "name": "bug" }],
+    "milestone": { "number": 1 }
+  },
+  {
+    "number": 11,
+    "title": "Add docs",
+    "body": null,
+    "html_url": "https://github.com/o/r/issues/11",
+    "labels": [],
+    "milestone": null
+  },
+  {
+    "number": 12,
+    "title": "Fix crash",
+    "html_url": "https://github.com/o/r/pull/12",
+    "pull_request": { "url": "https://api.github.com/repos/o/r/pulls/12" }
+  }
+]"#;
+
+    #[test]
+    fn issues_and_milestones_are_placed_under_repository() {
+        let mut galaxy = Galaxy::default();
+        let milestones = serde_json::from_str(MILESTONES).unwrap();
+        let issues = serde_json::from_str(ISSUES).unwrap();
+
+        let summary = populate(&mut galaxy, "o/r", milestones, issues, &mut Silent).unwrap();
+        assert_eq!(summary.stars, 2);
+        assert_eq!(summary.planets, 2);
+
+        let root = galaxy.get(summary.root).unwrap();
+        assert_eq!(root.title(), "o/r");
+        assert_eq!(root.children().len(), 2);
+
+        let milestone = galaxy.get(root.children()[0]).unwrap();
+        assert_eq!(milestone.title(), "v1.0");
+        let crash = galaxy.get(milestone.children()[0]).unwrap();
+        assert_eq!(crash.title(), "Crash on start");
+        assert_eq!(crash.tags(), &["bug".to_string()]);
+        assert_eq!(crash.value("github").as_deref(), Some("#10"));
+
+        let docs = galaxy.get(root.children()[1]).unwrap();
+        assert_eq!(docs.title(), "Add docs");
+        assert_eq!(docs.description(), "");
+    }
+
+    #[test]
+    fn repository_must_have_owner_and_name() {
+        let mut galaxy = Galaxy::default();
+        for repo in ["planit", "/planit", "jac-oblong/", "a/b/c"] {
+            assert!(matches!(
+                github(&mut galaxy, repo, &mut Silent),
+                Err(ImportError::InvalidRepository(_))
+            ));
+        }
+    }
+}
@@ -36,7 +36,24 @@
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
+pub mod api;
+pub mod batch;
 pub mod cli;
+pub mod completions;
+pub mod config;
+pub mod csv;
+pub mod diff;
+pub mod edit;
+pub mod export;
+pub mod githooks;
+pub mod hooks;
+pub mod import;
+pub mod org;
+pub mod rpc;
+pub mod serve;
+pub mod split;
+pub mod taskwarrior;
+pub mod tui;
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -44,12 +61,32 @@ pub mod cli;
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
-use std::{env, io};
+use std::{
+    env,
+    io::{self, IsTerminal},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use chrono::Local;
+
+use log::warn;
 
 pub use cli::Cli;
 use cli::Commands;
 
-use crate::core::DatabaseError;
+use crate::{
+    core::{self, CelestialBodyRef, DatabaseError, FilterError, Galaxy, GalaxyError, Reminders},
+    util::{
+        glyphs::{self, GlyphSet},
+        shell::TemplateError,
+        theme::{self, Themed},
+        time,
+    },
+};
+use config::{Config, ConfigError};
+use edit::EditError;
+use githooks::GitHookError;
+use import::ImportError;
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -59,6 +96,15 @@ use crate::core::DatabaseError;
 
 type Result<T> = std::result::Result<T, AppError>;
 
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the current command is run with `--dry-run`
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
 //                                   ENUMS                                    //
@@ -70,13 +116,73 @@ type Result<T> = std::result::Result<T, AppError>;
 pub enum AppError {
     IoError(io::Error),
     DatabaseError(DatabaseError),
+    ConfigError(ConfigError),
+    FilterError(FilterError),
+    TemplateError(TemplateError),
+    InvalidArgument(String),
+    CelestialBodyNotFound(u64),
+    ActionNotFound(String),
+    RetroNotFound(String),
+    ActionFailed(String, Option<i32>),
+    ApiError(u16),
+    ImportError(ImportError),
+    GalaxyError(GalaxyError),
+    EditError(EditError),
+    EditorFailed(Option<i32>),
+    GitFailed(Option<i32>),
+    GitHookError(GitHookError),
+    ServeFailed(String),
+    BatchFailed(usize),
+    DoctorFailed(usize),
+    Stopped(tui::Signal),
 }
 
 impl std::fmt::Display for AppError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::IoError(e) => write!(f, "Error during IO operation: {e}"),
+            Self::DatabaseError(e @ DatabaseError::InvalidValue(_)) => write!(
+                f,
+                "Error during database operation: {e} (use --lenient to skip it)"
+            ),
             Self::DatabaseError(e) => write!(f, "Error during database operation: {e}"),
+            Self::ConfigError(e) => write!(f, "Error in config: {e}"),
+            Self::FilterError(e) => write!(f, "Error in filter: {e}"),
+            Self::TemplateError(e) => write!(f, "Error in template: {e}"),
+            Self::InvalidArgument(message) => write!(f, "Invalid argument: {message}"),
+            Self::CelestialBodyNotFound(id) => write!(f, "No celestial body with id {id}"),
+            Self::ActionNotFound(name) => write!(f, "No action named \"{name}\""),
+            Self::RetroNotFound(name) => write!(f, "Sprint \"{name}\" has no retrospective"),
+            Self::ActionFailed(name, Some(code)) => {
+                write!(f, "Action \"{name}\" failed with exit code {code}")
+            }
+            Self::ActionFailed(name, None) => write!(f, "Action \"{name}\" was terminated"),
+            Self::ApiError(status) => write!(f, "API request failed with status {status}"),
+            Self::ImportError(e) => write!(f, "Error during import: {e}"),
+            Self::GalaxyError(e) => write!(f, "Error in galaxy: {e}"),
+            Self::EditError(e) => write!(f, "Error in edited text: {e}"),
+            Self::EditorFailed(Some(code)) => write!(f, "Editor failed with exit code {code}"),
+            Self::EditorFailed(None) => write!(f, "Editor was terminated"),
+            Self::GitFailed(Some(code)) => write!(f, "git failed with exit code {code}"),
+            Self::GitFailed(None) => write!(f, "git was terminated"),
+            Self::GitHookError(e) => write!(f, "{e}"),
+            Self::ServeFailed(e) => write!(f, "Could not start the server: {e}"),
+            Self::BatchFailed(1) => write!(f, "1 command failed, nothing was saved"),
+            Self::BatchFailed(n) => write!(f, "{n} commands failed, nothing was saved"),
+            Self::DoctorFailed(1) => write!(f, "Found 1 problem in the database"),
+            Self::DoctorFailed(n) => write!(f, "Found {n} problems in the database"),
+            Self::Stopped(signal) => write!(f, "Stopped by {signal}"),
+        }
+    }
+}
+
+impl AppError {
+    /// The exit code of the application after the error, which follows the
+    /// shell convention for processes stopped by a signal
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Stopped(signal) => signal.exit_code(),
+            _ => 1,
         }
     }
 }
@@ -95,12 +201,108 @@ impl From<DatabaseError> for AppError {
     }
 }
 
+impl From<ConfigError> for AppError {
+    fn from(value: ConfigError) -> Self {
+        Self::ConfigError(value)
+    }
+}
+
+impl From<FilterError> for AppError {
+    fn from(value: FilterError) -> Self {
+        Self::FilterError(value)
+    }
+}
+
+impl From<TemplateError> for AppError {
+    fn from(value: TemplateError) -> Self {
+        Self::TemplateError(value)
+    }
+}
+
+impl From<GalaxyError> for AppError {
+    fn from(value: GalaxyError) -> Self {
+        Self::GalaxyError(value)
+    }
+}
+
+impl From<EditError> for AppError {
+    fn from(value: EditError) -> Self {
+        Self::EditError(value)
+    }
+}
+
+impl From<GitHookError> for AppError {
+    fn from(value: GitHookError) -> Self {
+        Self::GitHookError(value)
+    }
+}
+
+impl From<ImportError> for AppError {
+    fn from(value: ImportError) -> Self {
+        Self::ImportError(value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Asks on the terminal before the status rules finish a `Star`
+struct AskToComplete;
+
+impl core::Prompter for AskToComplete {
+    fn complete(&self, star: &CelestialBodyRef) -> bool {
+        if dry_running() {
+            return false;
+        }
+        eprint!(
+            "All children of \"{}\" are finished. Mark it as Done? [y/N] ",
+            star.title()
+        );
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer).is_ok()
+            && matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
 //                                 FUNCTIONS                                  //
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Helper function that prints how many celestial bodies are overdue or due
+/// today to stderr. Any errors are ignored, since the command will report them.
+fn remind_on_start() {
+    let Ok(galaxy) = Galaxy::load() else {
+        return;
+    };
+    let reminders = Reminders::of(&galaxy, Local::now().date_naive(), 0);
+    if let Some(summary) = cli::reminders_summary(&reminders) {
+        eprintln!("{}", format!("{summary} (see `planit remind`)").warning());
+    }
+}
+
+/// Helper function that prints the parts of the database that are skipped by
+/// `--lenient` to stderr. Any errors are ignored, since the command will
+/// report them.
+fn report_skipped() {
+    let Ok((_, issues)) = Galaxy::load_with_issues() else {
+        return;
+    };
+    for issue in &issues {
+        eprintln!("{} {issue}", "Warning:".warning());
+    }
+    if !issues.is_empty() {
+        eprintln!(
+            "{}",
+            "These are removed from the database once it is saved".warning()
+        );
+    }
+}
+
 /// Runs the application. Does not return until all operations are completed.
 ///
 /// # Arguments
@@ -113,15 +315,193 @@ pub fn run(args: Cli) -> Result<()> {
         env::set_current_dir(dir)?;
     }
 
+    diff::set_quiet(args.quiet);
+    theme::set_color(args.color);
+    if args.ascii {
+        glyphs::set(GlyphSet::Ascii);
+    }
+    // Errors in the config are reported by the commands that use it
+    let mut remind = false;
+    match Config::load() {
+        Ok(config) => {
+            remind = config.remind;
+            match config.theme() {
+                Ok(theme) => theme::set(theme),
+                Err(e) => warn!("Using the default theme: {e}"),
+            }
+            glyphs::set(config.glyphs.unwrap_or_default());
+            theme::set_tag_colors(config.tag_colors);
+            time::set_style(config.dates.unwrap_or_default());
+            if !config.hooks.is_empty() {
+                core::set_dispatcher(Box::new(hooks::Hooks::new(config.hooks)));
+            }
+        }
+        Err(e) => warn!("Using the default theme and glyphs: {e}"),
+    }
+
     match args.verbose {
         0 => {}
         _ => todo!(),
     }
 
-    match args.command {
+    // Commands that print for other programs or take over the terminal are
+    // not interrupted by reminders
+    let interactive = matches!(
+        args.command,
+        Some(
+            Commands::List(_)
+                | Commands::New(_)
+                | Commands::Show(_)
+                | Commands::Edit(_)
+                | Commands::Track(_)
+                | Commands::Today(_)
+                | Commands::Focus(_)
+                | Commands::Stats(_)
+        )
+    );
+    if remind && interactive && io::stderr().is_terminal() {
+        remind_on_start();
+    }
+
+    // The TUI asks about finishing Stars itself, and the API reads requests
+    // from stdin
+    let asks = !matches!(
+        args.command,
+        None | Some(Commands::Tui(_)) | Some(Commands::Api(_))
+    );
+    if asks && io::stdin().is_terminal() && io::stderr().is_terminal() {
+        core::set_prompter(Box::new(AskToComplete));
+    }
+
+    // The TUI reports what was skipped itself, since it clears the screen
+    Galaxy::set_lenient(args.lenient);
+    if args.lenient && !matches!(args.command, None | Some(Commands::Tui(_))) {
+        report_skipped();
+    }
+
+    match args.dry_run {
+        true => dry_run(args.command),
+        false => dispatch(args.command),
+    }
+}
+
+/// Helper function that runs `command` on a copy of the `Galaxy` in memory,
+/// then prints what it would have changed instead of saving it
+fn dry_run(command: Option<Commands>) -> Result<()> {
+    let Some(command) = command.filter(previewable) else {
+        return Err(AppError::InvalidArgument(
+            "--dry-run cannot be used with this command".into(),
+        ));
+    };
+
+    Galaxy::begin_batch()?;
+    let snapshot = core::Snapshot::take(&Galaxy::load()?);
+    DRY_RUN.store(true, Ordering::Relaxed);
+    diff::set_quiet(true);
+    config::set_read_only(true);
+    let result = dispatch(Some(command));
+    let galaxy = Galaxy::load();
+    Galaxy::end_batch(false)?;
+    result?;
+
+    let galaxy = galaxy?;
+    let summary = snapshot.changes(&galaxy);
+    match summary.is_empty() {
+        true => println!("{}", "Dry run: nothing would change".muted()),
+        false => {
+            println!("{}", "Dry run: nothing was saved".muted());
+            diff::write_summary(&galaxy, &summary, &mut io::stdout())?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether the current command is run with `--dry-run`, for commands that
+/// preview more than the changes themselves
+pub fn dry_running() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+/// Helper function that checks whether `command` can be run with
+/// `--dry-run`. This excludes commands that take over the terminal, run
+/// forever, run other programs, or work on files directly.
+fn previewable(command: &Commands) -> bool {
+    match command {
+        Commands::Init(_)
+        | Commands::Tui(_)
+        | Commands::Run(_)
+        | Commands::Convert(_)
+        | Commands::Merge(_)
+        | Commands::Sync => false,
+        #[cfg(feature = "serve")]
+        Commands::Serve(_) => false,
+        Commands::List(args) => !args.watch,
+        Commands::Api(args) => !args.rpc,
+        Commands::Hooks(_) => false,
+        Commands::Link(args) => !matches!(args.command, cli::LinkCommands::Open(_)),
+        _ => true,
+    }
+}
+
+/// Helper function that runs a single command, or the TUI if there is none
+fn dispatch(command: Option<Commands>) -> Result<()> {
+    match command {
         Some(Commands::Init(args)) => cli::init(args),
         Some(Commands::List(args)) => cli::list(args),
         Some(Commands::New(args)) => cli::new(args),
-        None => todo!(),
+        Some(Commands::Show(args)) => cli::show(args),
+        Some(Commands::Bulk(args)) => cli::bulk(args),
+        Some(Commands::Edit(args)) => cli::edit(args),
+        Some(Commands::Run(args)) => cli::run(args),
+        Some(Commands::Api(args)) => cli::api(args),
+        #[cfg(feature = "serve")]
+        Some(Commands::Serve(args)) => serve::run(&args.host, args.port, args.write),
+        Some(Commands::Export(args)) => cli::export(args),
+        Some(Commands::Import(args)) => cli::import(args),
+        Some(Commands::Retro(args)) => cli::retro(args),
+        Some(Commands::Legend) => cli::legend(),
+        Some(Commands::Track(args)) => cli::track(args),
+        Some(Commands::Split(args)) => cli::split(args),
+        Some(Commands::Tui(args)) => tui::run(args),
+        Some(Commands::Archive(args)) => cli::archive(args, true),
+        Some(Commands::Unarchive(args)) => cli::archive(args, false),
+        Some(Commands::Pin(args)) => cli::pin(args, true),
+        Some(Commands::Unpin(args)) => cli::pin(args, false),
+        Some(Commands::Move(args)) => cli::reorder(args),
+        Some(Commands::Snooze(args)) => cli::snooze(args),
+        Some(Commands::Unsnooze(args)) => cli::unsnooze(args),
+        Some(Commands::Delete(args)) => cli::delete(args),
+        Some(Commands::Trash(args)) => cli::trash(args),
+        Some(Commands::Tag(args)) => cli::tag(args),
+        Some(Commands::Field(args)) => cli::field(args),
+        Some(Commands::View(args)) => cli::view(args),
+        Some(Commands::Link(args)) => cli::link(args),
+        Some(Commands::Check(args)) => cli::check(args),
+        Some(Commands::KeyPrefix(args)) => cli::key_prefix(args),
+        Some(Commands::IdFormat(args)) => cli::id_format(args),
+        Some(Commands::IdScheme(args)) => cli::id_scheme(args),
+        Some(Commands::Rules(args)) => cli::rules(args),
+        Some(Commands::Workflow(args)) => cli::workflow(args),
+        Some(Commands::IdPrefix(args)) => cli::id_prefix(args),
+        Some(Commands::Today(args)) => cli::today(args),
+        Some(Commands::Focus(args)) => cli::focus(args),
+        Some(Commands::Remind(args)) => cli::remind(args),
+        Some(Commands::Sprint(args)) => cli::sprint(args),
+        Some(Commands::Batch(args)) => batch::run(args),
+        Some(Commands::Completions(args)) => cli::completions(args),
+        Some(Commands::Stats(args)) => cli::stats(args),
+        Some(Commands::Report(args)) => cli::report(args),
+        Some(Commands::Convert(args)) => cli::convert(args),
+        Some(Commands::Merge(args)) => cli::merge(args),
+        Some(Commands::Diff(args)) => cli::diff(args),
+        Some(Commands::Sync) => cli::sync(),
+        Some(Commands::Git(args)) => cli::git(args),
+        Some(Commands::Hooks(args)) => cli::hooks(args),
+        Some(Commands::Which) => cli::which(),
+        Some(Commands::Schema(args)) => cli::schema(args),
+        Some(Commands::Doctor) => cli::doctor(),
+        Some(Commands::Generate(args)) => cli::generate(args),
+        Some(Commands::CompleteIds) => cli::complete_ids(),
+        None => tui::run(cli::TuiArgs::default()),
     }
 }
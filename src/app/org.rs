@@ -0,0 +1,537 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the bridge to Emacs org-mode files.
+ *
+ * Headlines map to celestial bodies: their nesting becomes the hierarchy, TODO
+ * keywords become the `Status`, priority cookies (`[#A]`) the `Priority`, and
+ * tags, `DEADLINE`s, and property drawers become tags, due dates, and fields.
+ * Headlines with children are imported as `Star`s, all others as `Planet`s,
+ * so the tags, deadlines, and properties of headlines with children are
+ * dropped.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+
+use crate::{
+    core::{CelestialBody, Filter, Galaxy, Priority, Status, ID},
+    util::progress::Reporter,
+};
+
+use super::{
+    export::{self, Exporter},
+    import::{ImportError, Importer, Summary},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The property holding the ID of an exported celestial body, which is not
+/// imported as a field
+const ID_PROPERTY: &str = "PLANIT_ID";
+
+/// The TODO keywords that are recognized without a `#+TODO:` line
+const KEYWORDS: [(&str, Status); 10] = [
+    ("TODO", Status::Todo),
+    ("NEXT", Status::Next),
+    ("STARTED", Status::Start),
+    ("DOING", Status::Start),
+    ("WAITING", Status::Hold),
+    ("HOLD", Status::Hold),
+    ("BLOCKED", Status::Block),
+    ("DONE", Status::Done),
+    ("CANCELED", Status::Cancel),
+    ("CANCELLED", Status::Cancel),
+];
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Imports and exports org-mode headlines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Org;
+
+impl Importer for Org {
+    /// Imports the headlines of the org-mode file with the contents `source`
+    /// under a new `Star` named after its `#+TITLE`
+    fn import(
+        &self,
+        galaxy: &mut Galaxy,
+        source: &str,
+        progress: &mut dyn Reporter,
+    ) -> Result<Summary, ImportError> {
+        let document = Document::parse(source)?;
+
+        let title = document
+            .title
+            .unwrap_or_else(|| "Imported from org-mode".into());
+        let root = galaxy.star().title(title).id();
+        let mut summary = Summary {
+            root,
+            stars: 1,
+            planets: 0,
+        };
+
+        progress.start("Adding headlines", Some(document.headlines.len() as u64));
+        let mut ids: Vec<ID> = Vec::with_capacity(document.headlines.len());
+        for (index, headline) in document.headlines.iter().enumerate() {
+            let has_children = document.headlines.iter().any(|h| h.parent == Some(index));
+            let id = if has_children {
+                summary.stars += 1;
+                let star = galaxy.star().title(headline.title.clone());
+                star.description(headline.description());
+                star.id()
+            } else {
+                summary.planets += 1;
+                let planet = galaxy.planet().title(headline.title.clone());
+                planet.description(headline.description());
+                planet.id()
+            };
+
+            if let Some(mut body) = galaxy.get_mut(id) {
+                if let Some(status) = headline.status {
+                    body.status(status, "Imported from org-mode".into());
+                }
+                if let Some(priority) = headline.priority {
+                    body.priority(priority);
+                }
+                for tag in &headline.tags {
+                    body.tag(tag.clone());
+                }
+                if let Some(deadline) = headline.deadline {
+                    body.field("due".into(), deadline.format("%Y-%m-%d").to_string());
+                }
+                for (key, value) in &headline.properties {
+                    body.field(key.clone(), value.clone());
+                }
+            }
+
+            let parent = headline.parent.map(|p| ids[p]).unwrap_or(root);
+            galaxy.set_parent(id, Some(parent))?;
+            ids.push(id);
+            progress.advance(1);
+        }
+        progress.finish();
+
+        Ok(summary)
+    }
+}
+
+impl Exporter for Org {
+    /// Writes the celestial bodies matching `filter` (and their ancestors) as
+    /// nested headlines
+    fn export(&self, galaxy: &Galaxy, filter: &Filter) -> String {
+        let selected = export::with_ancestors(galaxy, filter);
+
+        let mut out = String::new();
+        if !galaxy.get_title().is_empty() {
+            out.push_str(&format!("#+TITLE: {}\n", galaxy.get_title()));
+        }
+        out.push_str("#+TODO: TODO NEXT STARTED WAITING BLOCKED | DONE CANCELED\n");
+
+        let mut roots: Vec<ID> = galaxy
+            .iter()
+            .filter(|body| body.parent().is_none() && selected.contains(&body.id()))
+            .map(|body| body.id())
+            .collect();
+        roots.sort();
+        for id in roots {
+            out.push('\n');
+            write_headline(galaxy, id, 1, &selected, &mut out);
+        }
+
+        out
+    }
+}
+
+/// The headlines of an org-mode file, in order
+#[derive(Debug, Default)]
+struct Document {
+    title: Option<String>,
+    headlines: Vec<Headline>,
+}
+
+impl Document {
+    /// Parses the contents of an org-mode file
+    fn parse(source: &str) -> Result<Self, ImportError> {
+        let mut keywords: HashMap<String, Status> = KEYWORDS
+            .iter()
+            .map(|(word, status)| (word.to_string(), *status))
+            .collect();
+        let mut document = Document::default();
+        let mut drawer: Option<String> = None;
+
+        for (number, line) in source.lines().enumerate() {
+            if let Some(headline) = Headline::parse(line, &keywords) {
+                drawer = None;
+                let parent = document
+                    .headlines
+                    .iter()
+                    .rposition(|h| h.level < headline.level);
+                document.headlines.push(Headline { parent, ..headline });
+                continue;
+            }
+
+            let Some(headline) = document.headlines.last_mut() else {
+                if let Some((key, value)) = setting(line) {
+                    match key.to_uppercase().as_str() {
+                        "TITLE" => document.title = Some(value.into()),
+                        "TODO" | "SEQ_TODO" | "TYP_TODO" => declare(&mut keywords, value),
+                        _ => {}
+                    }
+                }
+                continue;
+            };
+
+            let trimmed = line.trim();
+            if let Some(name) = &drawer {
+                if trimmed.eq_ignore_ascii_case(":END:") {
+                    drawer = None;
+                } else if name == "PROPERTIES" {
+                    let property = trimmed
+                        .strip_prefix(':')
+                        .and_then(|rest| rest.split_once(':'))
+                        .ok_or_else(|| {
+                            ImportError::InvalidFile(format!(
+                                "line {}: invalid property \"{trimmed}\"",
+                                number + 1
+                            ))
+                        })?;
+                    let (key, value) = (property.0.trim(), property.1.trim());
+                    if !key.eq_ignore_ascii_case(ID_PROPERTY) && !value.is_empty() {
+                        headline.properties.push((key.into(), value.into()));
+                    }
+                }
+            } else if headline.body.is_empty() && is_drawer(trimmed) {
+                drawer = Some(trimmed.trim_matches(':').to_uppercase());
+            } else if headline.body.is_empty() && is_planning(trimmed) {
+                headline.deadline = headline.deadline.or_else(|| deadline(trimmed));
+            } else {
+                headline.body.push(line.into());
+            }
+        }
+
+        if let Some(name) = drawer {
+            return Err(ImportError::InvalidFile(format!(
+                "drawer :{name}: is never closed"
+            )));
+        }
+        Ok(document)
+    }
+}
+
+/// A single headline of an org-mode file
+#[derive(Debug, Default)]
+struct Headline {
+    level: usize,
+    /// Index of the enclosing headline
+    parent: Option<usize>,
+    status: Option<Status>,
+    priority: Option<Priority>,
+    title: String,
+    tags: Vec<String>,
+    deadline: Option<NaiveDate>,
+    properties: Vec<(String, String)>,
+    /// The lines of text below the headline
+    body: Vec<String>,
+}
+
+impl Headline {
+    /// Parses a headline such as `** TODO [#A] Title :tag:`, returning `None`
+    /// if `line` is not a headline
+    fn parse(line: &str, keywords: &HashMap<String, Status>) -> Option<Self> {
+        let level = line.chars().take_while(|c| *c == '*').count();
+        let rest = &line[level..];
+        if level == 0 || !(rest.is_empty() || rest.starts_with(' ')) {
+            return None;
+        }
+
+        let mut headline = Headline {
+            level,
+            ..Default::default()
+        };
+        let mut rest = rest.trim();
+
+        let (word, after) = rest.split_once(' ').unwrap_or((rest, ""));
+        if let Some(status) = keywords.get(word) {
+            headline.status = Some(*status);
+            rest = after.trim_start();
+        }
+
+        let cookie = rest.strip_prefix("[#").and_then(|r| r.split_once(']'));
+        if let Some((letter, after)) = cookie {
+            headline.priority = match letter {
+                "A" => Some(Priority::High),
+                "B" => Some(Priority::Medium),
+                "C" => Some(Priority::Low),
+                _ => None,
+            };
+            rest = after.trim_start();
+        }
+
+        if let Some((title, tags)) = rest.rsplit_once(char::is_whitespace) {
+            let tags = tags.strip_prefix(':').and_then(|t| t.strip_suffix(':'));
+            if let Some(tags) = tags.filter(|t| !t.is_empty() && !t.contains("::")) {
+                headline.tags = tags.split(':').map(String::from).collect();
+                rest = title.trim_end();
+            }
+        }
+        headline.title = rest.into();
+
+        Some(headline)
+    }
+
+    /// The text below the headline with the common indentation removed
+    fn description(&self) -> String {
+        let indent = self
+            .body
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        let lines: Vec<&str> = self
+            .body
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or("").trim_end())
+            .collect();
+        lines.join("\n").trim_matches('\n').into()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that splits an in-buffer setting such as `#+TITLE: Name`
+fn setting(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.trim().strip_prefix("#+")?.split_once(':')?;
+    Some((key, value.trim()))
+}
+
+/// Helper function that adds the keywords declared by a `#+TODO:` line, where
+/// unknown keywords before `|` are `Todo` and unknown ones after it `Done`
+fn declare(keywords: &mut HashMap<String, Status>, line: &str) {
+    let mut status = Status::Todo;
+    for word in line.split_whitespace() {
+        if word == "|" {
+            status = Status::Done;
+            continue;
+        }
+        // Strip fast access keys and logging settings, e.g. `DONE(d!)`
+        let word = word.split('(').next().unwrap_or(word);
+        keywords.entry(word.into()).or_insert(status);
+    }
+}
+
+/// Helper function that checks whether `line` opens a drawer, e.g.
+/// `:PROPERTIES:` or `:LOGBOOK:`
+fn is_drawer(line: &str) -> bool {
+    line.len() > 2
+        && line.starts_with(':')
+        && line.ends_with(':')
+        && !line[1..line.len() - 1].contains([':', ' '])
+}
+
+/// Helper function that checks whether `line` is a planning line, e.g.
+/// `DEADLINE: <2025-01-31 Fri>`
+fn is_planning(line: &str) -> bool {
+    ["DEADLINE:", "SCHEDULED:", "CLOSED:"]
+        .iter()
+        .any(|keyword| line.starts_with(keyword))
+}
+
+/// Helper function that finds the date of the `DEADLINE` in a planning line
+fn deadline(line: &str) -> Option<NaiveDate> {
+    let (_, after) = line.split_once("DEADLINE:")?;
+    let date = after.trim_start().get(1..11)?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// Helper function that writes the celestial body `id` and its selected
+/// descendants as headlines of `level`
+fn write_headline(galaxy: &Galaxy, id: ID, level: usize, selected: &HashSet<ID>, out: &mut String) {
+    let Some(body) = galaxy.get(id) else {
+        return;
+    };
+
+    let keyword = match body.status() {
+        Status::Todo => "TODO",
+        Status::Next => "NEXT",
+        Status::Start => "STARTED",
+        Status::Hold => "WAITING",
+        Status::Block => "BLOCKED",
+        Status::Done => "DONE",
+        Status::Cancel => "CANCELED",
+    };
+    let mut line = format!("{} {keyword}", "*".repeat(level));
+    match body.priority() {
+        Priority::Critical | Priority::High => line.push_str(" [#A]"),
+        Priority::Medium => line.push_str(" [#B]"),
+        Priority::Low => line.push_str(" [#C]"),
+        Priority::None => {}
+    }
+    line.push(' ');
+    line.push_str(&body.title().replace('\n', " "));
+    if !body.tags().is_empty() {
+        let tags: Vec<String> = body.tags().iter().map(|tag| sanitize(tag)).collect();
+        line.push_str(&format!(" :{}:", tags.join(":")));
+    }
+    out.push_str(&line);
+    out.push('\n');
+
+    let indent = " ".repeat(level + 1);
+    if let Some(due) = body.due() {
+        out.push_str(&format!(
+            "{indent}DEADLINE: <{}>\n",
+            due.format("%Y-%m-%d %a")
+        ));
+    }
+
+    out.push_str(&format!("{indent}:PROPERTIES:\n"));
+    out.push_str(&format!(
+        "{indent}:{ID_PROPERTY}: {}\n",
+        galaxy.display_id(id)
+    ));
+    let mut fields: Vec<_> = body
+        .fields()
+        .into_iter()
+        .flatten()
+        .filter(|(key, _)| key.as_str() != "due")
+        .collect();
+    fields.sort();
+    for (key, value) in fields {
+        let value = value.replace('\n', " ");
+        out.push_str(&format!("{indent}:{}: {value}\n", sanitize(key)));
+    }
+    out.push_str(&format!("{indent}:END:\n"));
+
+    for line in body.description().lines() {
+        match line.trim_end() {
+            "" => out.push('\n'),
+            line => out.push_str(&format!("{indent}{line}\n")),
+        }
+    }
+
+    for child in body.children() {
+        if selected.contains(child) {
+            write_headline(galaxy, *child, level + 1, selected, out);
+        }
+    }
+}
+
+/// Helper function that replaces the characters org-mode does not allow in
+/// tags and property names
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            c if c.is_alphanumeric() || "_@#%-".contains(c) => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::progress::Silent;
+
+    const ORG: &str = "#+TITLE: Website
+#+TODO: TODO REVIEW | DONE SHIPPED(s)
+
+* TODO [#A] Launch :release:
+  Everything needed
+  for the launch.
+** DONE Write copy
+   DEADLINE: <2025-03-01 Sat>
+   :PROPERTIES:
+   :PLANIT_ID: 4
+   :owner: sam
+   :END:
+** REVIEW Pick a theme :design:ui:
+* SHIPPED Domain name
+";
+
+    #[test]
+    fn headlines_become_celestial_bodies() {
+        let mut galaxy = Galaxy::default();
+        let summary = Org.import(&mut galaxy, ORG, &mut Silent).unwrap();
+        assert_eq!((summary.stars, summary.planets), (2, 3));
+
+        let root = galaxy.get(summary.root).unwrap();
+        assert_eq!(root.title(), "Website");
+        let launch = galaxy.get(root.children()[0]).unwrap();
+        assert_eq!(launch.title(), "Launch");
+        assert_eq!(launch.status(), Status::Todo);
+        assert_eq!(launch.priority(), Priority::High);
+        assert_eq!(launch.description(), "Everything needed\nfor the launch.");
+
+        let copy = galaxy.get(launch.children()[0]).unwrap();
+        assert_eq!(copy.status(), Status::Done);
+        assert_eq!(copy.due(), NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert_eq!(copy.value("owner").as_deref(), Some("sam"));
+        assert_eq!(copy.value(ID_PROPERTY), None);
+        let theme = galaxy.get(launch.children()[1]).unwrap();
+        assert_eq!(theme.title(), "Pick a theme");
+        assert_eq!(theme.status(), Status::Todo);
+        assert_eq!(theme.tags(), &["design".to_string(), "ui".to_string()]);
+        let domain = galaxy.get(root.children()[1]).unwrap();
+        assert_eq!(domain.status(), Status::Done);
+
+        let exported = Org.export(&galaxy, &Filter::default());
+        let mut copied = Galaxy::default();
+        let summary = Org.import(&mut copied, &exported, &mut Silent).unwrap();
+        assert_eq!((summary.stars, summary.planets), (3, 3));
+        let website = copied.get(copied.get(summary.root).unwrap().children()[0]);
+        let launch = copied.get(website.unwrap().children()[0]).unwrap();
+        assert_eq!(launch.description(), "Everything needed\nfor the launch.");
+        let copy = copied.get(launch.children()[0]).unwrap();
+        assert_eq!(copy.due(), NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert_eq!(copy.value("owner").as_deref(), Some("sam"));
+    }
+}
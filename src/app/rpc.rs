@@ -0,0 +1,505 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains a JSON-RPC interface to the `Galaxy`, which lets scripts and editor
+ * plugins create, query, update, and delete celestial bodies through a single
+ * long running `planit api --rpc` instead of running planit for every
+ * operation.
+ *
+ * Every line of input is a JSON-RPC 2.0 request, and every request with an
+ * `id` is answered with a single line of JSON. Celestial bodies are written
+ * the same way as by the structured API (see `api`).
+ *
+ * | **Method** | **Parameters**                                                                              |
+ * |:-----------|:--------------------------------------------------------------------------------------------|
+ * | `query`    | `filter`, `limit`, `offset`, `include`                                                      |
+ * | `get`      | `id`, `include`                                                                             |
+ * | `create`   | `kind`, `title`, `description`, `parent`, `status`, `priority`, `estimate`, `tags`, `assignees`, `fields` |
+ * | `update`   | `id`, `title`, `description`, `parent`, `status`, `comment`, `priority`, `estimate`, `tags`, `untag`, `assignees`, `unassign`, `fields` |
+ * | `delete`   | `id`                                                                                        |
+ *
+ * IDs are given as numbers, or as strings in any form accepted by the command
+ * line (e.g. `"#2a"` or `"PLAN-42"`).
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serde_json::{json, Value};
+
+use crate::core::{
+    CelestialBody, CelestialBodyKind, CelestialBodyMut, Estimate, Galaxy, Priority, Status, ID,
+};
+
+use super::{
+    api::{self, Method, Request},
+    cli::ValueEnum,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// An error that is sent back instead of a result
+#[derive(Debug, Clone, PartialEq)]
+pub struct RpcError {
+    /// The JSON-RPC error code
+    pub code: i64,
+    pub message: String,
+    /// The HTTP status code of the error, if it is more specific than the one
+    /// implied by `code`
+    pub status: Option<u16>,
+}
+
+impl RpcError {
+    /// The request is not valid JSON
+    const PARSE_ERROR: i64 = -32700;
+    /// The request is not a JSON-RPC request
+    const INVALID_REQUEST: i64 = -32600;
+    const METHOD_NOT_FOUND: i64 = -32601;
+    const INVALID_PARAMS: i64 = -32602;
+    /// The request was valid but could not be carried out
+    const SERVER_ERROR: i64 = -32000;
+
+    fn new(code: i64, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+            status: None,
+        }
+    }
+
+    /// Helper function for errors in the parameters of a request
+    fn params(message: impl ToString) -> Self {
+        Self::new(Self::INVALID_PARAMS, message)
+    }
+
+    /// Helper function for requests that could not be carried out
+    pub fn server(message: impl ToString) -> Self {
+        Self::new(Self::SERVER_ERROR, message)
+    }
+
+    /// The HTTP status code with the same meaning as the error
+    pub fn http_status(&self) -> u16 {
+        match (self.status, self.code) {
+            (Some(status), _) => status,
+            (None, Self::METHOD_NOT_FOUND) => 404,
+            (None, Self::SERVER_ERROR) => 500,
+            (None, _) => 400,
+        }
+    }
+}
+
+/// The answer to a single line of input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    /// The response to write, `None` for notifications (requests without an
+    /// `id`)
+    pub response: Option<Value>,
+    /// Whether the `Galaxy` was changed and needs to be saved
+    pub changed: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Handles a single line of input against `galaxy`
+pub fn handle(galaxy: &mut Galaxy, line: &str) -> Reply {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => {
+            let error = RpcError::new(RpcError::PARSE_ERROR, e);
+            return Reply {
+                response: Some(response(Value::Null, Err(error))),
+                changed: false,
+            };
+        }
+    };
+    let id = request.get("id").cloned();
+    let params = request.get("params").cloned().unwrap_or(json!({}));
+
+    let result = match request.get("method").and_then(Value::as_str) {
+        Some(_) if request.get("jsonrpc") != Some(&json!("2.0")) => Err(RpcError::new(
+            RpcError::INVALID_REQUEST,
+            "Requests must have \"jsonrpc\": \"2.0\"",
+        )),
+        Some(_) if !params.is_object() => Err(RpcError::params("Parameters must be an object")),
+        Some(method) => call(galaxy, method, &params),
+        None => Err(RpcError::new(
+            RpcError::INVALID_REQUEST,
+            "Requests must have a method",
+        )),
+    };
+    let changed = matches!(result, Ok((_, true)));
+
+    Reply {
+        response: id.map(|id| response(id, result.map(|(value, _)| value))),
+        changed,
+    }
+}
+
+/// Creates the response with the `id` of the request
+pub fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => {
+            let mut value = json!({ "code": error.code, "message": error.message });
+            if let Some(status) = error.status {
+                value["data"] = json!({ "status": status });
+            }
+            json!({ "jsonrpc": "2.0", "id": id, "error": value })
+        }
+    }
+}
+
+/// Carries out the request for `method` with `params`, returning the result and
+/// whether the `Galaxy` was changed
+pub fn call(galaxy: &mut Galaxy, method: &str, params: &Value) -> Result<(Value, bool), RpcError> {
+    match method {
+        "query" => {
+            let query = params
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(key, value)| (key.clone(), query_value(value)))
+                .collect();
+            let request = Request {
+                method: Method::Get,
+                path: "/bodies".into(),
+                query,
+            };
+            Ok((api_call(galaxy, &request)?, false))
+        }
+        "get" => {
+            let id = id(galaxy, params, "id")?.ok_or(RpcError::params("Missing id"))?;
+            Ok((body(galaxy, id, params.get("include"))?, false))
+        }
+        "create" => {
+            let kind = match string(params, "kind")? {
+                Some(kind) => parse::<CelestialBodyKind>(kind)?,
+                None => CelestialBodyKind::Planet,
+            };
+            let title = string(params, "title")?.ok_or(RpcError::params("Missing title"))?;
+            // Check everything before creating the celestial body, so that
+            // invalid requests do not leave anything behind
+            let parent = id(galaxy, params, "parent")?;
+            let changes = Changes::parse(params)?;
+
+            let title = title.to_string();
+            let id = match kind {
+                CelestialBodyKind::Comet => galaxy.comet().title(title).id(),
+                CelestialBodyKind::Planet => galaxy.planet().title(title).id(),
+                CelestialBodyKind::Star => galaxy.star().title(title).id(),
+            };
+            if let Some(mut body) = galaxy.get_mut(id) {
+                changes.apply(&mut body);
+            }
+            if parent.is_some() {
+                galaxy.set_parent(id, parent).map_err(|e| {
+                    let _ = galaxy.delete(id, Utc::now());
+                    RpcError::params(e)
+                })?;
+            }
+            Ok((body(galaxy, id, params.get("include"))?, true))
+        }
+        "update" => {
+            let id = id(galaxy, params, "id")?.ok_or(RpcError::params("Missing id"))?;
+            if let Some(parent) = params.get("parent") {
+                let parent = match parent {
+                    Value::Null => None,
+                    _ => id_value(galaxy, parent)?,
+                };
+                galaxy.set_parent(id, parent).map_err(RpcError::params)?;
+            }
+            let changes = Changes::parse(params)?;
+            if let Some(mut body) = galaxy.get_mut(id) {
+                if let Some(title) = string(params, "title")? {
+                    body.title(title.to_string());
+                }
+                changes.apply(&mut body);
+                for tag in strings(params, "untag")? {
+                    body.untag(&tag);
+                }
+                for assignee in strings(params, "unassign")? {
+                    body.unassign(&assignee);
+                }
+            }
+            Ok((body(galaxy, id, params.get("include"))?, true))
+        }
+        "delete" => {
+            let id = id(galaxy, params, "id")?.ok_or(RpcError::params("Missing id"))?;
+            let deleted = galaxy.delete(id, Utc::now()).map_err(RpcError::params)?;
+            Ok((json!({ "deleted": deleted }), true))
+        }
+        _ => Err(RpcError::new(
+            RpcError::METHOD_NOT_FOUND,
+            format!("Unknown method: {method}"),
+        )),
+    }
+}
+
+/// The changes that `create` and `update` have in common
+struct Changes {
+    description: Option<String>,
+    status: Option<(Status, String)>,
+    priority: Option<Priority>,
+    estimate: Option<Option<Estimate>>,
+    tags: Vec<String>,
+    assignees: Vec<String>,
+    fields: Vec<(String, String)>,
+}
+
+impl Changes {
+    /// Parses the changes from `params`, so that a request with any invalid
+    /// parameter changes nothing
+    fn parse(params: &Value) -> Result<Self, RpcError> {
+        let comment = string(params, "comment")?.unwrap_or_default().to_string();
+        let estimate = match params.get("estimate") {
+            None => None,
+            Some(Value::Null) => Some(None),
+            Some(Value::String(s)) => Some(Some(s.parse().map_err(RpcError::params)?)),
+            Some(_) => return Err(RpcError::params("estimate must be a string")),
+        };
+        let fields = match params.get("fields") {
+            None => Vec::new(),
+            Some(Value::Object(fields)) => fields
+                .iter()
+                .map(|(key, value)| match value {
+                    Value::String(value) => Ok((key.clone(), value.clone())),
+                    _ => Err(RpcError::params("fields must be strings")),
+                })
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(RpcError::params("fields must be an object")),
+        };
+
+        Ok(Self {
+            description: string(params, "description")?.map(str::to_string),
+            status: match string(params, "status")? {
+                Some(status) => Some((parse(status)?, comment)),
+                None => None,
+            },
+            priority: string(params, "priority")?.map(parse).transpose()?,
+            estimate,
+            tags: strings(params, "tags")?,
+            assignees: strings(params, "assignees")?,
+            fields,
+        })
+    }
+
+    fn apply(self, body: &mut CelestialBodyMut) {
+        if let Some(description) = self.description {
+            body.description(description);
+        }
+        if let Some((status, comment)) = self.status {
+            body.status(status, comment);
+        }
+        if let Some(priority) = self.priority {
+            body.priority(priority);
+        }
+        if let Some(estimate) = self.estimate {
+            body.estimate(estimate);
+        }
+        for tag in self.tags {
+            body.tag(tag);
+        }
+        for assignee in self.assignees {
+            body.assign(assignee);
+        }
+        for (key, value) in self.fields {
+            body.field(key, value);
+        }
+    }
+}
+
+/// Helper function that runs a request against the structured API
+fn api_call(galaxy: &Galaxy, request: &Request) -> Result<Value, RpcError> {
+    let response = api::handle(galaxy, request);
+    match response.is_success() {
+        true => Ok(response.body),
+        false => Err(RpcError {
+            status: Some(response.status),
+            ..RpcError::server(response.body["error"].as_str().unwrap_or_default())
+        }),
+    }
+}
+
+/// Helper function that gets the celestial body with `id` from the structured
+/// API
+fn body(galaxy: &Galaxy, id: ID, include: Option<&Value>) -> Result<Value, RpcError> {
+    let query = include
+        .map(|include| HashMap::from([("include".to_string(), query_value(include))]))
+        .unwrap_or_default();
+    let request = Request {
+        method: Method::Get,
+        path: format!("/bodies/{id}"),
+        query,
+    };
+    api_call(galaxy, &request)
+}
+
+/// Helper function that turns a parameter into a query parameter of the
+/// structured API. Lists are joined with commas.
+fn query_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(query_value).collect::<Vec<_>>().join(","),
+        value => value.to_string(),
+    }
+}
+
+/// Helper function that gets the string parameter `key`
+fn string<'a>(params: &'a Value, key: &str) -> Result<Option<&'a str>, RpcError> {
+    match params.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(_) => Err(RpcError::params(format!("{key} must be a string"))),
+    }
+}
+
+/// Helper function that gets the parameter `key`, which is a list of strings
+fn strings(params: &Value, key: &str) -> Result<Vec<String>, RpcError> {
+    let error = || RpcError::params(format!("{key} must be a list of strings"));
+    match params.get(key) {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string).ok_or_else(error))
+            .collect(),
+        Some(_) => Err(error()),
+    }
+}
+
+/// Helper function that gets the ID parameter `key`
+fn id(galaxy: &Galaxy, params: &Value, key: &str) -> Result<Option<ID>, RpcError> {
+    match params.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => id_value(galaxy, value),
+    }
+}
+
+/// Helper function that finds the celestial body an ID parameter refers to
+fn id_value(galaxy: &Galaxy, value: &Value) -> Result<Option<ID>, RpcError> {
+    let id = match value {
+        Value::Number(n) => n.as_u64().ok_or(RpcError::params("Invalid id"))?,
+        Value::String(s) => galaxy.resolve(s).map_err(RpcError::params)?,
+        _ => return Err(RpcError::params("Ids must be numbers or strings")),
+    };
+    match galaxy.get(id) {
+        Some(_) => Ok(Some(id)),
+        None => Err(RpcError {
+            status: Some(404),
+            ..RpcError::params(format!("No celestial body with id {id}"))
+        }),
+    }
+}
+
+/// Helper function that parses the name of a variant of `T`, ignoring case
+fn parse<T: ValueEnum>(s: &str) -> Result<T, RpcError> {
+    T::from_str(s, true).map_err(|_| RpcError::params(format!("Invalid value: {s}")))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request(galaxy: &mut Galaxy, method: &str, params: Value) -> Value {
+        let line = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        handle(galaxy, &line.to_string()).response.unwrap()
+    }
+
+    #[test]
+    fn requests_create_query_update_and_delete() {
+        let mut galaxy = Galaxy::default();
+        let star = request(
+            &mut galaxy,
+            "create",
+            json!({ "kind": "star", "title": "Web" }),
+        );
+        let star = star["result"]["id"].as_u64().unwrap();
+
+        let params =
+            json!({ "title": "Login", "parent": star, "tags": ["auth"], "estimate": "3pts" });
+        let planet = request(&mut galaxy, "create", params);
+        assert_eq!(planet["result"]["parent"], star);
+        assert_eq!(planet["result"]["tags"], json!(["auth"]));
+        let planet = planet["result"]["id"].as_u64().unwrap();
+
+        let params = json!({ "id": planet, "status": "start", "untag": ["auth"] });
+        let updated = request(&mut galaxy, "update", params);
+        assert_eq!(updated["result"]["status"], "Start");
+        assert_eq!(updated["result"]["tags"], json!([]));
+
+        let found = request(&mut galaxy, "query", json!({ "filter": "status:start" }));
+        assert_eq!(found["result"]["total"], 1);
+
+        let deleted = request(&mut galaxy, "delete", json!({ "id": format!("#{star}") }));
+        assert_eq!(deleted["result"]["deleted"], 2);
+        assert!(galaxy.iter().next().is_none());
+    }
+
+    #[test]
+    fn invalid_requests_change_nothing() {
+        let mut galaxy = Galaxy::default();
+
+        let reply = handle(&mut galaxy, "{ not json");
+        assert_eq!(
+            reply.response.unwrap()["error"]["code"],
+            RpcError::PARSE_ERROR
+        );
+
+        let params = json!({ "title": "Login", "status": "sideways" });
+        let response = request(&mut galaxy, "create", params);
+        assert_eq!(response["error"]["code"], RpcError::INVALID_PARAMS);
+        assert!(galaxy.iter().next().is_none());
+
+        let response = request(&mut galaxy, "get", json!({ "id": 7 }));
+        assert_eq!(response["error"]["code"], RpcError::INVALID_PARAMS);
+        let response = request(&mut galaxy, "launch", json!({}));
+        assert_eq!(response["error"]["code"], RpcError::METHOD_NOT_FOUND);
+
+        let line = json!({ "jsonrpc": "2.0", "method": "create", "params": { "title": "Quiet" } });
+        let reply = handle(&mut galaxy, &line.to_string());
+        assert_eq!(reply.response, None);
+        assert!(reply.changed);
+    }
+}
@@ -0,0 +1,259 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the local HTTP server started by `planit serve`, which exposes the
+ * structured API (see `api`) so that a browser dashboard or a phone shortcut
+ * can show the state of the project.
+ *
+ * The server is read-only unless it is started with `--write`, which also
+ * enables the following routes. Their bodies are JSON objects with the same
+ * fields as the parameters of `create` and `update` in `rpc`.
+ *
+ * | **Route**            | **Body**                                                 |
+ * |:---------------------|:---------------------------------------------------------|
+ * | `POST /bodies`       | `kind`, `title`, `parent`, `status`, `tags`, ...         |
+ * | `PATCH /bodies/<id>` | `title`, `status`, `comment`, `priority`, `tags`, ...    |
+ *
+ * Routing does not depend on the HTTP implementation, which is only compiled
+ * with the `serve` feature.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use serde_json::{json, Value};
+
+use crate::core::Galaxy;
+
+use super::{
+    api::{self, Method, Request, Response},
+    rpc,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Handles a single HTTP request against `galaxy`. Changes are only allowed if
+/// `writable` is set.
+///
+/// # Returns
+/// The response and whether the `Galaxy` was changed and needs to be saved
+pub fn handle(
+    galaxy: &mut Galaxy,
+    method: &str,
+    target: &str,
+    body: &str,
+    writable: bool,
+) -> (Response, bool) {
+    if method.eq_ignore_ascii_case("GET") {
+        return (
+            api::handle(galaxy, &Request::new(Method::Get, target)),
+            false,
+        );
+    }
+    if !writable {
+        return (
+            error(405, "The server is read-only, start it with --write"),
+            false,
+        );
+    }
+    if body.len() as u64 > Galaxy::MAX_SIZE {
+        return (
+            error(
+                413,
+                format!("The body is larger than {} bytes", Galaxy::MAX_SIZE),
+            ),
+            false,
+        );
+    }
+
+    let mut params: Value = match body.trim() {
+        "" => json!({}),
+        body => match serde_json::from_str(body) {
+            Ok(params @ Value::Object(_)) => params,
+            Ok(_) => return (error(400, "The body must be a JSON object"), false),
+            Err(e) => return (error(400, e), false),
+        },
+    };
+    let path = target.split_once('?').map_or(target, |(path, _)| path);
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let method = method.to_ascii_uppercase();
+
+    let result = match (method.as_str(), segments.as_slice()) {
+        ("POST", ["bodies"]) => rpc::call(galaxy, "create", &params),
+        ("PATCH", ["bodies", id]) => {
+            params["id"] = match id.parse::<u64>() {
+                Ok(id) => json!(id),
+                Err(_) => json!(id),
+            };
+            rpc::call(galaxy, "update", &params)
+        }
+        _ => return (error(404, format!("Unknown route: {method} {path}")), false),
+    };
+
+    match result {
+        Ok((body, changed)) => {
+            let status = if method == "POST" { 201 } else { 200 };
+            (Response { status, body }, changed)
+        }
+        Err(e) => (error(e.http_status(), e.message), false),
+    }
+}
+
+/// Listens on `host`:`port` until the process is stopped. The Galaxy is loaded
+/// for every request, so that changes made in the meantime are not
+/// overwritten. Bodies are read up to one byte past `Galaxy::MAX_SIZE`, so
+/// that larger ones are refused without being read completely.
+#[cfg(feature = "serve")]
+pub fn run(host: &str, port: u16, writable: bool) -> super::Result<()> {
+    use std::io::Read;
+
+    use log::{info, warn};
+    use tiny_http::{Header, Server};
+
+    use super::AppError;
+
+    let server = Server::http((host, port)).map_err(|e| AppError::ServeFailed(e.to_string()))?;
+    println!(
+        "Serving on http://{host}:{port} ({})",
+        if writable { "read-write" } else { "read-only" }
+    );
+
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let mut reader = request.as_reader().take(Galaxy::MAX_SIZE + 1);
+        let response = match reader.read_to_string(&mut body) {
+            Err(e) => error(400, e),
+            Ok(_) => match Galaxy::load() {
+                Err(e) => error(500, e),
+                Ok(mut galaxy) => {
+                    let method = request.method().to_string();
+                    let (response, changed) =
+                        handle(&mut galaxy, &method, request.url(), &body, writable);
+                    match changed {
+                        true => match galaxy.save() {
+                            Ok(()) => response,
+                            Err(e) => error(500, e),
+                        },
+                        false => response,
+                    }
+                }
+            },
+        };
+        info!(
+            "{} {} -> {}",
+            request.method(),
+            request.url(),
+            response.status
+        );
+
+        let header = Header::from_bytes("Content-Type", "application/json")
+            .expect("The header should be valid");
+        let reply = tiny_http::Response::from_string(response.body.to_string())
+            .with_status_code(response.status)
+            .with_header(header);
+        if let Err(e) = request.respond(reply) {
+            warn!("Could not send the response: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Helper function that creates an error response
+fn error(status: u16, message: impl ToString) -> Response {
+    Response {
+        status,
+        body: json!({ "error": message.to_string() }),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn changes_need_write_access() {
+        let mut galaxy = Galaxy::default();
+        let body = r#"{ "title": "Login", "tags": ["auth"] }"#;
+
+        let (response, changed) = handle(&mut galaxy, "POST", "/bodies", body, false);
+        assert_eq!((response.status, changed), (405, false));
+        assert!(galaxy.iter().next().is_none());
+
+        let (response, changed) = handle(&mut galaxy, "POST", "/bodies", body, true);
+        assert_eq!((response.status, changed), (201, true));
+        let id = response.body["id"].as_u64().unwrap();
+
+        let body = r#"{ "status": "done", "comment": "Shipped" }"#;
+        let path = format!("/bodies/{id}");
+        let (response, changed) = handle(&mut galaxy, "PATCH", &path, body, true);
+        assert_eq!((response.status, changed), (200, true));
+        assert_eq!(response.body["status"], "Done");
+
+        let (response, _) = handle(&mut galaxy, "GET", "/bodies?filter=status:done", "", false);
+        assert_eq!(response.body["total"], 1);
+
+        let (response, _) = handle(&mut galaxy, "DELETE", &path, "", true);
+        assert_eq!(response.status, 404);
+    }
+
+    #[test]
+    fn missing_bodies_are_not_found() {
+        let mut galaxy = Galaxy::default();
+        let body = r#"{ "status": "done" }"#;
+
+        let (response, changed) = handle(&mut galaxy, "PATCH", "/bodies/42", body, true);
+        assert_eq!((response.status, changed), (404, false));
+        assert_eq!(response.body["error"], "No celestial body with id 42");
+    }
+
+    #[test]
+    fn large_bodies_are_refused() {
+        let mut galaxy = Galaxy::default();
+        let title = "a".repeat(Galaxy::MAX_SIZE as usize);
+        let body = format!(r#"{{ "title": "{title}" }}"#);
+
+        let (response, changed) = handle(&mut galaxy, "POST", "/bodies", &body, true);
+        assert_eq!((response.status, changed), (413, false));
+        assert!(galaxy.iter().next().is_none());
+
+        let (response, _) = handle(&mut galaxy, "GET", "/bodies", &body, false);
+        assert_eq!(response.status, 200);
+    }
+}
@@ -0,0 +1,406 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Splits the Markdown description of a `Star` into child celestial bodies.
+ *
+ * This is meant for turning pasted meeting notes or plans into structured
+ * work. Every heading becomes a section and every checkbox list item (`- [ ]`
+ * or `- [x]`) becomes an item. Anything else stays as plain description text.
+ * Headings and checkboxes inside fenced code blocks are ignored.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use crate::core::{CelestialBody, CelestialBodyKind, Galaxy, GalaxyError, Status};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A Markdown description broken into sections and checkbox items
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Outline {
+    /// Text that is not part of any section or item
+    pub description: String,
+    /// Checkbox items that appear before the first heading
+    pub items: Vec<Item>,
+    pub sections: Vec<Section>,
+}
+
+/// Everything under a single Markdown heading
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub title: String,
+    /// Text under the heading that is not a checkbox item
+    pub description: String,
+    pub items: Vec<Item>,
+}
+
+/// A single checkbox list item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub title: String,
+    /// Whether the box was checked, i.e. `- [x]`
+    pub checked: bool,
+}
+
+/// Which kind of celestial body sections and items are turned into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping {
+    pub sections: CelestialBodyKind,
+    pub items: CelestialBodyKind,
+}
+
+/// What was added to the `Galaxy` by a split
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub sections: usize,
+    pub items: usize,
+}
+
+impl Outline {
+    /// Breaks the Markdown `text` into sections and checkbox items
+    pub fn parse(text: &str) -> Self {
+        let mut outline = Self::default();
+        let mut in_code_block = false;
+
+        for line in text.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+
+            if !in_code_block {
+                if let Some(title) = heading(trimmed) {
+                    outline.sections.push(Section {
+                        title: title.into(),
+                        ..Section::default()
+                    });
+                    continue;
+                }
+                if let Some(item) = checkbox(trimmed) {
+                    match outline.sections.last_mut() {
+                        Some(section) => section.items.push(item),
+                        None => outline.items.push(item),
+                    }
+                    continue;
+                }
+            }
+
+            let description = match outline.sections.last_mut() {
+                Some(section) => &mut section.description,
+                None => &mut outline.description,
+            };
+            description.push_str(line);
+            description.push('\n');
+        }
+
+        outline.description = outline.description.trim().into();
+        for section in &mut outline.sections {
+            section.description = section.description.trim().into();
+        }
+        outline
+    }
+
+    /// Whether there is nothing to split out of the description
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty() && self.sections.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Splits the description of the `Star` `id` into child celestial bodies. The
+/// description of the `Star` is replaced by whatever text is left over.
+///
+/// Sections become children of the `Star`. If sections are `Star`s, their
+/// items become their children, otherwise the items are placed directly under
+/// `id` after the section they came from. Checked items are marked `Done`.
+///
+/// # Errors
+/// Errors will occur in the following situations:
+/// - `id` does not exist
+/// - `id` is not a `Star`
+pub fn split(galaxy: &mut Galaxy, id: u64, mapping: Mapping) -> Result<Summary, GalaxyError> {
+    let star = galaxy
+        .get(id)
+        .ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+    if star.kind() != CelestialBodyKind::Star {
+        return Err(GalaxyError::NotAStar(id));
+    }
+    let outline = Outline::parse(star.description());
+    let mut summary = Summary::default();
+    if outline.is_empty() {
+        return Ok(summary);
+    }
+
+    for item in outline.items {
+        add_item(galaxy, id, mapping.items, item)?;
+        summary.items += 1;
+    }
+    for section in outline.sections {
+        let section_id = create(galaxy, mapping.sections, section.title, section.description);
+        galaxy.set_parent(section_id, Some(id))?;
+        summary.sections += 1;
+
+        let parent = match mapping.sections {
+            CelestialBodyKind::Star => section_id,
+            _ => id,
+        };
+        for item in section.items {
+            add_item(galaxy, parent, mapping.items, item)?;
+            summary.items += 1;
+        }
+    }
+
+    if let Some(mut star) = galaxy.get_mut(id) {
+        star.description(outline.description);
+    }
+    log::info!(
+        "Split Star ({id}) into {} sections and {} items",
+        summary.sections,
+        summary.items
+    );
+
+    Ok(summary)
+}
+
+/// Helper function that adds `item` to `galaxy` as a child of `parent`
+fn add_item(
+    galaxy: &mut Galaxy,
+    parent: u64,
+    kind: CelestialBodyKind,
+    item: Item,
+) -> Result<(), GalaxyError> {
+    let id = create(galaxy, kind, item.title, String::new());
+    galaxy.set_parent(id, Some(parent))?;
+    if let Some(mut body) = galaxy.get_mut(id).filter(|_| item.checked) {
+        body.status(Status::Done, "Checked in description".into());
+    }
+    Ok(())
+}
+
+/// Helper function that creates a celestial body of `kind`, returning its id
+fn create(galaxy: &mut Galaxy, kind: CelestialBodyKind, title: String, description: String) -> u64 {
+    match kind {
+        CelestialBodyKind::Comet => galaxy.comet().title(title).description(description).id(),
+        CelestialBodyKind::Planet => galaxy.planet().title(title).description(description).id(),
+        CelestialBodyKind::Star => galaxy.star().title(title).description(description).id(),
+    }
+}
+
+/// Helper function that returns the text of a Markdown heading, or `None` if
+/// `line` is not a heading
+fn heading(line: &str) -> Option<&str> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    match line[level..].strip_prefix(' ').map(str::trim) {
+        Some(title) if !title.is_empty() => Some(title),
+        _ => None,
+    }
+}
+
+/// Helper function that returns the checkbox item in `line`, or `None` if
+/// `line` is not a checkbox list item
+fn checkbox(line: &str) -> Option<Item> {
+    let rest = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|bullet| line.strip_prefix(bullet))?;
+    let (checked, title) = if let Some(title) = rest.strip_prefix("[ ] ") {
+        (false, title)
+    } else if let Some(title) = rest
+        .strip_prefix("[x] ")
+        .or_else(|| rest.strip_prefix("[X] "))
+    {
+        (true, title)
+    } else {
+        return None;
+    };
+
+    match title.trim() {
+        "" => None,
+        title => Some(Item {
+            title: title.into(),
+            checked,
+        }),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const NOTES: &str = "Notes from the planning meeting
+
+- [ ] Book a room
+
+## Backend
+Needs to be done before the release
+
+- [x] Add endpoint
+- [ ] Write tests
+- not a task
+
+```
+# not a heading
+- [ ] not an item
+```
+
+## Frontend
+";
+
+    #[test]
+    fn headings_and_checkboxes_are_outlined() {
+        let outline = Outline::parse(NOTES);
+        assert_eq!(outline.description, "Notes from the planning meeting");
+        assert_eq!(outline.items.len(), 1);
+        assert_eq!(outline.items[0].title, "Book a room");
+
+        assert_eq!(outline.sections.len(), 2);
+        let backend = &outline.sections[0];
+        assert_eq!(backend.title, "Backend");
+        assert!(backend.description.starts_with("Needs to be done"));
+        assert!(backend.description.contains("- not a task"));
+        assert!(backend.description.contains("# not a heading"));
+        assert_eq!(
+            backend.items,
+            vec![
+                Item {
+                    title: "Add endpoint".into(),
+                    checked: true
+                },
+                Item {
+                    title: "Write tests".into(),
+                    checked: false
+                },
+            ]
+        );
+        assert_eq!(outline.sections[1].title, "Frontend");
+        assert!(outline.sections[1].items.is_empty());
+
+        assert!(Outline::parse("Just text\n#hashtag").is_empty());
+    }
+
+    #[test]
+    fn star_sections_contain_their_items() {
+        let mut galaxy = Galaxy::default();
+        let id = galaxy
+            .star()
+            .title("Plan".into())
+            .description(NOTES.into())
+            .id();
+
+        let mapping = Mapping {
+            sections: CelestialBodyKind::Star,
+            items: CelestialBodyKind::Planet,
+        };
+        let summary = split(&mut galaxy, id, mapping).unwrap();
+        assert_eq!(
+            summary,
+            Summary {
+                sections: 2,
+                items: 3
+            }
+        );
+
+        let star = galaxy.get(id).unwrap();
+        assert_eq!(star.description(), "Notes from the planning meeting");
+        assert_eq!(star.children().len(), 3);
+
+        let backend = galaxy.get(star.children()[1]).unwrap();
+        assert_eq!(backend.kind(), CelestialBodyKind::Star);
+        assert_eq!(backend.children().len(), 2);
+        let endpoint = galaxy.get(backend.children()[0]).unwrap();
+        assert_eq!(endpoint.kind(), CelestialBodyKind::Planet);
+        assert_eq!(endpoint.status(), Status::Done);
+    }
+
+    #[test]
+    fn planet_sections_leave_items_under_star() {
+        let mut galaxy = Galaxy::default();
+        let id = galaxy
+            .star()
+            .title("Plan".into())
+            .description(NOTES.into())
+            .id();
+
+        let mapping = Mapping {
+            sections: CelestialBodyKind::Planet,
+            items: CelestialBodyKind::Comet,
+        };
+        split(&mut galaxy, id, mapping).unwrap();
+
+        let titles: Vec<_> = galaxy
+            .get(id)
+            .unwrap()
+            .children()
+            .iter()
+            .map(|child| galaxy.get(*child).unwrap().title().to_string())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Book a room",
+                "Backend",
+                "Add endpoint",
+                "Write tests",
+                "Frontend"
+            ]
+        );
+    }
+
+    #[test]
+    fn only_stars_can_be_split() {
+        let mut galaxy = Galaxy::default();
+        let id = galaxy.planet().description(NOTES.into()).id();
+        let mapping = Mapping {
+            sections: CelestialBodyKind::Planet,
+            items: CelestialBodyKind::Planet,
+        };
+        assert!(matches!(
+            split(&mut galaxy, id, mapping),
+            Err(GalaxyError::NotAStar(_))
+        ));
+    }
+}
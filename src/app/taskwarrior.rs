@@ -0,0 +1,363 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the bridge to Taskwarrior, which reads and writes the JSON format of
+ * `task export` and `task import`.
+ *
+ * Taskwarrior has no hierarchy, only dotted projects (e.g. `web.launch`). On
+ * import every project becomes a `Star` and tasks become `Planet`s, and on
+ * export the titles of the `Star`s above a celestial body become its project.
+ * The UUID of each task is kept in the `taskwarrior` field, so that exporting
+ * an imported task updates it in Taskwarrior instead of duplicating it.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        CelestialBody, CelestialBodyKind, CelestialBodyRef, Filter, Galaxy, Priority, Status, ID,
+    },
+    util::progress::Reporter,
+};
+
+use super::{
+    export::Exporter,
+    import::{ImportError, Importer, Summary},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The field holding the UUID of the task
+const UUID_FIELD: &str = "taskwarrior";
+
+/// The format of dates in Taskwarrior's JSON
+const DATE_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Imports and exports Taskwarrior tasks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Taskwarrior;
+
+impl Importer for Taskwarrior {
+    /// Imports the tasks in `source`, the output of `task export`, under a new
+    /// `Star`. Recurring templates are skipped since only their instances are
+    /// actual work.
+    fn import(
+        &self,
+        galaxy: &mut Galaxy,
+        source: &str,
+        progress: &mut dyn Reporter,
+    ) -> Result<Summary, ImportError> {
+        let tasks = parse(source)?;
+
+        let root = galaxy.star().title("Taskwarrior".into()).id();
+        let mut summary = Summary {
+            root,
+            stars: 1,
+            planets: 0,
+        };
+        let mut projects: HashMap<String, ID> = HashMap::new();
+
+        progress.start("Adding tasks", Some(tasks.len() as u64));
+        for task in tasks {
+            progress.advance(1);
+            let status = match task.status.as_str() {
+                "pending" if task.start.is_some() => Status::Start,
+                "pending" => Status::Todo,
+                "waiting" => Status::Hold,
+                "completed" => Status::Done,
+                "deleted" => Status::Cancel,
+                _ => continue,
+            };
+
+            let mut parent = root;
+            if let Some(project) = &task.project {
+                let mut path = String::new();
+                for name in project.split('.') {
+                    path = match path.is_empty() {
+                        true => name.to_string(),
+                        false => format!("{path}.{name}"),
+                    };
+                    parent = match projects.get(&path) {
+                        Some(id) => *id,
+                        None => {
+                            let id = galaxy.star().title(name.into()).id();
+                            galaxy.set_parent(id, Some(parent))?;
+                            projects.insert(path.clone(), id);
+                            summary.stars += 1;
+                            id
+                        }
+                    };
+                }
+            }
+
+            let description: Vec<&str> = task
+                .annotations
+                .iter()
+                .map(|a| a.description.as_str())
+                .collect();
+            let planet = galaxy.planet().title(task.description);
+            planet.description(description.join("\n"));
+            let id = planet.id();
+
+            if let Some(mut body) = galaxy.get_mut(id) {
+                body.status(status, "Imported from Taskwarrior".into());
+                body.priority(match task.priority.as_deref() {
+                    Some("H") => Priority::High,
+                    Some("M") => Priority::Medium,
+                    Some("L") => Priority::Low,
+                    _ => Priority::None,
+                });
+                for tag in task.tags {
+                    body.tag(tag);
+                }
+                if let Some(due) = task.due.as_deref().and_then(local_date) {
+                    body.field("due".into(), due.format("%Y-%m-%d").to_string());
+                }
+                body.field(UUID_FIELD.into(), task.uuid);
+            }
+            galaxy.set_parent(id, Some(parent))?;
+            summary.planets += 1;
+        }
+        progress.finish();
+
+        Ok(summary)
+    }
+}
+
+impl Exporter for Taskwarrior {
+    /// Writes the `Planet`s and `Comet`s matching `filter` as a JSON array that
+    /// `task import` accepts
+    fn export(&self, galaxy: &Galaxy, filter: &Filter) -> String {
+        let mut bodies: Vec<CelestialBodyRef> = galaxy
+            .iter()
+            .filter(|body| body.kind() != CelestialBodyKind::Star && filter.matches(body))
+            .collect();
+        bodies.sort_by_key(|body| body.id());
+
+        let tasks: Vec<Task> = bodies.iter().map(|body| task(galaxy, body)).collect();
+        serde_json::to_string_pretty(&tasks).unwrap_or_default() + "\n"
+    }
+}
+
+/// A task in Taskwarrior's JSON format, leaving out what has no equivalent
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Task {
+    uuid: String,
+    description: String,
+    status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    entry: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    modified: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    due: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    priority: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    annotations: Vec<Annotation>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Annotation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    entry: Option<String>,
+    description: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that parses either a JSON array of tasks or one task per
+/// line, both of which `task export` can produce
+fn parse(source: &str) -> Result<Vec<Task>, ImportError> {
+    let invalid = |e: serde_json::Error| ImportError::InvalidFile(e.to_string());
+    if source.trim_start().starts_with('[') {
+        return serde_json::from_str(source).map_err(invalid);
+    }
+    source
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(invalid))
+        .collect()
+}
+
+/// Helper function that converts a Taskwarrior date to the local date it falls
+/// on
+fn local_date(date: &str) -> Option<NaiveDate> {
+    let time = NaiveDateTime::parse_from_str(date, DATE_FORMAT).ok()?;
+    Some(
+        Utc.from_utc_datetime(&time)
+            .with_timezone(&Local)
+            .date_naive(),
+    )
+}
+
+/// Helper function that formats a time as a Taskwarrior date
+fn format(time: DateTime<Utc>) -> String {
+    time.format(DATE_FORMAT).to_string()
+}
+
+/// Helper function that converts a celestial body to a task
+fn task(galaxy: &Galaxy, body: &CelestialBodyRef) -> Task {
+    let uuid = body.value(UUID_FIELD).unwrap_or_else(|| {
+        let id = body.id();
+        format!(
+            "{:08x}-0000-4000-8000-{:012x}",
+            id >> 48,
+            id & 0xffff_ffff_ffff
+        )
+    });
+    let entry = body.history().first().map(|h| h.time());
+    let modified = body.updated_at().or(entry);
+    let status = body.status();
+
+    let mut project = Vec::new();
+    let mut parent = body.parent();
+    while let Some(star) = parent.and_then(|id| galaxy.get(id)) {
+        project.push(star.title().replace('.', "_"));
+        parent = star.parent();
+    }
+    project.reverse();
+
+    let due = body.due().and_then(|date| {
+        let midnight = date.and_hms_opt(0, 0, 0)?;
+        let local = Local.from_local_datetime(&midnight).earliest()?;
+        Some(format(local.with_timezone(&Utc)))
+    });
+
+    let annotations = match body.description().trim() {
+        "" => Vec::new(),
+        description => vec![Annotation {
+            entry: entry.or(modified).map(format),
+            description: description.into(),
+        }],
+    };
+
+    Task {
+        uuid,
+        description: body.title().into(),
+        status: match status {
+            Status::Done => "completed",
+            Status::Cancel => "deleted",
+            _ => "pending",
+        }
+        .into(),
+        entry: entry.map(format),
+        modified: modified.map(format),
+        start: (status == Status::Start)
+            .then(|| modified.map(format))
+            .flatten(),
+        end: status.is_final().then(|| modified.map(format)).flatten(),
+        due,
+        project: (!project.is_empty()).then(|| project.join(".")),
+        priority: match body.priority() {
+            Priority::Critical | Priority::High => Some("H".into()),
+            Priority::Medium => Some("M".into()),
+            Priority::Low => Some("L".into()),
+            Priority::None => None,
+        },
+        tags: body.tags().to_vec(),
+        annotations,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::progress::Silent;
+
+    const TASKS: &str = r#"[
+{"id":1,"description":"Write copy","entry":"20250101T100000Z","modified":"20250102T100000Z","project":"web.launch","priority":"H","status":"pending","start":"20250102T100000Z","tags":["docs"],"uuid":"2d7a5d52-1f0a-4b8e-9f6d-3c1a9e4e2b10","annotations":[{"entry":"20250102T100000Z","description":"Ask Sam first"}],"urgency":8.1},
+{"id":0,"description":"Buy domain","end":"20250103T100000Z","entry":"20250101T100000Z","project":"web","status":"completed","uuid":"7c1e0f4a-8d9b-4e2c-a1f3-5b6d7e8f9a01"},
+{"id":0,"description":"Water plants","entry":"20250101T100000Z","recur":"weekly","status":"recurring","uuid":"0b9a8c7d-6e5f-4a3b-8c2d-1e0f9a8b7c6d"}
+]"#;
+
+    #[test]
+    fn tasks_are_grouped_by_project() {
+        let mut galaxy = Galaxy::default();
+        let summary = Taskwarrior.import(&mut galaxy, TASKS, &mut Silent).unwrap();
+        assert_eq!((summary.stars, summary.planets), (3, 2));
+
+        let root = galaxy.get(summary.root).unwrap();
+        let web = galaxy.get(root.children()[0]).unwrap();
+        assert_eq!(web.title(), "web");
+        let launch = galaxy.get(web.children()[0]).unwrap();
+        assert_eq!(launch.title(), "launch");
+
+        let copy = galaxy.get(launch.children()[0]).unwrap();
+        assert_eq!(copy.title(), "Write copy");
+        assert_eq!(copy.status(), Status::Start);
+        assert_eq!(copy.priority(), Priority::High);
+        assert_eq!(copy.description(), "Ask Sam first");
+        let domain = galaxy.get(web.children()[1]).unwrap();
+        assert_eq!(domain.status(), Status::Done);
+
+        let exported = Taskwarrior.export(&galaxy, &Filter::default());
+        let tasks = parse(&exported).unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].uuid, "2d7a5d52-1f0a-4b8e-9f6d-3c1a9e4e2b10");
+        assert_eq!(tasks[0].project.as_deref(), Some("Taskwarrior.web.launch"));
+        assert_eq!(tasks[0].priority.as_deref(), Some("H"));
+        assert!(tasks[0].start.is_some());
+        assert_eq!(tasks[1].status, "completed");
+    }
+}
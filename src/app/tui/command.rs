@@ -0,0 +1,623 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the commands that can be typed into the TUI after pressing `:`.
+ *
+ * | **Command**                 | **Action**                                                |
+ * |:----------------------------|:----------------------------------------------------------|
+ * | `quit`                      | Quits the TUI                                             |
+ * | `view <view>`               | Switches the active pane to `<view>` (`tree`, `kanban`)   |
+ * | `open <view> [<filter>]`    | Replaces the active pane with a new `<view>`              |
+ * | `open <saved view>`         | Replaces the active pane with a view saved in the config  |
+ * | `filter [<filter>]`         | Filters the active pane, clearing the filter if empty     |
+ * | `sort <order>`              | Sorts the active pane (`priority`, `title`, `due`, ...)   |
+ * | `group-by <columns>`        | Sets the kanban columns (`tag`, `field:<key>`, ...)       |
+ * | `focus [<id>]`              | Scopes the active pane to a `Star`, clearing if empty     |
+ * | `archived`                  | Toggles showing archived celestial bodies                 |
+ * | `split [right/below]`       | Opens a blank pane                                        |
+ * | `clone [right/below]`       | Opens a pane showing the same thing as the active pane    |
+ * | `close`                     | Closes the active pane                                    |
+ * | `pane <up/down/left/right>` | Moves to the pane next to the active pane                 |
+ * | `resize <direction> [<n>]`  | Grows (`right`, `down`) or shrinks the active pane        |
+ * | `delete [<id>]`             | Moves a celestial body to the trash, after confirming     |
+ * | `add <args>`                | Creates a celestial body, taking the args of `planit new` |
+ * | `yank [<id>]`               | Remembers a celestial body (and its descendants)          |
+ * | `paste [<n>]`               | Pastes `<n>` copies of the yanked celestial body          |
+ * | `copy [<id>]`               | Copies a celestial body as Markdown to the clipboard      |
+ * | `open-link [<n>]`           | Opens the `<n>`th link of the selected body (the first)   |
+ * | `status <status>`           | Changes the status of the marked (or selected) bodies     |
+ * | `priority <priority>`       | Changes the priority of the marked (or selected) bodies   |
+ * | `tag <tag>` / `untag <tag>` | Adds / removes a tag on the marked (or selected) bodies   |
+ * | `move [<id>]`               | Moves the marked (or selected) bodies under a `Star`      |
+ * | `notify [<level>] <text>`   | Shows `<text>` at the bottom (`info`, `warning`, `error`) |
+ * | `!<command>`                | Runs a shell command on the selected body, e.g. `{id}`    |
+ * | `run [<action>]`            | Runs an action of the config on the selected body         |
+ * | `retro [<sprint>]`          | Opens the retrospective of a sprint (the active one)      |
+ *
+ * Any other name is looked up in the aliases of the `Config`.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{fmt, str::FromStr};
+
+use chrono::NaiveDate;
+use clap::Parser;
+
+use crate::{
+    app::{
+        batch::split_words,
+        cli::{NewArgs, ValueEnum},
+    },
+    core::{CelestialBodyKind, Filter, FilterError, GroupBy, Priority, Sort},
+    util,
+};
+
+use super::{view, Direction, Split, View};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Possible errors when parsing or running a command
+#[derive(Debug, PartialEq, Eq)]
+pub enum CommandError {
+    /// The command is neither built in nor an alias
+    UnknownCommand(String),
+    /// No view has the given name
+    UnknownView(String),
+    /// The command requires an argument that was not given
+    MissingArgument(String),
+    /// The argument is not valid for the command
+    InvalidArgument(String, String),
+    /// The filter given to `filter` is not valid
+    FilterError(FilterError),
+}
+
+impl std::error::Error for CommandError {}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::UnknownCommand(name) => write!(f, "Unknown command: {name}"),
+            CommandError::UnknownView(name) => write!(f, "Unknown view: {name}"),
+            CommandError::MissingArgument(name) => write!(f, "Missing argument for {name}"),
+            CommandError::InvalidArgument(name, arg) => {
+                write!(f, "Invalid argument for {name}: {arg}")
+            }
+            CommandError::FilterError(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<FilterError> for CommandError {
+    fn from(value: FilterError) -> Self {
+        Self::FilterError(value)
+    }
+}
+
+/// How important a notification is, which decides its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Level {
+    #[default]
+    Info,
+    #[value(alias = "warn")]
+    Warning,
+    Error,
+}
+
+/// A built in command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Quits, asking what to do with unsaved changes first
+    Quit,
+    /// Quits, discarding unsaved changes
+    ForceQuit,
+    /// Saves the changes made in the TUI to the database
+    Write,
+    /// Saves the changes made in the TUI, then quits
+    WriteQuit,
+    View(View),
+    /// Replaces the active pane with a new pane showing the named view, given
+    /// the rest of the arguments
+    Open(String, String),
+    /// Replaces the active pane with a new pane showing the view saved in the
+    /// `Config` under the name
+    OpenSaved(String),
+    /// Replaces the filter of the active pane, an empty filter shows everything
+    Filter(Filter),
+    /// Scopes the active pane to a `Star`, or the whole `Galaxy` if `None`
+    Focus(Option<String>),
+    Sort(Sort),
+    /// Changes what the columns of the kanban view of the active pane are
+    GroupBy(GroupBy),
+    Archived,
+    /// Shows only the celestial bodies assigned to the current user in the
+    /// active pane, or everything again
+    Mine,
+    /// Opens a blank pane next to the active pane
+    Split(Split),
+    /// Opens a pane showing the same thing as the active pane next to it
+    Clone(Split),
+    Close,
+    /// Makes the pane next to the active pane active
+    MoveFocus(Direction),
+    /// Grows or shrinks the active pane by a percentage of its split
+    ResizeView(Direction, u16),
+    /// Switches to the project with the name (see `Config::projects`), or
+    /// opens the project switcher if `None`
+    Project(Option<String>),
+    /// Shows a message at the bottom of the screen
+    Notify(Level, String),
+    /// Moves a celestial body to the trash after confirming, the selected one
+    /// if `None`
+    Delete(Option<String>),
+    /// Opens the title and description of a celestial body in the editor, the
+    /// selected one if `None`
+    Edit(Option<String>),
+    /// Creates a celestial body under the selected `Star`, given the
+    /// arguments of `planit new` (see `capture`)
+    Add(String),
+    /// Remembers a celestial body and its descendants to paste, the selected
+    /// one if `None`
+    Yank(Option<String>),
+    /// Pastes copies of the yanked celestial body under the selected `Star`
+    Paste(usize),
+    /// Copies a celestial body as Markdown to the system clipboard, the
+    /// selected one if `None`
+    Copy(Option<String>),
+    /// Opens a link of the selected celestial body with the system opener,
+    /// counting from 1
+    OpenLink(usize),
+    /// Changes the status of the celestial bodies marked in visual mode, or
+    /// the selected one, to a status of the workflow of the `Galaxy`
+    Status(String),
+    /// Changes the priority of the marked celestial bodies, or the selected
+    /// one
+    Priority(Priority),
+    /// Adds a tag to the marked celestial bodies, or the selected one
+    Tag(String),
+    /// Removes a tag from the marked celestial bodies, or the selected one
+    Untag(String),
+    /// Pins the marked celestial bodies (or the selected one) to the focus
+    /// view, or unpins them if `false`
+    Pin(bool),
+    /// Snoozes the marked celestial bodies (or the selected one) until a
+    /// date, or unsnoozes them if `None`
+    Snooze(Option<NaiveDate>),
+    /// Records why the marked celestial bodies (or the selected one) are
+    /// blocked or on hold, or removes the reason if `None`
+    Reason(Option<String>),
+    /// Moves the marked celestial bodies (or the selected one) under a `Star`,
+    /// or to the root of the `Galaxy` if `None`
+    Move(Option<String>),
+    /// Runs a shell command in the background and shows its output, with
+    /// placeholders replaced by the values of the selected celestial body
+    Shell(String),
+    /// Runs the action of the `Config` with the name on the selected
+    /// celestial body, or opens the list of its actions if `None`
+    Run(Option<String>),
+    /// Opens the form for the retrospective of the sprint with the name, or
+    /// of the active sprint if `None`
+    Retro(Option<String>),
+}
+
+impl Command {
+    /// How much `resize` grows or shrinks a pane by when no amount is given
+    pub const DEFAULT_RESIZE: u16 = 5;
+}
+
+impl FromStr for Command {
+    type Err = CommandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(command) = s.strip_prefix('!') {
+            return match command.trim() {
+                "" => Err(CommandError::MissingArgument("!".into())),
+                command => Ok(Command::Shell(command.to_string())),
+            };
+        }
+        let (name, arg) = match s.split_once(char::is_whitespace) {
+            Some((name, arg)) => (name, arg.trim()),
+            None => (s, ""),
+        };
+        let missing = || CommandError::MissingArgument(name.to_string());
+        let invalid = || CommandError::InvalidArgument(name.to_string(), arg.to_string());
+
+        match name {
+            "q" | "quit" => Ok(Command::Quit),
+            "q!" | "quit!" => Ok(Command::ForceQuit),
+            "w" | "write" => Ok(Command::Write),
+            "wq" | "x" => Ok(Command::WriteQuit),
+            "view" if arg.is_empty() => Err(missing()),
+            "view" => view::find(arg)
+                .map(|v| Command::View(v.view))
+                .ok_or_else(invalid),
+            "open" if arg.is_empty() => Err(missing()),
+            "open" => {
+                let (name, args) = arg.split_once(char::is_whitespace).unwrap_or((arg, ""));
+                match view::find(name) {
+                    Some(_) => Ok(Command::Open(name.to_string(), args.trim().to_string())),
+                    None => Err(CommandError::UnknownView(name.to_string())),
+                }
+            }
+            "filter" => Ok(Command::Filter(arg.parse()?)),
+            "sort" if arg.is_empty() => Err(missing()),
+            "sort" => Sort::from_str(arg, true)
+                .map(Command::Sort)
+                .map_err(|_| invalid()),
+            "group-by" if arg.is_empty() => Err(missing()),
+            "group-by" => arg.parse().map(Command::GroupBy).map_err(|_| invalid()),
+            "focus" if arg.is_empty() => Ok(Command::Focus(None)),
+            "focus" => Ok(Command::Focus(Some(arg.to_string()))),
+            "archived" => Ok(Command::Archived),
+            "mine" => Ok(Command::Mine),
+            "split" | "clone" if arg.is_empty() => match name {
+                "split" => Ok(Command::Split(Split::default())),
+                _ => Ok(Command::Clone(Split::default())),
+            },
+            "split" => Split::from_str(arg, true)
+                .map(Command::Split)
+                .map_err(|_| invalid()),
+            "clone" => Split::from_str(arg, true)
+                .map(Command::Clone)
+                .map_err(|_| invalid()),
+            "close" => Ok(Command::Close),
+            "pane" if arg.is_empty() => Err(missing()),
+            "pane" => Direction::from_str(arg, true)
+                .map(Command::MoveFocus)
+                .map_err(|_| invalid()),
+            "resize" if arg.is_empty() => Err(missing()),
+            "resize" => {
+                let (direction, amount) = match arg.split_once(char::is_whitespace) {
+                    Some((direction, amount)) => (direction, amount.trim().parse().ok()),
+                    None => (arg, Some(Command::DEFAULT_RESIZE)),
+                };
+                match (Direction::from_str(direction, true), amount) {
+                    (Ok(direction), Some(amount)) => Ok(Command::ResizeView(direction, amount)),
+                    _ => Err(invalid()),
+                }
+            }
+            "delete" if arg.is_empty() => Ok(Command::Delete(None)),
+            "delete" => Ok(Command::Delete(Some(arg.to_string()))),
+            "edit" if arg.is_empty() => Ok(Command::Edit(None)),
+            "edit" => Ok(Command::Edit(Some(arg.to_string()))),
+            "add" if arg.is_empty() => Err(missing()),
+            "add" => Ok(Command::Add(arg.to_string())),
+            "yank" if arg.is_empty() => Ok(Command::Yank(None)),
+            "yank" => Ok(Command::Yank(Some(arg.to_string()))),
+            "paste" if arg.is_empty() => Ok(Command::Paste(1)),
+            "paste" => arg.parse().map(Command::Paste).map_err(|_| invalid()),
+            "copy" if arg.is_empty() => Ok(Command::Copy(None)),
+            "copy" => Ok(Command::Copy(Some(arg.to_string()))),
+            "open-link" if arg.is_empty() => Ok(Command::OpenLink(1)),
+            "open-link" => match arg.parse() {
+                Ok(0) | Err(_) => Err(invalid()),
+                Ok(n) => Ok(Command::OpenLink(n)),
+            },
+            "status" | "tag" | "untag" if arg.is_empty() => Err(missing()),
+            "status" => Ok(Command::Status(arg.to_string())),
+            "tag" => Ok(Command::Tag(arg.to_string())),
+            "priority" if arg.is_empty() => Err(missing()),
+            "priority" => Priority::from_str(arg, true)
+                .map(Command::Priority)
+                .map_err(|_| invalid()),
+            "untag" => Ok(Command::Untag(arg.to_string())),
+            "pin" => Ok(Command::Pin(true)),
+            "unpin" => Ok(Command::Pin(false)),
+            "snooze" if arg.is_empty() => Err(missing()),
+            "snooze" => util::date::parse_from_today(arg.trim_start_matches("until ").trim())
+                .map(|until| Command::Snooze(Some(until)))
+                .map_err(|_| invalid()),
+            "unsnooze" => Ok(Command::Snooze(None)),
+            "reason" if arg.is_empty() => Ok(Command::Reason(None)),
+            "reason" => Ok(Command::Reason(Some(arg.to_string()))),
+            "move" if arg.is_empty() => Ok(Command::Move(None)),
+            "move" => Ok(Command::Move(Some(arg.to_string()))),
+            "run" if arg.is_empty() => Ok(Command::Run(None)),
+            "run" => Ok(Command::Run(Some(arg.to_string()))),
+            "retro" if arg.is_empty() => Ok(Command::Retro(None)),
+            "retro" => Ok(Command::Retro(Some(arg.to_string()))),
+            "project" if arg.is_empty() => Ok(Command::Project(None)),
+            "project" => Ok(Command::Project(Some(arg.to_string()))),
+            "notify" if arg.is_empty() => Err(missing()),
+            "notify" => {
+                let (level, text) = match arg.split_once(char::is_whitespace) {
+                    Some((level, text)) => match Level::from_str(level, true) {
+                        Ok(level) => (level, text.trim()),
+                        Err(_) => (Level::Info, arg),
+                    },
+                    None => (Level::Info, arg),
+                };
+                Ok(Command::Notify(level, text.to_string()))
+            }
+            _ => Err(CommandError::UnknownCommand(name.to_string())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A message shown at the bottom of the screen until the next key press, e.g.
+/// the error from the last command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub level: Level,
+    pub text: String,
+}
+
+/// The arguments typed into the quick-add popup, see `capture`
+#[derive(Parser)]
+#[command(no_binary_name = true)]
+struct Capture {
+    #[command(flatten)]
+    args: NewArgs,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses the text typed into the quick-add popup (or given to `add`), which
+/// takes the same arguments as `planit new`. The kind can be left out to
+/// create a `Planet`, e.g. `"Fix the login" -p high -e 2h`.
+///
+/// # Errors
+/// Errors will occur if the text is not valid arguments for `planit new`, or
+/// gives a `Star` an estimate or anything but a `Planet` a due date
+pub fn capture(line: &str) -> Result<NewArgs, CommandError> {
+    let invalid = |message: &str| CommandError::InvalidArgument("add".into(), message.into());
+    let mut words = split_words(line).map_err(|e| invalid(&e))?;
+    if words.is_empty() {
+        return Err(CommandError::MissingArgument("add".into()));
+    }
+    if CelestialBodyKind::from_str(&words[0], true).is_err() {
+        words.insert(0, "planet".into());
+    }
+
+    let args = Capture::try_parse_from(words)
+        .map_err(|e| {
+            // Only the first line of clap's message, without the usage
+            let message = e.to_string();
+            invalid(
+                message
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .trim_start_matches("error: "),
+            )
+        })?
+        .args;
+    if args.kind == CelestialBodyKind::Star && args.estimate.is_some() {
+        return Err(invalid("Stars cannot have an estimate"));
+    }
+    if args.kind != CelestialBodyKind::Planet && args.due.is_some() {
+        return Err(invalid("Only Planets can have a due date"));
+    }
+    Ok(args)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn commands_are_parsed() {
+        assert_eq!("quit".parse(), Ok(Command::Quit));
+        assert_eq!("q!".parse(), Ok(Command::ForceQuit));
+        assert_eq!("wq".parse(), Ok(Command::WriteQuit));
+        assert_eq!("mine".parse(), Ok(Command::Mine));
+        assert_eq!(" view  Kanban ".parse(), Ok(Command::View(View::Kanban)));
+        assert_eq!(
+            "filter tag:inbox".parse(),
+            Ok(Command::Filter("tag:inbox".parse().unwrap()))
+        );
+        assert_eq!("filter".parse(), Ok(Command::Filter(Filter::default())));
+        assert_eq!("view board".parse(), Ok(Command::View(View::Kanban)));
+        assert_eq!(
+            "open tree  tag:inbox".parse(),
+            Ok(Command::Open("tree".into(), "tag:inbox".into()))
+        );
+        assert_eq!("sort Due".parse(), Ok(Command::Sort(Sort::Due)));
+        assert_eq!(
+            "sort size".parse::<Command>(),
+            Err(CommandError::InvalidArgument("sort".into(), "size".into()))
+        );
+        assert_eq!("focus #3".parse(), Ok(Command::Focus(Some("#3".into()))));
+        assert_eq!("focus".parse(), Ok(Command::Focus(None)));
+        assert_eq!(
+            "group-by field:team".parse(),
+            Ok(Command::GroupBy(GroupBy::Field("team".into())))
+        );
+        assert_eq!("split".parse(), Ok(Command::Split(Split::Right)));
+        assert_eq!("clone below".parse(), Ok(Command::Clone(Split::Below)));
+        assert_eq!("pane up".parse(), Ok(Command::MoveFocus(Direction::Up)));
+        assert_eq!("paste".parse(), Ok(Command::Paste(1)));
+        assert_eq!("status done".parse(), Ok(Command::Status("done".into())));
+        assert_eq!("tag inbox".parse(), Ok(Command::Tag("inbox".into())));
+        assert_eq!(
+            "priority HIGH".parse(),
+            Ok(Command::Priority(Priority::High))
+        );
+        assert_eq!("move".parse(), Ok(Command::Move(None)));
+        assert_eq!(
+            "reason waiting on legal".parse(),
+            Ok(Command::Reason(Some("waiting on legal".into())))
+        );
+        assert_eq!("paste 3".parse(), Ok(Command::Paste(3)));
+        assert_eq!("yank #2".parse(), Ok(Command::Yank(Some("#2".into()))));
+        assert_eq!("open-link".parse(), Ok(Command::OpenLink(1)));
+        assert_eq!("open-link 2".parse(), Ok(Command::OpenLink(2)));
+        assert_eq!(
+            "open-link 0".parse::<Command>(),
+            Err(CommandError::InvalidArgument(
+                "open-link".into(),
+                "0".into()
+            ))
+        );
+        assert_eq!(
+            "resize left".parse(),
+            Ok(Command::ResizeView(Direction::Left, 5))
+        );
+        assert_eq!(
+            "resize down 20".parse(),
+            Ok(Command::ResizeView(Direction::Down, 20))
+        );
+        assert_eq!(
+            "notify warn  Almost out of time".parse(),
+            Ok(Command::Notify(Level::Warning, "Almost out of time".into()))
+        );
+        assert_eq!(
+            "notify saved".parse(),
+            Ok(Command::Notify(Level::Info, "saved".into()))
+        );
+        assert_eq!("run".parse(), Ok(Command::Run(None)));
+        assert_eq!(
+            "run Open PR page".parse(),
+            Ok(Command::Run(Some("Open PR page".into())))
+        );
+        assert_eq!("retro".parse(), Ok(Command::Retro(None)));
+        assert_eq!(
+            "retro Sprint 2".parse(),
+            Ok(Command::Retro(Some("Sprint 2".into())))
+        );
+        assert_eq!(
+            "! git log --grep {id}".parse(),
+            Ok(Command::Shell("git log --grep {id}".into()))
+        );
+    }
+
+    #[test]
+    fn invalid_commands_produce_errors() {
+        assert_eq!(
+            "view".parse::<Command>(),
+            Err(CommandError::MissingArgument("view".into()))
+        );
+        assert_eq!(
+            "view list".parse::<Command>(),
+            Err(CommandError::InvalidArgument("view".into(), "list".into()))
+        );
+        assert_eq!(
+            "open calendar".parse::<Command>(),
+            Err(CommandError::UnknownView("calendar".into()))
+        );
+        assert_eq!(
+            "pane".parse::<Command>(),
+            Err(CommandError::MissingArgument("pane".into()))
+        );
+        assert_eq!(
+            "resize left a lot".parse::<Command>(),
+            Err(CommandError::InvalidArgument(
+                "resize".into(),
+                "left a lot".into()
+            ))
+        );
+        assert_eq!(
+            "split above".parse::<Command>(),
+            Err(CommandError::InvalidArgument(
+                "split".into(),
+                "above".into()
+            ))
+        );
+        assert_eq!(
+            "priority urgent".parse::<Command>(),
+            Err(CommandError::InvalidArgument(
+                "priority".into(),
+                "urgent".into()
+            ))
+        );
+        assert_eq!(
+            "!".parse::<Command>(),
+            Err(CommandError::MissingArgument("!".into()))
+        );
+        assert_eq!(
+            "triage".parse::<Command>(),
+            Err(CommandError::UnknownCommand("triage".into()))
+        );
+        assert!(matches!(
+            "filter bogus:1".parse::<Command>(),
+            Err(CommandError::FilterError(_))
+        ));
+    }
+
+    #[test]
+    fn quick_add_takes_the_arguments_of_new() {
+        let args = capture(r#""Fix the login" -p high -e 2h -a ana,ben"#).unwrap();
+        assert_eq!(args.kind, CelestialBodyKind::Planet);
+        assert_eq!(args.title, "Fix the login");
+        assert_eq!(args.priority, crate::core::Priority::High);
+        assert!(args.estimate.is_some());
+        assert_eq!(args.assignees, ["ana", "ben"]);
+
+        let args = capture("comet Typo 'In the readme'").unwrap();
+        assert_eq!(args.kind, CelestialBodyKind::Comet);
+        assert_eq!(args.description.as_deref(), Some("In the readme"));
+
+        assert!(matches!(
+            capture("  "),
+            Err(CommandError::MissingArgument(_))
+        ));
+        assert!(capture("Fix the login").is_err());
+        assert!(capture("star Backend -e 3pts").is_err());
+        assert!(capture("'Unclosed").is_err());
+
+        let args = capture("'Ship it' --due 2025-12-01").unwrap();
+        assert_eq!(
+            args.due.map(|d| d.to_string()).as_deref(),
+            Some("2025-12-01")
+        );
+        assert!(capture("'Ship it' --due tomorrow").unwrap().due.is_some());
+        assert_eq!(
+            capture("'Ship it' --due someday").err(),
+            Some(CommandError::InvalidArgument(
+                "add".into(),
+                "invalid value 'someday' for '--due <DUE>': Invalid date \"someday\", expected \
+                 e.g. 2025-12-01, today, tomorrow, fri, eow, in 2 weeks, or 3 days ago"
+                    .into()
+            ))
+        );
+        assert!(capture("comet Typo --due fri").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the prompt shown before destructive operations in the TUI.
+ *
+ * Most prompts are answered with `y` or `n`. Prompts for operations that are
+ * hard to undo can instead require some text (e.g. the title of a `Star`) to
+ * be typed, so that they cannot be confirmed by accident.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::Input;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What is done once a prompt is confirmed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pending {
+    /// Moves the celestial body (and its descendants) to the trash
+    Delete(u64),
+    /// Moves the marked celestial bodies (and their descendants) to the trash
+    DeleteMany(Vec<u64>),
+    /// Moves the Stars to `Done`, once the status rules found that all of
+    /// their children are finished
+    Complete(Vec<u64>),
+    /// Saves the unsaved changes before quitting, which are discarded if the
+    /// prompt is answered with `n`
+    Quit,
+}
+
+/// The answer to a prompt after a key press
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    /// The prompt has not been answered yet
+    Waiting,
+    Yes,
+    No,
+    /// The prompt was dismissed with `Esc`, which is the same as `No` for
+    /// most prompts
+    Cancel,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A question that has to be answered before `pending` is done
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Confirm {
+    pub question: String,
+    /// The text that has to be typed to confirm, the prompt is answered with
+    /// `y` or `n` if `None`
+    pub expected: Option<String>,
+    /// What has been typed so far
+    pub input: Input,
+    pub pending: Pending,
+}
+
+impl Confirm {
+    /// Creates a prompt that is answered with `y` or `n`
+    pub fn new(question: String, pending: Pending) -> Self {
+        Self {
+            question,
+            expected: None,
+            input: Input::default(),
+            pending,
+        }
+    }
+
+    /// Creates a prompt that is only confirmed by typing `expected` and
+    /// pressing enter
+    pub fn typed(question: String, expected: String, pending: Pending) -> Self {
+        Self {
+            expected: Some(expected),
+            ..Self::new(question, pending)
+        }
+    }
+
+    /// Handles a single key press, returning whether the prompt was answered
+    pub fn handle_key(&mut self, key: KeyEvent) -> Answer {
+        match (&self.expected, key.code) {
+            (_, KeyCode::Esc) => Answer::Cancel,
+            (None, KeyCode::Char('y' | 'Y')) => Answer::Yes,
+            (None, KeyCode::Char('n' | 'N')) => Answer::No,
+            (None, _) => Answer::Waiting,
+            (Some(expected), KeyCode::Enter) if self.input.text() == expected => Answer::Yes,
+            (Some(_), KeyCode::Enter) => Answer::No,
+            (Some(_), _) => {
+                self.input.handle_key(key);
+                Answer::Waiting
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+
+    fn press(confirm: &mut Confirm, code: KeyCode) -> Answer {
+        confirm.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn typed_prompts_need_the_exact_text() {
+        let mut confirm = Confirm::typed("Delete?".into(), "Backend".into(), Pending::Delete(0));
+        assert_eq!(press(&mut confirm, KeyCode::Char('y')), Answer::Waiting);
+        press(&mut confirm, KeyCode::Backspace);
+        for c in "Backend".chars() {
+            assert_eq!(press(&mut confirm, KeyCode::Char(c)), Answer::Waiting);
+        }
+        assert_eq!(press(&mut confirm, KeyCode::Enter), Answer::Yes);
+
+        confirm.input = "Backen".into();
+        assert_eq!(press(&mut confirm, KeyCode::Enter), Answer::No);
+
+        let mut confirm = Confirm::new("Delete?".into(), Pending::Delete(0));
+        assert_eq!(press(&mut confirm, KeyCode::Char('x')), Answer::Waiting);
+        assert_eq!(press(&mut confirm, KeyCode::Char('Y')), Answer::Yes);
+        assert_eq!(press(&mut confirm, KeyCode::Char('n')), Answer::No);
+        assert_eq!(press(&mut confirm, KeyCode::Esc), Answer::Cancel);
+    }
+}
@@ -0,0 +1,234 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the events the TUI reacts to.
+ *
+ * Key presses, ticks of a timer, changes to the database made outside of the
+ * TUI, and commands sent from elsewhere all arrive as a single `AppEvent`, so
+ * the TUI is redrawn after each of them instead of only after key presses.
+ * Nothing busy-polls: the terminal is waited on until the next tick is due,
+ * and the database is only looked at on ticks.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    fmt, io,
+    sync::mpsc::{self, Receiver, Sender},
+    time::{Duration, Instant, SystemTime},
+};
+
+use crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+
+use crate::core::Galaxy;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Something the TUI reacts to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppEvent {
+    Key(KeyEvent),
+    /// Text pasted into the terminal
+    Paste(String),
+    /// The terminal was resized
+    Resize,
+    /// Sent every `Events::TICK`, e.g. to update the clock and save on its own
+    Tick,
+    /// The database was changed outside of the TUI
+    FsChange,
+    /// A command to run, as if it was typed after `:`
+    Command(String),
+    /// The TUI was asked to stop
+    Signal(Signal),
+}
+
+/// The signals that stop the TUI, see `Events::catch_signals`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    /// `SIGINT`, or `Ctrl+c` in the TUI
+    Interrupt,
+    /// `SIGTERM`
+    Terminate,
+    /// `SIGHUP`, e.g. when the terminal is closed
+    Hangup,
+}
+
+impl Signal {
+    /// The exit code of a process stopped by the signal, which is 128 plus
+    /// the number of the signal
+    pub fn exit_code(self) -> i32 {
+        128 + match self {
+            Signal::Interrupt => 2,
+            Signal::Terminate => 15,
+            Signal::Hangup => 1,
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Signal::Interrupt => write!(f, "SIGINT"),
+            Signal::Terminate => write!(f, "SIGTERM"),
+            Signal::Hangup => write!(f, "SIGHUP"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Where the events of the TUI come from
+#[derive(Debug)]
+pub struct Events {
+    /// When the next `AppEvent::Tick` is sent
+    next_tick: Instant,
+    /// Whether the database is watched for changes made outside of the TUI
+    watching: bool,
+    /// When the database was last changed as far as the TUI knows, see
+    /// `saw_database`
+    modified: Option<SystemTime>,
+    sender: Sender<AppEvent>,
+    receiver: Receiver<AppEvent>,
+}
+
+impl Default for Events {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            next_tick: Instant::now() + Self::TICK,
+            watching: false,
+            modified: None,
+            sender,
+            receiver,
+        }
+    }
+}
+
+impl Events {
+    /// How often `AppEvent::Tick` is sent
+    pub const TICK: Duration = Duration::from_secs(1);
+
+    /// Starts watching the database, sending `AppEvent::FsChange` on the next
+    /// tick after it is changed outside of the TUI
+    pub fn watch(&mut self) {
+        self.watching = true;
+        self.saw_database();
+    }
+
+    /// Remembers the current state of the database, e.g. after the TUI saved
+    /// or loaded it, so that it is not reported as changed
+    pub fn saw_database(&mut self) {
+        if self.watching {
+            self.modified = Galaxy::last_modified();
+        }
+    }
+
+    /// Makes the next `AppEvent::Tick` come after `within` at the latest, e.g.
+    /// to count down faster than every `TICK`
+    pub fn tick_within(&mut self, within: Duration) {
+        self.next_tick = self.next_tick.min(Instant::now() + within);
+    }
+
+    /// Returns a sender for events from elsewhere, e.g. other threads
+    pub fn sender(&self) -> Sender<AppEvent> {
+        self.sender.clone()
+    }
+
+    /// Sends `AppEvent::Signal` instead of ending the process on `SIGINT`,
+    /// `SIGTERM`, and `SIGHUP`, so that the terminal can be restored first.
+    /// The signal is handled on the next tick at the latest.
+    ///
+    /// # Errors
+    /// Errors will occur if the signal handlers cannot be registered
+    #[cfg(unix)]
+    pub fn catch_signals(&self) -> io::Result<()> {
+        use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+
+        let mut signals = signal_hook::iterator::Signals::new([SIGINT, SIGTERM, SIGHUP])?;
+        let sender = self.sender();
+        std::thread::spawn(move || {
+            for signal in signals.forever() {
+                let signal = match signal {
+                    SIGINT => Signal::Interrupt,
+                    SIGTERM => Signal::Terminate,
+                    _ => Signal::Hangup,
+                };
+                if sender.send(AppEvent::Signal(signal)).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Signals are only caught on unix
+    #[cfg(not(unix))]
+    pub fn catch_signals(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Waits for the next event. Events that were sent are returned first,
+    /// then key presses and other terminal events until the next tick is due.
+    ///
+    /// # Errors
+    /// Errors will occur if the terminal cannot be read from
+    pub fn wait(&mut self) -> io::Result<AppEvent> {
+        loop {
+            if let Ok(event) = self.receiver.try_recv() {
+                return Ok(event);
+            }
+            let timeout = self.next_tick.saturating_duration_since(Instant::now());
+            if !event::poll(timeout)? {
+                self.next_tick = Instant::now() + Self::TICK;
+                if self.watching && Galaxy::last_modified() != self.modified {
+                    self.saw_database();
+                    let _ = self.sender.send(AppEvent::FsChange);
+                }
+                return Ok(AppEvent::Tick);
+            }
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    return Ok(AppEvent::Key(key))
+                }
+                Event::Paste(text) => return Ok(AppEvent::Paste(text)),
+                Event::Resize(_, _) => return Ok(AppEvent::Resize),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,358 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the text input used wherever text is typed in the TUI, such as the
+ * command line and typed prompts.
+ *
+ * Keys mostly follow readline: `Ctrl`/`Alt` with the arrow keys jump words,
+ * `Ctrl+w` and `Alt+d` delete words, `Ctrl+u` / `Ctrl+k` delete to the start /
+ * end of the line, and `Shift` with a movement key selects text. `Ctrl+v`
+ * pastes from the system clipboard, and text pasted into the terminal is
+ * inserted as well.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::ops::Range;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::util::shell;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Text that is being typed, along with the cursor and selection
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+    /// Byte offset of the cursor, which is always on a character boundary
+    cursor: usize,
+    /// Where the selection was started, the selected text is between it and
+    /// the cursor
+    anchor: Option<usize>,
+    /// Whether enter (and up / down) are used to edit multiple lines, rather
+    /// than being left to the caller
+    multiline: bool,
+}
+
+impl Input {
+    /// Creates a single line input containing `text`, with the cursor at the
+    /// end
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self {
+            cursor: text.len(),
+            text,
+            anchor: None,
+            multiline: false,
+        }
+    }
+
+    /// Creates an input containing `text` that can span multiple lines
+    pub fn multiline(text: impl Into<String>) -> Self {
+        Self {
+            multiline: true,
+            ..Self::new(text)
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Removes all of the text, returning it
+    pub fn take(&mut self) -> String {
+        self.cursor = 0;
+        self.anchor = None;
+        std::mem::take(&mut self.text)
+    }
+
+    /// The byte range of the selected text, `None` if nothing is selected
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor.filter(|a| *a != self.cursor)?;
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    /// The line and column (counted in characters) of the cursor
+    pub fn cursor(&self) -> (usize, usize) {
+        let before = &self.text[..self.cursor];
+        let line = before.matches('\n').count();
+        (line, before[self.line_start()..].chars().count())
+    }
+
+    /// Handles a single key press, returning whether it was used. Keys that
+    /// are not used (e.g. enter in a single line input) are up to the caller.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let word = control || alt;
+
+        match key.code {
+            KeyCode::Left if word => self.move_to(self.previous_word(), shift),
+            KeyCode::Left => self.move_to(self.previous_char(), shift),
+            KeyCode::Right if word => self.move_to(self.next_word(), shift),
+            KeyCode::Right => self.move_to(self.next_char(), shift),
+            KeyCode::Home => self.move_to(self.line_start(), shift),
+            KeyCode::End => self.move_to(self.line_end(), shift),
+            KeyCode::Up if self.multiline => self.move_to(self.line_above(), shift),
+            KeyCode::Down if self.multiline => self.move_to(self.line_below(), shift),
+            KeyCode::Enter if self.multiline => self.insert("\n"),
+            KeyCode::Backspace if word => self.delete_to(self.previous_word()),
+            KeyCode::Backspace => self.delete_to(self.previous_char()),
+            KeyCode::Delete if word => self.delete_to(self.next_word()),
+            KeyCode::Delete => self.delete_to(self.next_char()),
+            KeyCode::Char(c) if control => match c {
+                'a' => self.move_to(self.line_start(), false),
+                'e' => self.move_to(self.line_end(), false),
+                'w' => self.delete_to(self.previous_word()),
+                'u' => self.delete_to(self.line_start()),
+                'k' => self.delete_to(self.line_end()),
+                'v' => {
+                    if let Some(text) = shell::clipboard() {
+                        self.paste(&text);
+                    }
+                }
+                _ => return false,
+            },
+            KeyCode::Char(c) if alt => match c {
+                'b' => self.move_to(self.previous_word(), false),
+                'f' => self.move_to(self.next_word(), false),
+                'd' => self.delete_to(self.next_word()),
+                _ => return false,
+            },
+            KeyCode::Char(c) => self.insert(c.encode_utf8(&mut [0; 4])),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Inserts pasted `text` at the cursor. Line breaks become spaces in a
+    /// single line input, and a trailing one is dropped.
+    pub fn paste(&mut self, text: &str) {
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        let text = text
+            .strip_suffix('\r')
+            .unwrap_or(text)
+            .replace("\r\n", "\n");
+        match self.multiline {
+            true => self.insert(&text),
+            false => self.insert(&text.replace('\n', " ")),
+        }
+    }
+
+    /// Helper function that replaces the selection (if any) with `text`
+    fn insert(&mut self, text: &str) {
+        self.delete_selection();
+        self.text.insert_str(self.cursor, text);
+        self.cursor += text.len();
+    }
+
+    /// Helper function that moves the cursor to `position`, extending the
+    /// selection if `select` and clearing it otherwise
+    fn move_to(&mut self, position: usize, select: bool) {
+        match select {
+            true => {
+                self.anchor.get_or_insert(self.cursor);
+            }
+            false => self.anchor = None,
+        }
+        self.cursor = position;
+    }
+
+    /// Helper function that deletes the text between the cursor and
+    /// `position`, or the selection if there is one
+    fn delete_to(&mut self, position: usize) {
+        if self.delete_selection() {
+            return;
+        }
+        let range = position.min(self.cursor)..position.max(self.cursor);
+        self.cursor = range.start;
+        self.text.replace_range(range, "");
+    }
+
+    /// Helper function that deletes the selected text, returning whether
+    /// anything was selected
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.anchor = None;
+        match selection {
+            Some(range) => {
+                self.cursor = range.start;
+                self.text.replace_range(range, "");
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn previous_char(&self) -> usize {
+        let c = self.text[..self.cursor].chars().next_back();
+        self.cursor - c.map_or(0, char::len_utf8)
+    }
+
+    fn next_char(&self) -> usize {
+        let c = self.text[self.cursor..].chars().next();
+        self.cursor + c.map_or(0, char::len_utf8)
+    }
+
+    /// Helper function that finds the start of the word before the cursor
+    fn previous_word(&self) -> usize {
+        let before = self.text[..self.cursor].trim_end_matches(|c| !is_word(c));
+        before.trim_end_matches(is_word).len()
+    }
+
+    /// Helper function that finds the end of the word after the cursor
+    fn next_word(&self) -> usize {
+        let after = self.text[self.cursor..].trim_start_matches(|c| !is_word(c));
+        self.text.len() - after.trim_start_matches(is_word).len()
+    }
+
+    fn line_start(&self) -> usize {
+        self.text[..self.cursor].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn line_end(&self) -> usize {
+        let end = self.text[self.cursor..].find('\n');
+        end.map_or(self.text.len(), |i| self.cursor + i)
+    }
+
+    /// Helper function that finds the same column on the line above, or the
+    /// end of that line if it is shorter
+    fn line_above(&self) -> usize {
+        let start = self.line_start();
+        if start == 0 {
+            return self.cursor;
+        }
+        let above = self.text[..start - 1].rfind('\n').map_or(0, |i| i + 1);
+        self.column_in(above..start - 1)
+    }
+
+    /// Helper function that finds the same column on the line below, or the
+    /// end of that line if it is shorter
+    fn line_below(&self) -> usize {
+        let end = self.line_end();
+        if end == self.text.len() {
+            return self.cursor;
+        }
+        let below = end + 1;
+        let below_end = self.text[below..].find('\n');
+        self.column_in(below..below_end.map_or(self.text.len(), |i| below + i))
+    }
+
+    /// Helper function that finds the column of the cursor in the line `line`
+    fn column_in(&self, line: Range<usize>) -> usize {
+        let (_, column) = self.cursor();
+        let mut chars = self.text[line.clone()].char_indices();
+        chars.nth(column).map_or(line.end, |(i, _)| line.start + i)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that decides which characters are part of a word
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn press(input: &mut Input, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        input.handle_key(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn words_are_jumped_and_deleted() {
+        let mut input = Input::new("filter tag:inbox");
+        press(&mut input, KeyCode::Left, KeyModifiers::CONTROL);
+        assert_eq!(input.cursor(), (0, 11));
+        press(&mut input, KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(input.text(), "filter inbox");
+        press(&mut input, KeyCode::Char('a'), KeyModifiers::CONTROL);
+        press(&mut input, KeyCode::Char('d'), KeyModifiers::ALT);
+        assert_eq!(input.text(), " inbox");
+        press(&mut input, KeyCode::Char('é'), KeyModifiers::NONE);
+        press(&mut input, KeyCode::Right, KeyModifiers::NONE);
+        press(&mut input, KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(input.text(), "éinbox");
+        assert_eq!(input.cursor(), (0, 1));
+        assert!(!press(&mut input, KeyCode::Enter, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn selections_are_replaced() {
+        let mut input = Input::new("focus #12");
+        press(&mut input, KeyCode::Left, KeyModifiers::SHIFT);
+        press(&mut input, KeyCode::Left, KeyModifiers::SHIFT);
+        assert_eq!(input.selection(), Some(7..9));
+        input.paste("3\n");
+        assert_eq!(input.text(), "focus #3");
+        assert_eq!(input.selection(), None);
+    }
+
+    #[test]
+    fn multiline_inputs_move_between_lines() {
+        let mut input = Input::multiline("first line\nab");
+        press(&mut input, KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(input.cursor(), (0, 2));
+        press(&mut input, KeyCode::End, KeyModifiers::NONE);
+        press(&mut input, KeyCode::Down, KeyModifiers::NONE);
+        assert_eq!(input.cursor(), (1, 2));
+        press(&mut input, KeyCode::Enter, KeyModifiers::NONE);
+        input.paste("c\r\nd");
+        assert_eq!(input.text(), "first line\nab\nc\nd");
+    }
+}
@@ -0,0 +1,411 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the tables of built in key bindings. Key presses are looked up in
+ * these tables, and the help overlay (`?`) is generated from them, so the
+ * help always shows what the keys actually do.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{Direction, Split, View};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TYPES                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The keys used in `Mode::Normal`
+pub const NORMAL: &[Binding] = &[
+    Binding::new(KeyCode::Char('q'), Action::Quit),
+    Binding::new(KeyCode::Esc, Action::Quit),
+    Binding::new(KeyCode::Char(':'), Action::CommandLine),
+    Binding::new(KeyCode::Char('?'), Action::Help),
+    Binding::new(KeyCode::Char('L'), Action::Legend),
+    Binding::new(KeyCode::Char('j'), Action::Down),
+    Binding::new(KeyCode::Down, Action::Down),
+    Binding::new(KeyCode::Char('k'), Action::Up),
+    Binding::new(KeyCode::Up, Action::Up),
+    Binding::new(KeyCode::Char('g'), Action::First),
+    Binding::new(KeyCode::Home, Action::First),
+    Binding::new(KeyCode::Char('G'), Action::Last),
+    Binding::new(KeyCode::End, Action::Last),
+    Binding::new(KeyCode::Char('d'), Action::PageDown).control(),
+    Binding::new(KeyCode::PageDown, Action::PageDown),
+    Binding::new(KeyCode::Char('u'), Action::PageUp).control(),
+    Binding::new(KeyCode::PageUp, Action::PageUp),
+    Binding::new(KeyCode::Char('/'), Action::Search),
+    Binding::new(KeyCode::Char('n'), Action::SearchNext),
+    Binding::new(KeyCode::Char('N'), Action::SearchPrevious),
+    Binding::new(KeyCode::Char('h'), Action::Left).only(&[View::Kanban]),
+    Binding::new(KeyCode::Left, Action::Left).only(&[View::Kanban]),
+    Binding::new(KeyCode::Char('l'), Action::Right).only(&[View::Kanban]),
+    Binding::new(KeyCode::Right, Action::Right).only(&[View::Kanban]),
+    Binding::new(KeyCode::Char('v'), Action::CycleView),
+    Binding::new(KeyCode::Tab, Action::CycleView),
+    Binding::new(KeyCode::Char('a'), Action::ToggleArchived),
+    Binding::new(KeyCode::Char('A'), Action::QuickAdd),
+    Binding::new(KeyCode::Char('y'), Action::Yank).only(&[View::Tree]),
+    Binding::new(KeyCode::Char('p'), Action::Paste).only(&[View::Tree]),
+    Binding::new(KeyCode::Char('K'), Action::MoveUp).only(&[View::Tree]),
+    Binding::new(KeyCode::Char('J'), Action::MoveDown).only(&[View::Tree]),
+    Binding::new(KeyCode::Char('Y'), Action::Copy),
+    Binding::new(KeyCode::Char('m'), Action::ToggleMine),
+    Binding::new(KeyCode::Char('P'), Action::Projects),
+    Binding::new(KeyCode::Char('r'), Action::Actions),
+    Binding::new(KeyCode::Char('d'), Action::Delete),
+    Binding::new(KeyCode::Char('e'), Action::Edit),
+    Binding::new(KeyCode::Char('o'), Action::OpenLink),
+    Binding::new(KeyCode::Char('s'), Action::Split(Split::Right)),
+    Binding::new(KeyCode::Char('S'), Action::Clone(Split::Right)),
+    Binding::new(KeyCode::Char('x'), Action::Close),
+    Binding::new(KeyCode::Char('h'), Action::MoveFocus(Direction::Left)).control(),
+    Binding::new(KeyCode::Char('j'), Action::MoveFocus(Direction::Down)).control(),
+    Binding::new(KeyCode::Char('k'), Action::MoveFocus(Direction::Up)).control(),
+    Binding::new(KeyCode::Char('l'), Action::MoveFocus(Direction::Right)).control(),
+    Binding::new(KeyCode::Char('w'), Action::PaneMode).control(),
+    Binding::new(KeyCode::Char('V'), Action::Visual),
+    Binding::new(KeyCode::Char(' '), Action::ToggleMark),
+];
+
+/// The keys used in `Mode::Visual`, after `V` or `Space`
+pub const VISUAL: &[Binding] = &[
+    Binding::new(KeyCode::Esc, Action::Visual),
+    Binding::new(KeyCode::Char('V'), Action::Visual),
+    Binding::new(KeyCode::Char(' '), Action::ToggleMark),
+    Binding::new(KeyCode::Char(':'), Action::CommandLine),
+    Binding::new(KeyCode::Char('?'), Action::Help),
+    Binding::new(KeyCode::Char('d'), Action::Delete),
+    Binding::new(KeyCode::Char('j'), Action::Down),
+    Binding::new(KeyCode::Down, Action::Down),
+    Binding::new(KeyCode::Char('k'), Action::Up),
+    Binding::new(KeyCode::Up, Action::Up),
+    Binding::new(KeyCode::Char('g'), Action::First),
+    Binding::new(KeyCode::Home, Action::First),
+    Binding::new(KeyCode::Char('G'), Action::Last),
+    Binding::new(KeyCode::End, Action::Last),
+    Binding::new(KeyCode::Char('d'), Action::PageDown).control(),
+    Binding::new(KeyCode::PageDown, Action::PageDown),
+    Binding::new(KeyCode::Char('u'), Action::PageUp).control(),
+    Binding::new(KeyCode::PageUp, Action::PageUp),
+    Binding::new(KeyCode::Char('n'), Action::SearchNext),
+    Binding::new(KeyCode::Char('N'), Action::SearchPrevious),
+    Binding::new(KeyCode::Char('h'), Action::Left).only(&[View::Kanban]),
+    Binding::new(KeyCode::Left, Action::Left).only(&[View::Kanban]),
+    Binding::new(KeyCode::Char('l'), Action::Right).only(&[View::Kanban]),
+    Binding::new(KeyCode::Right, Action::Right).only(&[View::Kanban]),
+];
+
+/// The keys used in `Mode::Pane`, after `Ctrl+w`
+pub const PANE: &[Binding] = &[
+    Binding::new(KeyCode::Char('<'), Action::Resize(Direction::Left)),
+    Binding::new(KeyCode::Char('>'), Action::Resize(Direction::Right)),
+    Binding::new(KeyCode::Char('-'), Action::Resize(Direction::Up)),
+    Binding::new(KeyCode::Char('+'), Action::Resize(Direction::Down)),
+    Binding::new(KeyCode::Char('h'), Action::MoveFocus(Direction::Left)),
+    Binding::new(KeyCode::Char('j'), Action::MoveFocus(Direction::Down)),
+    Binding::new(KeyCode::Char('k'), Action::MoveFocus(Direction::Up)),
+    Binding::new(KeyCode::Char('l'), Action::MoveFocus(Direction::Right)),
+    Binding::new(KeyCode::Char('s'), Action::Split(Split::Below)),
+    Binding::new(KeyCode::Char('v'), Action::Split(Split::Right)),
+    Binding::new(KeyCode::Char('x'), Action::Close),
+    Binding::new(KeyCode::Char('?'), Action::Help),
+];
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What a built in key does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    /// Starts typing a command
+    CommandLine,
+    /// Shows the help overlay
+    Help,
+    /// Shows what the icons and colors mean, see `cli::legend_sections`
+    Legend,
+    /// Makes the next key act on the panes
+    PaneMode,
+    /// Starts marking the celestial bodies from the selection on, or stops
+    /// marking them
+    Visual,
+    /// Marks or unmarks the selected celestial body
+    ToggleMark,
+    Down,
+    Up,
+    First,
+    Last,
+    /// Moves the selection down by the height of the list
+    PageDown,
+    /// Moves the selection up by the height of the list
+    PageUp,
+    /// Starts typing what to search for
+    Search,
+    SearchNext,
+    SearchPrevious,
+    /// Moves to the kanban column on the left
+    Left,
+    /// Moves to the kanban column on the right
+    Right,
+    CycleView,
+    ToggleArchived,
+    /// Shows only the celestial bodies assigned to the current user, or
+    /// everything again
+    ToggleMine,
+    /// Opens the project switcher, see `Config::projects`
+    Projects,
+    /// Lists the actions of the `Config` that can be run on the selected
+    /// celestial body
+    Actions,
+    /// Opens the quick-add popup, which creates a celestial body under the
+    /// selected `Star`
+    QuickAdd,
+    /// Remembers the selected celestial body (and its descendants) to paste
+    Yank,
+    /// Pastes a copy of the yanked celestial body under the selected `Star`
+    Paste,
+    /// Copies the selected celestial body as Markdown to the system clipboard
+    Copy,
+    /// Moves the selected celestial body above its previous sibling, see
+    /// `Galaxy::shift`
+    MoveUp,
+    /// Moves the selected celestial body below its next sibling
+    MoveDown,
+    /// Deletes the selected celestial body, after confirming
+    Delete,
+    /// Opens the description of the selected celestial body in the editor
+    Edit,
+    /// Opens a link of the selected celestial body, the first one unless a
+    /// count is typed before the key
+    OpenLink,
+    Split(Split),
+    Clone(Split),
+    Close,
+    MoveFocus(Direction),
+    Resize(Direction),
+}
+
+impl Action {
+    /// What the action does, as shown in the help overlay
+    pub fn description(&self) -> String {
+        match self {
+            Self::Quit => "Quit".into(),
+            Self::CommandLine => "Type a command".into(),
+            Self::Help => "Show this help".into(),
+            Self::Legend => "Show what the icons and colors mean".into(),
+            Self::PaneMode => "Act on panes with the next key".into(),
+            Self::Visual => "Start / stop marking celestial bodies".into(),
+            Self::ToggleMark => "Mark / unmark the selected celestial body".into(),
+            Self::Down => "Select the next celestial body".into(),
+            Self::Up => "Select the previous celestial body".into(),
+            Self::First => "Select the first celestial body".into(),
+            Self::Last => "Select the last celestial body".into(),
+            Self::PageDown => "Move down a page".into(),
+            Self::PageUp => "Move up a page".into(),
+            Self::Search => "Jump to a title or ID".into(),
+            Self::SearchNext => "Jump to the next match".into(),
+            Self::SearchPrevious => "Jump to the previous match".into(),
+            Self::Left => "Move to the column on the left".into(),
+            Self::Right => "Move to the column on the right".into(),
+            Self::CycleView => "Switch to the next view".into(),
+            Self::ToggleArchived => "Show / hide archived celestial bodies".into(),
+            Self::ToggleMine => "Show only your / everyone's celestial bodies".into(),
+            Self::Projects => "Switch to another project".into(),
+            Self::Actions => "Run an action on the selected celestial body".into(),
+            Self::QuickAdd => "Add a celestial body under the selected Star".into(),
+            Self::Yank => "Yank the selected celestial body and its descendants".into(),
+            Self::Paste => "Paste a copy of the yanked celestial body".into(),
+            Self::Copy => "Copy the selected celestial body as Markdown".into(),
+            Self::MoveUp => "Move the selected celestial body up among its siblings".into(),
+            Self::MoveDown => "Move the selected celestial body down among its siblings".into(),
+            Self::Delete => "Move the selected celestial body to the trash".into(),
+            Self::Edit => "Edit the description in $EDITOR".into(),
+            Self::OpenLink => "Open the first (or <count>th) link".into(),
+            Self::Split(Split::Right) => "Open a blank pane to the right".into(),
+            Self::Split(Split::Below) => "Open a blank pane below".into(),
+            Self::Clone(Split::Right) => "Open a copy of the pane to the right".into(),
+            Self::Clone(Split::Below) => "Open a copy of the pane below".into(),
+            Self::Close => "Close the pane".into(),
+            Self::MoveFocus(direction) => format!("Move to the pane {}", towards(*direction)),
+            Self::Resize(Direction::Left) => "Make the pane narrower".into(),
+            Self::Resize(Direction::Right) => "Make the pane wider".into(),
+            Self::Resize(Direction::Up) => "Make the pane shorter".into(),
+            Self::Resize(Direction::Down) => "Make the pane taller".into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single built in key binding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Binding {
+    pub code: KeyCode,
+    /// Whether `Ctrl` has to be held
+    pub control: bool,
+    pub action: Action,
+    /// The views the binding works in, every view if `None`
+    pub views: Option<&'static [View]>,
+}
+
+impl Binding {
+    const fn new(code: KeyCode, action: Action) -> Self {
+        Self {
+            code,
+            control: false,
+            action,
+            views: None,
+        }
+    }
+
+    const fn control(mut self) -> Self {
+        self.control = true;
+        self
+    }
+
+    const fn only(mut self, views: &'static [View]) -> Self {
+        self.views = Some(views);
+        self
+    }
+
+    /// Whether the binding works in `view`
+    pub fn applies_to(&self, view: View) -> bool {
+        self.views.is_none_or(|views| views.contains(&view))
+    }
+
+    /// How the key is written in the help overlay, e.g. `C-w` or `Down`
+    pub fn key(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Esc => "Esc".into(),
+            KeyCode::Tab => "Tab".into(),
+            KeyCode::Up => "Up".into(),
+            KeyCode::Down => "Down".into(),
+            KeyCode::Left => "Left".into(),
+            KeyCode::Right => "Right".into(),
+            KeyCode::Home => "Home".into(),
+            KeyCode::End => "End".into(),
+            KeyCode::PageUp => "PgUp".into(),
+            KeyCode::PageDown => "PgDn".into(),
+            code => format!("{code:?}"),
+        };
+        if self.control {
+            format!("C-{key}")
+        } else {
+            key
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Finds the action bound to `key` in `table` that works in `view`
+pub fn action(table: &[Binding], view: View, key: KeyEvent) -> Option<Action> {
+    let control = key.modifiers.contains(KeyModifiers::CONTROL);
+    table
+        .iter()
+        .find(|b| b.code == key.code && b.control == control && b.applies_to(view))
+        .map(|b| b.action)
+}
+
+/// Lists the bindings of `table` that work in `view`, with the keys of each
+/// action joined together (e.g. `j / Down`), in the order of the table
+pub fn help(table: &[Binding], view: View) -> Vec<(String, String)> {
+    let mut rows: Vec<(Action, Vec<String>)> = Vec::new();
+    for binding in table.iter().filter(|b| b.applies_to(view)) {
+        match rows
+            .iter_mut()
+            .find(|(action, _)| *action == binding.action)
+        {
+            Some((_, keys)) => keys.push(binding.key()),
+            None => rows.push((binding.action, vec![binding.key()])),
+        }
+    }
+    rows.into_iter()
+        .map(|(action, keys)| (keys.join(" / "), action.description()))
+        .collect()
+}
+
+/// Helper function that describes which way `direction` points
+fn towards(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Up => "above",
+        Direction::Down => "below",
+        Direction::Left => "to the left",
+        Direction::Right => "to the right",
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keys_are_found_for_the_view() {
+        let key = |c, modifiers| KeyEvent::new(KeyCode::Char(c), modifiers);
+        assert_eq!(
+            action(NORMAL, View::Kanban, key('h', KeyModifiers::NONE)),
+            Some(Action::Left)
+        );
+        assert_eq!(
+            action(NORMAL, View::Tree, key('h', KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(
+            action(NORMAL, View::Tree, key('h', KeyModifiers::CONTROL)),
+            Some(Action::MoveFocus(Direction::Left))
+        );
+
+        let help = help(NORMAL, View::Tree);
+        assert_eq!(help[0], ("q / Esc".into(), "Quit".into()));
+        assert!(!help.iter().any(|(_, d)| d.contains("column")));
+    }
+}
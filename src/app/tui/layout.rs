@@ -0,0 +1,476 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the tree describing how the panes of the TUI are laid out. Panes
+ * are split side by side or stacked, and each part of a split takes up a
+ * percentage of the space.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::cmp::Reverse;
+
+use ratatui::layout::{Constraint, Layout, Rect};
+
+use crate::app::cli::ValueEnum;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TYPES                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The smallest percentage of a split a pane can be resized to
+const MIN_SHARE: u16 = 10;
+
+/// The most panes that fit in one split without going under `MIN_SHARE`
+const MAX_CHILDREN: usize = (100 / MIN_SHARE) as usize;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Where a new pane is opened relative to the active pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Split {
+    /// Side by side, with the new pane on the right
+    #[default]
+    Right,
+    /// Stacked, with the new pane below
+    Below,
+}
+
+/// The directions the focus can move between panes in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A node in the tree describing how the panes are laid out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaneNode {
+    /// A single pane, by its index in `App::panes`
+    Leaf(usize),
+    /// Several panes sharing the space
+    Branch(PaneBranch),
+}
+
+impl PaneNode {
+    /// The indices of all panes, from left to right and top to bottom
+    pub fn leaves(&self) -> Vec<usize> {
+        match self {
+            Self::Leaf(index) => vec![*index],
+            Self::Branch(branch) => branch
+                .children
+                .iter()
+                .flat_map(|(child, _)| child.leaves())
+                .collect(),
+        }
+    }
+
+    /// Opens pane `new` next to pane `target`. If `target` is already part of
+    /// a split in the same direction, `new` is added to that split and gets an
+    /// equal share of the space.
+    ///
+    /// # Returns
+    /// `false` if there is no pane `target`, or its split is already full
+    pub fn split(&mut self, target: usize, new: usize, split: Split) -> bool {
+        match self {
+            Self::Leaf(index) if *index == target => {
+                let children = vec![Self::Leaf(target), Self::Leaf(new)];
+                *self = Self::Branch(PaneBranch::new(split, children));
+                true
+            }
+            Self::Leaf(_) => false,
+            Self::Branch(branch) => {
+                let position = branch
+                    .children
+                    .iter()
+                    .position(|(child, _)| *child == Self::Leaf(target));
+                match position {
+                    Some(_) if branch.split == split && branch.children.len() >= MAX_CHILDREN => {
+                        false
+                    }
+                    Some(position) if branch.split == split => {
+                        branch.children.insert(position + 1, (Self::Leaf(new), 0));
+                        branch.equalize();
+                        true
+                    }
+                    _ => branch
+                        .children
+                        .iter_mut()
+                        .any(|(child, _)| child.split(target, new, split)),
+                }
+            }
+        }
+    }
+
+    /// Removes pane `target`, giving its space to the rest of its split. The
+    /// panes after `target` are renumbered, as they move down in `App::panes`.
+    ///
+    /// # Returns
+    /// `false` if there is no pane `target` or it is the only pane
+    pub fn remove(&mut self, target: usize) -> bool {
+        if !self.remove_leaf(target) {
+            return false;
+        }
+        self.renumber(target);
+        true
+    }
+
+    /// Helper function that removes the leaf for pane `target`, collapsing
+    /// splits that are left with a single child
+    fn remove_leaf(&mut self, target: usize) -> bool {
+        let Self::Branch(branch) = self else {
+            return false;
+        };
+        let position = branch
+            .children
+            .iter()
+            .position(|(child, _)| *child == Self::Leaf(target));
+        let removed = match position {
+            Some(position) => {
+                branch.children.remove(position);
+                branch.rebalance();
+                true
+            }
+            None => branch
+                .children
+                .iter_mut()
+                .any(|(child, _)| child.remove_leaf(target)),
+        };
+        if branch.children.len() == 1 {
+            let (child, _) = branch.children.remove(0);
+            *self = child;
+        }
+        removed
+    }
+
+    /// Helper function that lowers the indices of the panes after `removed`
+    fn renumber(&mut self, removed: usize) {
+        match self {
+            Self::Leaf(index) if *index > removed => *index -= 1,
+            Self::Leaf(_) => {}
+            Self::Branch(branch) => {
+                for (child, _) in &mut branch.children {
+                    child.renumber(removed);
+                }
+            }
+        }
+    }
+
+    /// Resizes pane `target` by `amount` percent of the split it is in. `Right`
+    /// and `Down` make it wider and taller, `Left` and `Up` make it narrower
+    /// and shorter. The innermost split in the right direction is resized,
+    /// and no pane is made smaller than `MIN_SHARE` percent.
+    ///
+    /// # Returns
+    /// `false` if nothing could be resized
+    pub fn resize(&mut self, target: usize, direction: Direction, amount: u16) -> bool {
+        let Self::Branch(branch) = self else {
+            return false;
+        };
+        let Some(position) = branch
+            .children
+            .iter()
+            .position(|(child, _)| child.leaves().contains(&target))
+        else {
+            return false;
+        };
+        if branch.children[position]
+            .0
+            .resize(target, direction, amount)
+        {
+            return true;
+        }
+
+        let amount = i32::from(amount);
+        let (split, delta) = match direction {
+            Direction::Left => (Split::Right, -amount),
+            Direction::Right => (Split::Right, amount),
+            Direction::Up => (Split::Below, -amount),
+            Direction::Down => (Split::Below, amount),
+        };
+        branch.split == split && branch.resize(position, delta)
+    }
+
+    /// Divides `area` between the panes
+    pub fn areas(&self, area: Rect) -> Vec<(usize, Rect)> {
+        let branch = match self {
+            Self::Leaf(index) => return vec![(*index, area)],
+            Self::Branch(branch) => branch,
+        };
+        let constraints = branch
+            .children
+            .iter()
+            .map(|(_, share)| Constraint::Percentage(*share));
+        let areas = match branch.split {
+            Split::Right => Layout::horizontal(constraints),
+            Split::Below => Layout::vertical(constraints),
+        }
+        .split(area);
+        branch
+            .children
+            .iter()
+            .zip(areas.iter())
+            .flat_map(|((child, _), area)| child.areas(*area))
+            .collect()
+    }
+
+    /// Finds the pane next to pane `from` in `direction`, based on where the
+    /// panes are drawn. Of the panes touching `from`, the one sharing the
+    /// longest edge with it is picked.
+    pub fn move_focus(&self, from: usize, direction: Direction) -> Option<usize> {
+        // Only where the panes are relative to each other matters, not the
+        // actual size of the screen
+        let areas = self.areas(Rect::new(0, 0, 1000, 1000));
+        let (_, current) = *areas.iter().find(|(index, _)| *index == from)?;
+
+        let overlap = |start: u16, end: u16, other_start: u16, other_end: u16| {
+            end.min(other_end).saturating_sub(start.max(other_start))
+        };
+        areas
+            .iter()
+            .filter_map(|(index, area)| {
+                let (distance, shared) = match direction {
+                    Direction::Left if area.right() <= current.left() => (
+                        current.left() - area.right(),
+                        overlap(area.top(), area.bottom(), current.top(), current.bottom()),
+                    ),
+                    Direction::Right if area.left() >= current.right() => (
+                        area.left() - current.right(),
+                        overlap(area.top(), area.bottom(), current.top(), current.bottom()),
+                    ),
+                    Direction::Up if area.bottom() <= current.top() => (
+                        current.top() - area.bottom(),
+                        overlap(area.left(), area.right(), current.left(), current.right()),
+                    ),
+                    Direction::Down if area.top() >= current.bottom() => (
+                        area.top() - current.bottom(),
+                        overlap(area.left(), area.right(), current.left(), current.right()),
+                    ),
+                    _ => return None,
+                };
+                (shared > 0).then_some((*index, distance, shared))
+            })
+            .min_by_key(|(_, distance, shared)| (*distance, Reverse(*shared)))
+            .map(|(index, _, _)| index)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Panes that share the space in one direction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaneBranch {
+    /// Whether the children are side by side or stacked
+    split: Split,
+    /// The children and the percentage of the space each one takes up, which
+    /// always add up to 100
+    children: Vec<(PaneNode, u16)>,
+}
+
+impl PaneBranch {
+    /// Creates a split where `children` share the space equally
+    pub fn new(split: Split, children: Vec<PaneNode>) -> Self {
+        let mut branch = Self {
+            split,
+            children: children.into_iter().map(|child| (child, 0)).collect(),
+        };
+        branch.equalize();
+        branch
+    }
+
+    /// Helper function that gives every child an equal share, with the
+    /// remainder going to the last one
+    fn equalize(&mut self) {
+        for (_, share) in &mut self.children {
+            *share = 1;
+        }
+        self.rebalance();
+    }
+
+    /// Helper function that changes the share of the child at `position` by
+    /// `delta`, taking the difference from (or giving it to) the closest
+    /// siblings first
+    fn resize(&mut self, position: usize, delta: i32) -> bool {
+        let count = self.children.len();
+        let min = i32::from(MIN_SHARE);
+        let current = i32::from(self.children[position].1);
+        let lower = min.min(current);
+        let upper = 100 - min * (count as i32 - 1);
+        if lower > upper {
+            return false;
+        }
+        let target = (current + delta).clamp(lower, upper);
+        let mut change = target - current;
+        if change == 0 {
+            return false;
+        }
+
+        let siblings = (1..count)
+            .flat_map(|distance| [position + distance, position.wrapping_sub(distance)])
+            .filter(|index| *index < count);
+        for index in siblings {
+            let share = i32::from(self.children[index].1);
+            let new = (share - change).max(min.min(share));
+            change -= share - new;
+            self.children[index].1 = new as u16;
+            if change == 0 {
+                break;
+            }
+        }
+        // Whatever the siblings could not give up stays with them
+        self.children[position].1 = (target - change) as u16;
+        true
+    }
+
+    /// Helper function that scales the shares of the children so that they add
+    /// up to 100 again, keeping them in proportion to each other
+    fn rebalance(&mut self) {
+        let total: u32 = self.children.iter().map(|(_, s)| u32::from(*s)).sum();
+        let mut remaining = 100;
+        let last = self.children.len().saturating_sub(1);
+        for (index, (_, share)) in self.children.iter_mut().enumerate() {
+            *share = match index == last {
+                true => remaining,
+                false => (u32::from(*share) * 100 / total.max(1)) as u16,
+            };
+            remaining -= *share;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn shares(node: &PaneNode) -> Vec<u16> {
+        match node {
+            PaneNode::Leaf(_) => vec![],
+            PaneNode::Branch(branch) => branch.children.iter().map(|(_, s)| *s).collect(),
+        }
+    }
+
+    #[test]
+    fn splitting_and_removing_rebalances_shares() {
+        let mut layout = PaneNode::Leaf(0);
+        assert!(layout.split(0, 1, Split::Right));
+        assert_eq!(shares(&layout), vec![50, 50]);
+        assert!(layout.split(1, 2, Split::Right));
+        assert_eq!(shares(&layout), vec![33, 33, 34]);
+        assert_eq!(layout.leaves(), vec![0, 1, 2]);
+
+        assert!(layout.split(0, 3, Split::Below));
+        assert_eq!(layout.leaves(), vec![0, 3, 1, 2]);
+        assert!(!layout.split(7, 8, Split::Right));
+
+        assert!(layout.remove(3));
+        assert_eq!(layout.leaves(), vec![0, 1, 2]);
+        assert!(layout.remove(1));
+        assert_eq!(layout.leaves(), vec![0, 1]);
+        assert_eq!(shares(&layout), vec![49, 51]);
+        assert!(layout.remove(0));
+        assert_eq!(layout, PaneNode::Leaf(0));
+        assert!(!layout.remove(0));
+    }
+
+    #[test]
+    fn splits_stop_when_full() {
+        let mut layout = PaneNode::Leaf(0);
+        for new in 1..MAX_CHILDREN {
+            assert!(layout.split(new - 1, new, Split::Right));
+        }
+        assert_eq!(shares(&layout), vec![10; MAX_CHILDREN]);
+        assert!(!layout.split(0, MAX_CHILDREN, Split::Right));
+        assert_eq!(layout.leaves().len(), MAX_CHILDREN);
+
+        assert!(!layout.resize(0, Direction::Right, 10));
+        assert!(!layout.resize(4, Direction::Left, 10));
+        assert!(layout.split(0, MAX_CHILDREN, Split::Below));
+    }
+
+    #[test]
+    fn resizing_takes_space_from_siblings() {
+        let mut layout = PaneNode::Leaf(0);
+        layout.split(0, 1, Split::Right);
+        layout.split(1, 2, Split::Right);
+        layout.split(2, 3, Split::Below);
+
+        // Pane 3 is in a vertical split, but widening it widens that split
+        assert!(layout.resize(3, Direction::Right, 10));
+        assert_eq!(shares(&layout), vec![33, 23, 44]);
+        assert!(layout.resize(0, Direction::Right, 50));
+        assert_eq!(shares(&layout), vec![80, 10, 10]);
+        assert!(!layout.resize(0, Direction::Right, 5));
+        assert!(layout.resize(0, Direction::Left, 100));
+        assert_eq!(shares(&layout), vec![10, 80, 10]);
+
+        assert!(!layout.resize(0, Direction::Down, 10));
+        assert!(layout.resize(2, Direction::Down, 10));
+        assert!(!layout.resize(7, Direction::Down, 10));
+        assert!(!PaneNode::Leaf(0).resize(0, Direction::Left, 10));
+    }
+
+    #[test]
+    fn focus_moves_to_adjacent_pane() {
+        // +---+---+
+        // | 0 | 1 |
+        // |   +---+
+        // |   | 2 |
+        // +---+---+
+        let mut layout = PaneNode::Leaf(0);
+        layout.split(0, 1, Split::Right);
+        layout.split(1, 2, Split::Below);
+
+        assert_eq!(layout.move_focus(0, Direction::Right), Some(1));
+        assert_eq!(layout.move_focus(0, Direction::Left), None);
+        assert_eq!(layout.move_focus(1, Direction::Down), Some(2));
+        assert_eq!(layout.move_focus(2, Direction::Up), Some(1));
+        assert_eq!(layout.move_focus(2, Direction::Left), Some(0));
+        assert_eq!(layout.move_focus(0, Direction::Down), None);
+        assert_eq!(layout.move_focus(5, Direction::Down), None);
+    }
+}
@@ -0,0 +1,405 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Renders Markdown descriptions as styled lines for the TUI, and exports
+ * celestial bodies as Markdown to share them (see `export`).
+ *
+ * Only the parts of Markdown that are common in descriptions are handled:
+ * headings, lists (including checkboxes), block quotes, fenced code blocks,
+ * horizontal rules, and `**bold**`, `*italic*`, `~~struck~~`, `` `code` ``,
+ * and `[links](url)` within a line. Anything else is shown as it was written.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::fmt::Write;
+
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::{
+    core::{Galaxy, ID},
+    util::theme,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Exports the celestial body `id` as Markdown: its title (with its ID) as a
+/// heading, its description, and its descendants as a nested checklist
+pub fn export(galaxy: &Galaxy, id: ID) -> String {
+    let Some(body) = galaxy.get(id) else {
+        return String::new();
+    };
+    let mut md = String::new();
+    // Writing to a `String` cannot fail
+    let _ = writeln!(md, "# {} ({})", body.title(), galaxy.display_id(id));
+    if !body.description().trim().is_empty() {
+        let _ = writeln!(md);
+        let _ = writeln!(md, "{}", body.description().trim_end());
+    }
+    if !body.children().is_empty() {
+        let _ = writeln!(md);
+    }
+    for child in body.children() {
+        export_item(galaxy, *child, 0, &mut md);
+    }
+    md
+}
+
+/// Helper function that adds the celestial body `id` and its descendants to
+/// the checklist `md`, indented by `depth`
+fn export_item(galaxy: &Galaxy, id: ID, depth: usize, md: &mut String) {
+    let Some(body) = galaxy.get(id) else {
+        return;
+    };
+    let check = if body.status().is_final() { "x" } else { " " };
+    let _ = writeln!(
+        md,
+        "{}- [{check}] {} ({})",
+        "  ".repeat(depth),
+        body.title(),
+        galaxy.display_id(id)
+    );
+    for child in body.children() {
+        export_item(galaxy, *child, depth + 1, md);
+    }
+}
+
+/// Renders `markdown` as one line per line of the source
+pub fn render(markdown: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_code = false;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        lines.push(match in_code {
+            true => Line::from(Span::styled(format!("  {line}"), code_style())),
+            false => render_line(line),
+        });
+    }
+    lines
+}
+
+/// Helper function that renders a single line outside of a code block
+fn render_line(line: &str) -> Line<'static> {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+
+    if let Some((level, text)) = heading(trimmed) {
+        let mut style = Style::default()
+            .fg(theme::current().accent.into())
+            .add_modifier(Modifier::BOLD);
+        if level == 1 {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        return Line::from(inline(text, style));
+    }
+    if is_rule(trimmed) {
+        return Line::from(Span::styled(
+            "─".repeat(20),
+            Style::default().fg(theme::current().muted.into()),
+        ));
+    }
+    if let Some(text) = trimmed.strip_prefix('>') {
+        let style = Style::default()
+            .fg(theme::current().muted.into())
+            .add_modifier(Modifier::ITALIC);
+        let mut spans = vec![Span::styled(format!("{indent}▎ "), style)];
+        spans.extend(inline(text.trim_start(), style));
+        return Line::from(spans);
+    }
+
+    let (marker, text) = list_item(trimmed).unwrap_or((None, trimmed));
+    let mut spans = vec![Span::raw(indent.to_string())];
+    if let Some(marker) = marker {
+        spans.push(marker);
+    }
+    spans.extend(inline(text, Style::default()));
+    Line::from(spans)
+}
+
+/// Helper function that returns the level and text of a heading, or `None` if
+/// `line` is not a heading
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let text = line[level..].strip_prefix(' ')?.trim();
+    Some((level, text.trim_end_matches('#').trim_end()))
+}
+
+/// Helper function that checks whether `line` is a horizontal rule, e.g. `---`
+fn is_rule(line: &str) -> bool {
+    let line: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    line.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|marker| line.chars().all(|c| c == *marker))
+}
+
+/// Helper function that splits the bullet, number, or checkbox off of a list
+/// item, returning `None` if `line` is not a list item
+fn list_item(line: &str) -> Option<(Option<Span<'static>>, &str)> {
+    let bullet = Style::default().fg(theme::current().highlight.into());
+    let rest = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker));
+    if let Some(rest) = rest {
+        if let Some(rest) = rest.strip_prefix("[ ] ") {
+            return Some((Some(Span::styled("☐ ", bullet)), rest));
+        }
+        if let Some(rest) = rest
+            .strip_prefix("[x] ")
+            .or_else(|| rest.strip_prefix("[X] "))
+        {
+            return Some((
+                Some(Span::styled(
+                    "☑ ",
+                    Style::default().fg(theme::current().success.into()),
+                )),
+                rest,
+            ));
+        }
+        return Some((Some(Span::styled("• ", bullet)), rest));
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let rest = line[digits..]
+        .strip_prefix(". ")
+        .or_else(|| line[digits..].strip_prefix(") "))
+        .filter(|_| digits > 0)?;
+    let number = &line[..digits + 2];
+    Some((Some(Span::styled(number.to_string(), bullet)), rest))
+}
+
+/// Helper function that renders the emphasis, code spans, and links in `text`
+/// on top of `base`
+fn inline(text: &str, base: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    let mut previous = ' ';
+
+    while let Some(c) = rest.chars().next() {
+        // `_` only starts emphasis at the start of a word, so that names such
+        // as `snake_case` are left alone
+        let word_start = !previous.is_alphanumeric();
+        let styled = if c == '`' {
+            delimited(rest, "`").map(|(code, after)| {
+                (
+                    vec![Span::styled(code.to_string(), base.patch(code_style()))],
+                    after,
+                )
+            })
+        } else if c == '[' {
+            link(rest).map(|(text, url, after)| {
+                let mut spans = inline(
+                    text,
+                    base.fg(theme::current().link.into())
+                        .add_modifier(Modifier::UNDERLINED),
+                );
+                if url != text {
+                    spans.push(Span::styled(
+                        format!(" ({url})"),
+                        base.fg(theme::current().muted.into()),
+                    ));
+                }
+                (spans, after)
+            })
+        } else {
+            [
+                ("**", Modifier::BOLD),
+                ("__", Modifier::BOLD),
+                ("~~", Modifier::CROSSED_OUT),
+                ("*", Modifier::ITALIC),
+                ("_", Modifier::ITALIC),
+            ]
+            .into_iter()
+            .filter(|(marker, _)| word_start || !marker.starts_with('_'))
+            .find_map(|(marker, modifier)| {
+                let (inner, after) = delimited(rest, marker)?;
+                Some((inline(inner, base.add_modifier(modifier)), after))
+            })
+        };
+
+        match styled {
+            Some((styled, after)) => {
+                if !plain.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut plain), base));
+                }
+                spans.extend(styled);
+                previous = rest[..rest.len() - after.len()]
+                    .chars()
+                    .last()
+                    .unwrap_or(' ');
+                rest = after;
+            }
+            None => {
+                plain.push(c);
+                previous = c;
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    if !plain.is_empty() {
+        spans.push(Span::styled(plain, base));
+    }
+    spans
+}
+
+/// Helper function that splits `text` into what is between `marker` and the
+/// next `marker`, and what comes after. Returns `None` if `text` does not
+/// start with `marker`, it is never closed, or there is nothing between them.
+fn delimited<'a>(text: &'a str, marker: &str) -> Option<(&'a str, &'a str)> {
+    let rest = text.strip_prefix(marker)?;
+    let end = rest.find(marker)?;
+    let inner = &rest[..end];
+    if inner.is_empty() || inner.starts_with(' ') || inner.ends_with(' ') {
+        return None;
+    }
+    Some((inner, &rest[end + marker.len()..]))
+}
+
+/// Helper function that splits a `[text](url)` link off of the start of
+/// `text`, returning the link text, the URL, and what comes after
+fn link(text: &str) -> Option<(&str, &str, &str)> {
+    let rest = text.strip_prefix('[')?;
+    let (label, rest) = rest.split_once("](")?;
+    let (url, after) = rest.split_once(')')?;
+    if label.is_empty() || label.contains(']') {
+        return None;
+    }
+    Some((label, url, after))
+}
+
+/// Helper function that gives the style of code spans and blocks
+fn code_style() -> Style {
+    Style::default().fg(theme::current().code.into())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, Status};
+
+    /// Helper function that gives the text and modifiers of every span
+    fn spans(line: &Line) -> Vec<(String, Modifier)> {
+        line.spans
+            .iter()
+            .filter(|s| !s.content.is_empty())
+            .map(|s| (s.content.to_string(), s.style.add_modifier))
+            .collect()
+    }
+
+    #[test]
+    fn markdown_is_styled() {
+        let lines = render(
+            "# Plan\n\
+             Fix **the _login_ page** in `auth_flow`, see [docs](https://x.y).\n\
+             - [x] done\n\
+             \x20 2. my_snake_case\n\
+             ```\n\
+             **not bold**\n\
+             ```",
+        );
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            spans(&lines[0]),
+            [("Plan".into(), Modifier::BOLD | Modifier::UNDERLINED)]
+        );
+        assert_eq!(
+            spans(&lines[1]),
+            [
+                ("Fix ".into(), Modifier::empty()),
+                ("the ".into(), Modifier::BOLD),
+                ("login".into(), Modifier::BOLD | Modifier::ITALIC),
+                (" page".into(), Modifier::BOLD),
+                (" in ".into(), Modifier::empty()),
+                ("auth_flow".into(), Modifier::empty()),
+                (", see ".into(), Modifier::empty()),
+                ("docs".into(), Modifier::UNDERLINED),
+                (" (https://x.y)".into(), Modifier::empty()),
+                (".".into(), Modifier::empty()),
+            ]
+        );
+        assert_eq!(lines[2].spans[1].content, "☑ ");
+        assert_eq!(
+            spans(&lines[3]),
+            [
+                ("  ".into(), Modifier::empty()),
+                ("2. ".into(), Modifier::empty()),
+                ("my_snake_case".into(), Modifier::empty()),
+            ]
+        );
+        assert_eq!(
+            spans(&lines[4]),
+            [("  **not bold**".into(), Modifier::empty())]
+        );
+    }
+
+    #[test]
+    fn bodies_are_exported_as_checklists() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy
+            .star()
+            .title("Backend".into())
+            .description("Everything on the server\n".into())
+            .id();
+        let child = galaxy.star().title("API".into()).id();
+        let planet = galaxy.planet().title("Login".into()).id();
+        galaxy.set_parent(child, Some(star)).unwrap();
+        galaxy.set_parent(planet, Some(child)).unwrap();
+        galaxy
+            .get_mut(planet)
+            .unwrap()
+            .status(Status::Done, String::new());
+
+        assert_eq!(
+            export(&galaxy, star),
+            "# Backend (#0)\n\nEverything on the server\n\n- [ ] API (#1)\n  - [x] Login (#2)\n"
+        );
+        assert_eq!(export(&galaxy, planet), "# Login (#2)\n");
+    }
+}
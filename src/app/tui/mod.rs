@@ -0,0 +1,2335 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the TUI version of the application.
+ *
+ * The TUI is made up of one or more side by side panes (see `Pane`), each
+ * showing the `Galaxy` through its own `View`, along with the details of the
+ * selected celestial body in the active pane. The celestial bodies shown in a
+ * pane can be narrowed down with a `Filter` and scoped to the descendants of a
+ * single `Star`.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  MODULES                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+mod command;
+mod confirm;
+mod events;
+mod input;
+mod keys;
+mod layout;
+mod markdown;
+mod output;
+mod pane;
+mod retro;
+mod view;
+mod visual;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    cell::Cell,
+    env, io,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    event::{DisableBracketedPaste, EnableBracketedPaste, KeyCode, KeyEvent, KeyModifiers},
+    execute,
+};
+use ratatui::DefaultTerminal;
+
+use chrono::{Local, Utc};
+use log::warn;
+
+use crate::{
+    core::{Blocker, CelestialBodyKind, Filter, Galaxy, GalaxyError, Sort, Status},
+    util::shell,
+};
+
+use super::{
+    cli::{self, TuiArgs},
+    config::{self, Config},
+    edit, AppError, Result,
+};
+pub use command::{Command, CommandError, Level, Notification};
+pub use confirm::{Answer, Confirm, Pending};
+pub use events::{AppEvent, Events, Signal};
+pub use input::Input;
+pub use keys::Action;
+pub use layout::{Direction, PaneBranch, PaneNode, Split};
+pub use output::ShellOutput;
+pub use pane::{Pane, TreeRow};
+pub use retro::{RetroForm, Section};
+pub use view::{View, VIEWS};
+pub use visual::Visual;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TYPES                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A change made in the TUI. It is applied to the `Galaxy` of the TUI right
+/// away, and to the database once the changes are saved.
+type Change = Box<dyn Fn(&mut Galaxy) -> std::result::Result<(), GalaxyError>>;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What key presses are used for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// Keys move around and change the active pane
+    Normal,
+    /// Keys are typed into a command, which is run on enter
+    Command(Input),
+    /// Keys are typed into a search, which jumps to the first match on enter
+    Search(Input),
+    /// Keys are typed into the quick-add popup, which creates a celestial body
+    /// under the selected `Star` on enter
+    QuickAdd(Input),
+    /// The next key acts on the panes, entered with `Ctrl+w`
+    Pane,
+    /// Keys mark celestial bodies, which commands then act on all at once
+    Visual,
+    /// The keys of the mode are shown until the next key press
+    Help(Box<Mode>),
+    /// What the icons and colors mean is shown until the next key press
+    Legend,
+    /// Keys answer the prompt, which has to be confirmed before a
+    /// destructive operation is done
+    Confirm(Confirm),
+    /// Keys pick the project to switch to from the configuration, the index
+    /// of the selected one is kept
+    Projects(usize),
+    /// Keys pick the action of the `Config` to run on the selected celestial
+    /// body, the index of the selected action is kept
+    Actions(usize),
+    /// Keys scroll through the output of the last shell command
+    Output,
+    /// Keys are typed into the retrospective of a sprint, which is added to
+    /// on enter
+    Retro(RetroForm),
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The state of the TUI
+pub struct App {
+    galaxy: Galaxy,
+    config: Config,
+    /// All open panes, in the order they were opened. There is always at least
+    /// one.
+    panes: Vec<Pane>,
+    /// Where each pane is drawn
+    layout: PaneNode,
+    /// Index of the pane that receives key presses
+    active: usize,
+    mode: Mode,
+    /// Shown at the bottom of the screen until the next key press
+    message: Option<Notification>,
+    should_quit: bool,
+    /// The celestial body to open in the editor once the key press has been
+    /// handled, since the terminal has to be given to the editor
+    editing: Option<u64>,
+    /// Changes that have not been saved to the database yet, oldest first
+    unsaved: Vec<Change>,
+    /// When the last unsaved change was made, so that the changes are only
+    /// saved on their own once no more are made for a while
+    last_change: Option<Instant>,
+    events: Events,
+    /// The signal (or `Ctrl+c`) that stopped the TUI, if any
+    stopped_by: Option<Signal>,
+    /// The keys typed so far of a sequence bound in the config, and when the
+    /// last of them was typed
+    pending: Option<(String, Instant)>,
+    /// The celestial body yanked with `y`, which `p` pastes copies of
+    register: Option<u64>,
+    /// The celestial bodies marked in `Mode::Visual`
+    visual: Option<Visual>,
+    /// The output of the shell command run with `:!`, shown in
+    /// `Mode::Output`
+    output: Option<ShellOutput>,
+    /// How many times the next key is repeated, typed as digits before it
+    count: Option<usize>,
+    /// Whether changes are saved to the database at all, which is only not the
+    /// case in tests
+    persistent: bool,
+}
+
+impl App {
+    /// The largest count that can be typed before a key
+    const MAX_COUNT: usize = 9999;
+
+    /// Creates the state of the TUI with a single pane
+    pub fn new(galaxy: Galaxy, config: Config, pane: Pane) -> Self {
+        Self {
+            galaxy,
+            config,
+            panes: vec![pane],
+            layout: PaneNode::Leaf(0),
+            active: 0,
+            mode: Mode::Normal,
+            message: None,
+            should_quit: false,
+            editing: None,
+            unsaved: Vec::new(),
+            last_change: None,
+            events: Events::default(),
+            stopped_by: None,
+            pending: None,
+            register: None,
+            visual: None,
+            output: None,
+            count: None,
+            persistent: false,
+        }
+    }
+
+    /// The pane that receives key presses
+    pub fn active(&self) -> &Pane {
+        &self.panes[self.active]
+    }
+
+    /// Handles a single key press
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        self.message = None;
+        // Raw mode turns Ctrl+c into a key press instead of SIGINT
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return self.stop(Signal::Interrupt);
+        }
+        if let Mode::Confirm(confirm) = &mut self.mode {
+            match confirm.handle_key(key) {
+                Answer::Waiting => {}
+                answer => {
+                    let pending = confirm.pending.clone();
+                    self.mode = Mode::Normal;
+                    self.complete(pending, answer);
+                }
+            }
+            return;
+        }
+        if let Mode::Command(input) = &mut self.mode {
+            match key.code {
+                KeyCode::Esc => self.mode = self.rest(),
+                KeyCode::Enter => {
+                    let input = input.take();
+                    self.mode = self.rest();
+                    if let Err(e) = self.execute(&input) {
+                        self.notify(Level::Error, e.to_string());
+                    }
+                }
+                KeyCode::Backspace if input.is_empty() => self.mode = self.rest(),
+                _ => {
+                    input.handle_key(key);
+                }
+            }
+            return;
+        }
+        if let Mode::QuickAdd(input) = &mut self.mode {
+            match key.code {
+                KeyCode::Esc => self.mode = Mode::Normal,
+                KeyCode::Enter => {
+                    let line = input.take();
+                    self.mode = Mode::Normal;
+                    if !line.trim().is_empty() {
+                        self.run_command(Command::Add(line));
+                    }
+                }
+                _ => {
+                    input.handle_key(key);
+                }
+            }
+            return;
+        }
+        if let Mode::Retro(form) = &mut self.mode {
+            if key.code == KeyCode::Esc {
+                self.mode = Mode::Normal;
+            } else if let Some(text) = form.handle_key(key) {
+                let (sprint, section) = (form.sprint.clone(), form.section);
+                self.add_to_retro(sprint, section, text);
+            }
+            return;
+        }
+        if let Mode::Search(input) = &mut self.mode {
+            match key.code {
+                KeyCode::Esc => self.mode = Mode::Normal,
+                KeyCode::Enter => {
+                    let search = input.take();
+                    self.mode = Mode::Normal;
+                    if !search.trim().is_empty() {
+                        self.panes[self.active].search = Some(search.trim().to_string());
+                        self.search(true);
+                    }
+                }
+                KeyCode::Backspace if input.is_empty() => self.mode = Mode::Normal,
+                _ => {
+                    input.handle_key(key);
+                }
+            }
+            return;
+        }
+        if let Mode::Projects(selected) = &mut self.mode {
+            let last = self.config.projects.len().saturating_sub(1);
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => self.mode = Mode::Normal,
+                KeyCode::Char('j') | KeyCode::Down => *selected = (*selected + 1).min(last),
+                KeyCode::Char('k') | KeyCode::Up => *selected = selected.saturating_sub(1),
+                KeyCode::Enter => {
+                    let name = self.config.projects.keys().nth(*selected).cloned();
+                    self.mode = Mode::Normal;
+                    if let Some(name) = name {
+                        self.switch_project(&name);
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+        if let Mode::Actions(selected) = self.mode {
+            let actions = self.actions();
+            let last = actions.len().saturating_sub(1);
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => self.mode = Mode::Normal,
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.mode = Mode::Actions((selected + 1).min(last))
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.mode = Mode::Actions(selected.saturating_sub(1))
+                }
+                KeyCode::Enter => {
+                    self.mode = Mode::Normal;
+                    if let Some(action) = actions.get(selected) {
+                        self.run_shell(&action.command);
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+        if let (Mode::Output, Some(output)) = (&self.mode, &mut self.output) {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.output = None;
+                    self.mode = self.rest();
+                }
+                KeyCode::Char('j') | KeyCode::Down => output.scroll_by(1),
+                KeyCode::Char('k') | KeyCode::Up => output.scroll_by(-1),
+                KeyCode::Char('d') | KeyCode::PageDown => output.scroll_by(10),
+                KeyCode::Char('u') | KeyCode::PageUp => output.scroll_by(-10),
+                KeyCode::Char('g') | KeyCode::Home => output.scroll = 0,
+                KeyCode::Char('G') | KeyCode::End => output.scroll_by(isize::MAX),
+                _ => {}
+            }
+            return;
+        }
+        let view = self.active().view;
+        match self.mode {
+            Mode::Help(_) | Mode::Legend => {
+                self.mode = self.rest();
+                return;
+            }
+            Mode::Visual => {
+                let control = key.modifiers.contains(KeyModifiers::CONTROL);
+                match key.code {
+                    KeyCode::Esc if self.count.take().is_some() => {}
+                    KeyCode::Char(c) if !control && self.press_count(c) => {}
+                    _ => match keys::action(keys::VISUAL, view, key) {
+                        Some(action) => self.perform(action, Mode::Visual),
+                        None => self.count = None,
+                    },
+                }
+                return;
+            }
+            Mode::Pane => {
+                self.mode = Mode::Normal;
+                match keys::action(keys::PANE, view, key) {
+                    Some(action) => self.perform(action, Mode::Pane),
+                    None => self.count = None,
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        // Keys bound in the config take precedence over built in keys
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc if self.pending.is_some() || self.count.is_some() => {
+                self.pending = None;
+                self.count = None;
+                return;
+            }
+            KeyCode::Char(c) if !control && self.press_count(c) => return,
+            KeyCode::Char(c) if !control && self.press_bound(c) => return,
+            _ => self.pending = None,
+        }
+
+        if let Some(action) = keys::action(keys::NORMAL, view, key) {
+            self.perform(action, Mode::Normal);
+        }
+    }
+
+    /// Helper function that adds the digit `key` to the count of the next key,
+    /// unless a sequence of keys is pending or the digit is bound in the
+    /// config. A count cannot start with `0`.
+    ///
+    /// # Returns
+    /// Whether the key was used as part of a count
+    fn press_count(&mut self, key: char) -> bool {
+        let Some(digit) = key.to_digit(10) else {
+            return false;
+        };
+        let keys = key.to_string();
+        if self.pending.is_some()
+            || (digit == 0 && self.count.is_none())
+            || self.config.binding(&keys).is_some()
+            || !self.config.continuations(&keys).is_empty()
+        {
+            return false;
+        }
+        let count = self.count.unwrap_or(0) * 10 + digit as usize;
+        self.count = Some(count.min(Self::MAX_COUNT));
+        true
+    }
+
+    /// Helper function that adds `key` to the pending sequence of keys, and
+    /// runs what the sequence is bound to once it cannot continue. If `key`
+    /// does not continue the pending sequence, the pending sequence is
+    /// finished (see `flush`) and `key` starts over on its own.
+    ///
+    /// # Returns
+    /// Whether the key was used by a binding of the config
+    fn press_bound(&mut self, key: char) -> bool {
+        let pending = self.pending.take().map(|(keys, _)| keys);
+        let mut keys = pending.clone().unwrap_or_default();
+        keys.push(key);
+        if !self.config.continuations(&keys).is_empty() {
+            self.pending = Some((keys, Instant::now()));
+            return true;
+        }
+        if let Some(command) = self.config.binding(&keys).map(String::from) {
+            self.run_bound(&command);
+            return true;
+        }
+        match pending {
+            Some(pending) => {
+                self.flush(&pending);
+                self.press_bound(key)
+            }
+            None => false,
+        }
+    }
+
+    /// Helper function that finishes the pending sequence of keys once no key
+    /// was typed for `Config::key_timeout`, see `flush`
+    fn finish_pending(&mut self) {
+        let timeout = self.config.key_timeout();
+        if let Some((keys, since)) = self.pending.take() {
+            match since.elapsed() >= timeout {
+                true => self.flush(&keys),
+                false => self.pending = Some((keys, since)),
+            }
+        }
+    }
+
+    /// Helper function that finishes a sequence of keys that did not continue.
+    /// A sequence that is bound on its own runs what it is bound to, and a
+    /// single key does its built in action.
+    fn flush(&mut self, keys: &str) {
+        if let Some(command) = self.config.binding(keys).map(String::from) {
+            return self.run_bound(&command);
+        }
+        let mut chars = keys.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+            if let Some(action) = keys::action(keys::NORMAL, self.active().view, key) {
+                self.perform(action, Mode::Normal);
+            }
+        }
+    }
+
+    /// How long until the pending sequence of keys times out, `None` if there
+    /// is none
+    pub fn pending_for(&self) -> Option<Duration> {
+        let (_, since) = self.pending.as_ref()?;
+        Some(self.config.key_timeout().saturating_sub(since.elapsed()))
+    }
+
+    /// Helper function that runs a command or alias bound in the config, as
+    /// many times as the count typed before it
+    fn run_bound(&mut self, command: &str) {
+        for _ in 0..self.count.take().unwrap_or(1) {
+            if let Err(e) = self.execute(command) {
+                return self.notify(Level::Error, e.to_string());
+            }
+        }
+    }
+
+    /// Helper function that does what a built in key of `mode` is bound to,
+    /// using up the count typed before it (which is kept for the key after
+    /// `PaneMode`)
+    fn perform(&mut self, action: Action, mode: Mode) {
+        let count = match action {
+            Action::PaneMode => self.count,
+            _ => self.count.take(),
+        };
+        let times = count.unwrap_or(1);
+        match action {
+            Action::Quit => self.run_command(Command::Quit),
+            Action::CommandLine => self.mode = Mode::Command(Input::default()),
+            Action::Search => self.mode = Mode::Search(Input::default()),
+            Action::QuickAdd => self.mode = Mode::QuickAdd(Input::default()),
+            Action::Yank => self.run_command(Command::Yank(None)),
+            Action::Paste => self.run_command(Command::Paste(times)),
+            Action::Copy => self.run_command(Command::Copy(None)),
+            Action::MoveUp => self.shift(false, times),
+            Action::MoveDown => self.shift(true, times),
+            Action::Projects => self.run_command(Command::Project(None)),
+            Action::Actions => self.run_command(Command::Run(None)),
+            Action::SearchNext => self.search(true),
+            Action::SearchPrevious => self.search(false),
+            Action::Help => self.mode = Mode::Help(Box::new(mode)),
+            Action::Legend => self.mode = Mode::Legend,
+            Action::PaneMode => self.mode = Mode::Pane,
+            Action::Visual => match self.visual.take() {
+                Some(_) => self.mode = Mode::Normal,
+                None => {
+                    self.visual = Some(Visual::range(self.active().selected));
+                    self.mode = Mode::Visual;
+                }
+            },
+            Action::ToggleMark => {
+                if let Some(id) = self.active().selected {
+                    self.visual.get_or_insert_default().toggle(id);
+                    self.mode = Mode::Visual;
+                }
+            }
+            Action::Split(split) => self.run_command(Command::Split(split)),
+            Action::Clone(split) => self.run_command(Command::Clone(split)),
+            Action::Close => self.run_command(Command::Close),
+            Action::Delete => self.run_command(Command::Delete(None)),
+            Action::Edit => self.run_command(Command::Edit(None)),
+            Action::OpenLink => self.run_command(Command::OpenLink(times)),
+            Action::ToggleMine => self.run_command(Command::Mine),
+            Action::MoveFocus(direction) => {
+                for _ in 0..times {
+                    self.run_command(Command::MoveFocus(direction));
+                }
+            }
+            Action::Resize(direction) => {
+                let amount = Command::DEFAULT_RESIZE.saturating_mul(times.min(100) as u16);
+                self.run_command(Command::ResizeView(direction, amount))
+            }
+            action => self.panes[self.active].perform(&self.galaxy, action, count),
+        }
+    }
+
+    /// Inserts text pasted into the terminal into whatever is being typed
+    pub fn handle_paste(&mut self, text: &str) {
+        match &mut self.mode {
+            Mode::Command(input) | Mode::Search(input) | Mode::QuickAdd(input) => input.paste(text),
+            Mode::Retro(form) => form.input.paste(text),
+            Mode::Confirm(confirm) if confirm.expected.is_some() => confirm.input.paste(text),
+            _ => {}
+        }
+    }
+
+    /// Runs the command or alias `input`. Aliases expand to a sequence of built
+    /// in commands, which are all checked before any of them are run.
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - `input` is neither a built in command nor an alias
+    /// - `input` (or a command in the alias) has invalid arguments
+    pub fn execute(&mut self, input: &str) -> std::result::Result<(), CommandError> {
+        let commands = match self.config.aliases.get(input.trim()) {
+            Some(alias) => alias
+                .iter()
+                .map(|command| self.parse(command))
+                .collect::<std::result::Result<Vec<Command>, _>>()?,
+            None => vec![self.parse(input)?],
+        };
+        for command in commands {
+            self.run_command(command);
+        }
+        Ok(())
+    }
+
+    /// Helper function that parses a single built in command. Views saved in
+    /// the `Config` are opened with `open <name>`, unless a built in view has
+    /// the same name.
+    fn parse(&self, input: &str) -> std::result::Result<Command, CommandError> {
+        match input.trim().strip_prefix("open ").map(str::trim) {
+            Some(name) if self.config.views.contains_key(name) && view::find(name).is_none() => {
+                Ok(Command::OpenSaved(name.to_string()))
+            }
+            _ => input.parse(),
+        }
+    }
+
+    /// Helper function that runs a single built in command
+    fn run_command(&mut self, command: Command) {
+        let pane = &mut self.panes[self.active];
+        match command {
+            Command::Quit => self.quit(),
+            Command::ForceQuit => self.should_quit = true,
+            Command::Write => self.write(false),
+            Command::WriteQuit => self.write(true),
+            Command::View(view) => {
+                pane.view = view;
+                pane.offsets.clear();
+            }
+            Command::Open(name, args) => match view::open(&self.galaxy, &name, &args) {
+                Ok(new) => *pane = new,
+                Err(e) => self.notify(Level::Error, e.to_string()),
+            },
+            Command::OpenSaved(name) => {
+                match view::open_saved(&self.galaxy, &self.config.views[&name]) {
+                    Ok(new) => *pane = new,
+                    Err(e) => self.notify(Level::Error, e.to_string()),
+                }
+            }
+            Command::Filter(filter) => pane.filter = filter,
+            Command::Sort(sort) => pane.sort = sort,
+            Command::GroupBy(columns) => pane.columns = columns,
+            Command::Focus(Some(reference)) => match self.galaxy.resolve(&reference) {
+                Ok(id)
+                    if self
+                        .galaxy
+                        .get(id)
+                        .is_some_and(|b| b.kind() == CelestialBodyKind::Star) =>
+                {
+                    pane.focus = Some(id);
+                }
+                Ok(id) => {
+                    let id = self.galaxy.display_id(id);
+                    self.notify(Level::Error, format!("{id} is not a Star"));
+                }
+                Err(e) => self.notify(Level::Error, e.to_string()),
+            },
+            Command::Focus(None) => pane.focus = None,
+            Command::Archived => {
+                pane.show_archived = !pane.show_archived;
+                let shown = if pane.show_archived {
+                    "Showing"
+                } else {
+                    "Hiding"
+                };
+                self.notify(Level::Info, format!("{shown} archived celestial bodies"));
+            }
+            Command::Mine => match (pane.mine.take(), self.config.user()) {
+                (Some(_), _) => self.notify(Level::Info, "Showing everyone's celestial bodies"),
+                (None, Some(user)) => {
+                    let message = format!("Showing celestial bodies assigned to {user}");
+                    pane.mine = Some(user);
+                    self.notify(Level::Info, message);
+                }
+                (None, None) => self.notify(
+                    Level::Error,
+                    "Set `user` in the configuration to show your celestial bodies",
+                ),
+            },
+            Command::Split(split) => {
+                let pane = Pane::new(
+                    &self.galaxy,
+                    View::default(),
+                    Filter::default(),
+                    None,
+                    false,
+                );
+                self.split(pane, split);
+            }
+            Command::Clone(split) => self.split(self.active().clone_state(), split),
+            Command::Close => self.close(),
+            Command::MoveFocus(direction) => {
+                if let Some(index) = self.layout.move_focus(self.active, direction) {
+                    self.active = index;
+                }
+            }
+            Command::ResizeView(direction, amount) => {
+                if !self.layout.resize(self.active, direction, amount) {
+                    self.notify(Level::Warning, "The pane cannot be resized that way");
+                }
+            }
+            Command::Project(None) => self.open_projects(),
+            Command::Project(Some(name)) => self.switch_project(&name),
+            Command::Notify(level, text) => self.notify(level, text),
+            Command::Delete(reference) => self.confirm_delete(reference),
+            Command::Edit(reference) => self.editing = self.target(reference),
+            Command::Add(line) => self.add(&line),
+            Command::Yank(reference) => self.yank(reference),
+            Command::Paste(times) => self.paste(times),
+            Command::Copy(reference) => self.copy_text(reference),
+            Command::OpenLink(n) => self.open_link(n),
+            Command::Status(status) => {
+                let ids = self.targets();
+                self.change_all(ids, "Changed the status of {}", move |galaxy, id| {
+                    galaxy.set_status(id, &status, String::new()).map(|_| ())
+                });
+            }
+            Command::Priority(priority) => {
+                let ids = self.targets();
+                let done = format!("Changed the priority of {{}} to {priority}");
+                self.change_all(ids, &done, move |galaxy, id| {
+                    if let Some(mut body) = galaxy.get_mut(id) {
+                        body.priority(priority);
+                    }
+                    Ok(())
+                });
+            }
+            Command::Tag(tag) => {
+                let ids = self.targets();
+                self.change_all(ids, "Tagged {}", move |galaxy, id| {
+                    if let Some(mut body) = galaxy.get_mut(id) {
+                        body.tag(tag.clone());
+                    }
+                    Ok(())
+                });
+            }
+            Command::Untag(tag) => {
+                let ids = self.targets();
+                self.change_all(ids, "Untagged {}", move |galaxy, id| {
+                    if let Some(mut body) = galaxy.get_mut(id) {
+                        body.untag(&tag);
+                    }
+                    Ok(())
+                });
+            }
+            Command::Pin(pinned) => {
+                let ids = self.targets();
+                let done = if pinned { "Pinned {}" } else { "Unpinned {}" };
+                self.change_all(ids, done, move |galaxy, id| {
+                    if let Some(mut body) = galaxy.get_mut(id) {
+                        body.pin(pinned);
+                    }
+                    Ok(())
+                });
+            }
+            Command::Snooze(until) => {
+                let ids = self.targets();
+                let done = match until {
+                    Some(until) => format!("Snoozed {{}} until {until}"),
+                    None => "Unsnoozed {}".to_string(),
+                };
+                self.change_all(ids, &done, move |galaxy, id| {
+                    if let Some(mut body) = galaxy.get_mut(id) {
+                        body.snooze(until);
+                    }
+                    Ok(())
+                });
+            }
+            Command::Reason(reason) => {
+                let ids = self.targets();
+                let blocked = ids.iter().all(|id| {
+                    self.galaxy
+                        .get(*id)
+                        .is_some_and(|b| matches!(b.status(), Status::Block | Status::Hold))
+                });
+                if reason.is_some() && !blocked {
+                    return self
+                        .notify(Level::Warning, "Only blocked or held bodies have a reason");
+                }
+                self.change_all(ids, "Updated the reason of {}", move |galaxy, id| {
+                    if let Some(mut body) = galaxy.get_mut(id) {
+                        let blocker = reason.clone().map(|reason| Blocker {
+                            reason,
+                            by: None,
+                            reference: None,
+                        });
+                        body.set_blocker(blocker);
+                    }
+                    Ok(())
+                });
+            }
+            Command::Move(reference) => self.move_targets(reference),
+            Command::Shell(template) => self.run_shell(&template),
+            Command::Run(None) => match self.actions().is_empty() {
+                true => self.notify(Level::Warning, "No actions for the selected celestial body"),
+                false => self.mode = Mode::Actions(0),
+            },
+            Command::Run(Some(name)) => match self.actions().into_iter().find(|a| a.name == name) {
+                Some(action) => self.run_shell(&action.command),
+                None => self.notify(Level::Error, format!("No action named \"{name}\"")),
+            },
+            Command::Retro(name) => {
+                let sprint = match &name {
+                    Some(name) => self.galaxy.sprint(name),
+                    None => self.galaxy.active_sprint(Local::now().date_naive()),
+                };
+                match (sprint.map(|s| s.name().to_string()), name) {
+                    (Some(sprint), _) => self.mode = Mode::Retro(RetroForm::new(sprint)),
+                    (None, Some(name)) => {
+                        self.notify(Level::Error, GalaxyError::SprintNotFound(name).to_string())
+                    }
+                    (None, None) => self.notify(Level::Warning, "There is no active sprint"),
+                }
+            }
+        }
+        self.panes[self.active].reselect(&self.galaxy);
+    }
+
+    /// Helper function that jumps to the next (or previous if not `forward`)
+    /// match of the last search in the active pane, see `Pane::search`
+    fn search(&mut self, forward: bool) {
+        let pane = &mut self.panes[self.active];
+        let Some(search) = pane.search.clone() else {
+            return self.notify(Level::Warning, "Nothing has been searched for yet");
+        };
+        if !pane.search(&self.galaxy, forward) {
+            self.notify(Level::Warning, format!("Nothing matches {search:?}"));
+        }
+    }
+
+    /// Helper function that asks for confirmation before deleting the
+    /// celestial body `reference`, or the one selected in the active pane if
+    /// `None`. Stars with descendants are only deleted after typing their
+    /// title.
+    fn confirm_delete(&mut self, reference: Option<String>) {
+        let id = match reference {
+            Some(reference) => self.target(Some(reference)),
+            None => match self.targets()[..] {
+                [] => None,
+                [id] => Some(id),
+                ref ids => {
+                    let count: usize = ids.iter().map(|id| descendants(&self.galaxy, *id)).sum();
+                    let question = match count {
+                        0 => format!("Move the {} marked celestial bodies to the trash?", ids.len()),
+                        _ => format!(
+                            "Move the {} marked celestial bodies and their {count} descendants to the trash?",
+                            ids.len()
+                        ),
+                    };
+                    let confirm = Confirm::new(question, Pending::DeleteMany(ids.to_vec()));
+                    self.mode = Mode::Confirm(confirm);
+                    return;
+                }
+            },
+        };
+        let Some(body) = id.and_then(|id| self.galaxy.get(id)) else {
+            return;
+        };
+        let id = body.id();
+
+        let name = format!("{} ({})", body.title(), self.galaxy.display_id(id));
+        let descendants = descendants(&self.galaxy, id);
+        let confirm = match descendants {
+            0 => Confirm::new(format!("Move {name} to the trash?"), Pending::Delete(id)),
+            _ => Confirm::typed(
+                format!(
+                    "Move {name} and its {descendants} descendants to the trash? Type its title to confirm."
+                ),
+                body.title().to_string(),
+                Pending::Delete(id),
+            ),
+        };
+        self.mode = Mode::Confirm(confirm);
+    }
+
+    /// The mode to go back to after a command line or the help overlay, which
+    /// is `Mode::Visual` while celestial bodies are marked
+    fn rest(&self) -> Mode {
+        match self.visual {
+            Some(_) => Mode::Visual,
+            None => Mode::Normal,
+        }
+    }
+
+    /// The celestial bodies marked in the active pane, in the order they are
+    /// shown
+    pub fn marked(&self) -> Vec<u64> {
+        self.visual
+            .as_ref()
+            .map(|visual| visual.marked(self.active(), &self.galaxy))
+            .unwrap_or_default()
+    }
+
+    /// Helper function that finds the celestial bodies a command without a
+    /// reference acts on: the marked ones in `Mode::Visual` (which is left),
+    /// otherwise the selected one. Shows why if there are none.
+    fn targets(&mut self) -> Vec<u64> {
+        if self.visual.is_none() {
+            return self.target(None).into_iter().collect();
+        }
+        let marked = self.marked();
+        self.visual = None;
+        if self.mode == Mode::Visual {
+            self.mode = Mode::Normal;
+        }
+        if marked.is_empty() {
+            self.notify(Level::Warning, "Nothing is marked");
+        }
+        marked
+    }
+
+    /// Helper function that makes `change` to each of `ids` as a single change
+    /// (see `change`), then tells what was `done` to them, with `{}` replaced
+    /// by how many there were (e.g. "Tagged {}")
+    fn change_all<F>(&mut self, ids: Vec<u64>, done: &str, change: F)
+    where
+        F: Fn(&mut Galaxy, u64) -> std::result::Result<(), GalaxyError> + 'static,
+    {
+        if ids.is_empty() {
+            return;
+        }
+        let count = ids.len();
+        let result = self.change(move |galaxy| {
+            // Deleting or moving a `Star` may take some of the others with it
+            for id in &ids {
+                if galaxy.get(*id).is_some() {
+                    change(galaxy, *id)?;
+                }
+            }
+            Ok(())
+        });
+        match result {
+            Ok(()) => self.notify(Level::Info, done.replace("{}", &bodies(count))),
+            Err(e) => self.notify(Level::Error, e.to_string()),
+        }
+    }
+
+    /// Helper function that adds `text` to `section` of the retrospective of
+    /// the sprint `sprint`. Action items are created as Comets.
+    fn add_to_retro(&mut self, sprint: String, section: Section, text: String) {
+        let result = self.change(move |galaxy| {
+            match section {
+                Section::WentWell => {
+                    galaxy.retro(&sprint)?.add_went_well(text.clone());
+                }
+                Section::NeedsImprovement => {
+                    galaxy.retro(&sprint)?.add_needs_improvement(text.clone());
+                }
+                Section::ActionItems => {
+                    galaxy.retro_action_item(&sprint, text.clone())?;
+                }
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            self.mode = Mode::Normal;
+            self.notify(Level::Error, e.to_string());
+        }
+    }
+
+    /// Helper function that moves the selected celestial body `times` places
+    /// up (or down) among its siblings. The pane is sorted by rank, so that
+    /// the new order shows.
+    fn shift(&mut self, down: bool, times: usize) {
+        let Some(id) = self.active().selected else {
+            return;
+        };
+        self.panes[self.active].sort = Sort::Rank;
+        let result = self.change(move |galaxy| {
+            for _ in 0..times {
+                if !galaxy.shift(id, down)? {
+                    break;
+                }
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            self.notify(Level::Error, e.to_string());
+        }
+    }
+
+    /// Helper function that moves the marked (or selected) celestial bodies
+    /// under the `Star` `reference`, or to the root of the `Galaxy` if `None`
+    fn move_targets(&mut self, reference: Option<String>) {
+        let parent = match reference.map(|r| self.galaxy.resolve(&r)) {
+            None => None,
+            Some(Ok(id))
+                if self
+                    .galaxy
+                    .get(id)
+                    .is_some_and(|b| b.kind() == CelestialBodyKind::Star) =>
+            {
+                Some(id)
+            }
+            Some(Ok(id)) => {
+                let name = self.galaxy.display_id(id);
+                return self.notify(Level::Error, format!("{name} is not a Star"));
+            }
+            Some(Err(e)) => return self.notify(Level::Error, e.to_string()),
+        };
+        let ids = self.targets();
+        // Checked up front, since the change is not remembered if it fails
+        let mut ancestor = parent;
+        while let Some(id) = ancestor {
+            if ids.contains(&id) {
+                let name = self.galaxy.display_id(id);
+                return self.notify(Level::Error, format!("{name} cannot be moved into itself"));
+            }
+            ancestor = self.galaxy.get(id).and_then(|b| b.parent());
+        }
+        self.change_all(ids, "Moved {}", move |galaxy, id| {
+            match galaxy.get(id).is_some_and(|b| b.parent() != parent) {
+                true => galaxy.set_parent(id, parent),
+                false => Ok(()),
+            }
+        });
+    }
+
+    /// Helper function that resolves the celestial body a command acts on,
+    /// which is the one selected in the active pane if `reference` is `None`.
+    /// Shows why if there is none.
+    fn target(&mut self, reference: Option<String>) -> Option<u64> {
+        match reference {
+            Some(reference) => match self.galaxy.resolve(&reference) {
+                Ok(id) => Some(id),
+                Err(e) => {
+                    self.notify(Level::Error, e.to_string());
+                    None
+                }
+            },
+            None => {
+                let selected = self.active().selected;
+                if selected.is_none() {
+                    self.notify(Level::Warning, "Nothing is selected");
+                }
+                selected
+            }
+        }
+    }
+
+    /// The `Star` that quick-add and paste create celestial bodies under: the
+    /// selected celestial body if it is a `Star`, otherwise the `Star` it
+    /// belongs to, or the `Star` the active pane is scoped to
+    pub fn selected_star(&self) -> Option<u64> {
+        let pane = self.active();
+        match pane.selected(&self.galaxy) {
+            Some(body) if body.kind() == CelestialBodyKind::Star => Some(body.id()),
+            Some(body) => body.parent().or(pane.focus),
+            None => pane.focus,
+        }
+    }
+
+    /// Helper function that creates the celestial body described by `line`
+    /// (see `command::capture`) under `selected_star`, and selects it
+    fn add(&mut self, line: &str) {
+        let args = match command::capture(line) {
+            Ok(args) => args,
+            Err(e) => return self.notify(Level::Error, e.to_string()),
+        };
+        let parent = self.selected_star();
+        self.create("Added", move |galaxy| {
+            let new = cli::create(galaxy, args.clone());
+            if parent.is_some() {
+                galaxy.set_parent(new, parent)?;
+            }
+            Ok(new)
+        });
+    }
+
+    /// Helper function that remembers the celestial body `reference` (or the
+    /// selected one if `None`), and its descendants, to be pasted by `paste`
+    fn yank(&mut self, reference: Option<String>) {
+        if let Some(id) = self.target(reference) {
+            self.register = Some(id);
+            let name = self.galaxy.display_id(id);
+            match descendants(&self.galaxy, id) {
+                0 => self.notify(Level::Info, format!("Yanked {name}")),
+                n => self.notify(Level::Info, format!("Yanked {name} and {n} descendants")),
+            }
+        }
+    }
+
+    /// Helper function that pastes `times` copies of the yanked celestial body
+    /// (see `Galaxy::copy`) under `selected_star`, and selects the last one
+    fn paste(&mut self, times: usize) {
+        let Some(source) = self.register else {
+            return self.notify(Level::Warning, "Nothing has been yanked yet");
+        };
+        // The copy is selected, so the `Star` is only looked up once
+        let parent = self.selected_star();
+        for _ in 0..times {
+            self.create("Pasted", move |galaxy| galaxy.copy(source, parent));
+        }
+    }
+
+    /// Helper function that copies the celestial body `reference` (or the
+    /// selected one if `None`) as Markdown to the system clipboard
+    fn copy_text(&mut self, reference: Option<String>) {
+        if let Some(id) = self.target(reference) {
+            let name = self.galaxy.display_id(id);
+            match shell::set_clipboard(&markdown::export(&self.galaxy, id)) {
+                true => self.notify(Level::Info, format!("Copied {name} to the clipboard")),
+                false => self.notify(
+                    Level::Error,
+                    "Install wl-copy, xclip, or xsel to copy to the clipboard",
+                ),
+            }
+        }
+    }
+
+    /// Helper function that opens the `n`th link (counting from 1) of the
+    /// selected celestial body with the system opener
+    fn open_link(&mut self, n: usize) {
+        let Some(body) = self.target(None).and_then(|id| self.galaxy.get(id)) else {
+            return;
+        };
+        let name = self.galaxy.display_id(body.id());
+        let link = match body.links() {
+            [] => return self.notify(Level::Warning, format!("{name} has no links")),
+            links => match links.get(n.saturating_sub(1)) {
+                Some(link) => link.clone(),
+                None => {
+                    let message = format!("{name} only has {} links", links.len());
+                    return self.notify(Level::Warning, message);
+                }
+            },
+        };
+        match shell::open(&link.target) {
+            Ok(()) => self.notify(Level::Info, format!("Opened {}", link.label)),
+            Err(e) => self.notify(Level::Error, e.to_string()),
+        }
+    }
+
+    /// Helper function that makes a change creating a celestial body (see
+    /// `change`), then selects it and tells what was done, e.g. "Added"
+    fn create<F>(&mut self, done: &str, create: F)
+    where
+        F: Fn(&mut Galaxy) -> std::result::Result<u64, GalaxyError> + 'static,
+    {
+        // The ID is only known once the change is made, and may differ when
+        // the change is made again to save it
+        let created = Rc::new(Cell::new(None));
+        let id = Rc::clone(&created);
+        let change = self.change(move |galaxy| {
+            id.set(Some(create(galaxy)?));
+            Ok(())
+        });
+        match (change, created.get()) {
+            (Ok(()), Some(id)) => {
+                let name = self.galaxy.display_id(id);
+                self.panes[self.active].selected = Some(id);
+                self.notify(Level::Info, format!("{done} {name}"));
+            }
+            (Err(e), _) => self.notify(Level::Error, e.to_string()),
+            (Ok(()), None) => {}
+        }
+    }
+
+    /// Helper function that gives the terminal to the editor to change the
+    /// title and description of the celestial body `id` as Markdown
+    fn edit_description(&mut self, terminal: &mut DefaultTerminal, id: u64) {
+        let Some(body) = self.galaxy.get(id) else {
+            return;
+        };
+        let old = (body.title().to_string(), body.description().to_string());
+        stop_terminal();
+        let edited = edit::in_editor(&edit::to_markdown(&old.0, &old.1), "md");
+        *terminal = start_terminal();
+
+        let edited = match edited.and_then(|e| Ok(edit::parse_markdown(&e)?)) {
+            Ok(edited) => edited,
+            Err(e) => return self.notify(Level::Error, e.to_string()),
+        };
+        if edited == old {
+            return self.notify(Level::Info, "Nothing was changed");
+        }
+        let name = self.galaxy.display_id(id);
+        let (title, description) = edited;
+        let result = self.change(move |galaxy| {
+            let mut body = galaxy
+                .get_mut(id)
+                .ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+            body.title(title.clone()).description(description.clone());
+            Ok(())
+        });
+        match result {
+            Ok(()) => self.notify(Level::Info, format!("Edited {name}")),
+            Err(e) => self.notify(Level::Error, e.to_string()),
+        }
+    }
+
+    /// Helper function that does what a prompt was answered for
+    fn complete(&mut self, pending: Pending, answer: Answer) {
+        match (pending, answer) {
+            (Pending::Delete(id), Answer::Yes) => {
+                let now = Utc::now();
+                let name = self.galaxy.display_id(id);
+                match self.change(move |galaxy| galaxy.delete(id, now).map(|_| ())) {
+                    Ok(()) => self.notify(Level::Info, format!("Moved {name} to the trash")),
+                    Err(e) => self.notify(Level::Error, e.to_string()),
+                }
+            }
+            (Pending::DeleteMany(ids), Answer::Yes) => {
+                let now = Utc::now();
+                self.change_all(ids, "Moved {} to the trash", move |galaxy, id| {
+                    galaxy.delete(id, now).map(|_| ())
+                });
+            }
+            (Pending::Complete(ids), Answer::Yes) => {
+                self.change_all(ids, "Finished {}", move |galaxy, id| {
+                    if let Some(mut body) = galaxy.get_mut(id) {
+                        body.status(Status::Done, "All children are finished".into());
+                    }
+                    Ok(())
+                });
+            }
+            (Pending::Quit, Answer::Yes) => self.write(true),
+            (Pending::Quit, Answer::No) => self.should_quit = true,
+            _ => self.notify(Level::Info, "Cancelled"),
+        }
+    }
+
+    /// Helper function that applies `change` to the `Galaxy` and remembers it
+    /// until the changes are saved. The status rules are run afterwards, and
+    /// Stars that they would only finish once confirmed are asked about.
+    fn change<F>(&mut self, change: F) -> std::result::Result<(), GalaxyError>
+    where
+        F: Fn(&mut Galaxy) -> std::result::Result<(), GalaxyError> + 'static,
+    {
+        let since = Utc::now();
+        change(&mut self.galaxy)?;
+        // The rules are run again when the changes are saved
+        let declined = self.galaxy.apply_rules(since, &mut |_| false);
+        self.unsaved.push(Box::new(change));
+        self.last_change = Some(Instant::now());
+        for pane in &mut self.panes {
+            pane.reselect(&self.galaxy);
+        }
+
+        let question = match declined.as_slice() {
+            [] => return Ok(()),
+            [id] => format!(
+                "All children of {} ({}) are finished. Mark it as Done?",
+                self.galaxy
+                    .get(*id)
+                    .map(|b| b.title().to_string())
+                    .unwrap_or_default(),
+                self.galaxy.display_id(*id)
+            ),
+            ids => format!(
+                "All children of {} Stars are finished. Mark them as Done?",
+                ids.len()
+            ),
+        };
+        self.mode = Mode::Confirm(Confirm::new(question, Pending::Complete(declined)));
+        Ok(())
+    }
+
+    /// Saves the unsaved changes to the database, returning how many there
+    /// were. The database is loaded again and changed rather than saving the
+    /// `Galaxy` of the TUI, since its history may have been capped.
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - The database could not be loaded or saved
+    /// - A change can no longer be made, e.g. because the celestial body was
+    ///   deleted outside of the TUI
+    pub fn save(&mut self) -> Result<usize> {
+        if self.persistent && !self.unsaved.is_empty() {
+            let mut galaxy = Galaxy::load()?;
+            for change in &self.unsaved {
+                change(&mut galaxy)?;
+            }
+            galaxy.save()?;
+            self.events.saw_database();
+        }
+        Ok(std::mem::take(&mut self.unsaved).len())
+    }
+
+    /// Helper function that returns how long to wait before the unsaved
+    /// changes are saved on their own, `None` if they are not
+    fn autosave_in(&self) -> Option<Duration> {
+        let delay = self.config.autosave()?;
+        let last_change = self.last_change?;
+        Some(delay.saturating_sub(last_change.elapsed()))
+    }
+
+    /// Helper function that does what is done every `Events::TICK`: finishing
+    /// a sequence of keys that timed out, receiving the output of the running
+    /// shell command, and saving the unsaved changes once no more are made for
+    /// a while
+    fn tick(&mut self) {
+        self.finish_pending();
+        if let Some(output) = &mut self.output {
+            output.poll();
+        }
+        if self.autosave_in().is_some_and(|wait| wait.is_zero()) {
+            self.write(false);
+        }
+    }
+
+    /// Helper function that quits because of `signal`. The unsaved changes
+    /// are saved first if the TUI saves on its own (see `Config::autosave`),
+    /// and discarded otherwise.
+    fn stop(&mut self, signal: Signal) {
+        if self.config.autosave().is_some() {
+            if let Err(e) = self.save() {
+                warn!("Could not save the unsaved changes before stopping: {e}");
+            }
+        } else if !self.unsaved.is_empty() {
+            warn!("Discarding {} unsaved changes", self.unsaved.len());
+        }
+        self.stopped_by = Some(signal);
+        self.should_quit = true;
+    }
+
+    /// Helper function that loads the database again after it was changed
+    /// outside of the TUI. The unsaved changes are made to it again, and the
+    /// database is left alone if they no longer can be.
+    fn reload(&mut self) {
+        let stale_since = self.config.stale_after().map(|after| Utc::now() - after);
+        let mut galaxy = match Galaxy::load() {
+            Ok(galaxy) => galaxy
+                .show_stale(stale_since)
+                .project(self.galaxy.get_project().map(String::from)),
+            Err(e) => {
+                let message = format!("The database was changed, but could not be loaded: {e}");
+                return self.notify(Level::Warning, message);
+            }
+        };
+        if let Some(limit) = self.config.history_limit() {
+            galaxy.cap_history(limit);
+        }
+        for change in &self.unsaved {
+            if let Err(e) = change(&mut galaxy) {
+                let message = format!(
+                    "The database was changed, and the unsaved changes conflict with it: {e}"
+                );
+                return self.notify(Level::Warning, message);
+            }
+        }
+        self.galaxy = galaxy;
+        for pane in &mut self.panes {
+            pane.reselect(&self.galaxy);
+        }
+        self.notify(
+            Level::Info,
+            "Reloaded the database, which was changed outside of the TUI",
+        );
+    }
+
+    /// Helper function that opens the project switcher, with the current
+    /// project selected
+    fn open_projects(&mut self) {
+        if self.config.projects.is_empty() {
+            return self.notify(
+                Level::Warning,
+                "Set `projects` in the configuration to switch between them",
+            );
+        }
+        let current = self.galaxy.get_project();
+        let selected = self
+            .config
+            .projects
+            .keys()
+            .position(|name| Some(name.as_str()) == current)
+            .unwrap_or(0);
+        self.mode = Mode::Projects(selected);
+    }
+
+    /// Helper function that switches to the project `name` (see
+    /// `Config::projects`), saving the unsaved changes first. The panes keep
+    /// their views and filters, but are no longer focused, since the IDs
+    /// belong to the other project.
+    fn switch_project(&mut self, name: &str) {
+        let Some(dir) = self.config.projects.get(name).cloned() else {
+            return self.notify(Level::Error, format!("There is no project named {name:?}"));
+        };
+        if let Err(e) = self.save() {
+            return self.notify(Level::Error, format!("Could not save: {e}"));
+        }
+        let stale_since = self.config.stale_after().map(|after| Utc::now() - after);
+        let mut galaxy = match Galaxy::load_project(name, &dir) {
+            Ok(galaxy) => galaxy.show_stale(stale_since),
+            Err(e) => return self.notify(Level::Error, format!("Could not load {name}: {e}")),
+        };
+        // The database is found from the working directory, so saving and
+        // watching it follow along
+        if self.persistent
+            && let Err(e) = env::set_current_dir(&dir)
+        {
+            return self.notify(Level::Error, format!("Could not switch to {name}: {e}"));
+        }
+        if let Some(limit) = self.config.history_limit() {
+            galaxy.cap_history(limit);
+        }
+        self.galaxy = galaxy;
+        self.last_change = None;
+        self.register = None;
+        self.visual = None;
+        for pane in &mut self.panes {
+            pane.focus = None;
+            pane.reselect(&self.galaxy);
+        }
+        self.events.saw_database();
+        self.notify(Level::Info, format!("Switched to {name}"));
+    }
+
+    /// Helper function that saves the unsaved changes, and quits afterwards if
+    /// `quit` and they were saved
+    fn write(&mut self, quit: bool) {
+        self.last_change = None;
+        match self.save() {
+            Ok(0) => self.notify(Level::Info, "There are no changes to save"),
+            Ok(count) => {
+                let plural = if count == 1 { "" } else { "s" };
+                self.notify(Level::Info, format!("Saved {count} change{plural}"));
+            }
+            Err(e) => return self.notify(Level::Error, format!("Could not save: {e}")),
+        }
+        self.should_quit |= quit;
+    }
+
+    /// Helper function that quits, unless there are unsaved changes, in which
+    /// case it asks whether to save them first
+    fn quit(&mut self) {
+        let count = self.unsaved.len();
+        if count == 0 {
+            self.should_quit = true;
+            return;
+        }
+        let plural = if count == 1 { "" } else { "s" };
+        self.mode = Mode::Confirm(Confirm::new(
+            format!("Save the {count} unsaved change{plural} before quitting?"),
+            Pending::Quit,
+        ));
+    }
+
+    /// Shows `text` at the bottom of the screen until the next key press,
+    /// replacing any earlier message
+    pub fn notify(&mut self, level: Level, text: impl Into<String>) {
+        self.message = Some(Notification {
+            level,
+            text: text.into(),
+        });
+    }
+
+    /// The actions of the `Config` that can be run on the selected celestial
+    /// body, see `Config::actions_for`
+    pub fn actions(&self) -> Vec<config::Action> {
+        match self.active().selected(&self.galaxy) {
+            Some(body) => self.config.actions_for(&body).cloned().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Helper function that runs `template` with the system shell in the
+    /// background and shows its output. Placeholders such as `{id}` or
+    /// `{field.url}` are replaced with the values of the selected celestial
+    /// body, see `shell::expand`.
+    fn run_shell(&mut self, template: &str) {
+        let body = self.active().selected(&self.galaxy);
+        let command = match shell::expand(template, |key| body.as_ref()?.value(key)) {
+            Ok(command) => command,
+            Err(e) => return self.notify(Level::Error, e.to_string()),
+        };
+        match shell::Runner::spawn(&command) {
+            Ok(runner) => {
+                self.output = Some(ShellOutput::new(runner));
+                self.mode = Mode::Output;
+            }
+            Err(e) => self.notify(Level::Error, format!("Could not run {command}: {e}")),
+        }
+    }
+
+    /// Helper function that opens `pane` next to the active pane and makes it
+    /// active
+    fn split(&mut self, pane: Pane, split: Split) {
+        let index = self.panes.len();
+        if !self.layout.split(self.active, index, split) {
+            self.notify(Level::Warning, "No room for another pane in this split");
+            return;
+        }
+        self.panes.push(pane);
+        self.active = index;
+    }
+
+    /// Helper function that closes the active pane, unless it is the only one.
+    /// The pane before it (or after it, if it was the first) becomes active.
+    fn close(&mut self) {
+        let leaves = self.layout.leaves();
+        let position = leaves.iter().position(|i| *i == self.active);
+        let closed = self.active;
+        if !self.layout.remove(closed) {
+            self.notify(Level::Warning, "Cannot close the only pane");
+            return;
+        }
+        self.panes.remove(closed);
+
+        let next = match position {
+            Some(0) | None => leaves[1],
+            Some(position) => leaves[position - 1],
+        };
+        self.active = if next > closed { next - 1 } else { next };
+    }
+
+    /// Runs the event loop until the user quits
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while !self.should_quit {
+            terminal.draw(|frame| view::draw(self, frame))?;
+            match self.events.wait()? {
+                AppEvent::Key(key) => self.handle_key(key),
+                AppEvent::Paste(text) => self.handle_paste(&text),
+                AppEvent::Resize => {}
+                AppEvent::Tick => self.tick(),
+                AppEvent::FsChange => self.reload(),
+                AppEvent::Command(command) => {
+                    if let Err(e) = self.execute(&command) {
+                        self.notify(Level::Error, e.to_string());
+                    }
+                }
+                AppEvent::Signal(signal) => self.stop(signal),
+            }
+            // Count down the pending keys in the statusline, and stream the
+            // output of the running shell command
+            if self.pending.is_some() || self.output.as_ref().is_some_and(ShellOutput::running) {
+                self.events.tick_within(Duration::from_millis(100));
+            }
+            if let Some(id) = self.editing.take() {
+                self.edit_description(terminal, id);
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Launches the TUI
+///
+/// # Errors
+/// Errors will occur in the following situations:
+/// - There is no `Galaxy` or it could not be loaded
+/// - The filter is not valid
+/// - The focused celestial body does not exist or is not a `Star`
+pub fn run(args: TuiArgs) -> Result<()> {
+    let config = Config::load()?;
+    // The theme was already set, but errors in it are only reported here
+    config.theme()?;
+    let (galaxy, skipped) = Galaxy::load_with_issues()?;
+    let project = config.project_at(&Galaxy::location()?).map(String::from);
+    let mut galaxy = galaxy
+        .show_stale(config.stale_after().map(|after| Utc::now() - after))
+        .project(project);
+    // Changes are made to a freshly loaded database, so older history does not
+    // need to be kept around
+    if let Some(limit) = config.history_limit() {
+        galaxy.cap_history(limit);
+    }
+
+    let filter = match &args.filter {
+        Some(filter) => filter.parse()?,
+        None => Filter::default(),
+    };
+    let focus = match &args.focus {
+        Some(focus) => Some(galaxy.resolve(focus)?),
+        None => None,
+    };
+    if let Some(focus) = focus {
+        let body = galaxy
+            .get(focus)
+            .ok_or(AppError::CelestialBodyNotFound(focus))?;
+        if body.kind() != CelestialBodyKind::Star {
+            return Err(AppError::InvalidArgument(format!(
+                "Can only focus on a Star, {} is a {}",
+                galaxy.display_id(focus),
+                body.kind()
+            )));
+        }
+    }
+
+    let pane = Pane::new(&galaxy, args.view, filter, focus, args.all);
+    let mut app = App::new(galaxy, config, pane);
+    app.persistent = true;
+    app.events.watch();
+    app.events.catch_signals()?;
+    let sender = app.events.sender();
+    for command in args.commands {
+        let _ = sender.send(AppEvent::Command(command));
+    }
+    if let Some(first) = skipped.first() {
+        for issue in &skipped {
+            warn!("Loading the database: {issue}");
+        }
+        let more = match skipped.len() {
+            1 => String::new(),
+            n => format!(" (and {} more, see the log)", n - 1),
+        };
+        app.notify(Level::Warning, format!("{first}{more}"));
+    }
+    let mut terminal = start_terminal();
+    let result = app.run(&mut terminal);
+    stop_terminal();
+    result?;
+    match app.stopped_by {
+        Some(signal) => Err(AppError::Stopped(signal)),
+        None => Ok(()),
+    }
+}
+
+/// Helper function that takes over the terminal to draw the TUI
+fn start_terminal() -> DefaultTerminal {
+    let terminal = ratatui::init();
+    // Pasting still works without bracketed paste, one key press at a time
+    let _ = execute!(io::stdout(), EnableBracketedPaste);
+    terminal
+}
+
+/// Helper function that gives the terminal back, e.g. to the shell or an
+/// editor
+fn stop_terminal() {
+    let _ = execute!(io::stdout(), DisableBracketedPaste);
+    ratatui::restore();
+}
+
+/// Helper function that names `count` celestial bodies, e.g. in messages
+fn bodies(count: usize) -> String {
+    match count {
+        1 => "1 celestial body".into(),
+        count => format!("{count} celestial bodies"),
+    }
+}
+
+/// Helper function that counts the descendants of the celestial body `id`
+fn descendants(galaxy: &Galaxy, id: u64) -> usize {
+    let mut count = 0;
+    let mut stack = vec![id];
+    while let Some(id) = stack.pop() {
+        let children = galaxy.get(id).map(|b| b.children()).unwrap_or_default();
+        count += children.len();
+        stack.extend(children);
+    }
+    count
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crossterm::event::KeyModifiers;
+
+    use crate::{
+        app::config::SavedView,
+        core::{CelestialBody, Priority, Sort, Status},
+    };
+
+    use super::*;
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn splitting_opens_blank_or_cloned_pane() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let planet = galaxy.planet().id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        let pane = Pane::new(&galaxy, View::Kanban, Filter::default(), Some(star), false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.panes.len(), 2);
+        assert_eq!(app.active, 1);
+        assert_eq!(app.active().view, View::Tree);
+        assert_eq!(app.active().focus, None);
+        assert_eq!(app.active().selected, Some(star));
+
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(app.active, 0);
+        press(&mut app, KeyCode::Char('S'));
+        assert_eq!(app.active().view, View::Kanban);
+        assert_eq!(app.active().focus, Some(star));
+        assert_eq!(app.active().selected, Some(planet));
+
+        press(&mut app, KeyCode::Char('x'));
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(app.panes.len(), 1);
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn focus_moves_between_split_panes() {
+        let galaxy = Galaxy::default();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+        let ctrl = |app: &mut App, c| {
+            app.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL));
+        };
+
+        press(&mut app, KeyCode::Char('s'));
+        app.execute("clone below").unwrap();
+        assert_eq!(app.active, 2);
+        ctrl(&mut app, 'k');
+        assert_eq!(app.active, 1);
+        ctrl(&mut app, 'h');
+        assert_eq!(app.active, 0);
+        ctrl(&mut app, 'l');
+        ctrl(&mut app, 'j');
+        assert_eq!(app.active, 2);
+
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(app.active, 1);
+        assert_eq!(app.layout.leaves(), vec![0, 1]);
+        ctrl(&mut app, 'h');
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(app.active, 0);
+        assert_eq!(app.layout, PaneNode::Leaf(0));
+    }
+
+    #[test]
+    fn panes_are_resized_after_ctrl_w() {
+        let galaxy = Galaxy::default();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+        let width = |app: &App, index: usize| {
+            let areas = app.layout.areas(ratatui::layout::Rect::new(0, 0, 100, 10));
+            areas.iter().find(|(i, _)| *i == index).unwrap().1.width
+        };
+
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(width(&app, 1), 50);
+        app.handle_key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        assert_eq!(app.mode, Mode::Pane);
+        press(&mut app, KeyCode::Char('>'));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(width(&app, 1), 55);
+
+        app.execute("resize left 40").unwrap();
+        assert_eq!(width(&app, 1), 15);
+        assert_eq!(width(&app, 0), 85);
+    }
+
+    #[test]
+    fn notifications_say_what_happened_until_the_next_key() {
+        let galaxy = Galaxy::default();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+        let message = |app: &App| app.message.clone().map(|m| (m.level, m.text));
+
+        app.execute("notify error  Build failed").unwrap();
+        assert_eq!(message(&app), Some((Level::Error, "Build failed".into())));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(message(&app), None);
+
+        app.execute("archived").unwrap();
+        assert_eq!(
+            message(&app),
+            Some((Level::Info, "Showing archived celestial bodies".into()))
+        );
+        app.execute("close").unwrap();
+        assert_eq!(
+            message(&app),
+            Some((Level::Warning, "Cannot close the only pane".into()))
+        );
+        app.execute("resize left").unwrap();
+        assert_eq!(
+            message(&app),
+            Some((Level::Warning, "The pane cannot be resized that way".into()))
+        );
+        assert_eq!(
+            app.execute("notify"),
+            Err(CommandError::MissingArgument("notify".into()))
+        );
+    }
+
+    #[test]
+    fn deleting_asks_for_confirmation() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().title("Backend".into()).id();
+        let planet = galaxy.planet().title("API".into()).id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        app.execute("delete #1").unwrap();
+        assert!(matches!(app.mode, Mode::Confirm(_)));
+        press(&mut app, KeyCode::Char('n'));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.galaxy.get(planet).is_some());
+
+        assert_eq!(app.active().selected, Some(star));
+        press(&mut app, KeyCode::Char('d'));
+        press(&mut app, KeyCode::Char('y'));
+        assert!(matches!(app.mode, Mode::Confirm(_)));
+        press(&mut app, KeyCode::Backspace);
+        for c in "Backend".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.galaxy.get(star).is_none());
+        assert!(app.galaxy.get(planet).is_none());
+        assert_eq!(app.active().selected, None);
+    }
+
+    #[test]
+    fn quitting_asks_to_save_unsaved_changes() {
+        let mut galaxy = Galaxy::default();
+        galaxy.planet();
+        galaxy.planet();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        app.execute("delete #1").unwrap();
+        press(&mut app, KeyCode::Char('y'));
+        assert_eq!(app.unsaved.len(), 1);
+        press(&mut app, KeyCode::Char('q'));
+        assert!(matches!(
+            &app.mode,
+            Mode::Confirm(Confirm {
+                pending: Pending::Quit,
+                ..
+            })
+        ));
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(!app.should_quit);
+
+        app.execute("w").unwrap();
+        assert!(app.unsaved.is_empty());
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.should_quit);
+
+        app.should_quit = false;
+        app.execute("delete #0").unwrap();
+        press(&mut app, KeyCode::Char('y'));
+        app.execute("q").unwrap();
+        press(&mut app, KeyCode::Char('n'));
+        assert!(app.should_quit);
+        assert_eq!(app.unsaved.len(), 1);
+    }
+
+    #[test]
+    fn ticks_save_changes_once_they_stop() {
+        let mut galaxy = Galaxy::default();
+        galaxy.planet();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let config = Config {
+            autosave_ms: Some(0),
+            ..Config::default()
+        };
+        let mut app = App::new(galaxy, config, pane);
+
+        app.events
+            .sender()
+            .send(AppEvent::Command("delete #0".into()))
+            .unwrap();
+        let AppEvent::Command(command) = app.events.wait().unwrap() else {
+            panic!("Expected the command that was sent");
+        };
+        app.execute(&command).unwrap();
+        press(&mut app, KeyCode::Char('y'));
+        assert_eq!(app.unsaved.len(), 1);
+        app.tick();
+        assert!(app.unsaved.is_empty());
+        assert_eq!(app.message.unwrap().text, "Saved 1 change");
+    }
+
+    #[test]
+    fn key_sequences_wait_for_the_next_key() {
+        let mut galaxy = Galaxy::default();
+        galaxy.planet();
+        galaxy.planet();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut config = Config::default();
+        config.keys.insert("g k".into(), "view kanban".into());
+        config.keys.insert("gt".into(), "view tree".into());
+        let mut app = App::new(galaxy, config, pane);
+
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(
+            app.pending.as_ref().map(|(keys, _)| keys.as_str()),
+            Some("g")
+        );
+        assert!(app.pending_for().is_some());
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(app.active().view, View::Kanban);
+        assert!(app.pending.is_none());
+        press(&mut app, KeyCode::Char('g'));
+        press(&mut app, KeyCode::Char('t'));
+        assert_eq!(app.active().view, View::Tree);
+
+        // A key that does not continue the sequence does the built in action
+        // of the pending key first
+        press(&mut app, KeyCode::Char('g'));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.active().selected, Some(1));
+
+        press(&mut app, KeyCode::Char('g'));
+        app.tick();
+        assert!(app.pending.is_some());
+        app.pending = Some(("g".into(), Instant::now() - Duration::from_secs(2)));
+        app.tick();
+        assert!(app.pending.is_none());
+        assert_eq!(app.active().selected, Some(0));
+
+        press(&mut app, KeyCode::Char('g'));
+        press(&mut app, KeyCode::Esc);
+        assert!(app.pending.is_none());
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn quick_add_creates_under_the_selected_star() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().title("Backend".into()).id();
+        let planet = galaxy.planet().title("API".into()).id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        app.panes[0].selected = Some(planet);
+        assert_eq!(app.selected_star(), Some(star));
+        press(&mut app, KeyCode::Char('A'));
+        assert!(matches!(app.mode, Mode::QuickAdd(_)));
+        app.handle_paste(r#"comet "Fix the typo" -p high"#);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.unsaved.len(), 1);
+
+        let id = app.active().selected.unwrap();
+        let comet = app.galaxy.get(id).unwrap();
+        assert_eq!(comet.title(), "Fix the typo");
+        assert_eq!(comet.kind(), CelestialBodyKind::Comet);
+        assert_eq!(comet.parent(), Some(star));
+
+        app.execute("add star Backend -e 3pts").unwrap();
+        assert_eq!(app.message.unwrap().level, Level::Error);
+        assert_eq!(app.unsaved.len(), 1);
+    }
+
+    #[test]
+    fn yanked_bodies_are_pasted_under_the_selected_star() {
+        let mut galaxy = Galaxy::default();
+        let backend = galaxy.star().title("Backend".into()).id();
+        let planet = galaxy.planet().title("API".into()).id();
+        let frontend = galaxy.star().title("Frontend".into()).id();
+        galaxy.set_parent(planet, Some(backend)).unwrap();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        press(&mut app, KeyCode::Char('p'));
+        assert_eq!(app.message.as_ref().unwrap().level, Level::Warning);
+
+        app.panes[0].selected = Some(backend);
+        press(&mut app, KeyCode::Char('y'));
+        assert_eq!(app.register, Some(backend));
+        assert_eq!(
+            app.message.as_ref().unwrap().text,
+            "Yanked #0 and 1 descendants"
+        );
+
+        app.panes[0].selected = Some(frontend);
+        press(&mut app, KeyCode::Char('2'));
+        press(&mut app, KeyCode::Char('p'));
+        assert_eq!(app.unsaved.len(), 2);
+        let children = app.galaxy.get(frontend).unwrap().children().to_vec();
+        assert_eq!(children.len(), 2);
+        assert_eq!(app.active().selected, Some(children[1]));
+        let copy = app.galaxy.get(children[0]).unwrap();
+        assert_eq!(copy.title(), "Backend");
+        assert_eq!(app.galaxy.get(copy.children()[0]).unwrap().title(), "API");
+        assert_eq!(app.galaxy.get(backend).unwrap().children(), [planet]);
+    }
+
+    #[test]
+    fn commands_act_on_everything_marked() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let planets: Vec<_> = (0..4).map(|_| galaxy.planet().id()).collect();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        app.panes[0].selected = Some(planets[0]);
+        press(&mut app, KeyCode::Char('V'));
+        assert_eq!(app.mode, Mode::Visual);
+        press(&mut app, KeyCode::Char('2'));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.marked(), planets[..3]);
+        press(&mut app, KeyCode::Char(' '));
+        assert_eq!(app.marked(), planets[..2]);
+
+        press(&mut app, KeyCode::Char(':'));
+        app.handle_paste("status done");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.marked().is_empty());
+        assert_eq!(
+            app.message.as_ref().unwrap().text,
+            "Changed the status of 2 celestial bodies"
+        );
+        let status = |app: &App, id: u64| app.galaxy.get(id).unwrap().status();
+        assert_eq!(status(&app, planets[1]), Status::Done);
+        assert_eq!(status(&app, planets[2]), Status::Todo);
+
+        // Space starts marking single celestial bodies
+        press(&mut app, KeyCode::Char(' '));
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char(' '));
+        assert_eq!(app.marked(), planets[2..]);
+        app.execute(&format!("move #{star}")).unwrap();
+        assert_eq!(app.galaxy.get(star).unwrap().children(), &planets[2..]);
+
+        // Without marks, commands act on the selected celestial body
+        app.panes[0].selected = Some(planets[3]);
+        app.execute("priority critical").unwrap();
+        let priority = |app: &App, id: u64| app.galaxy.get(id).unwrap().priority();
+        assert_eq!(priority(&app, planets[3]), Priority::Critical);
+        assert_eq!(priority(&app, planets[2]), Priority::None);
+
+        app.panes[0].selected = Some(star);
+        press(&mut app, KeyCode::Char('V'));
+        press(&mut app, KeyCode::Char('G'));
+        app.execute(&format!("move #{star}")).unwrap();
+        assert_eq!(app.message.as_ref().unwrap().level, Level::Error);
+        assert_eq!(app.unsaved.len(), 3);
+
+        app.panes[0].selected = Some(star);
+        press(&mut app, KeyCode::Char('V'));
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('d'));
+        press(&mut app, KeyCode::Char('y'));
+        assert_eq!(app.galaxy.iter().count(), 2);
+        assert_eq!(app.mode, Mode::Normal);
+    }
+
+    #[test]
+    fn counts_repeat_the_next_key() {
+        let mut galaxy = Galaxy::default();
+        for _ in 0..12 {
+            galaxy.planet();
+        }
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+        let keys = |app: &mut App, keys: &str| {
+            for c in keys.chars() {
+                press(app, KeyCode::Char(c));
+            }
+        };
+
+        keys(&mut app, "5");
+        assert_eq!(app.count, Some(5));
+        keys(&mut app, "j");
+        assert_eq!(app.count, None);
+        assert_eq!(app.active().selected, Some(5));
+        keys(&mut app, "10j");
+        assert_eq!(app.active().selected, Some(11));
+        keys(&mut app, "3k");
+        assert_eq!(app.active().selected, Some(8));
+        keys(&mut app, "2g");
+        assert_eq!(app.active().selected, Some(1));
+        keys(&mut app, "0");
+        assert_eq!(app.count, None);
+        keys(&mut app, "99G");
+        assert_eq!(app.active().selected, Some(11));
+
+        keys(&mut app, "4");
+        press(&mut app, KeyCode::Esc);
+        keys(&mut app, "k");
+        assert_eq!(app.active().selected, Some(10));
+
+        // The count is kept for the key after `Ctrl+w`
+        keys(&mut app, "s3");
+        app.handle_key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        keys(&mut app, ">");
+        let areas = app.layout.areas(ratatui::layout::Rect::new(0, 0, 100, 10));
+        assert_eq!(areas.iter().find(|(i, _)| *i == 1).unwrap().1.width, 65);
+    }
+
+    #[test]
+    fn ctrl_c_stops_like_sigint() {
+        let mut galaxy = Galaxy::default();
+        galaxy.planet();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+        app.execute("delete #0").unwrap();
+        press(&mut app, KeyCode::Char('y'));
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(app.should_quit);
+        assert_eq!(app.stopped_by, Some(Signal::Interrupt));
+        // Without autosave, the unsaved changes are not saved
+        assert_eq!(app.unsaved.len(), 1);
+        assert_eq!(AppError::Stopped(Signal::Terminate).exit_code(), 143);
+    }
+
+    #[test]
+    fn help_shows_the_keys_of_the_mode() {
+        let galaxy = Galaxy::default();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        press(&mut app, KeyCode::Char('?'));
+        assert_eq!(app.mode, Mode::Help(Box::new(Mode::Normal)));
+        press(&mut app, KeyCode::Char('q'));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(!app.should_quit);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        press(&mut app, KeyCode::Char('?'));
+        assert_eq!(app.mode, Mode::Help(Box::new(Mode::Pane)));
+    }
+
+    #[test]
+    fn aliases_run_each_command() {
+        let mut galaxy = Galaxy::default();
+        let planet = galaxy.planet().id();
+        galaxy.get_mut(planet).unwrap().tag("inbox".into());
+        galaxy.planet();
+
+        let mut config = Config::default();
+        config.aliases.insert(
+            "triage".into(),
+            vec!["view kanban".into(), "filter tag:inbox".into()],
+        );
+        config.keys.insert("t".into(), "triage".into());
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, config, pane);
+
+        press(&mut app, KeyCode::Char('t'));
+        assert_eq!(app.active().view, View::Kanban);
+        assert_eq!(app.active().order(&app.galaxy), vec![planet]);
+
+        for c in ":filter".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        assert_eq!(app.mode, Mode::Command("filter".into()));
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.active().order(&app.galaxy).len(), 2);
+
+        assert_eq!(
+            app.execute("bogus"),
+            Err(CommandError::UnknownCommand("bogus".into()))
+        );
+        for c in ":focus 1".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        let message = |app: &App| app.message.clone().map(|m| (m.level, m.text));
+        assert_eq!(
+            message(&app),
+            Some((Level::Error, "#1 is not a Star".into()))
+        );
+        app.execute("notify warn Careful").unwrap();
+        assert_eq!(message(&app), Some((Level::Warning, "Careful".into())));
+
+        app.execute("open board tag:inbox").unwrap();
+        assert_eq!(app.active().view, View::Kanban);
+        assert_eq!(app.active().order(&app.galaxy), vec![planet]);
+        app.execute("open tree bogus:1").unwrap();
+        assert_eq!(app.active().view, View::Kanban);
+        assert!(app.message.is_some());
+    }
+
+    #[test]
+    fn saved_views_are_opened_by_name() {
+        let mut galaxy = Galaxy::default();
+        let b = galaxy.planet().title("b".into()).id();
+        let a = galaxy.planet().title("a".into()).id();
+        galaxy.planet().title("c".into());
+        galaxy.get_mut(a).unwrap().tag("backend".into());
+        galaxy.get_mut(b).unwrap().tag("backend".into());
+
+        let mut config = Config::default();
+        let view = SavedView {
+            filter: "tag:backend".into(),
+            sort: Sort::Title,
+            layout: View::Kanban,
+            ..SavedView::default()
+        };
+        config.views.insert("backend".into(), view);
+        config.views.insert("tree".into(), SavedView::default());
+        config
+            .aliases
+            .insert("work".into(), vec!["open backend".into()]);
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, config, pane);
+
+        app.execute("work").unwrap();
+        assert_eq!(app.active().view, View::Kanban);
+        assert_eq!(app.active().order(&app.galaxy), vec![a, b]);
+        assert_eq!(app.active().selected, Some(a));
+
+        app.execute("sort id").unwrap();
+        assert_eq!(app.active().order(&app.galaxy), vec![b, a]);
+        // Built in views are not shadowed by saved views
+        app.execute("open tree").unwrap();
+        assert_eq!(app.active().order(&app.galaxy).len(), 3);
+    }
+
+    #[test]
+    fn projects_are_switched_to_from_the_switcher() {
+        let dir = env::temp_dir().join(format!("planit-project-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut other = Galaxy::default();
+        let star = other.star().title("API".into()).id();
+        other.init(dir.clone(), true).unwrap();
+
+        let mut galaxy = Galaxy::default();
+        let comet = galaxy.comet().id();
+        let mut config = Config::default();
+        config.projects.insert("api".into(), dir.clone());
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, config, pane);
+        app.panes[0].focus = Some(comet);
+
+        app.execute("project web").unwrap();
+        assert_eq!(app.message.as_ref().map(|m| m.level), Some(Level::Error));
+        press(&mut app, KeyCode::Char('P'));
+        assert_eq!(app.mode, Mode::Projects(0));
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.galaxy.get_project(), Some("api"));
+        assert_eq!(app.galaxy.display_id(star), "api:#0");
+        assert_eq!(app.active().focus, None);
+        assert_eq!(app.active().selected, Some(star));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+    #[cfg(unix)]
+    #[test]
+    fn shell_commands_show_their_output() {
+        let mut galaxy = Galaxy::default();
+        galaxy.planet().title("Fix it's login".into());
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        app.execute("!echo {title}; exit 1").unwrap();
+        assert_eq!(app.mode, Mode::Output);
+        while app.output.as_ref().is_some_and(ShellOutput::running) {
+            std::thread::sleep(Duration::from_millis(10));
+            app.tick();
+        }
+        let lines = &app.output.as_ref().unwrap().lines;
+        assert_eq!(
+            lines,
+            &[
+                shell::Output::Stdout("Fix it's login".into()),
+                shell::Output::Exit(Some(1))
+            ]
+        );
+        press(&mut app, KeyCode::Char('q'));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.output.is_none());
+
+        app.execute("!echo {bogus}").unwrap();
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(
+            app.message.clone().map(|m| m.text),
+            Some("Unknown template key: bogus".into())
+        );
+    }
+    #[cfg(unix)]
+    #[test]
+    fn actions_are_picked_from_a_list() {
+        let mut galaxy = Galaxy::default();
+        let comet = galaxy.comet().title("Typo".into()).id();
+        galaxy.planet();
+        let mut config = Config::default();
+        for (name, kinds) in [("Show", vec![]), ("Fix", vec![CelestialBodyKind::Planet])] {
+            config.actions.push(config::Action {
+                name: name.into(),
+                command: format!("echo {name} {{title}}"),
+                kinds,
+                tags: vec![],
+            });
+        }
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, config, pane);
+        app.panes[0].selected = Some(comet);
+
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(app.mode, Mode::Actions(0));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.mode, Mode::Actions(0));
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.mode, Mode::Output);
+        assert_eq!(app.output.as_ref().unwrap().command, "echo Show 'Typo'");
+
+        press(&mut app, KeyCode::Esc);
+        app.execute("run Fix").unwrap();
+        assert_eq!(
+            app.message.clone().map(|m| m.text),
+            Some("No action named \"Fix\"".into())
+        );
+    }
+
+    #[test]
+    fn retrospectives_are_recorded_in_a_form() {
+        let mut galaxy = Galaxy::default();
+        let today = Local::now().date_naive();
+        galaxy
+            .sprint_create("Sprint 1".into(), today, today)
+            .unwrap();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let mut app = App::new(galaxy, Config::default(), pane);
+
+        app.execute("retro sprint 2").unwrap();
+        assert_eq!(
+            app.message.clone().map(|m| m.text),
+            Some("No sprint named \"sprint 2\"".into())
+        );
+        app.execute("retro").unwrap();
+        assert_eq!(app.mode, Mode::Retro(RetroForm::new("Sprint 1".into())));
+
+        app.handle_paste("Demo went smoothly");
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Tab);
+        press(&mut app, KeyCode::Tab);
+        app.handle_paste("Write a demo script");
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.mode, Mode::Normal);
+
+        let retro = app.galaxy.get_retro("Sprint 1").unwrap();
+        assert_eq!(retro.went_well(), ["Demo went smoothly"]);
+        let item = app.galaxy.get(retro.action_items()[0]).unwrap();
+        assert_eq!(item.title(), "Write a demo script");
+        assert_eq!(app.unsaved.len(), 2);
+    }
+}
@@ -0,0 +1,139 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+/*!
+ * Contains the output pane of the TUI, which shows what a shell command run
+ * with `:!<command>` writes to stdout and stderr while it is running, followed
+ * by its exit status.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use crate::util::shell::{Output, Runner};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The output of a shell command run from the TUI
+#[derive(Debug)]
+pub struct ShellOutput {
+    /// The command line, after its placeholders were expanded
+    pub command: String,
+    /// Everything received from the command so far, ending with
+    /// `Output::Exit` once it has finished
+    pub lines: Vec<Output>,
+    /// How many lines are scrolled past
+    pub scroll: usize,
+    /// The running command, `None` once it has finished
+    runner: Option<Runner>,
+}
+
+impl ShellOutput {
+    /// Shows the output of `runner`, which is received on every tick
+    pub fn new(runner: Runner) -> Self {
+        Self {
+            command: runner.command.clone(),
+            lines: Vec::new(),
+            scroll: 0,
+            runner: Some(runner),
+        }
+    }
+
+    /// Receives the output written since the last call without blocking
+    ///
+    /// # Returns
+    /// Whether anything was received
+    pub fn poll(&mut self) -> bool {
+        let Some(runner) = &self.runner else {
+            return false;
+        };
+        let before = self.lines.len();
+        while let Some(output) = runner.try_recv() {
+            let finished = matches!(output, Output::Exit(_));
+            self.lines.push(output);
+            if finished {
+                self.runner = None;
+                break;
+            }
+        }
+        self.lines.len() != before
+    }
+
+    /// Whether the command is still running
+    pub fn running(&self) -> bool {
+        self.runner.is_some()
+    }
+
+    /// Scrolls down by `delta` lines, or up if negative, without going past
+    /// the first or last line
+    pub fn scroll_by(&mut self, delta: isize) {
+        let last = self.lines.len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(delta).min(last);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn output_is_received_until_the_command_exits() {
+        let mut output =
+            ShellOutput::new(Runner::spawn("echo out; echo err 1>&2; exit 2").unwrap());
+        for _ in 0..500 {
+            output.poll();
+            if !output.running() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        assert!(!output.running());
+        assert!(output.lines.contains(&Output::Stdout("out".into())));
+        assert!(output.lines.contains(&Output::Stderr("err".into())));
+        assert_eq!(output.lines.last(), Some(&Output::Exit(Some(2))));
+        assert!(!output.poll());
+
+        output.scroll_by(-1);
+        assert_eq!(output.scroll, 0);
+        output.scroll_by(10);
+        assert_eq!(output.scroll, 2);
+    }
+}
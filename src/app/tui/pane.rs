@@ -0,0 +1,717 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the state of a single pane of the TUI.
+ *
+ * Each pane shows the `Galaxy` through its own `View`, with its own filter,
+ * focus, selection, and scroll position. Panes never own the `Galaxy`, so
+ * everything that needs it takes it as an argument.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashSet, ops::Range};
+
+use chrono::Local;
+
+use crate::core::{CelestialBodyKind, CelestialBodyRef, Filter, Galaxy, GroupBy, Sort, Status};
+
+use super::{Action, View};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single line of the tree view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeRow {
+    pub id: u64,
+    /// How many ancestors are shown above the celestial body
+    pub depth: usize,
+    /// Whether the celestial body matches the filter, or is only shown because
+    /// one of its descendants does
+    pub matches: bool,
+}
+
+/// A column of the kanban view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    /// What the celestial bodies in the column have in common, e.g. `Start`
+    pub label: String,
+    /// The status of the column if the columns are statuses, which colors it
+    pub status: Option<Status>,
+    pub ids: Vec<u64>,
+}
+
+/// The state of a single pane
+#[derive(Debug, Default)]
+pub struct Pane {
+    pub(super) view: View,
+    pub(super) filter: Filter,
+    /// The order siblings (or the celestial bodies in a column) are shown in
+    pub(super) sort: Sort,
+    /// What the columns of the kanban view are, e.g. statuses or tags
+    pub(super) columns: GroupBy,
+    /// Only the descendants of this `Star` are shown, everything is shown if
+    /// `None`
+    pub(super) focus: Option<u64>,
+    /// Whether archived celestial bodies are shown
+    pub(super) show_archived: bool,
+    /// Only the celestial bodies assigned to this user are shown, on top of
+    /// the filter
+    pub(super) mine: Option<String>,
+    pub(super) selected: Option<u64>,
+    /// How far each list in the view is scrolled. The tree view has a single
+    /// list, the kanban view has one per column.
+    pub(super) offsets: Vec<usize>,
+    /// How many rows the lists of the view had when they were last drawn,
+    /// which is how far the selection moves a page
+    pub(super) page: usize,
+    /// What was last searched for with `/`, repeated with `n` and `N`
+    pub(super) search: Option<String>,
+}
+
+impl Pane {
+    /// The statuses shown as columns in the kanban view, in order
+    pub const KANBAN_COLUMNS: [Status; 6] = [
+        Status::Todo,
+        Status::Next,
+        Status::Start,
+        Status::Block,
+        Status::Hold,
+        Status::Done,
+    ];
+
+    /// Creates a pane, selecting the first celestial body shown
+    pub fn new(
+        galaxy: &Galaxy,
+        view: View,
+        filter: Filter,
+        focus: Option<u64>,
+        show_archived: bool,
+    ) -> Self {
+        let mut pane = Self {
+            view,
+            filter,
+            focus,
+            show_archived,
+            ..Self::default()
+        };
+        pane.reselect(galaxy);
+        pane
+    }
+
+    /// Creates a new pane that shows exactly what this one does: the same
+    /// view, filter, sort order, focus, selection, and scroll position
+    pub fn clone_state(&self) -> Self {
+        Self {
+            view: self.view,
+            filter: self.filter.clone(),
+            sort: self.sort,
+            columns: self.columns.clone(),
+            focus: self.focus,
+            show_archived: self.show_archived,
+            mine: self.mine.clone(),
+            selected: self.selected,
+            offsets: self.offsets.clone(),
+            page: self.page,
+            search: self.search.clone(),
+        }
+    }
+
+    /// The rows of the tree view. A celestial body is shown if it matches the
+    /// filter or if any of its descendants do.
+    pub fn tree(&self, galaxy: &Galaxy) -> Vec<TreeRow> {
+        let mut roots: Vec<_> = match self.focus.and_then(|id| galaxy.get(id)) {
+            Some(focus) => focus.children().to_vec(),
+            None => galaxy
+                .iter()
+                .filter(|body| body.parent().is_none())
+                .map(|body| body.id())
+                .collect(),
+        };
+        self.sort.sort(galaxy, &mut roots);
+
+        let mut rows = Vec::new();
+        for root in roots {
+            self.tree_rows(galaxy, root, 0, &mut rows);
+        }
+        rows
+    }
+
+    /// Helper function that adds the rows for `id` and its descendants to
+    /// `rows`, returning whether anything was added
+    fn tree_rows(&self, galaxy: &Galaxy, id: u64, depth: usize, rows: &mut Vec<TreeRow>) -> bool {
+        let Some(body) = galaxy.get(id).filter(|_| !self.is_hidden(galaxy, id)) else {
+            return false;
+        };
+        let matches = self.matches(&body);
+        let index = rows.len();
+        rows.push(TreeRow { id, depth, matches });
+
+        let mut children = body.children().to_vec();
+        self.sort.sort(galaxy, &mut children);
+        let mut any_child = false;
+        for child in children {
+            any_child |= self.tree_rows(galaxy, child, depth + 1, rows);
+        }
+
+        if !matches && !any_child {
+            rows.remove(index);
+            return false;
+        }
+        true
+    }
+
+    /// The celestial bodies in each column of the kanban view. `Star`s are left
+    /// out because their status follows their children. Every status has a
+    /// column, while other columns (e.g. tags) are only there if they are not
+    /// empty, and a celestial body with several tags is in each of their
+    /// columns.
+    pub fn kanban(&self, galaxy: &Galaxy) -> Vec<Column> {
+        let mut ids: Vec<_> = galaxy
+            .matching(&self.filter)
+            .into_iter()
+            .filter(|body| {
+                body.kind() != CelestialBodyKind::Star && !self.is_hidden(galaxy, body.id())
+            })
+            .filter(|body| self.in_focus(galaxy, body) && self.matches(body))
+            .map(|body| body.id())
+            .collect();
+        self.sort.sort(galaxy, &mut ids);
+
+        if self.columns != GroupBy::Status {
+            return self
+                .columns
+                .group(galaxy, &ids)
+                .into_iter()
+                .map(|group| Column {
+                    label: group.label,
+                    status: None,
+                    ids: group.ids,
+                })
+                .collect();
+        }
+        Self::KANBAN_COLUMNS
+            .iter()
+            .map(|status| Column {
+                label: status.to_string(),
+                status: Some(*status),
+                ids: ids
+                    .iter()
+                    .copied()
+                    .filter(|id| galaxy.get(*id).is_some_and(|b| b.status() == *status))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// The order in which celestial bodies are selected when moving up / down.
+    /// Celestial bodies in several kanban columns are only selected in the
+    /// first one.
+    pub fn order(&self, galaxy: &Galaxy) -> Vec<u64> {
+        match self.view {
+            View::Tree => self.tree(galaxy).iter().map(|row| row.id).collect(),
+            View::Kanban => {
+                let mut seen = HashSet::new();
+                self.kanban(galaxy)
+                    .into_iter()
+                    .flat_map(|column| column.ids)
+                    .filter(|id| seen.insert(*id))
+                    .collect()
+            }
+        }
+    }
+
+    /// Whether `body` matches both the filter and the assignee toggle
+    fn matches(&self, body: &CelestialBodyRef) -> bool {
+        self.filter.matches(body)
+            && self
+                .mine
+                .as_ref()
+                .is_none_or(|user| body.is_assigned_to(user))
+    }
+
+    /// Whether `id` is left out because it is archived or snoozed
+    fn is_hidden(&self, galaxy: &Galaxy, id: u64) -> bool {
+        let today = Local::now().date_naive();
+        !self.show_archived && (galaxy.is_archived(id) || galaxy.is_snoozed(id, today))
+    }
+
+    /// Whether or not `body` is a descendant of the focused `Star`
+    fn in_focus(&self, galaxy: &Galaxy, body: &CelestialBodyRef) -> bool {
+        let Some(focus) = self.focus else {
+            return true;
+        };
+        let mut ancestor = body.parent();
+        while let Some(id) = ancestor {
+            if id == focus {
+                return true;
+            }
+            ancestor = galaxy.get(id).and_then(|b| b.parent());
+        }
+        false
+    }
+
+    /// The currently selected celestial body, if anything is shown
+    pub fn selected<'a>(&self, galaxy: &'a Galaxy) -> Option<CelestialBodyRef<'a>> {
+        self.selected.and_then(|id| galaxy.get(id))
+    }
+
+    /// Does what a built in key is bound to, if it applies to this pane.
+    /// Movements are repeated `count` times (e.g. `5 j` moves down five rows),
+    /// and `First` / `Last` select the row `count` instead if one is given.
+    pub fn perform(&mut self, galaxy: &Galaxy, action: Action, count: Option<usize>) {
+        let times = count.unwrap_or(1).max(1);
+        let rows = times as isize;
+        match action {
+            Action::Down => self.move_selection(galaxy, rows),
+            Action::Up => self.move_selection(galaxy, -rows),
+            Action::First | Action::Last if count.is_some() => self.select_row(galaxy, times),
+            Action::First => self.selected = self.order(galaxy).first().copied(),
+            Action::Last => self.selected = self.order(galaxy).last().copied(),
+            Action::PageDown => self.move_selection(galaxy, self.page.max(1) as isize * rows),
+            Action::PageUp => self.move_selection(galaxy, -(self.page.max(1) as isize * rows)),
+            Action::SearchNext | Action::SearchPrevious => {
+                let forward = action == Action::SearchNext;
+                for _ in 0..times {
+                    self.search(galaxy, forward);
+                }
+            }
+            Action::Left => (0..times).for_each(|_| self.move_column(galaxy, -1)),
+            Action::Right => (0..times).for_each(|_| self.move_column(galaxy, 1)),
+            Action::CycleView => {
+                self.view = self.view.next();
+                self.offsets.clear();
+                self.reselect(galaxy);
+            }
+            Action::ToggleArchived => {
+                self.show_archived = !self.show_archived;
+                self.reselect(galaxy);
+            }
+            _ => {}
+        }
+    }
+
+    /// Helper function that selects the first celestial body shown if the
+    /// selected one is no longer shown
+    pub(super) fn reselect(&mut self, galaxy: &Galaxy) {
+        let order = self.order(galaxy);
+        if !self.selected.is_some_and(|id| order.contains(&id)) {
+            self.selected = order.first().copied();
+        }
+    }
+
+    /// Helper function that selects the `row`th celestial body shown, counting
+    /// from 1 and stopping at the last one
+    fn select_row(&mut self, galaxy: &Galaxy, row: usize) {
+        let order = self.order(galaxy);
+        let index = row.saturating_sub(1).min(order.len().saturating_sub(1));
+        self.selected = order.get(index).copied();
+    }
+
+    /// Helper function that moves the selection `offset` celestial bodies
+    /// through `order`, stopping at either end
+    fn move_selection(&mut self, galaxy: &Galaxy, offset: isize) {
+        let order = self.order(galaxy);
+        let Some(last) = order.len().checked_sub(1) else {
+            self.selected = None;
+            return;
+        };
+        let index = match self
+            .selected
+            .and_then(|id| order.iter().position(|o| *o == id))
+        {
+            Some(index) => index.saturating_add_signed(offset).min(last),
+            None => 0,
+        };
+        self.selected = Some(order[index]);
+    }
+
+    /// Selects the next celestial body shown (or the previous one if not
+    /// `forward`) whose title contains `search`, ignoring case and wrapping
+    /// around. If `search` is the ID or key of a celestial body that is shown,
+    /// it is selected instead.
+    ///
+    /// # Returns
+    /// Whether a celestial body was found
+    pub fn search(&mut self, galaxy: &Galaxy, forward: bool) -> bool {
+        let Some(search) = &self.search else {
+            return false;
+        };
+        let order = self.order(galaxy);
+        if let Ok(id) = galaxy.resolve(search)
+            && order.contains(&id)
+        {
+            self.selected = Some(id);
+            return true;
+        }
+
+        let search = search.to_lowercase();
+        let len = order.len();
+        let start = self
+            .selected
+            .and_then(|id| order.iter().position(|o| *o == id));
+        let found = (1..=len)
+            .map(|step| match (start, forward) {
+                (Some(start), true) => (start + step) % len,
+                (Some(start), false) => (start + len - step) % len,
+                (None, true) => step - 1,
+                (None, false) => len - step,
+            })
+            .map(|index| order[index])
+            .find(|id| {
+                galaxy
+                    .get(*id)
+                    .is_some_and(|body| body.title().to_lowercase().contains(&search))
+            });
+        if found.is_some() {
+            self.selected = found;
+        }
+        found.is_some()
+    }
+
+    /// Helper function that moves the selection to the first celestial body of
+    /// the closest non-empty kanban column `offset` columns away
+    fn move_column(&mut self, galaxy: &Galaxy, offset: isize) {
+        if self.view != View::Kanban {
+            return;
+        }
+        let columns = self.kanban(galaxy);
+        let current = columns
+            .iter()
+            .position(|column| self.selected.is_some_and(|id| column.ids.contains(&id)))
+            .unwrap_or(0);
+
+        let mut index = current;
+        while let Some(next) = index
+            .checked_add_signed(offset)
+            .filter(|i| *i < columns.len())
+        {
+            index = next;
+            if let Some(first) = columns[index].ids.first() {
+                self.selected = Some(*first);
+                return;
+            }
+        }
+    }
+
+    /// How far the list `index` of the view is scrolled
+    pub fn offset(&self, index: usize) -> usize {
+        self.offsets.get(index).copied().unwrap_or(0)
+    }
+
+    /// Remembers how far the list `index` of the view is scrolled
+    pub fn set_offset(&mut self, index: usize, offset: usize) {
+        if self.offsets.len() <= index {
+            self.offsets.resize(index + 1, 0);
+        }
+        self.offsets[index] = offset;
+    }
+
+    /// Scrolls the list `index` of the view, which has `len` rows of which
+    /// `height` fit on the screen, just far enough that the row `selected` is
+    /// shown. Only the returned rows have to be drawn.
+    pub fn scroll(
+        &mut self,
+        index: usize,
+        selected: Option<usize>,
+        len: usize,
+        height: usize,
+    ) -> Range<usize> {
+        self.page = height;
+        if height == 0 {
+            return 0..0;
+        }
+        let mut offset = self.offset(index).min(len.saturating_sub(height));
+        match selected {
+            Some(selected) if selected < offset => offset = selected,
+            Some(selected) if selected >= offset + height => offset = selected + 1 - height,
+            _ => {}
+        }
+        self.set_offset(index, offset);
+        offset..(offset + height).min(len)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::core::CelestialBody;
+
+    use super::{super::keys, *};
+
+    /// Builds a galaxy with a `Star` (0) containing a `Planet` (1) and a
+    /// `Comet` (2), and a `Planet` (3) at the root
+    fn galaxy() -> Galaxy {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().title("Backend".into()).id();
+        let planet = galaxy.planet().title("API".into()).id();
+        let comet = galaxy.comet().title("Crash".into()).id();
+        galaxy.planet().title("Docs".into());
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        galaxy.set_parent(comet, Some(star)).unwrap();
+        galaxy
+            .get_mut(planet)
+            .unwrap()
+            .status(Status::Start, String::new());
+        galaxy
+    }
+
+    fn press(pane: &mut Pane, galaxy: &Galaxy, code: KeyCode) {
+        let key = KeyEvent::new(code, KeyModifiers::NONE);
+        if let Some(action) = keys::action(keys::NORMAL, pane.view, key) {
+            pane.perform(galaxy, action, None);
+        }
+    }
+
+    #[test]
+    fn tree_keeps_ancestors_of_matches() {
+        let galaxy = galaxy();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        let ids: Vec<_> = pane
+            .tree(&galaxy)
+            .iter()
+            .map(|row| (row.id, row.depth))
+            .collect();
+        assert_eq!(ids, vec![(0, 0), (1, 1), (2, 1), (3, 0)]);
+
+        let pane = Pane::new(&galaxy, View::Tree, "crash".parse().unwrap(), None, false);
+        assert_eq!(
+            pane.tree(&galaxy),
+            vec![
+                TreeRow {
+                    id: 0,
+                    depth: 0,
+                    matches: false
+                },
+                TreeRow {
+                    id: 2,
+                    depth: 1,
+                    matches: true
+                },
+            ]
+        );
+        assert_eq!(pane.selected(&galaxy).map(|b| b.id()), Some(0));
+    }
+
+    #[test]
+    fn focus_limits_to_descendants() {
+        let galaxy = galaxy();
+        let pane = Pane::new(&galaxy, View::Tree, Filter::default(), Some(0), false);
+        assert_eq!(pane.order(&galaxy), vec![1, 2]);
+
+        let pane = Pane::new(&galaxy, View::Kanban, Filter::default(), Some(0), false);
+        assert_eq!(pane.order(&galaxy), vec![2, 1]);
+    }
+
+    #[test]
+    fn kanban_groups_by_status() {
+        let galaxy = galaxy();
+        let pane = Pane::new(&galaxy, View::Kanban, Filter::default(), None, false);
+        let columns = pane.kanban(&galaxy);
+        assert_eq!(
+            (columns[0].status, &columns[0].ids),
+            (Some(Status::Todo), &vec![2, 3])
+        );
+        assert_eq!(
+            (columns[2].status, &columns[2].ids),
+            (Some(Status::Start), &vec![1])
+        );
+    }
+
+    #[test]
+    fn kanban_columns_can_be_tags_or_fields() {
+        let mut galaxy = galaxy();
+        galaxy.get_mut(1).unwrap().tag("api".into());
+        galaxy.get_mut(1).unwrap().tag("web".into());
+        galaxy.get_mut(3).unwrap().tag("web".into());
+
+        let mut pane = Pane::new(&galaxy, View::Kanban, Filter::default(), None, false);
+        pane.columns = GroupBy::Tag;
+        let columns: Vec<_> = pane
+            .kanban(&galaxy)
+            .into_iter()
+            .map(|column| (column.label, column.ids))
+            .collect();
+        assert_eq!(
+            columns,
+            [
+                ("api".into(), vec![1]),
+                ("web".into(), vec![1, 3]),
+                ("No tag".into(), vec![2])
+            ]
+        );
+        assert_eq!(pane.order(&galaxy), vec![1, 3, 2]);
+
+        galaxy
+            .get_mut(3)
+            .unwrap()
+            .field("team".into(), "docs".into());
+        pane.columns = "field:team".parse().unwrap();
+        let labels: Vec<_> = pane.kanban(&galaxy).into_iter().map(|c| c.label).collect();
+        assert_eq!(labels, ["docs", "No team"]);
+    }
+
+    #[test]
+    fn archived_are_hidden_until_toggled() {
+        let mut galaxy = galaxy();
+        galaxy.get_mut(0).unwrap().archive(true);
+
+        let mut pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        assert_eq!(pane.order(&galaxy), vec![3]);
+        press(&mut pane, &galaxy, KeyCode::Char('a'));
+        assert_eq!(pane.order(&galaxy), vec![0, 1, 2, 3]);
+        press(&mut pane, &galaxy, KeyCode::Char('a'));
+        assert_eq!(pane.order(&galaxy), vec![3]);
+        assert_eq!(pane.selected, Some(3));
+    }
+
+    #[test]
+    fn mine_only_shows_what_is_assigned() {
+        let mut galaxy = galaxy();
+        galaxy.get_mut(2).unwrap().assign("Alice".into());
+        galaxy.get_mut(3).unwrap().assign("bob".into());
+
+        let mut pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        pane.mine = Some("alice".into());
+        let matches: Vec<_> = pane
+            .tree(&galaxy)
+            .iter()
+            .map(|row| (row.id, row.matches))
+            .collect();
+        assert_eq!(matches, [(0, false), (2, true)]);
+        pane.view = View::Kanban;
+        assert_eq!(pane.order(&galaxy), vec![2]);
+
+        // The toggle applies on top of the filter
+        pane.filter = "status:start".parse().unwrap();
+        assert!(pane.order(&galaxy).is_empty());
+    }
+
+    #[test]
+    fn keys_move_selection() {
+        let galaxy = galaxy();
+        let mut pane = Pane::new(&galaxy, View::Kanban, Filter::default(), None, false);
+        assert_eq!(pane.selected, Some(2));
+        press(&mut pane, &galaxy, KeyCode::Char('j'));
+        assert_eq!(pane.selected, Some(3));
+        press(&mut pane, &galaxy, KeyCode::Char('l'));
+        assert_eq!(pane.selected, Some(1));
+        press(&mut pane, &galaxy, KeyCode::Char('j'));
+        assert_eq!(pane.selected, Some(1));
+        press(&mut pane, &galaxy, KeyCode::Char('h'));
+        assert_eq!(pane.selected, Some(2));
+
+        press(&mut pane, &galaxy, KeyCode::Char('v'));
+        assert_eq!(pane.view, View::Tree);
+        assert_eq!(pane.selected, Some(2));
+        press(&mut pane, &galaxy, KeyCode::Char('G'));
+        assert_eq!(pane.selected, Some(3));
+    }
+
+    #[test]
+    fn only_visible_rows_are_drawn() {
+        let mut galaxy = Galaxy::default();
+        for n in 0..100 {
+            galaxy.planet().title(format!("Planet {n}"));
+        }
+        let mut pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        assert_eq!(pane.scroll(0, Some(0), 100, 10), 0..10);
+        assert_eq!(pane.scroll(0, Some(25), 100, 10), 16..26);
+        assert_eq!(pane.scroll(0, Some(20), 100, 10), 16..26);
+        assert_eq!(pane.scroll(0, Some(3), 100, 10), 3..13);
+        assert_eq!(pane.scroll(0, None, 5, 10), 0..5);
+        assert_eq!(pane.scroll(0, Some(0), 5, 0), 0..0);
+
+        pane.page = 10;
+        press(&mut pane, &galaxy, KeyCode::PageDown);
+        assert_eq!(pane.selected, Some(10));
+        press(&mut pane, &galaxy, KeyCode::PageUp);
+        press(&mut pane, &galaxy, KeyCode::PageUp);
+        assert_eq!(pane.selected, Some(0));
+    }
+
+    #[test]
+    fn search_jumps_to_matching_titles() {
+        let galaxy = galaxy();
+        let mut pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        assert!(!pane.search(&galaxy, true));
+
+        pane.search = Some("a".into());
+        assert!(pane.search(&galaxy, true));
+        assert_eq!(pane.selected, Some(1));
+        press(&mut pane, &galaxy, KeyCode::Char('n'));
+        assert_eq!(pane.selected, Some(2));
+        press(&mut pane, &galaxy, KeyCode::Char('n'));
+        assert_eq!(pane.selected, Some(0));
+        press(&mut pane, &galaxy, KeyCode::Char('N'));
+        assert_eq!(pane.selected, Some(2));
+
+        pane.search = Some("3".into());
+        assert!(pane.search(&galaxy, true));
+        assert_eq!(pane.selected, Some(3));
+        pane.search = Some("nothing".into());
+        assert!(!pane.search(&galaxy, true));
+        assert_eq!(pane.selected, Some(3));
+    }
+
+    #[test]
+    fn cloned_state_matches_original() {
+        let galaxy = galaxy();
+        let mut pane = Pane::new(
+            &galaxy,
+            View::Kanban,
+            "tag:x".parse().unwrap(),
+            Some(0),
+            true,
+        );
+        pane.selected = Some(1);
+        pane.set_offset(2, 5);
+
+        let clone = pane.clone_state();
+        assert_eq!(clone.view, View::Kanban);
+        assert_eq!(clone.filter, pane.filter);
+        assert_eq!(clone.focus, Some(0));
+        assert!(clone.show_archived);
+        assert_eq!(clone.selected, Some(1));
+        assert_eq!(clone.offset(2), 5);
+        assert_eq!(clone.offset(7), 0);
+    }
+}
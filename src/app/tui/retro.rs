@@ -0,0 +1,170 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the form for recording the retrospective of a sprint in the TUI.
+ *
+ * The form has a section for each part of a retrospective. Text typed into
+ * it is added to the selected section on enter, and `Tab` moves between the
+ * sections. Action items are created as Comets right away.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::Input;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A part of a retrospective
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    #[default]
+    WentWell,
+    NeedsImprovement,
+    ActionItems,
+}
+
+impl Section {
+    /// All sections, in the order they are shown
+    pub const ALL: [Section; 3] = [
+        Section::WentWell,
+        Section::NeedsImprovement,
+        Section::ActionItems,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Section::WentWell => "What went well",
+            Section::NeedsImprovement => "What needs improvement",
+            Section::ActionItems => "Action items",
+        }
+    }
+
+    /// The section after this one, wrapping around to the first
+    fn next(self) -> Self {
+        let index = Section::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        Section::ALL[(index + 1) % Section::ALL.len()]
+    }
+
+    /// The section before this one, wrapping around to the last
+    fn previous(self) -> Self {
+        let index = Section::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        Section::ALL[(index + Section::ALL.len() - 1) % Section::ALL.len()]
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The form for recording the retrospective of a sprint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetroForm {
+    /// The name of the sprint the retrospective is for
+    pub sprint: String,
+    /// The section that typed text is added to
+    pub section: Section,
+    pub input: Input,
+}
+
+impl RetroForm {
+    pub fn new(sprint: String) -> Self {
+        Self {
+            sprint,
+            section: Section::default(),
+            input: Input::default(),
+        }
+    }
+
+    /// Handles a key press, other than `Esc` which closes the form
+    ///
+    /// # Returns
+    /// The text to add to `section` once enter is pressed, if anything was
+    /// typed
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<String> {
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => self.section = self.section.next(),
+            KeyCode::BackTab | KeyCode::Up => self.section = self.section.previous(),
+            KeyCode::Enter => {
+                let text = self.input.take();
+                let text = text.trim();
+                return (!text.is_empty()).then(|| text.to_string());
+            }
+            _ => {
+                self.input.handle_key(key);
+            }
+        }
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+
+    fn press(form: &mut RetroForm, code: KeyCode) -> Option<String> {
+        form.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn sections_wrap_around() {
+        let mut form = RetroForm::new("Sprint 1".into());
+        press(&mut form, KeyCode::BackTab);
+        assert_eq!(form.section, Section::ActionItems);
+        press(&mut form, KeyCode::Tab);
+        assert_eq!(form.section, Section::WentWell);
+    }
+
+    #[test]
+    fn entries_are_added_on_enter() {
+        let mut form = RetroForm::new("Sprint 1".into());
+        assert_eq!(press(&mut form, KeyCode::Enter), None);
+        for c in " Demo ".chars() {
+            press(&mut form, KeyCode::Char(c));
+        }
+        assert_eq!(press(&mut form, KeyCode::Enter), Some("Demo".into()));
+        assert!(form.input.is_empty());
+    }
+}
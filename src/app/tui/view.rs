@@ -0,0 +1,1223 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the views the TUI can show the `Galaxy` in, and how everything is
+ * drawn.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashSet, fmt, ops::Range};
+
+use chrono::{Local, Utc};
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{
+        cli::{self, ValueEnum},
+        config::SavedView,
+    },
+    core::{CelestialBodyKind, CelestialBodyRef, Filter, Galaxy, Priority, Status},
+    util::{self, shell, theme},
+};
+
+use super::{
+    keys, markdown, App, CommandError, Confirm, Input, Level, Mode, Pane, Pending, RetroForm,
+    Section,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TYPES                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Creates a pane showing a view, given the arguments typed after its name
+/// (e.g. the filter in `:open tree tag:backend`)
+pub type ViewConstructor = fn(&Galaxy, &str) -> Result<Pane, CommandError>;
+
+/// Every view that can be opened by name, and the names it can be opened by.
+/// New views only need to be added here to be usable from `view` and `open`.
+pub const VIEWS: &[RegisteredView] = &[
+    RegisteredView {
+        names: &["tree"],
+        view: View::Tree,
+        constructor: |galaxy, args| filtered(galaxy, View::Tree, args),
+    },
+    RegisteredView {
+        names: &["kanban", "board"],
+        view: View::Kanban,
+        constructor: |galaxy, args| filtered(galaxy, View::Kanban, args),
+    },
+    RegisteredView {
+        names: &["sprint"],
+        view: View::Kanban,
+        constructor: sprint_board,
+    },
+    RegisteredView {
+        names: &["today", "focus"],
+        view: View::Tree,
+        constructor: focus_list,
+    },
+];
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The different ways the TUI can show the `Galaxy`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum View {
+    /// Celestial bodies nested under their parents
+    #[default]
+    Tree,
+    /// Planets and Comets in columns by status
+    Kanban,
+}
+
+impl View {
+    /// The view after this one when cycling through views
+    pub fn next(self) -> Self {
+        match self {
+            Self::Tree => Self::Kanban,
+            Self::Kanban => Self::Tree,
+        }
+    }
+}
+
+/// A view in the registry of views, see `VIEWS`
+#[derive(Debug)]
+pub struct RegisteredView {
+    /// The names the view can be opened by, case insensitive
+    pub names: &'static [&'static str],
+    pub view: View,
+    pub constructor: ViewConstructor,
+}
+
+impl fmt::Display for View {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tree => write!(f, "Tree"),
+            Self::Kanban => write!(f, "Kanban"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Finds the view named `name` (case insensitive) in `VIEWS`
+pub fn find(name: &str) -> Option<&'static RegisteredView> {
+    VIEWS
+        .iter()
+        .find(|v| v.names.iter().any(|n| n.eq_ignore_ascii_case(name)))
+}
+
+/// Creates a pane showing the view named `name`
+///
+/// # Errors
+/// Errors will occur in the following situations:
+/// - There is no view named `name`
+/// - The view does not accept `args`
+pub fn open(galaxy: &Galaxy, name: &str, args: &str) -> Result<Pane, CommandError> {
+    match find(name) {
+        Some(view) => (view.constructor)(galaxy, args),
+        None => Err(CommandError::UnknownView(name.to_string())),
+    }
+}
+
+/// Creates a pane showing the view `saved`
+///
+/// # Errors
+/// Errors will occur in the following situations:
+/// - The filter of the view is not valid
+pub fn open_saved(galaxy: &Galaxy, saved: &SavedView) -> Result<Pane, CommandError> {
+    let mut pane = Pane {
+        view: saved.layout,
+        filter: saved.filter.parse()?,
+        sort: saved.sort,
+        columns: saved.group_by.clone().unwrap_or_default(),
+        ..Pane::default()
+    };
+    pane.reselect(galaxy);
+    Ok(pane)
+}
+
+/// Helper function that constructs a pane for views which take a filter as
+/// their arguments
+fn filtered(galaxy: &Galaxy, view: View, args: &str) -> Result<Pane, CommandError> {
+    let filter: Filter = args.parse()?;
+    Ok(Pane::new(galaxy, view, filter, None, false))
+}
+
+/// Helper function that constructs a kanban board of the sprint named `args`,
+/// or of the active sprint if no name is given
+fn sprint_board(galaxy: &Galaxy, args: &str) -> Result<Pane, CommandError> {
+    let sprint = match args.trim() {
+        "" => galaxy.active_sprint(Local::now().date_naive()),
+        name => galaxy.sprint(name),
+    };
+    // Without an active sprint, the name of a sprint has to be given
+    let sprint = sprint.ok_or_else(|| match args.trim() {
+        "" => CommandError::MissingArgument("sprint".into()),
+        name => CommandError::InvalidArgument("sprint".into(), name.into()),
+    })?;
+    let filter = Filter::ids(sprint.items().to_vec());
+    Ok(Pane::new(galaxy, View::Kanban, filter, None, false))
+}
+
+/// Helper function that constructs a tree of the working set for today (see
+/// `Galaxy::focus`), narrowed down by the filter in `args`. Celestial bodies
+/// that join the working set later are shown once the view is opened again.
+fn focus_list(galaxy: &Galaxy, args: &str) -> Result<Pane, CommandError> {
+    let filter = Filter::ids(galaxy.focus(Local::now().date_naive())).and(args.parse()?);
+    Ok(Pane::new(galaxy, View::Tree, filter, None, false))
+}
+
+/// Draws the entire TUI: a header, every pane as laid out, the details of the
+/// selected celestial body in the active pane, and the available keys
+pub fn draw(app: &mut App, frame: &mut Frame) {
+    let [header, main, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [panes, details] =
+        Layout::horizontal([Constraint::Percentage(65), Constraint::Percentage(35)]).areas(main);
+
+    draw_header(app, frame, header);
+    let marked: HashSet<_> = app.marked().into_iter().collect();
+    for (index, area) in app.layout.areas(panes) {
+        let active = index == app.active;
+        let marked = if active { &marked } else { &HashSet::new() };
+        let pane = &mut app.panes[index];
+        match pane.view {
+            View::Tree => draw_tree(pane, &app.galaxy, active, marked, frame, area),
+            View::Kanban => draw_kanban(pane, &app.galaxy, active, marked, frame, area),
+        }
+    }
+    draw_details(app, frame, details);
+    match &app.mode {
+        Mode::Help(mode) => draw_help(app, mode, frame, main),
+        Mode::Legend => draw_legend(app, frame, main),
+        Mode::Confirm(confirm) => draw_confirm(confirm, frame, main),
+        Mode::QuickAdd(input) => draw_quick_add(app, input, frame, main),
+        Mode::Projects(selected) => draw_projects(app, *selected, frame, main),
+        Mode::Actions(selected) => draw_actions(app, *selected, frame, main),
+        Mode::Output => draw_output(app, frame, main),
+        Mode::Retro(form) => draw_retro(app, form, frame, main),
+        _ => {}
+    }
+
+    let keys = match app.active().view {
+        View::Tree => {
+            " q quit  j/k move  g/G first/last  v switch view  a toggle archived  y/p yank/paste"
+        }
+        View::Kanban => {
+            " q quit  j/k move  h/l column  g/G first/last  v switch view  a toggle archived"
+        }
+    };
+    let line = match (&app.mode, &app.message) {
+        (Mode::Command(input) | Mode::Search(input), _) => {
+            let prompt = if matches!(app.mode, Mode::Search(_)) { "/" } else { ":" };
+            let mut line = input_lines(input).swap_remove(0);
+            line.spans.insert(0, Span::from(prompt));
+            let (_, column) = input.cursor();
+            frame.set_cursor_position((footer.x + 1 + column as u16, footer.y));
+            line
+        }
+        (Mode::QuickAdd(_), _) => Line::from(
+            " Enter add  Esc cancel  [comet/planet/star] <title> [<description>] [-p <priority>] [-e <estimate>] [-a <assignees>]",
+        )
+        .fg(theme::current().accent),
+        (Mode::Help(_), _) => Line::from(" Press any key to close the help").fg(theme::current().accent),
+        (Mode::Legend, _) => Line::from(" Press any key to close the legend").fg(theme::current().accent),
+        (Mode::Projects(_), _) => Line::from(" j/k move  Enter switch  Esc cancel").fg(theme::current().accent),
+        (Mode::Actions(_), _) => Line::from(" j/k move  Enter run  Esc cancel").fg(theme::current().accent),
+        (Mode::Retro(_), _) => {
+            Line::from(" Enter add  Tab/Shift+Tab section  Esc close").fg(theme::current().accent)
+        }
+        (Mode::Output, _) => {
+            Line::from(" j/k scroll  d/u page  g/G top/bottom  q/Esc close").fg(theme::current().accent)
+        }
+        (Mode::Confirm(confirm), _) => match (&confirm.expected, &confirm.pending) {
+            (Some(_), _) => Line::from(" Enter confirm  Esc cancel").fg(theme::current().accent),
+            (None, Pending::Quit) => {
+                Line::from(" y save and quit  n quit without saving  Esc cancel").fg(theme::current().accent)
+            }
+            (None, _) => Line::from(" y confirm  n/Esc cancel").fg(theme::current().accent),
+        },
+        (Mode::Visual, _) => Line::from(vec![
+            Span::from(format!(" -- VISUAL -- {} marked ", app.marked().len())).bold(),
+            Span::from(
+                " Space mark  d delete  :status/tag/untag/move  Esc stop",
+            ),
+        ])
+        .fg(theme::current().accent),
+        (Mode::Pane, _) => Line::from(
+            " </> narrower/wider  -/+ shorter/taller  h/j/k/l move  s/v split below/right  x close",
+        )
+        .fg(theme::current().accent),
+        (_, _) if app.pending.is_some() || app.count.is_some() => pending_line(app),
+        (_, Some(message)) => {
+            let line = Line::from(format!(" {}", message.text));
+            match message.level {
+                Level::Info => line.fg(theme::current().success),
+                Level::Warning => line.fg(theme::current().warning),
+                Level::Error => line.fg(theme::current().error),
+            }
+        }
+        _ => Line::from(format!(
+            "{keys}  A add  / search  s/S split blank/clone  x close pane  C-hjkl move pane  C-w pane keys  : command  ? help"
+        ))
+        .fg(theme::current().muted),
+    };
+    frame.render_widget(line, footer);
+}
+
+/// Helper function that shows the count and pending sequence of keys, how
+/// long the TUI waits for the next key, and what each continuation is bound to
+fn pending_line(app: &App) -> Line<'static> {
+    let count = app.count.map(|c| c.to_string()).unwrap_or_default();
+    let Some((keys, _)) = &app.pending else {
+        return Line::from(format!(" {count}"))
+            .fg(theme::current().highlight)
+            .bold();
+    };
+    let left = app.pending_for().unwrap_or_default().as_secs_f32();
+    let mut spans = vec![
+        Span::from(format!(" {count}{keys}"))
+            .fg(theme::current().highlight)
+            .bold(),
+        Span::from(format!(" ({left:.1}s)  ")).fg(theme::current().muted),
+    ];
+    for (continuation, command) in app.config.continuations(keys) {
+        spans.push(Span::from(continuation).fg(theme::current().highlight));
+        spans.push(Span::from(format!(" {command}  ")).fg(theme::current().accent));
+    }
+    Line::from(spans)
+}
+
+/// Helper function that draws the title of the `Galaxy`, the scope of the
+/// active pane, what work is being tracked on and for how long, and the time
+fn draw_header(app: &App, frame: &mut Frame, area: Rect) {
+    let pane = app.active();
+    let mut spans = Vec::new();
+    if let Some(project) = app.galaxy.get_project() {
+        spans.push(Span::from(format!(" {project}:")).fg(theme::current().highlight));
+    }
+    spans.push(Span::from(format!(" {} ", app.galaxy.get_title())).bold());
+    spans.push(Span::from(format!("[{}]", pane.view)).fg(theme::current().accent));
+    if !app.unsaved.is_empty() {
+        spans.push(Span::from(" [+]").fg(theme::current().warning).bold());
+    }
+    if let Some(focus) = pane.focus.and_then(|id| app.galaxy.get(id)) {
+        spans.push(
+            Span::from(format!(
+                " focus: {} ({})",
+                focus.title(),
+                app.galaxy.display_id(focus.id())
+            ))
+            .fg(CelestialBodyKind::Star.color()),
+        );
+    }
+    if !pane.filter.is_empty() {
+        spans.push(Span::from(" (filtered)").fg(theme::current().highlight));
+    }
+    if let Some(user) = &pane.mine {
+        spans.push(Span::from(format!(" (assigned to {user})")).fg(theme::current().highlight));
+    }
+    if pane.show_archived {
+        spans.push(Span::from(" (showing archived)").fg(theme::current().muted));
+    }
+    frame.render_widget(Line::from(spans), area);
+
+    // The time tracked and the clock are kept up to date by the ticks of the
+    // event loop
+    let mut right = Vec::new();
+    let tracked = app.galaxy.tracking().and_then(|id| app.galaxy.get(id));
+    if let Some(body) = tracked
+        && let Some(session) = body.work_log().active()
+    {
+        let minutes = (Utc::now() - session.start).num_minutes().max(0) as u64;
+        right.push(Span::from("tracking ").fg(theme::current().muted));
+        right.push(Span::from(body.title().to_string()).fg(theme::current().highlight));
+        right.push(
+            Span::from(format!(" {} ", app.galaxy.display_id(body.id())))
+                .fg(theme::current().muted),
+        );
+        right.push(
+            Span::from(format!("{}  ", util::time::hours_minutes(minutes)))
+                .fg(theme::current().accent)
+                .bold(),
+        );
+    }
+    right.push(Span::from(format!("{} ", Local::now().format("%H:%M"))).fg(theme::current().muted));
+    frame.render_widget(Line::from(right).right_aligned(), area);
+}
+
+/// Helper function that creates the border around a pane, highlighting the
+/// active pane
+fn pane_block(title: Line<'static>, active: bool) -> Block<'static> {
+    let block = Block::default().borders(Borders::ALL).title(title);
+    match active {
+        true => block.border_style(Style::default().fg(theme::current().border.into())),
+        false => block.border_style(Style::default().fg(theme::current().muted.into())),
+    }
+}
+
+/// Helper function that draws `pane` with the tree view. Only the rows that fit
+/// in `area` are turned into widgets, so huge trees are drawn just as fast.
+fn draw_tree(
+    pane: &mut Pane,
+    galaxy: &Galaxy,
+    active: bool,
+    marked: &HashSet<u64>,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    let rows = pane.tree(galaxy);
+    let block = pane_block(Line::from(" Tree "), active);
+    let selected = rows.iter().position(|row| Some(row.id) == pane.selected);
+    let shown = pane.scroll(0, selected, rows.len(), block.inner(area).height as usize);
+    let items: Vec<_> = rows[shown.clone()]
+        .iter()
+        .filter_map(|row| {
+            let body = galaxy.get(row.id)?;
+            let mut line = body_line(galaxy, &body, true);
+            line.spans.insert(0, Span::from("  ".repeat(row.depth)));
+            if !row.matches || galaxy.is_archived(row.id) {
+                line = line.fg(theme::current().muted);
+            }
+            Some(ListItem::new(mark(line, marked.contains(&row.id))))
+        })
+        .collect();
+
+    let mut state = ListState::default().with_selected(window(selected, &shown));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Helper function that draws `pane` with the kanban view, only turning the
+/// rows of each column that fit on the screen into widgets
+fn draw_kanban(
+    pane: &mut Pane,
+    galaxy: &Galaxy,
+    active: bool,
+    marked: &HashSet<u64>,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    let columns = pane.kanban(galaxy);
+    if columns.is_empty() {
+        frame.render_widget(pane_block(Line::from(" Nothing to show "), active), area);
+        return;
+    }
+    let areas = Layout::horizontal(vec![
+        Constraint::Ratio(1, columns.len() as u32);
+        columns.len()
+    ])
+    .split(area);
+
+    for (index, (column, area)) in columns.iter().zip(areas.iter()).enumerate() {
+        let ids = &column.ids;
+        let title = Line::from(format!(" {} ({}) ", column.label, ids.len()))
+            .style(column.status.map(status_style).unwrap_or_default());
+        let block = pane_block(title, active);
+        let selected = ids.iter().position(|id| Some(*id) == pane.selected);
+        let shown = pane.scroll(
+            index,
+            selected,
+            ids.len(),
+            block.inner(*area).height as usize,
+        );
+        let items: Vec<_> = ids[shown.clone()]
+            .iter()
+            .filter_map(|id| galaxy.get(*id))
+            .map(|body| {
+                let line = body_line(galaxy, &body, false);
+                ListItem::new(mark(line, marked.contains(&body.id())))
+            })
+            .collect();
+
+        let mut state = ListState::default().with_selected(window(selected, &shown));
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, *area, &mut state);
+    }
+}
+
+/// Helper function that shows that the row `line` is marked in visual mode
+fn mark(mut line: Line<'static>, marked: bool) -> Line<'static> {
+    if marked {
+        line.spans
+            .insert(0, Span::from("+ ").fg(theme::current().highlight).bold());
+        line = line.bold();
+    }
+    line
+}
+
+/// Helper function that finds the row `selected` among the `shown` rows
+fn window(selected: Option<usize>, shown: &Range<usize>) -> Option<usize> {
+    selected
+        .filter(|selected| shown.contains(selected))
+        .map(|selected| selected - shown.start)
+}
+
+/// Helper function that draws the keys of `mode` in the view of the active pane
+/// over the middle of `area`. Keys bound in the config are listed first, and
+/// the built in keys they replace are left out.
+fn draw_help(app: &App, mode: &Mode, frame: &mut Frame, area: Rect) {
+    let view = app.active().view;
+    let (table, config): (_, Vec<_>) = match mode {
+        Mode::Pane => (keys::PANE, vec![]),
+        Mode::Visual => (keys::VISUAL, vec![]),
+        _ => (keys::NORMAL, app.config.keys.iter().collect()),
+    };
+    let bindings: Vec<_> = table
+        .iter()
+        .copied()
+        .filter(|b| match b.code {
+            KeyCode::Char(c) if !b.control => app.config.binding(&c.to_string()).is_none(),
+            _ => true,
+        })
+        .collect();
+    let builtin = keys::help(&bindings, view);
+
+    let width = config
+        .iter()
+        .map(|(key, _)| key.len())
+        .chain(builtin.iter().map(|(key, _)| key.len()))
+        .max()
+        .unwrap_or(0);
+    let row = |key: &str, description: Span<'static>| {
+        Line::from(vec![
+            Span::from(format!(" {key:<width$}  "))
+                .fg(theme::current().highlight)
+                .bold(),
+            description,
+        ])
+    };
+    let mut lines = Vec::new();
+    for (key, command) in config {
+        lines.push(row(
+            key,
+            Span::from(format!(":{command}")).fg(theme::current().accent),
+        ));
+    }
+    for (key, description) in builtin {
+        lines.push(row(&key, Span::from(description)));
+    }
+
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let width = 60.min(area.width);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let title = match mode {
+        Mode::Pane => " Pane keys (after C-w) ".to_string(),
+        Mode::Visual => " Visual keys ".to_string(),
+        _ => format!(" Keys ({view}) "),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().border.into()))
+        .title(title);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+/// Helper function that draws what the icons and colors mean over the middle
+/// of `area`, from the same sections as `planit legend`
+fn draw_legend(app: &App, frame: &mut Frame, area: Rect) {
+    let mut lines = Vec::new();
+    for (index, (title, rows)) in cli::legend_sections(app.galaxy.workflow())
+        .into_iter()
+        .enumerate()
+    {
+        if index > 0 {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(format!(" {title}")).bold());
+        let width = rows.iter().map(|row| row.symbol.chars().count()).max();
+        for row in rows {
+            lines.push(Line::from(vec![
+                Span::from(format!(
+                    "   {:<1$}  ",
+                    row.symbol,
+                    width.unwrap_or_default()
+                ))
+                .fg(row.color),
+                Span::from(row.description),
+            ]));
+        }
+    }
+
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let width = 70.min(area.width);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().border.into()))
+        .title(" Legend ");
+    frame.render_widget(Clear, popup);
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+/// Helper function that draws the prompt `confirm` over the middle of `area`
+fn draw_confirm(confirm: &Confirm, frame: &mut Frame, area: Rect) {
+    let mut lines = vec![
+        Line::from(format!(" {}", confirm.question)),
+        Line::default(),
+    ];
+    match &confirm.expected {
+        Some(_) => {
+            let mut line = input_lines(&confirm.input).swap_remove(0).bold();
+            line.spans
+                .insert(0, Span::from(" > ").fg(theme::current().accent));
+            lines.push(line);
+        }
+        None => lines.push(Line::from(" [y/n]").fg(theme::current().muted)),
+    }
+
+    let width = 60.min(area.width);
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    // The question is the only line long enough to wrap
+    let wrapped =
+        (confirm.question.chars().count() + 1).div_ceil(width.saturating_sub(2).max(1) as usize);
+    let height = (wrapped as u16 + 4).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().error.into()))
+        .title(" Confirm ");
+    frame.render_widget(Clear, popup);
+    frame.render_widget(paragraph.block(block), popup);
+    if confirm.expected.is_some() {
+        let (_, column) = confirm.input.cursor();
+        let y = (popup.y + wrapped as u16 + 2).min(popup.bottom().saturating_sub(2));
+        frame.set_cursor_position((popup.x + 4 + column as u16, y));
+    }
+}
+
+/// Helper function that draws the quick-add popup over the middle of `area`,
+/// naming the `Star` the celestial body is added under
+fn draw_quick_add(app: &App, input: &Input, frame: &mut Frame, area: Rect) {
+    let title = match app.selected_star().and_then(|id| app.galaxy.get(id)) {
+        Some(star) => format!(" Add under {} ", star.title()),
+        None => " Add ".to_string(),
+    };
+    let mut line = input_lines(input).swap_remove(0);
+    line.spans
+        .insert(0, Span::from(" > ").fg(theme::current().accent));
+
+    let width = 60.min(area.width);
+    let height = 3.min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent.into()))
+        .title(title);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(Paragraph::new(line).block(block), popup);
+    let (_, column) = input.cursor();
+    frame.set_cursor_position((popup.x + 4 + column as u16, popup.y + 1));
+}
+
+/// Helper function that draws the project switcher over the middle of `area`,
+/// marking the current project
+fn draw_projects(app: &App, selected: usize, frame: &mut Frame, area: Rect) {
+    let current = app.galaxy.get_project();
+    let items: Vec<ListItem> = app
+        .config
+        .projects
+        .iter()
+        .map(|(name, dir)| {
+            let marker = if Some(name.as_str()) == current {
+                "*"
+            } else {
+                " "
+            };
+            ListItem::new(Line::from(vec![
+                Span::from(format!(" {marker} {name}  ")).bold(),
+                Span::from(dir.display().to_string()).fg(theme::current().muted),
+            ]))
+        })
+        .collect();
+
+    let width = 60.min(area.width);
+    let height = (items.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent.into()))
+        .title(" Projects ");
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_widget(Clear, popup);
+    frame.render_stateful_widget(list, popup, &mut state);
+}
+
+/// Helper function that draws the actions that can be run on the selected
+/// celestial body over the middle of `area`
+fn draw_actions(app: &App, selected: usize, frame: &mut Frame, area: Rect) {
+    let items: Vec<ListItem> = app
+        .actions()
+        .into_iter()
+        .map(|action| {
+            ListItem::new(Line::from(vec![
+                Span::from(format!(" {}  ", action.name)).bold(),
+                Span::from(action.command).fg(theme::current().muted),
+            ]))
+        })
+        .collect();
+
+    let width = 60.min(area.width);
+    let height = (items.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent.into()))
+        .title(" Actions ");
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_widget(Clear, popup);
+    frame.render_stateful_widget(list, popup, &mut state);
+}
+
+/// Helper function that draws the output of the last shell command over most
+/// of `area`, with stderr in the error color and the exit status at the end
+fn draw_output(app: &App, frame: &mut Frame, area: Rect) {
+    let Some(output) = &app.output else {
+        return;
+    };
+    let lines: Vec<Line> = output
+        .lines
+        .iter()
+        .map(|line| match line {
+            shell::Output::Stdout(text) => Line::from(format!(" {text}")),
+            shell::Output::Stderr(text) => {
+                Line::from(format!(" {text}")).fg(theme::current().error)
+            }
+            shell::Output::Exit(Some(0)) => Line::from(" Exited with status 0")
+                .fg(theme::current().success)
+                .bold(),
+            shell::Output::Exit(Some(code)) => Line::from(format!(" Exited with status {code}"))
+                .fg(theme::current().error)
+                .bold(),
+            shell::Output::Exit(None) => Line::from(" Terminated by a signal")
+                .fg(theme::current().error)
+                .bold(),
+        })
+        .collect();
+
+    let width = (area.width * 4 / 5).max(60.min(area.width));
+    let height = (area.height * 4 / 5).max(10.min(area.height));
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let title = match output.running() {
+        true => format!(" !{} (running) ", output.command),
+        false => format!(" !{} ", output.command),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent.into()))
+        .title(title);
+    let scroll = output.scroll.min(u16::MAX.into()) as u16;
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(block).scroll((scroll, 0)),
+        popup,
+    );
+}
+
+/// Helper function that draws the form for the retrospective of a sprint over
+/// the middle of `area`, with the text being typed under the selected section
+fn draw_retro(app: &App, form: &RetroForm, frame: &mut Frame, area: Rect) {
+    let retro = app.galaxy.get_retro(&form.sprint);
+    let mut lines = Vec::new();
+    let mut cursor = None;
+    for section in Section::ALL {
+        let selected = section == form.section;
+        let heading = Line::from(format!(" {}", section.title())).bold();
+        lines.push(match selected {
+            true => heading.fg(theme::current().accent),
+            false => heading,
+        });
+
+        let entries: Vec<Line> = match (retro, section) {
+            (None, _) => Vec::new(),
+            (Some(retro), Section::WentWell) => retro
+                .went_well()
+                .iter()
+                .map(|note| Line::from(format!("   - {note}")))
+                .collect(),
+            (Some(retro), Section::NeedsImprovement) => retro
+                .needs_improvement()
+                .iter()
+                .map(|note| Line::from(format!("   - {note}")))
+                .collect(),
+            (Some(retro), Section::ActionItems) => retro
+                .action_items()
+                .iter()
+                .filter_map(|id| app.galaxy.get(*id))
+                .map(|item| {
+                    let check = if item.status().is_final() { "x" } else { " " };
+                    Line::from(vec![
+                        Span::from(format!("   [{check}] {} ", item.title())),
+                        Span::from(app.galaxy.display_id(item.id())).fg(theme::current().muted),
+                    ])
+                })
+                .collect(),
+        };
+        lines.extend(entries);
+
+        if selected {
+            let mut line = input_lines(&form.input).swap_remove(0);
+            line.spans
+                .insert(0, Span::from("   > ").fg(theme::current().accent));
+            cursor = Some(lines.len());
+            lines.push(line);
+        }
+        lines.push(Line::default());
+    }
+    lines.pop();
+
+    let width = 70.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::current().accent.into()))
+        .title(format!(" Retrospective: {} ", form.sprint));
+    // Keeps the text being typed in view when there are too many entries
+    let row = cursor.unwrap_or(0) as u16;
+    let scroll = (row + 3).saturating_sub(height);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(block).scroll((scroll, 0)),
+        popup,
+    );
+    let (_, column) = form.input.cursor();
+    frame.set_cursor_position((popup.x + 6 + column as u16, popup.y + 1 + row - scroll));
+}
+
+/// Helper function that splits the text of `input` into lines, with the
+/// selected text highlighted
+fn input_lines(input: &Input) -> Vec<Line<'_>> {
+    let text = input.text();
+    let selection = input.selection().unwrap_or_default();
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in text.split('\n') {
+        let end = start + line.len();
+        let from = selection.start.clamp(start, end);
+        let to = selection.end.clamp(start, end);
+        lines.push(Line::from(vec![
+            Span::from(&text[start..from]),
+            Span::from(&text[from..to]).reversed(),
+            Span::from(&text[to..end]),
+        ]));
+        start = end + 1;
+    }
+    lines
+}
+
+/// Helper function that draws everything about the selected celestial body in
+/// the active pane
+fn draw_details(app: &App, frame: &mut Frame, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(" Details ");
+    let Some(body) = app.active().selected(&app.galaxy) else {
+        let empty = Paragraph::new("Nothing to show".fg(theme::current().muted)).block(block);
+        frame.render_widget(empty, area);
+        return;
+    };
+    let stage = app.galaxy.workflow().stage_of(&body);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::from(format!("{} ", body.kind())).style(kind_style(body.kind())),
+            Span::from(body.title()).bold(),
+            Span::from(match body.key() {
+                Some(key) => format!(" ({} {key})", app.galaxy.display_id(body.id())),
+                None => format!(" ({})", app.galaxy.display_id(body.id())),
+            })
+            .fg(theme::current().muted),
+        ]),
+        Line::default(),
+        Line::from(vec![
+            "Status: ".bold(),
+            Span::from(stage.name.clone()).fg(stage.color()),
+        ]),
+        Line::from(vec![
+            "Priority: ".bold(),
+            Span::from(body.priority().to_string()).style(priority_style(body.priority())),
+        ]),
+    ];
+    if let Some(blocker) = body.blocker() {
+        lines.insert(
+            3,
+            Line::from(vec![
+                "Reason: ".bold(),
+                blocker.describe(&app.galaxy).fg(theme::current().warning),
+            ]),
+        );
+    }
+    if let Some(estimate) = body.estimate() {
+        lines.push(Line::from(vec![
+            "Estimate: ".bold(),
+            estimate.to_string().fg(theme::current().highlight),
+        ]));
+    }
+    if let Some(progress) = app.galaxy.progress(body.id()).filter(|p| p.total > 0) {
+        lines.push(Line::from(vec![
+            "Progress: ".bold(),
+            format!(
+                "{} {}/{}",
+                progress.bar(10),
+                progress.finished,
+                progress.total
+            )
+            .fg(theme::current().highlight),
+        ]));
+    }
+    let now = Utc::now();
+    let today = Local::now().date_naive();
+    if let Some(due) = body.due() {
+        let color = match body.overdue(today) {
+            true => theme::current().error,
+            false => theme::current().muted,
+        };
+        lines.push(Line::from(vec![
+            "Due: ".bold(),
+            format!("{due} ").into(),
+            format!("({})", util::time::relative_due(due, today)).fg(color),
+        ]));
+    }
+    if let Some(updated) = body.updated_at() {
+        lines.push(Line::from(vec![
+            "Updated: ".bold(),
+            format!("{} ", util::time::relative(updated, now)).into(),
+            format!("({})", util::time::absolute(updated)).fg(theme::current().muted),
+        ]));
+    }
+    if !body.assignees().is_empty() {
+        lines.push(Line::from(vec![
+            "Assignees: ".bold(),
+            body.assignees().join(", ").into(),
+        ]));
+    }
+    if !body.links().is_empty() {
+        lines.push(Line::from("Links:".bold()));
+        for (i, link) in body.links().iter().enumerate() {
+            lines.push(Line::from(vec![
+                format!("  {}. ", i + 1).fg(theme::current().muted),
+                link.label.clone().fg(theme::current().highlight),
+            ]));
+        }
+    }
+    if let Some((done, total)) = body.checklist_progress() {
+        lines.push(Line::from(vec![
+            "Checklist: ".bold(),
+            format!("{done}/{total}").fg(theme::current().highlight),
+        ]));
+        for item in body.checklist() {
+            let style = match item.done {
+                true => Style::default().fg(theme::current().muted.into()),
+                false => Style::default(),
+            };
+            lines.push(Line::from(format!("  {item}")).style(style));
+        }
+    }
+    if !body.commits().is_empty() {
+        lines.push(Line::from("Commits:".bold()));
+        for commit in body.commits() {
+            lines.push(Line::from(vec![
+                format!("  {} ", commit.short_hash()).fg(theme::current().highlight),
+                commit.summary.clone().into(),
+            ]));
+        }
+    }
+    if !body.tags().is_empty() {
+        let mut spans = vec!["Tags:".bold()];
+        spans.extend(body.tags().iter().map(|tag| tag_span(tag)));
+        lines.push(Line::from(spans));
+    }
+    if !body.description().is_empty() {
+        lines.push(Line::default());
+        lines.extend(markdown::render(body.description()));
+    }
+    if !body.events().is_empty() {
+        /// The most events shown, as the pane has little room
+        const MAX_EVENTS: usize = 10;
+        let now = Utc::now();
+        lines.push(Line::default());
+        lines.push(Line::from("Activity:".bold()));
+        for event in body.events().iter().rev().take(MAX_EVENTS) {
+            lines.push(Line::from(vec![
+                format!("  {} ", util::time::relative(event.time, now)).fg(theme::current().muted),
+                event.change.to_string().into(),
+            ]));
+        }
+        if body.events().len() > MAX_EVENTS {
+            let more = body.events().len() - MAX_EVENTS;
+            lines.push(Line::from(format!("  ... {more} more")).fg(theme::current().muted));
+        }
+    }
+    // The ancestors lead up to the title as a breadcrumb
+    let breadcrumb = app.galaxy.breadcrumb(body.id());
+    if !breadcrumb.is_empty() {
+        lines.insert(0, Line::from(breadcrumb).fg(theme::current().muted));
+    }
+
+    let details = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(details, area);
+}
+
+/// Helper function that creates the line representing `body` in a list. The
+/// status is left out when it is already shown some other way.
+fn body_line(galaxy: &Galaxy, body: &CelestialBodyRef, with_status: bool) -> Line<'static> {
+    let node = body.as_tree_node();
+    let mut spans = vec![Span::from(format!("{} ", &*node.icon())).style(kind_style(body.kind()))];
+    if with_status {
+        let stage = galaxy.workflow().stage_of(body);
+        spans.push(Span::from(format!("{:<7}", stage.name)).fg(stage.color()));
+    }
+    if body.pinned() {
+        let pin = format!("{} ", util::glyphs::current().pin);
+        spans.push(Span::from(pin).fg(theme::current().highlight));
+    }
+    // Idle celestial bodies are dimmed, with a badge saying for how long.
+    // Otherwise, the title is in the color of the priority.
+    let idle = galaxy.is_idle(body);
+    let title = Span::from(body.title().to_string());
+    spans.push(match (idle, body.priority()) {
+        (true, _) => title.fg(theme::current().muted),
+        (false, Priority::None) => title,
+        (false, priority) => title.style(priority_style(priority)),
+    });
+    spans.push(
+        Span::from(match body.key() {
+            Some(key) => format!(" {key}"),
+            None => format!(" {}", galaxy.display_id(body.id())),
+        })
+        .fg(theme::current().muted),
+    );
+    if let Some((done, total)) = body.checklist_progress() {
+        spans.push(Span::from(format!(" [{done}/{total}]")).fg(theme::current().highlight));
+    }
+    // Like in the CLI tree, Stars show how far along their descendants are
+    if body.kind() == CelestialBodyKind::Star {
+        if let Some(progress) = galaxy.progress(body.id())
+            && let Some(percent) = progress.percent()
+        {
+            spans.push(Span::from(format!(" {}", progress.bar(8))).fg(theme::current().success));
+            spans.push(Span::from(format!(" {percent}%")).fg(theme::current().muted));
+        }
+        if let Some(rollup) = galaxy.rollup(body.id()).filter(|r| !r.is_empty()) {
+            spans.push(Span::from(format!(" {rollup}")).fg(theme::current().highlight));
+        }
+    }
+    spans.extend(body.tags().iter().map(|tag| tag_span(tag)));
+    if let Some(blocker) = body.blocker() {
+        spans.push(
+            Span::from(format!(" ({})", blocker.describe(galaxy))).fg(theme::current().warning),
+        );
+    }
+    let today = Local::now().date_naive();
+    if let Some(due) = body.due().filter(|_| !body.status().is_final()) {
+        let color = match body.overdue(today) {
+            true => theme::current().error,
+            false => theme::current().muted,
+        };
+        spans.push(Span::from(format!(" {}", util::time::due(due, today))).fg(color));
+    }
+    if idle {
+        let since = match body.last_activity() {
+            Some(time) => util::time::relative(time, Utc::now()).replace(" ago", ""),
+            None => "long".into(),
+        };
+        spans.push(Span::from(format!(" idle {since}")).fg(theme::current().muted));
+    }
+    Line::from(spans)
+}
+
+/// Helper function that creates the chip of `tag` (e.g. ` #backend`) in its
+/// color, matching the CLI colors
+fn tag_span(tag: &str) -> Span<'static> {
+    Span::from(format!(" #{tag}")).fg(theme::tag_color(tag))
+}
+
+/// The style used for each kind of celestial body, matching the CLI colors
+pub fn kind_style(kind: CelestialBodyKind) -> Style {
+    Style::default().fg(kind.color().into())
+}
+
+/// The style used for each status, matching the CLI colors
+pub fn status_style(status: Status) -> Style {
+    Style::default().fg(status.color().into())
+}
+
+/// The style used for each priority, matching the CLI colors
+pub fn priority_style(priority: Priority) -> Style {
+    let style = Style::default().fg(priority.color().into());
+    match priority {
+        Priority::Critical => style.add_modifier(Modifier::BOLD),
+        _ => style,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use chrono::Days;
+
+    use super::*;
+    use crate::core::CelestialBody;
+
+    #[test]
+    fn views_are_found_by_any_of_their_names() {
+        assert_eq!(find("tree").map(|v| v.view), Some(View::Tree));
+        assert_eq!(find("Board").map(|v| v.view), Some(View::Kanban));
+        assert!(find("calendar").is_none());
+        // Every name is only taken once
+        let mut names: Vec<_> = VIEWS.iter().flat_map(|v| v.names).collect();
+        let count = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), count);
+    }
+
+    #[test]
+    fn views_are_opened_with_their_arguments() {
+        let mut galaxy = Galaxy::default();
+        let planet = galaxy.planet().id();
+        let comet = galaxy.comet().id();
+        galaxy.get_mut(planet).unwrap().tag("backend".into());
+
+        let pane = open(&galaxy, "kanban", "tag:backend").unwrap();
+        assert_eq!(pane.view, View::Kanban);
+        assert_eq!(pane.order(&galaxy), [planet]);
+        assert_eq!(
+            open(&galaxy, "calendar", "").err(),
+            Some(CommandError::UnknownView("calendar".into()))
+        );
+        assert!(matches!(
+            open(&galaxy, "tree", "colour:red"),
+            Err(CommandError::FilterError(_))
+        ));
+
+        // Without an active sprint, the sprint has to be named
+        assert_eq!(
+            open(&galaxy, "sprint", "").err(),
+            Some(CommandError::MissingArgument("sprint".into()))
+        );
+        let today = Local::now().date_naive();
+        let end = today.checked_add_days(Days::new(30)).unwrap();
+        galaxy.sprint_create("next".into(), end, end).unwrap();
+        galaxy.sprint_add("next", comet).unwrap();
+        let pane = open(&galaxy, "sprint", "next").unwrap();
+        assert_eq!(pane.order(&galaxy), [comet]);
+        assert_eq!(
+            open(&galaxy, "sprint", "last").err(),
+            Some(CommandError::InvalidArgument(
+                "sprint".into(),
+                "last".into()
+            ))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the celestial bodies marked in visual mode of the TUI.
+ *
+ * Like in vim, `V` marks every celestial body from where it was pressed to the
+ * selection, which follows the cursor. `Space` marks (or unmarks) single
+ * celestial bodies on top of that. Commands such as `status` or `delete` then
+ * act on everything marked at once.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::BTreeSet;
+
+use crate::core::Galaxy;
+
+use super::Pane;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The celestial bodies marked in the active pane
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Visual {
+    /// Where the range started, `None` if only single celestial bodies are
+    /// marked
+    pub anchor: Option<u64>,
+    /// Celestial bodies marked with `Space`, which unmarks them instead if
+    /// they are in the range
+    pub toggled: BTreeSet<u64>,
+}
+
+impl Visual {
+    /// Starts marking a range at `anchor`
+    pub fn range(anchor: Option<u64>) -> Self {
+        Self {
+            anchor,
+            ..Self::default()
+        }
+    }
+
+    /// Marks `id` if it is not marked, or unmarks it if it is
+    pub fn toggle(&mut self, id: u64) {
+        if !self.toggled.remove(&id) {
+            self.toggled.insert(id);
+        }
+    }
+
+    /// The celestial bodies marked in `pane`, in the order they are shown
+    pub fn marked(&self, pane: &Pane, galaxy: &Galaxy) -> Vec<u64> {
+        let order = pane.order(galaxy);
+        let position = |id: Option<u64>| id.and_then(|id| order.iter().position(|o| *o == id));
+        let range = match (position(self.anchor), position(pane.selected)) {
+            (Some(a), Some(b)) => a.min(b)..a.max(b) + 1,
+            _ => 0..0,
+        };
+        order
+            .iter()
+            .enumerate()
+            .filter(|(index, id)| range.contains(index) != self.toggled.contains(id))
+            .map(|(_, id)| *id)
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{app::tui::View, core::Filter};
+
+    #[test]
+    fn ranges_and_toggled_bodies_are_marked() {
+        let mut galaxy = Galaxy::default();
+        for _ in 0..6 {
+            galaxy.planet();
+        }
+        let mut pane = Pane::new(&galaxy, View::Tree, Filter::default(), None, false);
+        pane.selected = Some(3);
+
+        let mut visual = Visual::range(Some(1));
+        assert_eq!(visual.marked(&pane, &galaxy), [1, 2, 3]);
+        visual.toggle(2);
+        visual.toggle(5);
+        assert_eq!(visual.marked(&pane, &galaxy), [1, 3, 5]);
+        pane.selected = Some(0);
+        assert_eq!(visual.marked(&pane, &galaxy), [0, 1, 2, 5]);
+
+        let mut visual = Visual::default();
+        visual.toggle(4);
+        assert_eq!(visual.marked(&pane, &galaxy), [4]);
+        visual.toggle(4);
+        assert!(visual.marked(&pane, &galaxy).is_empty());
+    }
+}
@@ -0,0 +1,215 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing a summary of the recent activity in a `Galaxy` (e.g. for
+ * standup notes), built from the status histories and events of its
+ * celestial bodies.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::{DateTime, Utc};
+
+use super::{CelestialBodyKind, Change, Galaxy, Status, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What happened to the celestial bodies of a `Galaxy` since some time,
+/// grouped by the closest Star containing them
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Activity {
+    /// The Stars with activity, in the order they are first found. Celestial
+    /// bodies that are not in a Star are grouped under `None`.
+    pub groups: Vec<(Option<ID>, Vec<ActivityEntry>)>,
+}
+
+/// What happened to a single celestial body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityEntry {
+    pub id: ID,
+    /// Whether the celestial body was created in the time span
+    pub created: bool,
+    /// The status before the first and after the last change in the time
+    /// span, `None` if the status did not change
+    pub status: Option<(Status, Status)>,
+}
+
+impl Activity {
+    /// Collects the activity in `galaxy` since `since`. Within each Star,
+    /// finished celestial bodies come first, followed by the ones that were
+    /// otherwise changed and the ones that were only created.
+    pub fn since(galaxy: &Galaxy, since: DateTime<Utc>) -> Self {
+        let mut activity = Self::default();
+        for body in galaxy.iter() {
+            let created = body
+                .events()
+                .iter()
+                .any(|event| event.change == Change::Created && event.time >= since);
+            let mut changes = body.history().iter().filter(|c| c.time() >= since);
+            let status = match (changes.next(), changes.next_back()) {
+                (Some(first), last) => {
+                    Some((first.old_status(), last.unwrap_or(first).new_status()))
+                }
+                (None, _) => None,
+            };
+            let status = status.filter(|(old, new)| old != new);
+            if !created && status.is_none() {
+                continue;
+            }
+
+            let mut star = body.parent().and_then(|id| galaxy.get(id));
+            while let Some(s) = star.filter(|s| s.kind() != CelestialBodyKind::Star) {
+                star = s.parent().and_then(|id| galaxy.get(id));
+            }
+            let star = star.map(|s| s.id());
+            let entry = ActivityEntry {
+                id: body.id(),
+                created,
+                status,
+            };
+            match activity.groups.iter_mut().find(|(id, _)| *id == star) {
+                Some((_, entries)) => entries.push(entry),
+                None => activity.groups.push((star, vec![entry])),
+            }
+        }
+
+        for (_, entries) in &mut activity.groups {
+            entries.sort_by_key(|entry| {
+                let rank = match entry.status {
+                    Some((_, Status::Done)) => 0,
+                    Some(_) => 1,
+                    None => 2,
+                };
+                (rank, entry.id)
+            });
+        }
+        activity
+    }
+
+    /// Whether nothing happened
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, StatusHistory};
+
+    fn change(old: Status, new: Status, time: &str) -> StatusHistory {
+        StatusHistory {
+            old,
+            new,
+            comment: String::new(),
+            time: DateTime::parse_from_rfc3339(time).unwrap().into(),
+        }
+    }
+
+    #[test]
+    fn activity_is_grouped_by_star() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+
+        let planet = galaxy.planet();
+        planet.history = vec![
+            change(Status::Todo, Status::Start, "2025-03-01T09:00:00Z"),
+            change(Status::Start, Status::Block, "2025-03-09T09:00:00Z"),
+            change(Status::Block, Status::Done, "2025-03-10T09:00:00Z"),
+        ];
+        let done = planet.id();
+        let planet = galaxy.planet();
+        planet.history = vec![
+            change(Status::Todo, Status::Start, "2025-03-09T09:00:00Z"),
+            change(Status::Start, Status::Todo, "2025-03-09T10:00:00Z"),
+        ];
+        let undone = planet.id();
+        let comet = galaxy.comet().id();
+        galaxy.set_parent(done, Some(star)).unwrap();
+        galaxy.set_parent(undone, Some(star)).unwrap();
+
+        let since = DateTime::parse_from_rfc3339("2025-03-09T00:00:00Z")
+            .unwrap()
+            .into();
+        let activity = Activity::since(&galaxy, since);
+
+        // Everything was created just now, so after `since`
+        assert_eq!(
+            activity.groups,
+            [
+                (
+                    None,
+                    vec![
+                        ActivityEntry {
+                            id: star,
+                            created: true,
+                            status: None,
+                        },
+                        ActivityEntry {
+                            id: comet,
+                            created: true,
+                            status: None,
+                        },
+                    ]
+                ),
+                (
+                    Some(star),
+                    vec![
+                        ActivityEntry {
+                            id: done,
+                            created: true,
+                            status: Some((Status::Start, Status::Done)),
+                        },
+                        ActivityEntry {
+                            id: undone,
+                            created: true,
+                            status: None,
+                        },
+                    ]
+                ),
+            ]
+        );
+
+        let later = DateTime::parse_from_rfc3339("2999-01-01T00:00:00Z")
+            .unwrap()
+            .into();
+        assert!(Activity::since(&galaxy, later).is_empty());
+    }
+}
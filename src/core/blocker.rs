@@ -0,0 +1,110 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the reasons celestial bodies are blocked or on hold.
+ *
+ * A reason is free text, optionally naming the celestial body that has to be
+ * finished first and/or something outside of the galaxy that is being waited
+ * on (e.g. a ticket of another team). Reasons only apply while the status is
+ * `Block` or `Hold`, and are dropped as soon as the status changes to anything
+ * else.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Galaxy, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Why a celestial body is blocked or on hold
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Blocker {
+    pub reason: String,
+    /// The celestial body that has to be finished first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by: Option<ID>,
+    /// Something outside of the galaxy that is being waited on, e.g. a ticket
+    /// or a person
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+impl Blocker {
+    /// Whether the blocker has neither a reason nor anything it waits on
+    pub fn is_empty(&self) -> bool {
+        self.reason.trim().is_empty() && self.by.is_none() && self.reference.is_none()
+    }
+
+    /// Describes the blocker in one line, e.g. `Needs the new API (waiting on
+    /// #12 and VEND-7)`
+    pub fn describe(&self, galaxy: &Galaxy) -> String {
+        self.describe_with(|id| galaxy.display_id(id))
+    }
+
+    /// Whether the reason or the reference contains `word`, ignoring case
+    pub fn mentions(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        self.reason.to_lowercase().contains(&word)
+            || self
+                .reference
+                .as_ref()
+                .is_some_and(|r| r.to_lowercase().contains(&word))
+    }
+
+    /// Helper function that describes the blocker, showing the ID of the
+    /// blocking celestial body with `display_id`
+    fn describe_with(&self, display_id: impl Fn(ID) -> String) -> String {
+        let on: Vec<String> = self
+            .by
+            .map(display_id)
+            .into_iter()
+            .chain(self.reference.clone())
+            .collect();
+        match (self.reason.trim(), on.is_empty()) {
+            (reason, true) => reason.to_string(),
+            ("", false) => format!("Waiting on {}", on.join(" and ")),
+            (reason, false) => format!("{reason} (waiting on {})", on.join(" and ")),
+        }
+    }
+}
+
+impl fmt::Display for Blocker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.describe_with(|id| format!("#{id}")))
+    }
+}
@@ -0,0 +1,926 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing a kind-agnostic view of the celestial bodies. This allows
+ * code outside of `core` to inspect any celestial body without needing to know
+ * which vector of the `Galaxy` it lives in.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::util;
+
+use super::{
+    Blocker, CelestialBody, CelestialBodyKind, Change, ChecklistItem, Comet, Commit, Estimate,
+    Event, Galaxy, Link, Planet, Priority, Star, Status, StatusHistory, WorkLog, ID,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A read-only reference to any celestial body within a `Galaxy`
+#[derive(Debug, Clone, Copy)]
+pub enum CelestialBodyRef<'a> {
+    Comet(&'a Comet),
+    Planet(&'a Planet),
+    Star(&'a Star),
+}
+
+impl<'a> CelestialBodyRef<'a> {
+    /// The kind of the referenced celestial body
+    pub fn kind(&self) -> CelestialBodyKind {
+        match self {
+            Self::Comet(_) => CelestialBodyKind::Comet,
+            Self::Planet(_) => CelestialBodyKind::Planet,
+            Self::Star(_) => CelestialBodyKind::Star,
+        }
+    }
+
+    /// The unique ID of the celestial body
+    pub fn id(&self) -> ID {
+        match self {
+            Self::Comet(comet) => comet.id,
+            Self::Planet(planet) => planet.id,
+            Self::Star(star) => star.id,
+        }
+    }
+
+    /// The short key of the celestial body (e.g. `PLAN-42`), if it has one
+    pub fn key(&self) -> Option<&'a str> {
+        match self {
+            Self::Comet(comet) => comet.key.as_deref(),
+            Self::Planet(planet) => planet.key.as_deref(),
+            Self::Star(star) => star.key.as_deref(),
+        }
+    }
+
+    /// The ID of the celestial body's parent, `None` if it is in the root of
+    /// the `Galaxy`
+    pub fn parent(&self) -> Option<ID> {
+        match self {
+            Self::Comet(comet) => comet.parent,
+            Self::Planet(planet) => planet.parent,
+            Self::Star(star) => star.parent,
+        }
+    }
+
+    /// The title of the celestial body
+    pub fn title(&self) -> &'a str {
+        match self {
+            Self::Comet(comet) => &comet.title,
+            Self::Planet(planet) => &planet.title,
+            Self::Star(star) => &star.title,
+        }
+    }
+
+    /// The description of the celestial body
+    pub fn description(&self) -> &'a str {
+        match self {
+            Self::Comet(comet) => &comet.description,
+            Self::Planet(planet) => &planet.description,
+            Self::Star(star) => &star.description,
+        }
+    }
+
+    /// The current status of the celestial body
+    pub fn status(&self) -> Status {
+        match self {
+            Self::Comet(comet) => comet.status,
+            Self::Planet(planet) => planet.status,
+            Self::Star(star) => star.status,
+        }
+    }
+
+    /// The priority of the celestial body
+    pub fn priority(&self) -> Priority {
+        match self {
+            Self::Comet(comet) => comet.priority,
+            Self::Planet(planet) => planet.priority,
+            Self::Star(star) => star.priority,
+        }
+    }
+
+    /// Whether the celestial body itself has been archived. See
+    /// `Galaxy::is_archived` to also take its ancestors into account.
+    pub fn archived(&self) -> bool {
+        match self {
+            Self::Comet(comet) => comet.archived,
+            Self::Planet(planet) => planet.archived,
+            Self::Star(star) => star.archived,
+        }
+    }
+
+    /// Whether the celestial body is pinned to the focus view, see
+    /// `Galaxy::focus`
+    pub fn pinned(&self) -> bool {
+        match self {
+            Self::Comet(comet) => comet.pinned,
+            Self::Planet(planet) => planet.pinned,
+            Self::Star(star) => star.pinned,
+        }
+    }
+
+    /// The date until which the celestial body is snoozed, `None` if it was
+    /// never snoozed or the snooze ended
+    pub fn snoozed_until(&self) -> Option<NaiveDate> {
+        match self {
+            Self::Comet(comet) => comet.snoozed_until,
+            Self::Planet(planet) => planet.snoozed_until,
+            Self::Star(star) => star.snoozed_until,
+        }
+    }
+
+    /// Whether the celestial body is still snoozed on `today`, i.e. it is
+    /// hidden from views
+    pub fn is_snoozed(&self, today: NaiveDate) -> bool {
+        self.snoozed_until().is_some_and(|until| until > today)
+    }
+
+    /// The place of the celestial body among its siblings, `None` if it was
+    /// never moved. See `Galaxy::place`.
+    pub fn rank(&self) -> Option<u32> {
+        match self {
+            Self::Comet(comet) => comet.rank,
+            Self::Planet(planet) => planet.rank,
+            Self::Star(star) => star.rank,
+        }
+    }
+
+    /// The estimated effort of the celestial body. Only `Planet`s and `Comet`s
+    /// have estimates, `Star`s use `Galaxy::rollup` instead.
+    pub fn estimate(&self) -> Option<Estimate> {
+        match self {
+            Self::Comet(comet) => comet.estimate,
+            Self::Planet(planet) => planet.estimate,
+            Self::Star(_) => None,
+        }
+    }
+
+    /// All changes to the status of the celestial body, oldest first
+    pub fn history(&self) -> &'a [StatusHistory] {
+        match self {
+            Self::Comet(comet) => &comet.history,
+            Self::Planet(planet) => &planet.history,
+            Self::Star(star) => &star.history,
+        }
+    }
+
+    /// The time spent working on the celestial body
+    pub fn work_log(&self) -> &'a WorkLog {
+        match self {
+            Self::Comet(comet) => &comet.work_log,
+            Self::Planet(planet) => &planet.work_log,
+            Self::Star(star) => &star.work_log,
+        }
+    }
+
+    /// All changes other than status changes, oldest first
+    pub fn events(&self) -> &'a [Event] {
+        match self {
+            Self::Comet(comet) => &comet.events,
+            Self::Planet(planet) => &planet.events,
+            Self::Star(star) => &star.events,
+        }
+    }
+
+    /// When the celestial body was last changed, `None` if it has not been
+    /// changed since it was loaded from an older database
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Comet(comet) => comet.updated_at,
+            Self::Planet(planet) => planet.updated_at,
+            Self::Star(star) => star.updated_at,
+        }
+    }
+
+    /// User defined tags. Only `Planet`s have tags, so this is empty for all
+    /// other kinds.
+    pub fn tags(&self) -> &'a [String] {
+        match self {
+            Self::Planet(planet) => &planet.tags,
+            _ => &[],
+        }
+    }
+
+    /// The names of the people working on the celestial body
+    pub fn assignees(&self) -> &'a [String] {
+        match self {
+            Self::Comet(comet) => &comet.assignees,
+            Self::Planet(planet) => &planet.assignees,
+            Self::Star(star) => &star.assignees,
+        }
+    }
+
+    /// Links to pull requests, documents, designs, etc., in the order they were
+    /// added
+    pub fn links(&self) -> &'a [Link] {
+        match self {
+            Self::Comet(comet) => &comet.links,
+            Self::Planet(planet) => &planet.links,
+            Self::Star(star) => &star.links,
+        }
+    }
+
+    /// The git commits referencing the celestial body, oldest first
+    pub fn commits(&self) -> &'a [Commit] {
+        match self {
+            Self::Comet(comet) => &comet.commits,
+            Self::Planet(planet) => &planet.commits,
+            Self::Star(star) => &star.commits,
+        }
+    }
+
+    /// The name of the status of the workflow that the celestial body is in,
+    /// `None` if it is in the built in status (see `Galaxy::set_status`)
+    pub fn stage(&self) -> Option<&'a str> {
+        match self {
+            Self::Comet(comet) => comet.stage.as_deref(),
+            Self::Planet(planet) => planet.stage.as_deref(),
+            Self::Star(star) => star.stage.as_deref(),
+        }
+    }
+
+    /// Why the celestial body is blocked or on hold, `None` if no reason was
+    /// given or the status is anything else
+    pub fn blocker(&self) -> Option<&'a Blocker> {
+        match self {
+            Self::Comet(comet) => comet.blocker.as_ref(),
+            Self::Planet(planet) => planet.blocker.as_ref(),
+            Self::Star(star) => star.blocker.as_ref(),
+        }
+    }
+
+    /// The checklist of the celestial body. Only `Planet`s and `Comet`s have
+    /// checklists, so this is empty for `Star`s.
+    pub fn checklist(&self) -> &'a [ChecklistItem] {
+        match self {
+            Self::Comet(comet) => &comet.checklist,
+            Self::Planet(planet) => &planet.checklist,
+            Self::Star(_) => &[],
+        }
+    }
+
+    /// How many checklist items are checked off and how many there are, `None`
+    /// if the checklist is empty
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        let checklist = self.checklist();
+        let done = checklist.iter().filter(|item| item.done).count();
+        (!checklist.is_empty()).then_some((done, checklist.len()))
+    }
+
+    /// Whether `name` is one of the assignees, ignoring case
+    pub fn is_assigned_to(&self, name: &str) -> bool {
+        self.assignees()
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(name))
+    }
+
+    /// User defined fields. Only `Planet`s have fields, so this is `None` for
+    /// all other kinds.
+    pub fn fields(&self) -> Option<&'a HashMap<String, String>> {
+        match self {
+            Self::Planet(planet) => Some(&planet.fields),
+            _ => None,
+        }
+    }
+
+    /// The date the celestial body is due, which is taken from the `due` field
+    /// (e.g. `2025-07-01`)
+    pub fn due(&self) -> Option<NaiveDate> {
+        let due = self.fields()?.get("due")?;
+        NaiveDate::parse_from_str(due.trim(), "%Y-%m-%d").ok()
+    }
+
+    /// Whether the due date is before `today` and the celestial body is
+    /// neither finished nor archived
+    pub fn overdue(&self, today: NaiveDate) -> bool {
+        let finished = self.status().is_final() || self.archived();
+        !finished && self.due().is_some_and(|due| due < today)
+    }
+
+    /// When anything about the celestial body last changed, taken from its
+    /// status changes, its events, and its `updated_at` stamp. Events that
+    /// planit records on its own (e.g. that it became overdue) do not count.
+    /// `None` if none of these were recorded.
+    pub fn last_activity(&self) -> Option<DateTime<Utc>> {
+        let status = self.history().last().map(|h| h.time());
+        let event = self
+            .events()
+            .iter()
+            .rev()
+            .find(|event| !matches!(event.change, Change::Overdue { .. } | Change::Woke { .. }))
+            .map(|event| event.time);
+        [status, event, self.updated_at()]
+            .into_iter()
+            .flatten()
+            .max()
+    }
+
+    /// Whether the celestial body is neither finished nor archived, and has
+    /// not changed since `since` (see `last_activity`)
+    pub fn is_stale(&self, since: DateTime<Utc>) -> bool {
+        let finished = self.status().is_final() || self.archived();
+        !finished && self.last_activity().is_none_or(|time| time < since)
+    }
+
+    /// The IDs of all celestial bodies directly owned by this one. Only
+    /// `Star`s have children, so this is empty for all other kinds.
+    pub fn children(&self) -> &'a [ID] {
+        match self {
+            Self::Star(star) => &star.children,
+            _ => &[],
+        }
+    }
+
+    /// Looks up a value of the celestial body by name. This is primarily used
+    /// when expanding templates (e.g. `util::shell::expand`).
+    ///
+    /// The built-in keys are `id`, `kind`, `title`, `description`, `status`,
+    /// `priority`, `estimate`, `parent`, `tags`, and `assignees`. Any other key is looked up in the user defined
+    /// fields, optionally prefixed by `field.`.
+    pub fn value(&self, key: &str) -> Option<String> {
+        match key {
+            "id" => Some(self.id().to_string()),
+            "kind" => Some(self.kind().to_string().to_lowercase()),
+            "title" => Some(self.title().to_string()),
+            "description" => Some(self.description().to_string()),
+            "status" => Some(self.status().to_string()),
+            "priority" => Some(self.priority().to_string()),
+            "estimate" => Some(self.estimate().map(|e| e.to_string()).unwrap_or_default()),
+            "parent" => Some(self.parent().map(|p| p.to_string()).unwrap_or_default()),
+            "tags" => Some(self.tags().join(",")),
+            "assignees" => Some(self.assignees().join(",")),
+            _ => {
+                let key = key.strip_prefix("field.").unwrap_or(key);
+                self.fields()?.get(key).cloned()
+            }
+        }
+    }
+
+    /// The celestial body as a node that can be used with `util::tree`
+    pub fn as_tree_node(&self) -> &'a dyn util::tree::PrintTreeNode<Galaxy> {
+        match self {
+            Self::Comet(comet) => *comet,
+            Self::Planet(planet) => *planet,
+            Self::Star(star) => *star,
+        }
+    }
+}
+
+/// A mutable reference to any celestial body within a `Galaxy`
+#[derive(Debug)]
+pub enum CelestialBodyMut<'a> {
+    Comet(&'a mut Comet),
+    Planet(&'a mut Planet),
+    Star(&'a mut Star),
+}
+
+impl CelestialBodyMut<'_> {
+    /// A read-only view of the celestial body
+    pub fn as_body_ref(&self) -> CelestialBodyRef<'_> {
+        match self {
+            Self::Comet(comet) => CelestialBodyRef::Comet(comet),
+            Self::Planet(planet) => CelestialBodyRef::Planet(planet),
+            Self::Star(star) => CelestialBodyRef::Star(star),
+        }
+    }
+
+    /// Records that `change` happened to the celestial body
+    pub(super) fn record(&mut self, change: Change) {
+        let events = match self {
+            Self::Comet(comet) => &mut comet.events,
+            Self::Planet(planet) => &mut planet.events,
+            Self::Star(star) => &mut star.events,
+        };
+        events.push(Event::now(change));
+        self.touch();
+    }
+
+    /// Stamps the celestial body as changed just now
+    pub(super) fn touch(&mut self) {
+        let updated_at = match self {
+            Self::Comet(comet) => &mut comet.updated_at,
+            Self::Planet(planet) => &mut planet.updated_at,
+            Self::Star(star) => &mut star.updated_at,
+        };
+        *updated_at = Some(Utc::now());
+    }
+
+    /// Setter for celestial body's title
+    pub fn title(&mut self, title: String) -> &mut Self {
+        let old = self.as_body_ref().title().to_string();
+        if old != title {
+            self.record(Change::Title {
+                old,
+                new: title.clone(),
+            });
+        }
+        match self {
+            Self::Comet(comet) => {
+                comet.title(title);
+            }
+            Self::Planet(planet) => {
+                planet.title(title);
+            }
+            Self::Star(star) => {
+                star.title(title);
+            }
+        }
+        self
+    }
+
+    /// Setter for celestial body's description
+    pub fn description(&mut self, description: String) -> &mut Self {
+        if self.as_body_ref().description() != description {
+            self.record(Change::Description);
+        }
+        match self {
+            Self::Comet(comet) => {
+                comet.description(description);
+            }
+            Self::Planet(planet) => {
+                planet.description(description);
+            }
+            Self::Star(star) => {
+                star.description(description);
+            }
+        }
+        self
+    }
+
+    /// Setter for celestial body's status. `comment` should be an explanation
+    /// of why the status has changed. The reason for being blocked is dropped
+    /// unless the new status is `Block` or `Hold`, and the status of the
+    /// workflow is always dropped.
+    pub fn status(&mut self, status: Status, comment: String) -> &mut Self {
+        if !matches!(status, Status::Block | Status::Hold) {
+            self.set_blocker(None);
+        }
+        *self.stage_mut() = None;
+        match self {
+            Self::Comet(comet) => {
+                comet.status(status, comment);
+            }
+            Self::Planet(planet) => {
+                planet.status(status, comment);
+            }
+            Self::Star(star) => {
+                star.status(status, comment);
+            }
+        }
+        self.touch();
+        self
+    }
+
+    /// Setter for celestial body's priority
+    pub fn priority(&mut self, priority: Priority) -> &mut Self {
+        let old = self.as_body_ref().priority();
+        if old != priority {
+            self.record(Change::Priority { old, new: priority });
+        }
+        match self {
+            Self::Comet(comet) => {
+                comet.priority(priority);
+            }
+            Self::Planet(planet) => {
+                planet.priority(priority);
+            }
+            Self::Star(star) => {
+                star.priority(priority);
+            }
+        }
+        self
+    }
+
+    /// Archives or unarchives the celestial body
+    ///
+    /// # Returns
+    /// `false` if the celestial body was already in that state
+    pub fn archive(&mut self, archived: bool) -> bool {
+        let field = match self {
+            Self::Comet(comet) => &mut comet.archived,
+            Self::Planet(planet) => &mut planet.archived,
+            Self::Star(star) => &mut star.archived,
+        };
+        if *field == archived {
+            return false;
+        }
+        *field = archived;
+        self.record(match archived {
+            true => Change::Archived,
+            false => Change::Unarchived,
+        });
+        true
+    }
+
+    /// Pins the celestial body to the focus view or unpins it
+    ///
+    /// # Returns
+    /// `false` if the celestial body was already in that state
+    pub fn pin(&mut self, pinned: bool) -> bool {
+        let field = match self {
+            Self::Comet(comet) => &mut comet.pinned,
+            Self::Planet(planet) => &mut planet.pinned,
+            Self::Star(star) => &mut star.pinned,
+        };
+        if *field == pinned {
+            return false;
+        }
+        *field = pinned;
+        self.record(match pinned {
+            true => Change::Pinned,
+            false => Change::Unpinned,
+        });
+        true
+    }
+
+    /// Snoozes the celestial body until `until`, or unsnoozes it if `None`.
+    /// Returns whether anything changed.
+    pub fn snooze(&mut self, until: Option<NaiveDate>) -> bool {
+        let field = self.snoozed_until_mut();
+        if *field == until {
+            return false;
+        }
+        *field = until;
+        self.record(match until {
+            Some(until) => Change::Snoozed { until },
+            None => Change::Unsnoozed,
+        });
+        true
+    }
+
+    /// Ends the snooze of the celestial body once its date has passed
+    pub(super) fn wake(&mut self) {
+        if let Some(until) = self.snoozed_until_mut().take() {
+            self.record(Change::Woke { until });
+        }
+    }
+
+    fn snoozed_until_mut(&mut self) -> &mut Option<NaiveDate> {
+        match self {
+            Self::Comet(comet) => &mut comet.snoozed_until,
+            Self::Planet(planet) => &mut planet.snoozed_until,
+            Self::Star(star) => &mut star.snoozed_until,
+        }
+    }
+
+    /// The time spent working on the celestial body
+    pub fn work_log(&mut self) -> &mut WorkLog {
+        self.touch();
+        match self {
+            Self::Comet(comet) => &mut comet.work_log,
+            Self::Planet(planet) => &mut planet.work_log,
+            Self::Star(star) => &mut star.work_log,
+        }
+    }
+
+    /// Sets (or clears if `None`) the estimated effort of the celestial body
+    ///
+    /// # Returns
+    /// `false` if the celestial body cannot have an estimate
+    pub fn estimate(&mut self, estimate: Option<Estimate>) -> bool {
+        let old = self.as_body_ref().estimate();
+        match self {
+            Self::Comet(comet) => comet.estimate = estimate,
+            Self::Planet(planet) => planet.estimate = estimate,
+            Self::Star(_) => return false,
+        }
+        if old != estimate {
+            self.record(Change::Estimate { old, new: estimate });
+        }
+        true
+    }
+
+    /// Adds `tag` to the celestial body
+    ///
+    /// # Returns
+    /// `false` if the celestial body cannot have tags or already has the tag
+    pub fn tag(&mut self, tag: String) -> bool {
+        match self {
+            Self::Planet(planet) if !planet.tags.contains(&tag) => {
+                planet.tags.push(tag.clone());
+                self.record(Change::TagAdded(tag));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Assigns `name` to the celestial body
+    ///
+    /// # Returns
+    /// `false` if `name` is already assigned (ignoring case)
+    pub fn assign(&mut self, name: String) -> bool {
+        if self.as_body_ref().is_assigned_to(&name) {
+            return false;
+        }
+        match self {
+            Self::Comet(comet) => comet.assignees.push(name.clone()),
+            Self::Planet(planet) => planet.assignees.push(name.clone()),
+            Self::Star(star) => star.assignees.push(name.clone()),
+        }
+        self.record(Change::Assigned(name));
+        true
+    }
+
+    /// Removes `name` (ignoring case) from the assignees of the celestial body
+    ///
+    /// # Returns
+    /// `false` if `name` was not assigned
+    pub fn unassign(&mut self, name: &str) -> bool {
+        let assignees = match self {
+            Self::Comet(comet) => &mut comet.assignees,
+            Self::Planet(planet) => &mut planet.assignees,
+            Self::Star(star) => &mut star.assignees,
+        };
+        let Some(position) = assignees.iter().position(|a| a.eq_ignore_ascii_case(name)) else {
+            return false;
+        };
+        let name = assignees.remove(position);
+        self.record(Change::Unassigned(name));
+        true
+    }
+
+    /// Adds `link` to the celestial body
+    ///
+    /// # Returns
+    /// `false` if the celestial body already links to the same target
+    pub fn link(&mut self, link: Link) -> bool {
+        let links = self.links_mut();
+        if links.iter().any(|l| l.target == link.target) {
+            return false;
+        }
+        links.push(link.clone());
+        self.record(Change::Linked(link.target));
+        true
+    }
+
+    /// Removes the link whose target or label is `target`
+    ///
+    /// # Returns
+    /// `false` if there is no such link
+    pub fn unlink(&mut self, target: &str) -> bool {
+        let links = self.links_mut();
+        let Some(position) = links
+            .iter()
+            .position(|l| l.target == target)
+            .or_else(|| links.iter().position(|l| l.label == target))
+        else {
+            return false;
+        };
+        let link = links.remove(position);
+        self.record(Change::Unlinked(link.target));
+        true
+    }
+
+    /// Attaches a git commit referencing the celestial body
+    ///
+    /// # Returns
+    /// `false` if the commit is already attached
+    pub fn commit(&mut self, commit: Commit) -> bool {
+        let commits = match self {
+            Self::Comet(comet) => &mut comet.commits,
+            Self::Planet(planet) => &mut planet.commits,
+            Self::Star(star) => &mut star.commits,
+        };
+        if commits.iter().any(|c| c.hash == commit.hash) {
+            return false;
+        }
+        commits.push(commit.clone());
+        self.record(Change::Committed(commit.hash));
+        true
+    }
+
+    /// Sets or removes the reason the celestial body is blocked or on hold
+    ///
+    /// # Returns
+    /// `false` if a reason is given but the status is neither `Block` nor
+    /// `Hold`
+    pub fn set_blocker(&mut self, blocker: Option<Blocker>) -> bool {
+        let status = self.as_body_ref().status();
+        let blocker = blocker.filter(|b| !b.is_empty());
+        if blocker.is_some() && !matches!(status, Status::Block | Status::Hold) {
+            return false;
+        }
+        let current = match self {
+            Self::Comet(comet) => &mut comet.blocker,
+            Self::Planet(planet) => &mut planet.blocker,
+            Self::Star(star) => &mut star.blocker,
+        };
+        if *current == blocker {
+            return true;
+        }
+        *current = blocker.clone();
+        self.record(Change::Reason(blocker.map(|b| b.to_string())));
+        true
+    }
+
+    /// Sets or removes the status of the workflow that the celestial body is
+    /// in, which should count as its current status (see
+    /// `Galaxy::set_status`)
+    ///
+    /// # Returns
+    /// `false` if it was already in `stage`
+    pub fn set_stage(&mut self, stage: Option<String>) -> bool {
+        let current = self.stage_mut();
+        if *current == stage {
+            return false;
+        }
+        *current = stage.clone();
+        self.record(Change::Stage(stage));
+        true
+    }
+
+    /// Adds an item with `text` to the end of the checklist
+    ///
+    /// # Returns
+    /// `false` if the celestial body cannot have a checklist
+    pub fn check_add(&mut self, text: String) -> bool {
+        let Some(checklist) = self.checklist_mut() else {
+            return false;
+        };
+        checklist.push(ChecklistItem::new(text.clone()));
+        self.record(Change::ChecklistAdded(text));
+        true
+    }
+
+    /// Checks off the checklist item at `index`, or unchecks it if it already
+    /// was
+    ///
+    /// # Returns
+    /// Whether the item is now checked off, `None` if there is no such item
+    pub fn check_toggle(&mut self, index: usize) -> Option<bool> {
+        let item = self.checklist_mut()?.get_mut(index)?;
+        item.done = !item.done;
+        let (text, done) = (item.text.clone(), item.done);
+        self.record(match done {
+            true => Change::Checked(text),
+            false => Change::Unchecked(text),
+        });
+        Some(done)
+    }
+
+    /// Removes the checklist item at `index`
+    ///
+    /// # Returns
+    /// The removed item, `None` if there is no such item
+    pub fn check_remove(&mut self, index: usize) -> Option<ChecklistItem> {
+        let checklist = self.checklist_mut()?;
+        if index >= checklist.len() {
+            return None;
+        }
+        let item = checklist.remove(index);
+        self.record(Change::ChecklistRemoved(item.text.clone()));
+        Some(item)
+    }
+
+    /// Helper function for mutable access to the status of the workflow of
+    /// any kind of celestial body
+    fn stage_mut(&mut self) -> &mut Option<String> {
+        match self {
+            Self::Comet(comet) => &mut comet.stage,
+            Self::Planet(planet) => &mut planet.stage,
+            Self::Star(star) => &mut star.stage,
+        }
+    }
+
+    /// Helper function for mutable access to the checklist, `None` for `Star`s
+    fn checklist_mut(&mut self) -> Option<&mut Vec<ChecklistItem>> {
+        match self {
+            Self::Comet(comet) => Some(&mut comet.checklist),
+            Self::Planet(planet) => Some(&mut planet.checklist),
+            Self::Star(_) => None,
+        }
+    }
+
+    /// Helper function for mutable access to the links of any kind of
+    /// celestial body
+    fn links_mut(&mut self) -> &mut Vec<Link> {
+        match self {
+            Self::Comet(comet) => &mut comet.links,
+            Self::Planet(planet) => &mut planet.links,
+            Self::Star(star) => &mut star.links,
+        }
+    }
+
+    /// Sets the user defined field `key` to `value`
+    ///
+    /// # Returns
+    /// `false` if the celestial body cannot have fields
+    pub fn field(&mut self, key: String, value: String) -> bool {
+        match self {
+            Self::Planet(planet) => {
+                let old = planet.fields.insert(key.clone(), value.clone());
+                if old.as_ref() != Some(&value) {
+                    self.record(Change::Field {
+                        key,
+                        old,
+                        new: Some(value),
+                    });
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Renames the tag `old` to `new`. If the celestial body already has `new`,
+    /// `old` is simply removed.
+    ///
+    /// # Returns
+    /// `false` if the celestial body did not have the tag
+    pub fn rename_tag(&mut self, old: &str, new: &str) -> bool {
+        match self {
+            Self::Planet(planet) if planet.tags.iter().any(|t| t == old) => {
+                if planet.tags.iter().any(|t| t == new) {
+                    planet.tags.retain(|t| t != old);
+                } else {
+                    for tag in planet.tags.iter_mut().filter(|t| *t == old) {
+                        *tag = new.to_string();
+                    }
+                }
+                self.record(Change::TagRenamed {
+                    old: old.to_string(),
+                    new: new.to_string(),
+                });
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Renames the user defined field `old` to `new`, keeping its value. Any
+    /// existing value of `new` is overwritten.
+    ///
+    /// # Returns
+    /// `false` if the celestial body did not have the field
+    pub fn rename_field(&mut self, old: &str, new: &str) -> bool {
+        match self {
+            Self::Planet(planet) => match planet.fields.remove(old) {
+                Some(value) => {
+                    planet.fields.insert(new.to_string(), value);
+                    self.record(Change::FieldRenamed {
+                        old: old.to_string(),
+                        new: new.to_string(),
+                    });
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Removes `tag` from the celestial body
+    ///
+    /// # Returns
+    /// `false` if the celestial body did not have the tag
+    pub fn untag(&mut self, tag: &str) -> bool {
+        match self {
+            Self::Planet(planet) => {
+                let len = planet.tags.len();
+                planet.tags.retain(|t| t != tag);
+                let removed = len != planet.tags.len();
+                if removed {
+                    self.record(Change::TagRemoved(tag.to_string()));
+                }
+                removed
+            }
+            _ => false,
+        }
+    }
+}
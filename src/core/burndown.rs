@@ -0,0 +1,364 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the burndown of a Star: how much of the work in it was
+ * still open at the end of each day, rebuilt from the status histories of its
+ * descendants.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Days, NaiveDate, TimeZone, Utc};
+use serde::Serialize;
+
+use super::{
+    CelestialBodyKind, CelestialBodyRef, Change, Estimate, Galaxy, GalaxyError, Status, ID,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The open work in a Star at the end of each day in a range
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Burndown {
+    pub star: ID,
+    /// Whether the work is counted in estimate points instead of celestial
+    /// bodies
+    pub points: bool,
+    pub days: Vec<BurndownDay>,
+}
+
+/// The open work in a Star at the end of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BurndownDay {
+    pub date: NaiveDate,
+    /// The work that was not `Done` or `Cancel`ed at the end of the day
+    pub remaining: u64,
+    /// The work that was finished during the day
+    pub done: u64,
+}
+
+impl Burndown {
+    /// Rebuilds the burndown of the Star `star` from `from` to `to` (both
+    /// inclusive). Only Planets and Comets count as work, Stars just group
+    /// them.
+    ///
+    /// # Arguments
+    /// - `galaxy`: The `Galaxy` containing the Star
+    /// - `star`: ID of the Star
+    /// - `from`: The first day
+    /// - `to`: The last day
+    /// - `points`: Count estimate points instead of celestial bodies. Celestial
+    ///   bodies without an estimate in points are left out.
+    /// - `tz`: The time zone that days start and end in
+    ///
+    /// # Errors
+    /// Errors will occur if `star` does not exist or is not a Star
+    pub fn of<Tz: TimeZone>(
+        galaxy: &Galaxy,
+        star: ID,
+        from: NaiveDate,
+        to: NaiveDate,
+        points: bool,
+        tz: &Tz,
+    ) -> Result<Self, GalaxyError> {
+        let body = galaxy
+            .get(star)
+            .ok_or(GalaxyError::CelestialBodyNotFound(star))?;
+        if body.kind() != CelestialBodyKind::Star {
+            return Err(GalaxyError::NotAStar(star));
+        }
+
+        let mut work = vec![];
+        let mut visited = HashSet::from([star]);
+        let mut stack = body.children().to_vec();
+        while let Some(id) = stack.pop() {
+            let Some(body) = galaxy.get(id) else {
+                continue;
+            };
+            if !visited.insert(id) {
+                continue;
+            }
+            let size = match (body.kind(), points, body.estimate()) {
+                (CelestialBodyKind::Star, _, _) => 0,
+                (_, false, _) => 1,
+                (_, true, Some(Estimate::Points(points))) => points,
+                (_, true, _) => 0,
+            };
+            if size > 0 {
+                work.push((body, size));
+            }
+            stack.extend(body.children());
+        }
+
+        let open_at = |time: DateTime<Utc>| -> (u64, u64) {
+            work.iter()
+                .filter_map(|(body, size)| Some((status_at(body, time)?, *size)))
+                .fold((0, 0), |(open, finished), (status, size)| {
+                    match status.is_final() {
+                        true => (open, finished + size),
+                        false => (open + size, finished),
+                    }
+                })
+        };
+
+        let mut days = vec![];
+        let mut date = from;
+        let (_, mut finished) = open_at(start_of(from, tz));
+        while date <= to {
+            let Some(next) = date.checked_add_days(Days::new(1)) else {
+                break;
+            };
+            let (remaining, finished_by_now) = open_at(start_of(next, tz));
+            days.push(BurndownDay {
+                date,
+                remaining,
+                done: finished_by_now.saturating_sub(finished),
+            });
+            finished = finished_by_now;
+            date = next;
+        }
+
+        Ok(Self { star, points, days })
+    }
+
+    /// The average work finished per day
+    pub fn velocity(&self) -> f64 {
+        match self.days.len() {
+            0 => 0.0,
+            days => self.days.iter().map(|day| day.done).sum::<u64>() as f64 / days as f64,
+        }
+    }
+
+    /// Draws the burndown as an SVG line chart
+    #[cfg(feature = "svg")]
+    pub fn to_svg(&self, title: &str) -> String {
+        const WIDTH: f64 = 640.0;
+        const HEIGHT: f64 = 360.0;
+        const MARGIN: f64 = 40.0;
+
+        let most = self
+            .days
+            .iter()
+            .map(|day| day.remaining)
+            .max()
+            .unwrap_or(0)
+            .max(1) as f64;
+        let step = (WIDTH - 2.0 * MARGIN) / (self.days.len().max(2) - 1) as f64;
+        let y =
+            |remaining: u64| HEIGHT - MARGIN - remaining as f64 / most * (HEIGHT - 2.0 * MARGIN);
+        let points: Vec<_> = self
+            .days
+            .iter()
+            .enumerate()
+            .map(|(i, day)| format!("{:.1},{:.1}", MARGIN + i as f64 * step, y(day.remaining)))
+            .collect();
+        let escape = |s: &str| {
+            s.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+        };
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" \
+             font-family=\"sans-serif\" font-size=\"12\">\n"
+        );
+        svg.push_str(&format!(
+            "  <text x=\"{MARGIN}\" y=\"{}\">{}</text>\n",
+            MARGIN / 2.0,
+            escape(title)
+        ));
+        svg.push_str(&format!(
+            "  <polyline points=\"{MARGIN},{MARGIN} {MARGIN},{0} {1},{0}\" fill=\"none\" \
+             stroke=\"gray\"/>\n",
+            HEIGHT - MARGIN,
+            WIDTH - MARGIN
+        ));
+        svg.push_str(&format!(
+            "  <text x=\"4\" y=\"{:.1}\">{most}</text>\n",
+            MARGIN + 4.0
+        ));
+        if let (Some(first), Some(last)) = (self.days.first(), self.days.last()) {
+            svg.push_str(&format!(
+                "  <text x=\"{MARGIN}\" y=\"{:.1}\">{}</text>\n",
+                HEIGHT - MARGIN / 2.0,
+                first.date
+            ));
+            svg.push_str(&format!(
+                "  <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>\n",
+                WIDTH - MARGIN,
+                HEIGHT - MARGIN / 2.0,
+                last.date
+            ));
+        }
+        svg.push_str(&format!(
+            "  <polyline points=\"{}\" fill=\"none\" stroke=\"steelblue\" stroke-width=\"2\"/>\n",
+            points.join(" ")
+        ));
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that finds the status `body` had at `time`, `None` if it
+/// was created after `time`
+fn status_at(body: &CelestialBodyRef, time: DateTime<Utc>) -> Option<Status> {
+    let created = body
+        .events()
+        .iter()
+        .find(|event| event.change == Change::Created);
+    if created.is_some_and(|event| event.time >= time) {
+        return None;
+    }
+
+    let history = body.history();
+    match history.iter().rev().find(|change| change.time() < time) {
+        Some(change) => Some(change.new_status()),
+        None => Some(history.first().map_or(body.status(), |c| c.old_status())),
+    }
+}
+
+/// Helper function that finds when `date` starts in `tz`
+fn start_of<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> DateTime<Utc> {
+    let midnight = date.and_time(chrono::NaiveTime::MIN);
+    tz.from_local_datetime(&midnight)
+        .earliest()
+        .map_or(midnight.and_utc(), |time| time.with_timezone(&Utc))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, Event, StatusHistory};
+
+    fn change(old: Status, new: Status, time: &str) -> StatusHistory {
+        StatusHistory {
+            old,
+            new,
+            comment: String::new(),
+            time: DateTime::parse_from_rfc3339(time).unwrap().into(),
+        }
+    }
+
+    #[test]
+    fn burndown_follows_the_history() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let mut ids = vec![];
+        for (status, history, estimate, created) in [
+            (
+                Status::Done,
+                vec![change(Status::Todo, Status::Done, "2026-10-02T12:00:00Z")],
+                3,
+                "2026-09-30T12:00:00Z",
+            ),
+            (
+                Status::Cancel,
+                vec![change(Status::Todo, Status::Cancel, "2026-10-03T12:00:00Z")],
+                5,
+                "2026-09-30T12:00:00Z",
+            ),
+            (Status::Start, vec![], 2, "2026-10-02T12:00:00Z"),
+        ] {
+            let planet = galaxy.planet();
+            planet.status = status;
+            planet.history = history;
+            planet.estimate = Some(Estimate::Points(estimate));
+            planet.events = vec![Event {
+                time: DateTime::parse_from_rfc3339(created).unwrap().into(),
+                change: Change::Created,
+            }];
+            ids.push(planet.id());
+        }
+        for id in ids {
+            galaxy.set_parent(id, Some(star)).unwrap();
+        }
+
+        let date = |s: &str| s.parse::<NaiveDate>().unwrap();
+        let burndown = Burndown::of(
+            &galaxy,
+            star,
+            date("2026-10-01"),
+            date("2026-10-03"),
+            false,
+            &Utc,
+        )
+        .unwrap();
+        let days: Vec<_> = burndown
+            .days
+            .iter()
+            .map(|d| (d.remaining, d.done))
+            .collect();
+        assert_eq!(days, [(2, 0), (2, 1), (1, 1)]);
+        assert_eq!(burndown.velocity(), 2.0 / 3.0);
+
+        let burndown = Burndown::of(
+            &galaxy,
+            star,
+            date("2026-10-01"),
+            date("2026-10-02"),
+            true,
+            &Utc,
+        )
+        .unwrap();
+        let remaining: Vec<_> = burndown.days.iter().map(|d| d.remaining).collect();
+        assert_eq!(remaining, [8, 7]);
+
+        let planet = galaxy.planet().id();
+        assert_eq!(
+            Burndown::of(
+                &galaxy,
+                planet,
+                date("2026-10-01"),
+                date("2026-10-02"),
+                false,
+                &Utc
+            ),
+            Err(GalaxyError::NotAStar(planet))
+        );
+    }
+}
@@ -0,0 +1,344 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the export of dates to iCalendar (`.ics`), so that they
+ * show up in calendar applications.
+ *
+ * Every celestial body with a due date becomes an all day event on that date,
+ * and so does every day it was planned for (see `DayPlan`). `Star`s whose
+ * descendants have due dates are milestones on the last of those dates. Each
+ * event has a UID made of the ID of the celestial body, what the event marks,
+ * and the title of the `Galaxy`, so that calendars subscribed to the export
+ * update their events instead of duplicating them.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashSet, fmt};
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+
+use super::{CelestialBodyKind, CelestialBodyRef, Filter, Galaxy, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The longest a line of an iCalendar file may be, in bytes
+const MAX_LINE_LEN: usize = 75;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What the date of a calendar entry marks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    /// The celestial body is due
+    Due,
+    /// The celestial body was planned to be worked on
+    Scheduled,
+    /// The last due date of the descendants of a `Star`
+    Milestone,
+}
+
+impl fmt::Display for EntryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Due => write!(f, "Due"),
+            Self::Scheduled => write!(f, "Scheduled"),
+            Self::Milestone => write!(f, "Milestone"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single all day event of a `Calendar`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub id: ID,
+    pub kind: EntryKind,
+    pub date: NaiveDate,
+}
+
+/// The dates of the celestial bodies in a `Galaxy`, ordered by date
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Calendar {
+    pub entries: Vec<Entry>,
+}
+
+impl Calendar {
+    /// Collects the dates of the celestial bodies matching `filter`. Archived
+    /// celestial bodies are left out, and so are finished ones unless
+    /// `finished` is set.
+    pub fn new(galaxy: &Galaxy, filter: &Filter, finished: bool) -> Self {
+        let included = |body: &CelestialBodyRef| {
+            filter.matches(body) && !body.archived() && (finished || !body.status().is_final())
+        };
+
+        let mut entries = Vec::new();
+        for body in galaxy.iter().filter(included) {
+            let date = match body.kind() {
+                CelestialBodyKind::Star => last_due(galaxy, body.id(), finished),
+                _ => body.due(),
+            };
+            let kind = match body.kind() {
+                CelestialBodyKind::Star => EntryKind::Milestone,
+                _ => EntryKind::Due,
+            };
+            if let Some(date) = date {
+                entries.push(Entry {
+                    id: body.id(),
+                    kind,
+                    date,
+                });
+            }
+        }
+        for plan in galaxy.plans() {
+            for id in plan.items() {
+                if galaxy.get(*id).is_some_and(|body| included(&body)) {
+                    entries.push(Entry {
+                        id: *id,
+                        kind: EntryKind::Scheduled,
+                        date: plan.date(),
+                    });
+                }
+            }
+        }
+
+        entries.sort_by_key(|entry| (entry.date, entry.id));
+        Self { entries }
+    }
+
+    /// Writes the calendar as an iCalendar file. `now` is only used for the
+    /// stamp of celestial bodies that do not record when they were changed.
+    pub fn to_ics(&self, galaxy: &Galaxy, now: DateTime<Utc>) -> String {
+        let domain = slug(galaxy.get_title());
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:-//planit//planit {}//EN", env!("CARGO_PKG_VERSION")),
+            "CALSCALE:GREGORIAN".to_string(),
+            format!("X-WR-CALNAME:{}", escape(galaxy.get_title())),
+        ];
+
+        for entry in &self.entries {
+            let Some(body) = galaxy.get(entry.id) else {
+                continue;
+            };
+            let end = entry
+                .date
+                .checked_add_days(Days::new(1))
+                .unwrap_or(entry.date);
+            let uid = match entry.kind {
+                EntryKind::Due => format!("planit-{}-due@{domain}", entry.id),
+                EntryKind::Milestone => format!("planit-{}-milestone@{domain}", entry.id),
+                EntryKind::Scheduled => format!(
+                    "planit-{}-scheduled-{}@{domain}",
+                    entry.id,
+                    entry.date.format("%Y%m%d")
+                ),
+            };
+            let stamp = body.updated_at().unwrap_or(now);
+            let title = format!(
+                "{}: {} ({})",
+                entry.kind,
+                body.title(),
+                galaxy.display_id(entry.id)
+            );
+
+            lines.push("BEGIN:VEVENT".into());
+            lines.push(format!("UID:{uid}"));
+            lines.push(format!("DTSTAMP:{}", stamp.format("%Y%m%dT%H%M%SZ")));
+            lines.push(format!(
+                "DTSTART;VALUE=DATE:{}",
+                entry.date.format("%Y%m%d")
+            ));
+            lines.push(format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+            lines.push(format!("SUMMARY:{}", escape(&title)));
+            if !body.description().is_empty() {
+                lines.push(format!("DESCRIPTION:{}", escape(body.description())));
+            }
+            if !body.tags().is_empty() {
+                let tags: Vec<_> = body.tags().iter().map(|t| escape(t)).collect();
+                lines.push(format!("CATEGORIES:{}", tags.join(",")));
+            }
+            if let Some(link) = body.links().iter().find(|link| link.is_url()) {
+                lines.push(format!("URL:{}", link.target));
+            }
+            lines.push("TRANSP:TRANSPARENT".into());
+            lines.push("END:VEVENT".into());
+        }
+        lines.push("END:VCALENDAR".into());
+
+        lines
+            .iter()
+            .map(|line| fold(line) + "\r\n")
+            .collect::<String>()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that finds the last due date of the descendants of `star`,
+/// ignoring finished ones unless `finished` is set
+fn last_due(galaxy: &Galaxy, star: ID, finished: bool) -> Option<NaiveDate> {
+    let mut last = None;
+    let mut visited = HashSet::new();
+    let mut stack: Vec<ID> = galaxy.get(star)?.children().to_vec();
+    while let Some(id) = stack.pop() {
+        let Some(body) = galaxy.get(id) else {
+            continue;
+        };
+        if !visited.insert(id) {
+            continue;
+        }
+        if finished || !body.status().is_final() {
+            last = last.max(body.due());
+        }
+        stack.extend(body.children());
+    }
+    last
+}
+
+/// Helper function that escapes text for iCalendar
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Helper function that folds a line longer than `MAX_LINE_LEN` bytes onto
+/// continuation lines, which start with a space
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > MAX_LINE_LEN {
+            folded.push_str("\r\n ");
+            len = 1;
+        }
+        folded.push(c);
+        len += c.len_utf8();
+    }
+    folded
+}
+
+/// Helper function that turns the title of the `Galaxy` into the domain part
+/// of UIDs, e.g. `My Project` into `my-project.planit`
+fn slug(title: &str) -> String {
+    let slug = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("-");
+    match slug.is_empty() {
+        true => "galaxy.planit".into(),
+        false => format!("{slug}.planit"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::core::{CelestialBody, Status};
+
+    use super::*;
+
+    #[test]
+    fn due_dates_plans_and_milestones_are_exported() {
+        let mut galaxy = Galaxy::default().title("My Project".into());
+        let star = galaxy.star().title("Release, v1".into()).id();
+        let early = galaxy.planet().title("Docs".into()).id();
+        let late = galaxy.planet().title("Ship".into()).id();
+        let done = galaxy.planet().title("Done".into()).id();
+        let jul = |day| NaiveDate::from_ymd_opt(2025, 7, day).unwrap();
+        for (id, day) in [(early, 1), (late, 4), (done, 2)] {
+            galaxy.set_parent(id, Some(star)).unwrap();
+            let mut body = galaxy.get_mut(id).unwrap();
+            body.field("due".into(), jul(day).to_string());
+        }
+        galaxy
+            .get_mut(done)
+            .unwrap()
+            .status(Status::Done, String::new());
+        galaxy.plan_add(jul(3), early).unwrap();
+
+        let calendar = Calendar::new(&galaxy, &Filter::default(), false);
+        let entry = |id, kind, day| Entry {
+            id,
+            kind,
+            date: jul(day),
+        };
+        assert_eq!(
+            calendar.entries,
+            [
+                entry(early, EntryKind::Due, 1),
+                entry(early, EntryKind::Scheduled, 3),
+                entry(star, EntryKind::Milestone, 4),
+                entry(late, EntryKind::Due, 4),
+            ]
+        );
+        let calendar = Calendar::new(&galaxy, &Filter::default(), true);
+        assert!(calendar.entries.contains(&entry(done, EntryKind::Due, 2)));
+
+        let ics =
+            Calendar::new(&galaxy, &"Release".parse().unwrap(), false).to_ics(&galaxy, Utc::now());
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.contains("UID:planit-0-milestone@my-project.planit\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250704\r\nDTEND;VALUE=DATE:20250705\r\n"));
+        assert!(ics.contains("SUMMARY:Milestone: Release\\, v1 (#0)\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+
+        let folded = fold(&"x".repeat(100));
+        assert_eq!(folded, format!("{}\r\n {}", "x".repeat(75), "x".repeat(25)));
+    }
+}
@@ -33,14 +33,17 @@
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
 use colored::Colorize;
 use log::info;
 use serde::{Deserialize, Serialize};
 
-use crate::util;
+use crate::util::{self, glyphs, theme::Themed};
 
-use super::{CelestialBody, Galaxy, Status, StatusHistory, ID};
+use super::{
+    Blocker, CelestialBody, CelestialBodyKind, CelestialBodyRef, ChecklistItem, Commit, Estimate,
+    Event, Galaxy, Link, Priority, Status, StatusHistory, WorkLog, ID,
+};
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -51,14 +54,67 @@ use super::{CelestialBody, Galaxy, Status, StatusHistory, ID};
 /// Comets are interrupting tasks / bugs. They should be small and compact. They
 /// only contain the core features required by all celestial bodies because they
 /// are meant to quickly go from `Todo` to `Done`.
-#[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Comet {
     pub(super) id: ID,
+    /// The short key (e.g. `PLAN-42`), `None` if the `Galaxy` has no key
+    /// prefix
+    #[serde(default)]
+    pub(super) key: Option<String>,
     pub(super) parent: Option<ID>,
     pub(super) title: String,
     pub(super) description: String,
     pub(super) status: Status,
+    #[serde(default)]
+    pub(super) priority: Priority,
+    /// Archived celestial bodies are hidden from views but otherwise kept
+    #[serde(default)]
+    pub(super) archived: bool,
+    /// Pinned celestial bodies are part of the focus view until they are
+    /// finished or unpinned
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(super) pinned: bool,
+    /// Snoozed celestial bodies are hidden from views until this date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) snoozed_until: Option<NaiveDate>,
+    /// The place among its siblings, see `Galaxy::place`. Unranked
+    /// celestial bodies come after ranked ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) rank: Option<u32>,
+    /// The estimated effort, `None` if it has not been estimated
+    #[serde(default)]
+    pub(super) estimate: Option<Estimate>,
     pub(super) history: Vec<StatusHistory>,
+    /// Time spent working on the celestial body
+    #[serde(default)]
+    pub(super) work_log: WorkLog,
+    /// All changes other than status changes, oldest first
+    #[serde(default)]
+    pub(super) events: Vec<Event>,
+    /// When the celestial body was last changed, `None` for celestial bodies
+    /// that have not been changed since these stamps were introduced
+    #[serde(default)]
+    pub(super) updated_at: Option<DateTime<Utc>>,
+    /// The names of the people working on the celestial body
+    #[serde(default)]
+    pub(super) assignees: Vec<String>,
+    /// Links to pull requests, documents, designs, etc.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(super) links: Vec<Link>,
+    /// The git commits referencing the celestial body, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(super) commits: Vec<Commit>,
+    /// Why the celestial body is blocked or on hold, `None` for any other
+    /// status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) blocker: Option<Blocker>,
+    /// The status of the workflow of the `Galaxy` that the celestial body is
+    /// in, `None` if it is just `status`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) stage: Option<String>,
+    /// Steps too small to be celestial bodies of their own
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(super) checklist: Vec<ChecklistItem>,
 }
 
 impl CelestialBody<'_> for Comet {
@@ -69,6 +125,10 @@ impl CelestialBody<'_> for Comet {
         }
     }
 
+    fn id(&self) -> ID {
+        self.id
+    }
+
     fn parent(&mut self, parent: ID) -> &mut Self {
         self.parent = Some(parent);
         self
@@ -98,27 +158,79 @@ impl CelestialBody<'_> for Comet {
         self.status = status;
         self
     }
+
+    fn priority(&mut self, priority: Priority) -> &mut Self {
+        info!(
+            "Comet ({}) changed priority from {} to {}",
+            self.id, self.priority, priority
+        );
+        self.priority = priority;
+        self
+    }
 }
 
 impl util::tree::PrintTreeNode<Galaxy> for Comet {
     fn icon(&self) -> colored::ColoredString {
-        "".red()
+        glyphs::current()
+            .comet
+            .color(CelestialBodyKind::Comet.color())
     }
 
     fn label(&self) -> colored::ColoredString {
-        "[COMET] ".red() // Added spaces line it up with planet
+        "[COMET] ".color(CelestialBodyKind::Comet.color()) // Added spaces line it up with planet
+    }
+
+    fn status(&self, galaxy: &Galaxy) -> colored::ColoredString {
+        galaxy.status_label(&CelestialBodyRef::Comet(self))
+    }
+
+    fn reference(&self, galaxy: &Galaxy) -> colored::ColoredString {
+        galaxy.id_label(&CelestialBodyRef::Comet(self))
     }
 
-    fn status(&self) -> colored::ColoredString {
-        self.status.into()
+    fn path(&self, galaxy: &Galaxy) -> colored::ColoredString {
+        galaxy.breadcrumb(self.id).muted()
+    }
+
+    fn priority(&self) -> colored::ColoredString {
+        self.priority.into()
+    }
+
+    fn progress(&self, _: &Galaxy) -> colored::ColoredString {
+        let checklist = CelestialBodyRef::Comet(self).checklist_progress();
+        match (self.estimate, checklist) {
+            (Some(estimate), Some((done, total))) => {
+                format!("[{estimate}] [{done}/{total}]").highlight()
+            }
+            (Some(estimate), None) => format!("[{estimate}]").highlight(),
+            (None, Some((done, total))) => format!("[{done}/{total}]").highlight(),
+            (None, None) => "".into(),
+        }
     }
 
     fn title(&self) -> colored::ColoredString {
         colored::ColoredString::from(self.title.clone())
     }
 
+    fn assignees(&self) -> colored::ColoredString {
+        let names: Vec<_> = self
+            .assignees
+            .iter()
+            .map(|name| format!("@{name}"))
+            .collect();
+        names.join(" ").muted()
+    }
+
+    fn pinned(&self) -> bool {
+        self.pinned
+    }
+
+    fn note(&self, galaxy: &Galaxy) -> colored::ColoredString {
+        galaxy.note(&CelestialBodyRef::Comet(self))
+    }
+
     fn description(&self) -> colored::ColoredString {
-        self.description.bright_black()
+        self.description.muted()
     }
 
     fn children<'a>(&self, _: &'a Galaxy) -> Vec<Box<&'a dyn util::tree::PrintTreeNode<Galaxy>>> {
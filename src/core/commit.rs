@@ -0,0 +1,204 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the git commits attached to celestial bodies by
+ * `planit git scan`, and the parsing of commit messages for references to
+ * celestial bodies.
+ *
+ * A commit references a celestial body either through a `Planit:` trailer
+ * (e.g. `Planit: PLAN-42, #7`), which may contain any ID or key, or by
+ * mentioning an ID or key in the message (e.g. `#42` or `PLAN-42`). Bare
+ * numbers are only references in trailers, since they are too common
+ * otherwise. A reference directly after a closing keyword (e.g. `Fixes
+ * PLAN-42`) closes the celestial body.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Words that close the celestial body referenced right after them
+const CLOSING_KEYWORDS: [&str; 9] = [
+    "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+];
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A git commit that references a celestial body
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Commit {
+    /// The full hash of the commit
+    pub hash: String,
+    /// The first line of the commit message
+    pub summary: String,
+    /// When the commit was made
+    pub time: DateTime<Utc>,
+}
+
+impl Commit {
+    /// The abbreviated hash, as shown by git
+    pub fn short_hash(&self) -> &str {
+        &self.hash[..self.hash.len().min(7)]
+    }
+}
+
+/// A reference to a celestial body found in a commit message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mention {
+    /// The ID or key as written in the message, e.g. `#42` or `PLAN-42`
+    pub reference: String,
+    /// Whether the reference follows a closing keyword, e.g. `Fixes PLAN-42`
+    pub closes: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Finds the references to celestial bodies in a commit message, in the order
+/// they first appear. References that appear more than once are only listed
+/// once, closing if any of them closes.
+pub fn mentions(message: &str) -> Vec<Mention> {
+    let mut mentions: Vec<Mention> = Vec::new();
+    let mut add = |reference: &str, closes: bool| match mentions
+        .iter_mut()
+        .find(|m| m.reference.eq_ignore_ascii_case(reference))
+    {
+        Some(mention) => mention.closes |= closes,
+        None => mentions.push(Mention {
+            reference: reference.to_string(),
+            closes,
+        }),
+    };
+
+    for line in message.lines() {
+        if let Some((key, value)) = line.split_once(':') {
+            let key = key.trim().to_lowercase();
+            let references: Vec<_> = value
+                .split([',', ' '])
+                .map(trim)
+                .filter(|r| !r.is_empty())
+                .collect();
+            // Only the `Planit` trailer may contain bare numbers, and messages
+            // such as `fix: handle #3` are not trailers
+            let closes = CLOSING_KEYWORDS.contains(&key.as_str())
+                && references.iter().all(|r| is_reference(r));
+            if key == "planit" || closes {
+                for reference in references {
+                    add(reference, closes);
+                }
+                continue;
+            }
+        }
+
+        // The closing keyword applies to a list of references, e.g.
+        // `fixes #1, #2 and #3`
+        let mut closing = false;
+        for word in line.split_whitespace() {
+            let word = trim(word);
+            if is_reference(word) {
+                add(word, closing);
+            } else {
+                let word = word.to_lowercase();
+                closing = CLOSING_KEYWORDS.contains(&word.as_str()) || (closing && word == "and");
+            }
+        }
+    }
+    mentions
+}
+
+/// Helper function that removes the punctuation around a word
+fn trim(word: &str) -> &str {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && c != '#')
+}
+
+/// Helper function that checks whether a word looks like an ID (e.g. `#42`) or
+/// key (e.g. `PLAN-42`)
+fn is_reference(word: &str) -> bool {
+    match word.strip_prefix('#') {
+        Some(id) => !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric()),
+        None => word.split_once('-').is_some_and(|(prefix, id)| {
+            prefix.starts_with(|c: char| c.is_ascii_alphabetic())
+                && prefix.chars().all(|c| c.is_ascii_alphanumeric())
+                && !id.is_empty()
+                && id.chars().all(|c| c.is_ascii_digit())
+        }),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn mention(reference: &str, closes: bool) -> Mention {
+        Mention {
+            reference: reference.into(),
+            closes,
+        }
+    }
+
+    #[test]
+    fn references_are_found_in_messages_and_trailers() {
+        let message = "Fix login (see #12)\n\nFixes PLAN-42, #7 and WEB-3.\nAlso touches \
+                       plan-42 and step 3-4.\n\nPlanit: 9, #12\nResolves: #5";
+        assert_eq!(
+            mentions(message),
+            [
+                mention("#12", false),
+                mention("PLAN-42", true),
+                mention("#7", true),
+                mention("WEB-3", true),
+                mention("9", false),
+                mention("#5", true),
+            ]
+        );
+        assert!(mentions("Bump version to 1.2-3 (see 42)").is_empty());
+        assert_eq!(mentions("fix: handle #3"), [mention("#3", false)]);
+    }
+}
@@ -0,0 +1,294 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the comparison of two versions of a `Galaxy`, e.g. to
+ * review what a merge or a pull changed in a database.
+ *
+ * Celestial bodies are matched by ID and compared field by field. The history,
+ * the events, and the work log only ever grow, and are summarized by the
+ * fields they change (e.g. `status`), so they are not compared themselves.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::Value;
+
+use super::{CelestialBodyKind, DatabaseError, Galaxy, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The fields of a celestial body that are not compared
+const SKIPPED: [&str; 6] = [
+    "id",
+    "history",
+    "events",
+    "work_log",
+    "updated_at",
+    "children",
+];
+
+/// The fields of a `Galaxy` that are not compared as values
+const COLLECTIONS: [&str; 10] = [
+    "version", "next_id", "last_key", "comets", "planets", "stars", "trash", "retros", "plans",
+    "sprints",
+];
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single value that differs between the two versions
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// The name of the value, e.g. `title` or `fields.owner`
+    pub field: String,
+    /// The old value, `None` if it was not set
+    pub old: Option<Value>,
+    /// The new value, `None` if it is no longer set
+    pub new: Option<Value>,
+}
+
+/// A celestial body that only exists in one of the two versions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComparedBody {
+    pub id: ID,
+    pub kind: CelestialBodyKind,
+    pub title: String,
+}
+
+/// A celestial body that exists in both versions but differs
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModifiedBody {
+    pub id: ID,
+    pub kind: CelestialBodyKind,
+    /// The title in the new version
+    pub title: String,
+    /// The changed fields, in alphabetical order
+    pub changes: Vec<FieldChange>,
+}
+
+/// The differences between two versions of a `Galaxy`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Comparison {
+    /// Changes to the `Galaxy` itself, e.g. its title
+    pub galaxy: Vec<FieldChange>,
+    /// Celestial bodies that only exist in the new version, lowest ID first
+    pub added: Vec<ComparedBody>,
+    /// Celestial bodies that only exist in the old version, lowest ID first
+    pub removed: Vec<ComparedBody>,
+    /// Celestial bodies that exist in both versions, lowest ID first
+    pub modified: Vec<ModifiedBody>,
+}
+
+impl Comparison {
+    /// Compares the `old` version of a `Galaxy` to the `new` one
+    ///
+    /// # Errors
+    /// Errors will occur if one of the `Galaxy`s had its history capped
+    pub fn of(old: Galaxy, new: Galaxy) -> Result<Self, DatabaseError> {
+        let old = old.into_json()?;
+        let new = new.into_json()?;
+
+        let mut comparison = Self {
+            galaxy: compare(&old, &new, &COLLECTIONS),
+            ..Self::default()
+        };
+        let (old, new) = (bodies(&old), bodies(&new));
+        for (id, (kind, body)) in &new {
+            match old.get(id) {
+                None => comparison.added.push(ComparedBody::of(*id, *kind, body)),
+                Some((_, before)) => {
+                    let changes = compare(before, body, &SKIPPED);
+                    if !changes.is_empty() {
+                        comparison.modified.push(ModifiedBody {
+                            id: *id,
+                            kind: *kind,
+                            title: title(body),
+                            changes,
+                        });
+                    }
+                }
+            }
+        }
+        comparison.removed = old
+            .iter()
+            .filter(|(id, _)| !new.contains_key(id))
+            .map(|(id, (kind, body))| ComparedBody::of(*id, *kind, body))
+            .collect();
+        Ok(comparison)
+    }
+
+    /// Whether the two versions are the same
+    pub fn is_empty(&self) -> bool {
+        self.galaxy.is_empty()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
+    }
+}
+
+impl ComparedBody {
+    /// Helper function that describes the celestial body in the database
+    fn of(id: ID, kind: CelestialBodyKind, body: &Value) -> Self {
+        Self {
+            id,
+            kind,
+            title: title(body),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that maps the IDs of the celestial bodies in the database
+/// `galaxy` to their kinds and the celestial bodies
+fn bodies(galaxy: &Value) -> BTreeMap<ID, (CelestialBodyKind, &Value)> {
+    [
+        ("comets", CelestialBodyKind::Comet),
+        ("planets", CelestialBodyKind::Planet),
+        ("stars", CelestialBodyKind::Star),
+    ]
+    .into_iter()
+    .flat_map(|(key, kind)| {
+        let list = galaxy[key].as_array().into_iter().flatten();
+        list.filter_map(move |body| Some((body["id"].as_u64()?, (kind, body))))
+    })
+    .collect()
+}
+
+/// Helper function that gives the title of a celestial body in the database
+fn title(body: &Value) -> String {
+    body["title"].as_str().unwrap_or_default().to_string()
+}
+
+/// Helper function that compares the values of two objects, except for the
+/// `skipped` ones. Nested objects (e.g. the custom fields) are compared value
+/// by value.
+fn compare(old: &Value, new: &Value, skipped: &[&str]) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    compare_into(&mut changes, "", old, new, skipped);
+    changes
+}
+
+/// Helper function that collects the changes between two objects, naming them
+/// with `prefix`
+fn compare_into(
+    changes: &mut Vec<FieldChange>,
+    prefix: &str,
+    old: &Value,
+    new: &Value,
+    skipped: &[&str],
+) {
+    let keys: BTreeSet<&String> = [old, new]
+        .into_iter()
+        .filter_map(Value::as_object)
+        .flat_map(|o| o.keys())
+        .collect();
+    for key in keys.iter().filter(|k| !skipped.contains(&k.as_str())) {
+        let field = format!("{prefix}{key}");
+        let unset = |v: Option<&Value>| v.filter(|v| !v.is_null()).cloned();
+        match (unset(old.get(key)), unset(new.get(key))) {
+            (Some(o), Some(n)) if o.is_object() && n.is_object() => {
+                compare_into(changes, &format!("{field}."), &o, &n, &[]);
+            }
+            (o, n) if o != n => changes.push(FieldChange {
+                field,
+                old: o,
+                new: n,
+            }),
+            _ => {}
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, Status};
+
+    #[test]
+    fn comparisons_find_the_changed_fields() {
+        let mut galaxy = Galaxy::default();
+        let planet = galaxy.planet().id();
+        let comet = galaxy.comet().id();
+        let base = galaxy.into_json().unwrap();
+        let copy = || Galaxy::from_json(base.clone()).unwrap();
+
+        let mut new = copy();
+        let mut body = new.get_mut(planet).unwrap();
+        body.title("Fix login".into())
+            .status(Status::Start, String::new())
+            .field("owner".into(), "sam".into());
+        new.get_mut(planet).unwrap().tag("auth".into());
+        new.delete(comet, chrono::Utc::now()).unwrap();
+        let added = new.comet().id();
+
+        let comparison = Comparison::of(copy(), new).unwrap();
+        assert!(comparison.galaxy.is_empty());
+        assert_eq!(
+            comparison.added,
+            vec![ComparedBody {
+                id: added,
+                kind: CelestialBodyKind::Comet,
+                title: String::new(),
+            }]
+        );
+        assert_eq!(comparison.removed.len(), 1);
+        assert_eq!(comparison.removed[0].id, comet);
+        assert_eq!(comparison.modified.len(), 1);
+        let modified = &comparison.modified[0];
+        assert_eq!(modified.id, planet);
+        assert_eq!(modified.title, "Fix login");
+        let fields: Vec<&str> = modified.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["fields.owner", "status", "tags", "title"]);
+        assert_eq!(modified.changes[0].old, None);
+        assert_eq!(modified.changes[0].new, Some("sam".into()));
+
+        assert!(Comparison::of(copy(), copy()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,293 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Generates sample celestial bodies, so that new users have something to
+ * explore and performance problems can be reproduced with large galaxies.
+ *
+ * The generated bodies are made up from fixed word lists with a small
+ * deterministic random number generator, so the same seed always generates
+ * the same bodies (apart from their dates, which are relative to now).
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::{DateTime, TimeDelta, Utc};
+use clap::ValueEnum;
+
+use super::{
+    CelestialBody, CelestialBodyMut, Estimate, Event, Galaxy, Priority, Status, StatusHistory, ID,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TYPES                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Names of the top level Stars
+const AREAS: [&str; 6] = [
+    "Backend",
+    "Frontend",
+    "Infrastructure",
+    "Documentation",
+    "Mobile",
+    "Design",
+];
+const VERBS: [&str; 8] = [
+    "Add", "Fix", "Refactor", "Document", "Test", "Remove", "Speed up", "Redesign",
+];
+const NOUNS: [&str; 10] = [
+    "login page",
+    "sync engine",
+    "settings screen",
+    "API client",
+    "database schema",
+    "search",
+    "onboarding flow",
+    "error messages",
+    "release pipeline",
+    "caching layer",
+];
+/// Problems used for the titles of Comets
+const PROBLEMS: [&str; 6] = [
+    "Crash",
+    "Typo",
+    "Wrong result",
+    "Timeout",
+    "Memory leak",
+    "Flaky test",
+];
+const TAGS: [&str; 7] = [
+    "backend", "frontend", "ui", "docs", "bug", "perf", "security",
+];
+const ESTIMATES: [&str; 6] = ["1pts", "2pts", "3pts", "5pts", "8pts", "4h"];
+/// How many days in the past the oldest celestial body was created
+const MAX_AGE_DAYS: i64 = 90;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A small SplitMix64 random number generator. It is not suitable for anything
+/// but sample data.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n.max(1)
+    }
+
+    /// Whether an event with a `percent` percent chance happens
+    fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Adds `bodies` sample celestial bodies to `galaxy`: roughly one Star for
+/// every ten bodies (areas at the root, milestones below them), with Planets
+/// and Comets spread between them. Each has a priority, most have tags and
+/// estimates, and their status histories are spread over the last few months.
+///
+/// # Returns
+/// The IDs of the celestial bodies that were added
+pub fn generate_demo(galaxy: &mut Galaxy, bodies: usize, seed: u64) -> Vec<ID> {
+    let mut rng = Rng(seed);
+    let now = Utc::now();
+    let mut added = Vec::with_capacity(bodies);
+
+    let stars = (bodies / 10).max(1).min(bodies);
+    let mut parents = Vec::new();
+    for n in 0..stars {
+        let id = if n < AREAS.len() {
+            galaxy
+                .star()
+                .title(AREAS[n].into())
+                .description(format!("Everything to do with {}", AREAS[n].to_lowercase()))
+                .id()
+        } else {
+            let area = parents[rng.below(AREAS.len().min(n) as u64) as usize];
+            let id = galaxy
+                .star()
+                .title(format!("Milestone {}", n - AREAS.len() + 1))
+                .id();
+            galaxy.set_parent(id, Some(area)).expect("areas are Stars");
+            id
+        };
+        parents.push(id);
+        added.push(id);
+    }
+
+    for _ in stars..bodies {
+        let parent = *rng.pick(&parents);
+        let noun = *rng.pick(&NOUNS);
+        let id = if rng.chance(20) {
+            galaxy
+                .comet()
+                .title(format!("{} in {}", rng.pick(&PROBLEMS), noun))
+                .id()
+        } else {
+            galaxy
+                .planet()
+                .title(format!("{} {}", rng.pick(&VERBS), noun))
+                .id()
+        };
+        galaxy
+            .set_parent(id, Some(parent))
+            .expect("parents are Stars");
+
+        if let Some(mut body) = galaxy.get_mut(id) {
+            body.priority(*rng.pick(Priority::value_variants()));
+            for _ in 0..rng.below(3) {
+                body.tag(rng.pick(&TAGS).to_string());
+            }
+            if rng.chance(60) {
+                body.estimate(rng.pick(&ESTIMATES).parse::<Estimate>().ok());
+            }
+            for status in path(rng.below(100)) {
+                body.status(*status, String::new());
+            }
+        }
+        added.push(id);
+    }
+
+    for id in &added {
+        let created = now - TimeDelta::minutes(rng.below(MAX_AGE_DAYS as u64 * 24 * 60) as i64);
+        match galaxy.get_mut(*id) {
+            Some(CelestialBodyMut::Comet(c)) => {
+                backdate(&mut c.history, &mut c.events, created, now)
+            }
+            Some(CelestialBodyMut::Planet(p)) => {
+                backdate(&mut p.history, &mut p.events, created, now)
+            }
+            Some(CelestialBodyMut::Star(s)) => {
+                backdate(&mut s.history, &mut s.events, created, now)
+            }
+            None => {}
+        }
+    }
+
+    added
+}
+
+/// Helper function that picks the statuses a celestial body went through,
+/// given a number in `0..100`. Most end up not started or done.
+fn path(roll: u64) -> &'static [Status] {
+    match roll {
+        0..35 => &[],
+        35..45 => &[Status::Next],
+        45..60 => &[Status::Next, Status::Start],
+        60..65 => &[Status::Next, Status::Start, Status::Block],
+        65..70 => &[Status::Start, Status::Hold],
+        70..95 => &[Status::Next, Status::Start, Status::Done],
+        _ => &[Status::Cancel],
+    }
+}
+
+/// Helper function that moves the events of a celestial body to when it was
+/// `created`, and spreads its status changes out evenly between then and `now`
+fn backdate(
+    history: &mut [StatusHistory],
+    events: &mut [Event],
+    created: DateTime<Utc>,
+    now: DateTime<Utc>,
+) {
+    for event in events.iter_mut() {
+        event.time = created;
+    }
+    let step = (now - created) / (history.len() as i32 + 1);
+    for (i, change) in history.iter_mut().enumerate() {
+        change.time = created + step * (i as i32 + 1);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::CelestialBodyKind;
+
+    #[test]
+    fn generated_galaxies_are_varied_and_reproducible() {
+        let mut galaxy = Galaxy::default();
+        let ids = generate_demo(&mut galaxy, 200, 7);
+        assert_eq!(ids.len(), 200);
+        assert_eq!(galaxy.iter().count(), 200);
+
+        let count = |kind| galaxy.iter().filter(|b| b.kind() == kind).count();
+        assert_eq!(count(CelestialBodyKind::Star), 20);
+        assert!(count(CelestialBodyKind::Planet) > count(CelestialBodyKind::Comet));
+        assert!(count(CelestialBodyKind::Comet) > 0);
+        assert!(galaxy.iter().any(|b| b.status() == Status::Done));
+        assert!(galaxy.iter().any(|b| !b.tags().is_empty()));
+
+        let now = Utc::now();
+        for body in galaxy.iter() {
+            let times: Vec<_> = body.history().iter().map(|h| h.time).collect();
+            assert!(times.is_sorted());
+            assert!(times.iter().all(|t| *t <= now));
+        }
+
+        let titles = |galaxy: &Galaxy| {
+            galaxy
+                .iter()
+                .map(|b| b.title().to_string())
+                .collect::<Vec<_>>()
+        };
+        let mut again = Galaxy::default();
+        generate_demo(&mut again, 200, 7);
+        assert_eq!(titles(&galaxy), titles(&again));
+    }
+}
@@ -0,0 +1,230 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing effort estimates for celestial bodies and the rollup of
+ * those estimates across the descendants of a `Star`.
+ *
+ * Estimates are either story points (e.g. `3`, `3pts`) or a duration (e.g.
+ * `30m`, `2h`, `1d`). A day is considered to be 8 hours of work. Points and
+ * durations are never mixed, so a rollup keeps separate totals for each.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The estimated effort of a celestial body
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Estimate {
+    /// Abstract story points
+    Points(u64),
+    /// A duration in minutes
+    Minutes(u64),
+}
+
+impl Estimate {
+    const MINUTES_PER_HOUR: u64 = 60;
+    const MINUTES_PER_DAY: u64 = 8 * Estimate::MINUTES_PER_HOUR;
+}
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Estimate::Points(points) => write!(f, "{points} pts"),
+            Estimate::Minutes(minutes) => write!(f, "{}", util::time::hours_minutes(*minutes)),
+        }
+    }
+}
+
+impl FromStr for Estimate {
+    type Err = EstimateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+        let number: u64 = number
+            .parse()
+            .map_err(|_| EstimateError::InvalidEstimate(s.clone()))?;
+
+        let invalid = || EstimateError::InvalidEstimate(s.clone());
+        match unit.trim() {
+            "" | "p" | "pt" | "pts" | "points" => Ok(Estimate::Points(number)),
+            "m" | "min" | "mins" => Ok(Estimate::Minutes(number)),
+            "h" | "hr" | "hrs" => number
+                .checked_mul(Estimate::MINUTES_PER_HOUR)
+                .map(Estimate::Minutes)
+                .ok_or_else(invalid),
+            "d" | "day" | "days" => number
+                .checked_mul(Estimate::MINUTES_PER_DAY)
+                .map(Estimate::Minutes)
+                .ok_or_else(invalid),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Possible errors when parsing an estimate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EstimateError {
+    InvalidEstimate(String),
+}
+
+impl std::error::Error for EstimateError {}
+
+impl fmt::Display for EstimateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EstimateError::InvalidEstimate(s) => write!(
+                f,
+                "Invalid estimate \"{s}\", expected points (e.g. 3pts) or a duration (e.g. 2h)"
+            ),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The total and completed estimates of a group of celestial bodies
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Rollup {
+    pub done_points: u64,
+    pub total_points: u64,
+    pub done_minutes: u64,
+    pub total_minutes: u64,
+}
+
+impl Rollup {
+    /// Adds `estimate` to the totals, also counting it as completed if `done`
+    pub fn add(&mut self, estimate: Estimate, done: bool) {
+        match estimate {
+            Estimate::Points(points) => {
+                self.total_points = self.total_points.saturating_add(points);
+                if done {
+                    self.done_points = self.done_points.saturating_add(points);
+                }
+            }
+            Estimate::Minutes(minutes) => {
+                self.total_minutes = self.total_minutes.saturating_add(minutes);
+                if done {
+                    self.done_minutes = self.done_minutes.saturating_add(minutes);
+                }
+            }
+        }
+    }
+
+    /// The remaining (not completed) points
+    pub fn remaining_points(&self) -> u64 {
+        self.total_points - self.done_points
+    }
+
+    /// The remaining (not completed) minutes
+    pub fn remaining_minutes(&self) -> u64 {
+        self.total_minutes - self.done_minutes
+    }
+
+    /// Whether or not nothing has been estimated
+    pub fn is_empty(&self) -> bool {
+        self.total_points == 0 && self.total_minutes == 0
+    }
+}
+
+impl fmt::Display for Rollup {
+    /// Formats the rollup as completed / total (e.g. `[3/8 pts] [1h/4h]`).
+    /// Units without any estimates are left out.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if self.total_points > 0 {
+            parts.push(format!("[{}/{} pts]", self.done_points, self.total_points));
+        }
+        if self.total_minutes > 0 {
+            parts.push(format!(
+                "[{}/{}]",
+                util::time::hours_minutes(self.done_minutes),
+                util::time::hours_minutes(self.total_minutes)
+            ));
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn estimates_are_parsed_with_units() {
+        assert_eq!("3".parse(), Ok(Estimate::Points(3)));
+        assert_eq!("5pts".parse(), Ok(Estimate::Points(5)));
+        assert_eq!("45m".parse(), Ok(Estimate::Minutes(45)));
+        assert_eq!("2h".parse(), Ok(Estimate::Minutes(120)));
+        assert_eq!("1d".parse(), Ok(Estimate::Minutes(480)));
+        assert!("2 weeks".parse::<Estimate>().is_err());
+        assert!("h".parse::<Estimate>().is_err());
+        assert!("99999999999999999d".parse::<Estimate>().is_err());
+    }
+
+    #[test]
+    fn rollup_is_formatted_per_unit() {
+        let mut rollup = Rollup::default();
+        assert_eq!(rollup.to_string(), "");
+
+        rollup.add(Estimate::Points(3), true);
+        rollup.add(Estimate::Points(5), false);
+        assert_eq!(rollup.to_string(), "[3/8 pts]");
+        assert_eq!(rollup.remaining_points(), 5);
+
+        rollup.add(Estimate::Minutes(90), false);
+        assert_eq!(rollup.to_string(), "[3/8 pts] [0m/1h30m]");
+
+        rollup.add(Estimate::Points(u64::MAX), false);
+        assert_eq!(rollup.total_points, u64::MAX);
+    }
+}
@@ -0,0 +1,236 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the activity log of celestial bodies. While the status
+ * history only records changes to the status, the activity log records every
+ * other change (e.g. editing the title, moving to a different parent, or
+ * adding a tag) so that it is possible to see what changed and when.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Estimate, Priority, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single change to a celestial body
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Change {
+    /// The celestial body was created
+    Created,
+    /// The title was changed
+    Title { old: String, new: String },
+    /// The description was changed. The text itself is not kept
+    Description,
+    /// The celestial body was moved, `None` is the root of the galaxy
+    Parent { old: Option<ID>, new: Option<ID> },
+    /// The priority was changed
+    Priority { old: Priority, new: Priority },
+    /// The estimate was set, changed, or cleared
+    Estimate {
+        old: Option<Estimate>,
+        new: Option<Estimate>,
+    },
+    /// The celestial body was archived
+    Archived,
+    /// The celestial body was unarchived
+    Unarchived,
+    /// The celestial body was pinned to the focus view
+    Pinned,
+    /// The celestial body was unpinned from the focus view
+    Unpinned,
+    /// The celestial body was snoozed, hiding it from views until `until`
+    Snoozed { until: NaiveDate },
+    /// The celestial body was unsnoozed before its snooze ended
+    Unsnoozed,
+    /// The snooze ended, showing the celestial body in views again
+    Woke { until: NaiveDate },
+    /// The celestial body was moved to the trash
+    Deleted,
+    /// The celestial body was restored from the trash
+    Restored,
+    /// A tag was added to a Planet
+    TagAdded(String),
+    /// A tag was removed from a Planet
+    TagRemoved(String),
+    /// A custom field was set or removed, `None` means it was not set
+    Field {
+        key: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+    /// A tag was renamed across the whole galaxy
+    TagRenamed { old: String, new: String },
+    /// A custom field was renamed across the whole galaxy
+    FieldRenamed { old: String, new: String },
+    /// The celestial body was not finished on the day it was planned for, so
+    /// it was added to the plan of a later day
+    CarriedOver { from: NaiveDate, to: NaiveDate },
+    /// The due date passed before the celestial body was finished
+    Overdue { due: NaiveDate },
+    /// The celestial body was not finished during a sprint, so it was added
+    /// to the next one
+    SprintCarriedOver { from: String, to: String },
+    /// Someone was assigned to the celestial body
+    Assigned(String),
+    /// Someone was no longer assigned to the celestial body
+    Unassigned(String),
+    /// A link to a URL or file was added to the celestial body
+    Linked(String),
+    /// A link to a URL or file was removed from the celestial body
+    Unlinked(String),
+    /// A git commit (given by its hash) referencing the celestial body was
+    /// attached to it
+    Committed(String),
+    /// The reason for being blocked or on hold was set, or removed if `None`
+    Reason(Option<String>),
+    /// The celestial body was moved to a status of the workflow, or back to
+    /// its built in status if `None`
+    Stage(Option<String>),
+    /// An item was added to the checklist
+    ChecklistAdded(String),
+    /// An item was removed from the checklist
+    ChecklistRemoved(String),
+    /// A checklist item was checked off
+    Checked(String),
+    /// A checklist item was no longer checked off
+    Unchecked(String),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parent = |p: &Option<ID>| match p {
+            Some(id) => format!("#{id}"),
+            None => "the root".to_string(),
+        };
+        let estimate = |e: &Option<Estimate>| match e {
+            Some(e) => e.to_string(),
+            None => "nothing".to_string(),
+        };
+
+        match self {
+            Change::Created => write!(f, "Created"),
+            Change::Title { old, new } => write!(f, "Title changed from \"{old}\" to \"{new}\""),
+            Change::Description => write!(f, "Description edited"),
+            Change::Parent { old, new } => {
+                write!(f, "Moved from {} to {}", parent(old), parent(new))
+            }
+            Change::Priority { old, new } => write!(f, "Priority changed from {old} to {new}"),
+            Change::Estimate { old, new } => write!(
+                f,
+                "Estimate changed from {} to {}",
+                estimate(old),
+                estimate(new)
+            ),
+            Change::Archived => write!(f, "Archived"),
+            Change::Unarchived => write!(f, "Unarchived"),
+            Change::Pinned => write!(f, "Pinned"),
+            Change::Unpinned => write!(f, "Unpinned"),
+            Change::Snoozed { until } => write!(f, "Snoozed until {until}"),
+            Change::Unsnoozed => write!(f, "Unsnoozed"),
+            Change::Woke { until } => write!(f, "Snooze until {until} ended"),
+            Change::Deleted => write!(f, "Deleted"),
+            Change::Restored => write!(f, "Restored from the trash"),
+            Change::TagAdded(tag) => write!(f, "Tag \"{tag}\" added"),
+            Change::TagRemoved(tag) => write!(f, "Tag \"{tag}\" removed"),
+            Change::Assigned(name) => write!(f, "Assigned to {name}"),
+            Change::Unassigned(name) => write!(f, "No longer assigned to {name}"),
+            Change::Linked(target) => write!(f, "Linked to {target}"),
+            Change::Unlinked(target) => write!(f, "No longer linked to {target}"),
+            Change::Committed(hash) => {
+                write!(f, "Referenced by commit {}", &hash[..hash.len().min(7)])
+            }
+            Change::Reason(Some(reason)) => write!(f, "Reason set to \"{reason}\""),
+            Change::Reason(None) => write!(f, "Reason removed"),
+            Change::Stage(Some(stage)) => write!(f, "Status set to {stage}"),
+            Change::Stage(None) => write!(f, "Custom status removed"),
+            Change::ChecklistAdded(text) => write!(f, "Checklist item \"{text}\" added"),
+            Change::ChecklistRemoved(text) => write!(f, "Checklist item \"{text}\" removed"),
+            Change::Checked(text) => write!(f, "Checked off \"{text}\""),
+            Change::Unchecked(text) => write!(f, "Unchecked \"{text}\""),
+            Change::Field { key, new: None, .. } => write!(f, "Field \"{key}\" removed"),
+            Change::Field {
+                key,
+                new: Some(new),
+                ..
+            } => write!(f, "Field \"{key}\" set to \"{new}\""),
+            Change::TagRenamed { old, new } => {
+                write!(f, "Tag \"{old}\" renamed to \"{new}\"")
+            }
+            Change::FieldRenamed { old, new } => {
+                write!(f, "Field \"{old}\" renamed to \"{new}\"")
+            }
+            Change::CarriedOver { from, to } => {
+                write!(f, "Unfinished on {from}, carried over to {to}")
+            }
+            Change::Overdue { due } => write!(f, "Overdue, was due on {due}"),
+            Change::SprintCarriedOver { from, to } => {
+                write!(
+                    f,
+                    "Unfinished in sprint \"{from}\", carried over to \"{to}\""
+                )
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A change to a celestial body and when it happened
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Event {
+    /// When the change happened
+    pub time: DateTime<Utc>,
+    /// What changed
+    pub change: Change,
+}
+
+impl Event {
+    /// Creates an event for `change` happening now
+    pub fn now(change: Change) -> Self {
+        Self {
+            time: Utc::now(),
+            change,
+        }
+    }
+}
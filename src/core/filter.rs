@@ -0,0 +1,559 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the filter language used to select celestial bodies.
+ *
+ * A filter is a whitespace separated list of terms. A celestial body matches
+ * the filter only if it matches every term. Each term is one of the following:
+ *
+ * | **Term**          | **Matches**                                         |
+ * |:------------------|:----------------------------------------------------|
+ * | `status:<s>`      | Bodies with the status `<s>`                        |
+ * | `stage:<s>`       | Bodies with the status `<s>` of the workflow (e.g. `review`) |
+ * | `priority:<p>`    | Bodies with the priority `<p>`                      |
+ * | `kind:<k>`        | Bodies of the kind `<k>` (`comet`, `planet`, `star`) |
+ * | `tag:<t>`         | Bodies with the tag `<t>`                           |
+ * | `field:<k>=<v>`   | Bodies with the field `<k>` set to `<v>`            |
+ * | `assignee:<name>` | Bodies assigned to `<name>` (`none` for nobody)     |
+ * | `parent:<id>`     | Bodies directly owned by `<id>` (`none` for root)   |
+ * | `id:<id>`         | The body with the id `<id>`                         |
+ * | `reason:<word>`   | Bodies blocked or on hold for a reason containing `<word>` (`any` for any reason) |
+ * | `blocked-by:<id>` | Bodies blocked or on hold until `<id>` is finished  |
+ * | `stale:<date>`    | Unfinished bodies without any activity since `<date>` (e.g. `14d`) |
+ * | `pinned:<yes/no>` | Bodies that are (or are not) pinned to the focus view |
+ * | `snoozed:<yes/no>` | Bodies that are (or are not) snoozed until a later day |
+ * | `<word>`          | Bodies whose title contains `<word>` (ignoring case) |
+ *
+ * The value of any `key:value` term may be a comma separated list, in which
+ * case the term matches if any of the values match (e.g. `status:todo,next`).
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::BTreeSet, fmt, str::FromStr};
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use clap::ValueEnum;
+
+use crate::util;
+
+use super::{index::Indexes, CelestialBodyKind, CelestialBodyRef, Priority, Status, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Possible errors when parsing a filter
+#[derive(Debug, PartialEq, Eq)]
+pub enum FilterError {
+    /// The key of a `key:value` term is not recognized
+    UnknownKey(String),
+    /// The value of a `key:value` term is not valid for the key
+    InvalidValue(String, String),
+}
+
+impl std::error::Error for FilterError {}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterError::UnknownKey(key) => write!(f, "Unknown filter key: {key}"),
+            FilterError::InvalidValue(key, value) => {
+                write!(f, "Invalid value for filter key {key}: {value}")
+            }
+        }
+    }
+}
+
+/// A single term of a filter. Terms containing a `Vec` match if any of the
+/// values match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Status(Vec<Status>),
+    Priority(Vec<Priority>),
+    Kind(Vec<CelestialBodyKind>),
+    Tag(Vec<String>),
+    Field(Vec<(String, String)>),
+    /// `None` matches celestial bodies without assignees
+    Assignee(Vec<Option<String>>),
+    Parent(Vec<Option<ID>>),
+    Id(Vec<ID>),
+    /// `None` matches any reason
+    Reason(Vec<Option<String>>),
+    BlockedBy(Vec<ID>),
+    /// The names of statuses of the workflow
+    Stage(Vec<String>),
+    /// The start of the day since which there was no activity
+    Stale(DateTime<Utc>),
+    Pinned(bool),
+    /// Whether the body is snoozed on the day the filter was parsed
+    Snoozed(bool, NaiveDate),
+    Title(String),
+}
+
+impl Term {
+    /// Whether or not `body` matches this term
+    fn matches(&self, body: &CelestialBodyRef) -> bool {
+        match self {
+            Term::Status(statuses) => statuses.contains(&body.status()),
+            Term::Priority(priorities) => priorities.contains(&body.priority()),
+            Term::Kind(kinds) => kinds.contains(&body.kind()),
+            Term::Tag(tags) => tags.iter().any(|tag| body.tags().contains(tag)),
+            Term::Field(fields) => fields.iter().any(|(key, value)| {
+                body.fields()
+                    .and_then(|fields| fields.get(key))
+                    .is_some_and(|v| v == value)
+            }),
+            Term::Assignee(names) => names.iter().any(|name| match name {
+                Some(name) => body.is_assigned_to(name),
+                None => body.assignees().is_empty(),
+            }),
+            Term::Parent(parents) => parents.contains(&body.parent()),
+            Term::Id(ids) => ids.contains(&body.id()),
+            Term::Reason(words) => body.blocker().is_some_and(|blocker| {
+                words.iter().any(|word| match word {
+                    Some(word) => blocker.mentions(word),
+                    None => true,
+                })
+            }),
+            Term::BlockedBy(ids) => body
+                .blocker()
+                .and_then(|blocker| blocker.by)
+                .is_some_and(|id| ids.contains(&id)),
+            Term::Stage(names) => {
+                let status = body.status().to_string();
+                let stage = body.stage().unwrap_or(&status);
+                names.iter().any(|name| name.eq_ignore_ascii_case(stage))
+            }
+            Term::Stale(since) => body.is_stale(*since),
+            Term::Pinned(pinned) => body.pinned() == *pinned,
+            Term::Snoozed(snoozed, today) => body.is_snoozed(*today) == *snoozed,
+            Term::Title(word) => body.title().to_lowercase().contains(word),
+        }
+    }
+
+    /// The celestial bodies that can match this term according to `indexes`,
+    /// `None` if the term is not indexed
+    fn candidates(&self, indexes: &Indexes) -> Option<BTreeSet<ID>> {
+        let ids = match self {
+            Term::Status(statuses) => statuses
+                .iter()
+                .flat_map(|status| indexes.with_status(*status))
+                .collect(),
+            Term::Tag(tags) => tags.iter().flat_map(|tag| indexes.with_tag(tag)).collect(),
+            Term::Parent(parents) => parents
+                .iter()
+                .flat_map(|parent| indexes.with_parent(*parent))
+                .collect(),
+            Term::Id(ids) => ids.iter().copied().collect(),
+            _ => return None,
+        };
+        Some(ids)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A parsed filter. See the module documentation for the syntax.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    terms: Vec<Term>,
+}
+
+impl Filter {
+    /// Whether or not `body` matches every term of the filter. An empty filter
+    /// matches everything.
+    pub fn matches(&self, body: &CelestialBodyRef) -> bool {
+        self.terms.iter().all(|term| term.matches(body))
+    }
+
+    /// The celestial bodies that can match the filter according to `indexes`,
+    /// which is the intersection over the indexed terms. `None` if no term is
+    /// indexed, in which case every celestial body has to be looked at.
+    pub(super) fn candidates(&self, indexes: &Indexes) -> Option<BTreeSet<ID>> {
+        self.terms
+            .iter()
+            .filter_map(|term| term.candidates(indexes))
+            .reduce(|a, b| a.intersection(&b).copied().collect())
+    }
+
+    /// Whether or not the filter has no terms
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Creates a filter that only matches the celestial bodies in `ids`, the
+    /// same as `id:<ids>`
+    pub fn ids(ids: Vec<ID>) -> Self {
+        Self {
+            terms: vec![Term::Id(ids)],
+        }
+    }
+
+    /// Creates a filter that only matches the celestial bodies assigned to
+    /// `name`, the same as `assignee:<name>`
+    pub fn assignee(name: &str) -> Self {
+        Self {
+            terms: vec![Term::Assignee(vec![Some(name.to_string())])],
+        }
+    }
+
+    /// Creates a filter that only matches the unfinished celestial bodies
+    /// without any activity since `since`, the same as `stale:<date>`
+    pub fn stale(since: DateTime<Utc>) -> Self {
+        Self {
+            terms: vec![Term::Stale(since)],
+        }
+    }
+
+    /// Combines the filter with `other`, matching only the celestial bodies
+    /// that match both
+    pub fn and(mut self, other: Filter) -> Self {
+        self.terms.extend(other.terms);
+        self
+    }
+
+    /// Rewrites the unparsed filter `filter` so that `tag:` terms refer to the
+    /// tag `new` instead of `old`. Everything else is kept as is.
+    pub fn rename_tag(filter: &str, old: &str, new: &str) -> String {
+        rename_values(filter, "tag", |value| match value == old {
+            true => new.to_string(),
+            false => value.to_string(),
+        })
+    }
+
+    /// Rewrites the unparsed filter `filter` so that `field:` terms refer to
+    /// the field `new` instead of `old`. Everything else is kept as is.
+    pub fn rename_field(filter: &str, old: &str, new: &str) -> String {
+        rename_values(filter, "field", |value| match value.split_once('=') {
+            Some((key, value)) if key == old => format!("{new}={value}"),
+            _ => value.to_string(),
+        })
+    }
+}
+
+/// Helper function that applies `rename` to every value of the `key:value`
+/// terms of the unparsed filter `filter` with the key `key`
+fn rename_values(filter: &str, key: &str, rename: impl Fn(&str) -> String) -> String {
+    filter
+        .split_whitespace()
+        .map(|word| match word.split_once(':') {
+            Some((k, values)) if k.eq_ignore_ascii_case(key) => {
+                let values: Vec<_> = values.split(',').map(&rename).collect();
+                format!("{k}:{}", values.join(","))
+            }
+            _ => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl FromStr for Filter {
+    type Err = FilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut terms = Vec::new();
+
+        for word in s.split_whitespace() {
+            let Some((key, value)) = word.split_once(':') else {
+                terms.push(Term::Title(word.to_lowercase()));
+                continue;
+            };
+
+            let invalid = || FilterError::InvalidValue(key.to_string(), value.to_string());
+            let values = value.split(',');
+            let term = match key.to_lowercase().as_str() {
+                "status" => Term::Status(
+                    values
+                        .map(|v| Status::from_str(v, true).map_err(|_| invalid()))
+                        .collect::<Result<_, _>>()?,
+                ),
+                "priority" => Term::Priority(
+                    values
+                        .map(|v| Priority::from_str(v, true).map_err(|_| invalid()))
+                        .collect::<Result<_, _>>()?,
+                ),
+                "kind" => Term::Kind(
+                    values
+                        .map(|v| CelestialBodyKind::from_str(v, true).map_err(|_| invalid()))
+                        .collect::<Result<_, _>>()?,
+                ),
+                "tag" => Term::Tag(values.map(String::from).collect()),
+                "field" => Term::Field(
+                    values
+                        .map(|v| {
+                            v.split_once('=')
+                                .map(|(k, v)| (k.to_string(), v.to_string()))
+                                .ok_or_else(invalid)
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                "assignee" => Term::Assignee(
+                    values
+                        .map(|v| match v.to_lowercase().as_str() {
+                            "none" => None,
+                            _ => Some(v.to_string()),
+                        })
+                        .collect(),
+                ),
+                "parent" => Term::Parent(
+                    values
+                        .map(|v| match v.to_lowercase().as_str() {
+                            "none" => Ok(None),
+                            _ => v.parse().map(Some).map_err(|_| invalid()),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                "id" => Term::Id(
+                    values
+                        .map(|v| v.parse().map_err(|_| invalid()))
+                        .collect::<Result<_, _>>()?,
+                ),
+                "reason" => Term::Reason(
+                    values
+                        .map(|v| match v.to_lowercase().as_str() {
+                            "any" => None,
+                            _ => Some(v.to_string()),
+                        })
+                        .collect(),
+                ),
+                "blocked-by" => Term::BlockedBy(
+                    values
+                        .map(|v| v.parse().map_err(|_| invalid()))
+                        .collect::<Result<_, _>>()?,
+                ),
+                "stage" => Term::Stage(values.map(String::from).collect()),
+                "stale" => Term::Stale(util::date::start_of(
+                    util::date::parse_from_today(value).map_err(|_| invalid())?,
+                )),
+                "pinned" => match value.to_lowercase().as_str() {
+                    "yes" | "true" => Term::Pinned(true),
+                    "no" | "false" => Term::Pinned(false),
+                    _ => return Err(invalid()),
+                },
+                "snoozed" => {
+                    let today = Local::now().date_naive();
+                    match value.to_lowercase().as_str() {
+                        "yes" | "true" => Term::Snoozed(true, today),
+                        "no" | "false" => Term::Snoozed(false, today),
+                        _ => return Err(invalid()),
+                    }
+                }
+                _ => return Err(FilterError::UnknownKey(key.to_string())),
+            };
+            terms.push(term);
+        }
+
+        Ok(Filter { terms })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::core::{Blocker, Change, Event, Planet, Star};
+
+    use super::*;
+
+    fn planet() -> Planet {
+        Planet {
+            id: 1,
+            parent: Some(3),
+            title: "Fix the Login page".into(),
+            status: Status::Next,
+            priority: Priority::High,
+            tags: vec!["backend".into(), "sprint-12".into()],
+            fields: HashMap::from([("owner".into(), "jacob".into())]),
+            assignees: vec!["Alice".into()],
+            ..Planet::default()
+        }
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let filter: Filter = "".parse().unwrap();
+        let planet = planet();
+        assert!(filter.is_empty());
+        assert!(filter.matches(&CelestialBodyRef::Planet(&planet)));
+    }
+
+    #[test]
+    fn filter_terms_must_all_match() {
+        let planet = planet();
+        let body = CelestialBodyRef::Planet(&planet);
+
+        let filter: Filter = "tag:sprint-12 status:next,start".parse().unwrap();
+        assert!(filter.matches(&body));
+
+        let filter: Filter = "tag:sprint-12 status:done".parse().unwrap();
+        assert!(!filter.matches(&body));
+
+        let filter: Filter = "login kind:planet field:owner=jacob parent:3"
+            .parse()
+            .unwrap();
+        assert!(filter.matches(&body));
+
+        let filter: Filter = "logout".parse().unwrap();
+        assert!(!filter.matches(&body));
+
+        let filter: Filter = "priority:critical,high".parse().unwrap();
+        assert!(filter.matches(&body));
+
+        let filter: Filter = "assignee:bob,alice".parse().unwrap();
+        assert!(filter.matches(&body));
+        assert!(!"assignee:none".parse::<Filter>().unwrap().matches(&body));
+    }
+
+    #[test]
+    fn filter_matches_bodies_without_tags() {
+        let star = Star {
+            id: 3,
+            ..Star::default()
+        };
+        let body = CelestialBodyRef::Star(&star);
+
+        assert!("parent:none id:3".parse::<Filter>().unwrap().matches(&body));
+        assert!(!"tag:backend".parse::<Filter>().unwrap().matches(&body));
+        assert!("assignee:none".parse::<Filter>().unwrap().matches(&body));
+        assert!(!"field:owner=jacob"
+            .parse::<Filter>()
+            .unwrap()
+            .matches(&body));
+    }
+
+    #[test]
+    fn filter_matches_reasons_for_being_blocked() {
+        let blocked = Planet {
+            status: Status::Block,
+            blocker: Some(Blocker {
+                reason: "Needs the new API".into(),
+                by: Some(7),
+                reference: Some("VEND-12".into()),
+            }),
+            ..planet()
+        };
+        let body = CelestialBodyRef::Planet(&blocked);
+
+        for filter in [
+            "reason:any",
+            "reason:api",
+            "reason:vend-12",
+            "blocked-by:3,7",
+        ] {
+            assert!(filter.parse::<Filter>().unwrap().matches(&body), "{filter}");
+        }
+        assert!(!"reason:vendor".parse::<Filter>().unwrap().matches(&body));
+        assert!(!"blocked-by:3".parse::<Filter>().unwrap().matches(&body));
+        let planet = planet();
+        assert!(!"reason:any"
+            .parse::<Filter>()
+            .unwrap()
+            .matches(&CelestialBodyRef::Planet(&planet)));
+    }
+
+    #[test]
+    fn filter_matches_bodies_without_recent_activity() {
+        let now = Utc::now();
+        let idle = Planet {
+            events: vec![
+                Event {
+                    time: now - chrono::TimeDelta::days(30),
+                    change: Change::Created,
+                },
+                // Recorded by planit on its own, so it is not activity
+                Event {
+                    time: now,
+                    change: Change::Overdue {
+                        due: now.date_naive(),
+                    },
+                },
+            ],
+            ..planet()
+        };
+        let filter: Filter = "stale:14d".parse().unwrap();
+        assert!(filter.matches(&CelestialBodyRef::Planet(&idle)));
+
+        let changed = Planet {
+            updated_at: Some(now),
+            ..idle.clone()
+        };
+        assert!(!filter.matches(&CelestialBodyRef::Planet(&changed)));
+        let done = Planet {
+            status: Status::Done,
+            ..idle
+        };
+        assert!(!filter.matches(&CelestialBodyRef::Planet(&done)));
+        assert!("stale:soon".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn invalid_filters_produce_errors() {
+        assert_eq!(
+            "color:red".parse::<Filter>(),
+            Err(FilterError::UnknownKey("color".into()))
+        );
+        assert_eq!(
+            "status:finished".parse::<Filter>(),
+            Err(FilterError::InvalidValue(
+                "status".into(),
+                "finished".into()
+            ))
+        );
+        assert_eq!(
+            "field:owner".parse::<Filter>(),
+            Err(FilterError::InvalidValue("field".into(), "owner".into()))
+        );
+    }
+
+    #[test]
+    fn tags_and_fields_can_be_renamed() {
+        assert_eq!(
+            Filter::rename_tag("bug tag:bug,ui status:todo", "bug", "defect"),
+            "bug tag:defect,ui status:todo"
+        );
+        assert_eq!(
+            Filter::rename_field("field:owner=jacob,team=ui", "owner", "assignee"),
+            "field:assignee=jacob,team=ui"
+        );
+    }
+}
@@ -35,18 +35,41 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 use std::{
-    collections::HashMap,
-    env, fmt, fs, io,
+    cell::{Cell, RefCell},
+    collections::{hash_map::RandomState, HashMap, HashSet},
+    env, fmt, fs,
+    hash::{BuildHasher, Hasher},
+    io, mem,
     path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::SystemTime,
 };
 
-use colored::Colorize;
-use log::info;
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Local, NaiveDate, TimeDelta, Utc};
+use clap::ValueEnum;
+use colored::{ColoredString, Colorize};
+use log::{info, warn};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use serde_path_to_error::Segment;
 
-use crate::util::{self, tree::PrintTreeNode};
+use crate::util::{
+    self,
+    theme::Themed,
+    tree::{Column, Descriptions, PrintTreeNode},
+};
 
-use super::{CelestialBody, CelestialBodyKind, Comet, Planet, Star, ID};
+use super::{
+    hooks::{self, Notification, Trigger},
+    index::Indexes,
+    rules::{self, Rules},
+    schema,
+    storage::{self, Layout, Storage},
+    workflow::{Stage, Workflow},
+    CelestialBody, CelestialBodyKind, CelestialBodyMut, CelestialBodyRef, Change, ChecklistItem,
+    Comet, DayPlan, Event, Filter, IdFormat, IdScheme, Planet, Progress, Retro, Rollup, Sort,
+    Sprint, Star, Status, TrashEntry, ID,
+};
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -56,6 +79,24 @@ use super::{CelestialBody, CelestialBodyKind, Comet, Planet, Star, ID};
 
 type Result<T> = std::result::Result<T, DatabaseError>;
 
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+thread_local! {
+    /// The `Galaxy` that `Galaxy::load` and `Galaxy::save` use instead of the
+    /// database while a batch is running, see `Galaxy::begin_batch`
+    static BATCH: RefCell<Option<Galaxy>> = const { RefCell::new(None) };
+    /// How many batches have been started and not ended yet
+    static BATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Whether `Galaxy::load` skips the parts of the database that cannot be
+/// parsed instead of failing, set once with `Galaxy::set_lenient`
+static LENIENT: AtomicBool = AtomicBool::new(false);
+
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
 //                                   ENUMS                                    //
@@ -69,10 +110,24 @@ pub enum DatabaseError {
     DatabaseNotFound(String),
     /// The specified database already exists
     DatabaseAlreadyExists(String),
+    /// A database already exists in a parent directory, which a new database
+    /// would shadow
+    NestedDatabase(String),
     /// An error occurred while performing an filesystem operation
     FileSystemError(io::Error),
     /// An error occurrd while parsing the database
     ParsingError(serde_json::Error),
+    /// The database was parsed, but one of its values is not valid
+    InvalidValue(ParseIssue),
+    /// The database is larger than `Database::MAX_SIZE` bytes
+    DatabaseTooLarge(u64),
+    /// The database parsed, but its contents are inconsistent
+    CorruptDatabase(String),
+    /// The history of the `Galaxy` was capped after loading, so saving it
+    /// would lose the rest of the history
+    HistoryCapped,
+    /// An error occurred while reading or writing a TOML or YAML database
+    FormatError(Format, String),
 }
 
 impl std::error::Error for DatabaseError {}
@@ -86,12 +141,31 @@ impl fmt::Display for DatabaseError {
             DatabaseError::DatabaseAlreadyExists(name) => {
                 write!(f, "Database already exists: {name}")
             }
+            DatabaseError::NestedDatabase(name) => write!(
+                f,
+                "Database already exists in a parent directory: {name} (use --nested to create one here anyway)"
+            ),
             DatabaseError::FileSystemError(io_error) => {
                 write!(f, "Database file system error: {io_error}")
             }
             DatabaseError::ParsingError(json_error) => {
                 write!(f, "Database parsing error: {json_error}")
             }
+            DatabaseError::InvalidValue(issue) => {
+                write!(f, "Database has an invalid value at {issue}")
+            }
+            DatabaseError::DatabaseTooLarge(max) => {
+                write!(f, "Database is larger than the limit of {max} bytes")
+            }
+            DatabaseError::CorruptDatabase(reason) => {
+                write!(f, "Database is corrupt: {reason}")
+            }
+            DatabaseError::FormatError(format, message) => {
+                write!(f, "Database {format} error: {message}")
+            }
+            DatabaseError::HistoryCapped => {
+                write!(f, "Database cannot be saved after its history was capped")
+            }
         }
     }
 }
@@ -108,12 +182,187 @@ impl From<serde_json::Error> for DatabaseError {
     }
 }
 
+/// The file formats the database can be stored in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// `.planit.json`
+    #[default]
+    Json,
+    /// `.planit.toml`
+    Toml,
+    /// `.planit.yaml`
+    Yaml,
+}
+
+impl Format {
+    /// The name of the database file in this format
+    pub fn filename(&self) -> &'static str {
+        match self {
+            Self::Json => ".planit.json",
+            Self::Toml => ".planit.toml",
+            Self::Yaml => ".planit.yaml",
+        }
+    }
+
+    /// The extension of files in this format, e.g. for the files of a
+    /// database in `Layout::Directory`
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Toml => "toml",
+            Self::Yaml => "yaml",
+        }
+    }
+
+    /// Finds the format of the database file at `path` from its name,
+    /// `Format::Json` if the name is not known
+    pub fn of(path: &Path) -> Format {
+        Format::value_variants()
+            .iter()
+            .copied()
+            .find(|format| path.file_name().is_some_and(|n| n == format.filename()))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json => write!(f, "JSON"),
+            Self::Toml => write!(f, "TOML"),
+            Self::Yaml => write!(f, "YAML"),
+        }
+    }
+}
+
+/// Possible errors when modifying the structure of a `Galaxy`
+#[derive(Debug, PartialEq, Eq)]
+pub enum GalaxyError {
+    /// There is no celestial body with the ID
+    CelestialBodyNotFound(ID),
+    /// The celestial body cannot be a parent because it is not a `Star`
+    NotAStar(ID),
+    /// The change would make a celestial body its own ancestor
+    CyclicParent(ID),
+    /// There is no celestial body with the ID in the trash
+    NotInTrash(ID),
+    /// The celestial body already has a field with the key
+    FieldExists(ID, String),
+    /// There is no celestial body with the short key
+    KeyNotFound(String),
+    /// The short key or ID prefix cannot be used
+    InvalidPrefix(String),
+    /// More than one random ID starts with the prefix
+    AmbiguousId(String),
+    /// There is no sprint with the name
+    SprintNotFound(String),
+    /// A sprint with the name already exists
+    SprintExists(String),
+    /// The sprint ends before it starts
+    InvalidSprintDates(NaiveDate, NaiveDate),
+    /// The sprint was already closed
+    SprintClosed(String),
+    /// There is no status with the name in the workflow
+    StatusNotFound(String),
+    /// A status with the name already exists in the workflow
+    StatusExists(String),
+    /// The name cannot be used for a status
+    InvalidStatusName(String),
+    /// The built in statuses cannot be removed from the workflow
+    BuiltInStatus(String),
+    /// The workflow does not allow moving from the first status to the second
+    TransitionNotAllowed(String, String),
+}
+
+impl std::error::Error for GalaxyError {}
+
+impl fmt::Display for GalaxyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GalaxyError::CelestialBodyNotFound(id) => {
+                write!(f, "No celestial body with id {id}")
+            }
+            GalaxyError::NotAStar(id) => {
+                write!(
+                    f,
+                    "Celestial body {id} is not a Star and cannot have children"
+                )
+            }
+            GalaxyError::CyclicParent(id) => {
+                write!(f, "Celestial body {id} cannot be its own ancestor")
+            }
+            GalaxyError::NotInTrash(id) => {
+                write!(f, "No celestial body with id {id} in the trash")
+            }
+            GalaxyError::FieldExists(id, key) => {
+                write!(f, "Celestial body {id} already has a field \"{key}\"")
+            }
+            GalaxyError::KeyNotFound(key) => {
+                write!(f, "No celestial body with key {key}")
+            }
+            GalaxyError::InvalidPrefix(prefix) => {
+                write!(f, "Prefix \"{prefix}\" must start with a letter")
+            }
+            GalaxyError::AmbiguousId(id) => {
+                write!(
+                    f,
+                    "More than one celestial body has an id starting with {id}"
+                )
+            }
+            GalaxyError::SprintNotFound(name) => write!(f, "No sprint named \"{name}\""),
+            GalaxyError::SprintExists(name) => {
+                write!(f, "A sprint named \"{name}\" already exists")
+            }
+            GalaxyError::InvalidSprintDates(start, end) => {
+                write!(f, "A sprint cannot end ({end}) before it starts ({start})")
+            }
+            GalaxyError::SprintClosed(name) => write!(f, "Sprint \"{name}\" is closed"),
+            GalaxyError::StatusNotFound(name) => write!(f, "No status named \"{name}\""),
+            GalaxyError::StatusExists(name) => {
+                write!(f, "A status named \"{name}\" already exists")
+            }
+            GalaxyError::InvalidStatusName(name) => {
+                write!(
+                    f,
+                    "Status \"{name}\" must not be empty or contain spaces, commas, or colons"
+                )
+            }
+            GalaxyError::BuiltInStatus(name) => {
+                write!(f, "The built in status {name} cannot be removed")
+            }
+            GalaxyError::TransitionNotAllowed(from, to) => {
+                write!(f, "The workflow does not allow moving from {from} to {to}")
+            }
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
 //                                  STRUCTS                                   //
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
+/// A value in a database that could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseIssue {
+    /// Where the value is in the database, e.g. `planets[3].status`
+    pub path: String,
+    /// The value itself, `None` if it is missing, or is an object or a list
+    pub value: Option<Value>,
+    /// What is wrong with the value
+    pub message: String,
+}
+
+impl fmt::Display for ParseIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "`{}` = {value}: {}", self.path, self.message),
+            None => write!(f, "`{}`: {}", self.path, self.message),
+        }
+    }
+}
+
 /// An struct representing the type of the celestial body and the index in the
 /// corresponding vector for said celestial body
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -133,9 +382,9 @@ impl CelestialBodyIndex {
 /// The representation of the database. This is an internal struct that should
 /// only be used by the `Galaxy` when loading / saving.
 /// NOTE: If this struct (or any structs it contains) is changed in any way,
-/// `SCHEMA_VERSION` needs to be incremented
+/// `SCHEMA_VERSION` needs to be incremented and `schema::schema` updated
 #[derive(Debug, Deserialize, Serialize)]
-struct Database {
+pub(super) struct Database {
     /// The current schema version. This field should ALWAYS exist.
     #[serde(deserialize_with = "ensure_database_version")]
     version: u64,
@@ -145,40 +394,103 @@ struct Database {
     title: String,
     description: String,
     next_id: ID,
+    #[serde(default)]
+    key_prefix: Option<String>,
+    #[serde(default)]
+    last_key: u64,
+    #[serde(default)]
+    id_format: IdFormat,
+    #[serde(default)]
+    id_scheme: IdScheme,
 
-    comets: Vec<Comet>,
-    planets: Vec<Planet>,
-    stars: Vec<Star>,
+    pub(super) comets: Vec<Comet>,
+    pub(super) planets: Vec<Planet>,
+    pub(super) stars: Vec<Star>,
+    #[serde(default)]
+    retros: Vec<Retro>,
+    #[serde(default)]
+    trash: Vec<TrashEntry>,
+    #[serde(default)]
+    plans: Vec<DayPlan>,
+    #[serde(default)]
+    sprints: Vec<Sprint>,
+    #[serde(default, skip_serializing_if = "Rules::is_default")]
+    rules: Rules,
+    #[serde(default, skip_serializing_if = "Workflow::is_default")]
+    workflow: Workflow,
 }
 
 impl Database {
-    const SCHEMA_VERSION: u64 = 2;
-    const DEFAULT_FILENAME: &str = ".planit.json";
+    pub(super) const SCHEMA_VERSION: u64 = 27;
+    /// The oldest schema version that can still be loaded. Any fields added
+    /// since this version must have a default value.
+    pub(super) const OLDEST_SCHEMA_VERSION: u64 = 2;
+    /// The largest database that will be loaded, so that a malformed database
+    /// cannot use up all of the memory. Databases that would be larger are
+    /// not saved, since they could not be loaded again.
+    pub(super) const MAX_SIZE: u64 = 64 * 1024 * 1024;
 
-    /// Finds the location for the database file
+    /// Finds the location for the database file (or directory), in any
+    /// `Layout` and `Format`
     ///
     /// # Errors
     /// Errors will occur in the following situations:
     /// - The specified database cannot be found. This includes if the default
     ///   database cannot be found.
     pub fn location() -> Result<PathBuf> {
-        let mut path: PathBuf = env::current_dir()?;
-        let file = Path::new(Database::DEFAULT_FILENAME);
+        let mut dir: PathBuf = env::current_dir()?;
 
         loop {
-            path.push(file);
-            if path.exists() {
+            if let Some(path) = Database::in_dir(&dir) {
                 break Ok(path);
             }
-            // Remove the file and go up one directory
-            if !(path.pop() && path.pop()) {
+            // Go up one directory
+            if !dir.pop() {
                 break Err(DatabaseError::DatabaseNotFound(
-                    Database::DEFAULT_FILENAME.into(),
+                    Format::default().filename().into(),
                 ));
             }
         }
     }
 
+    /// Helper function that finds the database directly in `dir`, in any
+    /// `Layout` and `Format`
+    fn in_dir(dir: &Path) -> Option<PathBuf> {
+        storage::find(dir).map(|storage| storage.path().to_path_buf())
+    }
+
+    /// Helper function that creates a database from `value`, skipping the
+    /// entries of lists (e.g. celestial bodies) that are not valid and
+    /// removing the references to them. The issues found are added to
+    /// `issues`.
+    pub(super) fn from_value_lenient(
+        mut value: Value,
+        mut issues: Vec<ParseIssue>,
+    ) -> Result<(Self, Vec<ParseIssue>)> {
+        // Entries that are not valid are set to null first, so that the paths
+        // of later issues still point at the right entries
+        invalidate::<Comet>(&mut value, "comets", &mut issues);
+        invalidate::<Planet>(&mut value, "planets", &mut issues);
+        invalidate::<Star>(&mut value, "stars", &mut issues);
+        invalidate::<Retro>(&mut value, "retros", &mut issues);
+        invalidate::<TrashEntry>(&mut value, "trash", &mut issues);
+        invalidate::<DayPlan>(&mut value, "plans", &mut issues);
+        invalidate::<Sprint>(&mut value, "sprints", &mut issues);
+        detach(&mut value, &mut issues);
+        for list in value
+            .as_object_mut()
+            .into_iter()
+            .flat_map(|o| o.values_mut())
+        {
+            if let Value::Array(entries) = list {
+                entries.retain(|entry| !entry.is_null());
+            }
+        }
+
+        let database = check(&value).map_err(DatabaseError::InvalidValue)?;
+        Ok((database, issues))
+    }
+
     /// Sets the `title` field and returns `self`
     pub fn title(mut self, title: String) -> Self {
         self.title = title;
@@ -197,6 +509,30 @@ impl Database {
         self
     }
 
+    /// Sets the `key_prefix` field and returns `self`
+    pub fn key_prefix(mut self, key_prefix: Option<String>) -> Self {
+        self.key_prefix = key_prefix;
+        self
+    }
+
+    /// Sets the `last_key` field and returns `self`
+    pub fn last_key(mut self, last_key: u64) -> Self {
+        self.last_key = last_key;
+        self
+    }
+
+    /// Sets the `id_format` field and returns `self`
+    pub fn id_format(mut self, id_format: IdFormat) -> Self {
+        self.id_format = id_format;
+        self
+    }
+
+    /// Sets the `id_scheme` field and returns `self`
+    pub fn id_scheme(mut self, id_scheme: IdScheme) -> Self {
+        self.id_scheme = id_scheme;
+        self
+    }
+
     /// Sets the `comets` field and returns `self`
     pub fn comets(mut self, comets: Vec<Comet>) -> Self {
         self.comets = comets;
@@ -214,6 +550,42 @@ impl Database {
         self.stars = stars;
         self
     }
+
+    /// Sets the `retros` field and returns `self`
+    pub fn retros(mut self, retros: Vec<Retro>) -> Self {
+        self.retros = retros;
+        self
+    }
+
+    /// Sets the `trash` field and returns `self`
+    pub fn trash(mut self, trash: Vec<TrashEntry>) -> Self {
+        self.trash = trash;
+        self
+    }
+
+    /// Sets the `plans` field and returns `self`
+    pub fn plans(mut self, plans: Vec<DayPlan>) -> Self {
+        self.plans = plans;
+        self
+    }
+
+    /// Sets the `sprints` field and returns `self`
+    pub fn sprints(mut self, sprints: Vec<Sprint>) -> Self {
+        self.sprints = sprints;
+        self
+    }
+
+    /// Sets the `rules` field and returns `self`
+    pub fn rules(mut self, rules: Rules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Sets the `workflow` field and returns `self`
+    pub fn workflow(mut self, workflow: Workflow) -> Self {
+        self.workflow = workflow;
+        self
+    }
 }
 
 impl Default for Database {
@@ -227,9 +599,19 @@ impl Default for Database {
             title: String::default(),
             description: String::default(),
             next_id: ID::default(),
+            key_prefix: None,
+            last_key: 0,
+            id_format: IdFormat::default(),
+            id_scheme: IdScheme::default(),
             comets: Vec::default(),
             planets: Vec::default(),
             stars: Vec::default(),
+            retros: Vec::default(),
+            trash: Vec::default(),
+            plans: Vec::default(),
+            sprints: Vec::default(),
+            rules: Rules::default(),
+            workflow: Workflow::default(),
         }
     }
 }
@@ -239,43 +621,210 @@ fn ensure_database_version<'de, D: serde::Deserializer<'de>>(
 ) -> std::result::Result<u64, D::Error> {
     let version = u64::deserialize(d)?;
     match version {
-        Database::SCHEMA_VERSION => Ok(version),
+        Database::OLDEST_SCHEMA_VERSION..=Database::SCHEMA_VERSION => Ok(version),
         _ => Err(serde::de::Error::custom(format!(
-            "Version mismatch for database. Expected {} got {}",
+            "Version mismatch for database. Expected {} to {} got {}",
+            Database::OLDEST_SCHEMA_VERSION,
             Database::SCHEMA_VERSION,
             version
         ))),
     }
 }
 
+/// Helper function that deserializes `value`, pointing at the value that is
+/// not valid if this fails
+pub(super) fn check<T: DeserializeOwned>(value: &Value) -> std::result::Result<T, ParseIssue> {
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let found = e.path().iter().try_fold(value, |v, segment| match segment {
+            Segment::Seq { index } => v.get(index),
+            Segment::Map { key } => v.get(key),
+            Segment::Enum { .. } | Segment::Unknown => Some(v),
+        });
+        ParseIssue {
+            path: e.path().to_string(),
+            value: found.filter(|v| !v.is_object() && !v.is_array()).cloned(),
+            message: e.into_inner().to_string(),
+        }
+    })
+}
+
+/// Helper function that sets the entries of the list `key` in the database
+/// `value` that are not a valid `T` to null, recording why
+fn invalidate<T: DeserializeOwned>(value: &mut Value, key: &str, issues: &mut Vec<ParseIssue>) {
+    let entries = value.get_mut(key).and_then(Value::as_array_mut);
+    for (index, entry) in entries.into_iter().flatten().enumerate() {
+        if let Err(mut issue) = check::<T>(entry) {
+            issue.path = match issue.path.as_str() {
+                "." => format!("{key}[{index}]"),
+                path => format!("{key}[{index}].{path}"),
+            };
+            issue.message += ", skipped";
+            issues.push(issue);
+            *entry = Value::Null;
+        }
+    }
+}
+
+/// Helper function that removes the references to the celestial bodies that
+/// were set to null by `invalidate` (or that never existed) from the database
+/// `value`. Their children are moved to the root of the `Galaxy`.
+fn detach(value: &mut Value, issues: &mut Vec<ParseIssue>) {
+    let ids = |key: &str| -> HashSet<u64> {
+        let entries = value[key].as_array().into_iter().flatten();
+        entries.filter_map(|entry| entry["id"].as_u64()).collect()
+    };
+    let stars = ids("stars");
+    let bodies: HashSet<u64> = [ids("comets"), ids("planets"), stars.clone()]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    for key in ["comets", "planets", "stars"] {
+        let entries = value.get_mut(key).and_then(Value::as_array_mut);
+        for (index, entry) in entries.into_iter().flatten().enumerate() {
+            if let Some(parent) = entry["parent"].as_u64().filter(|p| !stars.contains(p)) {
+                issues.push(ParseIssue {
+                    path: format!("{key}[{index}].parent"),
+                    value: Some(parent.into()),
+                    message: "the parent is not a Star, moved to the root".into(),
+                });
+                entry["parent"] = Value::Null;
+            }
+            let Some(children) = entry.get_mut("children").and_then(Value::as_array_mut) else {
+                continue;
+            };
+            children.retain(|child| {
+                let kept = child.as_u64().is_some_and(|c| bodies.contains(&c));
+                if !kept {
+                    issues.push(ParseIssue {
+                        path: format!("{key}[{index}].children"),
+                        value: Some(child.clone()),
+                        message: "the child does not exist, removed".into(),
+                    });
+                }
+                kept
+            });
+        }
+    }
+}
+
+/// Helper function that generates a random ID. IDs are kept below `i64::MAX`,
+/// as TOML cannot store larger numbers.
+fn random_id() -> ID {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_i64(Utc::now().timestamp_nanos_opt().unwrap_or_default());
+    hasher.write_u32(std::process::id());
+    hasher.finish() >> 1
+}
+
 /// The Galaxy is the top-level structure. It contains all celestial bodies
 /// within the project.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Galaxy {
     title: String,
     description: String,
 
     /// The ID of the next created celestial body
     next_id: ID,
+    /// The prefix of the short keys (e.g. `PLAN-`) given to new celestial
+    /// bodies, `None` if they are not given short keys
+    key_prefix: Option<String>,
+    /// The number used in the most recently generated short key
+    last_key: u64,
+    /// How IDs are displayed and parsed
+    id_format: IdFormat,
+    /// How the IDs of new celestial bodies are generated
+    id_scheme: IdScheme,
+    /// The number of characters of random IDs that are displayed, which is
+    /// the shortest length that keeps them unique. This is not saved in the
+    /// database.
+    short_id_len: usize,
 
     /// Vector of all comets that exist within the Galaxy (even those that are
-    /// "owned" by a star). No elements should ever be removed from this vector.
+    /// "owned" by a star). Elements are only removed when moved to the trash.
     pub(super) comets: Vec<Comet>,
     /// Vector of all planets that exist within the Galaxy (even those that are
-    /// "owned" by a star). No elements should ever be removed from this vector.
+    /// "owned" by a star). Elements are only removed when moved to the trash.
     pub(super) planets: Vec<Planet>,
     /// Vector of all stars that exist within the Galaxy (even those that are
-    /// "owned" by a star). No elements should ever be removed from this vector.
+    /// "owned" by a star). Elements are only removed when moved to the trash.
     pub(super) stars: Vec<Star>,
 
+    /// Retrospectives attached to sprints. There is at most one per sprint.
+    retros: Vec<Retro>,
+
+    /// Deleted celestial bodies that can still be restored, oldest first
+    trash: Vec<TrashEntry>,
+
+    /// The celestial bodies picked to work on each day, oldest first
+    plans: Vec<DayPlan>,
+
+    /// The sprints, in the order they were created
+    sprints: Vec<Sprint>,
+
+    /// The rules that change the status of Stars when their children change
+    rules: Rules,
+    /// The statuses that celestial bodies can have
+    workflow: Workflow,
+
+    /// Whether archived celestial bodies are shown when printing. This is not
+    /// saved in the database.
+    show_archived: bool,
+    /// Whether the reasons for being blocked or on hold are shown when
+    /// printing. This is not saved in the database.
+    show_reasons: bool,
+    /// The unfinished celestial bodies without any activity since this time
+    /// are noted as idle when printing. This is not saved in the database.
+    show_stale: Option<DateTime<Utc>>,
+    /// The name of the project the `Galaxy` belongs to when several are shown
+    /// together, which its IDs are namespaced by (e.g. `web:#12`). This is not
+    /// saved in the database.
+    project: Option<String>,
+    /// Whether older history was dropped by `cap_history`, in which case the
+    /// `Galaxy` must not be saved
+    history_capped: bool,
+    /// The celestial bodies that were created or changed since the `Galaxy`
+    /// was loaded, so that storages that keep them apart only write these.
+    /// `None` if this is not known, in which case all of them are written.
+    changed: Option<HashSet<ID>>,
+    /// The format the database is stored in. This is not saved in the
+    /// database, but taken from the name of the file.
+    format: Format,
+    /// How the database is laid out on disk. This is not saved in the
+    /// database, but taken from its location.
+    layout: Layout,
+    /// Status changes made after this time have not been reported to hooks
+    /// yet. This is not saved in the database.
+    reported_until: DateTime<Utc>,
+
     /// A map from the celestial body's id to the index within the corresponding
     /// vector (`comets`, `planets`, or `stars`)
     id_to_index: HashMap<ID, CelestialBodyIndex>,
+    /// The celestial bodies by status, tag, parent and due date. These are
+    /// only built once they are needed, and dropped whenever `id_to_index` is
+    /// rebuilt.
+    indexes: RefCell<Option<Indexes>>,
 }
 
 impl Galaxy {
+    /// The fewest characters of a random ID that are displayed
+    const MIN_SHORT_ID_LEN: usize = 4;
+    /// The largest database that can be loaded or saved, in bytes
+    pub const MAX_SIZE: u64 = Database::MAX_SIZE;
+
+    /// Finds the location of the database, searching in parent directories
+    /// for a database file in any `Format`
+    ///
+    /// # Errors
+    /// Errors will occur if the database cannot be found
+    pub fn location() -> Result<PathBuf> {
+        Database::location()
+    }
+
     /// Loads a `Galaxy` from a database. The database will be found by
-    /// searching in parent directories for `Database::DEFAULT_FILENAME`.
+    /// searching in parent directories for a database file in any `Format`.
+    /// While a batch is running, a copy of the `Galaxy` in memory is returned
+    /// instead (see `begin_batch`).
     ///
     /// # Returns
     /// A new `Galaxy` object.
@@ -287,71 +836,392 @@ impl Galaxy {
     /// - There is an error while doing a filesystem operation
     /// - There is an error while parsing the database
     pub fn load() -> Result<Self> {
-        let path = Database::location()?;
-        let file = fs::File::open(path)?;
-        let reader = io::BufReader::new(file);
-        Self::load_from_reader(reader)
+        Self::load_with_issues().map(|(galaxy, _)| galaxy)
     }
 
-    /// A helper function that reads the `Database` and uses it to create a
-    /// `Galaxy`. This is factored into a separate function primarily for ease
-    /// of testing the loading functionality without interacting with IO.
-    fn load_from_reader<R: io::Read>(reader: R) -> Result<Self> {
-        let value: Database = serde_json::from_reader(reader)?;
+    /// Same as `load`, but also returns the parts of the database that were
+    /// skipped because they could not be parsed, which only happens after
+    /// `set_lenient` (see `load_from_reader_lenient`)
+    ///
+    /// # Errors
+    /// The same errors as `load` will occur
+    pub fn load_with_issues() -> Result<(Self, Vec<ParseIssue>)> {
+        if let Some(galaxy) = BATCH.with_borrow(|batch| batch.clone()) {
+            return Ok((galaxy, Vec::new()));
+        }
+        let storage = storage::open(&Database::location()?);
+        let (database, issues) = match LENIENT.load(Ordering::Relaxed) {
+            true => storage.read_lenient()?,
+            false => (storage.read()?, Vec::new()),
+        };
+        let mut galaxy = Self::from_database(database, storage.format())?;
+        galaxy.changed = Some(HashSet::new());
+        Ok((galaxy.layout(storage.layout()), issues))
+    }
 
-        let mut id_to_index: HashMap<ID, CelestialBodyIndex> = HashMap::new();
-        for (i, comet) in value.comets.iter().enumerate() {
-            id_to_index.insert(
-                comet.id,
-                CelestialBodyIndex::new(CelestialBodyKind::Comet, i),
-            );
+    /// Loads a `Galaxy` from the database at `path`, which is either a
+    /// database file or a `.planit` directory
+    ///
+    /// # Errors
+    /// The same errors as `load_from_reader` will occur
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let storage = storage::open(path);
+        let mut galaxy = Self::from_database(storage.read()?, storage.format())?;
+        galaxy.changed = Some(HashSet::new());
+        Ok(galaxy.layout(storage.layout()))
+    }
+
+    /// Loads the `Galaxy` of the project `name`, whose database is directly in
+    /// `dir`, with its IDs namespaced by `name` (see `project`)
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - There is no database in `dir`
+    /// - There is an error while doing a filesystem operation
+    /// - There is an error while parsing the database
+    pub fn load_project(name: &str, dir: &Path) -> Result<Self> {
+        let path = Database::in_dir(dir)
+            .ok_or_else(|| DatabaseError::DatabaseNotFound(dir.display().to_string()))?;
+        Ok(Self::load_from(&path)?.project(Some(name.to_string())))
+    }
+
+    /// When the database was last changed, `None` if it cannot be found
+    pub fn last_modified() -> Option<SystemTime> {
+        storage::open(&Database::location().ok()?).modified()
+    }
+
+    /// The number of bytes the database takes up on disk
+    ///
+    /// # Errors
+    /// Errors will occur if the database cannot be found or read
+    pub fn database_size() -> Result<u64> {
+        storage::open(&Database::location()?).size()
+    }
+
+    /// Checks every file of the database against the JSON Schema of the
+    /// database (see `schema`), without loading it
+    ///
+    /// # Returns
+    /// Everything that does not match the schema, with paths that start with
+    /// the name of the file (e.g. `.planit.json:planets[3].status`)
+    ///
+    /// # Errors
+    /// Errors will occur if the database cannot be found or read
+    pub fn validate_files() -> Result<Vec<ParseIssue>> {
+        Self::validate_files_at(&Database::location()?)
+    }
+
+    /// Same as `validate_files`, but for the database at `path`, which is
+    /// either a database file or a `.planit` directory
+    ///
+    /// # Errors
+    /// Errors will occur if the database cannot be read
+    pub fn validate_files_at(path: &Path) -> Result<Vec<ParseIssue>> {
+        let mut issues = Vec::new();
+        for file in storage::open(path).raw_files()? {
+            let value = match file.value {
+                Ok(value) => value,
+                Err(e) => {
+                    issues.push(ParseIssue {
+                        path: file.name,
+                        value: None,
+                        message: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+            for issue in schema::validate(&value, file.def) {
+                issues.push(ParseIssue {
+                    path: match issue.path.as_str() {
+                        "." => file.name.clone(),
+                        path => format!("{}:{path}", file.name),
+                    },
+                    ..issue
+                });
+            }
         }
-        for (i, planet) in value.planets.iter().enumerate() {
-            id_to_index.insert(
-                planet.id,
-                CelestialBodyIndex::new(CelestialBodyKind::Planet, i),
-            );
+        Ok(issues)
+    }
+
+    /// Makes `load` skip the parts of the database that cannot be parsed
+    /// instead of failing (or makes it strict again)
+    pub fn set_lenient(lenient: bool) {
+        LENIENT.store(lenient, Ordering::Relaxed);
+    }
+
+    /// Starts a batch of commands. The database is loaded once, and until
+    /// `end_batch` is called, `load` and `save` work on this copy in memory
+    /// instead of the database. Batches can be nested, in which case the
+    /// outermost batch decides whether anything is saved.
+    ///
+    /// # Errors
+    /// The same errors as `load` will occur
+    pub fn begin_batch() -> Result<()> {
+        if BATCH_DEPTH.get() == 0 {
+            let galaxy = Self::load()?;
+            BATCH.set(Some(galaxy));
+        }
+        BATCH_DEPTH.set(BATCH_DEPTH.get() + 1);
+        Ok(())
+    }
+
+    /// Ends the batch started by `begin_batch`, writing everything saved
+    /// during the batch to the database if `save` is `true` and this is the
+    /// outermost batch
+    ///
+    /// # Errors
+    /// The same errors as `save` will occur
+    pub fn end_batch(save: bool) -> Result<()> {
+        BATCH_DEPTH.set(BATCH_DEPTH.get().saturating_sub(1));
+        if BATCH_DEPTH.get() > 0 {
+            return Ok(());
+        }
+        match BATCH.take() {
+            Some(galaxy) if save => galaxy.save(),
+            _ => Ok(()),
         }
-        for (i, star) in value.stars.iter().enumerate() {
-            id_to_index.insert(star.id, CelestialBodyIndex::new(CelestialBodyKind::Star, i));
+    }
+
+    /// Reads the `Database` from `reader` and uses it to create a `Galaxy`.
+    /// This is separate from `load` primarily for ease of testing (and fuzzing)
+    /// the loading functionality without interacting with IO.
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - There is an error while reading from `reader`
+    /// - The database is larger than `Database::MAX_SIZE`
+    /// - There is an error while parsing the database
+    /// - The database is inconsistent, e.g. two celestial bodies share an ID
+    ///   or a celestial body is its own ancestor
+    pub fn load_from_reader<R: io::Read>(reader: R) -> Result<Self> {
+        Self::load_from_reader_as(reader, Format::Json)
+    }
+
+    /// Same as `load_from_reader`, but for a database in `format`
+    ///
+    /// # Errors
+    /// The same errors as `load_from_reader` will occur
+    pub fn load_from_reader_as<R: io::Read>(reader: R, format: Format) -> Result<Self> {
+        let bytes = storage::read_limited(reader)?;
+        Self::from_database(storage::read_as(&bytes, format)?, format)
+    }
+
+    /// Same as `load_from_reader_as`, but entries (e.g. celestial bodies) that
+    /// cannot be parsed are skipped instead of failing. The children of
+    /// skipped Stars are moved to the root of the `Galaxy`. Everything that
+    /// was skipped is returned as well, and is gone once the `Galaxy` is
+    /// saved.
+    ///
+    /// # Errors
+    /// The same errors as `load_from_reader` will occur if the database is
+    /// not valid in a way that skipping entries does not fix, e.g. if it is
+    /// not valid JSON at all
+    pub fn load_from_reader_lenient<R: io::Read>(
+        reader: R,
+        format: Format,
+    ) -> Result<(Self, Vec<ParseIssue>)> {
+        let value = storage::parse(&storage::read_limited(reader)?, format)?;
+        let (database, issues) = Database::from_value_lenient(value, Vec::new())?;
+        Ok((Self::from_database(database, format)?, issues))
+    }
+
+    /// Helper function that creates a `Galaxy` from `value` and checks that
+    /// it is consistent
+    fn from_database(mut value: Database, format: Format) -> Result<Self> {
+        // Retrospectives used to be attached to Stars. They move to the last
+        // sprint that contained their Star when they were created, and keep
+        // the Star when there is no such sprint.
+        for i in 0..value.retros.len() {
+            let retro = &value.retros[i];
+            if !retro.sprint.is_empty() {
+                continue;
+            }
+            let Some(star) = retro.star else {
+                return Err(DatabaseError::CorruptDatabase(
+                    "a retrospective is attached to neither a sprint nor a Star".into(),
+                ));
+            };
+            let created = retro.created.date_naive();
+            let sprint = value
+                .sprints
+                .iter()
+                .filter(|sprint| sprint.items.contains(&star) && sprint.start <= created)
+                .filter(|sprint| !value.retros.iter().any(|r| r.sprint == sprint.name))
+                .max_by_key(|sprint| sprint.start)
+                .map(|sprint| sprint.name.clone());
+            match sprint {
+                Some(name) => {
+                    info!("Attaching the retrospective of Star {star} to sprint \"{name}\"");
+                    value.retros[i].sprint = name;
+                    value.retros[i].star = None;
+                }
+                None => info!("Keeping the retrospective of Star {star}, no sprint contained it"),
+            }
         }
 
-        Ok(Galaxy {
+        let mut galaxy = Galaxy {
             title: value.title,
             description: value.description,
             next_id: value.next_id,
+            key_prefix: value.key_prefix,
+            last_key: value.last_key,
+            id_format: value.id_format,
+            id_scheme: value.id_scheme,
+            short_id_len: 0,
             comets: value.comets,
             planets: value.planets,
             stars: value.stars,
-            id_to_index,
-        })
+            retros: value.retros,
+            trash: value.trash,
+            plans: value.plans,
+            sprints: value.sprints,
+            rules: value.rules,
+            workflow: value.workflow,
+            show_archived: false,
+            show_reasons: false,
+            show_stale: None,
+            project: None,
+            history_capped: false,
+            changed: None,
+            format,
+            layout: Layout::default(),
+            reported_until: Utc::now(),
+            id_to_index: HashMap::new(),
+            indexes: RefCell::default(),
+        };
+        galaxy.reindex();
+        galaxy.validate()?;
+
+        Ok(galaxy)
+    }
+
+    /// Helper function that checks that a freshly loaded `Galaxy` is
+    /// consistent, so that a hand edited or malicious database results in an
+    /// error instead of panics or endless loops later on
+    fn validate(&self) -> Result<()> {
+        let corrupt = |reason: String| Err(DatabaseError::CorruptDatabase(reason));
+
+        // Every ID (including the ones in the trash) must be unique and must
+        // have been handed out already
+        let mut ids = HashSet::new();
+        let trashed = self.trash.iter().flat_map(|entry| {
+            let comets = entry.comets.iter().map(|c| c.id);
+            let planets = entry.planets.iter().map(|p| p.id);
+            comets
+                .chain(planets)
+                .chain(entry.stars.iter().map(|s| s.id))
+        });
+        for id in self.iter().map(|body| body.id()).chain(trashed) {
+            if !ids.insert(id) {
+                return corrupt(format!("ID {id} is used more than once"));
+            }
+            if id >= self.next_id && self.id_scheme == IdScheme::Sequential {
+                return corrupt(format!("ID {id} is not below the next ID"));
+            }
+        }
+
+        for body in self.iter() {
+            let id = body.id();
+            if let Some(parent) = body.parent() {
+                match self.get(parent) {
+                    _ if parent == id => {
+                        return corrupt(format!("Celestial body {id} is its own parent"));
+                    }
+                    Some(CelestialBodyRef::Star(star)) if star.children.contains(&id) => {}
+                    Some(CelestialBodyRef::Star(_)) => {
+                        return corrupt(format!("Star {parent} does not list child {id}"));
+                    }
+                    Some(_) => {
+                        return corrupt(format!("Parent {parent} of {id} is not a Star"));
+                    }
+                    None => {
+                        return corrupt(format!("Parent {parent} of {id} does not exist"));
+                    }
+                }
+            }
+            for child in body.children() {
+                if self.get(*child).and_then(|c| c.parent()) != Some(id) {
+                    return corrupt(format!("Child {child} of Star {id} has another parent"));
+                }
+            }
+        }
+
+        // With every parent pointing to a Star that lists the child, the only
+        // remaining problem is a cycle, which never reaches the root
+        for body in self.iter() {
+            let mut ancestor = body.parent();
+            for _ in 0..self.id_to_index.len() {
+                ancestor = ancestor.and_then(|a| self.get(a)).and_then(|a| a.parent());
+            }
+            if ancestor.is_some() {
+                return corrupt(format!("Celestial body {} is its own ancestor", body.id()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds the map from IDs to indices. This needs to be done whenever
+    /// celestial bodies are removed from (or added back to) the vectors.
+    fn reindex(&mut self) {
+        *self.indexes.get_mut() = None;
+        self.id_to_index.clear();
+        for (i, comet) in self.comets.iter().enumerate() {
+            self.id_to_index.insert(
+                comet.id,
+                CelestialBodyIndex::new(CelestialBodyKind::Comet, i),
+            );
+        }
+        for (i, planet) in self.planets.iter().enumerate() {
+            self.id_to_index.insert(
+                planet.id,
+                CelestialBodyIndex::new(CelestialBodyKind::Planet, i),
+            );
+        }
+        for (i, star) in self.stars.iter().enumerate() {
+            self.id_to_index
+                .insert(star.id, CelestialBodyIndex::new(CelestialBodyKind::Star, i));
+        }
+        self.update_short_id_len();
     }
 
     /// Initializes a new database for `Galaxy` to be saved in. The new database
-    /// will be placed in the directory `dir`.
+    /// will be placed in the directory `dir`, in the format set with `format`
+    /// and the layout set with `layout`.
     ///
-    /// **WARNING**: This action is destructive. The old database will be
-    /// overwritten.
+    /// A database in `dir` would shadow any database in a parent directory, so
+    /// this is refused unless `nested` is set.
     ///
     /// # Errors
     /// Errors will occur in the following situations:
+    /// - There already is a database in `dir`, in any `Layout` and `Format`
+    /// - There is a database in a parent directory of `dir` and `nested` is
+    ///   not set
     /// - There is an error while doing a filesystem operation
     /// - There is an error while parsing the database
-    pub fn init(self, mut dir: PathBuf) -> Result<()> {
-        dir.push(Database::DEFAULT_FILENAME);
-        if dir.exists() {
+    pub fn init(mut self, dir: PathBuf, nested: bool) -> Result<()> {
+        if let Some(existing) = Database::in_dir(&dir) {
             return Err(DatabaseError::DatabaseAlreadyExists(
-                dir.to_string_lossy().to_string(),
+                existing.to_string_lossy().to_string(),
             ));
         }
+        if let Some(parent) = dir.ancestors().skip(1).find_map(Database::in_dir) {
+            if !nested {
+                return Err(DatabaseError::NestedDatabase(
+                    parent.to_string_lossy().to_string(),
+                ));
+            }
+            warn!("Creating a database that shadows {}", parent.display());
+        }
 
-        let file = fs::File::create(dir)?;
-        let writer = io::BufWriter::new(file);
-        self.save_to_writer(writer)
+        let storage = storage::create(&dir, self.layout, self.format);
+        self.changed = None;
+        self.save_to_storage(storage.as_ref())
     }
 
     /// Saves `Galaxy` to a database. The database will be found by searching
-    /// parent directories for `Database::DEFAULT_FILENAME`.
+    /// parent directories for a database in any `Layout` and `Format`. While a batch
+    /// is running, the `Galaxy` is only kept in memory until `end_batch`.
     ///
     /// **WARNING**: This action is destructive. The old database will be
     /// overwritten.
@@ -362,45 +1232,374 @@ impl Galaxy {
     ///   be found when `name` is `None`
     /// - There is an error while doing a filesystem operation
     /// - There is an error while parsing the database
-    pub fn save(self) -> Result<()> {
-        let path = Database::location()?;
-        let file = fs::File::create(path)?;
-        let writer = io::BufWriter::new(file);
-        self.save_to_writer(writer)
+    pub fn save(mut self) -> Result<()> {
+        let since = self.reported_until;
+        self.apply_rules(since, &mut |star| rules::confirm(star));
+        if BATCH.with_borrow(Option::is_some) {
+            self.show_archived = false;
+            self.show_reasons = false;
+            self.show_stale = None;
+            self.project = None;
+            BATCH.set(Some(self));
+            return Ok(());
+        }
+        let storage = storage::open(&Database::location()?);
+        let notifications = self.notifications(Local::now().date_naive());
+        self.save_to_storage(storage.as_ref())?;
+        hooks::dispatch(&notifications);
+        Ok(())
     }
 
-    /// Saves `Galaxy` to the database in `path`. Will create a new database if
-    /// one does not exist.
+    /// Runs the status rules of the `Galaxy` on the status changes made since
+    /// `since`, see `Rules::apply`
     ///
-    /// **WARNING**: This action is destructive. The old database will be
-    /// overwritten.
-    ///
-    /// # Errors
-    /// Errors will occur in the following situations:
-    /// - There is an error while doing a filesystem operation
-    /// - There is an error while parsing the database
-    pub fn save_to(self, path: PathBuf) -> Result<()> {
+    /// # Returns
+    /// The IDs of the Stars that were not confirmed
+    pub fn apply_rules(
+        &mut self,
+        since: DateTime<Utc>,
+        confirm: &mut dyn FnMut(&CelestialBodyRef) -> bool,
+    ) -> Vec<ID> {
+        let rules = self.rules.clone();
+        rules.apply(self, since, confirm)
+    }
+
+    /// Returns the status rules of the `Galaxy`
+    pub fn get_rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// Sets the status rules of the `Galaxy`
+    pub fn set_rules(&mut self, rules: Rules) {
+        self.rules = rules;
+    }
+
+    /// Returns the status workflow of the `Galaxy`
+    pub fn workflow(&self) -> &Workflow {
+        &self.workflow
+    }
+
+    /// Adds `stage` to the status workflow
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - The name of `stage` is taken or cannot be used
+    /// - One of the statuses that may follow `stage` does not exist
+    pub fn workflow_add(&mut self, mut stage: Stage) -> std::result::Result<(), GalaxyError> {
+        if stage.name.is_empty()
+            || stage
+                .name
+                .contains(|c: char| c.is_whitespace() || c == ',' || c == ':')
+        {
+            return Err(GalaxyError::InvalidStatusName(stage.name));
+        }
+        if self.workflow.find(&stage.name).is_some() {
+            return Err(GalaxyError::StatusExists(stage.name));
+        }
+        if let Some(next) = stage.next.iter().find(|next| {
+            self.workflow.find(next).is_none() && !next.eq_ignore_ascii_case(&stage.name)
+        }) {
+            return Err(GalaxyError::StatusNotFound(next.clone()));
+        }
+        for next in &mut stage.next {
+            if let Some(found) = self.workflow.find(next) {
+                *next = found.name.clone();
+            }
+        }
+        info!("Adding status \"{}\" to the workflow", stage.name);
+        self.workflow.push(stage);
+        Ok(())
+    }
+
+    /// Removes the status `name` from the workflow. The celestial bodies in it
+    /// are moved back to the built in status it counts as.
+    ///
+    /// # Returns
+    /// The IDs of the celestial bodies that were in the status
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - There is no status named `name`
+    /// - `name` is one of the built in statuses
+    pub fn workflow_remove(&mut self, name: &str) -> std::result::Result<Vec<ID>, GalaxyError> {
+        if let Some(stage) = self.workflow.find(name).filter(|s| s.is_built_in()) {
+            return Err(GalaxyError::BuiltInStatus(stage.name.clone()));
+        }
+        let stage = self
+            .workflow
+            .remove(name)
+            .ok_or_else(|| GalaxyError::StatusNotFound(name.to_string()))?;
+        let ids: Vec<_> = self
+            .iter()
+            .filter(|body| body.stage() == Some(stage.name.as_str()))
+            .map(|body| body.id())
+            .collect();
+        for id in &ids {
+            if let Some(mut body) = self.get_mut(*id) {
+                body.set_stage(None);
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Sets the statuses that may follow the status `name`, any status may if
+    /// `next` is empty
+    ///
+    /// # Errors
+    /// Errors will occur if `name` or one of `next` is not in the workflow
+    pub fn workflow_next(
+        &mut self,
+        name: &str,
+        next: Vec<String>,
+    ) -> std::result::Result<(), GalaxyError> {
+        if let Some(missing) = std::iter::once(name)
+            .chain(next.iter().map(String::as_str))
+            .find(|name| self.workflow.find(name).is_none())
+        {
+            return Err(GalaxyError::StatusNotFound(missing.to_string()));
+        }
+        let next = next
+            .iter()
+            .filter_map(|name| self.workflow.find(name))
+            .map(|stage| stage.name.clone())
+            .collect();
+        if let Some(stage) = self.workflow.find_mut(name) {
+            stage.next = next;
+        }
+        Ok(())
+    }
+
+    /// Moves the celestial body `id` to the status `name` of the workflow,
+    /// which sets its built in status to the category of `name`. `comment`
+    /// is only recorded if the built in status changes.
+    ///
+    /// # Returns
+    /// `false` if it already was in the status
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - `id` does not exist
+    /// - There is no status named `name`
+    /// - The workflow does not allow moving from the current status to `name`
+    pub fn set_status(
+        &mut self,
+        id: ID,
+        name: &str,
+        comment: String,
+    ) -> std::result::Result<bool, GalaxyError> {
+        let to = self
+            .workflow
+            .find(name)
+            .cloned()
+            .ok_or_else(|| GalaxyError::StatusNotFound(name.to_string()))?;
+        let body = self.get(id).ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+        let from = self.workflow.stage_of(&body);
+        if from.name == to.name {
+            return Ok(false);
+        }
+        if !from.allows(&to) {
+            return Err(GalaxyError::TransitionNotAllowed(from.name, to.name));
+        }
+
+        let status = body.status();
+        let mut body = self
+            .get_mut(id)
+            .ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+        if status != to.category {
+            body.status(to.category, comment);
+        }
+        body.set_stage((!to.is_built_in()).then_some(to.name));
+        Ok(true)
+    }
+
+    /// The status of `body` as shown in views, which is its status of the
+    /// workflow in that status' color
+    pub fn status_label(&self, body: &CelestialBodyRef) -> ColoredString {
+        match body.stage().and_then(|name| self.workflow.find(name)) {
+            Some(stage) => format!("{:<6}", stage.name).color(stage.color()),
+            None => body.status().into(),
+        }
+    }
+
+    /// Collects the notifications about status changes that have not been
+    /// reported yet, about celestial bodies that are overdue on `today`, and
+    /// about snoozes that ended by `today`. Overdue celestial bodies are
+    /// marked, so that they are only reported again if their due date
+    /// changes, and ended snoozes are cleared.
+    pub(super) fn notifications(&mut self, today: NaiveDate) -> Vec<Notification> {
+        let since = mem::replace(&mut self.reported_until, Utc::now());
+        let mut notifications = Vec::new();
+        let mut overdue = Vec::new();
+        let mut woke = Vec::new();
+
+        for body in self.iter() {
+            if body.snoozed_until().is_some() && !body.is_snoozed(today) {
+                notifications.push(Notification::new(Trigger::Woke, &body, Utc::now()));
+                woke.push(body.id());
+            }
+
+            if let Some(due) = body.due().filter(|_| body.overdue(today)) {
+                let reported = body.events().iter().any(|event| {
+                    matches!(event.change, Change::Overdue { due: reported } if reported == due)
+                });
+                if !reported {
+                    let time = Utc::now();
+                    notifications.push(Notification::new(Trigger::Overdue, &body, time));
+                    overdue.push((body.id(), due));
+                }
+            }
+
+            for change in body.history().iter().filter(|h| h.time() > since) {
+                let triggers = match change.new_status() {
+                    Status::Done => [Some(Trigger::Status), Some(Trigger::Completed)],
+                    Status::Block => [Some(Trigger::Status), Some(Trigger::Blocked)],
+                    _ => [Some(Trigger::Status), None],
+                };
+                for trigger in triggers.into_iter().flatten() {
+                    notifications.push(Notification {
+                        old: Some(change.old_status()),
+                        status: change.new_status(),
+                        comment: change.comment().to_string(),
+                        ..Notification::new(trigger, &body, change.time())
+                    });
+                }
+            }
+        }
+
+        for (id, due) in overdue {
+            if let Some(mut body) = self.get_mut(id) {
+                body.record(Change::Overdue { due });
+            }
+        }
+        for id in woke {
+            if let Some(mut body) = self.get_mut(id) {
+                body.wake();
+            }
+        }
+        notifications
+    }
+
+    /// Saves `Galaxy` to the database in `path`. Will create a new database if
+    /// one does not exist.
+    ///
+    /// **WARNING**: This action is destructive. The old database will be
+    /// overwritten.
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - There is an error while doing a filesystem operation
+    /// - There is an error while parsing the database
+    pub fn save_to(self, path: PathBuf) -> Result<()> {
         let file = fs::File::create(path)?;
         let writer = io::BufWriter::new(file);
         self.save_to_writer(writer)
     }
 
-    /// A helper function that creates a `Database` from the `Galaxy` and writes
-    /// it to the writer. This is factored into a separate function primarily
-    /// for ease of testing the saving functionality without interacting with IO.
-    fn save_to_writer<W: io::Write>(self, writer: W) -> Result<()> {
-        let db = Database::default()
+    /// Converts the database to `format` and `layout`, replacing the old
+    /// database with one in the same directory
+    ///
+    /// # Returns
+    /// The location of the new database
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - The database cannot be found
+    /// - There is an error while doing a filesystem operation
+    /// - There is an error while writing the database
+    pub fn convert(mut self, format: Format, layout: Layout) -> Result<PathBuf> {
+        let old = storage::open(&Database::location()?);
+        let dir = old.path().parent().unwrap_or(old.path());
+        let new = storage::create(dir, layout, format);
+
+        info!(
+            "Converting {} to {}",
+            old.path().display(),
+            new.path().display()
+        );
+        self.format = format;
+        self.layout = layout;
+        self.changed = None;
+        self.save_to_storage(new.as_ref())?;
+        if new.path() != old.path() || new.format() != old.format() {
+            old.remove()?;
+        }
+        Ok(new.path().to_path_buf())
+    }
+
+    /// Helper function that writes the `Galaxy` to `storage`, only writing
+    /// the celestial bodies that changed if they are known
+    fn save_to_storage(mut self, storage: &dyn Storage) -> Result<()> {
+        if self.history_capped {
+            return Err(DatabaseError::HistoryCapped);
+        }
+        match self.changed.take() {
+            Some(changed) => storage.write_changed(self.into_database(), &changed),
+            None => storage.write(self.into_database()),
+        }
+    }
+
+    /// Helper function that remembers that the celestial body `id` was
+    /// created or changed, see `changed` and `indexes`
+    fn touch(&mut self, id: ID) {
+        if let Some(changed) = &mut self.changed {
+            changed.insert(id);
+        }
+        if let Some(indexes) = self.indexes.get_mut() {
+            indexes.invalidate(id);
+        }
+    }
+
+    /// Creates a `Database` from the `Galaxy` and writes it to `writer`. This is
+    /// separate from `save` primarily for ease of testing (and fuzzing) the
+    /// saving functionality without interacting with IO.
+    pub fn save_to_writer<W: io::Write>(self, writer: W) -> Result<()> {
+        if self.history_capped {
+            return Err(DatabaseError::HistoryCapped);
+        }
+        let format = self.format;
+        storage::write_as(&self.into_database(), writer, format)
+    }
+
+    /// Turns the `Galaxy` into the JSON representation of its database, so
+    /// that it can be compared and merged field by field
+    ///
+    /// # Errors
+    /// Errors will occur if older history was dropped by `cap_history`
+    pub(super) fn into_json(self) -> Result<serde_json::Value> {
+        if self.history_capped {
+            return Err(DatabaseError::HistoryCapped);
+        }
+        Ok(serde_json::to_value(self.into_database())?)
+    }
+
+    /// The opposite of `into_json`
+    ///
+    /// # Errors
+    /// The same errors as `load_from_reader` will occur, other than reading
+    /// errors
+    pub(super) fn from_json(value: serde_json::Value) -> Result<Self> {
+        Self::from_database(serde_json::from_value(value)?, Format::default())
+    }
+
+    /// Helper function that turns the `Galaxy` into a `Database`
+    fn into_database(self) -> Database {
+        Database::default()
             .title(self.title)
             .description(self.description)
             .next_id(self.next_id)
+            .key_prefix(self.key_prefix)
+            .last_key(self.last_key)
+            .id_format(self.id_format)
+            .id_scheme(self.id_scheme)
             .comets(self.comets)
             .planets(self.planets)
-            .stars(self.stars);
-
-        match serde_json::to_writer_pretty(writer, &db) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(DatabaseError::ParsingError(e)),
-        }
+            .stars(self.stars)
+            .retros(self.retros)
+            .trash(self.trash)
+            .plans(self.plans)
+            .sprints(self.sprints)
+            .rules(self.rules)
+            .workflow(self.workflow)
     }
 
     /// Sets the `title` field and returns `self`
@@ -415,6 +1614,231 @@ impl Galaxy {
         self
     }
 
+    /// Sets how the database is laid out on disk when initialized and returns
+    /// `self`
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the format the database is stored in when initialized and returns
+    /// `self`
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets whether archived celestial bodies are shown when printing and
+    /// returns `self`
+    pub fn show_archived(mut self, show_archived: bool) -> Self {
+        self.show_archived = show_archived;
+        self
+    }
+
+    /// Sets whether the reasons for being blocked or on hold are shown when
+    /// printing and returns `self`
+    pub fn show_reasons(mut self, show_reasons: bool) -> Self {
+        self.show_reasons = show_reasons;
+        self
+    }
+
+    /// Sets the time since which celestial bodies without any activity are
+    /// noted as idle when printing and returns `self`
+    pub fn show_stale(mut self, show_stale: Option<DateTime<Utc>>) -> Self {
+        self.show_stale = show_stale;
+        self
+    }
+
+    /// Sets the name of the project the `Galaxy` belongs to, which its IDs
+    /// are namespaced by, and returns `self`
+    pub fn project(mut self, project: Option<String>) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// Returns the name of the project the `Galaxy` belongs to, if it is
+    /// shown together with others
+    pub fn get_project(&self) -> Option<&str> {
+        self.project.as_deref()
+    }
+
+    /// Returns the prefix of the short keys given to new celestial bodies
+    pub fn get_key_prefix(&self) -> Option<&str> {
+        self.key_prefix.as_deref()
+    }
+
+    /// Sets the prefix of the short keys (e.g. `PLAN-`) and gives a short key
+    /// to every celestial body that does not have one yet. Existing short keys
+    /// are kept so that they can still be used.
+    ///
+    /// # Returns
+    /// The number of celestial bodies that were given a short key
+    ///
+    /// # Errors
+    /// Errors will occur if `prefix` does not start with a letter, as the short
+    /// keys could otherwise be confused with IDs
+    pub fn set_key_prefix(&mut self, prefix: String) -> std::result::Result<usize, GalaxyError> {
+        if !prefix.starts_with(|c: char| c.is_alphabetic()) {
+            return Err(GalaxyError::InvalidPrefix(prefix));
+        }
+        info!("Setting key prefix to {prefix}");
+        self.key_prefix = Some(prefix);
+
+        let mut ids: Vec<_> = self
+            .iter()
+            .filter(|body| body.key().is_none())
+            .map(|body| body.id())
+            .collect();
+        ids.sort();
+        for id in &ids {
+            let key = self.next_key();
+            self.set_key_field(*id, key);
+        }
+        Ok(ids.len())
+    }
+
+    /// Returns how IDs are displayed and parsed
+    pub fn get_id_format(&self) -> IdFormat {
+        self.id_format
+    }
+
+    /// Sets how IDs are displayed and parsed
+    pub fn set_id_format(&mut self, id_format: IdFormat) {
+        info!("Setting ID format to {id_format}");
+        self.id_format = id_format;
+        self.update_short_id_len();
+    }
+
+    /// Returns the format the database is stored in
+    pub fn get_format(&self) -> Format {
+        self.format
+    }
+
+    /// Returns how the database is laid out on disk
+    pub fn get_layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Returns how the IDs of new celestial bodies are generated
+    pub fn get_id_scheme(&self) -> IdScheme {
+        self.id_scheme
+    }
+
+    /// Sets how the IDs of new celestial bodies are generated. Existing IDs
+    /// are kept, and sequential IDs continue after the largest one in use.
+    pub fn set_id_scheme(&mut self, id_scheme: IdScheme) {
+        info!("Setting ID scheme to {id_scheme}");
+        self.id_scheme = id_scheme;
+        if id_scheme == IdScheme::Sequential {
+            let largest = self.all_ids().max();
+            self.next_id = self.next_id.max(largest.map_or(0, |id| id + 1));
+        }
+        self.update_short_id_len();
+    }
+
+    /// Sets the prefix used instead of `#` when displaying the IDs of the
+    /// `Star` `star` and its descendants, or removes it if `prefix` is `None`
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - `star` does not exist or is not a `Star`
+    /// - `prefix` does not start with a letter
+    pub fn set_id_prefix(
+        &mut self,
+        star: ID,
+        prefix: Option<String>,
+    ) -> std::result::Result<(), GalaxyError> {
+        let index = self
+            .index(star)
+            .ok_or(GalaxyError::CelestialBodyNotFound(star))?;
+        if index.kind != CelestialBodyKind::Star {
+            return Err(GalaxyError::NotAStar(star));
+        }
+        if let Some(prefix) = prefix
+            .as_ref()
+            .filter(|p| !p.starts_with(|c: char| c.is_alphabetic()))
+        {
+            return Err(GalaxyError::InvalidPrefix(prefix.clone()));
+        }
+
+        info!("Setting ID prefix of ({star}) to {prefix:?}");
+        self.touch(star);
+        self.stars[index.index].id_prefix = prefix;
+        Ok(())
+    }
+
+    /// Displays `id` using the configured format, prefixed by the ID prefix of
+    /// the closest `Star` (including itself) that has one, or `#` otherwise.
+    /// The project of the `Galaxy` comes first if it has one, e.g. `web:#12`.
+    pub fn display_id(&self, id: ID) -> String {
+        let mut ancestor = Some(id);
+        let mut prefix = None;
+        while let Some(body) = ancestor.and_then(|a| self.get(a)) {
+            if let CelestialBodyRef::Star(star) = body {
+                prefix = prefix.or(star.id_prefix.as_deref());
+            }
+            ancestor = body.parent();
+        }
+        let mut id = self.id_format.format(id);
+        if self.id_scheme == IdScheme::Random {
+            id.truncate(self.short_id_len);
+        }
+        format!("{}{}{}", self.namespace(), prefix.unwrap_or("#"), id)
+    }
+
+    /// Helper function that returns what IDs and keys are namespaced by, the
+    /// project followed by a colon (or nothing without a project)
+    fn namespace(&self) -> String {
+        self.project
+            .as_ref()
+            .map(|project| format!("{project}:"))
+            .unwrap_or_default()
+    }
+
+    /// Keeps only the `limit` most recent status changes and events of each
+    /// celestial body, so that long histories do not take up memory when they
+    /// are not needed (e.g. in the TUI). The `Galaxy` cannot be saved
+    /// afterwards.
+    ///
+    /// # Returns
+    /// The number of status changes and events that were dropped
+    pub fn cap_history(&mut self, limit: usize) -> usize {
+        fn cap<T>(v: &mut Vec<T>, limit: usize) -> usize {
+            let dropped = v.len().saturating_sub(limit);
+            if dropped > 0 {
+                v.drain(..dropped);
+                v.shrink_to_fit();
+            }
+            dropped
+        }
+
+        self.changed = None;
+        let mut dropped = 0;
+        for comet in &mut self.comets {
+            dropped += cap(&mut comet.history, limit) + cap(&mut comet.events, limit);
+        }
+        for planet in &mut self.planets {
+            dropped += cap(&mut planet.history, limit) + cap(&mut planet.events, limit);
+        }
+        for star in &mut self.stars {
+            dropped += cap(&mut star.history, limit) + cap(&mut star.events, limit);
+        }
+        info!("Dropped {dropped} history entries beyond the limit of {limit}");
+
+        self.history_capped |= dropped > 0;
+        dropped
+    }
+
+    /// Returns the title of the `Galaxy`
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the description of the `Galaxy`
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
     /// Creates a new `Comet` object and registers it with the `Galaxy`
     ///
     /// # Returns
@@ -424,9 +1848,13 @@ impl Galaxy {
         let index = self.comets.len();
         info!("Creating new Comet with id {id}");
         // Create new comet and set the id
-        let comet = Comet::new(id);
+        let mut comet = Comet::new(id);
+        comet.key = self.next_key();
+        comet.events.push(Event::now(Change::Created));
+        comet.updated_at = Some(Utc::now());
         // put the comet into the vector of comets
         self.comets.push(comet);
+        self.touch(id);
         // associate the id with the index
         self.id_to_index
             .insert(id, CelestialBodyIndex::new(CelestialBodyKind::Comet, index));
@@ -443,9 +1871,13 @@ impl Galaxy {
         let index = self.planets.len();
         info!("Creating new Planet with id {id}");
         // Create new planet and set the id
-        let planet = Planet::new(id);
+        let mut planet = Planet::new(id);
+        planet.key = self.next_key();
+        planet.events.push(Event::now(Change::Created));
+        planet.updated_at = Some(Utc::now());
         // put the planet into the vector of planets
         self.planets.push(planet);
+        self.touch(id);
         // associate the id with the index
         self.id_to_index.insert(
             id,
@@ -464,9 +1896,13 @@ impl Galaxy {
         let index = self.stars.len();
         info!("Creating new Star with id {id}");
         // Create new star and set the id
-        let star = Star::new(id);
+        let mut star = Star::new(id);
+        star.key = self.next_key();
+        star.events.push(Event::now(Change::Created));
+        star.updated_at = Some(Utc::now());
         // put the star into the vector of stars
         self.stars.push(star);
+        self.touch(id);
         // associate the id with the index
         self.id_to_index
             .insert(id, CelestialBodyIndex::new(CelestialBodyKind::Star, index));
@@ -479,98 +1915,1372 @@ impl Galaxy {
         self.id_to_index.get(&id).cloned()
     }
 
-    /// Helper function for retrieving and increment the next id
-    fn next_id(&mut self) -> ID {
-        let id = self.next_id;
-        self.next_id += 1;
-        id
-    }
-
-    /// Pretty-prints the galaxy to the writer provided
+    /// Finds the celestial body referred to by `reference`, which is either its
+    /// short key (ignoring case) or its ID in the configured format. The ID
+    /// may be written as it is displayed, e.g. `#42` or `WEB-42`. Random IDs
+    /// may be shortened to any prefix that is unique. The project of the
+    /// `Galaxy` may come first, e.g. `web:#42`.
     ///
-    /// # Arguments
-    /// - `w`: Writer to write everything into
-    /// - `width`: Horizontal character limit. Lines will be truncated to this
-    ///   length and "..." will be used to denote the truncation
-    /// - `include_description`: Whether or not the print the description fields
-    ///   when pretty printing
-    /// - `recursive`: Recurse through the children or only print the first
-    ///   layer
-    pub fn pretty_print_to_writer<W: io::Write>(
-        &self,
-        w: &mut W,
-        width: usize,
-        include_description: bool,
-        recursive: bool,
-    ) -> io::Result<()> {
-        let mut children: Vec<Box<&dyn PrintTreeNode<Self>>> = self
-            .comets
-            .iter()
-            .map(|comet| Box::new(comet as &dyn PrintTreeNode<Galaxy>))
+    /// # Errors
+    /// Errors will occur if there is no celestial body with the ID or key
+    pub fn resolve(&self, reference: &str) -> std::result::Result<ID, GalaxyError> {
+        let reference = reference.trim();
+        let namespace = self.namespace();
+        let reference = match reference.get(..namespace.len()) {
+            Some(prefix) if !namespace.is_empty() && prefix.eq_ignore_ascii_case(&namespace) => {
+                &reference[namespace.len()..]
+            }
+            _ => reference,
+        };
+        if let Some(body) = self.iter().find(|body| {
+            body.key()
+                .is_some_and(|k| k.eq_ignore_ascii_case(reference))
+        }) {
+            return Ok(body.id());
+        }
+
+        let prefixes = self.stars.iter().filter_map(|s| s.id_prefix.as_deref());
+        let candidates: Vec<&str> = std::iter::once("#")
+            .chain(prefixes)
+            .filter_map(|prefix| {
+                let len = prefix.len();
+                let matches = reference.len() > len
+                    && reference.is_char_boundary(len)
+                    && reference[..len].eq_ignore_ascii_case(prefix);
+                matches.then(|| &reference[len..])
+            })
+            .chain([reference])
             .collect();
-        children.extend(
-            self.planets
-                .iter()
-                .map(|planet| Box::new(planet as &dyn PrintTreeNode<Galaxy>)),
-        );
-        children.extend(
-            self.stars
-                .iter()
-                .map(|star| Box::new(star as &dyn PrintTreeNode<Galaxy>)),
-        );
+        let id = candidates.iter().find_map(|id| self.id_format.parse(id));
+        match id {
+            Some(id) if self.get(id).is_some() => Ok(id),
+            // Random IDs are displayed as prefixes, which need to be unique
+            _ if self.id_scheme == IdScheme::Random => {
+                let candidates: Vec<String> = candidates
+                    .iter()
+                    .filter(|c| !c.is_empty())
+                    .map(|c| c.to_lowercase())
+                    .collect();
+                let mut matches = self.id_to_index.keys().filter(|id| {
+                    let id = self.id_format.format(**id);
+                    candidates.iter().any(|c| id.starts_with(c.as_str()))
+                });
+                match (matches.next(), matches.next()) {
+                    (Some(id), None) => Ok(*id),
+                    (Some(_), Some(_)) => Err(GalaxyError::AmbiguousId(reference.to_string())),
+                    (None, _) => Err(GalaxyError::KeyNotFound(reference.to_string())),
+                }
+            }
+            Some(id) => Err(GalaxyError::CelestialBodyNotFound(id)),
+            None => Err(GalaxyError::KeyNotFound(reference.to_string())),
+        }
+    }
 
-        util::tree::print_to_writer(
-            self,
-            w,
-            width,
-            include_description,
-            recursive,
-            self.title.purple(),
-            self.description.bright_black(),
-            children,
-        )?;
+    /// Returns a reference to the celestial body with the ID if it exists
+    pub fn get(&self, id: ID) -> Option<CelestialBodyRef<'_>> {
+        let index = self.index(id)?;
+        let body = match index.kind {
+            CelestialBodyKind::Comet => CelestialBodyRef::Comet(&self.comets[index.index]),
+            CelestialBodyKind::Planet => CelestialBodyRef::Planet(&self.planets[index.index]),
+            CelestialBodyKind::Star => CelestialBodyRef::Star(&self.stars[index.index]),
+        };
+        Some(body)
+    }
 
-        Ok(())
+    /// Returns a mutable reference to the celestial body with the ID if it
+    /// exists
+    pub fn get_mut(&mut self, id: ID) -> Option<CelestialBodyMut<'_>> {
+        let index = self.index(id)?;
+        self.touch(id);
+        let body = match index.kind {
+            CelestialBodyKind::Comet => CelestialBodyMut::Comet(&mut self.comets[index.index]),
+            CelestialBodyKind::Planet => CelestialBodyMut::Planet(&mut self.planets[index.index]),
+            CelestialBodyKind::Star => CelestialBodyMut::Star(&mut self.stars[index.index]),
+        };
+        Some(body)
     }
-}
 
-////////////////////////////////////////////////////////////////////////////////
-//                                                                            //
-//                                   TESTS                                    //
-//                                                                            //
-////////////////////////////////////////////////////////////////////////////////
+    /// Moves the celestial body `id` so that it is owned by `parent`, or to the
+    /// root of the `Galaxy` if `parent` is `None`
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - `id` or `parent` do not exist
+    /// - `parent` is not a `Star`
+    /// - `parent` is `id` or one of its descendants
+    pub fn set_parent(
+        &mut self,
+        id: ID,
+        parent: Option<ID>,
+    ) -> std::result::Result<(), GalaxyError> {
+        let body = self.get(id).ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+        let old_parent = body.parent();
 
-#[cfg(test)]
-mod test {
-    use chrono::DateTime;
+        if let Some(parent) = parent {
+            let index = self
+                .index(parent)
+                .ok_or(GalaxyError::CelestialBodyNotFound(parent))?;
+            if index.kind != CelestialBodyKind::Star {
+                return Err(GalaxyError::NotAStar(parent));
+            }
+            // Walk up from the new parent to make sure `id` is not an ancestor
+            let mut ancestor = Some(parent);
+            while let Some(a) = ancestor {
+                if a == id {
+                    return Err(GalaxyError::CyclicParent(id));
+                }
+                ancestor = self.get(a).and_then(|b| b.parent());
+            }
+        }
 
-    use crate::core::{Status, StatusHistory};
+        if let Some(old) = old_parent.and_then(|old| self.index(old)) {
+            self.stars[old.index].children.retain(|child| *child != id);
+            self.touch(self.stars[old.index].id);
+        }
+        if let Some(new) = parent.and_then(|new| self.index(new)) {
+            self.stars[new.index].children.push(id);
+            self.touch(self.stars[new.index].id);
+        }
 
-    use super::*;
+        info!("Moving celestial body ({id}) from {old_parent:?} to {parent:?}");
+        if let Some(mut body) = self.get_mut(id) {
+            body.record(Change::Parent {
+                old: old_parent,
+                new: parent,
+            });
+        }
+        self.set_parent_field(id, parent);
+
+        Ok(())
+    }
+
+    /// Helper function that sets the short key of the celestial body `id`
+    fn set_key_field(&mut self, id: ID, key: Option<String>) {
+        self.touch(id);
+        match self.id_to_index[&id] {
+            CelestialBodyIndex {
+                kind: CelestialBodyKind::Comet,
+                index,
+            } => self.comets[index].key = key,
+            CelestialBodyIndex {
+                kind: CelestialBodyKind::Planet,
+                index,
+            } => self.planets[index].key = key,
+            CelestialBodyIndex {
+                kind: CelestialBodyKind::Star,
+                index,
+            } => self.stars[index].key = key,
+        }
+    }
+
+    /// Helper function that only sets the `parent` field of the celestial body
+    /// `id`, without updating the children of the old and new parent
+    fn set_parent_field(&mut self, id: ID, parent: Option<ID>) {
+        self.touch(id);
+        match self.id_to_index[&id] {
+            CelestialBodyIndex {
+                kind: CelestialBodyKind::Comet,
+                index,
+            } => self.comets[index].parent = parent,
+            CelestialBodyIndex {
+                kind: CelestialBodyKind::Planet,
+                index,
+            } => self.planets[index].parent = parent,
+            CelestialBodyIndex {
+                kind: CelestialBodyKind::Star,
+                index,
+            } => self.stars[index].parent = parent,
+        }
+    }
+
+    /// The celestial bodies with the same parent as `id` (including `id`),
+    /// in the order they were arranged in. See `place`.
+    pub fn siblings(&self, id: ID) -> Vec<ID> {
+        let parent = self.get(id).and_then(|body| body.parent());
+        let mut siblings: Vec<_> = match parent.and_then(|p| self.get(p)) {
+            Some(parent) => parent.children().to_vec(),
+            None => self
+                .iter()
+                .filter(|body| body.parent().is_none())
+                .map(|body| body.id())
+                .collect(),
+        };
+        Sort::Rank.sort(self, &mut siblings);
+        siblings
+    }
+
+    /// Moves the celestial body `id` right before (or after if `after`) its
+    /// sibling `target`, moving it under the parent of `target` first if they
+    /// are not siblings. The siblings are ranked from 0 in their new order,
+    /// which `Sort::Rank` lists them in.
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - `id` or `target` do not exist
+    /// - `target` is a descendant of `id`
+    pub fn place(
+        &mut self,
+        id: ID,
+        target: ID,
+        after: bool,
+    ) -> std::result::Result<(), GalaxyError> {
+        self.get(id).ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+        let parent = self
+            .get(target)
+            .ok_or(GalaxyError::CelestialBodyNotFound(target))?
+            .parent();
+        if id == target {
+            return Ok(());
+        }
+        if self.get(id).and_then(|body| body.parent()) != parent {
+            self.set_parent(id, parent)?;
+        }
+
+        let mut siblings = self.siblings(id);
+        siblings.retain(|sibling| *sibling != id);
+        let position = siblings.iter().position(|s| *s == target).unwrap_or(0);
+        siblings.insert(position + usize::from(after), id);
+
+        info!("Placing celestial body ({id}) next to ({target})");
+        for (rank, sibling) in (0..).zip(siblings) {
+            self.touch(sibling);
+            match self.index(sibling) {
+                Some(CelestialBodyIndex {
+                    kind: CelestialBodyKind::Comet,
+                    index,
+                }) => self.comets[index].rank = Some(rank),
+                Some(CelestialBodyIndex {
+                    kind: CelestialBodyKind::Planet,
+                    index,
+                }) => self.planets[index].rank = Some(rank),
+                Some(CelestialBodyIndex {
+                    kind: CelestialBodyKind::Star,
+                    index,
+                }) => self.stars[index].rank = Some(rank),
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Swaps the celestial body `id` with the sibling shown right above it (or
+    /// below if `down`), skipping hidden ones. Returns whether it was moved,
+    /// which it is not at either end.
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - `id` does not exist
+    pub fn shift(&mut self, id: ID, down: bool) -> std::result::Result<bool, GalaxyError> {
+        let mut siblings = self.siblings(id);
+        siblings.retain(|sibling| *sibling == id || !self.is_hidden(*sibling));
+        let position = siblings
+            .iter()
+            .position(|sibling| *sibling == id)
+            .ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+        let neighbour = match down {
+            true => siblings.get(position + 1),
+            false => position.checked_sub(1).and_then(|p| siblings.get(p)),
+        };
+        match neighbour {
+            Some(&neighbour) => self.place(id, neighbour, down).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Copies the celestial body `id` and all of its descendants under
+    /// `parent`, or to the root of the `Galaxy` if `parent` is `None`. The
+    /// copies get new IDs and keys, and start over as `Todo` with an empty
+    /// history, work log, list of commits, and reason for being blocked, and
+    /// with nothing on their checklist checked off. Everything else (e.g. the description, tags, and
+    /// estimate) is copied.
+    ///
+    /// # Returns
+    /// The ID of the copy of `id`
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - `id` or `parent` do not exist
+    /// - `parent` is not a `Star`
+    pub fn copy(&mut self, id: ID, parent: Option<ID>) -> std::result::Result<ID, GalaxyError> {
+        let index = self
+            .index(id)
+            .ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+        if let Some(parent) = parent {
+            match self.index(parent) {
+                None => return Err(GalaxyError::CelestialBodyNotFound(parent)),
+                Some(p) if p.kind != CelestialBodyKind::Star => {
+                    return Err(GalaxyError::NotAStar(parent));
+                }
+                Some(_) => {}
+            }
+        }
+
+        info!("Copying celestial body ({id}) to {parent:?}");
+        let (copy, children) = match index.kind {
+            CelestialBodyKind::Comet => {
+                let source = self.comets[index.index].clone();
+                let comet = self.comet();
+                *comet = Comet {
+                    id: comet.id,
+                    key: comet.key.take(),
+                    parent: None,
+                    status: Status::default(),
+                    history: Vec::new(),
+                    work_log: Default::default(),
+                    commits: Vec::new(),
+                    blocker: None,
+                    stage: None,
+                    checklist: source
+                        .checklist
+                        .iter()
+                        .map(|item| ChecklistItem::new(item.text.clone()))
+                        .collect(),
+                    events: mem::take(&mut comet.events),
+                    updated_at: comet.updated_at,
+                    ..source
+                };
+                (comet.id, Vec::new())
+            }
+            CelestialBodyKind::Planet => {
+                let source = self.planets[index.index].clone();
+                let planet = self.planet();
+                *planet = Planet {
+                    id: planet.id,
+                    key: planet.key.take(),
+                    parent: None,
+                    status: Status::default(),
+                    history: Vec::new(),
+                    work_log: Default::default(),
+                    commits: Vec::new(),
+                    blocker: None,
+                    stage: None,
+                    checklist: source
+                        .checklist
+                        .iter()
+                        .map(|item| ChecklistItem::new(item.text.clone()))
+                        .collect(),
+                    events: mem::take(&mut planet.events),
+                    updated_at: planet.updated_at,
+                    ..source
+                };
+                (planet.id, Vec::new())
+            }
+            CelestialBodyKind::Star => {
+                let source = self.stars[index.index].clone();
+                let children = source.children.clone();
+                let star = self.star();
+                *star = Star {
+                    id: star.id,
+                    key: star.key.take(),
+                    // Prefixes have to be unique
+                    id_prefix: None,
+                    parent: None,
+                    status: Status::default(),
+                    history: Vec::new(),
+                    work_log: Default::default(),
+                    commits: Vec::new(),
+                    blocker: None,
+                    stage: None,
+                    events: mem::take(&mut star.events),
+                    updated_at: star.updated_at,
+                    children: Vec::new(),
+                    ..source
+                };
+                (star.id, children)
+            }
+        };
+
+        // The children are looked up before the copy is made, so copying a
+        // `Star` into itself does not copy the copy
+        for child in children {
+            self.copy(child, Some(copy))?;
+        }
+        if parent.is_some() {
+            self.set_parent(copy, parent)?;
+        }
+        Ok(copy)
+    }
+
+    /// Moves the celestial body `id` and all of its descendants into the trash
+    /// at `now`. Work being tracked on any of them is stopped first.
+    ///
+    /// # Returns
+    /// The number of celestial bodies that were moved into the trash
+    ///
+    /// # Errors
+    /// Errors will occur if `id` does not exist
+    pub fn delete(
+        &mut self,
+        id: ID,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<usize, GalaxyError> {
+        let parent = self
+            .get(id)
+            .ok_or(GalaxyError::CelestialBodyNotFound(id))?
+            .parent();
+
+        let mut ids = HashSet::new();
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let Some(body) = self.get(id) else {
+                continue;
+            };
+            if ids.insert(id) {
+                stack.extend(body.children());
+            }
+        }
+
+        if self
+            .tracking()
+            .is_some_and(|tracking| ids.contains(&tracking))
+        {
+            self.stop_tracking(now);
+        }
+        if let Some(parent) = parent.and_then(|parent| self.index(parent)) {
+            self.stars[parent.index]
+                .children
+                .retain(|child| *child != id);
+            self.touch(self.stars[parent.index].id);
+        }
+        if let Some(mut body) = self.get_mut(id) {
+            body.record(Change::Deleted);
+        }
+
+        info!(
+            "Moving celestial body ({id}) and {} descendants to the trash",
+            ids.len() - 1
+        );
+        let (comets, kept): (Vec<_>, Vec<_>) = mem::take(&mut self.comets)
+            .into_iter()
+            .partition(|c| ids.contains(&c.id));
+        self.comets = kept;
+        let (planets, kept): (Vec<_>, Vec<_>) = mem::take(&mut self.planets)
+            .into_iter()
+            .partition(|p| ids.contains(&p.id));
+        self.planets = kept;
+        let (stars, kept): (Vec<_>, Vec<_>) = mem::take(&mut self.stars)
+            .into_iter()
+            .partition(|s| ids.contains(&s.id));
+        self.stars = kept;
+        self.reindex();
+
+        self.trash.push(TrashEntry {
+            id,
+            deleted: now,
+            comets,
+            planets,
+            stars,
+        });
+
+        Ok(ids.len())
+    }
+
+    /// Restores the celestial body `id` and its descendants from the trash. It
+    /// is put back under its old parent, or in the root of the `Galaxy` if the
+    /// old parent no longer exists.
+    ///
+    /// # Returns
+    /// The number of celestial bodies that were restored
+    ///
+    /// # Errors
+    /// Errors will occur if `id` is not in the trash
+    pub fn restore(&mut self, id: ID) -> std::result::Result<usize, GalaxyError> {
+        let position = self
+            .trash
+            .iter()
+            .position(|entry| entry.id == id)
+            .ok_or(GalaxyError::NotInTrash(id))?;
+        let entry = self.trash.remove(position);
+        let restored = entry.len();
+
+        info!("Restoring celestial body ({id}) from the trash");
+        let ids = (entry.comets.iter().map(|comet| comet.id))
+            .chain(entry.planets.iter().map(|planet| planet.id))
+            .chain(entry.stars.iter().map(|star| star.id));
+        for restored_id in ids.collect::<Vec<_>>() {
+            self.touch(restored_id);
+        }
+        self.comets.extend(entry.comets);
+        self.planets.extend(entry.planets);
+        self.stars.extend(entry.stars);
+        self.reindex();
+
+        let parent = self.get(id).and_then(|body| body.parent());
+        match parent.and_then(|parent| self.index(parent)) {
+            Some(index) if index.kind == CelestialBodyKind::Star => {
+                self.stars[index.index].children.push(id);
+                self.touch(self.stars[index.index].id);
+            }
+            _ => self.set_parent_field(id, None),
+        }
+        if let Some(mut body) = self.get_mut(id) {
+            body.record(Change::Restored);
+        }
+
+        Ok(restored)
+    }
+
+    /// Returns the deleted celestial bodies in the trash, oldest first
+    pub fn trash(&self) -> &[TrashEntry] {
+        &self.trash
+    }
+
+    /// Permanently removes everything in the trash that was deleted before
+    /// `before`, or everything if `before` is `None`
+    ///
+    /// # Returns
+    /// The number of entries that were removed
+    pub fn empty_trash(&mut self, before: Option<DateTime<Utc>>) -> usize {
+        let (removed, kept): (Vec<_>, Vec<_>) = mem::take(&mut self.trash)
+            .into_iter()
+            .partition(|entry| before.is_none_or(|before| entry.deleted < before));
+        self.trash = kept;
+
+        info!(
+            "Permanently removed {} entries from the trash",
+            removed.len()
+        );
+        removed.len()
+    }
+
+    /// Totals the estimates of the celestial body `id` and all of its
+    /// descendants. Celestial bodies that are `Done` count as completed, while
+    /// those that are `Cancel`ed are not counted at all.
+    ///
+    /// # Returns
+    /// `None` if there is no celestial body with the ID
+    pub fn rollup(&self, id: ID) -> Option<Rollup> {
+        self.get(id)?;
+
+        let mut rollup = Rollup::default();
+        let mut visited = HashSet::new();
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let Some(body) = self.get(id) else {
+                continue;
+            };
+            if !visited.insert(id) {
+                continue;
+            }
+            if let Some(estimate) = body.estimate() {
+                match body.status() {
+                    Status::Cancel => {}
+                    status => rollup.add(estimate, status == Status::Done),
+                }
+            }
+            stack.extend(body.children());
+        }
+
+        Some(rollup)
+    }
+
+    /// Counts how many of the descendants of the celestial body `id` are in a
+    /// final state (`Done` or `Cancel`). All descendants are counted, not just
+    /// the direct children.
+    ///
+    /// # Returns
+    /// `None` if there is no celestial body with the ID
+    pub fn progress(&self, id: ID) -> Option<Progress> {
+        let mut progress = Progress::default();
+        let mut visited = HashSet::from([id]);
+        let mut stack = self.get(id)?.children().to_vec();
+        while let Some(id) = stack.pop() {
+            let Some(body) = self.get(id) else {
+                continue;
+            };
+            if !visited.insert(id) {
+                continue;
+            }
+            progress.total += 1;
+            if body.status().is_final() {
+                progress.finished += 1;
+            }
+            stack.extend(body.children());
+        }
+
+        Some(progress)
+    }
+
+    /// Returns the ID of the celestial body that work is currently being
+    /// tracked on, if any
+    pub fn tracking(&self) -> Option<ID> {
+        self.iter()
+            .find(|body| body.work_log().active().is_some())
+            .map(|body| body.id())
+    }
+
+    /// Starts tracking work on the celestial body `id` at `now`. Only one
+    /// celestial body can be tracked at a time, so any other tracking is
+    /// stopped first.
+    ///
+    /// # Returns
+    /// The ID and length of the session that was stopped, if any
+    ///
+    /// # Errors
+    /// Errors will occur if `id` does not exist
+    pub fn start_tracking(
+        &mut self,
+        id: ID,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<Option<(ID, TimeDelta)>, GalaxyError> {
+        self.index(id)
+            .ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+
+        let stopped = match self.tracking() {
+            Some(tracking) if tracking == id => return Ok(None),
+            Some(_) => self.stop_tracking(now),
+            None => None,
+        };
+        if let Some(mut body) = self.get_mut(id) {
+            info!("Starting to track work on ({id})");
+            body.work_log().start(now);
+        }
+
+        Ok(stopped)
+    }
+
+    /// Stops tracking work at `now`
+    ///
+    /// # Returns
+    /// The ID and length of the session that was stopped, `None` if nothing
+    /// was being tracked
+    pub fn stop_tracking(&mut self, now: DateTime<Utc>) -> Option<(ID, TimeDelta)> {
+        let id = self.tracking()?;
+        info!("Stopping tracking work on ({id})");
+        let elapsed = self.get_mut(id)?.work_log().stop(now)?;
+        Some((id, elapsed))
+    }
+
+    /// Returns the retrospective attached to the sprint `sprint` (case
+    /// insensitive), creating an empty one if it does not exist yet. Closed
+    /// sprints can have a retrospective too.
+    ///
+    /// # Errors
+    /// Errors will occur if there is no sprint named `sprint`
+    pub fn retro(&mut self, sprint: &str) -> std::result::Result<&mut Retro, GalaxyError> {
+        let name = self
+            .sprint(sprint)
+            .map(|s| s.name.clone())
+            .ok_or_else(|| GalaxyError::SprintNotFound(sprint.to_string()))?;
+
+        let position = match self.retros.iter().position(|r| r.sprint == name) {
+            Some(position) => position,
+            None => {
+                info!("Creating new retrospective for sprint \"{name}\"");
+                self.retros.push(Retro::new(name));
+                self.retros.len() - 1
+            }
+        };
+        Ok(&mut self.retros[position])
+    }
+
+    /// Returns the retrospective attached to the sprint `sprint` (case
+    /// insensitive) if it exists
+    pub fn get_retro(&self, sprint: &str) -> Option<&Retro> {
+        self.retros
+            .iter()
+            .find(|r| r.sprint.eq_ignore_ascii_case(sprint))
+    }
+
+    /// Adds an action item to the retrospective of the sprint `sprint`. The
+    /// action item is tracked as a new `Comet`, which is added to the open
+    /// sprint that starts next after `sprint` (if there is one).
+    ///
+    /// # Returns
+    /// The ID of the new `Comet`
+    ///
+    /// # Errors
+    /// Errors will occur if there is no sprint named `sprint`
+    pub fn retro_action_item(
+        &mut self,
+        sprint: &str,
+        title: String,
+    ) -> std::result::Result<ID, GalaxyError> {
+        let name = self.retro(sprint)?.sprint.clone();
+        let next = self.sprint(&name).and_then(|current| {
+            self.sprints
+                .iter()
+                .filter(|s| !s.closed && s.start > current.start)
+                .min_by_key(|s| s.start)
+                .map(|s| s.name.clone())
+        });
+
+        let id = self.comet().title(title).id();
+        if let Some(next) = next {
+            self.sprint_add(&next, id)?;
+        }
+        self.retro(&name)?.action_items.push(id);
+        Ok(id)
+    }
+
+    /// Renames the tag `old` to `new` on every celestial body, including those
+    /// in the trash. Celestial bodies that already have `new` lose `old`.
+    ///
+    /// # Returns
+    /// The IDs of the celestial bodies that had the tag
+    pub fn rename_tag(&mut self, old: &str, new: &str) -> Vec<ID> {
+        let mut renamed = Vec::new();
+        if old == new {
+            return renamed;
+        }
+
+        info!("Renaming tag {old} to {new}");
+        for planet in self.planets_mut() {
+            if CelestialBodyMut::Planet(planet).rename_tag(old, new) {
+                renamed.push(planet.id);
+            }
+        }
+        renamed
+    }
+
+    /// Renames the field `old` to `new` on every celestial body, including
+    /// those in the trash. Nothing is changed if any celestial body already
+    /// has both fields.
+    ///
+    /// # Returns
+    /// The IDs of the celestial bodies that had the field
+    ///
+    /// # Errors
+    /// Errors will occur if a celestial body has both `old` and `new`
+    pub fn rename_field(
+        &mut self,
+        old: &str,
+        new: &str,
+    ) -> std::result::Result<Vec<ID>, GalaxyError> {
+        let mut renamed = Vec::new();
+        if old == new {
+            return Ok(renamed);
+        }
+        if let Some(planet) = self
+            .planets_mut()
+            .find(|p| p.fields.contains_key(old) && p.fields.contains_key(new))
+        {
+            return Err(GalaxyError::FieldExists(planet.id, new.to_string()));
+        }
+
+        info!("Renaming field {old} to {new}");
+        for planet in self.planets_mut() {
+            if CelestialBodyMut::Planet(planet).rename_field(old, new) {
+                renamed.push(planet.id);
+            }
+        }
+        Ok(renamed)
+    }
+
+    /// Helper function that returns every `Planet`, including those in the
+    /// trash. Only Planets have tags and fields.
+    fn planets_mut(&mut self) -> impl Iterator<Item = &mut Planet> {
+        let ids: Vec<ID> = self.planets.iter().map(|planet| planet.id).collect();
+        for id in ids {
+            self.touch(id);
+        }
+        self.planets.iter_mut().chain(
+            self.trash
+                .iter_mut()
+                .flat_map(|entry| entry.planets.iter_mut()),
+        )
+    }
+
+    /// Returns all day plans, oldest first
+    pub fn plans(&self) -> &[DayPlan] {
+        &self.plans
+    }
+
+    /// Returns the plan for `date` if anything was picked for that day
+    pub fn plan(&self, date: NaiveDate) -> Option<&DayPlan> {
+        self.plans.iter().find(|plan| plan.date == date)
+    }
+
+    /// Picks the celestial body `id` to work on during `date`
+    ///
+    /// # Returns
+    /// `false` if it was already picked for that day
+    ///
+    /// # Errors
+    /// Errors will occur if `id` does not exist
+    pub fn plan_add(&mut self, date: NaiveDate, id: ID) -> std::result::Result<bool, GalaxyError> {
+        self.index(id)
+            .ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+
+        let plan = self.plan_mut(date);
+        if plan.items.contains(&id) {
+            return Ok(false);
+        }
+        info!("Adding ({id}) to the plan for {date}");
+        plan.items.push(id);
+        Ok(true)
+    }
+
+    /// Removes the celestial body `id` from the plan for `date`
+    ///
+    /// # Returns
+    /// `false` if it was not picked for that day
+    pub fn plan_remove(&mut self, date: NaiveDate, id: ID) -> bool {
+        let Some(plan) = self.plans.iter_mut().find(|plan| plan.date == date) else {
+            return false;
+        };
+        let len = plan.items.len();
+        plan.items.retain(|item| *item != id);
+        len != plan.items.len()
+    }
+
+    /// Carries the unfinished celestial bodies of every earlier plan that has
+    /// not been carried over yet into the plan for `today`. Each celestial
+    /// body that is carried over records it in its activity log.
+    ///
+    /// # Returns
+    /// The IDs of the celestial bodies that were carried over
+    pub fn carry_over(&mut self, today: NaiveDate) -> Vec<ID> {
+        let mut unfinished = Vec::new();
+        self.plans.sort_by_key(|plan| plan.date);
+        for plan in self
+            .plans
+            .iter_mut()
+            .filter(|plan| plan.date < today && !plan.carried_over)
+        {
+            plan.carried_over = true;
+            unfinished.extend(plan.items.iter().map(|id| (plan.date, *id)));
+        }
+
+        let mut carried = Vec::new();
+        for (from, id) in unfinished {
+            let finished = self.get(id).is_none_or(|body| body.status().is_final());
+            if finished || !self.plan_add(today, id).unwrap_or(false) {
+                continue;
+            }
+            info!("Carrying ({id}) over from {from} to {today}");
+            if let Some(mut body) = self.get_mut(id) {
+                body.record(Change::CarriedOver { from, to: today });
+            }
+            carried.push(id);
+        }
+        carried
+    }
+
+    /// Returns the working set for `today`: the unfinished celestial bodies
+    /// that are pinned, due by `today`, or started (Planets and Comets only,
+    /// since Stars are started along with their children). Pinned ones come
+    /// first, and each part is in order of priority.
+    pub fn focus(&self, today: NaiveDate) -> Vec<ID> {
+        let mut bodies: Vec<_> = self
+            .iter()
+            .filter(|body| !body.status().is_final() && !self.is_hidden_on(body.id(), today))
+            .filter(|body| {
+                let started =
+                    body.status() == Status::Start && body.kind() != CelestialBodyKind::Star;
+                body.pinned() || started || body.due().is_some_and(|due| due <= today)
+            })
+            .collect();
+        bodies.sort_by(|a, b| {
+            b.pinned()
+                .cmp(&a.pinned())
+                .then_with(|| Sort::Priority.compare(a, b))
+        });
+        bodies.iter().map(|body| body.id()).collect()
+    }
+
+    /// Returns all sprints, in the order they were created
+    pub fn sprints(&self) -> &[Sprint] {
+        &self.sprints
+    }
+
+    /// Returns the sprint named `name` (case insensitive)
+    pub fn sprint(&self, name: &str) -> Option<&Sprint> {
+        self.sprints
+            .iter()
+            .find(|sprint| sprint.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the open sprint that `date` is a day of, the one that started
+    /// last if there are several
+    pub fn active_sprint(&self, date: NaiveDate) -> Option<&Sprint> {
+        self.sprints
+            .iter()
+            .filter(|sprint| sprint.is_active(date))
+            .max_by_key(|sprint| sprint.start)
+    }
+
+    /// Returns the sprint that `id` is in, preferring open sprints
+    pub fn sprint_of(&self, id: ID) -> Option<&Sprint> {
+        let mut sprints = self.sprints.iter().filter(|s| s.items.contains(&id));
+        sprints
+            .clone()
+            .find(|s| !s.closed)
+            .or_else(|| sprints.next_back())
+    }
+
+    /// Creates the empty sprint `name` from `start` to `end` (inclusive)
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - A sprint named `name` already exists
+    /// - `end` is before `start`
+    pub fn sprint_create(
+        &mut self,
+        name: String,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> std::result::Result<(), GalaxyError> {
+        if self.sprint(&name).is_some() {
+            return Err(GalaxyError::SprintExists(name));
+        }
+        if end < start {
+            return Err(GalaxyError::InvalidSprintDates(start, end));
+        }
+        info!("Creating sprint \"{name}\" from {start} to {end}");
+        self.sprints.push(Sprint::new(name, start, end));
+        Ok(())
+    }
+
+    /// Adds the celestial body `id` to the sprint `name`, removing it from any
+    /// other open sprint
+    ///
+    /// # Returns
+    /// `false` if it was already in the sprint
+    ///
+    /// # Errors
+    /// Errors will occur in the following situations:
+    /// - `id` does not exist
+    /// - There is no sprint named `name`, or it is closed
+    pub fn sprint_add(&mut self, name: &str, id: ID) -> std::result::Result<bool, GalaxyError> {
+        self.index(id)
+            .ok_or(GalaxyError::CelestialBodyNotFound(id))?;
+        let position = self.open_sprint(name)?;
+        if self.sprints[position].items.contains(&id) {
+            return Ok(false);
+        }
+
+        for sprint in self.sprints.iter_mut().filter(|s| !s.closed) {
+            sprint.items.retain(|item| *item != id);
+        }
+        info!("Adding ({id}) to sprint \"{name}\"");
+        self.sprints[position].items.push(id);
+        Ok(true)
+    }
+
+    /// Removes the celestial body `id` from the sprint `name`
+    ///
+    /// # Returns
+    /// `false` if it was not in the sprint
+    ///
+    /// # Errors
+    /// Errors will occur if there is no sprint named `name`, or it is closed
+    pub fn sprint_remove(&mut self, name: &str, id: ID) -> std::result::Result<bool, GalaxyError> {
+        let position = self.open_sprint(name)?;
+        let sprint = &mut self.sprints[position];
+        let len = sprint.items.len();
+        sprint.items.retain(|item| *item != id);
+        Ok(len != sprint.items.len())
+    }
+
+    /// Closes the sprint `name`, carrying its unfinished celestial bodies over
+    /// to the open sprint that starts next after it (if there is one). Each
+    /// celestial body that is carried over records it in its activity log.
+    ///
+    /// # Returns
+    /// The name of the sprint the unfinished celestial bodies were carried
+    /// over to, and their IDs
+    ///
+    /// # Errors
+    /// Errors will occur if there is no sprint named `name`, or it is closed
+    pub fn sprint_close(
+        &mut self,
+        name: &str,
+    ) -> std::result::Result<(Option<String>, Vec<ID>), GalaxyError> {
+        let position = self.open_sprint(name)?;
+        self.sprints[position].closed = true;
+        let closed = &self.sprints[position];
+        info!("Closing sprint \"{}\"", closed.name);
+
+        let next = self
+            .sprints
+            .iter()
+            .filter(|s| !s.closed && s.start >= closed.start)
+            .min_by_key(|s| s.start)
+            .map(|s| s.name.clone());
+        let unfinished: Vec<ID> = closed
+            .items
+            .iter()
+            .copied()
+            .filter(|id| self.get(*id).is_some_and(|b| !b.status().is_final()))
+            .collect();
+        let Some(next) = next else {
+            return Ok((None, unfinished));
+        };
+
+        let from = self.sprints[position].name.clone();
+        for id in &unfinished {
+            if self.sprint_add(&next, *id)? {
+                info!("Carrying ({id}) over from sprint \"{from}\" to \"{next}\"");
+                if let Some(mut body) = self.get_mut(*id) {
+                    body.record(Change::SprintCarriedOver {
+                        from: from.clone(),
+                        to: next.clone(),
+                    });
+                }
+            }
+        }
+        Ok((Some(next), unfinished))
+    }
+
+    /// Helper function that finds the position of the open sprint `name`
+    fn open_sprint(&self, name: &str) -> std::result::Result<usize, GalaxyError> {
+        let position = self
+            .sprints
+            .iter()
+            .position(|sprint| sprint.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| GalaxyError::SprintNotFound(name.to_string()))?;
+        match self.sprints[position].closed {
+            true => Err(GalaxyError::SprintClosed(
+                self.sprints[position].name.clone(),
+            )),
+            false => Ok(position),
+        }
+    }
+
+    /// Helper function that returns the plan for `date`, creating an empty one
+    /// if it does not exist yet
+    fn plan_mut(&mut self, date: NaiveDate) -> &mut DayPlan {
+        let position = match self.plans.iter().position(|plan| plan.date == date) {
+            Some(position) => position,
+            None => {
+                self.plans.push(DayPlan::new(date));
+                self.plans.len() - 1
+            }
+        };
+        &mut self.plans[position]
+    }
+
+    /// Whether `id` or any of its ancestors have been archived
+    pub fn is_archived(&self, id: ID) -> bool {
+        let mut ancestor = Some(id);
+        while let Some(body) = ancestor.and_then(|a| self.get(a)) {
+            if body.archived() {
+                return true;
+            }
+            ancestor = body.parent();
+        }
+        false
+    }
+
+    /// The titles of the ancestors of `id` and its own, starting at the root,
+    /// e.g. `["Backend", "Auth", "Fix token refresh"]`. Empty if `id` does not
+    /// exist.
+    pub fn path(&self, id: ID) -> Vec<String> {
+        let mut path = Vec::new();
+        let mut ancestor = Some(id);
+        while let Some(body) = ancestor.and_then(|a| self.get(a)) {
+            path.push(body.title().to_string());
+            ancestor = body.parent();
+        }
+        path.reverse();
+        path
+    }
+
+    /// The titles of the ancestors of `id` leading up to its title, e.g.
+    /// `Backend > Auth >`. Empty for celestial bodies at the root.
+    pub fn breadcrumb(&self, id: ID) -> String {
+        let path = self.path(id);
+        match path.split_last() {
+            Some((_, ancestors)) if !ancestors.is_empty() => {
+                format!("{} >", ancestors.join(" > "))
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// Whether `id` or any of its ancestors are snoozed on `today`
+    pub fn is_snoozed(&self, id: ID, today: NaiveDate) -> bool {
+        let mut ancestor = Some(id);
+        while let Some(body) = ancestor.and_then(|a| self.get(a)) {
+            if body.is_snoozed(today) {
+                return true;
+            }
+            ancestor = body.parent();
+        }
+        false
+    }
+
+    /// Whether `id` should be left out when printing, i.e. it is archived or
+    /// snoozed and such celestial bodies are not being shown
+    pub fn is_hidden(&self, id: ID) -> bool {
+        self.is_hidden_on(id, Local::now().date_naive())
+    }
+
+    /// Same as `is_hidden`, with snoozes checked against `today`
+    fn is_hidden_on(&self, id: ID, today: NaiveDate) -> bool {
+        !self.show_archived && (self.is_archived(id) || self.is_snoozed(id, today))
+    }
+
+    /// Why `body` is blocked or on hold as a note for printing, `None` if
+    /// reasons are not being shown or there is none
+    pub fn reason_note(&self, body: &CelestialBodyRef) -> Option<String> {
+        let blocker = body.blocker().filter(|_| self.show_reasons)?;
+        let label = match body.status() {
+            Status::Hold => "On hold",
+            _ => "Blocked",
+        };
+        Some(format!("{label}: {}", blocker.describe(self)))
+    }
+
+    /// How `body` is referred to when printing, its key if it has one and its
+    /// ID (see `display_id`) otherwise
+    pub fn id_label(&self, body: &CelestialBodyRef) -> ColoredString {
+        match body.key() {
+            Some(key) => format!("{}{key}", self.namespace()).muted(),
+            None => self.display_id(body.id()).muted(),
+        }
+    }
+
+    /// Whether `body` is noted as idle when printing, see `show_stale`
+    pub fn is_idle(&self, body: &CelestialBodyRef) -> bool {
+        self.show_stale.is_some_and(|since| body.is_stale(since))
+    }
+
+    /// How long `body` has been idle as a note for printing, `None` if stale
+    /// celestial bodies are not being shown or it is not stale
+    pub fn idle_note(&self, body: &CelestialBodyRef) -> Option<String> {
+        let since = self.show_stale.filter(|_| self.is_idle(body))?;
+        Some(match body.last_activity() {
+            Some(time) => format!(
+                "Idle, last changed {}",
+                util::time::relative(time, Utc::now())
+            ),
+            None => format!(
+                "Idle, no activity since before {}",
+                util::time::absolute(since)
+            ),
+        })
+    }
+
+    /// The note printed below `body`, i.e. why it is blocked or how long it
+    /// has been idle. Empty if there is nothing to note.
+    pub fn note(&self, body: &CelestialBodyRef) -> ColoredString {
+        match (self.reason_note(body), self.idle_note(body)) {
+            (Some(reason), _) => reason.warning(),
+            (None, Some(idle)) => idle.muted(),
+            (None, None) => "".into(),
+        }
+    }
+
+    /// Returns the celestial bodies that match `filter`, in the same order as
+    /// `iter`. If the filter has a status, tag, parent or ID term, only the
+    /// celestial bodies that can match are looked at.
+    pub fn matching(&self, filter: &Filter) -> Vec<CelestialBodyRef<'_>> {
+        let Some(candidates) = self.with_indexes(|indexes| filter.candidates(indexes)) else {
+            return self.iter().filter(|body| filter.matches(body)).collect();
+        };
+        let mut candidates: Vec<_> = candidates
+            .into_iter()
+            .filter_map(|id| Some((self.index(id)?, id)))
+            .collect();
+        candidates.sort();
+        candidates
+            .into_iter()
+            .filter_map(|(_, id)| self.get(id))
+            .filter(|body| filter.matches(body))
+            .collect()
+    }
+
+    /// Returns the celestial bodies that are due on or before `until` along
+    /// with their due dates, sorted by them
+    pub fn due_until(&self, until: NaiveDate) -> Vec<(NaiveDate, ID)> {
+        self.with_indexes(|indexes| indexes.due_until(until).collect())
+    }
+
+    /// Helper function that calls `f` with the indexes, building them or
+    /// indexing the celestial bodies that changed again first
+    fn with_indexes<R>(&self, f: impl FnOnce(&Indexes) -> R) -> R {
+        let mut indexes = self.indexes.borrow_mut();
+        let indexes = indexes.get_or_insert_with(|| Indexes::new(self.iter()));
+        if indexes.is_stale() {
+            indexes.refresh(|id| self.get(id));
+        }
+        f(indexes)
+    }
+
+    /// Returns an iterator over every celestial body in the `Galaxy`
+    pub fn iter(&self) -> impl Iterator<Item = CelestialBodyRef<'_>> {
+        self.comets
+            .iter()
+            .map(CelestialBodyRef::Comet)
+            .chain(self.planets.iter().map(CelestialBodyRef::Planet))
+            .chain(self.stars.iter().map(CelestialBodyRef::Star))
+    }
+
+    /// Helper function for retrieving and increment the next id
+    fn next_id(&mut self) -> ID {
+        if self.id_scheme == IdScheme::Random {
+            let in_use: HashSet<ID> = self.all_ids().collect();
+            let id = std::iter::repeat_with(random_id)
+                .find(|id| !in_use.contains(id))
+                .unwrap_or_default();
+            let new = self.id_format.format(id);
+            for other in &in_use {
+                let other = self.id_format.format(*other);
+                let common = new.chars().zip(other.chars()).take_while(|(a, b)| a == b);
+                self.short_id_len = self.short_id_len.max(common.count() + 1);
+            }
+            return id;
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Helper function that iterates over the IDs of all celestial bodies,
+    /// including the ones in the trash
+    fn all_ids(&self) -> impl Iterator<Item = ID> + '_ {
+        let trashed = self.trash.iter().flat_map(|entry| {
+            let comets = entry.comets.iter().map(|c| c.id);
+            let planets = entry.planets.iter().map(|p| p.id);
+            comets
+                .chain(planets)
+                .chain(entry.stars.iter().map(|s| s.id))
+        });
+        self.id_to_index.keys().copied().chain(trashed)
+    }
+
+    /// Helper function that finds the shortest length at which the displayed
+    /// random IDs are still unique
+    fn update_short_id_len(&mut self) {
+        if self.id_scheme != IdScheme::Random {
+            return;
+        }
+        let mut ids: Vec<String> = self
+            .id_to_index
+            .keys()
+            .map(|id| self.id_format.format(*id))
+            .collect();
+        ids.sort();
+        self.short_id_len = ids
+            .windows(2)
+            .map(|pair| {
+                let common = pair[0].chars().zip(pair[1].chars());
+                common.take_while(|(a, b)| a == b).count() + 1
+            })
+            .fold(Self::MIN_SHORT_ID_LEN, usize::max);
+    }
+
+    /// Helper function for generating the next short key. Keys that are already
+    /// in use (including by celestial bodies in the trash) are skipped, which
+    /// can happen when the prefix is changed back to an earlier one.
+    ///
+    /// # Returns
+    /// `None` if the `Galaxy` has no key prefix
+    fn next_key(&mut self) -> Option<String> {
+        let prefix = self.key_prefix.clone()?;
+        loop {
+            self.last_key += 1;
+            let key = format!("{prefix}{}", self.last_key);
+            let in_use = self
+                .iter()
+                .filter_map(|body| body.key())
+                .chain(self.trash.iter().flat_map(|entry| entry.keys()))
+                .any(|k| k.eq_ignore_ascii_case(&key));
+            if !in_use {
+                break Some(key);
+            }
+        }
+    }
+
+    /// Pretty-prints the galaxy to the writer provided
+    ///
+    /// # Arguments
+    /// - `w`: Writer to write everything into
+    /// - `width`: Horizontal character limit. Lines will be truncated to this
+    ///   length and "..." will be used to denote the truncation
+    /// - `descriptions`: How (and whether) to print the description fields
+    /// - `recursive`: Recurse through the children or only print the first
+    ///   layer
+    /// - `columns`: The columns printed for each celestial body, in order
+    pub fn pretty_print_to_writer<W: io::Write>(
+        &self,
+        w: &mut W,
+        width: usize,
+        descriptions: Descriptions,
+        recursive: bool,
+        columns: &[Column],
+    ) -> io::Result<()> {
+        // Show the most important celestial bodies first
+        let mut bodies: Vec<_> = self.iter().filter(|b| !self.is_hidden(b.id())).collect();
+        bodies.sort_by(|a, b| Sort::Priority.compare(a, b));
+        let children: Vec<Box<&dyn PrintTreeNode<Self>>> = bodies
+            .iter()
+            .map(|body| Box::new(body.as_tree_node()))
+            .collect();
+
+        util::tree::print_to_writer(
+            self,
+            w,
+            width,
+            descriptions,
+            recursive,
+            columns,
+            self.title.accent(),
+            self.description.muted(),
+            children,
+        )?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use chrono::DateTime;
+
+    use crate::core::{Estimate, Link, Priority, Status, StatusHistory, WorkLog};
+
+    use super::*;
 
     const DB_STRING: &str = r#"{
-  "version": 2,
+  "version": 27,
   "comment": "Database for Planit project. See https://github.com/jac-oblong/planit",
   "title": "Test",
   "description": "This is a test",
   "next_id": 4,
+  "key_prefix": null,
+  "last_key": 0,
+  "id_format": "Decimal",
+  "id_scheme": "Sequential",
   "comets": [
     {
       "id": 0,
+      "key": null,
       "parent": null,
       "title": "Test Comet",
       "description": "This is a test comet",
       "status": "Todo",
-      "history": []
+      "priority": "None",
+      "archived": false,
+      "estimate": null,
+      "history": [],
+      "work_log": [],
+      "events": [],
+      "updated_at": null,
+      "assignees": []
     }
   ],
   "planets": [
     {
       "id": 1,
+      "key": null,
       "parent": 3,
       "title": "Test Planet 1",
       "description": "This is a test planet",
       "status": "Hold",
+      "priority": "High",
+      "archived": false,
+      "estimate": {
+        "Minutes": 90
+      },
       "history": [
         {
           "old": "Todo",
@@ -578,42 +3288,685 @@ mod test {
           "comment": "No",
           "time": "2020-12-25T19:33:51Z"
         }
-      ],
-      "tags": [],
-      "fields": {}
-    },
-    {
-      "id": 2,
-      "parent": 3,
-      "title": "Test Planet 2",
-      "description": "This is a test planet",
-      "status": "Done",
-      "history": [],
-      "tags": [
-        "tag1",
-        "tag2"
-      ],
-      "fields": {
-        "key1": "value1",
-        "key2": "value2"
-      }
+      ],
+      "work_log": [
+        {
+          "start": "2020-12-25T18:00:00Z",
+          "end": "2020-12-25T19:30:00Z"
+        }
+      ],
+      "events": [
+        {
+          "time": "2020-12-25T17:00:00Z",
+          "change": "Created"
+        },
+        {
+          "time": "2020-12-25T17:05:00Z",
+          "change": {
+            "TagRemoved": "tag1"
+          }
+        }
+      ],
+      "updated_at": null,
+      "assignees": [],
+      "tags": [],
+      "fields": {}
+    },
+    {
+      "id": 2,
+      "key": null,
+      "parent": 3,
+      "title": "Test Planet 2",
+      "description": "This is a test planet",
+      "status": "Done",
+      "priority": "None",
+      "archived": false,
+      "estimate": {
+        "Points": 3
+      },
+      "history": [],
+      "work_log": [],
+      "events": [],
+      "updated_at": null,
+      "assignees": [],
+      "tags": [
+        "tag1",
+        "tag2"
+      ],
+      "fields": {
+        "key1": "value1",
+        "key2": "value2"
+      }
+    }
+  ],
+  "stars": [
+    {
+      "id": 3,
+      "key": null,
+      "id_prefix": null,
+      "parent": null,
+      "title": "Test Star",
+      "description": "This is a test star",
+      "status": "Todo",
+      "priority": "Critical",
+      "archived": true,
+      "history": [],
+      "work_log": [],
+      "events": [],
+      "updated_at": null,
+      "assignees": [],
+      "children": [
+        1,
+        2
+      ]
+    }
+  ],
+  "retros": [],
+  "trash": [],
+  "plans": [],
+  "sprints": []
+}"#;
+
+    /// The work log of "Test Planet 1" in `DB_STRING`
+    fn work_log() -> WorkLog {
+        let mut work_log = WorkLog::default();
+        work_log.start(
+            DateTime::parse_from_rfc3339("2020-12-25T18:00:00Z")
+                .unwrap()
+                .into(),
+        );
+        work_log.stop(
+            DateTime::parse_from_rfc3339("2020-12-25T19:30:00Z")
+                .unwrap()
+                .into(),
+        );
+        work_log
+    }
+
+    /// The events of "Test Planet 1" in `DB_STRING`
+    fn events() -> Vec<Event> {
+        vec![
+            Event {
+                time: DateTime::parse_from_rfc3339("2020-12-25T17:00:00Z")
+                    .unwrap()
+                    .into(),
+                change: Change::Created,
+            },
+            Event {
+                time: DateTime::parse_from_rfc3339("2020-12-25T17:05:00Z")
+                    .unwrap()
+                    .into(),
+                change: Change::TagRemoved("tag1".into()),
+            },
+        ]
+    }
+
+    #[test]
+    fn path_lists_the_titles_of_ancestors() {
+        let mut galaxy = Galaxy::default();
+        let backend = galaxy.star().title("Backend".into()).id();
+        let auth = galaxy.star().title("Auth".into()).id();
+        let fix = galaxy.planet().title("Fix token refresh".into()).id();
+        galaxy.set_parent(auth, Some(backend)).unwrap();
+        galaxy.set_parent(fix, Some(auth)).unwrap();
+
+        assert_eq!(galaxy.path(fix), ["Backend", "Auth", "Fix token refresh"]);
+        assert_eq!(galaxy.path(backend), ["Backend"]);
+        assert!(galaxy.path(42).is_empty());
+
+        assert_eq!(galaxy.breadcrumb(fix), "Backend > Auth >");
+        assert_eq!(galaxy.breadcrumb(backend), "");
+    }
+
+    #[test]
+    fn archiving_hides_descendants() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let planet = galaxy.planet().id();
+        let other = galaxy.planet().id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+
+        assert!(galaxy.get_mut(star).unwrap().archive(true));
+        assert!(!galaxy.get_mut(star).unwrap().archive(true));
+        assert!(galaxy.is_archived(star));
+        assert!(galaxy.is_archived(planet));
+        assert!(!galaxy.is_archived(other));
+        assert!(galaxy.is_hidden(planet));
+        assert_eq!(
+            galaxy.get(star).unwrap().events().last().unwrap().change,
+            Change::Archived
+        );
+
+        let galaxy = galaxy.show_archived(true);
+        assert!(galaxy.is_archived(planet));
+        assert!(!galaxy.is_hidden(planet));
+    }
+
+    #[test]
+    fn deleted_bodies_can_be_restored() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let child = galaxy.star().id();
+        let planet = galaxy.planet().id();
+        let other = galaxy.comet().id();
+        galaxy.set_parent(child, Some(star)).unwrap();
+        galaxy.set_parent(planet, Some(child)).unwrap();
+        galaxy.start_tracking(planet, Utc::now()).unwrap();
+
+        assert_eq!(galaxy.delete(child, Utc::now()), Ok(2));
+        assert!(galaxy.get(child).is_none());
+        assert!(galaxy.get(planet).is_none());
+        assert!(galaxy.get(star).unwrap().children().is_empty());
+        assert_eq!(galaxy.get(other).unwrap().id(), other);
+        assert_eq!(galaxy.tracking(), None);
+        assert_eq!(galaxy.trash().len(), 1);
+        assert_eq!(galaxy.restore(planet), Err(GalaxyError::NotInTrash(planet)));
+
+        assert_eq!(galaxy.restore(child), Ok(2));
+        assert!(galaxy.trash().is_empty());
+        assert_eq!(galaxy.get(star).unwrap().children(), [child]);
+        assert_eq!(galaxy.get(planet).unwrap().parent(), Some(child));
+        assert_eq!(
+            galaxy.get(child).unwrap().events().last().unwrap().change,
+            Change::Restored
+        );
+    }
+
+    #[test]
+    fn copies_include_descendants_but_start_over() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().title("Backend".into()).id();
+        let planet = galaxy.planet().title("API".into()).id();
+        let target = galaxy.star().id();
+        let comet = galaxy.comet().id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        galaxy
+            .get_mut(planet)
+            .unwrap()
+            .status(Status::Done, String::new());
+
+        let copy = galaxy.copy(star, Some(target)).unwrap();
+        assert_ne!(copy, star);
+        assert_eq!(galaxy.get(target).unwrap().children(), [copy]);
+        let body = galaxy.get(copy).unwrap();
+        assert_eq!(body.title(), "Backend");
+        assert_eq!(body.parent(), Some(target));
+        let [child] = body.children() else {
+            panic!("Expected the Planet to be copied");
+        };
+        let child = galaxy.get(*child).unwrap();
+        assert_ne!(child.id(), planet);
+        assert_eq!(child.title(), "API");
+        assert_eq!(child.status(), Status::Todo);
+        assert_eq!(galaxy.get(planet).unwrap().status(), Status::Done);
+        assert_eq!(galaxy.get(star).unwrap().children(), [planet]);
+
+        // Copying a Star into itself only copies it once
+        let copy = galaxy.copy(star, Some(star)).unwrap();
+        assert_eq!(galaxy.get(star).unwrap().children(), [planet, copy]);
+        assert_eq!(galaxy.get(copy).unwrap().children().len(), 1);
+
+        assert_eq!(
+            galaxy.copy(star, Some(comet)),
+            Err(GalaxyError::NotAStar(comet))
+        );
+        assert_eq!(
+            galaxy.copy(99, None),
+            Err(GalaxyError::CelestialBodyNotFound(99))
+        );
+    }
+
+    #[test]
+    fn restored_bodies_without_parent_go_to_root() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let planet = galaxy.planet().id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+
+        galaxy.delete(planet, Utc::now()).unwrap();
+        galaxy.delete(star, Utc::now()).unwrap();
+        galaxy.restore(planet).unwrap();
+
+        assert_eq!(galaxy.get(planet).unwrap().parent(), None);
+        assert_eq!(galaxy.trash().len(), 1);
+    }
+
+    #[test]
+    fn emptying_trash_removes_old_entries() {
+        let mut galaxy = Galaxy::default();
+        let old = galaxy.star().id();
+        let new = galaxy.planet().id();
+        let now = Utc::now();
+
+        galaxy.delete(old, now - TimeDelta::days(40)).unwrap();
+        galaxy.delete(new, now).unwrap();
+
+        assert_eq!(galaxy.empty_trash(Some(now - TimeDelta::days(30))), 1);
+        assert_eq!(galaxy.trash().len(), 1);
+        assert_eq!(galaxy.trash()[0].id(), new);
+
+        assert_eq!(galaxy.empty_trash(None), 1);
+        assert!(galaxy.trash().is_empty());
+    }
+
+    #[test]
+    fn links_are_added_and_removed_by_target_or_label() {
+        let mut galaxy = Galaxy::default();
+        let id = galaxy.comet().id();
+        let mut body = galaxy.get_mut(id).unwrap();
+        assert!(body.link(Link::new("https://example.com/pr/1".into(), None)));
+        assert!(body.link(Link::new("docs/design.pdf".into(), Some("Design".into()))));
+        assert!(!body.link(Link::new("docs/design.pdf".into(), None)));
+        assert_eq!(
+            galaxy.get(id).unwrap().events().last().unwrap().change,
+            Change::Linked("docs/design.pdf".into())
+        );
+
+        let mut body = galaxy.get_mut(id).unwrap();
+        assert!(body.unlink("Design"));
+        assert!(!body.unlink("Design"));
+        assert!(body.unlink("https://example.com/pr/1"));
+        assert!(galaxy.get(id).unwrap().links().is_empty());
+    }
+
+    #[test]
+    fn checklist_items_are_checked_off_and_not_copied_as_done() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let id = galaxy.planet().id();
+        assert!(!galaxy.get_mut(star).unwrap().check_add("Nope".into()));
+
+        let mut body = galaxy.get_mut(id).unwrap();
+        assert!(body.check_add("Write tests".into()));
+        assert!(body.check_add("Update readme".into()));
+        assert_eq!(body.check_toggle(1), Some(true));
+        assert_eq!(body.check_toggle(2), None);
+        assert_eq!(
+            galaxy.get(id).unwrap().events().last().unwrap().change,
+            Change::Checked("Update readme".into())
+        );
+        assert_eq!(galaxy.get(id).unwrap().checklist_progress(), Some((1, 2)));
+
+        let copy = galaxy.copy(id, None).unwrap();
+        assert_eq!(galaxy.get(copy).unwrap().checklist_progress(), Some((0, 2)));
+
+        let mut body = galaxy.get_mut(id).unwrap();
+        assert_eq!(body.check_remove(0).unwrap().text, "Write tests");
+        assert_eq!(body.check_toggle(0), Some(false));
+        assert_eq!(galaxy.get(id).unwrap().checklist_progress(), Some((0, 1)));
+    }
+
+    #[test]
+    fn tags_and_fields_are_renamed_everywhere() {
+        let mut galaxy = Galaxy::default();
+        let a = galaxy.planet().id();
+        let b = galaxy.planet().id();
+        let trashed = galaxy.planet().id();
+        for id in [a, b, trashed] {
+            let mut body = galaxy.get_mut(id).unwrap();
+            body.tag("bug".into());
+            body.field("owner".into(), id.to_string());
+        }
+        galaxy.get_mut(b).unwrap().tag("defect".into());
+        galaxy
+            .get_mut(b)
+            .unwrap()
+            .field("assignee".into(), "x".into());
+        galaxy.delete(trashed, Utc::now()).unwrap();
+
+        assert_eq!(galaxy.rename_tag("bug", "defect"), vec![a, b, trashed]);
+        assert_eq!(galaxy.get(a).unwrap().tags(), ["defect"]);
+        assert_eq!(galaxy.get(b).unwrap().tags(), ["defect"]);
+        assert_eq!(
+            galaxy.get(a).unwrap().events().last().unwrap().change,
+            Change::TagRenamed {
+                old: "bug".into(),
+                new: "defect".into()
+            }
+        );
+
+        assert_eq!(
+            galaxy.rename_field("owner", "assignee"),
+            Err(GalaxyError::FieldExists(b, "assignee".into()))
+        );
+        assert_eq!(
+            galaxy.get(a).unwrap().value("field.owner"),
+            Some("0".into())
+        );
+
+        galaxy.planets[1].fields.remove("assignee");
+        assert_eq!(
+            galaxy.rename_field("owner", "assignee"),
+            Ok(vec![a, b, trashed])
+        );
+        assert_eq!(
+            galaxy.get(a).unwrap().value("field.assignee"),
+            Some("0".into())
+        );
+        galaxy.restore(trashed).unwrap();
+        assert_eq!(galaxy.get(trashed).unwrap().tags(), ["defect"]);
+    }
+
+    #[test]
+    fn short_keys_are_generated_and_resolved() {
+        let mut galaxy = Galaxy::default();
+        let old = galaxy.planet().id();
+        assert_eq!(galaxy.get(old).unwrap().key(), None);
+        assert_eq!(
+            galaxy.set_key_prefix("1-".into()),
+            Err(GalaxyError::InvalidPrefix("1-".into()))
+        );
+
+        assert_eq!(galaxy.set_key_prefix("PLAN-".into()), Ok(1));
+        let star = galaxy.star().id();
+        assert_eq!(galaxy.get(old).unwrap().key(), Some("PLAN-1"));
+        assert_eq!(galaxy.get(star).unwrap().key(), Some("PLAN-2"));
+        assert_eq!(galaxy.resolve("plan-2"), Ok(star));
+        assert_eq!(galaxy.resolve("#0"), Ok(old));
+        assert_eq!(
+            galaxy.resolve("7"),
+            Err(GalaxyError::CelestialBodyNotFound(7))
+        );
+        assert_eq!(
+            galaxy.resolve("PLAN-9"),
+            Err(GalaxyError::KeyNotFound("PLAN-9".into()))
+        );
+
+        // Keys in use, even in the trash, are never handed out again
+        galaxy.delete(star, Utc::now()).unwrap();
+        galaxy.last_key = 0;
+        let comet = galaxy.comet().id();
+        assert_eq!(galaxy.get(comet).unwrap().key(), Some("PLAN-3"));
+    }
+
+    #[test]
+    fn unfinished_plan_items_are_carried_over() {
+        let mut galaxy = Galaxy::default();
+        let done = galaxy.planet().id();
+        let todo = galaxy.planet().id();
+        let again = galaxy.comet().id();
+        galaxy
+            .get_mut(done)
+            .unwrap()
+            .status(Status::Done, String::new());
+        let day = |d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+
+        for id in [done, todo, again] {
+            assert_eq!(galaxy.plan_add(day(1), id), Ok(true));
+        }
+        assert_eq!(galaxy.plan_add(day(1), todo), Ok(false));
+        assert_eq!(
+            galaxy.plan_add(day(1), 9),
+            Err(GalaxyError::CelestialBodyNotFound(9))
+        );
+        galaxy.plan_add(day(3), again).unwrap();
+
+        assert_eq!(galaxy.carry_over(day(3)), vec![todo]);
+        assert_eq!(galaxy.plan(day(3)).unwrap().items(), [again, todo]);
+        assert_eq!(
+            galaxy.get(todo).unwrap().events().last().unwrap().change,
+            Change::CarriedOver {
+                from: day(1),
+                to: day(3)
+            }
+        );
+
+        // Plans are only carried over once
+        assert_eq!(galaxy.carry_over(day(4)), vec![again, todo]);
+        assert!(galaxy.carry_over(day(4)).is_empty());
+        assert!(galaxy.plan_remove(day(4), todo));
+        assert!(!galaxy.plan_remove(day(4), todo));
+    }
+
+    #[test]
+    fn focus_has_pinned_due_and_started_bodies() {
+        let mut galaxy = Galaxy::default();
+        let started = galaxy.comet().id();
+        let pinned = galaxy.planet().id();
+        let due = galaxy.planet().id();
+        let later = galaxy.planet().id();
+        let done = galaxy.planet().id();
+        let star = galaxy.star().id();
+        galaxy.planet();
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+        for id in [started, star] {
+            let mut body = galaxy.get_mut(id).unwrap();
+            body.status(Status::Start, String::new());
+        }
+        assert!(galaxy.get_mut(pinned).unwrap().pin(true));
+        assert!(!galaxy.get_mut(pinned).unwrap().pin(true));
+        galaxy
+            .get_mut(due)
+            .unwrap()
+            .field("due".into(), "2025-02-27".into());
+        galaxy
+            .get_mut(later)
+            .unwrap()
+            .field("due".into(), "2025-03-02".into());
+        let mut body = galaxy.get_mut(done).unwrap();
+        body.pin(true);
+        body.status(Status::Done, String::new());
+
+        let focus = galaxy.focus(today);
+        assert_eq!(focus[0], pinned);
+        let mut rest = focus[1..].to_vec();
+        rest.sort();
+        assert_eq!(rest, vec![started, due]);
+        assert_eq!(
+            galaxy.get(pinned).unwrap().events().last().unwrap().change,
+            Change::Pinned
+        );
+        let filter: Filter = "pinned:yes".parse().unwrap();
+        assert_eq!(galaxy.matching(&filter).len(), 2);
+    }
+
+    #[test]
+    fn siblings_are_listed_in_the_order_they_were_placed_in() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let first = galaxy.planet().id();
+        let second = galaxy.comet().id();
+        let third = galaxy.planet().id();
+        let outside = galaxy.planet().id();
+        for id in [first, second, third] {
+            galaxy.set_parent(id, Some(star)).unwrap();
+        }
+        assert_eq!(galaxy.siblings(first), vec![first, second, third]);
+
+        galaxy.place(third, first, false).unwrap();
+        assert_eq!(galaxy.siblings(first), vec![third, first, second]);
+        galaxy.place(third, second, true).unwrap();
+        assert_eq!(galaxy.siblings(first), vec![first, second, third]);
+
+        assert!(galaxy.shift(second, false).unwrap());
+        assert!(!galaxy.shift(second, false).unwrap());
+        assert!(galaxy.shift(first, true).unwrap());
+        assert_eq!(galaxy.siblings(first), vec![second, third, first]);
+
+        // Placing next to a celestial body elsewhere moves it there
+        galaxy.place(outside, third, true).unwrap();
+        assert_eq!(galaxy.get(outside).unwrap().parent(), Some(star));
+        assert_eq!(galaxy.siblings(first), vec![second, third, outside, first]);
+        assert_eq!(
+            galaxy.place(star, first, false),
+            Err(GalaxyError::CyclicParent(star))
+        );
+
+        // Ranks take priority over IDs in every order, and survive a save
+        let mut ids = vec![first, second, third, outside];
+        Sort::Priority.sort(&galaxy, &mut ids);
+        assert_eq!(ids, vec![second, third, outside, first]);
+        let mut buffer = Vec::new();
+        galaxy.save_to_writer(&mut buffer).unwrap();
+        let galaxy = Galaxy::load_from_reader(buffer.as_slice()).unwrap();
+        assert_eq!(galaxy.siblings(first), vec![second, third, outside, first]);
+    }
+
+    #[test]
+    fn snoozed_bodies_are_hidden_until_the_date_passes() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let planet = galaxy.planet().id();
+        let pinned = galaxy.planet().id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        galaxy.get_mut(pinned).unwrap().pin(true);
+        let today = Local::now().date_naive();
+        let until = today + TimeDelta::days(3);
+
+        assert!(galaxy.get_mut(star).unwrap().snooze(Some(until)));
+        assert!(!galaxy.get_mut(star).unwrap().snooze(Some(until)));
+        assert!(galaxy.get_mut(pinned).unwrap().snooze(Some(until)));
+        assert!(galaxy.is_hidden(planet));
+        assert!(galaxy.focus(today).is_empty());
+        assert_eq!(galaxy.focus(until), vec![pinned]);
+        let filter: Filter = "snoozed:yes".parse().unwrap();
+        assert_eq!(galaxy.matching(&filter).len(), 2);
+
+        // Once the date passes, the snooze is cleared and reported
+        assert!(galaxy.notifications(today).is_empty());
+        let woke: Vec<_> = galaxy
+            .notifications(until)
+            .iter()
+            .map(|n| (n.trigger, n.id))
+            .collect();
+        assert_eq!(woke, [(Trigger::Woke, pinned), (Trigger::Woke, star)]);
+        assert_eq!(galaxy.get(star).unwrap().snoozed_until(), None);
+        assert_eq!(
+            galaxy.get(star).unwrap().events().last().unwrap().change,
+            Change::Woke { until }
+        );
+        assert!(!galaxy.is_hidden(planet));
+        assert!(galaxy.notifications(until).is_empty());
+
+        assert!(galaxy.get_mut(planet).unwrap().snooze(Some(until)));
+        assert!(galaxy.get_mut(planet).unwrap().snooze(None));
+        assert_eq!(
+            galaxy.get(planet).unwrap().events().last().unwrap().change,
+            Change::Unsnoozed
+        );
+    }
+
+    #[test]
+    fn unfinished_sprint_items_are_carried_over_to_the_next_sprint() {
+        let mut galaxy = Galaxy::default();
+        let done = galaxy.planet().id();
+        let todo = galaxy.planet().id();
+        galaxy
+            .get_mut(done)
+            .unwrap()
+            .status(Status::Done, String::new());
+        let day = |d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+
+        galaxy.sprint_create("One".into(), day(1), day(14)).unwrap();
+        assert_eq!(
+            galaxy.sprint_create("one".into(), day(1), day(2)),
+            Err(GalaxyError::SprintExists("one".into()))
+        );
+        assert_eq!(
+            galaxy.sprint_create("Two".into(), day(28), day(15)),
+            Err(GalaxyError::InvalidSprintDates(day(28), day(15)))
+        );
+        galaxy
+            .sprint_create("Two".into(), day(15), day(28))
+            .unwrap();
+
+        for id in [done, todo] {
+            assert_eq!(galaxy.sprint_add("one", id), Ok(true));
+        }
+        assert_eq!(galaxy.sprint_add("One", todo), Ok(false));
+        assert_eq!(galaxy.active_sprint(day(3)).unwrap().name(), "One");
+        assert_eq!(galaxy.active_sprint(day(29)), None);
+
+        assert_eq!(
+            galaxy.sprint_close("One"),
+            Ok((Some("Two".to_string()), vec![todo]))
+        );
+        assert_eq!(galaxy.sprint("two").unwrap().items(), [todo]);
+        assert_eq!(galaxy.sprint("one").unwrap().items(), [done, todo]);
+        assert_eq!(galaxy.sprint_of(todo).unwrap().name(), "Two");
+        assert_eq!(galaxy.active_sprint(day(3)), None);
+        assert_eq!(
+            galaxy.sprint_add("One", done),
+            Err(GalaxyError::SprintClosed("One".into()))
+        );
+        assert_eq!(galaxy.sprint_close("Two"), Ok((None, vec![todo])));
+    }
+
+    #[test]
+    fn ids_are_displayed_and_resolved_in_the_configured_format() {
+        let mut galaxy = Galaxy::default();
+        for _ in 0..40 {
+            galaxy.comet();
+        }
+        let star = galaxy.star().id();
+        let planet = galaxy.planet().id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+
+        assert_eq!(galaxy.display_id(planet), "#41");
+        galaxy.set_id_format(IdFormat::Hex);
+        assert_eq!(galaxy.display_id(planet), "#29");
+        assert_eq!(galaxy.resolve("#29"), Ok(planet));
+        assert_eq!(galaxy.resolve("28"), Ok(star));
+
+        assert_eq!(
+            galaxy.set_id_prefix(planet, Some("WEB-".into())),
+            Err(GalaxyError::NotAStar(planet))
+        );
+        galaxy.set_id_prefix(star, Some("WEB-".into())).unwrap();
+        galaxy.set_id_format(IdFormat::Base36);
+        assert_eq!(galaxy.display_id(planet), "WEB-15");
+        assert_eq!(galaxy.display_id(star), "WEB-14");
+        assert_eq!(galaxy.display_id(0), "#0");
+        assert_eq!(galaxy.resolve("web-15"), Ok(planet));
+        assert_eq!(
+            galaxy.resolve("#zz"),
+            Err(GalaxyError::CelestialBodyNotFound(1295))
+        );
     }
-  ],
-  "stars": [
-    {
-      "id": 3,
-      "parent": null,
-      "title": "Test Star",
-      "description": "This is a test star",
-      "status": "Todo",
-      "history": [],
-      "children": [
-        1,
-        2
-      ]
+
+    #[test]
+    fn ids_are_namespaced_by_project() {
+        let mut galaxy = Galaxy::default();
+        let comet = galaxy.comet().id();
+        let planet = galaxy.planet().id();
+        galaxy.set_key_field(planet, Some("login".into()));
+        let galaxy = galaxy.project(Some("web".into()));
+
+        assert_eq!(galaxy.display_id(comet), "web:#0");
+        assert_eq!(galaxy.resolve("web:#0"), Ok(comet));
+        assert_eq!(galaxy.resolve("WEB:1"), Ok(planet));
+        assert_eq!(galaxy.resolve("#1"), Ok(planet));
+        assert_eq!(galaxy.resolve("web:login"), Ok(planet));
+        assert_eq!(
+            galaxy.id_label(&galaxy.get(planet).unwrap()).input,
+            "web:login"
+        );
+        assert!(galaxy.resolve("api:#0").is_err());
+    }
+
+    #[test]
+    fn random_ids_are_displayed_as_unique_prefixes() {
+        let mut galaxy = Galaxy::default();
+        let sequential = galaxy.comet().id();
+        galaxy.set_id_scheme(IdScheme::Random);
+        galaxy.set_id_format(IdFormat::Hex);
+        let ids: Vec<ID> = (0..50).map(|_| galaxy.planet().id()).collect();
+
+        assert_eq!(galaxy.display_id(sequential), "#0");
+        let displayed: HashSet<String> = ids.iter().map(|id| galaxy.display_id(*id)).collect();
+        assert_eq!(displayed.len(), ids.len());
+        for id in &ids {
+            let displayed = galaxy.display_id(*id);
+            assert!(displayed.len() > Galaxy::MIN_SHORT_ID_LEN);
+            assert_eq!(galaxy.resolve(&displayed), Ok(*id));
+            assert!(*id <= i64::MAX as u64);
+        }
+
+        galaxy.set_id_scheme(IdScheme::Sequential);
+        let next = galaxy.comet().id();
+        assert!(ids.iter().all(|id| next > *id));
     }
-  ]
-}"#;
 
     #[test]
     fn created_comet_added_to_vec_and_map() {
@@ -660,6 +4013,272 @@ mod test {
         );
     }
 
+    #[test]
+    fn set_parent_updates_parent_and_children() {
+        let mut galaxy = Galaxy::default();
+        let star1 = galaxy.star().id();
+        let star2 = galaxy.star().id();
+        let planet = galaxy.planet().id();
+
+        galaxy.set_parent(planet, Some(star1)).unwrap();
+        assert_eq!(galaxy.get(planet).unwrap().parent(), Some(star1));
+        assert_eq!(galaxy.get(star1).unwrap().children(), &[planet]);
+
+        galaxy.set_parent(planet, Some(star2)).unwrap();
+        assert_eq!(galaxy.get(planet).unwrap().parent(), Some(star2));
+        assert!(galaxy.get(star1).unwrap().children().is_empty());
+        assert_eq!(galaxy.get(star2).unwrap().children(), &[planet]);
+
+        galaxy.set_parent(planet, None).unwrap();
+        assert_eq!(galaxy.get(planet).unwrap().parent(), None);
+        assert!(galaxy.get(star2).unwrap().children().is_empty());
+
+        let changes: Vec<_> = galaxy
+            .get(planet)
+            .unwrap()
+            .events()
+            .iter()
+            .map(|e| &e.change)
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                &Change::Created,
+                &Change::Parent {
+                    old: None,
+                    new: Some(star1)
+                },
+                &Change::Parent {
+                    old: Some(star1),
+                    new: Some(star2)
+                },
+                &Change::Parent {
+                    old: Some(star2),
+                    new: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn edits_are_recorded_in_the_event_log() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let planet = galaxy.planet().id();
+
+        let mut body = galaxy.get_mut(planet).unwrap();
+        body.title("Fix login".into());
+        // Setting the same title again is not a change
+        body.title("Fix login".into());
+        body.tag("auth".into());
+        body.untag("auth");
+        body.field("owner".into(), "ana".into());
+        body.field("owner".into(), "bo".into());
+        galaxy.set_parent(planet, Some(star)).unwrap();
+
+        let changes: Vec<_> = galaxy
+            .get(planet)
+            .unwrap()
+            .events()
+            .iter()
+            .map(|e| e.change.clone())
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                Change::Created,
+                Change::Title {
+                    old: String::new(),
+                    new: "Fix login".into()
+                },
+                Change::TagAdded("auth".into()),
+                Change::TagRemoved("auth".into()),
+                Change::Field {
+                    key: "owner".into(),
+                    old: None,
+                    new: Some("ana".into())
+                },
+                Change::Field {
+                    key: "owner".into(),
+                    old: Some("ana".into()),
+                    new: Some("bo".into())
+                },
+                Change::Parent {
+                    old: None,
+                    new: Some(star)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn assignees_are_recorded_and_saved() {
+        let mut galaxy = Galaxy::default();
+        let planet = galaxy.planet().id();
+        let mut body = galaxy.get_mut(planet).unwrap();
+        assert!(body.assign("Alice".into()));
+        assert!(!body.assign("alice".into()));
+        assert!(body.assign("bob".into()));
+        assert!(body.unassign("BOB"));
+        assert!(!body.unassign("carol"));
+
+        let body = galaxy.get(planet).unwrap();
+        assert_eq!(body.assignees(), ["Alice"]);
+        assert!(body.is_assigned_to("ALICE"));
+        let changes: Vec<_> = body.events().iter().map(|e| e.change.clone()).collect();
+        assert_eq!(
+            changes,
+            [
+                Change::Created,
+                Change::Assigned("Alice".into()),
+                Change::Assigned("bob".into()),
+                Change::Unassigned("bob".into()),
+            ]
+        );
+
+        let mut writer = Vec::new();
+        galaxy.save_to_writer(&mut writer).unwrap();
+        let galaxy = Galaxy::load_from_reader(writer.as_slice()).unwrap();
+        assert_eq!(galaxy.get(planet).unwrap().assignees(), ["Alice"]);
+
+        // Databases from before assignees have none
+        let old = DB_STRING
+            .replace("\"version\": 27", "\"version\": 16")
+            .replace("      \"assignees\": [],\n", "")
+            .replace(",\n      \"assignees\": []", "");
+        assert!(!old.contains("assignees"));
+        let galaxy = Galaxy::load_from_reader(io::Cursor::new(old)).unwrap();
+        assert!(galaxy.iter().all(|body| body.assignees().is_empty()));
+    }
+
+    #[test]
+    fn event_logs_survive_saving_and_loading() {
+        let dir = env::temp_dir().join(format!("planit-events-{}", std::process::id()));
+        for layout in [Layout::File, Layout::Directory] {
+            fs::create_dir_all(&dir).unwrap();
+            let mut galaxy = Galaxy::default().layout(layout);
+            let star = galaxy.star().id();
+            let planet = galaxy.planet().id();
+            let mut body = galaxy.get_mut(planet).unwrap();
+            body.title("Fix login".into()).tag("auth".into());
+            body.field("owner".into(), "ana".into());
+            galaxy.set_parent(planet, Some(star)).unwrap();
+            let events = galaxy.get(planet).unwrap().events().to_vec();
+            assert_eq!(events.len(), 5);
+            galaxy.init(dir.clone(), true).unwrap();
+
+            let galaxy = Galaxy::load_from(&Database::in_dir(&dir).unwrap()).unwrap();
+            assert_eq!(galaxy.get(planet).unwrap().events(), events);
+            assert_eq!(galaxy.get(star).unwrap().events().len(), 1);
+
+            fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn set_parent_rejects_invalid_parents() {
+        let mut galaxy = Galaxy::default();
+        let star1 = galaxy.star().id();
+        let star2 = galaxy.star().id();
+        let planet = galaxy.planet().id();
+
+        assert_eq!(
+            galaxy.set_parent(star1, Some(planet)),
+            Err(GalaxyError::NotAStar(planet))
+        );
+        assert_eq!(
+            galaxy.set_parent(star1, Some(42)),
+            Err(GalaxyError::CelestialBodyNotFound(42))
+        );
+        assert_eq!(
+            galaxy.set_parent(star1, Some(star1)),
+            Err(GalaxyError::CyclicParent(star1))
+        );
+
+        galaxy.set_parent(star2, Some(star1)).unwrap();
+        assert_eq!(
+            galaxy.set_parent(star1, Some(star2)),
+            Err(GalaxyError::CyclicParent(star1))
+        );
+    }
+
+    #[test]
+    fn rollup_totals_descendant_estimates() {
+        let reader = io::Cursor::new(DB_STRING);
+        let mut galaxy = Galaxy::load_from_reader(reader).unwrap();
+
+        let rollup = galaxy.rollup(3).unwrap();
+        assert_eq!(rollup.to_string(), "[3/3 pts] [0m/1h30m]");
+
+        galaxy.comets[0].estimate = Some(Estimate::Points(2));
+        galaxy.set_parent(0, Some(3)).unwrap();
+        assert_eq!(
+            galaxy.rollup(3).unwrap().to_string(),
+            "[3/5 pts] [0m/1h30m]"
+        );
+
+        galaxy.comets[0].status = Status::Cancel;
+        assert_eq!(
+            galaxy.rollup(3).unwrap().to_string(),
+            "[3/3 pts] [0m/1h30m]"
+        );
+        assert_eq!(galaxy.rollup(42), None);
+    }
+
+    #[test]
+    fn progress_counts_final_descendants() {
+        let mut galaxy = Galaxy::default();
+        let root = galaxy.star().id();
+        let inner = galaxy.star().id();
+        let done = galaxy.planet().status(Status::Done, String::new()).id();
+        let cancel = galaxy.comet().status(Status::Cancel, String::new()).id();
+        let todo = galaxy.planet().id();
+        galaxy.set_parent(inner, Some(root)).unwrap();
+        galaxy.set_parent(done, Some(inner)).unwrap();
+        galaxy.set_parent(cancel, Some(inner)).unwrap();
+        galaxy.set_parent(todo, Some(root)).unwrap();
+
+        let progress = galaxy.progress(root).unwrap();
+        assert_eq!(
+            progress,
+            Progress {
+                finished: 2,
+                total: 4
+            }
+        );
+        assert_eq!(progress.percent(), Some(50));
+        assert_eq!(progress.bar(4), "██░░");
+
+        assert_eq!(galaxy.progress(inner).unwrap().percent(), Some(100));
+        assert_eq!(galaxy.progress(todo).unwrap().percent(), None);
+        assert_eq!(galaxy.progress(42), None);
+    }
+
+    #[test]
+    fn only_one_celestial_body_is_tracked_at_a_time() {
+        let mut galaxy = Galaxy::default();
+        let first = galaxy.planet().id();
+        let second = galaxy.comet().id();
+        let now = Utc::now();
+
+        assert_eq!(galaxy.start_tracking(first, now), Ok(None));
+        assert_eq!(galaxy.tracking(), Some(first));
+        assert_eq!(
+            galaxy.start_tracking(second, now + TimeDelta::minutes(5)),
+            Ok(Some((first, TimeDelta::minutes(5))))
+        );
+        assert_eq!(galaxy.tracking(), Some(second));
+        assert_eq!(
+            galaxy.stop_tracking(now + TimeDelta::minutes(6)),
+            Some((second, TimeDelta::minutes(1)))
+        );
+        assert_eq!(galaxy.tracking(), None);
+        assert_eq!(
+            galaxy.start_tracking(42, now),
+            Err(GalaxyError::CelestialBodyNotFound(42))
+        );
+    }
+
     #[test]
     fn loading_galaxy_produces_correct_object() {
         let reader = io::Cursor::new(DB_STRING);
@@ -674,11 +4293,27 @@ mod test {
             galaxy.comets[0],
             Comet {
                 id: 0,
+                key: None,
                 parent: None,
                 title: "Test Comet".into(),
                 description: "This is a test comet".into(),
                 status: Status::Todo,
-                history: Vec::new()
+                priority: Priority::None,
+                archived: false,
+                pinned: false,
+                snoozed_until: None,
+                rank: None,
+                estimate: None,
+                history: Vec::new(),
+                work_log: WorkLog::default(),
+                events: vec![],
+                updated_at: None,
+                assignees: vec![],
+                links: vec![],
+                commits: vec![],
+                blocker: None,
+                stage: None,
+                checklist: vec![],
             }
         );
 
@@ -687,10 +4322,17 @@ mod test {
             galaxy.planets[0],
             Planet {
                 id: 1,
+                key: None,
                 parent: Some(3),
                 title: "Test Planet 1".into(),
                 description: "This is a test planet".into(),
                 status: Status::Hold,
+                priority: Priority::High,
+                archived: false,
+                pinned: false,
+                snoozed_until: None,
+                rank: None,
+                estimate: Some(Estimate::Minutes(90)),
                 history: vec![StatusHistory {
                     old: Status::Todo,
                     new: Status::Hold,
@@ -699,6 +4341,16 @@ mod test {
                         .unwrap()
                         .into()
                 }],
+                work_log: work_log(),
+
+                events: events(),
+                updated_at: None,
+                assignees: vec![],
+                links: vec![],
+                commits: vec![],
+                blocker: None,
+                stage: None,
+                checklist: vec![],
                 tags: vec![],
                 fields: HashMap::new()
             }
@@ -707,11 +4359,28 @@ mod test {
             galaxy.planets[1],
             Planet {
                 id: 2,
+                key: None,
                 parent: Some(3),
                 title: "Test Planet 2".into(),
                 description: "This is a test planet".into(),
                 status: Status::Done,
+                priority: Priority::None,
+                archived: false,
+                pinned: false,
+                snoozed_until: None,
+                rank: None,
+                estimate: Some(Estimate::Points(3)),
                 history: vec![],
+                work_log: WorkLog::default(),
+
+                events: vec![],
+                updated_at: None,
+                assignees: vec![],
+                links: vec![],
+                commits: vec![],
+                blocker: None,
+                stage: None,
+                checklist: vec![],
                 tags: vec!["tag1".into(), "tag2".into()],
                 fields: HashMap::from([
                     ("key1".into(), "value1".into()),
@@ -725,11 +4394,27 @@ mod test {
             galaxy.stars[0],
             Star {
                 id: 3,
+                key: None,
+                id_prefix: None,
                 parent: None,
                 title: "Test Star".into(),
                 description: "This is a test star".into(),
                 status: Status::Todo,
+                priority: Priority::Critical,
+                archived: true,
+                pinned: false,
+                snoozed_until: None,
+                rank: None,
                 history: vec![],
+                work_log: WorkLog::default(),
+
+                events: vec![],
+                updated_at: None,
+                assignees: vec![],
+                links: vec![],
+                commits: vec![],
+                blocker: None,
+                stage: None,
                 children: vec![1, 2]
             }
         );
@@ -753,27 +4438,62 @@ mod test {
         );
     }
 
+    #[test]
+    fn database_string_matches_the_schema() {
+        let value: Value = serde_json::from_str(DB_STRING).unwrap();
+        assert_eq!(schema::validate(&value, None), []);
+    }
+
     #[test]
     fn saving_galaxy_produces_correct_string() {
         let galaxy = Galaxy {
             title: "Test".to_string(),
             description: "This is a test".to_string(),
             next_id: 4,
+            key_prefix: None,
+            last_key: 0,
+            id_format: IdFormat::Decimal,
+            id_scheme: IdScheme::Sequential,
+            short_id_len: 0,
             comets: vec![Comet {
                 id: 0,
+                key: None,
                 parent: None,
                 title: "Test Comet".to_string(),
                 description: "This is a test comet".to_string(),
                 status: Status::Todo,
+                priority: Priority::None,
+                archived: false,
+                pinned: false,
+                snoozed_until: None,
+                rank: None,
+                estimate: None,
                 history: vec![],
+                work_log: WorkLog::default(),
+
+                events: vec![],
+                updated_at: None,
+                assignees: vec![],
+                links: vec![],
+                commits: vec![],
+                blocker: None,
+                stage: None,
+                checklist: vec![],
             }],
             planets: vec![
                 Planet {
                     id: 1,
+                    key: None,
                     parent: Some(3),
                     title: "Test Planet 1".to_string(),
                     description: "This is a test planet".to_string(),
                     status: Status::Hold,
+                    priority: Priority::High,
+                    archived: false,
+                    pinned: false,
+                    snoozed_until: None,
+                    rank: None,
+                    estimate: Some(Estimate::Minutes(90)),
                     history: vec![StatusHistory {
                         old: Status::Todo,
                         new: Status::Hold,
@@ -782,16 +4502,43 @@ mod test {
                             .unwrap()
                             .into(),
                     }],
+                    work_log: work_log(),
+
+                    events: events(),
+                    updated_at: None,
+                    assignees: vec![],
+                    links: vec![],
+                    commits: vec![],
+                    blocker: None,
+                    stage: None,
+                    checklist: vec![],
                     tags: vec![],
                     fields: HashMap::default(),
                 },
                 Planet {
                     id: 2,
+                    key: None,
                     parent: Some(3),
                     title: "Test Planet 2".to_string(),
                     description: "This is a test planet".to_string(),
                     status: Status::Done,
+                    priority: Priority::None,
+                    archived: false,
+                    pinned: false,
+                    snoozed_until: None,
+                    rank: None,
+                    estimate: Some(Estimate::Points(3)),
                     history: vec![],
+                    work_log: WorkLog::default(),
+
+                    events: vec![],
+                    updated_at: None,
+                    assignees: vec![],
+                    links: vec![],
+                    commits: vec![],
+                    blocker: None,
+                    stage: None,
+                    checklist: vec![],
                     tags: vec!["tag1".to_string(), "tag2".to_string()],
                     fields: HashMap::from([
                         ("key1".to_string(), "value1".to_string()),
@@ -801,19 +4548,51 @@ mod test {
             ],
             stars: vec![Star {
                 id: 3,
+                key: None,
+                id_prefix: None,
                 parent: None,
                 title: "Test Star".to_string(),
                 description: "This is a test star".to_string(),
                 status: Status::Todo,
+                priority: Priority::Critical,
+                archived: true,
+                pinned: false,
+                snoozed_until: None,
+                rank: None,
                 history: vec![],
+                work_log: WorkLog::default(),
+
+                events: vec![],
+                updated_at: None,
+                assignees: vec![],
+                links: vec![],
+                commits: vec![],
+                blocker: None,
+                stage: None,
                 children: vec![1, 2],
             }],
+            retros: vec![],
+            trash: vec![],
+            plans: vec![],
+            sprints: vec![],
+            rules: Rules::default(),
+            workflow: Workflow::default(),
+            show_archived: false,
+            show_reasons: false,
+            show_stale: None,
+            project: None,
+            history_capped: false,
+            changed: None,
+            format: Format::Json,
+            layout: Layout::File,
+            reported_until: DateTime::default(),
             id_to_index: HashMap::from([
                 (0, CelestialBodyIndex::new(CelestialBodyKind::Comet, 0)),
                 (1, CelestialBodyIndex::new(CelestialBodyKind::Planet, 0)),
                 (2, CelestialBodyIndex::new(CelestialBodyKind::Planet, 1)),
                 (3, CelestialBodyIndex::new(CelestialBodyKind::Star, 0)),
             ]),
+            indexes: RefCell::default(),
         };
 
         let mut writer = Vec::new();
@@ -821,6 +4600,445 @@ mod test {
         assert_eq!(writer, DB_STRING.as_bytes());
     }
 
+    #[test]
+    fn older_database_versions_can_be_loaded() {
+        let old = DB_STRING
+            .replace("\"version\": 27", "\"version\": 2")
+            .replace("      \"priority\": \"High\",\n", "");
+        let galaxy = Galaxy::load_from_reader(io::Cursor::new(old)).unwrap();
+        assert_eq!(galaxy.planets[0].priority, Priority::None);
+        let old = DB_STRING
+            .replace("\"version\": 27", "\"version\": 26")
+            .replace(
+                "\"retros\": []",
+                r#""retros": [{ "star": 1, "created": "2025-01-01T00:00:00Z", "went_well": [],
+                "needs_improvement": [], "action_items": [] }]"#,
+            );
+        let galaxy = Galaxy::load_from_reader(io::Cursor::new(old)).unwrap();
+        assert_eq!(galaxy.retros.len(), 1);
+        assert_eq!(galaxy.retros[0].star, Some(1));
+
+        let new = DB_STRING.replace("\"version\": 27", "\"version\": 28");
+        assert!(Galaxy::load_from_reader(io::Cursor::new(new)).is_err());
+    }
+
+    #[test]
+    fn retrospectives_of_stars_are_attached_to_their_sprint() {
+        let retro = |star: &str| {
+            format!(
+                r#"{{ {star} "created": "2025-03-14T00:00:00Z", "went_well": ["Pairing"],
+                "needs_improvement": [], "action_items": [] }}"#
+            )
+        };
+        let old = DB_STRING
+            .replace("\"version\": 27", "\"version\": 26")
+            .replace(
+                "\"retros\": []",
+                &format!(
+                    "\"retros\": [{}, {}]",
+                    retro(r#""star": 3,"#),
+                    retro(r#""star": 4,"#)
+                ),
+            )
+            .replace(
+                "\"sprints\": []",
+                r#""sprints": [{ "name": "one", "start": "2025-03-03", "end": "2025-03-16",
+                "items": [3] }]"#,
+            );
+        let galaxy = Galaxy::load_from_reader(io::Cursor::new(old.clone())).unwrap();
+        let retro = galaxy.get_retro("one").unwrap();
+        assert_eq!(retro.star, None);
+        assert_eq!(retro.went_well, ["Pairing"]);
+        // No sprint contained the other Star, so it keeps it across saves
+        let galaxy = Galaxy::from_json(galaxy.into_json().unwrap()).unwrap();
+        assert_eq!(galaxy.retros.len(), 2);
+        assert_eq!(galaxy.retros[1].sprint, "");
+        assert_eq!(galaxy.retros[1].star, Some(4));
+
+        let orphan = old.replace(r#""star": 4,"#, "");
+        assert!(matches!(
+            Galaxy::load_from_reader(io::Cursor::new(orphan)),
+            Err(DatabaseError::CorruptDatabase(_))
+        ));
+    }
+
+    #[test]
+    fn malformed_databases_are_rejected() {
+        let load = |db: String| Galaxy::load_from_reader(io::Cursor::new(db));
+
+        let absurd = DB_STRING.replace("\"version\": 27", "\"version\": 18446744073709551615");
+        assert!(matches!(
+            load(absurd),
+            Err(DatabaseError::InvalidValue(ParseIssue { path, .. })) if path == "version"
+        ));
+        assert!(matches!(
+            load("{".into()),
+            Err(DatabaseError::ParsingError(_))
+        ));
+
+        let duplicate = DB_STRING.replace("\"id\": 2,", "\"id\": 1,");
+        assert!(matches!(
+            load(duplicate),
+            Err(DatabaseError::CorruptDatabase(_))
+        ));
+
+        let unissued = DB_STRING.replace("\"next_id\": 4,", "\"next_id\": 3,");
+        assert!(matches!(
+            load(unissued),
+            Err(DatabaseError::CorruptDatabase(_))
+        ));
+
+        let star = Star {
+            id: 3,
+            parent: Some(3),
+            children: vec![3],
+            ..Star::default()
+        };
+        let galaxy = Galaxy {
+            next_id: 4,
+            stars: vec![star],
+            ..Galaxy::default()
+        };
+        let mut writer = Vec::new();
+        galaxy.save_to_writer(&mut writer).unwrap();
+        let own_parent = String::from_utf8(writer).unwrap();
+        assert!(matches!(
+            load(own_parent),
+            Err(DatabaseError::CorruptDatabase(_))
+        ));
+
+        let huge = format!("\"{}\"", "a".repeat(Database::MAX_SIZE as usize));
+        assert!(matches!(
+            load(huge),
+            Err(DatabaseError::DatabaseTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn lenient_loads_skip_malformed_celestial_bodies() {
+        let db = DB_STRING.replace("\"status\": \"Hold\"", "\"status\": \"Doing\"");
+        let issue = ParseIssue {
+            path: "planets[0].status".into(),
+            value: Some("Doing".into()),
+            message: "unknown variant `Doing`, expected one of `Todo`, `Block`, `Next`, `Start`, \
+                      `Hold`, `Done`, `Cancel`"
+                .into(),
+        };
+        match Galaxy::load_from_reader(io::Cursor::new(&db)) {
+            Err(DatabaseError::InvalidValue(invalid)) => assert_eq!(invalid, issue),
+            result => panic!("Expected an invalid value, got {result:?}"),
+        }
+
+        let (galaxy, issues) =
+            Galaxy::load_from_reader_lenient(io::Cursor::new(&db), Format::Json).unwrap();
+        assert!(galaxy.get(1).is_none());
+        assert_eq!(galaxy.stars[0].children, vec![2]);
+        assert_eq!(
+            issues,
+            vec![
+                ParseIssue {
+                    message: format!("{}, skipped", issue.message),
+                    ..issue
+                },
+                ParseIssue {
+                    path: "stars[0].children".into(),
+                    value: Some(1.into()),
+                    message: "the child does not exist, removed".into(),
+                },
+            ]
+        );
+
+        let (_, issues) =
+            Galaxy::load_from_reader_lenient(io::Cursor::new(DB_STRING), Format::Json).unwrap();
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn nested_databases_require_opting_in() {
+        let outer = env::temp_dir().join(format!("planit-nested-{}", std::process::id()));
+        let inner = outer.join("inner");
+        fs::create_dir_all(&inner).unwrap();
+
+        // The temporary directory itself might be inside of a database
+        Galaxy::default().init(outer.clone(), true).unwrap();
+        assert!(matches!(
+            Galaxy::default().init(outer.clone(), true),
+            Err(DatabaseError::DatabaseAlreadyExists(_))
+        ));
+        assert!(matches!(
+            Galaxy::default().init(inner.clone(), false),
+            Err(DatabaseError::NestedDatabase(_))
+        ));
+        Galaxy::default().init(inner.clone(), true).unwrap();
+        assert!(Database::in_dir(&inner).is_some());
+
+        fs::remove_dir_all(outer).unwrap();
+    }
+
+    #[test]
+    fn saving_changes_when_the_database_was_modified() {
+        let dir = env::temp_dir().join(format!("planit-modified-{}", std::process::id()));
+        for layout in [Layout::File, Layout::Directory] {
+            fs::create_dir_all(&dir).unwrap();
+            let mut galaxy = Galaxy::default().layout(layout);
+            galaxy.planet();
+            let comet = galaxy.comet().id();
+            galaxy.init(dir.clone(), true).unwrap();
+            let path = Database::in_dir(&dir).unwrap();
+            let storage = storage::open(&path);
+            let mut modified = storage.modified().unwrap();
+
+            // `list --watch` redraws whenever this changes, so edits and
+            // deletions both have to change it
+            let changes: [fn(&mut Galaxy, ID); 2] = [
+                |galaxy, comet| {
+                    galaxy.get_mut(comet).unwrap().title("Crash".into());
+                },
+                |galaxy, comet| {
+                    galaxy.delete(comet, Utc::now()).unwrap();
+                },
+            ];
+            for change in changes {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                let mut galaxy = Galaxy::load_from(&path).unwrap();
+                change(&mut galaxy, comet);
+                galaxy.save_to_storage(storage.as_ref()).unwrap();
+                let now = storage.modified().unwrap();
+                assert!(now > modified, "{layout:?}");
+                modified = now;
+            }
+
+            fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn databases_over_the_size_limit_are_not_saved() {
+        let dir = env::temp_dir().join(format!("planit-too-large-{}", std::process::id()));
+        for layout in [Layout::File, Layout::Directory] {
+            fs::create_dir_all(&dir).unwrap();
+            Galaxy::load_from_reader(io::Cursor::new(DB_STRING))
+                .unwrap()
+                .layout(layout)
+                .init(dir.clone(), true)
+                .unwrap();
+            let path = Database::in_dir(&dir).unwrap();
+            let before = storage::open(&path).raw_files().unwrap();
+
+            // One byte over the limit once the description is filled in. The
+            // limit is per file, and the description is in the galaxy file.
+            let mut galaxy = Galaxy::load_from(&path).unwrap();
+            let file = match layout {
+                Layout::File => path.clone(),
+                Layout::Directory => path.join("galaxy.json"),
+            };
+            let length = (Database::MAX_SIZE - fs::metadata(&file).unwrap().len() + 1) as usize;
+            galaxy.description += &"a".repeat(length);
+            assert!(matches!(
+                galaxy.save_to_storage(storage::open(&path).as_ref()),
+                Err(DatabaseError::DatabaseTooLarge(_))
+            ));
+            let after = storage::open(&path).raw_files().unwrap();
+            assert_eq!(
+                after
+                    .iter()
+                    .map(|f| f.value.as_ref().ok())
+                    .collect::<Vec<_>>(),
+                before
+                    .iter()
+                    .map(|f| f.value.as_ref().ok())
+                    .collect::<Vec<_>>()
+            );
+            assert!(Galaxy::load_from(&path).is_ok());
+
+            fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn older_database_versions_match_the_schema() {
+        let dir = env::temp_dir().join(format!("planit-doctor-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(".planit.json");
+        let version = format!("\"version\": {}", Database::SCHEMA_VERSION);
+        let old = DB_STRING
+            .replace(&version, "\"version\": 2")
+            .replace("      \"priority\": \"High\",\n", "");
+        fs::write(&path, old).unwrap();
+        assert_eq!(Galaxy::validate_files_at(&path).unwrap(), []);
+
+        let new = DB_STRING.replace(
+            &version,
+            &format!("\"version\": {}", Database::SCHEMA_VERSION + 1),
+        );
+        fs::write(&path, new).unwrap();
+        let issues = Galaxy::validate_files_at(&path).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, ".planit.json:version");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn database_can_be_stored_as_a_directory() {
+        let dir = env::temp_dir().join(format!("planit-directory-{}", std::process::id()));
+        for format in [Format::Json, Format::Toml] {
+            fs::create_dir_all(&dir).unwrap();
+            let galaxy = Galaxy::load_from_reader(io::Cursor::new(DB_STRING)).unwrap();
+            let galaxy = galaxy.format(format).layout(Layout::Directory);
+            galaxy.init(dir.clone(), true).unwrap();
+            let path = dir.join(".planit");
+            let planet = path.join(format!("planets/1.{}", format.extension()));
+            assert!(planet.is_file());
+
+            let galaxy = Galaxy::load_from(&path).unwrap();
+            assert_eq!(galaxy.layout, Layout::Directory);
+            let mut writer = Vec::new();
+            galaxy
+                .format(Format::Json)
+                .save_to_writer(&mut writer)
+                .unwrap();
+            assert_eq!(String::from_utf8(writer).unwrap(), DB_STRING);
+
+            // Broken celestial bodies are reported by their file
+            let broken = fs::read_to_string(&planet)
+                .unwrap()
+                .replacen("Hold", "Doing", 1);
+            fs::write(&planet, broken).unwrap();
+            match Galaxy::load_from(&path) {
+                Err(DatabaseError::InvalidValue(issue)) => {
+                    assert_eq!(
+                        issue.path,
+                        format!("planets/1.{}:status", format.extension())
+                    );
+                }
+                result => panic!("Expected an invalid value, got {result:?}"),
+            }
+            let (database, issues) = storage::open(&path).read_lenient().unwrap();
+            assert_eq!(database.planets.len(), 1);
+            assert_eq!(issues.len(), 2);
+
+            fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn indexes_follow_changes_to_celestial_bodies() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let first = galaxy.planet().id();
+        let second = galaxy.planet().id();
+        galaxy.set_parent(first, Some(star)).unwrap();
+        let ids = |galaxy: &Galaxy, filter: &str| -> Vec<ID> {
+            let filter: Filter = filter.parse().unwrap();
+            let ids: Vec<_> = galaxy.matching(&filter).iter().map(|b| b.id()).collect();
+            let scanned: Vec<_> = galaxy
+                .iter()
+                .filter(|body| filter.matches(body))
+                .map(|body| body.id())
+                .collect();
+            assert_eq!(ids, scanned, "{filter:?}");
+            ids
+        };
+        assert_eq!(ids(&galaxy, &format!("parent:{star}")), vec![first]);
+        assert_eq!(ids(&galaxy, "status:todo"), vec![first, second, star]);
+
+        let mut body = galaxy.get_mut(second).unwrap();
+        body.status(Status::Start, String::new());
+        body.tag("bug".into());
+        body.field("due".into(), "2025-03-01".into());
+        assert_eq!(ids(&galaxy, "status:start tag:bug"), vec![second]);
+        assert_eq!(ids(&galaxy, "status:todo"), vec![first, star]);
+        let march = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        assert_eq!(galaxy.due_until(march), vec![(march, second)]);
+        assert!(galaxy.due_until(march.pred_opt().unwrap()).is_empty());
+
+        galaxy.set_parent(second, Some(star)).unwrap();
+        assert_eq!(ids(&galaxy, &format!("parent:{star}")), vec![first, second]);
+        galaxy.delete(first, Utc::now()).unwrap();
+        assert_eq!(
+            ids(&galaxy, &format!("parent:{star} id:{first},{second}")),
+            vec![second]
+        );
+        assert!(ids(&galaxy, "parent:none title").is_empty());
+    }
+
+    #[test]
+    fn only_changed_celestial_bodies_are_written() {
+        let dir = env::temp_dir().join(format!("planit-changed-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let galaxy = Galaxy::load_from_reader(io::Cursor::new(DB_STRING)).unwrap();
+        galaxy
+            .layout(Layout::Directory)
+            .init(dir.clone(), true)
+            .unwrap();
+        let path = dir.join(".planit");
+        let comet = path.join("comets/0.json");
+        let planet = path.join("planets/1.json");
+        // Files of unchanged celestial bodies are not even read, so edits to
+        // them survive
+        let edited = format!("{}\n", fs::read_to_string(&comet).unwrap());
+        fs::write(&comet, &edited).unwrap();
+
+        let mut galaxy = Galaxy::load_from(&path).unwrap();
+        let id = galaxy.planet().id();
+        if let Some(CelestialBodyMut::Planet(planet)) = galaxy.get_mut(1) {
+            planet.title = "Changed".into();
+        }
+        galaxy
+            .save_to_storage(storage::open(&path).as_ref())
+            .unwrap();
+        assert_eq!(fs::read_to_string(&comet).unwrap(), edited);
+        assert!(fs::read_to_string(&planet).unwrap().contains("Changed"));
+        assert!(path.join(format!("planets/{id}.json")).is_file());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn placed_bodies_keep_their_order_in_a_directory() {
+        let dir = env::temp_dir().join(format!("planit-placed-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut galaxy = Galaxy::default();
+        let first = galaxy.planet().id();
+        galaxy.planet();
+        let third = galaxy.planet().id();
+        galaxy
+            .layout(Layout::Directory)
+            .init(dir.clone(), true)
+            .unwrap();
+        let path = dir.join(".planit");
+
+        let mut galaxy = Galaxy::load_from(&path).unwrap();
+        galaxy.place(third, first, false).unwrap();
+        let order = galaxy.siblings(first);
+        galaxy
+            .save_to_storage(storage::open(&path).as_ref())
+            .unwrap();
+        let galaxy = Galaxy::load_from(&path).unwrap();
+        assert_eq!(galaxy.siblings(first), order);
+        assert_eq!(order[0], third);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn database_can_be_converted_between_formats() {
+        for format in [Format::Toml, Format::Yaml] {
+            let mut galaxy = Galaxy::load_from_reader(io::Cursor::new(DB_STRING)).unwrap();
+            galaxy.format = format;
+            let mut converted = Vec::new();
+            galaxy.save_to_writer(&mut converted).unwrap();
+
+            let galaxy = Galaxy::load_from_reader_as(converted.as_slice(), format).unwrap();
+            assert_eq!(galaxy.format, format);
+            let galaxy = galaxy.format(Format::Json);
+            let mut writer = Vec::new();
+            galaxy.save_to_writer(&mut writer).unwrap();
+            assert_eq!(String::from_utf8(writer).unwrap(), DB_STRING);
+        }
+    }
+
     #[test]
     fn loaded_galaxy_can_be_saved_without_changes() {
         let reader = io::Cursor::new(DB_STRING);
@@ -0,0 +1,300 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the grouping of celestial bodies into sections, e.g. by
+ * status or tag, as done by `planit list --group-by` and the columns of the
+ * kanban view.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Galaxy, Priority, Status, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What celestial bodies are grouped by. Written as its name (e.g. `tag`), or
+/// as `field:<key>` for a custom field.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum GroupBy {
+    /// One group per status, in the order of the workflow
+    #[default]
+    Status,
+    /// One group per tag. Celestial bodies with several tags are in several
+    /// groups.
+    Tag,
+    /// One group per assignee. Celestial bodies with several assignees are in
+    /// several groups.
+    Assignee,
+    /// One group per parent
+    Parent,
+    /// One group per priority, most important first
+    Priority,
+    /// One group per value of the custom field with the key
+    Field(String),
+}
+
+/// Helper enum that orders the groups, with the celestial bodies that have no
+/// tag / assignee / parent last
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Key {
+    Status(Status),
+    Priority(Priority),
+    Name(String),
+    Parent(ID),
+    Missing,
+}
+
+impl fmt::Display for GroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Status => write!(f, "status"),
+            Self::Tag => write!(f, "tag"),
+            Self::Assignee => write!(f, "assignee"),
+            Self::Parent => write!(f, "parent"),
+            Self::Priority => write!(f, "priority"),
+            Self::Field(key) => write!(f, "field:{key}"),
+        }
+    }
+}
+
+impl FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "status" => Ok(Self::Status),
+            "tag" => Ok(Self::Tag),
+            "assignee" => Ok(Self::Assignee),
+            "parent" => Ok(Self::Parent),
+            "priority" => Ok(Self::Priority),
+            _ => match s.trim().split_once(':') {
+                Some((field, key)) if field.eq_ignore_ascii_case("field") && !key.is_empty() => {
+                    Ok(Self::Field(key.to_string()))
+                }
+                _ => Err(format!(
+                    "Cannot group by {s}, expected status, tag, assignee, parent, priority, or \
+                     field:<key>"
+                )),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for GroupBy {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<GroupBy> for String {
+    fn from(value: GroupBy) -> Self {
+        value.to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A section of grouped celestial bodies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// What the celestial bodies have in common, e.g. `Start` or `backend`
+    pub label: String,
+    pub ids: Vec<ID>,
+}
+
+impl GroupBy {
+    /// Groups `ids`, keeping their order within each group. Empty groups are
+    /// left out, and IDs that are not in `galaxy` are ignored.
+    pub fn group(&self, galaxy: &Galaxy, ids: &[ID]) -> Vec<Group> {
+        let mut groups: BTreeMap<Key, Vec<ID>> = BTreeMap::new();
+        for &id in ids {
+            let Some(body) = galaxy.get(id) else {
+                continue;
+            };
+            let keys = match self {
+                Self::Status => vec![Key::Status(body.status())],
+                Self::Tag => body.tags().iter().cloned().map(Key::Name).collect(),
+                Self::Assignee => body.assignees().iter().cloned().map(Key::Name).collect(),
+                Self::Parent => body.parent().into_iter().map(Key::Parent).collect(),
+                Self::Priority => vec![Key::Priority(body.priority())],
+                Self::Field(key) => body
+                    .fields()
+                    .and_then(|fields| fields.get(key))
+                    .map(|value| Key::Name(value.clone()))
+                    .into_iter()
+                    .collect(),
+            };
+            match keys.is_empty() {
+                true => groups.entry(Key::Missing).or_default().push(id),
+                false => keys
+                    .into_iter()
+                    .for_each(|key| groups.entry(key).or_default().push(id)),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, ids)| Group {
+                label: self.label(galaxy, key),
+                ids,
+            })
+            .collect()
+    }
+
+    /// Helper function that names the group of `key`
+    fn label(&self, galaxy: &Galaxy, key: Key) -> String {
+        match (key, self) {
+            (Key::Status(status), _) => status.to_string(),
+            (Key::Priority(priority), _) => priority.to_string(),
+            (Key::Name(name), _) => name,
+            (Key::Parent(id), _) => {
+                let title = galaxy.get(id).map(|b| b.title()).unwrap_or_default();
+                format!("{} {title}", galaxy.display_id(id))
+            }
+            (Key::Missing, Self::Tag) => "No tag".into(),
+            (Key::Missing, Self::Assignee) => "Unassigned".into(),
+            (Key::Missing, Self::Field(key)) => format!("No {key}"),
+            (Key::Missing, _) => "No parent".into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::CelestialBody;
+
+    #[test]
+    fn bodies_are_grouped_in_order() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().title("Backend".into()).id();
+        let a = galaxy.planet().id();
+        let b = galaxy.planet().id();
+        let c = galaxy.planet().id();
+        galaxy.set_parent(a, Some(star)).unwrap();
+        galaxy.set_parent(c, Some(star)).unwrap();
+        galaxy.get_mut(a).unwrap().tag("web".into());
+        galaxy.get_mut(a).unwrap().tag("api".into());
+        galaxy.get_mut(c).unwrap().tag("api".into());
+        galaxy
+            .get_mut(c)
+            .unwrap()
+            .status(Status::Start, String::new());
+
+        let groups = |by: GroupBy| {
+            by.group(&galaxy, &[c, b, a, 42])
+                .into_iter()
+                .map(|g| (g.label, g.ids))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            groups(GroupBy::Status),
+            [("Todo".into(), vec![b, a]), ("Start".into(), vec![c])]
+        );
+        assert_eq!(
+            groups(GroupBy::Tag),
+            [
+                ("api".into(), vec![c, a]),
+                ("web".into(), vec![a]),
+                ("No tag".into(), vec![b])
+            ]
+        );
+        assert_eq!(
+            groups(GroupBy::Parent),
+            [
+                ("#0 Backend".into(), vec![c, a]),
+                ("No parent".into(), vec![b])
+            ]
+        );
+        assert_eq!(
+            groups(GroupBy::Assignee),
+            [("Unassigned".into(), vec![c, b, a])]
+        );
+    }
+
+    #[test]
+    fn bodies_are_grouped_by_priority_and_fields() {
+        let mut galaxy = Galaxy::default();
+        let a = galaxy.planet().priority(Priority::Low).id();
+        let b = galaxy.planet().priority(Priority::High).id();
+        let c = galaxy.planet().priority(Priority::Low).id();
+        galaxy
+            .get_mut(a)
+            .unwrap()
+            .field("team".into(), "web".into());
+        galaxy
+            .get_mut(b)
+            .unwrap()
+            .field("team".into(), "api".into());
+
+        let groups = |by: GroupBy| {
+            by.group(&galaxy, &[a, b, c])
+                .into_iter()
+                .map(|g| (g.label, g.ids))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            groups(GroupBy::Priority),
+            [("High".into(), vec![b]), ("Low".into(), vec![a, c])]
+        );
+        assert_eq!(
+            groups("field:team".parse().unwrap()),
+            [
+                ("api".into(), vec![b]),
+                ("web".into(), vec![a]),
+                ("No team".into(), vec![c])
+            ]
+        );
+        assert_eq!("Priority".parse(), Ok(GroupBy::Priority));
+        assert_eq!(GroupBy::Field("team".into()).to_string(), "field:team");
+        assert!("field:".parse::<GroupBy>().is_err());
+    }
+}
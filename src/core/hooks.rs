@@ -0,0 +1,244 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the notifications that hooks are run for. Notifications
+ * are collected when the `Galaxy` is saved, so that every change is only
+ * reported once and only after it was written, no matter whether it was made
+ * from the command line or the TUI. They are then handed to the `Dispatcher`
+ * set with `set_dispatcher`, which decides what to do with them.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::HashMap, fmt, sync::OnceLock};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::{CelestialBodyKind, CelestialBodyRef, Status, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+static DISPATCHER: OnceLock<Box<dyn Dispatcher>> = OnceLock::new();
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TRAITS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Receives the notifications of a saved `Galaxy`
+pub trait Dispatcher: Send + Sync {
+    fn dispatch(&self, notification: &Notification);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What a notification is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Trigger {
+    /// The status of a celestial body changed
+    Status,
+    /// A celestial body was moved to `Done`
+    Completed,
+    /// A celestial body was moved to `Block`
+    Blocked,
+    /// The due date of an unfinished celestial body passed
+    Overdue,
+    /// The snooze of a celestial body ended, showing it in views again
+    Woke,
+}
+
+impl fmt::Display for Trigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Status => write!(f, "status"),
+            Self::Completed => write!(f, "completed"),
+            Self::Blocked => write!(f, "blocked"),
+            Self::Overdue => write!(f, "overdue"),
+            Self::Woke => write!(f, "woke"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Something that happened to a celestial body. This contains everything
+/// about the celestial body that hooks might need, since the `Galaxy` is gone
+/// by the time they run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Notification {
+    pub trigger: Trigger,
+    pub id: ID,
+    pub kind: CelestialBodyKind,
+    pub title: String,
+    pub status: Status,
+    /// The status before the change, only for status changes
+    pub old: Option<Status>,
+    /// The explanation of a status change
+    pub comment: String,
+    pub due: Option<NaiveDate>,
+    pub tags: Vec<String>,
+    pub fields: HashMap<String, String>,
+    /// When it happened
+    pub time: DateTime<Utc>,
+}
+
+impl Notification {
+    /// Creates a notification about `body`
+    pub(super) fn new(trigger: Trigger, body: &CelestialBodyRef, time: DateTime<Utc>) -> Self {
+        Self {
+            trigger,
+            id: body.id(),
+            kind: body.kind(),
+            title: body.title().to_string(),
+            status: body.status(),
+            old: None,
+            comment: String::new(),
+            due: body.due(),
+            tags: body.tags().to_vec(),
+            fields: body.fields().cloned().unwrap_or_default(),
+            time,
+        }
+    }
+
+    /// Returns the value of `key` (e.g. `title`, `old`, or `field.url`) as
+    /// text, so that it can be used in templates
+    pub fn value(&self, key: &str) -> Option<String> {
+        match key {
+            "trigger" => Some(self.trigger.to_string()),
+            "id" => Some(self.id.to_string()),
+            "kind" => Some(self.kind.to_string().to_lowercase()),
+            "title" => Some(self.title.clone()),
+            "status" => Some(self.status.to_string()),
+            "old" => Some(self.old.map(|s| s.to_string()).unwrap_or_default()),
+            "comment" => Some(self.comment.clone()),
+            "due" => Some(self.due.map(|d| d.to_string()).unwrap_or_default()),
+            "tags" => Some(self.tags.join(",")),
+            "time" => Some(self.time.to_rfc3339()),
+            _ => {
+                let key = key.strip_prefix("field.").unwrap_or(key);
+                self.fields.get(key).cloned()
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Sets what is done with notifications. Can only be set once, later calls
+/// are ignored. Notifications are dropped if it is never set.
+pub fn set_dispatcher(dispatcher: Box<dyn Dispatcher>) {
+    let _ = DISPATCHER.set(dispatcher);
+}
+
+/// Hands `notifications` to the dispatcher, if there is one
+pub(super) fn dispatch(notifications: &[Notification]) {
+    if let Some(dispatcher) = DISPATCHER.get() {
+        for notification in notifications {
+            dispatcher.dispatch(notification);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, Galaxy};
+
+    #[test]
+    fn saving_reports_status_changes_and_overdue_bodies_once() {
+        let mut galaxy = Galaxy::default();
+        let done = galaxy.planet().title("Done".into()).id();
+        let late = galaxy.planet().title("Late".into()).id();
+        galaxy
+            .get_mut(late)
+            .unwrap()
+            .field("due".into(), "2025-03-01".into());
+        galaxy
+            .get_mut(done)
+            .unwrap()
+            .status(Status::Done, "Shipped".into());
+
+        let today = NaiveDate::from_ymd_opt(2025, 3, 2).unwrap();
+        let notifications = galaxy.notifications(today);
+        let triggers: Vec<_> = notifications.iter().map(|n| (n.trigger, n.id)).collect();
+        assert_eq!(
+            triggers,
+            [
+                (Trigger::Status, done),
+                (Trigger::Completed, done),
+                (Trigger::Overdue, late),
+            ]
+        );
+        assert_eq!(notifications[1].value("old").as_deref(), Some("Todo"));
+        assert_eq!(
+            notifications[1].value("comment").as_deref(),
+            Some("Shipped")
+        );
+
+        // The overdue body is only reported again once its due date changes
+        assert!(galaxy
+            .notifications(today)
+            .iter()
+            .all(|n| n.trigger != Trigger::Overdue));
+        galaxy
+            .get_mut(late)
+            .unwrap()
+            .field("due".into(), "2025-03-02".into());
+        assert!(galaxy.notifications(today).is_empty());
+        let tomorrow = today.succ_opt().unwrap();
+        assert_eq!(galaxy.notifications(tomorrow)[0].trigger, Trigger::Overdue);
+    }
+}
@@ -0,0 +1,194 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the secondary indexes of a `Galaxy`.
+ *
+ * Besides the map from IDs to indices, the `Galaxy` keeps track of which
+ * celestial bodies have each status, tag, parent and due date, so that
+ * filtering only looks at the celestial bodies that can match instead of all
+ * of them. The indexes are built the first time they are needed. Afterwards,
+ * only the celestial bodies that were changed since are looked at again.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use chrono::NaiveDate;
+
+use super::{CelestialBodyRef, Status, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The indexed values of a single celestial body, needed to remove it from the
+/// indexes again once it changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    status: Status,
+    tags: Vec<String>,
+    parent: Option<ID>,
+    due: Option<NaiveDate>,
+}
+
+impl Entry {
+    fn of(body: &CelestialBodyRef) -> Self {
+        Self {
+            status: body.status(),
+            tags: body.tags().to_vec(),
+            parent: body.parent(),
+            due: body.due(),
+        }
+    }
+}
+
+/// The celestial bodies of a `Galaxy` by status, tag, parent and due date
+#[derive(Debug, Default, Clone)]
+pub(super) struct Indexes {
+    entries: HashMap<ID, Entry>,
+    by_status: BTreeMap<Status, BTreeSet<ID>>,
+    by_tag: BTreeMap<String, BTreeSet<ID>>,
+    by_parent: BTreeMap<Option<ID>, BTreeSet<ID>>,
+    by_due: BTreeMap<NaiveDate, BTreeSet<ID>>,
+    /// The celestial bodies that may have changed since they were indexed
+    stale: HashSet<ID>,
+}
+
+impl Indexes {
+    /// Builds the indexes of all `bodies`
+    pub fn new<'a>(bodies: impl Iterator<Item = CelestialBodyRef<'a>>) -> Self {
+        let mut indexes = Self::default();
+        for body in bodies {
+            indexes.insert(&body);
+        }
+        indexes
+    }
+
+    /// Remembers that the celestial body `id` may have changed (or was created
+    /// or removed), so that it is indexed again by `refresh`
+    pub fn invalidate(&mut self, id: ID) {
+        self.stale.insert(id);
+    }
+
+    /// Whether some celestial bodies have to be indexed again before the
+    /// indexes can be used
+    pub fn is_stale(&self) -> bool {
+        !self.stale.is_empty()
+    }
+
+    /// Indexes the celestial bodies that may have changed again. `get` returns
+    /// the celestial body with an ID, if it still exists.
+    pub fn refresh<'a>(&mut self, get: impl Fn(ID) -> Option<CelestialBodyRef<'a>>) {
+        for id in std::mem::take(&mut self.stale) {
+            let body = get(id);
+            if self.entries.get(&id) == body.as_ref().map(Entry::of).as_ref() {
+                continue;
+            }
+            self.remove(id);
+            if let Some(body) = body {
+                self.insert(&body);
+            }
+        }
+    }
+
+    /// The celestial bodies with the status `status`
+    pub fn with_status(&self, status: Status) -> impl Iterator<Item = ID> + '_ {
+        self.by_status.get(&status).into_iter().flatten().copied()
+    }
+
+    /// The celestial bodies with the tag `tag`
+    pub fn with_tag(&self, tag: &str) -> impl Iterator<Item = ID> + '_ {
+        self.by_tag.get(tag).into_iter().flatten().copied()
+    }
+
+    /// The celestial bodies directly owned by `parent`, or in the root of the
+    /// `Galaxy` if it is `None`
+    pub fn with_parent(&self, parent: Option<ID>) -> impl Iterator<Item = ID> + '_ {
+        self.by_parent.get(&parent).into_iter().flatten().copied()
+    }
+
+    /// The celestial bodies that are due on or before `until` along with their
+    /// due dates, sorted by them
+    pub fn due_until(&self, until: NaiveDate) -> impl Iterator<Item = (NaiveDate, ID)> + '_ {
+        self.by_due
+            .range(..=until)
+            .flat_map(|(due, ids)| ids.iter().map(|id| (*due, *id)))
+    }
+
+    /// Helper function that adds `body` to the indexes
+    fn insert(&mut self, body: &CelestialBodyRef) {
+        let id = body.id();
+        let entry = Entry::of(body);
+        self.by_status.entry(entry.status).or_default().insert(id);
+        for tag in &entry.tags {
+            self.by_tag.entry(tag.clone()).or_default().insert(id);
+        }
+        self.by_parent.entry(entry.parent).or_default().insert(id);
+        if let Some(due) = entry.due {
+            self.by_due.entry(due).or_default().insert(id);
+        }
+        self.entries.insert(id, entry);
+    }
+
+    /// Helper function that removes the celestial body `id` from the indexes
+    fn remove(&mut self, id: ID) {
+        let Some(entry) = self.entries.remove(&id) else {
+            return;
+        };
+        remove_from(&mut self.by_status, &entry.status, id);
+        for tag in &entry.tags {
+            remove_from(&mut self.by_tag, tag, id);
+        }
+        remove_from(&mut self.by_parent, &entry.parent, id);
+        if let Some(due) = entry.due {
+            remove_from(&mut self.by_due, &due, id);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that removes `id` from the set of `key` in `index`, and the
+/// set itself once it is empty
+fn remove_from<K: Ord>(index: &mut BTreeMap<K, BTreeSet<ID>>, key: &K, id: ID) {
+    if let Some(ids) = index.get_mut(key) {
+        ids.remove(&id);
+        if ids.is_empty() {
+            index.remove(key);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing links from celestial bodies to things outside of the
+ * galaxy, such as pull requests, documents, and designs.
+ *
+ * A link is either a URL or a path to a file. Links are opened with the system
+ * opener (`xdg-open`, `open`, or `start`), so anything the opener understands
+ * can be linked.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A labelled URL or file path attached to a celestial body
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Link {
+    /// The name shown for the link, which is the target if no label was given
+    pub label: String,
+    /// The URL or file path that is opened
+    pub target: String,
+}
+
+impl Link {
+    /// Creates a link to `target`, which is labelled with the target itself if
+    /// `label` is `None` or empty
+    pub fn new(target: String, label: Option<String>) -> Self {
+        let label = label
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .unwrap_or_else(|| target.clone());
+        Self { label, target }
+    }
+
+    /// Whether the target is a URL (e.g. `https://...` or `mailto:...`) rather
+    /// than a file path
+    pub fn is_url(&self) -> bool {
+        self.target.split_once(':').is_some_and(|(scheme, _)| {
+            scheme.len() > 1
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        })
+    }
+}
+
+impl fmt::Display for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.label == self.target {
+            write!(f, "{}", self.target)
+        } else {
+            write!(f, "{} ({})", self.label, self.target)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn links_are_labelled_with_their_target_by_default() {
+        let link = Link::new("https://example.com/pr/1".into(), None);
+        assert_eq!(link.label, "https://example.com/pr/1");
+        assert_eq!(link.to_string(), "https://example.com/pr/1");
+        assert!(link.is_url());
+
+        let link = Link::new("docs/design.pdf".into(), Some("Design".into()));
+        assert_eq!(link.to_string(), "Design (docs/design.pdf)");
+        assert!(!link.is_url());
+
+        let link = Link::new(r"C:\docs\design.pdf".into(), Some(" ".into()));
+        assert_eq!(link.label, r"C:\docs\design.pdf");
+        assert!(!link.is_url());
+    }
+}
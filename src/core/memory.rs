@@ -0,0 +1,154 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing an estimate of how much memory a `Galaxy` uses.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::mem::{size_of, size_of_val};
+
+use super::{CelestialBodyKind, CelestialBodyRef, Comet, Galaxy, Planet, Star, StatusHistory};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// An estimate of how much memory the celestial bodies of a `Galaxy`
+/// (including the ones in the trash) take up. Only the largest allocations are
+/// counted, so the actual usage is somewhat higher.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Number of celestial bodies
+    pub bodies: usize,
+    /// Number of status changes in the histories of the celestial bodies
+    pub history_entries: usize,
+    /// Number of other changes in the histories of the celestial bodies
+    pub events: usize,
+    /// Bytes used by the celestial bodies themselves (titles, tags, etc.)
+    pub body_bytes: usize,
+    /// Bytes used by the status changes
+    pub history_bytes: usize,
+    /// Bytes used by the other changes
+    pub event_bytes: usize,
+}
+
+impl MemoryUsage {
+    /// Estimates the memory used by `galaxy`
+    pub fn of(galaxy: &Galaxy) -> Self {
+        let trashed = galaxy.trash().iter().flat_map(|entry| {
+            let comets = entry.comets.iter().map(CelestialBodyRef::Comet);
+            let planets = entry.planets.iter().map(CelestialBodyRef::Planet);
+            comets
+                .chain(planets)
+                .chain(entry.stars.iter().map(CelestialBodyRef::Star))
+        });
+
+        let mut usage = Self::default();
+        for body in galaxy.iter().chain(trashed) {
+            usage.add(body);
+        }
+        usage
+    }
+
+    /// The total number of bytes used
+    pub fn total(&self) -> usize {
+        self.body_bytes + self.history_bytes + self.event_bytes
+    }
+
+    /// Helper function that adds the memory used by `body`
+    fn add(&mut self, body: CelestialBodyRef) {
+        let strings = |s: &[String]| {
+            s.iter()
+                .map(|s| size_of::<String>() + s.len())
+                .sum::<usize>()
+        };
+        let fields: usize = body.fields().map_or(0, |fields| {
+            fields
+                .iter()
+                .map(|(k, v)| 2 * size_of::<String>() + k.len() + v.len())
+                .sum()
+        });
+        let size = match body.kind() {
+            CelestialBodyKind::Comet => size_of::<Comet>(),
+            CelestialBodyKind::Planet => size_of::<Planet>(),
+            CelestialBodyKind::Star => size_of::<Star>() + body.children().len() * 8,
+        };
+
+        self.bodies += 1;
+        self.body_bytes +=
+            size + body.title().len() + body.description().len() + strings(body.tags()) + fields;
+        self.history_entries += body.history().len();
+        self.history_bytes += body
+            .history()
+            .iter()
+            .map(|change| size_of::<StatusHistory>() + change.comment.len())
+            .sum::<usize>();
+        self.events += body.events().len();
+        self.event_bytes += size_of_val(body.events());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, Status};
+
+    #[test]
+    fn capping_history_reduces_memory_usage() {
+        let mut galaxy = Galaxy::default();
+        let planet = galaxy.planet().id();
+        for _ in 0..10 {
+            let mut body = galaxy.get_mut(planet).unwrap();
+            body.status(Status::Start, "started".into());
+            body.status(Status::Todo, "stopped".into());
+        }
+
+        let before = MemoryUsage::of(&galaxy);
+        assert_eq!(before.bodies, 1);
+        assert_eq!(before.history_entries, 20);
+        assert_eq!(before.events, 1);
+
+        assert_eq!(galaxy.cap_history(5), 15);
+        let after = MemoryUsage::of(&galaxy);
+        assert_eq!(after.history_entries, 5);
+        assert_eq!(after.events, 1);
+        assert!(after.total() < before.total());
+        assert_eq!(galaxy.get(planet).unwrap().status(), Status::Todo);
+    }
+}
@@ -0,0 +1,691 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the three-way merge of `Galaxy`s, so that a database that
+ * is committed to git can be merged structurally instead of line by line.
+ *
+ * Celestial bodies are matched by ID and merged field by field. Lists such as
+ * tags, the history, and the events are merged as sets, so additions from both
+ * sides are kept. A field that was changed differently on both sides is a
+ * conflict, which is resolved by keeping the value of the side that changed
+ * the celestial body last.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+use super::{DatabaseError, Galaxy, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// One of the two versions being merged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Ours,
+    Theirs,
+}
+
+/// How a conflict was resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    /// The value of one side was kept
+    Kept(Side),
+    /// The celestial body was changed on one side but deleted on the other,
+    /// and stays deleted
+    Deleted,
+    /// The parent of the celestial body was deleted on the other side, so it
+    /// was moved to the root of the `Galaxy`
+    MovedToRoot,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A change that was made on both sides and could not be combined
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// The celestial body (or Star of a retrospective from before sprints),
+    /// `None` for the `Galaxy` itself and its sprints, plans, and
+    /// retrospectives
+    pub id: Option<ID>,
+    /// The name of the conflicting value, e.g. `title` or `fields.owner`
+    pub field: String,
+    pub resolution: Resolution,
+}
+
+/// The result of merging two versions of a `Galaxy`
+#[derive(Debug)]
+pub struct Merge {
+    pub galaxy: Galaxy,
+    /// The conflicts, in the order they were found
+    pub conflicts: Vec<MergeConflict>,
+    /// Celestial bodies that were created on both sides with the same ID, as
+    /// the old and the new ID of the one created by `theirs`
+    pub renumbered: Vec<(ID, ID)>,
+}
+
+impl Merge {
+    /// Merges the changes made since `base` in `ours` and in `theirs`
+    ///
+    /// # Errors
+    /// Errors will occur if one of the `Galaxy`s had its history capped, or if
+    /// the merged `Galaxy` is not consistent (e.g. both sides moved a Star
+    /// into the other one)
+    pub fn of(base: Galaxy, ours: Galaxy, theirs: Galaxy) -> Result<Self, DatabaseError> {
+        let base = base.into_json()?;
+        let ours = ours.into_json()?;
+        let mut theirs = theirs.into_json()?;
+
+        let mut next_id = ours["next_id"].as_u64().max(theirs["next_id"].as_u64());
+        let renumbered = renumber(&base, &ours, &mut theirs, next_id.as_mut());
+
+        // Fields with a default value are left out of the database, so a field
+        // may only be found on one of the sides
+        let keys: BTreeSet<String> = [&base, &ours, &theirs]
+            .into_iter()
+            .flat_map(|galaxy| galaxy.as_object().into_iter().flatten())
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        let mut merger = Merger::default();
+        let mut merged = Map::new();
+        for key in &keys {
+            let (b, o, t) = (Some(&base[key]), &ours[key], &theirs[key]);
+            let value = match key.as_str() {
+                "next_id" => next_id.into(),
+                "last_key" => o.as_u64().max(t.as_u64()).into(),
+                "comets" | "planets" | "stars" => merger.entries(b, o, t, &["id"], |m, b, o, t| {
+                    let newer = match updated_at(t) > updated_at(o) {
+                        true => Side::Theirs,
+                        false => Side::Ours,
+                    };
+                    m.record(b, o, t, o["id"].as_u64(), "", newer)
+                }),
+                "retros" => merger.entries(b, o, t, &["sprint", "star"], |m, b, o, t| {
+                    let prefix = match o["sprint"].as_str() {
+                        Some(sprint) if !sprint.is_empty() => format!("retros.{sprint}."),
+                        _ => "retros.".into(),
+                    };
+                    m.record(b, o, t, o["star"].as_u64(), &prefix, Side::Ours)
+                }),
+                "plans" => merger.entries(b, o, t, &["date"], |m, b, o, t| {
+                    m.record(b, o, t, None, "plans.", Side::Ours)
+                }),
+                "sprints" => merger.entries(b, o, t, &["name"], |m, b, o, t| {
+                    m.record(b, o, t, None, "sprints.", Side::Ours)
+                }),
+                // Trash entries never change, they are only added and removed
+                "trash" => merger.entries(b, o, t, &["id"], |m, b, o, t| {
+                    m.scalar(b, o, t, o["id"].as_u64(), "trash", Side::Ours)
+                }),
+                _ => merger.scalar(b, o, t, None, key, Side::Ours),
+            };
+            // A field that was reset to its default on one side is left out
+            if !value.is_null() || (ours.get(key).is_some() && theirs.get(key).is_some()) {
+                merged.insert(key.clone(), value);
+            }
+        }
+        let mut merged = Value::Object(merged);
+        merger.reparent(&mut merged);
+        rekey(&mut merged);
+
+        Ok(Self {
+            galaxy: Galaxy::from_json(merged)?,
+            conflicts: merger.conflicts,
+            renumbered,
+        })
+    }
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.id {
+            Some(id) => write!(f, "#{id}: ")?,
+            None => write!(f, "Galaxy: ")?,
+        }
+        match self.resolution {
+            Resolution::Kept(side) => write!(
+                f,
+                "`{}` was changed on both sides, kept {}",
+                self.field,
+                match side {
+                    Side::Ours => "ours",
+                    Side::Theirs => "theirs",
+                }
+            ),
+            Resolution::Deleted => write!(f, "changed on one side but deleted on the other"),
+            Resolution::MovedToRoot => write!(f, "its parent was deleted, moved to the root"),
+        }
+    }
+}
+
+/// Helper struct that collects the conflicts while merging
+#[derive(Debug, Default)]
+struct Merger {
+    conflicts: Vec<MergeConflict>,
+}
+
+impl Merger {
+    /// Merges two lists of objects that are identified by their `key` fields.
+    /// Objects that exist on both sides are merged with `merge`, and objects
+    /// that were removed on one side are removed, even if they were changed
+    /// on the other side.
+    fn entries<F>(
+        &mut self,
+        base: Option<&Value>,
+        ours: &Value,
+        theirs: &Value,
+        key: &[&str],
+        mut merge: F,
+    ) -> Value
+    where
+        F: FnMut(&mut Self, Option<&Value>, &Value, &Value) -> Value,
+    {
+        let (b, o, t) = (
+            index(base, key),
+            index(Some(ours), key),
+            index(Some(theirs), key),
+        );
+
+        let mut merged = Vec::new();
+        for entry in ours.as_array().into_iter().flatten() {
+            let k = identify(entry, key);
+            match (b.get(&k), t.get(&k)) {
+                (base, Some(theirs)) => merged.push(merge(self, base.copied(), entry, theirs)),
+                (Some(base), None) if *base != entry => self.deleted(entry[key[0]].as_u64()),
+                (Some(_), None) => {}
+                (None, None) => merged.push(entry.clone()),
+            }
+        }
+        for entry in theirs.as_array().into_iter().flatten() {
+            let k = identify(entry, key);
+            match (b.get(&k), o.contains_key(&k)) {
+                (_, true) => {}
+                (Some(base), false) if *base != entry => self.deleted(entry[key[0]].as_u64()),
+                (Some(_), false) => {}
+                (None, false) => merged.push(entry.clone()),
+            }
+        }
+        Value::Array(merged)
+    }
+
+    /// Merges two objects field by field, resolving conflicts by keeping the
+    /// value of `newer`. `prefix` is put in front of the names of the fields
+    /// in conflicts.
+    fn record(
+        &mut self,
+        base: Option<&Value>,
+        ours: &Value,
+        theirs: &Value,
+        id: Option<ID>,
+        prefix: &str,
+        newer: Side,
+    ) -> Value {
+        let (Some(o), Some(t)) = (ours.as_object(), theirs.as_object()) else {
+            return self.scalar(base, ours, theirs, id, prefix, newer);
+        };
+        let b = base.and_then(Value::as_object);
+        let field = |key: &str| b.and_then(|b| b.get(key));
+
+        let mut merged = Map::new();
+        for (key, ov) in o {
+            let bv = field(key);
+            let Some(tv) = t.get(key) else {
+                // Removed by theirs, unless it was added or changed by ours
+                if bv != Some(ov) {
+                    merged.insert(key.clone(), ov.clone());
+                }
+                continue;
+            };
+            let name = format!("{prefix}{key}");
+            let value = match (key.as_str(), ov) {
+                ("updated_at", _) => match updated_at(theirs) > updated_at(ours) {
+                    true => tv.clone(),
+                    false => ov.clone(),
+                },
+                ("fields", _) => self.record(bv, ov, tv, id, "fields.", newer),
+                ("work_log", _) => self.entries(bv, ov, tv, &["start"], |m, b, o, t| {
+                    m.scalar(b, o, t, id, &name, newer)
+                }),
+                (_, Value::Array(_)) => union(bv, ov, tv),
+                _ => self.scalar(bv, ov, tv, id, &name, newer),
+            };
+            merged.insert(key.clone(), value);
+        }
+        for (key, tv) in t.iter().filter(|(key, _)| !o.contains_key(*key)) {
+            if field(key) != Some(tv) {
+                merged.insert(key.clone(), tv.clone());
+            }
+        }
+        Value::Object(merged)
+    }
+
+    /// Merges a single value, keeping the value of `newer` if both sides
+    /// changed it
+    fn scalar(
+        &mut self,
+        base: Option<&Value>,
+        ours: &Value,
+        theirs: &Value,
+        id: Option<ID>,
+        field: &str,
+        newer: Side,
+    ) -> Value {
+        if ours == theirs || base == Some(theirs) {
+            return ours.clone();
+        }
+        if base == Some(ours) {
+            return theirs.clone();
+        }
+        self.conflicts.push(MergeConflict {
+            id,
+            field: field.to_string(),
+            resolution: Resolution::Kept(newer),
+        });
+        match newer {
+            Side::Ours => ours.clone(),
+            Side::Theirs => theirs.clone(),
+        }
+    }
+
+    /// Helper function that records that a changed celestial body was deleted
+    fn deleted(&mut self, id: Option<ID>) {
+        self.conflicts.push(MergeConflict {
+            id,
+            field: String::new(),
+            resolution: Resolution::Deleted,
+        });
+    }
+
+    /// Rebuilds the children of every Star from the parents of the merged
+    /// celestial bodies, as the children and the parent are merged
+    /// separately. Celestial bodies whose parent no longer exists are moved to
+    /// the root.
+    fn reparent(&mut self, galaxy: &mut Value) {
+        let stars: HashSet<u64> = bodies(galaxy, &["stars"])
+            .filter_map(|star| star["id"].as_u64())
+            .collect();
+
+        let mut children: HashMap<u64, Vec<u64>> = HashMap::new();
+        for kind in ["comets", "planets", "stars"] {
+            for body in galaxy[kind].as_array_mut().into_iter().flatten() {
+                let Some(parent) = body["parent"].as_u64() else {
+                    continue;
+                };
+                let id = body["id"].as_u64();
+                if stars.contains(&parent) {
+                    children.entry(parent).or_default().extend(id);
+                } else {
+                    body["parent"] = Value::Null;
+                    self.conflicts.push(MergeConflict {
+                        id,
+                        field: "parent".into(),
+                        resolution: Resolution::MovedToRoot,
+                    });
+                }
+            }
+        }
+
+        for star in galaxy["stars"].as_array_mut().into_iter().flatten() {
+            let wanted = star["id"]
+                .as_u64()
+                .and_then(|id| children.remove(&id))
+                .unwrap_or_default();
+            // Keep the merged order, followed by the children that were only
+            // found through their parent
+            let mut list: Vec<u64> = star["children"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_u64)
+                .filter(|child| wanted.contains(child))
+                .collect();
+            for child in wanted {
+                if !list.contains(&child) {
+                    list.push(child);
+                }
+            }
+            star["children"] = list.into();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Helper function that merges two lists as sets, keeping the order of `ours`
+/// followed by the additions of `theirs`. Lists of timestamped entries (e.g.
+/// the history) are sorted by time afterwards.
+fn union(base: Option<&Value>, ours: &Value, theirs: &Value) -> Value {
+    let list = |v: Option<&Value>| v.and_then(Value::as_array).cloned().unwrap_or_default();
+    let (b, o, t) = (list(base), list(Some(ours)), list(Some(theirs)));
+
+    let mut merged: Vec<Value> = o
+        .iter()
+        .filter(|v| t.contains(v) || !b.contains(v))
+        .cloned()
+        .collect();
+    merged.extend(t.into_iter().filter(|v| !o.contains(v) && !b.contains(v)));
+    if merged.iter().all(|v| v.get("time").is_some()) {
+        merged.sort_by_cached_key(|v| time(&v["time"]));
+    }
+    Value::Array(merged)
+}
+
+/// Helper function that maps the `key` fields of the objects in `list` to the
+/// objects
+fn index<'a>(list: Option<&'a Value>, key: &[&str]) -> HashMap<String, &'a Value> {
+    let list = list.and_then(Value::as_array).into_iter().flatten();
+    list.map(|entry| (identify(entry, key), entry)).collect()
+}
+
+/// Helper function that joins the `key` fields of `entry` into a single key
+fn identify(entry: &Value, key: &[&str]) -> String {
+    let fields: Vec<String> = key.iter().map(|key| entry[key].to_string()).collect();
+    fields.join(",")
+}
+
+/// Helper function that parses a timestamp
+fn time(value: &Value) -> Option<DateTime<Utc>> {
+    serde_json::from_value(value.clone()).ok()
+}
+
+/// Helper function that finds when a celestial body was last changed
+fn updated_at(body: &Value) -> Option<DateTime<Utc>> {
+    time(&body["updated_at"])
+}
+
+/// Helper function that iterates over the celestial bodies of the `kinds` in
+/// the database `galaxy`, including the ones in the trash
+fn bodies<'a>(galaxy: &'a Value, kinds: &'a [&str]) -> impl Iterator<Item = &'a Value> {
+    let trash = galaxy["trash"].as_array().into_iter().flatten();
+    std::iter::once(galaxy).chain(trash).flat_map(move |v| {
+        kinds
+            .iter()
+            .flat_map(move |kind| v[*kind].as_array().into_iter().flatten())
+    })
+}
+
+/// Helper function that gives the celestial bodies that were created with the
+/// same IDs on both sides new IDs on `theirs` side, starting at `next_id`
+fn renumber(
+    base: &Value,
+    ours: &Value,
+    theirs: &mut Value,
+    next_id: Option<&mut u64>,
+) -> Vec<(ID, ID)> {
+    const KINDS: &[&str] = &["comets", "planets", "stars"];
+    let ids = |galaxy: &Value| -> HashMap<u64, Value> {
+        bodies(galaxy, KINDS)
+            .filter_map(|body| Some((body["id"].as_u64()?, body.clone())))
+            .collect()
+    };
+    let (b, o) = (ids(base), ids(ours));
+    let collisions: BTreeSet<u64> = ids(theirs)
+        .into_iter()
+        .filter(|(id, body)| !b.contains_key(id) && o.get(id).is_some_and(|o| o != body))
+        .map(|(id, _)| id)
+        .collect();
+    let Some(next_id) = next_id else {
+        return Vec::new();
+    };
+
+    let mut renumbered = HashMap::new();
+    for id in collisions {
+        renumbered.insert(id, *next_id);
+        *next_id += 1;
+    }
+    let map = |value: &mut Value| {
+        if let Some(new) = value.as_u64().and_then(|id| renumbered.get(&id)) {
+            *value = (*new).into();
+        }
+    };
+
+    let rewrite = |galaxy: &mut Value| {
+        for kind in KINDS {
+            for body in galaxy[*kind].as_array_mut().into_iter().flatten() {
+                map(&mut body["id"]);
+                map(&mut body["parent"]);
+                if let Some(children) = body.get_mut("children").and_then(Value::as_array_mut) {
+                    children.iter_mut().for_each(map);
+                }
+            }
+        }
+    };
+    rewrite(theirs);
+    for entry in theirs["trash"].as_array_mut().into_iter().flatten() {
+        map(&mut entry["id"]);
+        rewrite(entry);
+    }
+    for retro in theirs["retros"].as_array_mut().into_iter().flatten() {
+        if let Some(star) = retro.get_mut("star") {
+            map(star);
+        }
+        let items = retro["action_items"].as_array_mut().into_iter().flatten();
+        items.for_each(map);
+    }
+    for key in ["plans", "sprints"] {
+        for plan in theirs[key].as_array_mut().into_iter().flatten() {
+            plan["items"]
+                .as_array_mut()
+                .into_iter()
+                .flatten()
+                .for_each(map);
+        }
+    }
+
+    let mut renumbered: Vec<(ID, ID)> = renumbered.into_iter().collect();
+    renumbered.sort();
+    renumbered
+}
+
+/// Helper function that gives new short keys to the celestial bodies whose
+/// key is already used by another one, as both sides may have handed out the
+/// same keys
+fn rekey(galaxy: &mut Value) {
+    let Some(prefix) = galaxy["key_prefix"].as_str().map(str::to_string) else {
+        return;
+    };
+    let mut last_key = galaxy["last_key"].as_u64().unwrap_or_default();
+    let keys: Vec<String> = bodies(galaxy, &["comets", "planets", "stars"])
+        .filter_map(|body| body["key"].as_str())
+        .map(str::to_lowercase)
+        .collect();
+    let mut used: HashSet<String> = HashSet::new();
+    for kind in ["comets", "planets", "stars"] {
+        for body in galaxy[kind].as_array_mut().into_iter().flatten() {
+            let Some(key) = body["key"].as_str().map(str::to_lowercase) else {
+                continue;
+            };
+            if used.insert(key) {
+                continue;
+            }
+            let key = loop {
+                last_key += 1;
+                let key = format!("{prefix}{last_key}");
+                if !keys.contains(&key.to_lowercase()) {
+                    break key;
+                }
+            };
+            used.insert(key.to_lowercase());
+            body["key"] = key.into();
+        }
+    }
+    galaxy["last_key"] = last_key.into();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, TimeDelta};
+
+    use super::*;
+    use crate::core::{CelestialBody, Rules, Stage, Status};
+
+    #[test]
+    fn merges_combine_the_changes_of_both_sides() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let planet = galaxy.planet().id();
+        let comet = galaxy.comet().id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        let base = galaxy.into_json().unwrap();
+        let copy = || Galaxy::from_json(base.clone()).unwrap();
+
+        let mut ours = copy();
+        let mut body = ours.get_mut(planet).unwrap();
+        body.title("Fix login".into()).tag("auth".into());
+        ours.get_mut(star).unwrap().description("Ours".into());
+        let added = ours.planet().id();
+
+        let mut theirs = copy();
+        let mut body = theirs.get_mut(planet).unwrap();
+        body.status(Status::Start, String::new())
+            .tag("urgent".into());
+        theirs.get_mut(star).unwrap().description("Theirs".into());
+        theirs.stars[0].updated_at = Some(Utc::now() + TimeDelta::hours(1));
+        theirs.delete(comet, Utc::now()).unwrap();
+        let other = theirs.comet().id();
+        theirs.set_parent(other, Some(star)).unwrap();
+
+        let merge = Merge::of(copy(), ours, theirs).unwrap();
+        assert_eq!(
+            merge.conflicts,
+            vec![MergeConflict {
+                id: Some(star),
+                field: "description".into(),
+                resolution: Resolution::Kept(Side::Theirs),
+            }]
+        );
+        assert_eq!(merge.renumbered, vec![(other, added + 1)]);
+
+        let galaxy = merge.galaxy;
+        let body = galaxy.get(planet).unwrap();
+        assert_eq!(body.title(), "Fix login");
+        assert_eq!(body.status(), Status::Start);
+        assert_eq!(body.tags(), ["auth", "urgent"]);
+        assert!(galaxy.get(comet).is_none());
+        assert_eq!(galaxy.get(added).unwrap().title(), "");
+        assert_eq!(galaxy.get(added + 1).unwrap().parent(), Some(star));
+        assert_eq!(galaxy.get(star).unwrap().children(), [planet, added + 1]);
+        assert_eq!(galaxy.get(star).unwrap().description(), "Theirs");
+    }
+
+    #[test]
+    fn fields_only_found_on_one_side_are_merged() {
+        let base = Galaxy::default().into_json().unwrap();
+        let copy = || Galaxy::from_json(base.clone()).unwrap();
+
+        let mut theirs = copy();
+        let stage = Stage::new("review".into(), Status::Start);
+        theirs.workflow_add(stage.clone()).unwrap();
+        let merge = Merge::of(copy(), copy(), theirs).unwrap();
+        assert!(merge.conflicts.is_empty());
+        assert_eq!(merge.galaxy.workflow().find("review"), Some(&stage));
+
+        // Removing it again leaves the field out of the database on one side
+        let base = merge.galaxy.into_json().unwrap();
+        let copy = || Galaxy::from_json(base.clone()).unwrap();
+        let mut ours = copy();
+        ours.workflow_remove("review").unwrap();
+        let merge = Merge::of(copy(), ours, copy()).unwrap();
+        assert!(merge.conflicts.is_empty());
+        assert!(merge.galaxy.workflow().find("review").is_none());
+    }
+    #[test]
+    fn rules_changed_on_one_side_are_kept() {
+        let base = Galaxy::default().into_json().unwrap();
+        let copy = || Galaxy::from_json(base.clone()).unwrap();
+
+        let mut theirs = copy();
+        let rules = Rules {
+            enabled: true,
+            ..Rules::default()
+        };
+        theirs.set_rules(rules.clone());
+        let merge = Merge::of(copy(), copy(), theirs).unwrap();
+        assert!(merge.conflicts.is_empty());
+        assert_eq!(merge.galaxy.get_rules(), &rules);
+    }
+
+    #[test]
+    fn retros_of_different_sprints_are_kept_apart() {
+        let mut galaxy = Galaxy::default();
+        let first = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        let second = NaiveDate::from_ymd_opt(2025, 3, 17).unwrap();
+        galaxy
+            .sprint_create("one".into(), first, first + TimeDelta::days(13))
+            .unwrap();
+        galaxy
+            .sprint_create("two".into(), second, second + TimeDelta::days(13))
+            .unwrap();
+        let base = galaxy.into_json().unwrap();
+        let copy = || Galaxy::from_json(base.clone()).unwrap();
+
+        let mut ours = copy();
+        ours.retro("one").unwrap().went_well.push("Pairing".into());
+        let mut theirs = copy();
+        theirs
+            .retro("two")
+            .unwrap()
+            .needs_improvement
+            .push("Reviews".into());
+
+        let merge = Merge::of(copy(), ours, theirs).unwrap();
+        assert!(merge.conflicts.is_empty());
+        let galaxy = merge.galaxy;
+        let one = galaxy.get_retro("one").unwrap();
+        assert_eq!(one.went_well, ["Pairing"]);
+        assert!(one.needs_improvement.is_empty());
+        let two = galaxy.get_retro("two").unwrap();
+        assert!(two.went_well.is_empty());
+        assert_eq!(two.needs_improvement, ["Reviews"]);
+    }
+}
@@ -31,7 +31,7 @@
  * All celestial bodies have shared core features: namely a title and a
  * description. This will be used for display purposes. Additionally, with the
  * exception of the `Galaxy`, everything has a unique ID, a `Status`, a
- * parent, and a history. The parent can optionally be `None` if the celestial
+ * `Priority`, a parent, and a history. The parent can optionally be `None` if the celestial
  * body is in the root of the `Galaxy`. The history will keep track of all
  * changes to the status of the celestial body.
  */
@@ -42,10 +42,41 @@
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
+mod activity;
+mod blocker;
+mod body;
+mod burndown;
+mod calendar;
+mod checklist;
 mod comet;
+mod commit;
+mod compare;
+mod demo;
+mod estimate;
+mod event;
+mod filter;
 mod galaxy;
+mod group;
+mod hooks;
+mod index;
+mod link;
+mod memory;
+mod merge;
+mod plan;
 mod planet;
+mod remind;
+mod retro;
+mod rules;
+mod schema;
+mod sort;
+mod sprint;
 mod star;
+mod stats;
+mod storage;
+mod summary;
+mod trash;
+mod workflow;
+mod worklog;
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -56,15 +87,51 @@ mod star;
 use std::fmt::Display;
 
 use chrono::{DateTime, Utc};
+use clap::ValueEnum;
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 
-use crate::app::cli;
+pub use crate::core::activity::{Activity, ActivityEntry};
+pub use crate::core::blocker::Blocker;
+pub use crate::core::body::{CelestialBodyMut, CelestialBodyRef};
+pub use crate::core::burndown::{Burndown, BurndownDay};
+pub use crate::core::calendar::{Calendar, Entry, EntryKind};
+pub use crate::core::checklist::ChecklistItem;
 pub use crate::core::comet::Comet;
-pub use crate::core::galaxy::{CelestialBodyIndex, DatabaseError, Galaxy};
+pub use crate::core::commit::{mentions, Commit, Mention};
+pub use crate::core::compare::{ComparedBody, Comparison, FieldChange, ModifiedBody};
+pub use crate::core::demo::generate_demo;
+pub use crate::core::estimate::{Estimate, EstimateError, Rollup};
+pub use crate::core::event::{Change, Event};
+pub use crate::core::filter::{Filter, FilterError};
+pub use crate::core::galaxy::{
+    CelestialBodyIndex, DatabaseError, Format, Galaxy, GalaxyError, ParseIssue,
+};
+pub use crate::core::group::{Group, GroupBy};
+pub use crate::core::hooks::{set_dispatcher, Dispatcher, Notification, Trigger};
+pub use crate::core::link::Link;
+pub use crate::core::memory::MemoryUsage;
+pub use crate::core::merge::{Merge, MergeConflict, Resolution, Side};
+pub use crate::core::plan::DayPlan;
 pub use crate::core::planet::Planet;
+pub use crate::core::remind::Reminders;
+pub use crate::core::retro::Retro;
+pub use crate::core::rules::{set_prompter, Completion, Prompter, Rules};
+pub use crate::core::schema::schema;
+pub use crate::core::sort::Sort;
+pub use crate::core::sprint::Sprint;
 pub use crate::core::star::Star;
+pub use crate::core::stats::Stats;
+pub use crate::core::storage::Layout;
+pub use crate::core::summary::{BodyChanges, Snapshot, Summary};
+pub use crate::core::trash::TrashEntry;
+pub use crate::core::workflow::{Stage, Workflow};
+pub use crate::core::worklog::{WorkLog, WorkSession};
 use crate::util;
+use crate::util::{
+    glyphs,
+    theme::{self, Color},
+};
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -72,7 +139,8 @@ use crate::util;
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
-type ID = u64;
+/// The unique ID of a celestial body
+pub type ID = u64;
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -87,6 +155,9 @@ pub trait CelestialBody<'a>:
     /// Constructor that uses `id` for the new celestial body
     fn new(id: ID) -> Self;
 
+    /// Getter for celestial body's id
+    fn id(&self) -> ID;
+
     /// Setter for celestial body's parent
     fn parent(&mut self, parent: ID) -> &mut Self;
     /// Setter for celestial body's title
@@ -96,6 +167,8 @@ pub trait CelestialBody<'a>:
     /// Setter for celestial body's status. `commet` should be an explanation of
     /// why the status has changed
     fn status(&mut self, status: Status, comment: String) -> &mut Self;
+    /// Setter for celestial body's priority
+    fn priority(&mut self, priority: Priority) -> &mut Self;
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -105,7 +178,7 @@ pub trait CelestialBody<'a>:
 ////////////////////////////////////////////////////////////////////////////////
 
 /// Represents the different types of celestial bodies
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, cli::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, ValueEnum)]
 pub enum CelestialBodyKind {
     /// An interrupting task / bug
     Comet,
@@ -115,6 +188,102 @@ pub enum CelestialBodyKind {
     Star,
 }
 
+impl CelestialBodyKind {
+    /// The color of the kind in the current theme
+    pub fn color(&self) -> Color {
+        let theme = theme::current();
+        match self {
+            Self::Comet => theme.comet,
+            Self::Planet => theme.planet,
+            Self::Star => theme.star,
+        }
+    }
+}
+
+impl Display for CelestialBodyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Comet => write!(f, "Comet"),
+            Self::Planet => write!(f, "Planet"),
+            Self::Star => write!(f, "Star"),
+        }
+    }
+}
+
+/// How the IDs of celestial bodies are written when displayed and parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, ValueEnum)]
+pub enum IdFormat {
+    /// Base 10, e.g. `#42`
+    #[default]
+    Decimal,
+    /// Base 16, e.g. `#2a`
+    Hex,
+    /// Base 36, e.g. `#16`
+    Base36,
+}
+
+impl IdFormat {
+    /// Writes `id` in this format
+    pub fn format(&self, id: u64) -> String {
+        match self {
+            Self::Decimal => id.to_string(),
+            Self::Hex => format!("{id:x}"),
+            Self::Base36 => {
+                let mut id = id;
+                let mut digits = Vec::new();
+                loop {
+                    digits.push(char::from_digit((id % 36) as u32, 36).unwrap_or('0'));
+                    id /= 36;
+                    if id == 0 {
+                        break digits.iter().rev().collect();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parses an ID written in this format, ignoring case
+    pub fn parse(&self, s: &str) -> Option<u64> {
+        let radix = match self {
+            Self::Decimal => 10,
+            Self::Hex => 16,
+            Self::Base36 => 36,
+        };
+        u64::from_str_radix(s, radix).ok()
+    }
+}
+
+/// How the IDs of new celestial bodies are generated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, ValueEnum)]
+pub enum IdScheme {
+    /// One more than the previous ID, which is easy to type but results in
+    /// the same IDs being handed out on different branches
+    #[default]
+    Sequential,
+    /// Random IDs that are very unlikely to be handed out twice, displayed as
+    /// the shortest prefix that is unique within the `Galaxy`
+    Random,
+}
+
+impl Display for IdFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Decimal => write!(f, "Decimal"),
+            Self::Hex => write!(f, "Hex"),
+            Self::Base36 => write!(f, "Base36"),
+        }
+    }
+}
+
+impl Display for IdScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sequential => write!(f, "Sequential"),
+            Self::Random => write!(f, "Random"),
+        }
+    }
+}
+
 /// Represents the status of the `Planet` / `Comet` / `Star`
 ///
 /// The status should follow the pattern `Todo` -> `Next` -> `Start` -> `Done`.
@@ -122,7 +291,7 @@ pub enum CelestialBodyKind {
 ///
 /// Only `Done` and `Cancel` are considered to be final states. Parents cannot
 /// move to a final state unless all children are in a final state.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, ValueEnum)]
 pub enum Status {
     /// No work has been started, still in the "backlog"
     Todo,
@@ -140,6 +309,27 @@ pub enum Status {
     Cancel,
 }
 
+impl Status {
+    /// Whether or not the status is a final state (`Done` or `Cancel`)
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Done | Self::Cancel)
+    }
+
+    /// The color of the status in the current theme
+    pub fn color(&self) -> Color {
+        let theme = theme::current();
+        match self {
+            Self::Todo => theme.todo,
+            Self::Next => theme.next,
+            Self::Start => theme.start,
+            Self::Hold => theme.hold,
+            Self::Block => theme.block,
+            Self::Done => theme.done,
+            Self::Cancel => theme.cancel,
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Self {
         Self::Todo
@@ -162,14 +352,83 @@ impl Display for Status {
 
 impl From<Status> for colored::ColoredString {
     fn from(value: Status) -> Self {
+        let text = match value {
+            Status::Todo => "Todo ",
+            Status::Next => "Next ",
+            Status::Start => "Start ",
+            Status::Hold => "Hold ",
+            Status::Block => "Block ",
+            Status::Done => "Done  ",
+            Status::Cancel => "Cancel",
+        };
+        text.color(value.color())
+    }
+}
+
+/// Represents how important the `Planet` / `Comet` / `Star` is
+///
+/// Priorities are ordered from most to least important, so sorting by priority
+/// places `Critical` first and `None` last.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Deserialize,
+    Serialize,
+    ValueEnum,
+)]
+pub enum Priority {
+    /// Must be handled immediately
+    Critical,
+    /// Should be handled before anything else
+    High,
+    /// Should be handled soon
+    Medium,
+    /// Can be handled whenever there is time
+    Low,
+    /// No priority has been assigned
+    #[default]
+    None,
+}
+
+impl Priority {
+    /// The color of the priority in the current theme
+    pub fn color(&self) -> Color {
+        let theme = theme::current();
+        match self {
+            Self::Critical => theme.critical,
+            Self::High => theme.high,
+            Self::Medium => theme.medium,
+            Self::Low => theme.low,
+            Self::None => theme.muted,
+        }
+    }
+}
+
+impl Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Critical => write!(f, "Critical"),
+            Self::High => write!(f, "High"),
+            Self::Medium => write!(f, "Medium"),
+            Self::Low => write!(f, "Low"),
+            Self::None => write!(f, "None"),
+        }
+    }
+}
+
+impl From<Priority> for colored::ColoredString {
+    fn from(value: Priority) -> Self {
         match value {
-            Status::Todo => "Todo ".bright_yellow(),
-            Status::Next => "Next ".purple(),
-            Status::Start => "Start ".green(),
-            Status::Hold => "Hold ".bright_black(),
-            Status::Block => "Block ".red(),
-            Status::Done => "Done  ".bright_black(),
-            Status::Cancel => "Cancel".bright_black(),
+            Priority::Critical => value.to_string().color(value.color()).bold(),
+            Priority::None => "".into(),
+            _ => value.to_string().color(value.color()),
         }
     }
 }
@@ -180,11 +439,67 @@ impl From<Status> for colored::ColoredString {
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
+/// How many of the descendants of a celestial body are in a final state
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// Number of descendants that are `Done` or `Cancel`ed
+    pub finished: usize,
+    /// Total number of descendants
+    pub total: usize,
+}
+
+impl Progress {
+    /// The completion as a percentage from 0 to 100, `None` if there are no
+    /// descendants
+    pub fn percent(&self) -> Option<u8> {
+        match self.total {
+            0 => None,
+            total => Some((self.finished * 100 / total) as u8),
+        }
+    }
+
+    /// Draws the completion as a bar that is `width` characters wide
+    pub fn bar(&self, width: usize) -> String {
+        let filled = match self.total {
+            0 => 0,
+            total => self.finished * width / total,
+        };
+        let glyphs = glyphs::current();
+        format!(
+            "{}{}",
+            glyphs.bar_filled.repeat(filled),
+            glyphs.bar_empty.repeat(width - filled)
+        )
+    }
+}
+
 /// A single change to the celestial body's status that occurred in history
-#[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct StatusHistory {
     old: Status,
     new: Status,
     comment: String,
     time: DateTime<Utc>,
 }
+
+impl StatusHistory {
+    /// The status before the change
+    pub fn old_status(&self) -> Status {
+        self.old
+    }
+
+    /// The status after the change
+    pub fn new_status(&self) -> Status {
+        self.new
+    }
+
+    /// The explanation of why the status changed
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    /// When the change occurred
+    pub fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+}
@@ -0,0 +1,80 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the daily plan.
+ *
+ * Each day, a handful of celestial bodies can be picked to work on. The picked
+ * celestial bodies are kept per date, and anything that was not finished by
+ * the end of the day is carried over to the next day it is planned.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::ID;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The celestial bodies picked to work on during a single day
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DayPlan {
+    pub(super) date: NaiveDate,
+    /// The IDs of the picked celestial bodies, in the order they were picked
+    pub(super) items: Vec<ID>,
+    /// Whether the unfinished items have been carried over to a later day
+    #[serde(default)]
+    pub(super) carried_over: bool,
+}
+
+impl DayPlan {
+    /// Creates an empty plan for `date`
+    pub(super) fn new(date: NaiveDate) -> Self {
+        Self {
+            date,
+            ..Self::default()
+        }
+    }
+
+    /// The day the plan is for
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// The IDs of the picked celestial bodies, in the order they were picked
+    pub fn items(&self) -> &[ID] {
+        &self.items
+    }
+}
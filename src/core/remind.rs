@@ -0,0 +1,136 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the reminders about celestial bodies that are overdue or
+ * due soon. Due dates are taken from the `due` field (see
+ * `CelestialBodyRef::due`).
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::{Days, NaiveDate};
+
+use super::{Galaxy, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The unfinished celestial bodies that are due, each with its due date and
+/// sorted by it (and then by priority)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Reminders {
+    /// Due before today
+    pub overdue: Vec<(ID, NaiveDate)>,
+    /// Due today
+    pub today: Vec<(ID, NaiveDate)>,
+    /// Due within the number of days after today that was asked for
+    pub upcoming: Vec<(ID, NaiveDate)>,
+}
+
+impl Reminders {
+    /// Collects the reminders in `galaxy` on `today`, including celestial
+    /// bodies that are due within `days` days after it. Finished and archived
+    /// celestial bodies are left out.
+    pub fn of(galaxy: &Galaxy, today: NaiveDate, days: u64) -> Self {
+        let until = today.checked_add_days(Days::new(days)).unwrap_or(today);
+        let mut due: Vec<_> = galaxy
+            .due_until(until)
+            .into_iter()
+            .filter_map(|(due, id)| Some((due, galaxy.get(id)?)))
+            .filter(|(_, body)| !body.status().is_final() && !body.archived())
+            .map(|(due, body)| (due, body.priority(), body.id()))
+            .collect();
+        due.sort();
+
+        let mut reminders = Self::default();
+        for (due, _, id) in due {
+            let list = match due.cmp(&today) {
+                std::cmp::Ordering::Less => &mut reminders.overdue,
+                std::cmp::Ordering::Equal => &mut reminders.today,
+                std::cmp::Ordering::Greater => &mut reminders.upcoming,
+            };
+            list.push((id, due));
+        }
+        reminders
+    }
+
+    /// Whether nothing is due
+    pub fn is_empty(&self) -> bool {
+        self.overdue.is_empty() && self.today.is_empty() && self.upcoming.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, Priority, Status};
+
+    #[test]
+    fn bodies_are_grouped_by_due_date() {
+        let mut galaxy = Galaxy::default();
+        let mut due = |title: &str, date: &str| {
+            let id = galaxy.planet().title(title.into()).id();
+            galaxy.get_mut(id).unwrap().field("due".into(), date.into());
+            id
+        };
+        let late = due("Late", "2025-03-01");
+        let done = due("Done", "2025-02-01");
+        let today = due("Today", "2025-03-05");
+        let urgent = due("Urgent", "2025-03-05");
+        let soon = due("Soon", "2025-03-07");
+        due("Later", "2025-03-09");
+        galaxy.planet().title("No due date".into());
+        galaxy
+            .get_mut(done)
+            .unwrap()
+            .status(Status::Done, String::new());
+        galaxy.get_mut(urgent).unwrap().priority(Priority::Critical);
+
+        let date = |d: &str| d.parse::<NaiveDate>().unwrap();
+        let reminders = Reminders::of(&galaxy, date("2025-03-05"), 2);
+        assert_eq!(reminders.overdue, [(late, date("2025-03-01"))]);
+        assert_eq!(
+            reminders.today,
+            [(urgent, date("2025-03-05")), (today, date("2025-03-05"))]
+        );
+        assert_eq!(reminders.upcoming, [(soon, date("2025-03-07"))]);
+
+        assert!(Reminders::of(&galaxy, date("2025-01-01"), 0).is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing retrospectives. A retrospective is attached to a `Sprint`
+ * and records what went well, what needs improvement, and the action items
+ * that came out of it. Every action item is tracked as a `Comet`, which is
+ * added to the next sprint so that it is not forgotten.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Galaxy, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A retrospective of the work done in a `Sprint`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Retro {
+    /// The name of the `Sprint` the retrospective is attached to. Empty for
+    /// retrospectives from before schema version 27 that are still attached to
+    /// `star`, as no sprint contained it.
+    #[serde(default)]
+    pub(super) sprint: String,
+    /// The `Star` a retrospective from before schema version 27 is attached to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) star: Option<ID>,
+    pub(super) created: DateTime<Utc>,
+    pub(super) went_well: Vec<String>,
+    pub(super) needs_improvement: Vec<String>,
+    /// The IDs of the `Comet`s created for each action item
+    pub(super) action_items: Vec<ID>,
+}
+
+impl Retro {
+    /// Creates an empty retrospective for the `Sprint` `sprint`
+    pub(super) fn new(sprint: String) -> Self {
+        Self {
+            sprint,
+            created: Utc::now(),
+            ..Self::default()
+        }
+    }
+
+    /// The name of the `Sprint` the retrospective is attached to
+    pub fn sprint(&self) -> &str {
+        &self.sprint
+    }
+
+    /// When the retrospective was started
+    pub fn created(&self) -> DateTime<Utc> {
+        self.created
+    }
+
+    /// The notes on what went well
+    pub fn went_well(&self) -> &[String] {
+        &self.went_well
+    }
+
+    /// The notes on what needs improvement
+    pub fn needs_improvement(&self) -> &[String] {
+        &self.needs_improvement
+    }
+
+    /// The IDs of the `Comet`s tracking each action item
+    pub fn action_items(&self) -> &[ID] {
+        &self.action_items
+    }
+
+    /// Adds a note on something that went well
+    pub fn add_went_well(&mut self, note: String) -> &mut Self {
+        self.went_well.push(note);
+        self
+    }
+
+    /// Adds a note on something that needs improvement
+    pub fn add_needs_improvement(&mut self, note: String) -> &mut Self {
+        self.needs_improvement.push(note);
+        self
+    }
+
+    /// Exports the retrospective as Markdown, suitable for a wiki page. Action
+    /// items are rendered as a checklist using the current status of their
+    /// `Comet`s.
+    pub fn to_markdown(&self, galaxy: &Galaxy) -> String {
+        let mut md = String::new();
+
+        // Writing to a `String` cannot fail
+        let _ = writeln!(md, "# Retrospective: {}", self.sprint);
+        let _ = writeln!(md);
+        match galaxy.sprint(&self.sprint) {
+            Some(sprint) => {
+                let _ = writeln!(md, "_{} to {}_", sprint.start(), sprint.end());
+            }
+            None => {
+                let _ = writeln!(md, "_{}_", self.created.format("%Y-%m-%d"));
+            }
+        }
+
+        for (heading, notes) in [
+            ("What went well", &self.went_well),
+            ("What needs improvement", &self.needs_improvement),
+        ] {
+            let _ = writeln!(md);
+            let _ = writeln!(md, "## {heading}");
+            let _ = writeln!(md);
+            if notes.is_empty() {
+                let _ = writeln!(md, "_Nothing_");
+            }
+            for note in notes {
+                let _ = writeln!(md, "- {note}");
+            }
+        }
+
+        let _ = writeln!(md);
+        let _ = writeln!(md, "## Action items");
+        let _ = writeln!(md);
+        if self.action_items.is_empty() {
+            let _ = writeln!(md, "_Nothing_");
+        }
+        for item in self.action_items.iter().filter_map(|id| galaxy.get(*id)) {
+            let check = if item.status().is_final() { "x" } else { " " };
+            let _ = writeln!(
+                md,
+                "- [{check}] {} ({})",
+                item.title(),
+                galaxy.display_id(item.id())
+            );
+        }
+
+        md
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use chrono::{Days, NaiveDate};
+
+    use crate::core::{CelestialBody, Status};
+
+    use super::*;
+
+    #[test]
+    fn markdown_export_includes_all_sections() {
+        let mut galaxy = Galaxy::default();
+        let start = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        galaxy.sprint_create("Sprint 1".into(), start, end).unwrap();
+        let next = end + Days::new(3);
+        galaxy
+            .sprint_create("Sprint 2".into(), next, next + Days::new(11))
+            .unwrap();
+        galaxy
+            .retro("sprint 1")
+            .unwrap()
+            .add_went_well("Shipped login".into())
+            .add_needs_improvement("Too many meetings".into());
+        let item = galaxy
+            .retro_action_item("Sprint 1", "Cancel standup".into())
+            .unwrap();
+        galaxy.comets[0].status(Status::Done, String::new());
+        // The action item is worked on in the next sprint
+        assert_eq!(galaxy.sprint("Sprint 2").unwrap().items(), [item]);
+
+        let md = galaxy.get_retro("Sprint 1").unwrap().to_markdown(&galaxy);
+        assert!(md.starts_with("# Retrospective: Sprint 1\n\n_2025-03-03 to 2025-03-14_\n"));
+        assert!(md.contains("## What went well\n\n- Shipped login\n"));
+        assert!(md.contains("## What needs improvement\n\n- Too many meetings\n"));
+        assert!(md.contains(&format!("- [x] Cancel standup (#{item})\n")));
+    }
+}
@@ -0,0 +1,302 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the rules that change the status of a `Star` when the
+ * status of its children changes, e.g. finishing a `Star` once all of its
+ * children are finished. The rules are stored with each `Galaxy`, so that
+ * they can be turned off for some projects and not others, and are run
+ * whenever the `Galaxy` is saved.
+ *
+ * Stars that are only finished once confirmed are handed to the `Prompter`
+ * set with `set_prompter`, and are left alone if there is none.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{fmt, sync::OnceLock};
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::{CelestialBodyKind, CelestialBodyRef, Galaxy, Status, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+static PROMPTER: OnceLock<Box<dyn Prompter>> = OnceLock::new();
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TRAITS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Asks whether a `Star` whose children are all finished should be finished
+/// as well
+pub trait Prompter: Send + Sync {
+    fn complete(&self, star: &CelestialBodyRef) -> bool;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// What is done with a `Star` once all of its children are finished
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Completion {
+    /// The `Star` is left alone
+    Off,
+    /// The `Star` is moved to `Done` once confirmed
+    #[default]
+    Prompt,
+    /// The `Star` is moved to `Done` right away
+    Auto,
+}
+
+impl fmt::Display for Completion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::Prompt => write!(f, "prompt"),
+            Self::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The status rules of a `Galaxy`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Rules {
+    /// Whether any of the rules are run. They are off until turned on, so
+    /// that existing Galaxies are not changed by them.
+    pub enabled: bool,
+    /// What is done with a `Star` once all of its children are `Done` or
+    /// `Cancel`
+    pub complete_stars: Completion,
+    /// Whether a `Star` in `Todo` is moved to `Start` once one of its children
+    /// is started
+    pub start_parents: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            complete_stars: Completion::default(),
+            start_parents: true,
+        }
+    }
+}
+
+impl Rules {
+    /// Whether these are the default rules, which are not saved
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Runs the rules on the status changes made in `galaxy` since `since`,
+    /// including those made by the rules themselves, so that finishing the
+    /// last child of a `Star` can also finish its parent. Stars that are only
+    /// finished once confirmed are passed to `confirm`.
+    ///
+    /// # Returns
+    /// The IDs of the Stars that were not confirmed
+    pub fn apply(
+        &self,
+        galaxy: &mut Galaxy,
+        since: DateTime<Utc>,
+        confirm: &mut dyn FnMut(&CelestialBodyRef) -> bool,
+    ) -> Vec<ID> {
+        let mut declined = Vec::new();
+        if !self.enabled {
+            return declined;
+        }
+
+        loop {
+            // The parents of the celestial bodies whose status changed, and
+            // whether one of them was started
+            let mut parents: Vec<(ID, bool)> = Vec::new();
+            for body in galaxy.iter() {
+                let Some(change) = body.history().iter().rev().find(|h| h.time() >= since) else {
+                    continue;
+                };
+                let Some(parent) = body.parent() else {
+                    continue;
+                };
+                let started =
+                    change.new_status() == Status::Start && body.status() == Status::Start;
+                match parents.iter_mut().find(|(id, _)| *id == parent) {
+                    Some((_, any)) => *any |= started,
+                    None => parents.push((parent, started)),
+                }
+            }
+
+            let mut changed = false;
+            for (parent, started) in parents {
+                let Some(star) = galaxy.get(parent) else {
+                    continue;
+                };
+                if star.kind() != CelestialBodyKind::Star || declined.contains(&parent) {
+                    continue;
+                }
+                let finished = !star.children().is_empty()
+                    && star
+                        .children()
+                        .iter()
+                        .filter_map(|id| galaxy.get(*id))
+                        .all(|child| child.status().is_final());
+
+                let status = if finished && !star.status().is_final() {
+                    let confirmed = match self.complete_stars {
+                        Completion::Off => false,
+                        Completion::Prompt => confirm(&star),
+                        Completion::Auto => true,
+                    };
+                    if !confirmed && self.complete_stars == Completion::Prompt {
+                        declined.push(parent);
+                    }
+                    confirmed.then_some((Status::Done, "All children are finished"))
+                } else if started && self.start_parents && star.status() == Status::Todo {
+                    Some((Status::Start, "A child was started"))
+                } else {
+                    None
+                };
+
+                if let Some((status, comment)) = status
+                    && let Some(mut star) = galaxy.get_mut(parent)
+                {
+                    star.status(status, comment.into());
+                    changed = true;
+                }
+            }
+            if !changed {
+                break declined;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Sets who is asked before a `Star` is finished. Can only be set once, later
+/// calls are ignored. Stars are not finished if it is never set.
+pub fn set_prompter(prompter: Box<dyn Prompter>) {
+    let _ = PROMPTER.set(prompter);
+}
+
+/// Asks the prompter whether `star` should be finished, `false` if there is
+/// none
+pub(super) fn confirm(star: &CelestialBodyRef) -> bool {
+    PROMPTER
+        .get()
+        .is_some_and(|prompter| prompter.complete(star))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::CelestialBody;
+
+    /// Changes the status of `id`, returning when it was changed
+    fn set(galaxy: &mut Galaxy, id: ID, status: Status) -> DateTime<Utc> {
+        let since = Utc::now();
+        galaxy.get_mut(id).unwrap().status(status, String::new());
+        since
+    }
+
+    #[test]
+    fn status_changes_propagate_to_parent_stars() {
+        let mut galaxy = Galaxy::default();
+        let root = galaxy.star().title("Root".into()).id();
+        let star = galaxy.star().title("Star".into()).id();
+        let first = galaxy.planet().title("First".into()).id();
+        let second = galaxy.planet().title("Second".into()).id();
+        galaxy.set_parent(star, Some(root)).unwrap();
+        galaxy.set_parent(first, Some(star)).unwrap();
+        galaxy.set_parent(second, Some(star)).unwrap();
+        let status = |galaxy: &Galaxy, id| galaxy.get(id).unwrap().status();
+        let mut rules = Rules {
+            enabled: true,
+            ..Rules::default()
+        };
+
+        // Starting a child starts every ancestor still in `Todo`
+        let since = set(&mut galaxy, first, Status::Start);
+        assert!(rules.apply(&mut galaxy, since, &mut |_| false).is_empty());
+        assert_eq!(status(&galaxy, star), Status::Start);
+        assert_eq!(status(&galaxy, root), Status::Start);
+
+        // Stars are only finished once confirmed, and only once all of their
+        // children are finished
+        set(&mut galaxy, first, Status::Done);
+        let since = set(&mut galaxy, second, Status::Cancel);
+        let mut asked = Vec::new();
+        let declined = rules.apply(&mut galaxy, since, &mut |star| {
+            asked.push(star.id());
+            false
+        });
+        assert_eq!((asked, declined), (vec![star], vec![star]));
+        assert_eq!(status(&galaxy, star), Status::Start);
+
+        // Finishing the only child of the root finishes the root as well
+        rules.complete_stars = Completion::Auto;
+        assert!(rules.apply(&mut galaxy, since, &mut |_| false).is_empty());
+        assert_eq!(status(&galaxy, star), Status::Done);
+        assert_eq!(status(&galaxy, root), Status::Done);
+
+        // Nothing changes once the rules are turned off, as they are by default
+        let since = set(&mut galaxy, first, Status::Start);
+        Rules::default().apply(&mut galaxy, since, &mut |_| true);
+        assert_eq!(status(&galaxy, star), Status::Done);
+    }
+}
@@ -0,0 +1,481 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the JSON Schema of the database, so that tools and
+ * scripts that read or write the database directly know what it looks like.
+ *
+ * The schema describes the current `SCHEMA_VERSION` of the database, but
+ * accepts any version that can still be loaded. Its
+ * `$defs` also describe each kind of celestial body on its own, as they are
+ * stored in the files of a `.planit` directory. Only the parts of JSON Schema
+ * that the schema uses are checked by `validate`: `$ref`, `type`, `enum`,
+ * `const`, `format` (`date` and `date-time`), `minimum`, `maximum`, `properties`,
+ * `required`, `additionalProperties`, `minProperties`, `maxProperties`, and
+ * `items`.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::{DateTime, NaiveDate};
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+
+use super::{galaxy::Database, Completion, IdFormat, IdScheme, ParseIssue, Priority, Status};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The JSON Schema of the current version of the database
+pub fn schema() -> Value {
+    let id = json!({ "type": "integer", "minimum": 0 });
+    let ids = json!({ "type": "array", "items": { "$ref": "#/$defs/id" } });
+    let strings = json!({ "type": "array", "items": { "type": "string" } });
+    let time = json!({ "type": "string", "format": "date-time" });
+    let date = json!({ "type": "string", "format": "date" });
+    let estimate = json!({ "$ref": "#/$defs/estimate" });
+    let list =
+        |def: &str| json!({ "type": "array", "items": { "$ref": format!("#/$defs/{def}") } });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "planit database",
+        "description": format!("Version {} of the planit database", Database::SCHEMA_VERSION),
+        "type": "object",
+        "properties": {
+            "version": {
+                "type": "integer",
+                "minimum": Database::OLDEST_SCHEMA_VERSION,
+                "maximum": Database::SCHEMA_VERSION,
+            },
+            "comment": { "type": "string" },
+            "title": { "type": "string" },
+            "description": { "type": "string" },
+            "next_id": id,
+            "key_prefix": { "type": ["string", "null"] },
+            "last_key": id,
+            "id_format": variants(IdFormat::value_variants()),
+            "id_scheme": variants(IdScheme::value_variants()),
+            "comets": list("comet"),
+            "planets": list("planet"),
+            "stars": list("star"),
+            "retros": list("retro"),
+            "trash": list("trash_entry"),
+            "plans": list("plan"),
+            "sprints": list("sprint"),
+            "rules": object(
+                json!({
+                    "enabled": { "type": "boolean" },
+                    "complete_stars": variants(Completion::value_variants()),
+                    "start_parents": { "type": "boolean" },
+                }),
+                &[],
+            ),
+            "workflow": list("stage"),
+        },
+        "required": [
+            "version", "comment", "title", "description", "next_id", "comets", "planets", "stars"
+        ],
+        "additionalProperties": false,
+        "$defs": {
+            "id": id,
+            "comet": body(&[("estimate", estimate.clone()), ("checklist", list("checklist_item"))]),
+            "planet": body(&[
+                ("estimate", estimate),
+                ("checklist", list("checklist_item")),
+                ("tags", strings.clone()),
+                ("fields", json!({ "type": "object", "additionalProperties": { "type": "string" } })),
+            ]),
+            "star": body(&[("id_prefix", json!({ "type": ["string", "null"] })), ("children", ids.clone())]),
+            "status": variants(Status::value_variants()),
+            "priority": variants(Priority::value_variants()),
+            "estimate": {
+                "type": ["object", "null"],
+                "properties": { "Points": id, "Minutes": id },
+                "additionalProperties": false,
+                "minProperties": 1,
+                "maxProperties": 1,
+            },
+            "status_change": object(
+                json!({
+                    "old": { "$ref": "#/$defs/status" },
+                    "new": { "$ref": "#/$defs/status" },
+                    "comment": { "type": "string" },
+                    "time": time,
+                }),
+                &["old", "new", "comment", "time"],
+            ),
+            "work_session": object(
+                json!({ "start": time, "end": { "type": ["string", "null"], "format": "date-time" } }),
+                &["start"],
+            ),
+            // Changes are only described as far as they are needed to find
+            // them, since there are many kinds of them
+            "event": object(json!({ "time": time, "change": {} }), &["time", "change"]),
+            "link": object(
+                json!({ "label": { "type": "string" }, "target": { "type": "string" } }),
+                &["label", "target"],
+            ),
+            "commit": object(
+                json!({ "hash": { "type": "string" }, "summary": { "type": "string" }, "time": time }),
+                &["hash", "summary", "time"],
+            ),
+            "blocker": object(
+                json!({
+                    "reason": { "type": "string" },
+                    "by": { "type": ["integer", "null"], "minimum": 0 },
+                    "reference": { "type": ["string", "null"] },
+                }),
+                &["reason"],
+            ),
+            "checklist_item": object(
+                json!({ "text": { "type": "string" }, "done": { "type": "boolean" } }),
+                &["text"],
+            ),
+            "retro": object(
+                json!({
+                    "sprint": { "type": "string" },
+                    "star": id,
+                    "created": time,
+                    "went_well": strings,
+                    "needs_improvement": strings,
+                    "action_items": ids,
+                }),
+                &["created", "went_well", "needs_improvement", "action_items"],
+            ),
+            "trash_entry": object(
+                json!({
+                    "id": id,
+                    "deleted": time,
+                    "comets": list("comet"),
+                    "planets": list("planet"),
+                    "stars": list("star"),
+                }),
+                &["id", "deleted", "comets", "planets", "stars"],
+            ),
+            "plan": object(
+                json!({ "date": date, "items": ids, "carried_over": { "type": "boolean" } }),
+                &["date", "items"],
+            ),
+            "sprint": object(
+                json!({
+                    "name": { "type": "string" },
+                    "start": date,
+                    "end": date,
+                    "items": ids,
+                    "closed": { "type": "boolean" },
+                }),
+                &["name", "start", "end", "items"],
+            ),
+            "stage": object(
+                json!({
+                    "name": { "type": "string" },
+                    "category": { "$ref": "#/$defs/status" },
+                    "color": { "type": ["string", "null"] },
+                    "next": strings,
+                }),
+                &["name", "category"],
+            ),
+        },
+    })
+}
+
+/// Checks `value` against the schema, or against its definition `def` (e.g.
+/// `planet`) if given
+///
+/// # Returns
+/// Everything that does not match the schema, with the paths of the values
+/// (e.g. `planets[3].status`). Empty if `value` matches it.
+pub fn validate(value: &Value, def: Option<&str>) -> Vec<ParseIssue> {
+    let schema = schema();
+    let mut issues = Vec::new();
+    match def {
+        Some(def) => check(value, &schema["$defs"][def], &schema, "", &mut issues),
+        None => check(value, &schema, &schema, "", &mut issues),
+    }
+    issues
+}
+
+/// Helper function that describes the properties a celestial body has, along
+/// with the ones of its kind in `extra`
+fn body(extra: &[(&str, Value)]) -> Value {
+    let def = |name: &str| json!({ "$ref": format!("#/$defs/{name}") });
+    let list = |name: &str| json!({ "type": "array", "items": def(name) });
+    let mut properties = json!({
+        "id": def("id"),
+        "key": { "type": ["string", "null"] },
+        "parent": { "type": ["integer", "null"], "minimum": 0 },
+        "title": { "type": "string" },
+        "description": { "type": "string" },
+        "status": def("status"),
+        "priority": def("priority"),
+        "archived": { "type": "boolean" },
+        "pinned": { "type": "boolean" },
+        "snoozed_until": { "type": ["string", "null"], "format": "date" },
+        "rank": { "type": ["integer", "null"], "minimum": 0 },
+        "history": list("status_change"),
+        "work_log": list("work_session"),
+        "events": list("event"),
+        "updated_at": { "type": ["string", "null"], "format": "date-time" },
+        "assignees": { "type": "array", "items": { "type": "string" } },
+        "links": list("link"),
+        "commits": list("commit"),
+        "blocker": { "anyOf": [{ "type": "null" }, def("blocker")] },
+        "stage": { "type": ["string", "null"] },
+    });
+    let mut required = vec!["id", "title", "description", "status", "history"];
+    for (name, schema) in extra {
+        properties[name] = schema.clone();
+        if matches!(*name, "tags" | "fields" | "children") {
+            required.push(name);
+        }
+    }
+    object(properties, &required)
+}
+
+/// Helper function that describes an object with exactly `properties`, of
+/// which the ones in `required` have to be given
+fn object(properties: Value, required: &[&str]) -> Value {
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Helper function that describes a value that is one of `variants`, as they
+/// are written to the database
+fn variants<T: serde::Serialize>(variants: &[T]) -> Value {
+    json!({ "enum": variants })
+}
+
+/// Helper function that names the type of `value` as JSON Schema does
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_u64() || n.is_i64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Helper function that checks `value` at `path` against `schema`, adding
+/// everything that does not match to `issues`. `root` is the whole schema,
+/// which `$ref`s point into.
+fn check(value: &Value, schema: &Value, root: &Value, path: &str, issues: &mut Vec<ParseIssue>) {
+    let mut issue = |message: String| {
+        issues.push(ParseIssue {
+            path: if path.is_empty() {
+                ".".into()
+            } else {
+                path.into()
+            },
+            value: Some(value.clone()).filter(|v| !v.is_object() && !v.is_array()),
+            message,
+        })
+    };
+
+    if let Some(reference) = schema["$ref"].as_str() {
+        let def = reference.trim_start_matches("#/$defs/");
+        return check(value, &root["$defs"][def], root, path, issues);
+    }
+    if let Some(options) = schema["anyOf"].as_array() {
+        let matches = |option| {
+            let mut found = Vec::new();
+            check(value, option, root, path, &mut found);
+            found
+        };
+        // The issues of the closest option are the most helpful ones
+        let found = options.iter().map(matches).min_by_key(Vec::len);
+        issues.extend(found.into_iter().flatten());
+        return;
+    }
+
+    let found = type_of(value);
+    let types: Vec<_> = match &schema["type"] {
+        Value::String(t) => vec![t.as_str()],
+        Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    let allowed = |t: &&str| *t == found || (*t == "number" && found == "integer");
+    if !types.is_empty() && !types.iter().any(allowed) {
+        return issue(format!("expected {}, found {found}", types.join(" or ")));
+    }
+    if let Some(expected) = schema.get("const").filter(|c| *c != value) {
+        return issue(format!("expected {expected}"));
+    }
+    if let Some(variants) = schema["enum"].as_array().filter(|v| !v.contains(value)) {
+        let variants: Vec<_> = variants.iter().map(Value::to_string).collect();
+        return issue(format!("expected one of {}", variants.join(", ")));
+    }
+    if let (Some(minimum), Some(number)) = (schema["minimum"].as_f64(), value.as_f64())
+        && number < minimum
+    {
+        return issue(format!("expected at least {minimum}"));
+    }
+    if let (Some(maximum), Some(number)) = (schema["maximum"].as_f64(), value.as_f64())
+        && number > maximum
+    {
+        return issue(format!("expected at most {maximum}"));
+    }
+    if let (Some(format), Some(text)) = (schema["format"].as_str(), value.as_str()) {
+        let valid = match format {
+            "date" => NaiveDate::parse_from_str(text, "%Y-%m-%d").is_ok(),
+            "date-time" => DateTime::parse_from_rfc3339(text).is_ok(),
+            _ => true,
+        };
+        if !valid {
+            return issue(format!("expected a {format}"));
+        }
+    }
+
+    match value {
+        Value::Object(object) => check_object(object, schema, root, path, issues),
+        Value::Array(items) => {
+            let Some(items_schema) = schema.get("items") else {
+                return;
+            };
+            for (index, item) in items.iter().enumerate() {
+                let path = format!("{path}[{index}]");
+                check(item, items_schema, root, &path, issues);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Helper function that checks the properties of `object` at `path`, see
+/// `check`
+fn check_object(
+    object: &Map<String, Value>,
+    schema: &Value,
+    root: &Value,
+    path: &str,
+    issues: &mut Vec<ParseIssue>,
+) {
+    let at = |key: &str| match path {
+        "" => key.to_string(),
+        path => format!("{path}.{key}"),
+    };
+    let count = object.len() as u64;
+    let too_few = schema["minProperties"]
+        .as_u64()
+        .is_some_and(|min| count < min);
+    let too_many = schema["maxProperties"]
+        .as_u64()
+        .is_some_and(|max| count > max);
+    if too_few || too_many {
+        issues.push(ParseIssue {
+            path: if path.is_empty() {
+                ".".into()
+            } else {
+                path.into()
+            },
+            value: None,
+            message: format!("unexpected number of keys ({count})"),
+        });
+    }
+    for key in schema["required"].as_array().into_iter().flatten() {
+        let Some(key) = key.as_str().filter(|key| !object.contains_key(*key)) else {
+            continue;
+        };
+        issues.push(ParseIssue {
+            path: at(key),
+            value: None,
+            message: "missing".into(),
+        });
+    }
+    for (key, value) in object {
+        match (
+            schema["properties"].get(key),
+            &schema["additionalProperties"],
+        ) {
+            (Some(property), _) => check(value, property, root, &at(key), issues),
+            (None, Value::Bool(false)) => issues.push(ParseIssue {
+                path: at(key),
+                value: None,
+                message: "unknown key, it is dropped when the database is saved".into(),
+            }),
+            (None, additional @ Value::Object(_)) => {
+                check(value, additional, root, &at(key), issues)
+            }
+            (None, _) => {}
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use crate::core::{generate_demo, Galaxy};
+
+    use super::*;
+
+    #[test]
+    fn saved_databases_match_the_schema() {
+        let mut galaxy = Galaxy::default();
+        generate_demo(&mut galaxy, 100, 7);
+        let mut bytes = Vec::new();
+        galaxy.save_to_writer(&mut bytes).unwrap();
+        let mut value: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(validate(&value, None), []);
+        assert_eq!(validate(&value["planets"][0], Some("planet")), []);
+
+        value["planets"][0]["status"] = "Finished".into();
+        value["planets"][0]["owner"] = "jacob".into();
+        value["stars"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("children");
+        value["version"] = (Database::SCHEMA_VERSION + 1).into();
+        let mut paths: Vec<_> = validate(&value, None).into_iter().map(|i| i.path).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "planets[0].owner",
+                "planets[0].status",
+                "stars[0].children",
+                "version"
+            ]
+        );
+    }
+}
@@ -0,0 +1,154 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the orders celestial bodies can be listed in, e.g. by
+ * saved views.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{cmp::Ordering, fmt};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::{CelestialBodyRef, Galaxy, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The order celestial bodies are listed in. Celestial bodies that are equal
+/// in that order are listed by rank (see `Galaxy::place`), then by ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Sort {
+    /// Most important first
+    #[default]
+    Priority,
+    /// Oldest first
+    Id,
+    /// Alphabetically, ignoring case
+    Title,
+    /// In the order of the workflow, from `Todo` to `Cancel`
+    Status,
+    /// Earliest due date first, celestial bodies without one last
+    Due,
+    /// Most recently changed first
+    Updated,
+    /// In the order siblings were arranged in, see `Galaxy::place`
+    Rank,
+}
+
+impl Sort {
+    /// Compares `a` and `b` in this order
+    pub fn compare(self, a: &CelestialBodyRef, b: &CelestialBodyRef) -> Ordering {
+        let ordering = match self {
+            Self::Priority => a.priority().cmp(&b.priority()),
+            Self::Id => Ordering::Equal,
+            Self::Title => a.title().to_lowercase().cmp(&b.title().to_lowercase()),
+            Self::Status => a.status().cmp(&b.status()),
+            Self::Due => match (a.due(), b.due()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (a, b) => a.is_none().cmp(&b.is_none()),
+            },
+            Self::Updated => b.updated_at().cmp(&a.updated_at()),
+            Self::Rank => Ordering::Equal,
+        };
+        // Unranked celestial bodies come last
+        let rank = |body: &CelestialBodyRef| (body.rank().is_none(), body.rank());
+        ordering
+            .then_with(|| rank(a).cmp(&rank(b)))
+            .then_with(|| a.id().cmp(&b.id()))
+    }
+
+    /// Sorts `ids` in this order. IDs that are not in `galaxy` are put last.
+    pub fn sort(self, galaxy: &Galaxy, ids: &mut [ID]) {
+        ids.sort_by(|x, y| match (galaxy.get(*x), galaxy.get(*y)) {
+            (Some(a), Some(b)) => self.compare(&a, &b),
+            (a, b) => a.is_none().cmp(&b.is_none()).then(x.cmp(y)),
+        });
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Priority => write!(f, "priority"),
+            Self::Id => write!(f, "id"),
+            Self::Title => write!(f, "title"),
+            Self::Status => write!(f, "status"),
+            Self::Due => write!(f, "due"),
+            Self::Updated => write!(f, "updated"),
+            Self::Rank => write!(f, "rank"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, Priority};
+
+    #[test]
+    fn bodies_are_sorted_with_ids_breaking_ties() {
+        let mut galaxy = Galaxy::default();
+        let b = galaxy.planet().title("b".into()).id();
+        let a = galaxy.planet().title("A".into()).id();
+        let c = galaxy.planet().title("c".into()).id();
+        galaxy.get_mut(c).unwrap().priority(Priority::High);
+        galaxy
+            .get_mut(c)
+            .unwrap()
+            .field("due".into(), "2025-03-01".into());
+        galaxy
+            .get_mut(b)
+            .unwrap()
+            .field("due".into(), "2025-04-01".into());
+
+        let sorted = |sort: Sort| {
+            let mut ids = vec![c, 42, a, b];
+            sort.sort(&galaxy, &mut ids);
+            ids
+        };
+        assert_eq!(sorted(Sort::Id), [b, a, c, 42]);
+        assert_eq!(sorted(Sort::Title), [a, b, c, 42]);
+        assert_eq!(sorted(Sort::Priority), [c, b, a, 42]);
+        assert_eq!(sorted(Sort::Due), [c, b, a, 42]);
+    }
+}
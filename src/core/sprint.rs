@@ -0,0 +1,108 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing sprints.
+ *
+ * A sprint is a named period of time (e.g. two weeks) with the celestial
+ * bodies that are meant to be finished during it. When a sprint is closed,
+ * its unfinished celestial bodies are carried over to the next sprint.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::ID;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A named period of time and the celestial bodies to finish during it
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Sprint {
+    pub(super) name: String,
+    /// The first day of the sprint
+    pub(super) start: NaiveDate,
+    /// The last day of the sprint
+    pub(super) end: NaiveDate,
+    /// The IDs of the celestial bodies in the sprint, in the order they were
+    /// added
+    pub(super) items: Vec<ID>,
+    /// Whether the sprint is over and its unfinished items were carried over
+    #[serde(default)]
+    pub(super) closed: bool,
+}
+
+impl Sprint {
+    /// Creates an empty sprint
+    pub(super) fn new(name: String, start: NaiveDate, end: NaiveDate) -> Self {
+        Self {
+            name,
+            start,
+            end,
+            items: Vec::new(),
+            closed: false,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The first day of the sprint
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// The last day of the sprint
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// The IDs of the celestial bodies in the sprint, in the order they were
+    /// added
+    pub fn items(&self) -> &[ID] {
+        &self.items
+    }
+
+    /// Whether the sprint was closed
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Whether the sprint is open and `date` is one of its days
+    pub fn is_active(&self, date: NaiveDate) -> bool {
+        !self.closed && (self.start..=self.end).contains(&date)
+    }
+}
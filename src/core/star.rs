@@ -33,14 +33,17 @@
 //                                                                            //
 ////////////////////////////////////////////////////////////////////////////////
 
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
 use colored::Colorize;
 use log::info;
 use serde::{Deserialize, Serialize};
 
-use crate::util;
+use crate::util::{self, glyphs, theme::Themed};
 
-use super::{CelestialBody, Galaxy, Status, StatusHistory, ID};
+use super::{
+    Blocker, CelestialBody, CelestialBodyKind, CelestialBodyRef, Commit, Event, Galaxy, Link,
+    Priority, Status, StatusHistory, WorkLog, ID,
+};
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -51,14 +54,65 @@ use super::{CelestialBody, Galaxy, Status, StatusHistory, ID};
 /// Stars are collections of other celestial bodies. They can contain Planets,
 /// Comets, and even other Stars. They are meant to be used to separate elements
 /// into organized groups.
-#[derive(Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Star {
     pub(super) id: ID,
+    /// The short key (e.g. `PLAN-42`), `None` if the `Galaxy` has no key
+    /// prefix
+    #[serde(default)]
+    pub(super) key: Option<String>,
+    /// Prefix used instead of `#` when displaying the IDs of the Star and its
+    /// descendants (e.g. `WEB-`)
+    #[serde(default)]
+    pub(super) id_prefix: Option<String>,
     pub(super) parent: Option<ID>,
     pub(super) title: String,
     pub(super) description: String,
     pub(super) status: Status,
+    #[serde(default)]
+    pub(super) priority: Priority,
+    /// Archived celestial bodies are hidden from views but otherwise kept
+    #[serde(default)]
+    pub(super) archived: bool,
+    /// Pinned celestial bodies are part of the focus view until they are
+    /// finished or unpinned
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(super) pinned: bool,
+    /// Snoozed celestial bodies are hidden from views until this date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) snoozed_until: Option<NaiveDate>,
+    /// The place among its siblings, see `Galaxy::place`. Unranked
+    /// celestial bodies come after ranked ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) rank: Option<u32>,
     pub(super) history: Vec<StatusHistory>,
+    /// Time spent working on the celestial body
+    #[serde(default)]
+    pub(super) work_log: WorkLog,
+    /// All changes other than status changes, oldest first
+    #[serde(default)]
+    pub(super) events: Vec<Event>,
+    /// When the celestial body was last changed, `None` for celestial bodies
+    /// that have not been changed since these stamps were introduced
+    #[serde(default)]
+    pub(super) updated_at: Option<DateTime<Utc>>,
+    /// The names of the people working on the celestial body
+    #[serde(default)]
+    pub(super) assignees: Vec<String>,
+    /// Links to pull requests, documents, designs, etc.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(super) links: Vec<Link>,
+    /// The git commits referencing the celestial body, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(super) commits: Vec<Commit>,
+    /// Why the celestial body is blocked or on hold, `None` for any other
+    /// status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) blocker: Option<Blocker>,
+    /// The status of the workflow of the `Galaxy` that the celestial body is
+    /// in, `None` if it is just `status`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) stage: Option<String>,
 
     /// Contains the ids of all the celestial bodies that are directly owned by
     /// this star
@@ -73,6 +127,10 @@ impl CelestialBody<'_> for Star {
         }
     }
 
+    fn id(&self) -> ID {
+        self.id
+    }
+
     fn parent(&mut self, parent: ID) -> &mut Self {
         self.parent = Some(parent);
         self
@@ -102,35 +160,90 @@ impl CelestialBody<'_> for Star {
         self.status = status;
         self
     }
+
+    fn priority(&mut self, priority: Priority) -> &mut Self {
+        info!(
+            "Star ({}) changed priority from {} to {}",
+            self.id, self.priority, priority
+        );
+        self.priority = priority;
+        self
+    }
 }
 
 impl util::tree::PrintTreeNode<Galaxy> for Star {
     fn icon(&self) -> colored::ColoredString {
-        "".yellow()
+        glyphs::current()
+            .star
+            .color(CelestialBodyKind::Star.color())
     }
 
     fn label(&self) -> colored::ColoredString {
-        "[STAR]  ".yellow() // Added spaces line it up with planet
+        "[STAR]  ".color(CelestialBodyKind::Star.color()) // Added spaces line it up with planet
+    }
+
+    fn status(&self, galaxy: &Galaxy) -> colored::ColoredString {
+        galaxy.status_label(&CelestialBodyRef::Star(self))
+    }
+
+    fn reference(&self, galaxy: &Galaxy) -> colored::ColoredString {
+        galaxy.id_label(&CelestialBodyRef::Star(self))
+    }
+
+    fn path(&self, galaxy: &Galaxy) -> colored::ColoredString {
+        galaxy.breadcrumb(self.id).muted()
     }
 
-    fn status(&self) -> colored::ColoredString {
-        self.status.into()
+    fn priority(&self) -> colored::ColoredString {
+        self.priority.into()
+    }
+
+    fn progress(&self, root: &Galaxy) -> colored::ColoredString {
+        let percent = root.progress(self.id).and_then(|p| p.percent());
+        let rollup = root.rollup(self.id).unwrap_or_default();
+        match (percent, rollup.is_empty()) {
+            (None, true) => "".into(),
+            (None, false) => rollup.to_string().highlight(),
+            (Some(percent), true) => format!("{percent:>3}%").highlight(),
+            (Some(percent), false) => format!("{percent:>3}% {rollup}").highlight(),
+        }
     }
 
     fn title(&self) -> colored::ColoredString {
         colored::ColoredString::from(self.title.clone())
     }
 
+    fn assignees(&self) -> colored::ColoredString {
+        let names: Vec<_> = self
+            .assignees
+            .iter()
+            .map(|name| format!("@{name}"))
+            .collect();
+        names.join(" ").muted()
+    }
+
+    fn pinned(&self) -> bool {
+        self.pinned
+    }
+
+    fn note(&self, galaxy: &Galaxy) -> colored::ColoredString {
+        galaxy.note(&CelestialBodyRef::Star(self))
+    }
+
     fn description(&self) -> colored::ColoredString {
-        self.description.bright_black()
+        self.description.muted()
     }
 
     fn children<'a>(
         &self,
         root: &'a Galaxy,
     ) -> Vec<Box<&'a dyn util::tree::PrintTreeNode<Galaxy>>> {
-        let children = self
-            .children
+        // Show the most important children first
+        let mut sorted = self.children.clone();
+        sorted.retain(|child| !root.is_hidden(*child));
+        super::Sort::Priority.sort(root, &mut sorted);
+
+        let children = sorted
             .iter()
             .map(|child| match root.index(*child) {
                 Some(index) => match index.kind {
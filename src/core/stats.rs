@@ -0,0 +1,307 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing a summary of the work in a `Galaxy`, built from the
+ * current statuses and the status histories of its celestial bodies.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Days, NaiveDate, Utc, Weekday};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use super::{CelestialBodyKind, Galaxy, Status, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A summary of the work in a `Galaxy`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    /// Number of celestial bodies of each kind
+    pub kinds: BTreeMap<CelestialBodyKind, usize>,
+    /// Number of celestial bodies with each status
+    pub statuses: BTreeMap<Status, usize>,
+    /// Number of celestial bodies finished in each of the last weeks, oldest
+    /// first
+    pub throughput: Vec<Throughput>,
+    /// How long celestial bodies take from being started to being done
+    pub cycle_time: Option<CycleTime>,
+    /// The celestial bodies that have been blocked the longest, longest first
+    pub blocked: Vec<Blocked>,
+    /// The Stars with the most unfinished descendants, busiest first
+    pub busiest_stars: Vec<BusyStar>,
+    /// The work assigned to each person, by name. Celestial bodies with
+    /// several assignees are counted for each of them.
+    pub assignees: BTreeMap<String, Workload>,
+}
+
+/// Number of celestial bodies finished in a week
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Throughput {
+    /// The Monday the week starts on
+    pub week: NaiveDate,
+    /// Number of times a status was changed to `Done`
+    pub done: usize,
+}
+
+/// The average time from the first `Start` to the last `Done` of the celestial
+/// bodies that are done. Celestial bodies that were never started are left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CycleTime {
+    /// Number of celestial bodies the average is taken over
+    pub bodies: usize,
+    pub average_minutes: u64,
+}
+
+/// A celestial body that is currently `Block`ed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Blocked {
+    pub id: ID,
+    pub title: String,
+    /// When the celestial body was blocked, `None` if its history does not say
+    pub since: Option<DateTime<Utc>>,
+}
+
+/// A Star and how much work is left in it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BusyStar {
+    pub id: ID,
+    pub title: String,
+    /// Number of descendants that are not `Done` or `Cancel`ed
+    pub open: usize,
+    /// Number of descendants
+    pub total: usize,
+}
+
+/// How much work is assigned to someone
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Workload {
+    /// Number of assigned celestial bodies that are not `Done` or `Cancel`ed
+    pub open: usize,
+    /// Number of assigned celestial bodies that are `Done`
+    pub done: usize,
+}
+
+impl Stats {
+    /// Summarizes `galaxy`
+    ///
+    /// # Arguments
+    /// - `galaxy`: The `Galaxy` to summarize
+    /// - `now`: The current time, the throughput is counted for the weeks up to
+    ///   (and including) the week of `now`
+    /// - `weeks`: The number of weeks to count the throughput for
+    /// - `top`: The number of blocked celestial bodies and Stars to list
+    pub fn of(galaxy: &Galaxy, now: DateTime<Utc>, weeks: usize, top: usize) -> Self {
+        let mut stats = Self::default();
+        for kind in CelestialBodyKind::value_variants() {
+            stats.kinds.insert(*kind, 0);
+        }
+        for status in Status::value_variants() {
+            stats.statuses.insert(*status, 0);
+        }
+
+        let this_week = now.date_naive().week(Weekday::Mon).first_day();
+        stats.throughput = (0..weeks)
+            .rev()
+            .map(|ago| Throughput {
+                week: this_week - Days::new(7 * ago as u64),
+                done: 0,
+            })
+            .collect();
+
+        let mut cycle_minutes = vec![];
+        for body in galaxy.iter() {
+            *stats.kinds.entry(body.kind()).or_default() += 1;
+            *stats.statuses.entry(body.status()).or_default() += 1;
+
+            let history = body.history();
+            for change in history.iter().filter(|c| c.new_status() == Status::Done) {
+                let week = change.time().date_naive().week(Weekday::Mon).first_day();
+                if let Some(throughput) = stats.throughput.iter_mut().find(|t| t.week == week) {
+                    throughput.done += 1;
+                }
+            }
+
+            let last = |status: Status| history.iter().rev().find(|c| c.new_status() == status);
+            match body.status() {
+                Status::Done => {
+                    let done = last(Status::Done).map(|c| c.time());
+                    let start = history
+                        .iter()
+                        .find(|c| c.new_status() == Status::Start)
+                        .map(|c| c.time());
+                    if let (Some(start), Some(done)) = (start, done)
+                        && start <= done
+                    {
+                        cycle_minutes.push((done - start).num_minutes() as u64);
+                    }
+                }
+                Status::Block => stats.blocked.push(Blocked {
+                    id: body.id(),
+                    title: body.title().to_string(),
+                    since: last(Status::Block).map(|c| c.time()),
+                }),
+                _ => {}
+            }
+
+            for assignee in body.assignees() {
+                let workload = stats.assignees.entry(assignee.clone()).or_default();
+                match body.status() {
+                    Status::Done => workload.done += 1,
+                    status if !status.is_final() => workload.open += 1,
+                    _ => {}
+                }
+            }
+
+            if body.kind() == CelestialBodyKind::Star
+                && let Some(progress) = galaxy.progress(body.id())
+                && progress.total > progress.finished
+            {
+                stats.busiest_stars.push(BusyStar {
+                    id: body.id(),
+                    title: body.title().to_string(),
+                    open: progress.total - progress.finished,
+                    total: progress.total,
+                });
+            }
+        }
+
+        if !cycle_minutes.is_empty() {
+            stats.cycle_time = Some(CycleTime {
+                bodies: cycle_minutes.len(),
+                average_minutes: cycle_minutes.iter().sum::<u64>() / cycle_minutes.len() as u64,
+            });
+        }
+        // Celestial bodies without a known start of the block come last
+        stats
+            .blocked
+            .sort_by_key(|blocked| (blocked.since.is_none(), blocked.since, blocked.id));
+        stats.blocked.truncate(top);
+        stats
+            .busiest_stars
+            .sort_by_key(|star| (usize::MAX - star.open, star.id));
+        stats.busiest_stars.truncate(top);
+
+        stats
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, StatusHistory};
+
+    fn change(old: Status, new: Status, time: &str) -> StatusHistory {
+        StatusHistory {
+            old,
+            new,
+            comment: String::new(),
+            time: DateTime::parse_from_rfc3339(time).unwrap().into(),
+        }
+    }
+
+    #[test]
+    fn stats_summarize_the_history() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+
+        let planet = galaxy.planet();
+        planet.status = Status::Done;
+        planet.history = vec![
+            change(Status::Todo, Status::Start, "2026-10-05T09:00:00Z"),
+            change(Status::Start, Status::Done, "2026-10-06T09:00:00Z"),
+        ];
+        let done = planet.id();
+        let comet = galaxy.comet();
+        comet.status = Status::Done;
+        comet.history = vec![
+            change(Status::Todo, Status::Start, "2026-10-13T09:00:00Z"),
+            change(Status::Start, Status::Done, "2026-10-13T11:00:00Z"),
+        ];
+        let planet = galaxy.planet();
+        planet.status = Status::Block;
+        planet.history = vec![change(Status::Todo, Status::Block, "2026-09-01T09:00:00Z")];
+        planet.assignees = vec!["alice".into()];
+        let blocked = planet.id();
+        galaxy.get_mut(done).unwrap().assign("alice".into());
+        galaxy.set_parent(blocked, Some(star)).unwrap();
+        galaxy.set_parent(done, Some(star)).unwrap();
+
+        let now = DateTime::parse_from_rfc3339("2026-10-16T12:00:00Z")
+            .unwrap()
+            .into();
+        let stats = Stats::of(&galaxy, now, 3, 5);
+
+        assert_eq!(stats.kinds[&CelestialBodyKind::Planet], 2);
+        assert_eq!(stats.statuses[&Status::Done], 2);
+        assert_eq!(stats.statuses[&Status::Hold], 0);
+        let done: Vec<_> = stats
+            .throughput
+            .iter()
+            .map(|t| (t.week.to_string(), t.done))
+            .collect();
+        assert_eq!(
+            done,
+            [
+                ("2026-09-28".to_string(), 0),
+                ("2026-10-05".to_string(), 1),
+                ("2026-10-12".to_string(), 1),
+            ]
+        );
+        assert_eq!(
+            stats.cycle_time,
+            Some(CycleTime {
+                bodies: 2,
+                average_minutes: (24 * 60 + 2 * 60) / 2,
+            })
+        );
+        assert_eq!(stats.blocked.len(), 1);
+        assert_eq!(stats.blocked[0].id, blocked);
+        assert_eq!(stats.busiest_stars.len(), 1);
+        assert_eq!(
+            (stats.busiest_stars[0].open, stats.busiest_stars[0].total),
+            (1, 2)
+        );
+        assert_eq!(stats.assignees["alice"], Workload { open: 1, done: 1 });
+    }
+}
@@ -0,0 +1,628 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the ways a database can be stored on disk.
+ *
+ * By default, the whole database is a single file (e.g. `.planit.json`). It
+ * can instead be a `.planit` directory with a small file per celestial body,
+ * so that changes to different celestial bodies on different git branches
+ * touch different files and merge without conflicts. Everything else (e.g.
+ * the title, retrospectives, and the trash) is kept in `.planit/galaxy.json`.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    collections::HashSet,
+    fmt,
+    fs::{self, File},
+    io::{self, Read},
+    mem,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use clap::ValueEnum;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use super::{
+    galaxy::{check, Database},
+    Comet, DatabaseError, Format, ParseIssue, Planet, Star, ID,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TYPES                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+type Result<T> = std::result::Result<T, DatabaseError>;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TRAITS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A place a database is stored in
+pub(super) trait Storage {
+    /// The database file or directory
+    fn path(&self) -> &Path;
+
+    /// The format of the file(s)
+    fn format(&self) -> Format;
+
+    /// How the database is laid out on disk
+    fn layout(&self) -> Layout;
+
+    /// Whether the database exists
+    fn exists(&self) -> bool;
+
+    /// Reads the database, failing at the first value that is not valid
+    fn read(&self) -> Result<Database>;
+
+    /// Reads the database, skipping the entries (e.g. celestial bodies) that
+    /// are not valid, see `Galaxy::load_from_reader_lenient`
+    fn read_lenient(&self) -> Result<(Database, Vec<ParseIssue>)>;
+
+    /// Reads every file of the database without checking what they contain,
+    /// e.g. to check them against the schema
+    fn raw_files(&self) -> Result<Vec<RawFile>>;
+
+    /// Writes the database, creating it if it does not exist yet
+    fn write(&self, database: Database) -> Result<()>;
+
+    /// Writes the database like `write`, but storages that keep celestial
+    /// bodies apart only serialize the ones in `changed`
+    fn write_changed(&self, database: Database, changed: &HashSet<ID>) -> Result<()> {
+        let _ = changed;
+        self.write(database)
+    }
+
+    /// Removes the database
+    fn remove(&self) -> Result<()>;
+
+    /// When the database was last changed, `None` if it does not exist
+    fn modified(&self) -> Option<SystemTime>;
+
+    /// The number of bytes the database takes up
+    fn size(&self) -> Result<u64>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How a database is laid out on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Layout {
+    /// Everything in a single file, e.g. `.planit.json`
+    #[default]
+    File,
+    /// A `.planit` directory with a file per celestial body, which keeps git
+    /// merge conflicts to a minimum
+    Directory,
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File => write!(f, "a single file"),
+            Self::Directory => write!(f, "a directory"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A file of a database as it is on disk, see `Storage::raw_files`
+#[derive(Debug)]
+pub(super) struct RawFile {
+    /// The name of the file within the database, e.g. `planets/3.json`
+    pub name: String,
+    /// The definition of the schema the file follows (e.g. `planet`), `None`
+    /// if it follows the schema of the whole database
+    pub def: Option<&'static str>,
+    /// What the file contains, or why it could not be parsed
+    pub value: Result<Value>,
+}
+
+/// A database that is a single file
+#[derive(Debug)]
+pub(super) struct SingleFile {
+    path: PathBuf,
+    format: Format,
+}
+
+/// A database that is a directory with a file per celestial body
+#[derive(Debug)]
+pub(super) struct Directory {
+    path: PathBuf,
+    format: Format,
+}
+
+impl Storage for SingleFile {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn format(&self) -> Format {
+        self.format
+    }
+
+    fn layout(&self) -> Layout {
+        Layout::File
+    }
+
+    fn exists(&self) -> bool {
+        self.path.is_file()
+    }
+
+    fn read(&self) -> Result<Database> {
+        read_as(&read_limited(File::open(&self.path)?)?, self.format)
+    }
+
+    fn read_lenient(&self) -> Result<(Database, Vec<ParseIssue>)> {
+        let value = parse(&read_limited(File::open(&self.path)?)?, self.format)?;
+        Database::from_value_lenient(value, Vec::new())
+    }
+
+    fn raw_files(&self) -> Result<Vec<RawFile>> {
+        let bytes = read_limited(File::open(&self.path)?)?;
+        let name = self.path.file_name().unwrap_or_default();
+        Ok(vec![RawFile {
+            name: name.to_string_lossy().into_owned(),
+            def: None,
+            value: parse(&bytes, self.format),
+        }])
+    }
+
+    fn write(&self, database: Database) -> Result<()> {
+        let bytes = write_limited(&database, self.format)?;
+        Ok(fs::write(&self.path, bytes)?)
+    }
+
+    fn remove(&self) -> Result<()> {
+        Ok(fs::remove_file(&self.path)?)
+    }
+
+    fn modified(&self) -> Option<SystemTime> {
+        fs::metadata(&self.path).and_then(|m| m.modified()).ok()
+    }
+
+    fn size(&self) -> Result<u64> {
+        Ok(fs::metadata(&self.path)?.len())
+    }
+}
+
+impl Directory {
+    /// The name of the directory
+    const NAME: &str = ".planit";
+    /// The directories the celestial bodies are kept in, one per kind
+    const KINDS: [&str; 3] = ["comets", "planets", "stars"];
+
+    /// Helper function that gives the path of the file with everything but
+    /// the celestial bodies
+    fn galaxy_file(&self) -> PathBuf {
+        self.path
+            .join(format!("galaxy.{}", self.format.extension()))
+    }
+
+    /// Helper function that lists the files of the celestial bodies of one
+    /// kind with their IDs, lowest ID first. Files with other names are
+    /// ignored.
+    fn files(&self, kind: &str) -> Result<Vec<(ID, PathBuf)>> {
+        let entries = match fs::read_dir(self.path.join(kind)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            entries => entries?,
+        };
+        let mut files = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|e| e == self.format.extension())
+            {
+                let id = path.file_stem().and_then(|s| s.to_str()?.parse().ok());
+                files.extend(id.map(|id| (id, path)));
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Helper function that names a file within the directory for errors
+    fn name(&self, path: &Path) -> String {
+        path.strip_prefix(&self.path)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
+    /// Helper function that makes an error while parsing the file at `path`
+    /// point at the file. Other errors are returned as they are.
+    fn locate(&self, path: &Path, error: DatabaseError) -> DatabaseError {
+        let name = self.name(path);
+        let issue = match error {
+            DatabaseError::InvalidValue(issue) if issue.path == "." => ParseIssue {
+                path: name,
+                ..issue
+            },
+            DatabaseError::InvalidValue(issue) => ParseIssue {
+                path: format!("{name}:{}", issue.path),
+                ..issue
+            },
+            DatabaseError::ParsingError(_) | DatabaseError::FormatError(..) => ParseIssue {
+                path: name,
+                value: None,
+                message: error.to_string(),
+            },
+            error => return error,
+        };
+        DatabaseError::InvalidValue(issue)
+    }
+
+    /// Helper function that reads the celestial bodies of one kind, failing at
+    /// the first one that is not valid
+    fn bodies<T: DeserializeOwned>(&self, kind: &str) -> Result<Vec<T>> {
+        let read = |path: &Path| read_as(&read_limited(File::open(path)?)?, self.format);
+        self.files(kind)?
+            .into_iter()
+            .map(|(_, path)| read(&path).map_err(|e| self.locate(&path, e)))
+            .collect()
+    }
+
+    /// Helper function that reads the celestial bodies of one kind, skipping
+    /// the ones that are not valid
+    fn bodies_lenient<T: DeserializeOwned>(
+        &self,
+        kind: &str,
+        issues: &mut Vec<ParseIssue>,
+    ) -> Result<Vec<Value>> {
+        let read = |path: &Path| -> Result<Value> {
+            let value = parse(&read_limited(File::open(path)?)?, self.format)?;
+            check::<T>(&value).map_err(DatabaseError::InvalidValue)?;
+            Ok(value)
+        };
+        let mut bodies = Vec::new();
+        for (_, path) in self.files(kind)? {
+            match read(&path).map_err(|e| self.locate(&path, e)) {
+                Ok(body) => bodies.push(body),
+                Err(DatabaseError::InvalidValue(mut issue)) => {
+                    issue.message += ", skipped";
+                    issues.push(issue);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(bodies)
+    }
+
+    /// Helper function that serializes the celestial bodies of one kind into
+    /// the contents of their files. Only the bodies in `changed` (or whose
+    /// file is missing) are serialized if it is given, the others are `None`.
+    fn serialize_bodies<T: Serialize>(
+        &self,
+        kind: &str,
+        bodies: &[T],
+        id: fn(&T) -> ID,
+        changed: Option<&HashSet<ID>>,
+    ) -> Result<Vec<(PathBuf, Option<Vec<u8>>)>> {
+        let dir = self.path.join(kind);
+        let mut files = Vec::new();
+        for body in bodies {
+            let path = dir.join(format!("{}.{}", id(body), self.format.extension()));
+            if changed.is_some_and(|changed| !changed.contains(&id(body))) && path.is_file() {
+                files.push((path, None));
+                continue;
+            }
+            let bytes = write_limited(body, self.format)?;
+            files.push((path, Some(bytes)));
+        }
+        Ok(files)
+    }
+
+    /// Helper function that writes the `files` of the celestial bodies of one
+    /// kind (see `serialize_bodies`), and removes the files of the ones that
+    /// are gone. Files that would not change are left alone.
+    fn write_bodies(&self, kind: &str, files: Vec<(PathBuf, Option<Vec<u8>>)>) -> Result<()> {
+        fs::create_dir_all(self.path.join(kind))?;
+        let mut written = HashSet::new();
+        for (path, bytes) in files {
+            if let Some(bytes) = bytes
+                && fs::read(&path).ok().as_ref() != Some(&bytes)
+            {
+                fs::write(&path, bytes)?;
+            }
+            written.insert(path);
+        }
+        for (_, path) in self.files(kind)? {
+            if !written.contains(&path) {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Helper function that writes the galaxy file and the celestial bodies,
+    /// see `write_bodies`
+    fn write_database(&self, mut database: Database, changed: Option<&HashSet<ID>>) -> Result<()> {
+        // Everything is serialized first, so that nothing is written if any
+        // of the files would be too large to be read again
+        let comets = mem::take(&mut database.comets);
+        let comets = self.serialize_bodies("comets", &comets, |c| c.id, changed)?;
+        let planets = mem::take(&mut database.planets);
+        let planets = self.serialize_bodies("planets", &planets, |p| p.id, changed)?;
+        let stars = mem::take(&mut database.stars);
+        let stars = self.serialize_bodies("stars", &stars, |s| s.id, changed)?;
+        let galaxy = write_limited(&database, self.format)?;
+
+        fs::create_dir_all(&self.path)?;
+        self.write_bodies("comets", comets)?;
+        self.write_bodies("planets", planets)?;
+        self.write_bodies("stars", stars)?;
+        Ok(fs::write(self.galaxy_file(), galaxy)?)
+    }
+
+    /// Helper function that lists every file of the database
+    fn all_files(&self) -> Result<Vec<PathBuf>> {
+        let mut files = vec![self.galaxy_file()];
+        for kind in Self::KINDS {
+            files.extend(self.files(kind)?.into_iter().map(|(_, path)| path));
+        }
+        Ok(files)
+    }
+}
+
+impl Storage for Directory {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn format(&self) -> Format {
+        self.format
+    }
+
+    fn layout(&self) -> Layout {
+        Layout::Directory
+    }
+
+    fn exists(&self) -> bool {
+        self.galaxy_file().is_file()
+    }
+
+    fn read(&self) -> Result<Database> {
+        let path = self.galaxy_file();
+        let read = || read_as::<Database>(&read_limited(File::open(&path)?)?, self.format);
+        let mut database = read().map_err(|e| self.locate(&path, e))?;
+        database.comets = self.bodies::<Comet>("comets")?;
+        database.planets = self.bodies::<Planet>("planets")?;
+        database.stars = self.bodies::<Star>("stars")?;
+        Ok(database)
+    }
+
+    fn read_lenient(&self) -> Result<(Database, Vec<ParseIssue>)> {
+        let mut issues = Vec::new();
+        let mut value = parse(&read_limited(File::open(self.galaxy_file())?)?, self.format)?;
+        value["comets"] = self.bodies_lenient::<Comet>("comets", &mut issues)?.into();
+        value["planets"] = self
+            .bodies_lenient::<Planet>("planets", &mut issues)?
+            .into();
+        value["stars"] = self.bodies_lenient::<Star>("stars", &mut issues)?.into();
+        Database::from_value_lenient(value, issues)
+    }
+
+    fn raw_files(&self) -> Result<Vec<RawFile>> {
+        let read = |path: &Path| parse(&read_limited(File::open(path)?)?, self.format);
+        let galaxy = self.galaxy_file();
+        let mut files = vec![RawFile {
+            name: self.name(&galaxy),
+            def: None,
+            value: read(&galaxy),
+        }];
+        for (kind, def) in Self::KINDS.into_iter().zip(["comet", "planet", "star"]) {
+            for (_, path) in self.files(kind)? {
+                files.push(RawFile {
+                    name: self.name(&path),
+                    def: Some(def),
+                    value: read(&path),
+                });
+            }
+        }
+        Ok(files)
+    }
+
+    fn write(&self, database: Database) -> Result<()> {
+        self.write_database(database, None)
+    }
+
+    fn write_changed(&self, database: Database, changed: &HashSet<ID>) -> Result<()> {
+        self.write_database(database, Some(changed))
+    }
+
+    fn remove(&self) -> Result<()> {
+        for file in self.all_files()? {
+            fs::remove_file(file)?;
+        }
+        // Directories that still contain other files (e.g. the files of
+        // another format) are kept
+        for kind in Self::KINDS {
+            let _ = fs::remove_dir(self.path.join(kind));
+        }
+        let _ = fs::remove_dir(&self.path);
+        Ok(())
+    }
+
+    fn modified(&self) -> Option<SystemTime> {
+        // Removing a file only changes the directory it was in
+        let dirs = Self::KINDS.iter().map(|kind| self.path.join(kind));
+        let files = self.all_files().ok()?.into_iter().chain(dirs);
+        files
+            .filter_map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+            .max()
+    }
+
+    fn size(&self) -> Result<u64> {
+        let sizes = self.all_files()?.into_iter().map(fs::metadata);
+        Ok(sizes.map(|m| m.map(|m| m.len())).sum::<io::Result<u64>>()?)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Finds the database directly in `dir`, in any `Layout` and `Format`
+pub(super) fn find(dir: &Path) -> Option<Box<dyn Storage>> {
+    Layout::value_variants()
+        .iter()
+        .flat_map(|layout| {
+            let formats = Format::value_variants().iter();
+            formats.map(|format| create(dir, *layout, *format))
+        })
+        .find(|storage| storage.exists())
+}
+
+/// Opens the database at `path`, which is either a database file or a
+/// `.planit` directory
+pub(super) fn open(path: &Path) -> Box<dyn Storage> {
+    if !path.is_dir() {
+        return Box::new(SingleFile {
+            path: path.to_path_buf(),
+            format: Format::of(path),
+        });
+    }
+    let directory = |format| Directory {
+        path: path.to_path_buf(),
+        format,
+    };
+    let mut formats = Format::value_variants().iter().copied();
+    let format = formats.find(|format| directory(*format).exists());
+    Box::new(directory(format.unwrap_or_default()))
+}
+
+/// The database in `layout` and `format` that would be created in `dir`
+pub(super) fn create(dir: &Path, layout: Layout, format: Format) -> Box<dyn Storage> {
+    match layout {
+        Layout::File => Box::new(SingleFile {
+            path: dir.join(format.filename()),
+            format,
+        }),
+        Layout::Directory => Box::new(Directory {
+            path: dir.join(Directory::NAME),
+            format,
+        }),
+    }
+}
+
+/// Helper function that reads everything from `reader`, unless it is larger
+/// than `Database::MAX_SIZE`
+pub(super) fn read_limited<R: io::Read>(reader: R) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    reader
+        .take(Database::MAX_SIZE + 1)
+        .read_to_end(&mut bytes)?;
+    if bytes.len() as u64 > Database::MAX_SIZE {
+        return Err(DatabaseError::DatabaseTooLarge(Database::MAX_SIZE));
+    }
+    Ok(bytes)
+}
+
+/// Helper function that serializes `value` in `format`, unless it is larger
+/// than `Database::MAX_SIZE`, since it could then not be read again
+pub(super) fn write_limited<T: Serialize>(value: &T, format: Format) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    write_as(value, &mut bytes, format)?;
+    if bytes.len() as u64 > Database::MAX_SIZE {
+        return Err(DatabaseError::DatabaseTooLarge(Database::MAX_SIZE));
+    }
+    Ok(bytes)
+}
+
+/// Helper function that parses a `T` in `format` from `bytes`. If one of its
+/// values is not valid, the error points at it.
+pub(super) fn read_as<T: DeserializeOwned>(bytes: &[u8], format: Format) -> Result<T> {
+    let error = |e: &dyn fmt::Display| DatabaseError::FormatError(format, e.to_string());
+    let result = match format {
+        Format::Json => serde_json::from_slice(bytes).map_err(DatabaseError::from),
+        Format::Toml => {
+            let s = std::str::from_utf8(bytes).map_err(|e| error(&e))?;
+            toml::from_str(s).map_err(|e| error(&e))
+        }
+        Format::Yaml => serde_yaml::from_slice(bytes).map_err(|e| error(&e)),
+    };
+    // Finding the invalid value takes a second pass, which is only done when
+    // the value could not be read
+    result.map_err(|e| match parse(bytes, format).map(|v| check::<T>(&v)) {
+        Ok(Err(issue)) => DatabaseError::InvalidValue(issue),
+        _ => e,
+    })
+}
+
+/// Helper function that parses `bytes` in `format` without checking what
+/// they contain
+pub(super) fn parse(bytes: &[u8], format: Format) -> Result<Value> {
+    let error = |e: &dyn fmt::Display| DatabaseError::FormatError(format, e.to_string());
+    match format {
+        Format::Json => Ok(serde_json::from_slice(bytes)?),
+        Format::Toml => {
+            let s = std::str::from_utf8(bytes).map_err(|e| error(&e))?;
+            toml::from_str(s).map_err(|e| error(&e))
+        }
+        Format::Yaml => serde_yaml::from_slice(bytes).map_err(|e| error(&e)),
+    }
+}
+
+/// Helper function that writes `value` in `format` to `writer`
+pub(super) fn write_as<T: Serialize, W: io::Write>(
+    value: &T,
+    mut writer: W,
+    format: Format,
+) -> Result<()> {
+    let error = |e: &dyn fmt::Display| DatabaseError::FormatError(format, e.to_string());
+    match format {
+        Format::Json => serde_json::to_writer_pretty(writer, value)?,
+        Format::Toml => {
+            let s = toml::to_string_pretty(value).map_err(|e| error(&e))?;
+            writer.write_all(s.as_bytes())?;
+        }
+        Format::Yaml => serde_yaml::to_writer(writer, value).map_err(|e| error(&e))?,
+    }
+    Ok(())
+}
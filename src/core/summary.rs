@@ -0,0 +1,183 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing summaries of what changed in a `Galaxy`, e.g. to show
+ * what a command did (or would have done, when it is run with `--dry-run`).
+ *
+ * Every celestial body records its status changes in its history and all
+ * other changes as events. A `Snapshot` remembers how long these were, so that
+ * everything recorded since can be collected into a `Summary` later.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::collections::HashMap;
+
+use super::{Event, Galaxy, StatusHistory, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The state of every celestial body in a `Galaxy` at some point in time
+#[derive(Debug, Default, Clone)]
+pub struct Snapshot {
+    /// The title, the number of status changes, and the number of events of
+    /// every celestial body
+    bodies: HashMap<ID, (String, usize, usize)>,
+}
+
+/// The changes recorded by a single celestial body since a `Snapshot`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyChanges {
+    pub id: ID,
+    /// Status changes, oldest first
+    pub statuses: Vec<StatusHistory>,
+    /// All other changes (e.g. reparenting), oldest first
+    pub events: Vec<Event>,
+}
+
+/// Everything that changed in a `Galaxy` since a `Snapshot` was taken
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Summary {
+    /// Celestial bodies that did not exist yet, lowest ID first
+    pub created: Vec<ID>,
+    /// Celestial bodies that no longer exist (e.g. because they were moved to
+    /// the trash) with their titles, lowest ID first
+    pub removed: Vec<(ID, String)>,
+    /// Celestial bodies that existed before and recorded changes since, lowest
+    /// ID first
+    pub changed: Vec<BodyChanges>,
+}
+
+impl Snapshot {
+    /// Takes a snapshot of every celestial body in `galaxy`
+    pub fn take(galaxy: &Galaxy) -> Self {
+        let bodies = galaxy
+            .iter()
+            .map(|body| {
+                let state = (
+                    body.title().to_string(),
+                    body.history().len(),
+                    body.events().len(),
+                );
+                (body.id(), state)
+            })
+            .collect();
+        Self { bodies }
+    }
+
+    /// Collects everything that changed in `galaxy` since the snapshot was
+    /// taken
+    pub fn changes(&self, galaxy: &Galaxy) -> Summary {
+        let mut summary = Summary::default();
+        for body in galaxy.iter() {
+            let Some((_, history, events)) = self.bodies.get(&body.id()) else {
+                summary.created.push(body.id());
+                continue;
+            };
+            let statuses = body.history().get(*history..).unwrap_or_default();
+            let events = body.events().get(*events..).unwrap_or_default();
+            if !statuses.is_empty() || !events.is_empty() {
+                summary.changed.push(BodyChanges {
+                    id: body.id(),
+                    statuses: statuses.to_vec(),
+                    events: events.to_vec(),
+                });
+            }
+        }
+        summary.removed = self
+            .bodies
+            .iter()
+            .filter(|(id, _)| galaxy.get(**id).is_none())
+            .map(|(id, (title, _, _))| (*id, title.clone()))
+            .collect();
+
+        summary.created.sort();
+        summary.removed.sort();
+        summary.changed.sort_by_key(|changes| changes.id);
+        summary
+    }
+}
+
+impl Summary {
+    /// Whether nothing changed at all
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::core::{CelestialBody, Change, Status};
+
+    #[test]
+    fn summaries_list_created_changed_and_removed_bodies() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().title("Release".into()).id();
+        let planet = galaxy.planet().title("Changelog".into()).id();
+        let comet = galaxy.comet().title("Typo".into()).id();
+        let untouched = galaxy.comet().id();
+        let snapshot = Snapshot::take(&galaxy);
+        assert!(snapshot.changes(&galaxy).is_empty());
+
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        galaxy
+            .get_mut(planet)
+            .unwrap()
+            .status(Status::Start, String::new());
+        galaxy.delete(comet, Utc::now()).unwrap();
+        let created = galaxy.planet().id();
+
+        let summary = snapshot.changes(&galaxy);
+        assert_eq!(summary.created, [created]);
+        assert_eq!(summary.removed, [(comet, "Typo".to_string())]);
+        let changed: Vec<_> = summary.changed.iter().map(|c| c.id).collect();
+        assert!(!changed.contains(&untouched));
+        let planet = summary.changed.iter().find(|c| c.id == planet).unwrap();
+        assert_eq!(planet.statuses[0].new_status(), Status::Start);
+        assert!(planet.events.iter().any(|e| e.change
+            == Change::Parent {
+                old: None,
+                new: Some(star)
+            }));
+    }
+}
@@ -0,0 +1,136 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the trash for deleted celestial bodies.
+ *
+ * Deleting a celestial body does not remove it from the database right away.
+ * Instead, it (and all of its descendants) are moved into the trash, where they
+ * can be restored until the trash is emptied.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Comet, Planet, Star, ID};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A deleted celestial body along with all of its descendants
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TrashEntry {
+    /// The ID of the celestial body that was deleted
+    pub(super) id: ID,
+    pub(super) deleted: DateTime<Utc>,
+    pub(super) comets: Vec<Comet>,
+    pub(super) planets: Vec<Planet>,
+    pub(super) stars: Vec<Star>,
+}
+
+impl TrashEntry {
+    /// The ID of the celestial body that was deleted
+    pub fn id(&self) -> ID {
+        self.id
+    }
+
+    /// When the celestial body was deleted
+    pub fn deleted(&self) -> DateTime<Utc> {
+        self.deleted
+    }
+
+    /// The short key of the celestial body that was deleted, if it has one
+    pub fn key(&self) -> Option<&str> {
+        self.comets
+            .iter()
+            .filter(|c| c.id == self.id)
+            .map(|c| c.key.as_deref())
+            .chain(
+                self.planets
+                    .iter()
+                    .filter(|p| p.id == self.id)
+                    .map(|p| p.key.as_deref()),
+            )
+            .chain(
+                self.stars
+                    .iter()
+                    .filter(|s| s.id == self.id)
+                    .map(|s| s.key.as_deref()),
+            )
+            .next()
+            .flatten()
+    }
+
+    /// Every short key used by the celestial bodies in the entry
+    pub(super) fn keys(&self) -> impl Iterator<Item = &str> {
+        self.comets
+            .iter()
+            .filter_map(|c| c.key.as_deref())
+            .chain(self.planets.iter().filter_map(|p| p.key.as_deref()))
+            .chain(self.stars.iter().filter_map(|s| s.key.as_deref()))
+    }
+
+    /// The title of the celestial body that was deleted
+    pub fn title(&self) -> &str {
+        self.comets
+            .iter()
+            .filter(|c| c.id == self.id)
+            .map(|c| c.title.as_str())
+            .chain(
+                self.planets
+                    .iter()
+                    .filter(|p| p.id == self.id)
+                    .map(|p| p.title.as_str()),
+            )
+            .chain(
+                self.stars
+                    .iter()
+                    .filter(|s| s.id == self.id)
+                    .map(|s| s.title.as_str()),
+            )
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// The number of celestial bodies that were deleted, including the
+    /// descendants
+    pub fn len(&self) -> usize {
+        self.comets.len() + self.planets.len() + self.stars.len()
+    }
+
+    /// Whether the entry contains no celestial bodies
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
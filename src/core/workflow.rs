@@ -0,0 +1,241 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the status workflow of a `Galaxy`.
+ *
+ * A workflow is the list of statuses that celestial bodies can have, which
+ * starts out as the built in `Status`es. Teams can add their own (e.g.
+ * "Review" or "QA"), each counting as one of the built in statuses, its
+ * category. Everything that only cares whether a celestial body is e.g.
+ * started or finished keeps looking at the category, while views show the
+ * status of the workflow. Each status can also limit which statuses may
+ * follow it.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::util::theme::Color;
+
+use super::{CelestialBodyRef, Status};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A status of a workflow
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Stage {
+    pub name: String,
+    /// The built in status this one counts as
+    pub category: Status,
+    /// The color it is shown in, the color of `category` if `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// The names of the statuses that may follow this one. Any status may
+    /// follow it if empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub next: Vec<String>,
+}
+
+impl Stage {
+    /// Creates a status of the workflow that counts as `category`
+    pub fn new(name: String, category: Status) -> Self {
+        Self {
+            name,
+            category,
+            color: None,
+            next: Vec::new(),
+        }
+    }
+
+    /// Whether this is one of the built in statuses
+    pub fn is_built_in(&self) -> bool {
+        self.name == self.category.to_string()
+    }
+
+    /// The color it is shown in
+    pub fn color(&self) -> Color {
+        self.color.unwrap_or_else(|| self.category.color())
+    }
+
+    /// Whether a celestial body in this status may be moved to `to`
+    pub fn allows(&self, to: &Stage) -> bool {
+        self.next.is_empty()
+            || self.name == to.name
+            || self
+                .next
+                .iter()
+                .any(|next| next.eq_ignore_ascii_case(&to.name))
+    }
+}
+
+/// The statuses that celestial bodies can have. The built in statuses are
+/// always part of it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Workflow {
+    stages: Vec<Stage>,
+}
+
+impl Default for Workflow {
+    fn default() -> Self {
+        Self {
+            stages: Status::value_variants()
+                .iter()
+                .map(|status| Stage::new(status.to_string(), *status))
+                .collect(),
+        }
+    }
+}
+
+impl Workflow {
+    /// Whether this is the default workflow, which is not saved
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// All statuses of the workflow, in the order they were added
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    /// Finds the status called `name`, ignoring case
+    pub fn find(&self, name: &str) -> Option<&Stage> {
+        self.stages
+            .iter()
+            .find(|stage| stage.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The status of `body` in this workflow. This is the built in status of
+    /// `body` if it has none of its own, or if it was removed.
+    pub fn stage_of(&self, body: &CelestialBodyRef) -> Stage {
+        body.stage()
+            .and_then(|name| self.find(name))
+            .or_else(|| self.find(&body.status().to_string()))
+            .cloned()
+            .unwrap_or_else(|| Stage::new(body.status().to_string(), body.status()))
+    }
+
+    /// Helper function that finds the status called `name`, ignoring case
+    pub(super) fn find_mut(&mut self, name: &str) -> Option<&mut Stage> {
+        self.stages
+            .iter_mut()
+            .find(|stage| stage.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Helper function that adds `stage`, whose name must not be taken yet
+    pub(super) fn push(&mut self, stage: Stage) {
+        self.stages.push(stage);
+    }
+
+    /// Helper function that removes the status called `name` and any
+    /// transitions to it
+    pub(super) fn remove(&mut self, name: &str) -> Option<Stage> {
+        let position = self
+            .stages
+            .iter()
+            .position(|stage| stage.name.eq_ignore_ascii_case(name))?;
+        let stage = self.stages.remove(position);
+        for other in &mut self.stages {
+            other
+                .next
+                .retain(|next| !next.eq_ignore_ascii_case(&stage.name));
+        }
+        Some(stage)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{CelestialBody, Filter, Galaxy, GalaxyError};
+
+    #[test]
+    fn custom_statuses_count_as_their_category() {
+        let mut galaxy = Galaxy::default();
+        let id = galaxy.planet().title("Login".into()).id();
+        let mut review = Stage::new("Review".into(), Status::Start);
+        review.next = vec!["Done".into(), "Start".into()];
+        galaxy.workflow_add(review).unwrap();
+        assert_eq!(
+            galaxy.workflow_add(Stage::new("review".into(), Status::Hold)),
+            Err(GalaxyError::StatusExists("review".into()))
+        );
+        assert_eq!(
+            galaxy.workflow_add(Stage::new("In QA".into(), Status::Start)),
+            Err(GalaxyError::InvalidStatusName("In QA".into()))
+        );
+
+        assert!(galaxy.set_status(id, "review", String::new()).unwrap());
+        let body = galaxy.get(id).unwrap();
+        assert_eq!(
+            (body.status(), body.stage()),
+            (Status::Start, Some("Review"))
+        );
+        let stage: Filter = "stage:review".parse().unwrap();
+        let status: Filter = "status:start".parse().unwrap();
+        assert_eq!(galaxy.matching(&stage).len(), 1);
+        assert_eq!(galaxy.matching(&status).len(), 1);
+
+        // Only the listed statuses may follow a status with transitions
+        assert_eq!(
+            galaxy.set_status(id, "todo", String::new()),
+            Err(GalaxyError::TransitionNotAllowed(
+                "Review".into(),
+                "Todo".into()
+            ))
+        );
+        assert!(galaxy.set_status(id, "Start", String::new()).unwrap());
+        assert_eq!(galaxy.get(id).unwrap().stage(), None);
+        assert_eq!(galaxy.get(id).unwrap().history().len(), 1);
+
+        // Removing a status moves its celestial bodies back to the category
+        galaxy.set_status(id, "Review", String::new()).unwrap();
+        assert_eq!(
+            galaxy.workflow_remove("Start"),
+            Err(GalaxyError::BuiltInStatus("Start".into()))
+        );
+        assert_eq!(galaxy.workflow_remove("Review"), Ok(vec![id]));
+        let body = galaxy.get(id).unwrap();
+        assert_eq!((body.status(), body.stage()), (Status::Start, None));
+        assert!(galaxy.workflow().is_default());
+    }
+}
@@ -0,0 +1,174 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Module containing the log of time spent working on a celestial body. Work is
+ * recorded as sessions with a start and an end time. At most one session in
+ * the whole `Galaxy` should be open (i.e. without an end) at a time.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single period of time spent working on a celestial body
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WorkSession {
+    pub start: DateTime<Utc>,
+    /// When the session ended, `None` if it is still in progress
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl WorkSession {
+    /// The time spent in the session that falls between `from` and `to`.
+    /// Sessions that are still in progress are counted up to `now`.
+    pub fn duration_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> TimeDelta {
+        let start = self.start.max(from);
+        let end = self.end.unwrap_or(now).min(to);
+        (end - start).max(TimeDelta::zero())
+    }
+}
+
+/// All work sessions of a celestial body, oldest first
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct WorkLog {
+    sessions: Vec<WorkSession>,
+}
+
+impl WorkLog {
+    /// All work sessions, oldest first
+    pub fn sessions(&self) -> &[WorkSession] {
+        &self.sessions
+    }
+
+    /// The session that is still in progress, if any
+    pub fn active(&self) -> Option<&WorkSession> {
+        self.sessions.last().filter(|s| s.end.is_none())
+    }
+
+    /// Starts a new session at `now`
+    ///
+    /// # Returns
+    /// `false` if a session is already in progress
+    pub fn start(&mut self, now: DateTime<Utc>) -> bool {
+        if self.active().is_some() {
+            return false;
+        }
+        self.sessions.push(WorkSession {
+            start: now,
+            end: None,
+        });
+        true
+    }
+
+    /// Ends the session in progress at `now`
+    ///
+    /// # Returns
+    /// The length of the session, `None` if no session was in progress
+    pub fn stop(&mut self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        let session = self.sessions.last_mut().filter(|s| s.end.is_none())?;
+        session.end = Some(now);
+        Some(now - session.start)
+    }
+
+    /// The total time spent between `from` and `to`. Sessions that are still in
+    /// progress are counted up to `now`.
+    pub fn total_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> TimeDelta {
+        self.sessions
+            .iter()
+            .map(|s| s.duration_between(from, to, now))
+            .sum()
+    }
+
+    /// Whether or not any work has been logged
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sessions_are_started_and_stopped() {
+        let now = Utc::now();
+        let mut log = WorkLog::default();
+
+        assert!(log.start(now));
+        assert!(!log.start(now));
+        assert!(log.active().is_some());
+
+        assert_eq!(
+            log.stop(now + TimeDelta::minutes(30)),
+            Some(TimeDelta::minutes(30))
+        );
+        assert_eq!(log.stop(now), None);
+        assert!(log.active().is_none());
+    }
+
+    #[test]
+    fn totals_are_clipped_to_range() {
+        let now = Utc::now();
+        let mut log = WorkLog::default();
+        log.start(now - TimeDelta::hours(3));
+        log.stop(now - TimeDelta::hours(1));
+        log.start(now - TimeDelta::minutes(10));
+
+        let total = log.total_between(now - TimeDelta::hours(2), now, now);
+        assert_eq!(total, TimeDelta::minutes(70));
+
+        let all = log.total_between(DateTime::<Utc>::MIN_UTC, now, now);
+        assert_eq!(all, TimeDelta::minutes(130));
+    }
+}
@@ -27,8 +27,94 @@
 
 /*!
  * A crate for creating a TUI-based project management application.
+ *
+ * The command line interface and the TUI are only built with the `app`
+ * feature, which is enabled by default. Other tools can use the `Galaxy` and
+ * its celestial bodies without them (and without ratatui or crossterm) by
+ * depending on the crate with `default-features = false`. The types needed
+ * for that are re-exported here.
+ *
+ * ```
+ * use planit::{CelestialBody, Filter, Format, Galaxy, Status};
+ *
+ * let mut galaxy = Galaxy::default().format(Format::Yaml);
+ * let id = galaxy.planet().id();
+ * galaxy
+ *     .get_mut(id)
+ *     .unwrap()
+ *     .title("Write the docs".into())
+ *     .status(Status::Start, String::new());
+ *
+ * let filter: Filter = "status:start".parse().unwrap();
+ * let started = galaxy.matching(&filter);
+ * assert_eq!(started[0].title(), "Write the docs");
+ *
+ * let mut yaml = Vec::new();
+ * galaxy.save_to_writer(&mut yaml).unwrap();
+ * ```
  */
 
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  MODULES                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "app")]
 pub mod app;
 pub mod core;
 pub mod util;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+pub use crate::core::{
+    set_dispatcher, Activity, Burndown, CelestialBody, CelestialBodyKind, CelestialBodyMut,
+    CelestialBodyRef, Change, Comet, Comparison, DatabaseError, Dispatcher, Estimate, Event,
+    Filter, FilterError, Format, Galaxy, GalaxyError, IdFormat, IdScheme, Merge, MergeConflict,
+    Notification, ParseIssue, Planet, Priority, Reminders, Sprint, Star, Stats, Status,
+    StatusHistory, Trigger, ID,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn the_core_api_is_usable_from_the_crate_root() {
+        let mut galaxy = Galaxy::default();
+        let star = galaxy.star().id();
+        let planet: ID = galaxy.planet().title("Write the docs".into()).id();
+        galaxy.set_parent(planet, Some(star)).unwrap();
+        galaxy
+            .get_mut(planet)
+            .unwrap()
+            .status(Status::Done, String::new());
+
+        let body: CelestialBodyRef = galaxy.get(planet).unwrap();
+        assert_eq!(body.kind(), CelestialBodyKind::Planet);
+        assert_eq!(body.events()[0].change, Change::Created);
+        let filter: Filter = "status:done".parse().unwrap();
+        assert_eq!(galaxy.matching(&filter).len(), 1);
+        assert!(matches!(
+            "colour:red".parse::<Filter>(),
+            Err(FilterError::UnknownKey(_))
+        ));
+
+        let mut json = Vec::new();
+        galaxy.save_to_writer(&mut json).unwrap();
+        let loaded = Galaxy::load_from_reader(json.as_slice()).unwrap();
+        assert_eq!(loaded.get(planet).unwrap().parent(), Some(star));
+        let merge = Merge::of(loaded.clone(), loaded.clone(), loaded).unwrap();
+        assert!(merge.conflicts.is_empty());
+    }
+}
@@ -40,5 +40,7 @@ fn main() {
 
     if let Err(e) = result {
         error!("Error in running application: {e}");
+        eprintln!("{e}");
+        std::process::exit(e.exit_code());
     }
 }
@@ -0,0 +1,321 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Parses the dates typed by users, which can be given relative to today.
+ *
+ * | **Date**                         | **Meaning**                                     |
+ * |:---------------------------------|:------------------------------------------------|
+ * | `2025-12-01`                     | That day                                        |
+ * | `today`, `now`, `eod`            | Today                                           |
+ * | `tomorrow`, `yesterday`          | The day after / before today                    |
+ * | `mon`, `friday`, `next fri`      | The next day with that weekday, after today     |
+ * | `last fri`                       | The last day with that weekday, before today    |
+ * | `eow`, `eom`                     | The coming Friday, the last day of the month    |
+ * | `next week`, `next month`        | A week / month from today                       |
+ * | `in 2 weeks`, `in 3d`, `+3d`     | That long after today                           |
+ * | `2 weeks ago`, `3d`, `-3d`       | That long before today                          |
+ *
+ * Amounts are given in days (`d`), weeks (`w`), months (`m`), or years (`y`),
+ * either as a single word (`2w`) or as two (`2 weeks`). A single word without
+ * a sign (e.g. `3d`) is in the past, so that `--since 3d` reads naturally.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Days, Local, Months, NaiveDate, NaiveTime, Utc, Weekday};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Possible errors when parsing a date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateError {
+    /// Nothing was given
+    Empty,
+    /// The text is not a date in any of the accepted forms
+    Invalid(String),
+    /// The date is too far in the past or future to be represented
+    OutOfRange(String),
+}
+
+impl std::error::Error for DateError {}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateError::Empty => write!(f, "Missing date"),
+            DateError::Invalid(s) => write!(
+                f,
+                "Invalid date \"{s}\", expected e.g. 2025-12-01, today, tomorrow, fri, eow, \
+                 in 2 weeks, or 3 days ago"
+            ),
+            DateError::OutOfRange(s) => write!(f, "Date \"{s}\" is out of range"),
+        }
+    }
+}
+
+/// Helper enum for the units of an amount of time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Unit {
+    /// Helper function that parses a unit, either abbreviated or spelled out
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "d" | "day" | "days" => Some(Self::Day),
+            "w" | "wk" | "wks" | "week" | "weeks" => Some(Self::Week),
+            "m" | "mo" | "month" | "months" => Some(Self::Month),
+            "y" | "yr" | "yrs" | "year" | "years" => Some(Self::Year),
+            _ => None,
+        }
+    }
+
+    /// Helper function that moves `date` by `amount` of this unit, forward if
+    /// `forward` and backward otherwise
+    fn shift(self, date: NaiveDate, amount: u32, forward: bool) -> Option<NaiveDate> {
+        let days = match self {
+            Self::Day => amount,
+            Self::Week => amount.checked_mul(7)?,
+            Self::Month | Self::Year => 0,
+        };
+        let months = match self {
+            Self::Day | Self::Week => 0,
+            Self::Month => amount,
+            Self::Year => amount.checked_mul(12)?,
+        };
+        let (days, months) = (Days::new(days.into()), Months::new(months));
+        match forward {
+            true => date.checked_add_days(days)?.checked_add_months(months),
+            false => date.checked_sub_days(days)?.checked_sub_months(months),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses a date relative to `today`, see the module documentation for the
+/// accepted forms. Case and extra whitespace are ignored.
+///
+/// # Errors
+/// Errors will occur in the following situations:
+/// - `s` is empty
+/// - `s` is not a date in any of the accepted forms
+/// - The date is too far from `today` to be represented
+pub fn parse(s: &str, today: NaiveDate) -> Result<NaiveDate, DateError> {
+    let lower = s.trim().to_lowercase();
+    let words: Vec<_> = lower.split_whitespace().collect();
+    let invalid = || DateError::Invalid(s.trim().to_string());
+
+    let date = match words.as_slice() {
+        [] => return Err(DateError::Empty),
+        ["today" | "now" | "eod"] => Some(today),
+        ["tomorrow" | "tmr" | "tmrw"] => today.succ_opt(),
+        ["yesterday"] => today.pred_opt(),
+        ["eow"] => next_weekday(today, Weekday::Fri, true),
+        ["eom"] => today
+            .with_day(1)
+            .and_then(|first| Unit::Month.shift(first, 1, true))
+            .and_then(|first| first.pred_opt()),
+        ["next", "week"] => Unit::Week.shift(today, 1, true),
+        ["next", "month"] => Unit::Month.shift(today, 1, true),
+        ["next", "year"] => Unit::Year.shift(today, 1, true),
+        ["in", amount @ ..] => {
+            let (amount, unit) = parse_amount(amount).ok_or_else(invalid)?;
+            unit.shift(today, amount, true)
+        }
+        [amount @ .., "ago"] => {
+            let (amount, unit) = parse_amount(amount).ok_or_else(invalid)?;
+            unit.shift(today, amount, false)
+        }
+        [day] | ["next", day] if parse_weekday(day).is_some() => {
+            let weekday = parse_weekday(day).ok_or_else(invalid)?;
+            today
+                .succ_opt()
+                .and_then(|date| next_weekday(date, weekday, true))
+        }
+        ["last", day] => {
+            let weekday = parse_weekday(day).ok_or_else(invalid)?;
+            today
+                .pred_opt()
+                .and_then(|date| next_weekday(date, weekday, false))
+        }
+        [word] => match word.as_bytes()[0] {
+            b'+' | b'-' => {
+                let (amount, unit) = parse_amount(&[&word[1..]]).ok_or_else(invalid)?;
+                unit.shift(today, amount, word.starts_with('+'))
+            }
+            b'0'..=b'9' if !word.contains('-') => {
+                let (amount, unit) = parse_amount(&[word]).ok_or_else(invalid)?;
+                unit.shift(today, amount, false)
+            }
+            _ => Some(NaiveDate::parse_from_str(word, "%Y-%m-%d").map_err(|_| invalid())?),
+        },
+        _ => return Err(invalid()),
+    };
+    date.ok_or_else(|| DateError::OutOfRange(s.trim().to_string()))
+}
+
+/// Parses a date relative to the current day, e.g. as the value parser of
+/// command line arguments. See `parse`.
+pub fn parse_from_today(s: &str) -> Result<NaiveDate, DateError> {
+    parse(s, Local::now().date_naive())
+}
+
+/// Finds when `date` starts in the local timezone, e.g. to compare it with
+/// the times changes were recorded at
+pub fn start_of(date: NaiveDate) -> DateTime<Utc> {
+    date.and_time(NaiveTime::MIN)
+        .and_local_timezone(Local)
+        .earliest()
+        .map_or(DateTime::<Utc>::MIN_UTC, |time| time.with_timezone(&Utc))
+}
+
+/// Helper function that parses an amount of time, either as a single word
+/// (e.g. `2w`) or as a number and a unit (e.g. `2 weeks`)
+fn parse_amount(words: &[&str]) -> Option<(u32, Unit)> {
+    let (amount, unit) = match words {
+        [word] => word.split_at(word.find(|c: char| !c.is_ascii_digit())?),
+        [amount, unit] => (*amount, *unit),
+        _ => return None,
+    };
+    Some((amount.parse().ok()?, Unit::parse(unit)?))
+}
+
+/// Helper function that parses the name of a weekday, either abbreviated or
+/// spelled out
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    match s {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Helper function that finds the first day with the weekday `weekday`,
+/// starting at `date` and going forward if `forward` and backward otherwise
+fn next_weekday(date: NaiveDate, weekday: Weekday, forward: bool) -> Option<NaiveDate> {
+    let from = date.weekday().num_days_from_monday();
+    let to = weekday.num_days_from_monday();
+    match forward {
+        true => date.checked_add_days(Days::new(((to + 7 - from) % 7).into())),
+        false => date.checked_sub_days(Days::new(((from + 7 - to) % 7).into())),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Parses `s` relative to Monday 2025-03-10
+    fn day(s: &str) -> Result<String, DateError> {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        parse(s, today).map(|d| d.to_string())
+    }
+
+    #[test]
+    fn days_are_parsed_relative_to_today() {
+        assert_eq!(day("today").as_deref(), Ok("2025-03-10"));
+        assert_eq!(day("EOD").as_deref(), Ok("2025-03-10"));
+        assert_eq!(day("Yesterday").as_deref(), Ok("2025-03-09"));
+        assert_eq!(day("tomorrow").as_deref(), Ok("2025-03-11"));
+        assert_eq!(day("2025-01-31").as_deref(), Ok("2025-01-31"));
+        assert_eq!(day(" 2025-12-01 ").as_deref(), Ok("2025-12-01"));
+    }
+
+    #[test]
+    fn weekdays_are_the_next_or_last_one() {
+        assert_eq!(day("fri").as_deref(), Ok("2025-03-14"));
+        assert_eq!(day("next Friday").as_deref(), Ok("2025-03-14"));
+        assert_eq!(day("mon").as_deref(), Ok("2025-03-17"));
+        assert_eq!(day("last tuesday").as_deref(), Ok("2025-03-04"));
+        assert_eq!(day("last sun").as_deref(), Ok("2025-03-09"));
+        assert_eq!(day("eow").as_deref(), Ok("2025-03-14"));
+        assert_eq!(day("eom").as_deref(), Ok("2025-03-31"));
+    }
+
+    #[test]
+    fn amounts_move_forward_or_backward() {
+        assert_eq!(day("in 2 weeks").as_deref(), Ok("2025-03-24"));
+        assert_eq!(day("in 3d").as_deref(), Ok("2025-03-13"));
+        assert_eq!(day("+1m").as_deref(), Ok("2025-04-10"));
+        assert_eq!(day("next year").as_deref(), Ok("2026-03-10"));
+        assert_eq!(day("3d").as_deref(), Ok("2025-03-07"));
+        assert_eq!(day("2w").as_deref(), Ok("2025-02-24"));
+        assert_eq!(day("-1y").as_deref(), Ok("2024-03-10"));
+        assert_eq!(day("1 month ago").as_deref(), Ok("2025-02-10"));
+    }
+
+    #[test]
+    fn invalid_dates_produce_errors() {
+        assert_eq!(day("  "), Err(DateError::Empty));
+        assert_eq!(day("xd"), Err(DateError::Invalid("xd".into())));
+        assert_eq!(
+            day("last week"),
+            Err(DateError::Invalid("last week".into()))
+        );
+        assert_eq!(
+            day("in 2 fortnights"),
+            Err(DateError::Invalid("in 2 fortnights".into()))
+        );
+        assert_eq!(
+            day("2025-02-30"),
+            Err(DateError::Invalid("2025-02-30".into()))
+        );
+        assert_eq!(
+            day("in 4294967295 years"),
+            Err(DateError::OutOfRange("in 4294967295 years".into()))
+        );
+    }
+}
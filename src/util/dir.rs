@@ -74,3 +74,19 @@ pub fn cache() -> Option<PathBuf> {
 
     None
 }
+
+/// Helper function to obtain the config directory for the project
+///
+/// # Returns
+/// The path representing the config directory, if it exists.
+pub fn config() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var("PLANIT_CONFIG") {
+        return Some(PathBuf::from(dir));
+    }
+
+    if let Some(dirs) = ProjectDirs::from("org", "planit", "planit") {
+        return Some(dirs.config_dir().to_path_buf());
+    }
+
+    None
+}
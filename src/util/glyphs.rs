@@ -0,0 +1,197 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the characters used to draw trees, icons, and progress bars.
+ *
+ * Not every terminal (or font) can show the Nerd Font icons or even the box
+ * drawing characters, so the glyphs can be switched to plain unicode or to
+ * ASCII. Like the theme, the glyphs are chosen once at startup with `set`.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The glyphs in use, set once at startup
+static GLYPHS: OnceLock<GlyphSet> = OnceLock::new();
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The sets of glyphs to choose from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum GlyphSet {
+    /// Box drawing characters and Nerd Font icons
+    #[default]
+    NerdFont,
+    /// Box drawing characters and icons that most fonts have
+    Unicode,
+    /// Only ASCII characters
+    Ascii,
+}
+
+impl GlyphSet {
+    /// The glyphs of the set
+    pub fn glyphs(self) -> &'static Glyphs {
+        match self {
+            GlyphSet::NerdFont => &Glyphs::NERD_FONT,
+            GlyphSet::Unicode => &Glyphs::UNICODE,
+            GlyphSet::Ascii => &Glyphs::ASCII,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The characters used for each part of the output. Connectors are three
+/// characters wide so that they line up with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    /// Connects the root of a tree to its children
+    pub top_corner: &'static str,
+    /// Continues a tree past a node that is not the last child
+    pub vconnector: &'static str,
+    /// Connects a child that is not the last one
+    pub node_piece: &'static str,
+    /// Connects the last child
+    pub bot_corner: &'static str,
+    pub comet: &'static str,
+    pub planet: &'static str,
+    pub star: &'static str,
+    /// Marks pinned celestial bodies
+    pub pin: &'static str,
+    /// The finished part of a progress bar
+    pub bar_filled: &'static str,
+    /// The unfinished part of a progress bar
+    pub bar_empty: &'static str,
+}
+
+impl Glyphs {
+    pub const NERD_FONT: Glyphs = Glyphs {
+        top_corner: "┏━ ",
+        vconnector: "┃  ",
+        node_piece: "┣━ ",
+        bot_corner: "┗━ ",
+        comet: "",
+        planet: "",
+        star: "",
+        pin: "",
+        bar_filled: "█",
+        bar_empty: "░",
+    };
+
+    pub const UNICODE: Glyphs = Glyphs {
+        comet: "☄",
+        planet: "●",
+        star: "★",
+        pin: "⚑",
+        ..Glyphs::NERD_FONT
+    };
+
+    pub const ASCII: Glyphs = Glyphs {
+        top_corner: "+- ",
+        vconnector: "|  ",
+        node_piece: "+- ",
+        bot_corner: "`- ",
+        comet: "!",
+        planet: "o",
+        star: "*",
+        pin: "^",
+        bar_filled: "#",
+        bar_empty: "-",
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Sets the glyphs used from now on. Only the first call has an effect.
+pub fn set(set: GlyphSet) {
+    let _ = GLYPHS.set(set);
+}
+
+/// The glyphs in use, the Nerd Font glyphs if none were set
+pub fn current() -> &'static Glyphs {
+    GLYPHS.get().copied().unwrap_or_default().glyphs()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn connectors_line_up_in_every_set() {
+        for set in GlyphSet::value_variants() {
+            let glyphs = set.glyphs();
+            let connectors = [
+                glyphs.top_corner,
+                glyphs.vconnector,
+                glyphs.node_piece,
+                glyphs.bot_corner,
+            ];
+            assert!(connectors.iter().all(|c| c.chars().count() == 3));
+        }
+        let ascii = Glyphs::ASCII;
+        assert!([
+            ascii.top_corner,
+            ascii.comet,
+            ascii.bar_filled,
+            ascii.bar_empty
+        ]
+        .iter()
+        .all(|g| g.is_ascii()));
+    }
+}
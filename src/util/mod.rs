@@ -27,8 +27,18 @@
  * A collection of helper utility functions
  */
 
+pub mod date;
+#[cfg(feature = "app")]
 pub mod dir;
+pub mod glyphs;
+#[cfg(feature = "app")]
 pub mod log;
+#[cfg(feature = "app")]
 pub mod panic;
+pub mod progress;
+pub mod shell;
+pub mod theme;
+pub mod time;
 pub mod tree;
+#[cfg(feature = "app")]
 pub mod tui;
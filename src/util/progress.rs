@@ -0,0 +1,294 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Progress reporting for long running operations such as imports.
+ *
+ * When stderr is a terminal a bar is redrawn in place, otherwise a line is
+ * logged every few seconds so that redirected output stays readable.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TYPES                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How long `Lines` waits between reports
+const LINE_INTERVAL: Duration = Duration::from_secs(5);
+/// How long `Bar` waits between redraws
+const BAR_INTERVAL: Duration = Duration::from_millis(50);
+/// The number of characters in the bar drawn by `Bar`
+const BAR_WIDTH: u64 = 30;
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TRAITS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Receives updates about the progress of a long running operation. An
+/// operation consists of one or more steps, each of which is started, advanced
+/// any number of times, and finished.
+pub trait Reporter {
+    /// Starts a new step described by `step`, with `total` units of work if
+    /// the amount is known up front
+    fn start(&mut self, step: &str, total: Option<u64>);
+
+    /// Records that `units` more units of work of the current step are done
+    fn advance(&mut self, units: u64);
+
+    /// Finishes the current step
+    fn finish(&mut self);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The state of the current step, shared by the reporters
+#[derive(Debug, Default)]
+struct Step {
+    name: String,
+    total: Option<u64>,
+    done: u64,
+}
+
+impl Step {
+    fn new(name: &str, total: Option<u64>) -> Self {
+        Self {
+            name: name.to_string(),
+            total,
+            done: 0,
+        }
+    }
+
+    /// Formats the amount of work done, e.g. `3/10` or `3`
+    fn count(&self) -> String {
+        match self.total {
+            Some(total) => format!("{}/{}", self.done, total),
+            None => self.done.to_string(),
+        }
+    }
+}
+
+/// Reports nothing
+#[derive(Debug, Default)]
+pub struct Silent;
+
+impl Reporter for Silent {
+    fn start(&mut self, _: &str, _: Option<u64>) {}
+    fn advance(&mut self, _: u64) {}
+    fn finish(&mut self) {}
+}
+
+/// Draws a bar that is redrawn in place, meant for terminals
+#[derive(Debug)]
+pub struct Bar<W: Write> {
+    writer: W,
+    step: Step,
+    drawn: Option<Instant>,
+}
+
+impl<W: Write> Bar<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            step: Step::default(),
+            drawn: None,
+        }
+    }
+
+    /// Helper function that redraws the bar. Errors are ignored since progress
+    /// is not worth failing an operation over.
+    fn draw(&mut self) {
+        let bar = match self.step.total {
+            Some(total) if total > 0 => {
+                let filled = (self.step.done.min(total) * BAR_WIDTH / total) as usize;
+                let empty = BAR_WIDTH as usize - filled;
+                format!("[{}{}] ", "#".repeat(filled), "-".repeat(empty))
+            }
+            _ => String::new(),
+        };
+        let _ = write!(
+            self.writer,
+            "\r\x1b[2K{} {}{}",
+            self.step.name,
+            bar,
+            self.step.count()
+        );
+        let _ = self.writer.flush();
+        self.drawn = Some(Instant::now());
+    }
+}
+
+impl<W: Write> Reporter for Bar<W> {
+    fn start(&mut self, step: &str, total: Option<u64>) {
+        self.step = Step::new(step, total);
+        self.draw();
+    }
+
+    fn advance(&mut self, units: u64) {
+        self.step.done += units;
+        if self.drawn.is_none_or(|t| t.elapsed() >= BAR_INTERVAL) {
+            self.draw();
+        }
+    }
+
+    fn finish(&mut self) {
+        self.draw();
+        let _ = writeln!(self.writer);
+    }
+}
+
+/// Writes a line when a step starts and finishes, and every `interval` in
+/// between, meant for output that is not a terminal
+#[derive(Debug)]
+pub struct Lines<W: Write> {
+    writer: W,
+    step: Step,
+    interval: Duration,
+    written: Instant,
+}
+
+impl<W: Write> Lines<W> {
+    pub fn new(writer: W, interval: Duration) -> Self {
+        Self {
+            writer,
+            step: Step::default(),
+            interval,
+            written: Instant::now(),
+        }
+    }
+
+    /// Helper function that writes a line. Errors are ignored since progress
+    /// is not worth failing an operation over.
+    fn write(&mut self, status: &str) {
+        let _ = writeln!(
+            self.writer,
+            "{}: {} {}",
+            self.step.name,
+            self.step.count(),
+            status
+        );
+        self.written = Instant::now();
+    }
+}
+
+impl<W: Write> Reporter for Lines<W> {
+    fn start(&mut self, step: &str, total: Option<u64>) {
+        self.step = Step::new(step, total);
+        self.write("started");
+    }
+
+    fn advance(&mut self, units: u64) {
+        self.step.done += units;
+        if self.written.elapsed() >= self.interval {
+            self.write("in progress");
+        }
+    }
+
+    fn finish(&mut self) {
+        self.write("done");
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Creates the reporter suited to stderr: a `Bar` if it is a terminal and
+/// `Lines` otherwise
+pub fn stderr() -> Box<dyn Reporter> {
+    if io::stderr().is_terminal() {
+        Box::new(Bar::new(io::stderr()))
+    } else {
+        Box::new(Lines::new(io::stderr(), LINE_INTERVAL))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lines_are_written_for_each_step() {
+        let mut output = Vec::new();
+        let mut lines = Lines::new(&mut output, Duration::ZERO);
+        lines.start("Fetching issues", None);
+        lines.advance(100);
+        lines.finish();
+        lines.start("Adding issues", Some(4));
+        lines.advance(4);
+        lines.finish();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Fetching issues: 0 started\n\
+             Fetching issues: 100 in progress\n\
+             Fetching issues: 100 done\n\
+             Adding issues: 0/4 started\n\
+             Adding issues: 4/4 in progress\n\
+             Adding issues: 4/4 done\n"
+        );
+    }
+
+    #[test]
+    fn bar_is_filled_in_proportion() {
+        let mut output = Vec::new();
+        let mut bar = Bar::new(&mut output);
+        bar.start("Adding", Some(3));
+        bar.advance(1);
+        bar.finish();
+
+        let output = String::from_utf8(output).unwrap();
+        let last = output.trim_end().rsplit('\r').next().unwrap();
+        assert_eq!(
+            last,
+            format!("\x1b[2KAdding [{}{}] 1/3", "#".repeat(10), "-".repeat(20))
+        );
+    }
+}
@@ -0,0 +1,417 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Helper utilities related to running shell commands. Commands are run in a
+ * background thread so that their output can be streamed to the caller (e.g.
+ * an output pane) while they are still running.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{
+    env, fmt,
+    io::{self, BufRead, BufReader, Read, Write},
+    path::Path,
+    process::{Command, Stdio},
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A single piece of output from a running command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    /// A line written to stdout
+    Stdout(String),
+    /// A line written to stderr
+    Stderr(String),
+    /// The command finished. Contains the exit code, which is `None` if the
+    /// command was terminated by a signal.
+    Exit(Option<i32>),
+}
+
+/// Possible errors when expanding a command template
+#[derive(Debug, PartialEq, Eq)]
+pub enum TemplateError {
+    /// The placeholder does not correspond to any known value
+    UnknownKey(String),
+    /// A `{` was never closed
+    Unclosed,
+}
+
+impl std::error::Error for TemplateError {}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::UnknownKey(key) => write!(f, "Unknown template key: {key}"),
+            TemplateError::Unclosed => write!(f, "Unclosed '{{' in template"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// A command running in the background
+#[derive(Debug)]
+pub struct Runner {
+    /// The command line that is being run
+    pub command: String,
+    rx: Receiver<Output>,
+}
+
+impl Runner {
+    /// Starts running `command` with the system shell in a background thread
+    ///
+    /// # Errors
+    /// Errors will occur if the shell could not be started
+    pub fn spawn(command: &str) -> io::Result<Self> {
+        let mut child = shell(command)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let (tx, rx) = mpsc::channel();
+        let stdout = child.stdout.take().map(|out| {
+            let tx = tx.clone();
+            thread::spawn(move || stream_lines(out, tx, Output::Stdout))
+        });
+        let stderr = child.stderr.take().map(|err| {
+            let tx = tx.clone();
+            thread::spawn(move || stream_lines(err, tx, Output::Stderr))
+        });
+
+        thread::spawn(move || {
+            // Wait for all output to be sent before reporting the exit status
+            for reader in [stdout, stderr].into_iter().flatten() {
+                let _ = reader.join();
+            }
+            let code = child.wait().ok().and_then(|status| status.code());
+            let _ = tx.send(Output::Exit(code));
+        });
+
+        Ok(Self {
+            command: command.to_string(),
+            rx,
+        })
+    }
+
+    /// Returns the next piece of output if one is available without blocking
+    pub fn try_recv(&self) -> Option<Output> {
+        self.rx.try_recv().ok()
+    }
+
+    /// Blocks until the next piece of output is available. Returns `None` once
+    /// the command has finished and all output has been received.
+    pub fn recv(&self) -> Option<Output> {
+        self.rx.recv().ok()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Creates a `Command` that will run `command` with the system shell
+pub fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    }
+}
+
+/// Creates a `Command` that will open `path` in the user's editor, which is
+/// taken from `$VISUAL` or `$EDITOR` (falling back to `vi`, or `notepad` on
+/// Windows)
+pub fn editor(path: &Path) -> Command {
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| if cfg!(windows) { "notepad" } else { "vi" }.to_string());
+    shell(&format!("{editor} {}", quote(&path.to_string_lossy())))
+}
+
+/// Creates a `Command` that will page through whatever is written to its
+/// stdin, which is taken from `$PAGER` (falling back to `less`). Like git,
+/// `less` is told to keep colors and to quit if everything fits on screen
+/// unless `$LESS` says otherwise.
+pub fn pager() -> Command {
+    pager_from(env::var("PAGER").ok(), env::var_os("LESS").is_some())
+}
+
+/// Helper function that creates the pager `Command` from the value of `$PAGER`
+/// and whether `$LESS` is set
+fn pager_from(pager: Option<String>, less: bool) -> Command {
+    let pager = pager
+        .filter(|p| !p.trim().is_empty())
+        .unwrap_or_else(|| if cfg!(windows) { "more" } else { "less" }.to_string());
+    let mut cmd = shell(&pager);
+    if !less {
+        cmd.env("LESS", "FRX");
+    }
+    cmd
+}
+
+/// Reads the system clipboard by running the first clipboard tool that is
+/// installed, returning `None` if none of them are or the clipboard is empty
+pub fn clipboard() -> Option<String> {
+    let tools: &[&[&str]] = if cfg!(windows) {
+        &[&["powershell", "-NoProfile", "-Command", "Get-Clipboard"]]
+    } else if cfg!(target_os = "macos") {
+        &[&["pbpaste"]]
+    } else {
+        &[
+            &["wl-paste", "--no-newline"],
+            &["xclip", "-selection", "clipboard", "-o"],
+            &["xsel", "--clipboard", "--output"],
+        ]
+    };
+    tools.iter().find_map(|tool| {
+        let output = Command::new(tool[0])
+            .args(&tool[1..])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        let text = String::from_utf8(output.stdout).ok()?;
+        (output.status.success() && !text.is_empty()).then_some(text)
+    })
+}
+
+/// Writes `text` to the system clipboard by running the first clipboard tool
+/// that is installed, returning whether one of them succeeded
+pub fn set_clipboard(text: &str) -> bool {
+    let tools: &[&[&str]] = if cfg!(windows) {
+        &[&["clip"]]
+    } else if cfg!(target_os = "macos") {
+        &[&["pbcopy"]]
+    } else {
+        &[
+            &["wl-copy"],
+            &["xclip", "-selection", "clipboard", "-i"],
+            &["xsel", "--clipboard", "--input"],
+        ]
+    };
+    tools.iter().any(|tool| {
+        let Ok(mut child) = Command::new(tool[0])
+            .args(&tool[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            return false;
+        };
+        // Dropping stdin closes it, so that the tool stops reading
+        let written = child
+            .stdin
+            .take()
+            .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+        child.wait().is_ok_and(|status| status.success()) && written
+    })
+}
+
+/// Opens `target` (a URL or file path) with the system opener: `open` on
+/// macOS, `start` on Windows, and `xdg-open` everywhere else
+///
+/// # Errors
+/// Errors will occur if the opener could not be run or did not succeed
+pub fn open(target: &str) -> io::Result<()> {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        // The first quoted argument of `start` is the window title
+        cmd.args(["/C", "start", ""]);
+        cmd
+    } else if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else {
+        Command::new("xdg-open")
+    };
+    let status = cmd
+        .arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    match status.success() {
+        true => Ok(()),
+        false => Err(io::Error::other(format!(
+            "Could not open {target} ({status})"
+        ))),
+    }
+}
+
+/// Expands `{key}` placeholders in `template` using `lookup`. Every value is
+/// quoted so that it is passed to the shell as a single argument. Use `{{` and
+/// `}}` for literal braces.
+///
+/// # Errors
+/// Errors will occur if `lookup` does not know a key or a `{` is never closed
+pub fn expand<F>(template: &str, lookup: F) -> Result<String, TemplateError>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut expanded = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                expanded.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                expanded.push('}');
+            }
+            '{' => {
+                let mut key = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => key.push(c),
+                        None => return Err(TemplateError::Unclosed),
+                    }
+                }
+                let key = key.trim();
+                let value = lookup(key).ok_or_else(|| TemplateError::UnknownKey(key.into()))?;
+                expanded.push_str(&quote(&value));
+            }
+            c => expanded.push(c),
+        }
+    }
+
+    Ok(expanded)
+}
+
+/// Quotes `s` so that the shell treats it as a single literal argument
+pub fn quote(s: &str) -> String {
+    if cfg!(windows) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        format!("'{}'", s.replace('\'', "'\\''"))
+    }
+}
+
+/// Helper function that sends every line of `reader` through `tx`
+fn stream_lines<R: Read>(reader: R, tx: Sender<Output>, wrap: fn(String) -> Output) {
+    for line in BufReader::new(reader).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if tx.send(wrap(line)).is_err() {
+            break;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn expand_quotes_values() {
+        let lookup = |key: &str| match key {
+            "id" => Some("42".to_string()),
+            "title" => Some("it's; rm -rf".to_string()),
+            _ => None,
+        };
+
+        assert_eq!(
+            expand("echo {id} { title } {{literal}}", lookup),
+            Ok("echo '42' 'it'\\''s; rm -rf' {literal}".to_string())
+        );
+        assert_eq!(
+            expand("echo {missing}", lookup),
+            Err(TemplateError::UnknownKey("missing".into()))
+        );
+        assert_eq!(expand("echo {id", lookup), Err(TemplateError::Unclosed));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn runner_streams_output_and_exit_code() {
+        let runner = Runner::spawn("echo out; echo err 1>&2; exit 3").unwrap();
+
+        let mut output = Vec::new();
+        while let Some(out) = runner.recv() {
+            output.push(out);
+        }
+
+        assert!(output.contains(&Output::Stdout("out".into())));
+        assert!(output.contains(&Output::Stderr("err".into())));
+        assert_eq!(output.last(), Some(&Output::Exit(Some(3))));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pager_falls_back_to_less() {
+        let args = |cmd: &Command| cmd.get_args().map(|a| a.to_owned()).collect::<Vec<_>>();
+        let less = |cmd: &Command| {
+            cmd.get_envs()
+                .find(|(key, _)| *key == "LESS")
+                .and_then(|(_, value)| value.map(|v| v.to_owned()))
+        };
+
+        let cmd = pager_from(None, false);
+        assert_eq!(args(&cmd), ["-c", "less"]);
+        assert_eq!(less(&cmd), Some("FRX".into()));
+        assert_eq!(args(&pager_from(Some(" ".into()), false)), ["-c", "less"]);
+
+        // `$LESS` set by the user is left alone
+        let cmd = pager_from(Some("most -s".into()), true);
+        assert_eq!(args(&cmd), ["-c", "most -s"]);
+        assert_eq!(less(&cmd), None);
+    }
+}
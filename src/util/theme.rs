@@ -0,0 +1,556 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Contains the color themes used by both the command line output and the TUI.
+ *
+ * A `Theme` names the color of everything that is colored, such as each
+ * status or the tree connectors, so that changing the theme changes the
+ * colors everywhere. The theme is chosen once at startup with `set`, and
+ * `current` is used wherever something is colored.
+ *
+ * Tags are colored with one of the colors of the theme's palette, picked by
+ * hashing the name of the tag, so that a tag has the same color everywhere
+ * and in every run. Colors can be pinned to tags with `set_tag_colors`.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::{collections::BTreeMap, fmt, str::FromStr, sync::OnceLock};
+
+use clap::ValueEnum;
+use colored::{ColoredString, Colorize};
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The theme in use, set once at startup
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// The colors pinned to tags, set once at startup
+static TAG_COLORS: OnceLock<BTreeMap<String, Color>> = OnceLock::new();
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TRAITS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Colors command line output with the colors of the current theme
+pub trait Themed: Colorize + Sized {
+    /// Colors less important text, e.g. descriptions and hints
+    fn muted(self) -> ColoredString {
+        self.color(current().muted)
+    }
+
+    /// Colors text that should stand out, e.g. headings
+    fn accent(self) -> ColoredString {
+        self.color(current().accent)
+    }
+
+    /// Colors values such as estimates, progress, and tags
+    fn highlight(self) -> ColoredString {
+        self.color(current().highlight)
+    }
+
+    /// Colors something that was added or went well
+    fn success(self) -> ColoredString {
+        self.color(current().success)
+    }
+
+    /// Colors something that was changed or needs attention
+    fn warning(self) -> ColoredString {
+        self.color(current().warning)
+    }
+
+    /// Colors something that was removed or went wrong
+    fn error(self) -> ColoredString {
+        self.color(current().error)
+    }
+}
+
+impl<T: Colorize> Themed for T {}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// When the CLI output is colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ColorChoice {
+    /// Only when writing to a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// A color that a theme can use. Written as its name (e.g. `bright_blue`) or
+/// as a hex code (e.g. `#ff8800`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// The names of the colors, in the order of the variants
+    const NAMES: [&str; 16] = [
+        "black",
+        "red",
+        "green",
+        "yellow",
+        "blue",
+        "magenta",
+        "cyan",
+        "white",
+        "bright_black",
+        "bright_red",
+        "bright_green",
+        "bright_yellow",
+        "bright_blue",
+        "bright_magenta",
+        "bright_cyan",
+        "bright_white",
+    ];
+    const NAMED: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+        Color::BrightBlack,
+        Color::BrightRed,
+        Color::BrightGreen,
+        Color::BrightYellow,
+        Color::BrightBlue,
+        Color::BrightMagenta,
+        Color::BrightCyan,
+        Color::BrightWhite,
+    ];
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            color => {
+                let index = Color::NAMED.iter().position(|c| c == color).unwrap_or(0);
+                write!(f, "{}", Color::NAMES[index])
+            }
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid color: {s}");
+        if let Some(hex) = s.strip_prefix('#') {
+            if hex.len() != 6 || !hex.is_ascii() {
+                return Err(invalid());
+            }
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+            return Ok(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+        }
+        let name = s.trim().to_lowercase().replace(['-', ' '], "_");
+        let name = match name.as_str() {
+            "purple" => "magenta",
+            "gray" | "grey" => "white",
+            "dark_gray" | "dark_grey" => "bright_black",
+            name => name,
+        };
+        Color::NAMES
+            .iter()
+            .position(|n| *n == name)
+            .map(|i| Color::NAMED[i])
+            .ok_or_else(invalid)
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Color> for String {
+    fn from(value: Color) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<Color> for colored::Color {
+    fn from(value: Color) -> Self {
+        match value {
+            Color::Black => Self::Black,
+            Color::Red => Self::Red,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Blue => Self::Blue,
+            Color::Magenta => Self::Magenta,
+            Color::Cyan => Self::Cyan,
+            Color::White => Self::White,
+            Color::BrightBlack => Self::BrightBlack,
+            Color::BrightRed => Self::BrightRed,
+            Color::BrightGreen => Self::BrightGreen,
+            Color::BrightYellow => Self::BrightYellow,
+            Color::BrightBlue => Self::BrightBlue,
+            Color::BrightMagenta => Self::BrightMagenta,
+            Color::BrightCyan => Self::BrightCyan,
+            Color::BrightWhite => Self::BrightWhite,
+            Color::Rgb(r, g, b) => Self::TrueColor { r, g, b },
+        }
+    }
+}
+
+#[cfg(feature = "app")]
+impl From<Color> for ratatui::style::Color {
+    fn from(value: Color) -> Self {
+        match value {
+            Color::Black => Self::Black,
+            Color::Red => Self::Red,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Blue => Self::Blue,
+            Color::Magenta => Self::Magenta,
+            Color::Cyan => Self::Cyan,
+            Color::White => Self::Gray,
+            Color::BrightBlack => Self::DarkGray,
+            Color::BrightRed => Self::LightRed,
+            Color::BrightGreen => Self::LightGreen,
+            Color::BrightYellow => Self::LightYellow,
+            Color::BrightBlue => Self::LightBlue,
+            Color::BrightMagenta => Self::LightMagenta,
+            Color::BrightCyan => Self::LightCyan,
+            Color::BrightWhite => Self::White,
+            Color::Rgb(r, g, b) => Self::Rgb(r, g, b),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STRUCTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// The color of everything that is colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Theme {
+    pub comet: Color,
+    pub planet: Color,
+    pub star: Color,
+
+    pub todo: Color,
+    pub next: Color,
+    pub start: Color,
+    pub hold: Color,
+    pub block: Color,
+    pub done: Color,
+    pub cancel: Color,
+
+    pub critical: Color,
+    pub high: Color,
+    pub medium: Color,
+    pub low: Color,
+
+    /// The connectors of printed trees
+    pub tree: Color,
+    /// The border of the active pane and of popups
+    pub border: Color,
+    /// Text that should stand out, e.g. headings and key hints
+    pub accent: Color,
+    /// Less important text, e.g. descriptions and inactive borders
+    pub muted: Color,
+    /// Values such as estimates, progress, and tags
+    pub highlight: Color,
+    pub code: Color,
+    pub link: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// The palette tags are colored with, see `Theme::tag_color`
+    pub tags: [Color; 6],
+}
+
+impl Theme {
+    /// The names of the built in themes, the first is the default
+    pub const BUILT_IN: [&str; 3] = ["dark", "light", "high-contrast"];
+
+    /// The default theme, meant for terminals with a dark background
+    pub const DARK: Theme = Theme {
+        comet: Color::Red,
+        planet: Color::Blue,
+        star: Color::Yellow,
+        todo: Color::BrightYellow,
+        next: Color::Magenta,
+        start: Color::Green,
+        hold: Color::BrightBlack,
+        block: Color::Red,
+        done: Color::BrightBlack,
+        cancel: Color::BrightBlack,
+        critical: Color::BrightRed,
+        high: Color::Red,
+        medium: Color::Yellow,
+        low: Color::Blue,
+        tree: Color::Magenta,
+        border: Color::Magenta,
+        accent: Color::Magenta,
+        muted: Color::BrightBlack,
+        highlight: Color::Cyan,
+        code: Color::Yellow,
+        link: Color::Blue,
+        success: Color::Green,
+        warning: Color::Yellow,
+        error: Color::Red,
+        tags: [
+            Color::Cyan,
+            Color::Green,
+            Color::Magenta,
+            Color::Blue,
+            Color::BrightYellow,
+            Color::BrightRed,
+        ],
+    };
+
+    /// A theme for terminals with a light background, which avoids the yellows
+    /// that are hard to read on them
+    pub const LIGHT: Theme = Theme {
+        star: Color::Rgb(0xaf, 0x87, 0x00),
+        todo: Color::Rgb(0xaf, 0x87, 0x00),
+        hold: Color::Rgb(0x6c, 0x6c, 0x6c),
+        done: Color::Rgb(0x6c, 0x6c, 0x6c),
+        cancel: Color::Rgb(0x6c, 0x6c, 0x6c),
+        critical: Color::Rgb(0xd7, 0x00, 0x00),
+        medium: Color::Rgb(0xaf, 0x5f, 0x00),
+        muted: Color::Rgb(0x6c, 0x6c, 0x6c),
+        highlight: Color::Rgb(0x00, 0x87, 0x87),
+        code: Color::Rgb(0xaf, 0x5f, 0x00),
+        warning: Color::Rgb(0xaf, 0x5f, 0x00),
+        tags: [
+            Color::Rgb(0x00, 0x87, 0x87),
+            Color::Rgb(0x00, 0x87, 0x00),
+            Color::Rgb(0x87, 0x00, 0x87),
+            Color::Rgb(0x00, 0x5f, 0xaf),
+            Color::Rgb(0xaf, 0x5f, 0x00),
+            Color::Rgb(0xaf, 0x00, 0x00),
+        ],
+        ..Theme::DARK
+    };
+
+    /// A theme that only uses bright colors, and white rather than gray for
+    /// less important text
+    pub const HIGH_CONTRAST: Theme = Theme {
+        comet: Color::BrightRed,
+        planet: Color::BrightBlue,
+        star: Color::BrightYellow,
+        next: Color::BrightMagenta,
+        start: Color::BrightGreen,
+        hold: Color::BrightWhite,
+        block: Color::BrightRed,
+        done: Color::BrightWhite,
+        cancel: Color::BrightWhite,
+        high: Color::BrightRed,
+        medium: Color::BrightYellow,
+        low: Color::BrightBlue,
+        tree: Color::BrightWhite,
+        border: Color::BrightYellow,
+        accent: Color::BrightCyan,
+        muted: Color::BrightWhite,
+        highlight: Color::BrightCyan,
+        code: Color::BrightYellow,
+        link: Color::BrightBlue,
+        success: Color::BrightGreen,
+        warning: Color::BrightYellow,
+        error: Color::BrightRed,
+        tags: [
+            Color::BrightCyan,
+            Color::BrightGreen,
+            Color::BrightMagenta,
+            Color::BrightBlue,
+            Color::BrightYellow,
+            Color::BrightRed,
+        ],
+        ..Theme::DARK
+    };
+
+    /// Finds the built in theme called `name`
+    pub fn built_in(name: &str) -> Option<Theme> {
+        match name {
+            "dark" => Some(Theme::DARK),
+            "light" => Some(Theme::LIGHT),
+            "high-contrast" => Some(Theme::HIGH_CONTRAST),
+            _ => None,
+        }
+    }
+
+    /// The color of the palette for `tag`, which only depends on its name
+    pub fn tag_color(&self, tag: &str) -> Color {
+        // FNV-1a, which unlike the hasher of the standard library is sure to
+        // give the same hash in every version
+        let hash = tag.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.tags[(hash % self.tags.len() as u64) as usize]
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::DARK
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Sets the theme used from now on. Only the first call has an effect.
+pub fn set(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// The theme in use, the default theme if none was set
+pub fn current() -> &'static Theme {
+    THEME.get().unwrap_or(&Theme::DARK)
+}
+
+/// Sets the colors pinned to tags, which are used instead of the palette of
+/// the theme. Only the first call has an effect.
+pub fn set_tag_colors(colors: BTreeMap<String, Color>) {
+    let _ = TAG_COLORS.set(colors);
+}
+
+/// The color of `tag`, either the color pinned to it or one of the palette of
+/// the current theme
+pub fn tag_color(tag: &str) -> Color {
+    match TAG_COLORS.get().and_then(|colors| colors.get(tag)) {
+        Some(color) => *color,
+        None => current().tag_color(tag),
+    }
+}
+
+/// `tag` as a chip (e.g. `#backend`) in its color, see `tag_color`
+pub fn tag_chip(tag: &str) -> ColoredString {
+    format!("#{tag}").color(tag_color(tag))
+}
+
+/// Sets whether the CLI output is colored. With `Auto`, escape codes are only
+/// written to terminals and never when `NO_COLOR` is set (or always when
+/// `CLICOLOR_FORCE` is set).
+pub fn set_color(choice: ColorChoice) {
+    match choice {
+        ColorChoice::Auto => colored::control::unset_override(),
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn colors_are_parsed() {
+        assert_eq!("bright_black".parse(), Ok(Color::BrightBlack));
+        assert_eq!("Dark Gray".parse(), Ok(Color::BrightBlack));
+        assert_eq!("purple".parse(), Ok(Color::Magenta));
+        assert_eq!("#FF8800".parse(), Ok(Color::Rgb(0xff, 0x88, 0x00)));
+        assert!("#ff88".parse::<Color>().is_err());
+        assert!("teal".parse::<Color>().is_err());
+        for color in Color::NAMED.into_iter().chain([Color::Rgb(1, 2, 3)]) {
+            assert_eq!(color.to_string().parse(), Ok(color));
+        }
+    }
+
+    #[test]
+    fn tags_always_get_the_same_color() {
+        let tags = ["backend", "frontend", "ui", "sprint-12", "bug", "docs"];
+        let colors: Vec<_> = tags.iter().map(|t| Theme::DARK.tag_color(t)).collect();
+        assert_eq!(Theme::DARK.tag_color("backend"), colors[0]);
+        assert!(colors.iter().all(|color| Theme::DARK.tags.contains(color)));
+        // Not every tag should end up with the same color
+        assert!(colors.iter().any(|color| *color != colors[0]));
+
+        let index = |theme: &Theme, color| theme.tags.iter().position(|c| *c == color);
+        for tag in tags {
+            assert_eq!(
+                index(&Theme::DARK, Theme::DARK.tag_color(tag)),
+                index(&Theme::LIGHT, Theme::LIGHT.tag_color(tag))
+            );
+        }
+    }
+
+    #[test]
+    fn never_coloring_writes_no_escape_codes() {
+        // Other tests expect plain output too, so colors are never forced on
+        set_color(ColorChoice::Never);
+        assert_eq!("Done".green().bold().to_string(), "Done");
+        assert_eq!("Late".warning().to_string(), "Late");
+        assert_eq!(tag_chip("backend").to_string(), "#backend");
+    }
+}
@@ -0,0 +1,233 @@
+////////////////////////////////////////////////////////////////////////////
+// The MIT License (MIT)                                                  //
+//                                                                        //
+// Copyright (c) 2025 Jacob Long                                          //
+//                                                                        //
+// Permission is hereby granted, free of charge, to any person obtaining  //
+// a copy of this software and associated documentation files (the        //
+// "Software"), to deal in the Software without restriction, including    //
+// without limitation the rights to use, copy, modify, merge, publish,    //
+// distribute, sublicense, and/or sell copies of the Software, and to     //
+// permit persons to whom the Software is furnished to do so, subject to  //
+// the following conditions:                                              //
+//                                                                        //
+// The above copyright notice and this permission notice shall be         //
+// included in all copies or substantial portions of the Software.        //
+//                                                                        //
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,        //
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF     //
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. //
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY   //
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,   //
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE      //
+// SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.                 //
+////////////////////////////////////////////////////////////////////////////
+
+/*!
+ * Helper utilities related to displaying dates and times.
+ *
+ * Times are shown relative to now (e.g. "3 days ago") unless the absolute
+ * style is chosen at startup with `set_style`, in which case they are shown
+ * as the local date and time. Details (e.g. `planit show`) show both.
+ */
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  IMPORTS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                  STATICS                                   //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+static STYLE: OnceLock<DateStyle> = OnceLock::new();
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How dates and times are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum DateStyle {
+    /// Relative to now, e.g. "3 days ago" or "due in 2 weeks"
+    #[default]
+    Relative,
+    /// As the date (and time), e.g. "2025-03-01 14:30" or "due 2025-03-15"
+    Absolute,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                 FUNCTIONS                                  //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// Sets how dates and times are shown. Only the first call has an effect.
+pub fn set_style(style: DateStyle) {
+    let _ = STYLE.set(style);
+}
+
+/// How dates and times are shown, relative if no style was set
+pub fn style() -> DateStyle {
+    STYLE.get().copied().unwrap_or_default()
+}
+
+/// Formats `time` in the style that was set, either relative to `now` (see
+/// `relative`) or as the local date and time (see `absolute`)
+pub fn timestamp(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    match style() {
+        DateStyle::Relative => relative(time, now),
+        DateStyle::Absolute => absolute(time),
+    }
+}
+
+/// Formats `time` as the local date and time (e.g. "2025-03-01 14:30")
+pub fn absolute(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+/// Formats when something is due in the style that was set, either relative
+/// to `today` (e.g. "due in 3 days" or "overdue by 1 week") or as the date
+/// (e.g. "due 2025-03-15")
+pub fn due(date: NaiveDate, today: NaiveDate) -> String {
+    match style() {
+        DateStyle::Relative => relative_due(date, today),
+        DateStyle::Absolute => format!("due {date}"),
+    }
+}
+
+/// Formats when something is due relative to `today` (e.g. "due in 3 days"
+/// or "overdue by 1 week"), regardless of the style that was set
+pub fn relative_due(date: NaiveDate, today: NaiveDate) -> String {
+    match (date - today).num_days() {
+        0 => "due today".to_string(),
+        1 => "due tomorrow".to_string(),
+        days if days > 0 => format!("due in {}", span(days)),
+        days => format!("overdue by {}", span(-days)),
+    }
+}
+
+/// Helper function that formats a number of days in the largest unit that
+/// fits (e.g. "3 days", "2 weeks", or "1 month")
+fn span(days: i64) -> String {
+    let (amount, unit) = if days < 7 {
+        (days, "day")
+    } else if days < 30 {
+        (days / 7, "week")
+    } else if days < 365 {
+        (days / 30, "month")
+    } else {
+        (days / 365, "year")
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+    format!("{amount} {unit}{plural}")
+}
+
+/// Formats `time` relative to `now` in a human readable way (e.g. "3 days ago")
+///
+/// # Arguments
+/// - `time`: The time to format
+/// - `now`: The time that `time` is relative to
+///
+/// # Returns
+/// The formatted string. Times in the future are formatted as "in 3 days"
+pub fn relative(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = now - time;
+    let seconds = delta.num_seconds().abs();
+
+    let (amount, unit) = if seconds < 60 {
+        return "just now".to_string();
+    } else if seconds < 60 * 60 {
+        (seconds / 60, "minute")
+    } else if seconds < 60 * 60 * 24 {
+        (seconds / (60 * 60), "hour")
+    } else if seconds < 60 * 60 * 24 * 7 {
+        (seconds / (60 * 60 * 24), "day")
+    } else if seconds < 60 * 60 * 24 * 30 {
+        (seconds / (60 * 60 * 24 * 7), "week")
+    } else if seconds < 60 * 60 * 24 * 365 {
+        (seconds / (60 * 60 * 24 * 30), "month")
+    } else {
+        (seconds / (60 * 60 * 24 * 365), "year")
+    };
+
+    let plural = if amount == 1 { "" } else { "s" };
+    if delta.num_seconds() >= 0 {
+        format!("{amount} {unit}{plural} ago")
+    } else {
+        format!("in {amount} {unit}{plural}")
+    }
+}
+
+/// Formats a number of minutes as hours and minutes (e.g. `1h30m`)
+pub fn hours_minutes(minutes: u64) -> String {
+    let hours = minutes / 60;
+    let minutes = minutes % 60;
+    match (hours, minutes) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h{m}m"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    #[test]
+    fn relative_formats_past_and_future() {
+        let now = Utc::now();
+
+        assert_eq!(relative(now, now), "just now");
+        assert_eq!(relative(now - TimeDelta::minutes(1), now), "1 minute ago");
+        assert_eq!(relative(now - TimeDelta::hours(5), now), "5 hours ago");
+        assert_eq!(relative(now - TimeDelta::days(3), now), "3 days ago");
+        assert_eq!(relative(now - TimeDelta::days(15), now), "2 weeks ago");
+        assert_eq!(relative(now + TimeDelta::days(2), now), "in 2 days");
+        assert_eq!(relative(now - TimeDelta::days(800), now), "2 years ago");
+    }
+
+    #[test]
+    fn due_dates_are_relative_to_today() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let due = |s: &str| relative_due(s.parse().unwrap(), today);
+
+        assert_eq!(due("2025-03-10"), "due today");
+        assert_eq!(due("2025-03-11"), "due tomorrow");
+        assert_eq!(due("2025-03-13"), "due in 3 days");
+        assert_eq!(due("2025-03-24"), "due in 2 weeks");
+        assert_eq!(due("2025-03-09"), "overdue by 1 day");
+        assert_eq!(due("2025-03-02"), "overdue by 1 week");
+        assert_eq!(due("2024-01-01"), "overdue by 1 year");
+    }
+
+    #[test]
+    fn hours_minutes_omits_empty_units() {
+        assert_eq!(hours_minutes(0), "0m");
+        assert_eq!(hours_minutes(45), "45m");
+        assert_eq!(hours_minutes(120), "2h");
+        assert_eq!(hours_minutes(90), "1h30m");
+    }
+}
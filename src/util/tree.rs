@@ -33,7 +33,7 @@
  *  ┏━ <Root Title>
  *  ┃  <Root Description>
  *  ┃
- *  ┣━ <Node Icon> <Node Label> <Node Status> <Node Title>
+ *  ┣━ <Node Icon> <Node Label> <Node Status> [Priority] [Progress] [Due] <Node Title>
  *  ┃              <Node Description>
  *  ┣━ <Node Icon> <Node Label> <Node Status> <Node Title>
  *  ┃              <Node Description>
@@ -50,7 +50,8 @@
  *  ```
  *
  *  The format can also be customized to not show descriptions or not recurse
- *  into children
+ *  into children, and to show other columns (see `Column`) in another order.
+ *  Columns after the title are only printed as far as they fit.
  */
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -61,7 +62,83 @@
 
 use std::io;
 
+use clap::ValueEnum;
 use colored::{ColoredString, Colorize};
+use serde::{Deserialize, Serialize};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::{glyphs, theme};
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   ENUMS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+/// How the descriptions of nodes are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Descriptions {
+    /// Descriptions are left out
+    #[default]
+    Hidden,
+    /// Only as much of a description as fits on one line is printed
+    Truncated,
+    /// Descriptions are wrapped onto as many lines as they need
+    Wrapped,
+}
+
+/// A column of the line printed for each node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Column {
+    Icon,
+    /// The label of the kind of node (e.g. `[PLANET]`)
+    Kind,
+    Id,
+    Status,
+    Priority,
+    Progress,
+    Due,
+    Title,
+    Tags,
+    Assignee,
+    /// The titles of the ancestors of the node, leading up to its title
+    /// (e.g. `Backend > Auth >`)
+    Path,
+}
+
+impl Column {
+    /// The columns printed unless others are chosen
+    pub const DEFAULT: [Column; 8] = [
+        Column::Icon,
+        Column::Kind,
+        Column::Status,
+        Column::Priority,
+        Column::Progress,
+        Column::Due,
+        Column::Title,
+        Column::Tags,
+    ];
+
+    /// Helper function that gets the parts of `node` shown in this column,
+    /// leaving out empty ones. Only tags can have more than one part.
+    fn parts<T>(self, node: &dyn PrintTreeNode<T>, root: &T) -> Vec<ColoredString> {
+        let parts = match self {
+            Column::Icon => vec![node.icon()],
+            Column::Kind => vec![node.label()],
+            Column::Id => vec![node.reference(root)],
+            Column::Status => vec![node.status(root)],
+            Column::Priority => vec![node.priority()],
+            Column::Progress => vec![node.progress(root)],
+            Column::Due => vec![node.due()],
+            Column::Title => vec![node.title()],
+            Column::Tags => node.chips(),
+            Column::Assignee => vec![node.assignees()],
+            Column::Path => vec![node.path(root)],
+        };
+        parts.into_iter().filter(|part| !part.is_empty()).collect()
+    }
+}
 
 ////////////////////////////////////////////////////////////////////////////////
 //                                                                            //
@@ -71,8 +148,8 @@ use colored::{ColoredString, Colorize};
 
 /// Used for pretty-printing trees. This is primarily designed for nodes that
 /// have a icon (should only be a single character), a label (denoting the
-/// type / kind of object it is), a status, a title, and a description. Each
-/// node may also have some number of children.
+/// type / kind of object it is), a status, an optional priority, progress,
+/// and due date, a title, and a description. Each node may also have some number of children.
 ///
 /// An example of the printed format is shown below.
 ///
@@ -89,9 +166,48 @@ pub trait PrintTreeNode<T> {
     /// Labels the type / kind of object it is
     fn label(&self) -> ColoredString;
     /// Status of the node
-    fn status(&self) -> ColoredString;
+    fn status(&self, root: &T) -> ColoredString;
+    /// How the node is referred to (e.g. `#12`). Not printed if empty
+    fn reference(&self, _root: &T) -> ColoredString {
+        ColoredString::default()
+    }
+    /// Where the node is, as the titles of its ancestors
+    /// (e.g. `Backend > Auth >`). Not printed if empty
+    fn path(&self, _root: &T) -> ColoredString {
+        ColoredString::default()
+    }
+    /// Priority of the node. Not printed if empty
+    fn priority(&self) -> ColoredString {
+        ColoredString::default()
+    }
+    /// Progress of the node (e.g. completed / total work). Not printed if
+    /// empty
+    fn progress(&self, _root: &T) -> ColoredString {
+        ColoredString::default()
+    }
+    /// When the node is due (e.g. "(due in 3 days)"). Not printed if empty
+    fn due(&self) -> ColoredString {
+        ColoredString::default()
+    }
     /// Title of the node
     fn title(&self) -> ColoredString;
+    /// Whether the node is marked as pinned before its title
+    fn pinned(&self) -> bool {
+        false
+    }
+    /// Chips printed after the title (e.g. tags), as many as fit
+    fn chips(&self) -> Vec<ColoredString> {
+        Vec::new()
+    }
+    /// Who is working on the node. Not printed if empty
+    fn assignees(&self) -> ColoredString {
+        ColoredString::default()
+    }
+    /// Note printed on its own line below the title (e.g. why the node is
+    /// blocked). Not printed if empty
+    fn note(&self, _root: &T) -> ColoredString {
+        ColoredString::default()
+    }
     /// Description for the node
     fn description(&self) -> ColoredString;
     /// Any potential children of the node
@@ -112,11 +228,12 @@ pub trait PrintTreeNode<T> {
 ///   memory associated with the data-structure and the children keep track of
 ///   references between nodes
 /// - `w`: Writer to write everything into
-/// - `width`: Horizontal character limit. Lines will be truncated to that
-///   length (with "..." to show lines were truncated)
-/// - `include_description`: Whether or not to print the description field
-///   when printing
+/// - `width`: Horizontal limit in terminal columns. Lines will be truncated to
+///   that width (with "..." to show lines were truncated)
+/// - `descriptions`: How (and whether) to print the description field
 /// - `recursive`: Recurse into children of the root's children as well
+/// - `columns`: The columns printed for each child, in order. The title is
+///   printed last if it is not one of them
 /// - `title`: The title of the root
 /// - `description`: The description for the root
 /// - `children`: The top-level children of root
@@ -124,25 +241,28 @@ pub fn print_to_writer<W: io::Write, T>(
     root: &T,
     w: &mut W,
     width: usize,
-    include_description: bool,
+    descriptions: Descriptions,
     recursive: bool,
+    columns: &[Column],
     title: ColoredString,
     description: ColoredString,
     children: Vec<Box<&dyn PrintTreeNode<T>>>,
 ) -> io::Result<()> {
-    let top_corner = "┏━ ".purple();
-    let vconnector = "┃  ".purple();
+    let tree = theme::current().tree;
+    let glyphs = glyphs::current();
+    let top_corner = glyphs.top_corner.color(tree);
+    let vconnector = glyphs.vconnector.color(tree);
 
     // print out the root
-    let root_title = truncate(title, width - top_corner.chars().count());
-    writeln!(w, "{}{}", top_corner, root_title)?;
-    if include_description {
-        let root_description = truncate(description, width - vconnector.chars().count());
-        writeln!(w, "{}{}", vconnector, root_description)?;
+    let indent = glyphs.top_corner.width();
+    let title = truncate(title, width.saturating_sub(indent));
+    writeln!(w, "{}", clip(&[top_corner, title], width))?;
+    for line in fit(description, width.saturating_sub(indent), descriptions) {
+        writeln!(w, "{}", clip(&[vconnector.clone(), line], width))?;
     }
-    writeln!(w, "{}", vconnector)?;
+    writeln!(w, "{}", clip(&[vconnector], width))?;
 
-    print_children_to_writer(root, w, width, include_description, recursive, children)?;
+    print_children_to_writer(root, w, width, descriptions, recursive, columns, children)?;
 
     Ok(())
 }
@@ -151,49 +271,82 @@ pub fn print_to_writer<W: io::Write, T>(
 //
 /// # Arguments
 /// - `w`: Writer to write everything into
-/// - `width`: Horizontal character limit. Lines will be truncated to that
-///   length (with "..." to show lines were truncated)
-/// - `include_description`: Whether or not to print the description field
-///   when printing
+/// - `width`: Horizontal limit in terminal columns. Lines will be truncated to
+///   that width (with "..." to show lines were truncated)
+/// - `descriptions`: How (and whether) to print the description field
 /// - `recursive`: Recurse into children of the children given
+/// - `columns`: The columns printed for each child, in order
 /// - `children`: Children to write to `w`
 fn print_children_to_writer<W: io::Write, T>(
     root: &T,
     w: &mut W,
     width: usize,
-    include_description: bool,
+    descriptions: Descriptions,
     recursive: bool,
+    columns: &[Column],
     children: Vec<Box<&dyn PrintTreeNode<T>>>,
 ) -> io::Result<()> {
-    let node_piece = "┣━ ".purple();
-    let vconnector = "┃  ".purple();
-    let bot_corner = "┗━ ".purple();
-    let empty = ColoredString::from("   ");
+    let tree = theme::current().tree;
+    let glyphs = glyphs::current();
+    let node_piece = glyphs.node_piece.color(tree);
+    let vconnector = glyphs.vconnector.color(tree);
+    let bot_corner = glyphs.bot_corner.color(tree);
+    let empty = ColoredString::from(" ".repeat(glyphs.vconnector.width()));
 
     let mut itr = children.iter().peekable();
     while let Some(child) = itr.next() {
         let is_last = itr.peek().is_none();
         let connector = if is_last { &bot_corner } else { &node_piece };
         let icon = child.icon();
-        let line = format!(
-            "{}{} {} {} ",
-            connector,
-            icon,
-            child.label(),
-            child.status()
-        );
-        let title = truncate(child.title(), width - line.chars().count());
-        writeln!(w, "{}{}", line, title)?;
+        // The title is truncated to fit the columns before it, while the
+        // columns after it are left out once they no longer fit
+        let title = columns.iter().position(|c| *c == Column::Title);
+        let (before, after) = columns.split_at(title.unwrap_or(columns.len()));
+        let parts: Vec<_> = before.iter().flat_map(|c| c.parts(**child, root)).collect();
+        // Only the text takes up columns, not the escape codes of its colors
+        let used = connector.input.width()
+            + parts
+                .iter()
+                .map(|part| part.input.width() + 1)
+                .sum::<usize>();
+        let mut line = vec![connector.clone()];
+        for part in parts {
+            line.extend([part, " ".into()]);
+        }
+        let pin = match child.pinned() {
+            true => format!("{} ", glyphs.pin).color(theme::current().highlight),
+            false => ColoredString::default(),
+        };
+        let used = used + pin.input.width();
+        let title = truncate(child.title(), width.saturating_sub(used));
+        let mut used = used + title.input.width();
+        line.extend([pin, title]);
+        for part in after.iter().skip(1).flat_map(|c| c.parts(**child, root)) {
+            used += part.input.width() + 1;
+            if used > width {
+                break;
+            }
+            line.extend([" ".into(), part]);
+        }
+        // The columns before the title are only cut off at very small widths
+        writeln!(w, "{}", clip(&line, width))?;
 
-        if include_description {
-            let connector = if is_last { &empty } else { &vconnector };
-            let line = format!(
-                "{:<width$} ",
-                connector,
-                width = connector.chars().count() + icon.chars().count()
-            );
-            let description = truncate(child.description(), width - line.chars().count());
-            writeln!(w, "{}{}", line, description)?;
+        let connector = if is_last { &empty } else { &vconnector };
+        let indent = connector.input.width() + icon.input.width() + 1;
+        let padding = " ".repeat(indent - connector.input.width());
+        let note = child.note(root);
+        if !note.is_empty() {
+            let note = truncate(note, width.saturating_sub(indent));
+            let line = [connector.clone(), padding.as_str().into(), note];
+            writeln!(w, "{}", clip(&line, width))?;
+        }
+        for line in fit(
+            child.description(),
+            width.saturating_sub(indent),
+            descriptions,
+        ) {
+            let line = [connector.clone(), padding.as_str().into(), line];
+            writeln!(w, "{}", clip(&line, width))?;
         }
 
         if recursive {
@@ -201,8 +354,9 @@ fn print_children_to_writer<W: io::Write, T>(
                 root,
                 w,
                 width,
-                include_description,
+                descriptions,
                 recursive,
+                columns,
                 child.children(root),
             )?;
         }
@@ -211,14 +365,241 @@ fn print_children_to_writer<W: io::Write, T>(
     Ok(())
 }
 
-/// Helper function to truncate the length of `s` to be less than or equal to
-/// `width` characters
+/// Helper function that fits `s` into lines of at most `width` columns in the
+/// way given by `descriptions`
+fn fit(s: ColoredString, width: usize, descriptions: Descriptions) -> Vec<ColoredString> {
+    match descriptions {
+        Descriptions::Hidden => vec![],
+        Descriptions::Truncated => vec![truncate(s, width)],
+        Descriptions::Wrapped => wrap(s, width),
+    }
+}
+
+/// Helper function that joins `segments` into a line of at most `width`
+/// columns, truncating the segment that crosses `width` and leaving out the
+/// ones after it
+fn clip(segments: &[ColoredString], width: usize) -> String {
+    let mut line = String::new();
+    let mut used = 0;
+    for segment in segments {
+        let remaining = width - used;
+        if segment.input.width() > remaining {
+            line.push_str(&truncate(segment.clone(), remaining).to_string());
+            break;
+        }
+        used += segment.input.width();
+        line.push_str(&segment.to_string());
+    }
+    line
+}
+
+/// Helper function to truncate `s` so that it takes up at most `width` columns
+/// in a terminal. Wide characters (e.g. CJK or emoji) take up two columns.
 fn truncate(mut s: ColoredString, width: usize) -> ColoredString {
-    if s.input.chars().count() <= width {
-        s
-    } else {
-        s.input.truncate(width - 3);
-        s.input.push_str("...");
-        s
+    if s.input.width() <= width {
+        return s;
+    }
+
+    let dots = width.min(3);
+    let mut used = 0;
+    let end = s
+        .input
+        .char_indices()
+        .find(|(_, c)| {
+            used += c.width().unwrap_or(0);
+            used > width - dots
+        })
+        .map_or(s.input.len(), |(i, _)| i);
+    s.input.truncate(end);
+    s.input.push_str(&".".repeat(dots));
+    s
+}
+
+/// Helper function to wrap `s` onto lines that take up at most `width` columns
+/// in a terminal. Lines are broken between words where possible, and every line
+/// keeps the colors of `s`.
+fn wrap(s: ColoredString, width: usize) -> Vec<ColoredString> {
+    let width = width.max(1);
+    let mut lines = vec![];
+    for paragraph in s.input.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let needed = match line.is_empty() {
+                true => word.width(),
+                false => line.width() + 1 + word.width(),
+            };
+            if needed <= width {
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(word);
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            // Words that are too long for a line of their own are split
+            for c in word.chars() {
+                if line.width() + c.width().unwrap_or(0) > width && !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                line.push(c);
+            }
+        }
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines
+        .into_iter()
+        .map(|input| {
+            let mut line = s.clone();
+            line.input = input;
+            line
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+//                                                                            //
+//                                   TESTS                                    //
+//                                                                            //
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn inputs(lines: Vec<ColoredString>) -> Vec<String> {
+        lines.into_iter().map(|line| line.input).collect()
+    }
+
+    struct Node;
+
+    impl PrintTreeNode<()> for Node {
+        fn icon(&self) -> ColoredString {
+            "*".into()
+        }
+        fn label(&self) -> ColoredString {
+            "[NODE]".into()
+        }
+        fn status(&self, _: &()) -> ColoredString {
+            "Todo".into()
+        }
+        fn reference(&self, _: &()) -> ColoredString {
+            "#1".into()
+        }
+        fn title(&self) -> ColoredString {
+            "Write the tests".into()
+        }
+        fn chips(&self) -> Vec<ColoredString> {
+            vec!["#backend".into(), "#ui".into()]
+        }
+        fn assignees(&self) -> ColoredString {
+            "@alice".into()
+        }
+        fn description(&self) -> ColoredString {
+            "".into()
+        }
+        fn children<'a>(&self, _: &'a ()) -> Vec<Box<&'a dyn PrintTreeNode<()>>> {
+            vec![]
+        }
+    }
+
+    fn line(columns: &[Column], width: usize) -> String {
+        colored::control::set_override(false);
+        let mut w = vec![];
+        let children: Vec<Box<&dyn PrintTreeNode<()>>> = vec![Box::new(&Node)];
+        let (title, description) = ("Root".into(), "".into());
+        print_to_writer(
+            &(),
+            &mut w,
+            width,
+            Descriptions::Hidden,
+            false,
+            columns,
+            title,
+            description,
+            children,
+        )
+        .unwrap();
+        let output = String::from_utf8(w).unwrap();
+        let line = output.lines().nth(2).unwrap();
+        line.split_once(' ').unwrap().1.to_string()
+    }
+
+    #[test]
+    fn columns_are_printed_in_order() {
+        assert_eq!(
+            line(&Column::DEFAULT, 80),
+            "* [NODE] Todo Write the tests #backend #ui"
+        );
+        use Column::*;
+        assert_eq!(
+            line(&[Id, Title, Assignee, Status], 80),
+            "#1 Write the tests @alice Todo"
+        );
+        assert_eq!(line(&[Assignee, Id], 80), "@alice #1 Write the tests");
+        // Columns after the title are left out once they no longer fit
+        assert!(line(&[Id, Title, Tags], 30).ends_with("#1 Write the tests #backend"));
+    }
+
+    #[test]
+    fn lines_never_exceed_the_width() {
+        colored::control::set_override(false);
+        for width in [0, 2, 5, 12, 20] {
+            let mut w = vec![];
+            let children: Vec<Box<&dyn PrintTreeNode<()>>> = vec![Box::new(&Node)];
+            print_to_writer(
+                &(),
+                &mut w,
+                width,
+                Descriptions::Wrapped,
+                false,
+                &Column::DEFAULT,
+                "Root".into(),
+                "The root of the tree".into(),
+                children,
+            )
+            .unwrap();
+            let output = String::from_utf8(w).unwrap();
+            for line in output.lines() {
+                assert!(line.width() <= width, "{line:?} is wider than {width}");
+            }
+        }
+        assert_eq!(line(&Column::DEFAULT, 10), "* [N...");
+    }
+
+    #[test]
+    fn truncation_counts_columns() {
+        assert_eq!(truncate("planit".into(), 6).input, "planit");
+        assert_eq!(truncate("planit".into(), 5).input, "pl...");
+        assert_eq!(truncate("日本語のタスク".into(), 9).input, "日本語...");
+        assert_eq!(truncate("日本語のタスク".into(), 8).input, "日本...");
+        assert_eq!(truncate("planit".into(), 2).input, "..");
+        assert_eq!(truncate("planit".into(), 0).input, "");
+    }
+
+    #[test]
+    fn wrapping_breaks_between_words() {
+        assert_eq!(
+            inputs(wrap("the quick brown fox".into(), 10)),
+            ["the quick", "brown fox"]
+        );
+        assert_eq!(
+            inputs(wrap("abcdefgh ij".into(), 3)),
+            ["abc", "def", "gh", "ij"]
+        );
+        assert_eq!(
+            inputs(wrap("日本語 タスク".into(), 6)),
+            ["日本語", "タスク"]
+        );
+        assert_eq!(
+            inputs(wrap("first\nsecond".into(), 20)),
+            ["first", "second"]
+        );
+        assert_eq!(inputs(wrap("".into(), 20)), [""]);
     }
 }